@@ -1,8 +1,12 @@
 // projects/libraries/protocol/src/command.rs
 use crate::payload::Payload;
-use crate::{CommandType, metadata::Metadata};
+use crate::{CommandType, ProtocolId, STREAM_CANCEL_ACTION, metadata::Metadata};
 use serde::{Deserialize, Serialize};
 
+/// Action of the JSON command telling a receiver to abandon the request
+/// `metadata.request_id`, e.g. because its caller stopped waiting.
+pub const REQUEST_CANCEL_ACTION: &str = "request.cancel";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Command {
     pub metadata: Metadata,
@@ -24,4 +28,41 @@ impl Command {
         });
         action_ok && payload_ok
     }
+
+    /// Command cancelling the stream opened by the request `request_id`.
+    pub fn cancel_stream(request_id: ProtocolId) -> Self {
+        Self {
+            metadata: Metadata {
+                request_id,
+                ..Metadata::now()
+            },
+            command_type: CommandType::CancelJob,
+            action: Some(STREAM_CANCEL_ACTION.to_string()),
+            payload: None,
+        }
+    }
+
+    /// True for a stream cancellation; the stream is `metadata.request_id`.
+    pub fn is_stream_cancel(&self) -> bool {
+        self.action.as_deref() == Some(STREAM_CANCEL_ACTION)
+    }
+
+    /// Command abandoning the request `request_id`; nobody waits for its
+    /// answer anymore.
+    pub fn cancel_request(request_id: ProtocolId) -> Self {
+        Self {
+            metadata: Metadata {
+                request_id,
+                ..Metadata::now()
+            },
+            command_type: CommandType::CancelJob,
+            action: Some(REQUEST_CANCEL_ACTION.to_string()),
+            payload: None,
+        }
+    }
+
+    /// True for a request cancellation; the request is `metadata.request_id`.
+    pub fn is_request_cancel(&self) -> bool {
+        self.action.as_deref() == Some(REQUEST_CANCEL_ACTION)
+    }
 }
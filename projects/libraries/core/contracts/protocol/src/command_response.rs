@@ -10,3 +10,45 @@ pub struct CommandResponse {
     pub payload: Option<Payload>,
     pub error: Option<ProtocolError>,
 }
+
+impl CommandResponse {
+    /// Error response carrying `error`, with its code as HTTP status
+    pub fn from_error(metadata: Metadata, description: &str, error: ProtocolError) -> Self {
+        Self {
+            metadata,
+            status: ResponseStatus {
+                code: u16::try_from(error.code).unwrap_or(500),
+                description: description.to_string(),
+            },
+            message: Some(error.message.clone()),
+            payload: None,
+            error: Some(error),
+        }
+    }
+
+    /// The request was refused because the receiver is at capacity.
+    /// Nothing was executed, so the caller may retry later.
+    pub fn overloaded(metadata: Metadata, reason: impl std::fmt::Display) -> Self {
+        Self::from_error(
+            metadata,
+            "Service Unavailable",
+            ProtocolError::overloaded(reason),
+        )
+    }
+
+    /// The request deadline (`metadata.deadline_ms`) passed without an answer
+    pub fn deadline_exceeded(metadata: Metadata) -> Self {
+        let deadline_ms = metadata.deadline_ms.unwrap_or_default();
+        Self::from_error(
+            metadata,
+            "Gateway Timeout",
+            ProtocolError::deadline_exceeded(deadline_ms),
+        )
+    }
+
+    pub fn is_overloaded(&self) -> bool {
+        self.error
+            .as_ref()
+            .is_some_and(ProtocolError::is_overloaded)
+    }
+}
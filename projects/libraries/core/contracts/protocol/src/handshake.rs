@@ -0,0 +1,66 @@
+// projects/libraries/protocol/src/handshake.rs
+use serde::{Deserialize, Serialize};
+
+use crate::{ProtocolError, SchemaVersionRange};
+
+/// Payload type carried by a handshake command.
+pub const HANDSHAKE_PAYLOAD_TYPE: &str = "protocol/handshake";
+/// Payload type carried by the handshake reply.
+pub const HANDSHAKE_ACK_PAYLOAD_TYPE: &str = "protocol/handshake_ack";
+
+/// Versions and payload types a peer (engine or product backend) understands.
+/// Sent when a connection opens; the receiver answers with a `HandshakeAck`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Handshake {
+    pub schema_versions: SchemaVersionRange,
+    /// Registered `payload_type`s (informational, used for diagnostics).
+    #[serde(default)]
+    pub payload_types: Vec<String>,
+}
+
+/// Outcome of a successful negotiation: the version both peers must use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandshakeAck {
+    pub schema_version: u32,
+    pub schema_versions: SchemaVersionRange,
+}
+
+impl Handshake {
+    pub fn new(schema_versions: SchemaVersionRange) -> Self {
+        Self {
+            schema_versions,
+            payload_types: Vec::new(),
+        }
+    }
+
+    /// Handshake for this build of the protocol crate.
+    pub fn current() -> Self {
+        Self::new(SchemaVersionRange::current())
+    }
+
+    pub fn with_payload_types(mut self, payload_types: Vec<String>) -> Self {
+        self.payload_types = payload_types;
+        self
+    }
+
+    /// Negotiate with a remote handshake: highest version both sides support.
+    pub fn negotiate(&self, remote: &Handshake) -> Result<HandshakeAck, ProtocolError> {
+        let schema_version = self
+            .schema_versions
+            .negotiate(&remote.schema_versions)
+            .ok_or_else(|| {
+                ProtocolError::no_common_version(self.schema_versions, remote.schema_versions)
+            })?;
+
+        Ok(HandshakeAck {
+            schema_version,
+            schema_versions: self.schema_versions,
+        })
+    }
+}
+
+impl Default for Handshake {
+    fn default() -> Self {
+        Self::current()
+    }
+}
@@ -7,34 +7,51 @@ pub mod command_type;
 pub mod event;
 pub mod event_type;
 pub mod event_variant;
+pub mod handshake;
 pub mod log_level;
 pub mod metadatas;
 pub mod payload;
+pub mod payload_field_type;
+pub mod payload_registry;
+pub mod payload_schema;
 pub mod preview_request;
 pub mod preview_response;
 pub mod protocol_error;
 pub mod protocol_id;
 pub mod response_status;
 pub mod run_request;
+pub mod schema_version_range;
+pub mod streaming;
 pub mod validation_error;
 
 pub use apply_request::ApplyRequest;
 pub use apply_response::ApplyResponse;
-pub use command::Command;
+pub use command::{Command, REQUEST_CANCEL_ACTION};
 pub use command_response::CommandResponse;
 pub use command_type::CommandType;
 pub use event::Event;
 pub use event_type::EventType;
 pub use event_variant::EventVariant;
+pub use handshake::{HANDSHAKE_ACK_PAYLOAD_TYPE, HANDSHAKE_PAYLOAD_TYPE, Handshake, HandshakeAck};
 pub use log_level::LogLevel;
 pub use metadatas::*;
 pub use payload::Payload;
+pub use payload_field_type::PayloadFieldType;
+pub use payload_registry::PayloadRegistry;
+pub use payload_schema::PayloadSchema;
 pub use preview_request::PreviewRequest;
 pub use preview_response::PreviewResponse;
 pub use protocol_error::ProtocolError;
 pub use protocol_id::ProtocolId;
 pub use response_status::ResponseStatus;
 pub use run_request::RunRequest;
+pub use schema_version_range::{
+    MIN_SUPPORTED_SCHEMA_VERSION, PROTOCOL_SCHEMA_VERSION, SchemaVersionRange,
+};
+pub use streaming::{
+    Frame, FrameCodec, FrameError, FrameKind, STREAM_CANCEL_ACTION, StreamControl, StreamEvent,
+    StreamReceiver, StreamSender,
+};
 pub use validation_error::ValidationError;
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 // projects/libraries/protocol/src/metadata.rs
 use crate::protocol_id::ProtocolId;
 use crate::validation_error::ValidationError;
+use crate::{PROTOCOL_SCHEMA_VERSION, ProtocolError, SchemaVersionRange};
 use common::Id128;
 use common_time::timestamp_utils::Timestamp;
 use common_time::timestamp_utils::current_timestamp_ms;
@@ -29,6 +30,11 @@ pub struct Metadata {
 
     // Compatibility
     pub schema_version: Option<u32>,
+
+    // Flow control: absolute deadline (ms since UNIX epoch) after which the
+    // caller no longer waits for an answer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadline_ms: Option<Timestamp>,
 }
 
 impl Metadata {
@@ -129,6 +135,53 @@ impl Metadata {
     pub fn to_key(&self) -> String {
         self.request_id.to_string()
     }
+
+    /// Sets the schema version this message is encoded with
+    pub fn with_schema_version(mut self, schema_version: u32) -> Self {
+        self.schema_version = Some(schema_version);
+        self
+    }
+
+    /// Sets the absolute deadline (ms since UNIX epoch) of the request
+    pub fn with_deadline_ms(mut self, deadline_ms: Timestamp) -> Self {
+        self.deadline_ms = Some(deadline_ms);
+        self
+    }
+
+    /// Sets the deadline `timeout_ms` from now
+    pub fn with_timeout_ms(self, timeout_ms: u64) -> Self {
+        self.with_deadline_ms(Self::current_timestamp_ms().saturating_add(timeout_ms))
+    }
+
+    /// Time left before the deadline at `now_ms`; `None` without a deadline,
+    /// `Some(0)` once it has passed.
+    pub fn remaining_ms(&self, now_ms: Timestamp) -> Option<u64> {
+        self.deadline_ms
+            .map(|deadline_ms| deadline_ms.saturating_sub(now_ms))
+    }
+
+    /// Whether the deadline has passed at `now_ms`
+    pub fn is_expired_at(&self, now_ms: Timestamp) -> bool {
+        self.remaining_ms(now_ms) == Some(0)
+    }
+
+    /// Schema version of the message; messages without one predate
+    /// versioning and are treated as the current version.
+    pub fn effective_schema_version(&self) -> u32 {
+        self.schema_version.unwrap_or(PROTOCOL_SCHEMA_VERSION)
+    }
+
+    /// Checks the message schema version against the versions the receiver supports
+    pub fn check_schema_version(
+        &self,
+        supported: &SchemaVersionRange,
+    ) -> Result<u32, ProtocolError> {
+        let version = self.effective_schema_version();
+        if !supported.contains(version) {
+            return Err(ProtocolError::incompatible_version(version, &[*supported]));
+        }
+        Ok(version)
+    }
 }
 
 impl Hash for Metadata {
@@ -139,6 +192,7 @@ impl Hash for Metadata {
         self.client_id.hash(state);
         self.timestamp_ms.hash(state);
         self.schema_version.hash(state);
+        self.deadline_ms.hash(state);
     }
 }
 
@@ -150,6 +204,7 @@ impl PartialEq for Metadata {
             && self.client_id == other.client_id
             && self.timestamp_ms == other.timestamp_ms
             && self.schema_version == other.schema_version
+            && self.deadline_ms == other.deadline_ms
     }
 }
 
@@ -159,13 +214,14 @@ impl fmt::Display for Metadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Metadata {{ request_id: {}, job_id: {:?}, product_id: {:?}, client_id: {:?}, timestamp_ms: {:?}, schema_version: {:?} }}",
+            "Metadata {{ request_id: {}, job_id: {:?}, product_id: {:?}, client_id: {:?}, timestamp_ms: {:?}, schema_version: {:?}, deadline_ms: {:?} }}",
             self.request_id,
             self.job_id,
             self.product_id,
             self.client_id,
             self.timestamp_ms,
-            self.schema_version
+            self.schema_version,
+            self.deadline_ms
         )
     }
 }
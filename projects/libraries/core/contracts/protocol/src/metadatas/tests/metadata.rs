@@ -112,3 +112,40 @@ fn test_metadata_to_key_uses_request_id() {
     };
     assert_eq!(metadata.to_key(), metadata.request_id.to_string());
 }
+
+#[test]
+fn test_metadata_deadline_remaining_and_expiry() {
+    let metadata = Metadata::now().with_deadline_ms(10_000);
+
+    assert_eq!(metadata.remaining_ms(4_000), Some(6_000));
+    assert!(!metadata.is_expired_at(9_999));
+    assert!(metadata.is_expired_at(10_000));
+    assert_eq!(metadata.remaining_ms(12_000), Some(0));
+
+    let without_deadline = Metadata::now();
+    assert_eq!(without_deadline.remaining_ms(12_000), None);
+    assert!(!without_deadline.is_expired_at(u64::MAX));
+}
+
+#[test]
+fn test_metadata_with_timeout_ms_sets_deadline_from_now() {
+    let before = Metadata::current_timestamp_ms();
+    let metadata = Metadata::now().with_timeout_ms(2_000);
+    let deadline_ms = metadata.deadline_ms.expect("deadline set");
+    assert!(deadline_ms >= before + 2_000);
+    assert!(deadline_ms <= Metadata::current_timestamp_ms() + 2_000);
+}
+
+#[test]
+fn test_metadata_deadline_is_optional_on_the_wire() {
+    let metadata = Metadata::with_timestamp(1_000);
+    let json = common_json::to_string(&metadata).expect("serialize");
+    assert!(!json.contains("deadline_ms"));
+    let decoded: Metadata = common_json::from_json_str(&json).expect("deserialize");
+    assert_eq!(decoded.deadline_ms, None);
+
+    let metadata = metadata.with_deadline_ms(5_000);
+    let json = common_json::to_string(&metadata).expect("serialize");
+    let decoded: Metadata = common_json::from_json_str(&json).expect("deserialize");
+    assert_eq!(decoded, metadata);
+}
@@ -0,0 +1,45 @@
+// projects/libraries/protocol/src/payload_field_type.rs
+use common_json::Json;
+use serde::{Deserialize, Serialize};
+
+/// JSON type expected for one property of a `PayloadSchema`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadFieldType {
+    String,
+    Number,
+    /// A number without fractional part
+    Integer,
+    Boolean,
+    Object,
+    Array,
+    Any,
+}
+
+impl PayloadFieldType {
+    pub fn matches(&self, value: &Json) -> bool {
+        match self {
+            Self::String => matches!(value, Json::String(_)),
+            Self::Number => matches!(value, Json::Number(_)),
+            Self::Integer => {
+                matches!(value, Json::Number(n) if n.as_f64().fract() == 0.0)
+            }
+            Self::Boolean => matches!(value, Json::Bool(_)),
+            Self::Object => matches!(value, Json::Object(_)),
+            Self::Array => matches!(value, Json::Array(_)),
+            Self::Any => true,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::Boolean => "boolean",
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Any => "any",
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// projects/libraries/protocol/src/payload_registry.rs
+use std::any::{Any, TypeId, type_name};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use common_json::{Json, from_json};
+use serde::de::DeserializeOwned;
+
+use crate::{Command, Payload, ProtocolError, SchemaVersionRange};
+
+type Decoder = Arc<dyn Fn(&Json) -> Result<Box<dyn Any + Send>, ProtocolError> + Send + Sync>;
+
+struct PayloadEntry {
+    type_id: TypeId,
+    type_name: &'static str,
+    /// schema version -> decoder
+    decoders: BTreeMap<u32, Decoder>,
+}
+
+/// Maps `payload_type` strings to Rust types, with one decoder per schema version.
+///
+/// - `register` decodes every version of a range with serde (same shape across versions).
+/// - `register_decoder` plugs a hand-written decoder for one version, typically to
+///   upgrade an older wire shape into the current type.
+/// - Decoding checks the message schema version and reports a `ProtocolError`
+///   (`CODE_INCOMPATIBLE_VERSION`, `CODE_UNKNOWN_PAYLOAD_TYPE`, `CODE_INVALID_PAYLOAD`).
+#[derive(Default)]
+pub struct PayloadRegistry {
+    entries: HashMap<String, PayloadEntry>,
+}
+
+impl PayloadRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `T` for `payload_type` on every version of `versions`, decoded with serde.
+    pub fn register<T>(
+        &mut self,
+        payload_type: &str,
+        versions: SchemaVersionRange,
+    ) -> Result<(), ProtocolError>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let owned_type = payload_type.to_string();
+        for version in versions.min..=versions.max {
+            let owned_type = owned_type.clone();
+            self.register_decoder::<T, _>(payload_type, version, move |json| {
+                from_json::<T>(json).map_err(|e| ProtocolError::invalid_payload(&owned_type, e))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Register a custom decoder producing `T` for one schema version of `payload_type`.
+    pub fn register_decoder<T, F>(
+        &mut self,
+        payload_type: &str,
+        version: u32,
+        decoder: F,
+    ) -> Result<(), ProtocolError>
+    where
+        T: Send + 'static,
+        F: Fn(&Json) -> Result<T, ProtocolError> + Send + Sync + 'static,
+    {
+        let payload_type = payload_type.trim();
+        if payload_type.is_empty() {
+            return Err(ProtocolError::unknown_payload_type(payload_type));
+        }
+
+        let entry = self
+            .entries
+            .entry(payload_type.to_string())
+            .or_insert_with(|| PayloadEntry {
+                type_id: TypeId::of::<T>(),
+                type_name: type_name::<T>(),
+                decoders: BTreeMap::new(),
+            });
+
+        if entry.type_id != TypeId::of::<T>() {
+            return Err(ProtocolError::invalid_payload(
+                payload_type,
+                format!(
+                    "already registered as {}, cannot register {}",
+                    entry.type_name,
+                    type_name::<T>()
+                ),
+            ));
+        }
+
+        let decoder: Decoder =
+            Arc::new(move |json| decoder(json).map(|v| Box::new(v) as Box<dyn Any + Send>));
+        entry.decoders.insert(version, decoder);
+        Ok(())
+    }
+
+    pub fn contains(&self, payload_type: &str) -> bool {
+        self.entries.contains_key(payload_type)
+    }
+
+    /// Registered payload types, sorted.
+    pub fn payload_types(&self) -> Vec<String> {
+        let mut types: Vec<String> = self.entries.keys().cloned().collect();
+        types.sort();
+        types
+    }
+
+    /// Schema versions with a decoder for `payload_type`, ascending.
+    pub fn supported_versions(&self, payload_type: &str) -> Vec<u32> {
+        self.entries
+            .get(payload_type)
+            .map(|e| e.decoders.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Decode a raw JSON payload registered under `payload_type` for `schema_version`.
+    pub fn decode<T: 'static>(
+        &self,
+        payload_type: &str,
+        schema_version: u32,
+        json: &Json,
+    ) -> Result<T, ProtocolError> {
+        let entry = self
+            .entries
+            .get(payload_type)
+            .ok_or_else(|| ProtocolError::unknown_payload_type(payload_type))?;
+
+        if entry.type_id != TypeId::of::<T>() {
+            return Err(ProtocolError::invalid_payload(
+                payload_type,
+                format!(
+                    "registered as {}, requested {}",
+                    entry.type_name,
+                    type_name::<T>()
+                ),
+            ));
+        }
+
+        let decoder = entry.decoders.get(&schema_version).ok_or_else(|| {
+            ProtocolError::incompatible_version(schema_version, &version_ranges(&entry.decoders))
+        })?;
+
+        decoder(json)?
+            .downcast::<T>()
+            .map(|boxed| *boxed)
+            .map_err(|_| ProtocolError::invalid_payload(payload_type, "decoder type mismatch"))
+    }
+
+    /// Decode a `Payload` envelope (`payload_type` and `payload` are both required).
+    pub fn decode_payload<T: 'static>(
+        &self,
+        payload: &Payload,
+        schema_version: u32,
+    ) -> Result<T, ProtocolError> {
+        let payload_type = payload
+            .payload_type
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .ok_or_else(|| ProtocolError::unknown_payload_type(""))?;
+        let json = payload
+            .payload
+            .as_ref()
+            .ok_or_else(|| ProtocolError::invalid_payload(payload_type, "missing payload"))?;
+        self.decode(payload_type, schema_version, json)
+    }
+
+    /// Decode a command payload using the command schema version
+    /// (`PROTOCOL_SCHEMA_VERSION` when the sender did not set one).
+    pub fn decode_command<T: 'static>(&self, cmd: &Command) -> Result<T, ProtocolError> {
+        let payload = cmd
+            .payload
+            .as_ref()
+            .ok_or_else(|| ProtocolError::unknown_payload_type(""))?;
+        self.decode_payload(payload, cmd.metadata.effective_schema_version())
+    }
+}
+
+/// Collapse a sorted set of versions into contiguous ranges for error messages.
+fn version_ranges(decoders: &BTreeMap<u32, Decoder>) -> Vec<SchemaVersionRange> {
+    let mut ranges: Vec<SchemaVersionRange> = Vec::new();
+    for version in decoders.keys().copied() {
+        match ranges.last_mut() {
+            Some(last) if last.max + 1 == version => last.max = version,
+            _ => ranges.push(SchemaVersionRange::single(version)),
+        }
+    }
+    ranges
+}
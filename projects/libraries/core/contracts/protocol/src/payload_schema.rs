@@ -0,0 +1,134 @@
+// projects/libraries/protocol/src/payload_schema.rs
+use std::collections::BTreeMap;
+
+use common_json::Json;
+use serde::{Deserialize, Serialize};
+
+use crate::{Payload, PayloadFieldType, ProtocolError};
+
+/// Shape of the payload a backend accepts for one action, published at
+/// registration so the engine can reject malformed commands before
+/// forwarding them.
+///
+/// Only the top level of the payload object is checked: required
+/// properties, property types and, unless `additional_properties` is set,
+/// unknown properties.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayloadSchema {
+    /// Expected `payload_type`; any type is accepted when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_type: Option<String>,
+    #[serde(default)]
+    pub required: Vec<String>,
+    #[serde(default)]
+    pub properties: BTreeMap<String, PayloadFieldType>,
+    #[serde(default = "default_additional_properties")]
+    pub additional_properties: bool,
+}
+
+fn default_additional_properties() -> bool {
+    true
+}
+
+impl Default for PayloadSchema {
+    fn default() -> Self {
+        Self {
+            payload_type: None,
+            required: Vec::new(),
+            properties: BTreeMap::new(),
+            additional_properties: true,
+        }
+    }
+}
+
+impl PayloadSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_payload_type(mut self, payload_type: &str) -> Self {
+        self.payload_type = Some(payload_type.to_string());
+        self
+    }
+
+    /// Declares a property that must be present
+    pub fn required(mut self, name: &str, field_type: PayloadFieldType) -> Self {
+        self.required.push(name.to_string());
+        self.properties.insert(name.to_string(), field_type);
+        self
+    }
+
+    /// Declares a property that may be omitted
+    pub fn optional(mut self, name: &str, field_type: PayloadFieldType) -> Self {
+        self.properties.insert(name.to_string(), field_type);
+        self
+    }
+
+    /// Rejects properties that are not declared
+    pub fn closed(mut self) -> Self {
+        self.additional_properties = false;
+        self
+    }
+
+    /// Checks `payload` (possibly absent) against the schema. Errors are
+    /// `CODE_UNKNOWN_PAYLOAD_TYPE` for a wrong `payload_type` and
+    /// `CODE_INVALID_PAYLOAD` for a wrong shape.
+    pub fn validate(&self, payload: Option<&Payload>) -> Result<(), ProtocolError> {
+        let payload_type = payload
+            .and_then(|p| p.payload_type.as_deref())
+            .unwrap_or_default();
+        if let Some(expected) = &self.payload_type
+            && expected != payload_type
+        {
+            return Err(ProtocolError::unknown_payload_type(payload_type));
+        }
+        let label = self.payload_type.as_deref().unwrap_or(payload_type);
+
+        let object = match payload.and_then(|p| p.payload.as_ref()) {
+            Some(Json::Object(object)) => Some(object),
+            None | Some(Json::Null) if self.required.is_empty() => None,
+            None | Some(Json::Null) => {
+                return Err(ProtocolError::invalid_payload(label, "payload is missing"));
+            }
+            Some(_) if self.required.is_empty() && self.properties.is_empty() => None,
+            Some(_) => {
+                return Err(ProtocolError::invalid_payload(label, "expected an object"));
+            }
+        };
+        let Some(object) = object else {
+            return Ok(());
+        };
+
+        if let Some(missing) = self
+            .required
+            .iter()
+            .find(|name| !object.contains_key(*name))
+        {
+            return Err(ProtocolError::invalid_payload(
+                label,
+                format!("missing property '{missing}'"),
+            ));
+        }
+
+        let mut names: Vec<&String> = object.keys().collect();
+        names.sort();
+        for name in names {
+            match self.properties.get(name) {
+                Some(field_type) if !field_type.matches(&object[name]) => {
+                    return Err(ProtocolError::invalid_payload(
+                        label,
+                        format!("property '{name}' must be {}", field_type.as_str()),
+                    ));
+                }
+                None if !self.additional_properties => {
+                    return Err(ProtocolError::invalid_payload(
+                        label,
+                        format!("unknown property '{name}'"),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
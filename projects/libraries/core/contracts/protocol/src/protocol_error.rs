@@ -1,8 +1,99 @@
 // projects/libraries/protocol/src/protocol_error.rs
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+use crate::SchemaVersionRange;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct ProtocolError {
     pub code: i32,
     pub message: String,
 }
+
+impl ProtocolError {
+    /// Payload does not match the registered shape
+    pub const CODE_INVALID_PAYLOAD: i32 = 422;
+    /// `payload_type` missing or not registered
+    pub const CODE_UNKNOWN_PAYLOAD_TYPE: i32 = 415;
+    /// No schema version in common between both peers
+    pub const CODE_INCOMPATIBLE_VERSION: i32 = 426;
+    /// Receiver queues are full; the request was not accepted
+    pub const CODE_OVERLOADED: i32 = 503;
+    /// The request deadline passed before an answer was available
+    pub const CODE_DEADLINE_EXCEEDED: i32 = 504;
+
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// A message (or peer) uses a schema version outside of what is supported.
+    pub fn incompatible_version(requested: u32, supported: &[SchemaVersionRange]) -> Self {
+        let supported = supported
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        Self::new(
+            Self::CODE_INCOMPATIBLE_VERSION,
+            format!("Incompatible schema version {requested} (supported: {supported})"),
+        )
+    }
+
+    /// Two peers advertise disjoint schema version ranges.
+    pub fn no_common_version(local: SchemaVersionRange, remote: SchemaVersionRange) -> Self {
+        Self::new(
+            Self::CODE_INCOMPATIBLE_VERSION,
+            format!("No common schema version (local: {local}, remote: {remote})"),
+        )
+    }
+
+    pub fn unknown_payload_type(payload_type: &str) -> Self {
+        Self::new(
+            Self::CODE_UNKNOWN_PAYLOAD_TYPE,
+            format!("Unknown payload type '{payload_type}'"),
+        )
+    }
+
+    pub fn invalid_payload(payload_type: &str, reason: impl fmt::Display) -> Self {
+        Self::new(
+            Self::CODE_INVALID_PAYLOAD,
+            format!("Invalid '{payload_type}' payload: {reason}"),
+        )
+    }
+
+    /// The receiver refused the request because it is at capacity; the
+    /// caller may retry later.
+    pub fn overloaded(reason: impl fmt::Display) -> Self {
+        Self::new(Self::CODE_OVERLOADED, format!("Overloaded: {reason}"))
+    }
+
+    pub fn deadline_exceeded(deadline_ms: u64) -> Self {
+        Self::new(
+            Self::CODE_DEADLINE_EXCEEDED,
+            format!("Deadline {deadline_ms} exceeded"),
+        )
+    }
+
+    pub fn is_overloaded(&self) -> bool {
+        self.code == Self::CODE_OVERLOADED
+    }
+
+    pub fn is_deadline_exceeded(&self) -> bool {
+        self.code == Self::CODE_DEADLINE_EXCEEDED
+    }
+
+    pub fn is_incompatible_version(&self) -> bool {
+        self.code == Self::CODE_INCOMPATIBLE_VERSION
+    }
+}
+
+impl fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "protocol error {}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for ProtocolError {}
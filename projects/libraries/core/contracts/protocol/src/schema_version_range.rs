@@ -0,0 +1,65 @@
+// projects/libraries/protocol/src/schema_version_range.rs
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Current protocol schema version spoken by this build.
+pub const PROTOCOL_SCHEMA_VERSION: u32 = 1;
+/// Oldest schema version this build still understands.
+pub const MIN_SUPPORTED_SCHEMA_VERSION: u32 = 1;
+
+/// Inclusive range of schema versions a peer understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SchemaVersionRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl SchemaVersionRange {
+    /// Range with `min <= max` (bounds are swapped otherwise).
+    pub fn new(min: u32, max: u32) -> Self {
+        Self {
+            min: min.min(max),
+            max: min.max(max),
+        }
+    }
+
+    pub fn single(version: u32) -> Self {
+        Self::new(version, version)
+    }
+
+    /// Versions supported by this build of the protocol crate.
+    pub fn current() -> Self {
+        Self::new(MIN_SUPPORTED_SCHEMA_VERSION, PROTOCOL_SCHEMA_VERSION)
+    }
+
+    pub fn contains(&self, version: u32) -> bool {
+        self.min <= version && version <= self.max
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        (min <= max).then_some(Self { min, max })
+    }
+
+    /// Highest version both ranges support.
+    pub fn negotiate(&self, other: &Self) -> Option<u32> {
+        self.intersect(other).map(|range| range.max)
+    }
+}
+
+impl Default for SchemaVersionRange {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+impl fmt::Display for SchemaVersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.min == self.max {
+            write!(f, "v{}", self.min)
+        } else {
+            write!(f, "v{}..=v{}", self.min, self.max)
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// projects/libraries/protocol/src/streaming/frame.rs
+use common_json::{from_json_str, to_string};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::streaming::{FrameError, FrameKind, StreamControl};
+use crate::{Command, ProtocolId};
+
+/// One unit on a framed connection, correlated by `Metadata::request_id`.
+///
+/// `sequence` orders the chunks of a stream (`Chunk` and `End` frames);
+/// it is 0 for `Json` and `Control` frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub request_id: ProtocolId,
+    pub sequence: u64,
+    pub kind: FrameKind,
+}
+
+impl Frame {
+    pub fn new(request_id: ProtocolId, sequence: u64, kind: FrameKind) -> Self {
+        Self {
+            request_id,
+            sequence,
+            kind,
+        }
+    }
+
+    /// Wrap a JSON protocol message.
+    pub fn json<T: Serialize>(request_id: ProtocolId, message: &T) -> Result<Self, FrameError> {
+        let json = to_string(message).map_err(|e| FrameError::Json(e.to_string()))?;
+        Ok(Self::new(request_id, 0, FrameKind::Json(json.into_bytes())))
+    }
+
+    /// Wrap a command, correlated by its own request id.
+    pub fn command(cmd: &Command) -> Result<Self, FrameError> {
+        Self::json(cmd.metadata.request_id, cmd)
+    }
+
+    pub fn control(request_id: ProtocolId, control: StreamControl) -> Self {
+        Self::new(request_id, 0, FrameKind::Control(control))
+    }
+
+    /// Decode the JSON message of a `Json` frame.
+    pub fn decode_json<T: DeserializeOwned>(&self) -> Result<T, FrameError> {
+        let FrameKind::Json(bytes) = &self.kind else {
+            return Err(FrameError::UnexpectedFrame("expected a json frame"));
+        };
+        let text = std::str::from_utf8(bytes).map_err(|e| FrameError::Json(e.to_string()))?;
+        from_json_str(text).map_err(|e| FrameError::Json(e.to_string()))
+    }
+
+    pub fn is_end_of_stream(&self) -> bool {
+        matches!(self.kind, FrameKind::End { .. })
+    }
+}
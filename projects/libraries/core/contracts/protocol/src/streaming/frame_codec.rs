@@ -0,0 +1,137 @@
+// projects/libraries/protocol/src/streaming/frame_codec.rs
+use common::Id128;
+use common_binary::{BinaryDecode, BinaryEncode};
+use serde::{Deserialize, Serialize};
+
+use crate::ProtocolId;
+use crate::streaming::{Frame, FrameError, FrameKind};
+
+/// Version of the frame body layout, checked on decode.
+pub const FRAME_FORMAT_VERSION: u8 = 1;
+/// Default upper bound for one frame body (16 MiB).
+pub const DEFAULT_MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+const LEN_PREFIX: usize = 4;
+
+/// Body layout, encoded with `common_binary`.
+/// The request id travels as raw bytes: `ProtocolId` only deserializes from
+/// self-describing formats.
+#[derive(Serialize, Deserialize)]
+struct WireFrame {
+    version: u8,
+    request_id: [u8; 16],
+    sequence: u64,
+    kind: FrameKind,
+}
+
+/// Length-prefixed frame codec: `[u32 LE body length][body]`.
+///
+/// - `encode` produces one self-contained frame.
+/// - `push` + `next_frame` decode incrementally from a byte stream, so partial
+///   reads and several frames per read are both handled.
+/// - Bodies above `max_frame_len` are rejected before being buffered.
+#[derive(Debug)]
+pub struct FrameCodec {
+    max_frame_len: usize,
+    buffer: Vec<u8>,
+}
+
+impl Default for FrameCodec {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FRAME_LEN)
+    }
+}
+
+impl FrameCodec {
+    pub fn new(max_frame_len: usize) -> Self {
+        Self {
+            max_frame_len: max_frame_len.min(u32::MAX as usize),
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Encode a frame with its length prefix.
+    pub fn encode(&self, frame: &Frame) -> Result<Vec<u8>, FrameError> {
+        let wire = WireFrame {
+            version: FRAME_FORMAT_VERSION,
+            request_id: *frame.request_id.as_inner().as_bytes(),
+            sequence: frame.sequence,
+            kind: frame.kind.clone(),
+        };
+
+        let mut out = vec![0u8; LEN_PREFIX];
+        wire.encode_binary(&mut out)
+            .map_err(|e| FrameError::Binary(e.to_string()))?;
+
+        let len = out.len() - LEN_PREFIX;
+        if len > self.max_frame_len {
+            return Err(FrameError::FrameTooLarge {
+                len,
+                max: self.max_frame_len,
+            });
+        }
+        out[..LEN_PREFIX].copy_from_slice(&(len as u32).to_le_bytes());
+        Ok(out)
+    }
+
+    /// Decode one complete frame body (without its length prefix).
+    pub fn decode_body(&self, body: &[u8]) -> Result<Frame, FrameError> {
+        if body.len() > self.max_frame_len {
+            return Err(FrameError::FrameTooLarge {
+                len: body.len(),
+                max: self.max_frame_len,
+            });
+        }
+        let wire = WireFrame::decode_binary(body).map_err(|e| FrameError::Binary(e.to_string()))?;
+        if wire.version != FRAME_FORMAT_VERSION {
+            return Err(FrameError::UnsupportedVersion(wire.version));
+        }
+
+        Ok(Frame {
+            request_id: ProtocolId::new(Id128::from_bytes_unchecked(wire.request_id)),
+            sequence: wire.sequence,
+            kind: wire.kind,
+        })
+    }
+
+    /// Append bytes read from the connection.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Next complete frame, or `None` until more bytes are pushed.
+    /// An oversized length prefix is an error: the connection should be closed.
+    pub fn next_frame(&mut self) -> Result<Option<Frame>, FrameError> {
+        if self.buffer.len() < LEN_PREFIX {
+            return Ok(None);
+        }
+
+        let mut prefix = [0u8; LEN_PREFIX];
+        prefix.copy_from_slice(&self.buffer[..LEN_PREFIX]);
+        let len = u32::from_le_bytes(prefix) as usize;
+        if len > self.max_frame_len {
+            return Err(FrameError::FrameTooLarge {
+                len,
+                max: self.max_frame_len,
+            });
+        }
+
+        let end = LEN_PREFIX + len;
+        if self.buffer.len() < end {
+            return Ok(None);
+        }
+
+        let frame = self.decode_body(&self.buffer[LEN_PREFIX..end]);
+        self.buffer.drain(..end);
+        frame.map(Some)
+    }
+
+    /// Bytes received but not yet decoded.
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+}
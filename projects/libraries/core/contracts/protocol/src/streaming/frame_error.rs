@@ -0,0 +1,37 @@
+// projects/libraries/protocol/src/streaming/frame_error.rs
+use thiserror::Error;
+
+use crate::ProtocolId;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FrameError {
+    #[error("frame of {len} bytes exceeds the {max} bytes limit")]
+    FrameTooLarge { len: usize, max: usize },
+
+    #[error("unsupported frame format version {0}")]
+    UnsupportedVersion(u8),
+
+    #[error("binary frame error: {0}")]
+    Binary(String),
+
+    #[error("json frame error: {0}")]
+    Json(String),
+
+    #[error("unexpected frame: {0}")]
+    UnexpectedFrame(&'static str),
+
+    #[error("frame belongs to another stream: {0}")]
+    UnknownStream(ProtocolId),
+
+    #[error("out of order chunk: expected sequence {expected}, got {actual}")]
+    OutOfOrder { expected: u64, actual: u64 },
+
+    #[error("no credit left, wait for the consumer")]
+    Backpressure,
+
+    #[error("stream cancelled")]
+    Cancelled,
+
+    #[error("stream already ended")]
+    StreamClosed,
+}
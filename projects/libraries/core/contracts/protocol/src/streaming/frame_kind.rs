@@ -0,0 +1,18 @@
+// projects/libraries/protocol/src/streaming/frame_kind.rs
+use serde::{Deserialize, Serialize};
+
+use crate::ProtocolError;
+use crate::streaming::StreamControl;
+
+/// What a frame carries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrameKind {
+    /// A whole JSON protocol message (`Command`, `Event`, `CommandResponse`).
+    Json(Vec<u8>),
+    /// One chunk of a streamed response.
+    Chunk(Vec<u8>),
+    /// Last frame of a stream; `error` is set when the producer failed.
+    End { error: Option<ProtocolError> },
+    /// Flow control sent by the consumer.
+    Control(StreamControl),
+}
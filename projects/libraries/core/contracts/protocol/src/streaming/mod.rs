@@ -0,0 +1,29 @@
+// projects/libraries/protocol/src/streaming/mod.rs
+//! Length-prefixed binary framing and chunked responses.
+//!
+//! JSON messages stay the default transport; frames are used where a single
+//! `CommandResponse` does not fit (log tailing, large artifacts).
+pub mod frame;
+pub mod frame_codec;
+pub mod frame_error;
+pub mod frame_kind;
+pub mod stream_control;
+pub mod stream_event;
+pub mod stream_receiver;
+pub mod stream_sender;
+
+pub use frame::Frame;
+pub use frame_codec::{DEFAULT_MAX_FRAME_LEN, FRAME_FORMAT_VERSION, FrameCodec};
+pub use frame_error::FrameError;
+pub use frame_kind::FrameKind;
+pub use stream_control::StreamControl;
+pub use stream_event::StreamEvent;
+pub use stream_receiver::StreamReceiver;
+pub use stream_sender::StreamSender;
+
+/// Action of the JSON command cancelling a stream; its `metadata.request_id`
+/// is the `request_id` of the stream to cancel.
+pub const STREAM_CANCEL_ACTION: &str = "stream.cancel";
+
+#[cfg(test)]
+mod tests;
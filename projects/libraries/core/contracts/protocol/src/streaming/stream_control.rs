@@ -0,0 +1,17 @@
+// projects/libraries/protocol/src/streaming/stream_control.rs
+use serde::{Deserialize, Serialize};
+
+/// Flow control signals sent by the consumer of a stream.
+///
+/// Backpressure is credit based: the producer may only send as many chunks
+/// as the consumer granted, and stops while paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StreamControl {
+    /// Allow `n` more chunks.
+    Credit(u32),
+    /// Stop sending until `Resume`, whatever the remaining credit.
+    Pause,
+    Resume,
+    /// Abort the stream; the producer sends nothing more.
+    Cancel,
+}
@@ -0,0 +1,12 @@
+// projects/libraries/protocol/src/streaming/stream_event.rs
+use crate::ProtocolError;
+
+/// What a `StreamReceiver` produced from an accepted frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamEvent {
+    Data(Vec<u8>),
+    /// The producer finished successfully.
+    End,
+    /// The producer ended the stream with an error.
+    Failed(ProtocolError),
+}
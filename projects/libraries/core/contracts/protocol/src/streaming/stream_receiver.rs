@@ -0,0 +1,136 @@
+// projects/libraries/protocol/src/streaming/stream_receiver.rs
+use crate::ProtocolId;
+use crate::streaming::{Frame, FrameError, FrameKind, StreamControl, StreamEvent};
+
+/// Consumer side of a chunked response.
+///
+/// Checks chunk order, detects the end of stream and hands credit back as
+/// chunks are consumed, so the producer never has more than `window` chunks
+/// in flight or waiting to be processed.
+#[derive(Debug, Clone)]
+pub struct StreamReceiver {
+    request_id: ProtocolId,
+    window: u32,
+    expected_sequence: u64,
+    /// Chunks received and not yet reported as consumed.
+    pending: u32,
+    /// Chunks consumed whose credit was not yet returned.
+    unacked: u32,
+    finished: bool,
+    cancelled: bool,
+}
+
+impl StreamReceiver {
+    pub fn new(request_id: ProtocolId, window: u32) -> Self {
+        Self {
+            request_id,
+            window: window.max(1),
+            expected_sequence: 0,
+            pending: 0,
+            unacked: 0,
+            finished: false,
+            cancelled: false,
+        }
+    }
+
+    pub fn request_id(&self) -> ProtocolId {
+        self.request_id
+    }
+
+    pub fn window(&self) -> u32 {
+        self.window
+    }
+
+    /// Chunks received but not yet consumed.
+    pub fn pending(&self) -> u32 {
+        self.pending
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Credit frame to send when the stream opens.
+    pub fn initial_credit(&self) -> Frame {
+        Frame::control(self.request_id, StreamControl::Credit(self.window))
+    }
+
+    /// Accept the next frame of the stream.
+    pub fn accept(&mut self, frame: Frame) -> Result<StreamEvent, FrameError> {
+        if frame.request_id != self.request_id {
+            return Err(FrameError::UnknownStream(frame.request_id));
+        }
+        if self.cancelled {
+            return Err(FrameError::Cancelled);
+        }
+        if self.finished {
+            return Err(FrameError::StreamClosed);
+        }
+
+        match frame.kind {
+            FrameKind::Chunk(data) => {
+                self.check_sequence(frame.sequence)?;
+                if self.pending >= self.window {
+                    // The producer ignored its credit.
+                    return Err(FrameError::Backpressure);
+                }
+                self.expected_sequence += 1;
+                self.pending += 1;
+                Ok(StreamEvent::Data(data))
+            }
+            FrameKind::End { error } => {
+                self.check_sequence(frame.sequence)?;
+                self.finished = true;
+                Ok(error.map_or(StreamEvent::End, StreamEvent::Failed))
+            }
+            FrameKind::Json(_) | FrameKind::Control(_) => {
+                Err(FrameError::UnexpectedFrame("expected a chunk or end frame"))
+            }
+        }
+    }
+
+    /// Report `count` chunks as processed. Returns a credit frame once at least
+    /// half of the window was consumed, rather than one control frame per chunk.
+    pub fn consumed(&mut self, count: u32) -> Option<Frame> {
+        let count = count.min(self.pending);
+        self.pending -= count;
+        self.unacked += count;
+
+        if self.finished || self.cancelled || self.unacked == 0 {
+            return None;
+        }
+        if self.unacked < (self.window / 2).max(1) {
+            return None;
+        }
+
+        let credit = std::mem::take(&mut self.unacked);
+        Some(Frame::control(
+            self.request_id,
+            StreamControl::Credit(credit),
+        ))
+    }
+
+    pub fn pause(&self) -> Frame {
+        Frame::control(self.request_id, StreamControl::Pause)
+    }
+
+    pub fn resume(&self) -> Frame {
+        Frame::control(self.request_id, StreamControl::Resume)
+    }
+
+    /// Cancel the stream; later frames for it are rejected.
+    pub fn cancel(&mut self) -> Frame {
+        self.cancelled = true;
+        Frame::control(self.request_id, StreamControl::Cancel)
+    }
+
+    fn check_sequence(&self, sequence: u64) -> Result<(), FrameError> {
+        if sequence != self.expected_sequence {
+            return Err(FrameError::OutOfOrder {
+                expected: self.expected_sequence,
+                actual: sequence,
+            });
+        }
+        Ok(())
+    }
+}
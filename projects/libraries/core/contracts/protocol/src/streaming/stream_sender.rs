@@ -0,0 +1,123 @@
+// projects/libraries/protocol/src/streaming/stream_sender.rs
+use crate::streaming::{Frame, FrameError, FrameKind, StreamControl};
+use crate::{ProtocolError, ProtocolId};
+
+/// Producer side of a chunked response.
+///
+/// Numbers chunks from 0, ends the stream with an `End` frame and enforces the
+/// consumer's flow control (credit, pause, cancel).
+#[derive(Debug, Clone)]
+pub struct StreamSender {
+    request_id: ProtocolId,
+    next_sequence: u64,
+    credit: u64,
+    paused: bool,
+    cancelled: bool,
+    finished: bool,
+}
+
+impl StreamSender {
+    /// `initial_credit`: chunks allowed before the consumer grants more.
+    pub fn new(request_id: ProtocolId, initial_credit: u32) -> Self {
+        Self {
+            request_id,
+            next_sequence: 0,
+            credit: u64::from(initial_credit),
+            paused: false,
+            cancelled: false,
+            finished: false,
+        }
+    }
+
+    pub fn request_id(&self) -> ProtocolId {
+        self.request_id
+    }
+
+    /// Sequence number of the next chunk (also the number of chunks sent).
+    pub fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    pub fn credit(&self) -> u64 {
+        self.credit
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Can a chunk be sent right now?
+    pub fn can_send(&self) -> bool {
+        !self.finished && !self.cancelled && !self.paused && self.credit > 0
+    }
+
+    pub fn apply_control(&mut self, control: StreamControl) {
+        match control {
+            StreamControl::Credit(n) => self.credit = self.credit.saturating_add(u64::from(n)),
+            StreamControl::Pause => self.paused = true,
+            StreamControl::Resume => self.paused = false,
+            StreamControl::Cancel => self.cancelled = true,
+        }
+    }
+
+    /// Apply a control frame received from the consumer.
+    pub fn apply(&mut self, frame: &Frame) -> Result<(), FrameError> {
+        if frame.request_id != self.request_id {
+            return Err(FrameError::UnknownStream(frame.request_id));
+        }
+        match frame.kind {
+            FrameKind::Control(control) => {
+                self.apply_control(control);
+                Ok(())
+            }
+            _ => Err(FrameError::UnexpectedFrame("expected a control frame")),
+        }
+    }
+
+    /// Build the next chunk frame, consuming one credit.
+    pub fn chunk(&mut self, data: Vec<u8>) -> Result<Frame, FrameError> {
+        self.check_open()?;
+        if self.paused || self.credit == 0 {
+            return Err(FrameError::Backpressure);
+        }
+
+        self.credit -= 1;
+        let frame = Frame::new(self.request_id, self.next_sequence, FrameKind::Chunk(data));
+        self.next_sequence += 1;
+        Ok(frame)
+    }
+
+    /// End the stream successfully. Not subject to credit.
+    pub fn finish(&mut self) -> Result<Frame, FrameError> {
+        self.end(None)
+    }
+
+    /// End the stream with an error. Not subject to credit.
+    pub fn fail(&mut self, error: ProtocolError) -> Result<Frame, FrameError> {
+        self.end(Some(error))
+    }
+
+    fn end(&mut self, error: Option<ProtocolError>) -> Result<Frame, FrameError> {
+        self.check_open()?;
+        self.finished = true;
+        Ok(Frame::new(
+            self.request_id,
+            self.next_sequence,
+            FrameKind::End { error },
+        ))
+    }
+
+    fn check_open(&self) -> Result<(), FrameError> {
+        if self.cancelled {
+            return Err(FrameError::Cancelled);
+        }
+        if self.finished {
+            return Err(FrameError::StreamClosed);
+        }
+        Ok(())
+    }
+}
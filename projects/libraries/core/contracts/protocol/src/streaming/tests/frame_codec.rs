@@ -0,0 +1,103 @@
+// projects/libraries/protocol/src/streaming/tests/frame_codec.rs
+use crate::{
+    Command, CommandType, Frame, FrameCodec, FrameError, FrameKind, Metadata, ProtocolError,
+    StreamControl,
+};
+
+fn sample_frames() -> Vec<Frame> {
+    let id = Metadata::now().request_id;
+    vec![
+        Frame::new(id, 0, FrameKind::Chunk(b"line 1\n".to_vec())),
+        Frame::new(id, 1, FrameKind::Chunk(Vec::new())),
+        Frame::control(id, StreamControl::Credit(8)),
+        Frame::new(
+            id,
+            2,
+            FrameKind::End {
+                error: Some(ProtocolError::new(500, "boom")),
+            },
+        ),
+    ]
+}
+
+#[test]
+fn test_encode_decode_round_trip() {
+    let mut codec = FrameCodec::default();
+    for frame in sample_frames() {
+        let bytes = codec.encode(&frame).expect("encode");
+        codec.push(&bytes);
+        assert_eq!(codec.next_frame().expect("decode"), Some(frame));
+    }
+    assert_eq!(codec.buffered_len(), 0);
+}
+
+#[test]
+fn test_incremental_decoding_handles_split_and_merged_reads() {
+    let frames = sample_frames();
+    let mut codec = FrameCodec::default();
+    let wire: Vec<u8> = frames
+        .iter()
+        .flat_map(|f| codec.encode(f).expect("encode"))
+        .collect();
+
+    let mut decoded = Vec::new();
+    for byte in wire.chunks(3) {
+        codec.push(byte);
+        while let Some(frame) = codec.next_frame().expect("decode") {
+            decoded.push(frame);
+        }
+    }
+    assert_eq!(decoded, frames);
+}
+
+#[test]
+fn test_json_frame_carries_command() {
+    let cmd = Command {
+        metadata: Metadata::now(),
+        command_type: CommandType::Query,
+        action: Some("engine.ping".to_string()),
+        payload: None,
+    };
+    let codec = FrameCodec::default();
+    let bytes = codec
+        .encode(&Frame::command(&cmd).expect("frame"))
+        .expect("encode");
+
+    let frame = codec.decode_body(&bytes[4..]).expect("decode");
+    assert_eq!(frame.request_id, cmd.metadata.request_id);
+    let back: Command = frame.decode_json().expect("json");
+    assert_eq!(back.action.as_deref(), Some("engine.ping"));
+}
+
+#[test]
+fn test_oversized_frames_are_rejected() {
+    let codec = FrameCodec::new(16);
+    let frame = Frame::new(Metadata::now().request_id, 0, FrameKind::Chunk(vec![0; 64]));
+    assert!(matches!(
+        codec.encode(&frame),
+        Err(FrameError::FrameTooLarge { max: 16, .. })
+    ));
+
+    let mut codec = FrameCodec::new(16);
+    codec.push(&1024u32.to_le_bytes());
+    assert!(matches!(
+        codec.next_frame(),
+        Err(FrameError::FrameTooLarge { len: 1024, .. })
+    ));
+}
+
+#[test]
+fn test_unknown_format_version_is_rejected() {
+    let codec = FrameCodec::default();
+    let mut bytes = codec
+        .encode(&Frame::control(
+            Metadata::now().request_id,
+            StreamControl::Pause,
+        ))
+        .expect("encode");
+    bytes[4] = 99;
+    assert_eq!(
+        codec.decode_body(&bytes[4..]),
+        Err(FrameError::UnsupportedVersion(99))
+    );
+}
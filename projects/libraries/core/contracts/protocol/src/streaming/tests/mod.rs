@@ -0,0 +1,3 @@
+// projects/libraries/protocol/src/streaming/tests/mod.rs
+mod frame_codec;
+mod stream;
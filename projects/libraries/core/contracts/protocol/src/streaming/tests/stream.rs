@@ -0,0 +1,104 @@
+// projects/libraries/protocol/src/streaming/tests/stream.rs
+use crate::{
+    Command, FrameError, Metadata, ProtocolError, StreamEvent, StreamReceiver, StreamSender,
+};
+
+#[test]
+fn test_stream_round_trip_with_credit() {
+    let id = Metadata::now().request_id;
+    let mut receiver = StreamReceiver::new(id, 4);
+    let mut sender = StreamSender::new(id, 0);
+    sender.apply(&receiver.initial_credit()).expect("credit");
+
+    let mut received = Vec::new();
+    for i in 0..10u8 {
+        if !sender.can_send() {
+            // Consumer processes what it has and hands credit back.
+            let pending = receiver.pending();
+            let credit = receiver.consumed(pending).expect("credit frame");
+            sender.apply(&credit).expect("credit");
+        }
+        let frame = sender.chunk(vec![i]).expect("chunk");
+        match receiver.accept(frame).expect("accept") {
+            StreamEvent::Data(data) => received.extend(data),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    let end = sender.finish().expect("end");
+    assert_eq!(end.sequence, 10);
+    assert_eq!(receiver.accept(end).expect("accept"), StreamEvent::End);
+    assert!(receiver.is_finished());
+    assert_eq!(received, (0..10).collect::<Vec<u8>>());
+}
+
+#[test]
+fn test_sender_respects_backpressure_and_pause() {
+    let id = Metadata::now().request_id;
+    let receiver = StreamReceiver::new(id, 2);
+    let mut sender = StreamSender::new(id, 1);
+
+    sender.chunk(vec![1]).expect("first chunk");
+    assert_eq!(sender.chunk(vec![2]), Err(FrameError::Backpressure));
+
+    sender.apply(&receiver.pause()).expect("pause");
+    sender.apply(&receiver.initial_credit()).expect("credit");
+    assert!(!sender.can_send());
+    sender.apply(&receiver.resume()).expect("resume");
+    assert!(sender.can_send());
+}
+
+#[test]
+fn test_receiver_rejects_out_of_order_and_foreign_frames() {
+    let id = Metadata::now().request_id;
+    let mut receiver = StreamReceiver::new(id, 8);
+    let mut sender = StreamSender::new(id, 8);
+
+    let _lost = sender.chunk(vec![0]).expect("chunk");
+    let second = sender.chunk(vec![1]).expect("chunk");
+    assert_eq!(
+        receiver.accept(second),
+        Err(FrameError::OutOfOrder {
+            expected: 0,
+            actual: 1
+        })
+    );
+
+    let other = Metadata::now().request_id;
+    let mut foreign = StreamSender::new(other, 1);
+    assert!(matches!(
+        receiver.accept(foreign.chunk(vec![0]).expect("chunk")),
+        Err(FrameError::UnknownStream(_))
+    ));
+}
+
+#[test]
+fn test_failed_stream_and_cancel() {
+    let id = Metadata::now().request_id;
+    let mut receiver = StreamReceiver::new(id, 1);
+    let mut sender = StreamSender::new(id, 1);
+
+    let end = sender
+        .fail(ProtocolError::new(500, "disk full"))
+        .expect("end");
+    assert!(end.is_end_of_stream());
+    assert_eq!(
+        receiver.accept(end).expect("accept"),
+        StreamEvent::Failed(ProtocolError::new(500, "disk full"))
+    );
+    assert_eq!(sender.finish(), Err(FrameError::StreamClosed));
+
+    let mut sender = StreamSender::new(id, 4);
+    let mut receiver = StreamReceiver::new(id, 4);
+    sender.apply(&receiver.cancel()).expect("cancel");
+    assert!(sender.is_cancelled());
+    assert_eq!(sender.chunk(vec![0]), Err(FrameError::Cancelled));
+}
+
+#[test]
+fn test_cancel_command_is_correlated_by_request_id() {
+    let id = Metadata::now().request_id;
+    let cmd = Command::cancel_stream(id);
+    assert!(cmd.is_stream_cancel());
+    assert_eq!(cmd.metadata.request_id, id);
+}
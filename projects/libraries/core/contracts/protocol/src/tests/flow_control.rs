@@ -0,0 +1,43 @@
+// projects/libraries/protocol/src/tests/flow_control.rs
+use crate::{
+    Command, CommandResponse, CommandType, Metadata, ProtocolError, ProtocolId,
+    REQUEST_CANCEL_ACTION,
+};
+
+#[test]
+fn test_overloaded_response_is_explicit() {
+    let metadata = Metadata::now();
+    let request_id = metadata.request_id;
+    let response = CommandResponse::overloaded(metadata, "backend queue full");
+
+    assert_eq!(response.status.code, 503);
+    assert_eq!(response.metadata.request_id, request_id);
+    assert!(response.is_overloaded());
+    let error = response.error.expect("error");
+    assert_eq!(error.code, ProtocolError::CODE_OVERLOADED);
+    assert!(error.message.contains("backend queue full"));
+}
+
+#[test]
+fn test_deadline_exceeded_response_reports_deadline() {
+    let response = CommandResponse::deadline_exceeded(Metadata::now().with_deadline_ms(42));
+
+    assert_eq!(response.status.code, 504);
+    assert!(!response.is_overloaded());
+    let error = response.error.expect("error");
+    assert!(error.is_deadline_exceeded());
+    assert!(error.message.contains("42"));
+}
+
+#[test]
+fn test_cancel_request_targets_request_id() {
+    let request_id = ProtocolId::default();
+    let cancel = Command::cancel_request(request_id);
+
+    assert_eq!(cancel.metadata.request_id, request_id);
+    assert!(matches!(cancel.command_type, CommandType::CancelJob));
+    assert_eq!(cancel.action.as_deref(), Some(REQUEST_CANCEL_ACTION));
+    assert!(cancel.is_request_cancel());
+    assert!(!cancel.is_stream_cancel());
+    assert!(!Command::cancel_stream(request_id).is_request_cancel());
+}
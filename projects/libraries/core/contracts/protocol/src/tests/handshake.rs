@@ -0,0 +1,43 @@
+use crate::{Handshake, Metadata, ProtocolError, SchemaVersionRange};
+
+#[test]
+fn test_negotiate_picks_highest_common_version() {
+    let engine = Handshake::new(SchemaVersionRange::new(1, 3));
+    let product = Handshake::new(SchemaVersionRange::new(2, 5));
+    let ack = engine.negotiate(&product).expect("negotiate");
+    assert_eq!(ack.schema_version, 3);
+    assert_eq!(ack.schema_versions, SchemaVersionRange::new(1, 3));
+}
+
+#[test]
+fn test_negotiate_disjoint_ranges_fails() {
+    let engine = Handshake::new(SchemaVersionRange::new(1, 2));
+    let product = Handshake::new(SchemaVersionRange::new(3, 4));
+    let err = engine.negotiate(&product).expect_err("must fail");
+    assert_eq!(err.code, ProtocolError::CODE_INCOMPATIBLE_VERSION);
+}
+
+#[test]
+fn test_metadata_schema_version_check() {
+    let supported = SchemaVersionRange::new(1, 2);
+    assert_eq!(Metadata::default().check_schema_version(&supported), Ok(1));
+    assert_eq!(
+        Metadata::default()
+            .with_schema_version(2)
+            .check_schema_version(&supported),
+        Ok(2)
+    );
+    assert!(
+        Metadata::default()
+            .with_schema_version(9)
+            .check_schema_version(&supported)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_range_constructor_orders_bounds() {
+    assert_eq!(SchemaVersionRange::new(3, 1), SchemaVersionRange::new(1, 3));
+    assert_eq!(SchemaVersionRange::new(1, 3).to_string(), "v1..=v3");
+    assert_eq!(SchemaVersionRange::single(2).to_string(), "v2");
+}
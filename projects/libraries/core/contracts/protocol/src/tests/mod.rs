@@ -1,4 +1,8 @@
 // projects/libraries/protocol/src/tests/mod.rs
 mod event;
+mod flow_control;
+mod handshake;
+mod payload_registry;
+mod payload_schema;
 
 pub(crate) mod test_helpers;
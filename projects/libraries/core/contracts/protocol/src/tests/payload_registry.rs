@@ -0,0 +1,112 @@
+use common_json::{Json, JsonAccess, pjson};
+use serde::Deserialize;
+
+use crate::{
+    Command, CommandType, Metadata, Payload, PayloadRegistry, ProtocolError, SchemaVersionRange,
+};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Login {
+    user_id: String,
+    password: String,
+}
+
+fn registry() -> PayloadRegistry {
+    let mut registry = PayloadRegistry::new();
+    registry
+        .register::<Login>("accounts/login", SchemaVersionRange::new(2, 3))
+        .expect("register v2..v3");
+    // v1 used `user` instead of `user_id`
+    registry
+        .register_decoder::<Login, _>("accounts/login", 1, |json: &Json| {
+            let field = |name: &str| {
+                json.get_field(name)
+                    .and_then(|v| v.as_str_strict())
+                    .map(str::to_string)
+                    .map_err(|e| ProtocolError::invalid_payload("accounts/login", e))
+            };
+            Ok(Login {
+                user_id: field("user")?,
+                password: field("password")?,
+            })
+        })
+        .expect("register v1");
+    registry
+}
+
+fn command(schema_version: Option<u32>, payload: Json) -> Command {
+    Command {
+        metadata: Metadata {
+            schema_version,
+            ..Metadata::now()
+        },
+        command_type: CommandType::Execute,
+        action: Some("accounts.login".to_string()),
+        payload: Some(Payload {
+            payload_type: Some("accounts/login".to_string()),
+            payload: Some(payload),
+        }),
+    }
+}
+
+#[test]
+fn test_decode_current_version_with_serde() {
+    let registry = registry();
+    let cmd = command(Some(3), pjson!({ "user_id": "u1", "password": "pw" }));
+    let login: Login = registry.decode_command(&cmd).expect("decode");
+    assert_eq!(login.user_id, "u1");
+}
+
+#[test]
+fn test_decode_legacy_version_with_custom_decoder() {
+    let registry = registry();
+    let cmd = command(Some(1), pjson!({ "user": "u1", "password": "pw" }));
+    let login: Login = registry.decode_command(&cmd).expect("decode");
+    assert_eq!(login.user_id, "u1");
+    assert_eq!(registry.supported_versions("accounts/login"), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_unsupported_version_is_incompatible() {
+    let registry = registry();
+    let cmd = command(Some(7), pjson!({ "user_id": "u1", "password": "pw" }));
+    let err = registry
+        .decode_command::<Login>(&cmd)
+        .expect_err("must fail");
+    assert!(err.is_incompatible_version());
+    assert!(err.message.contains("v1..=v3"), "{}", err.message);
+}
+
+#[test]
+fn test_unknown_payload_type_and_wrong_type() {
+    let registry = registry();
+    let err = registry
+        .decode::<Login>("accounts/unknown", 1, &pjson!({}))
+        .expect_err("must fail");
+    assert_eq!(err.code, ProtocolError::CODE_UNKNOWN_PAYLOAD_TYPE);
+
+    let err = registry
+        .decode::<String>("accounts/login", 2, &pjson!({}))
+        .expect_err("must fail");
+    assert_eq!(err.code, ProtocolError::CODE_INVALID_PAYLOAD);
+}
+
+#[test]
+fn test_invalid_payload_shape() {
+    let registry = registry();
+    let cmd = command(Some(2), pjson!({ "user_id": 5 }));
+    let err = registry
+        .decode_command::<Login>(&cmd)
+        .expect_err("must fail");
+    assert_eq!(err.code, ProtocolError::CODE_INVALID_PAYLOAD);
+}
+
+#[test]
+fn test_conflicting_registration_is_rejected() {
+    let mut registry = registry();
+    assert!(
+        registry
+            .register::<String>("accounts/login", SchemaVersionRange::single(4))
+            .is_err()
+    );
+}
@@ -0,0 +1,91 @@
+// projects/libraries/protocol/src/tests/payload_schema.rs
+use common_json::{Json, pjson};
+
+use crate::{Payload, PayloadFieldType, PayloadSchema, ProtocolError};
+
+fn payload(payload_type: &str, value: Json) -> Payload {
+    Payload {
+        payload_type: Some(payload_type.to_string()),
+        payload: Some(value),
+    }
+}
+
+fn preview_schema() -> PayloadSchema {
+    PayloadSchema::new()
+        .with_payload_type("git_autopilot/preview")
+        .required("repo", PayloadFieldType::String)
+        .optional("depth", PayloadFieldType::Integer)
+        .closed()
+}
+
+#[test]
+fn matching_payload_is_accepted() {
+    let schema = preview_schema();
+    let ok = payload(
+        "git_autopilot/preview",
+        pjson!({ "repo": "crate", "depth": 3 }),
+    );
+    assert!(schema.validate(Some(&ok)).is_ok());
+
+    let without_optional = payload("git_autopilot/preview", pjson!({ "repo": "crate" }));
+    assert!(schema.validate(Some(&without_optional)).is_ok());
+}
+
+#[test]
+fn wrong_payload_type_is_rejected() {
+    let err = preview_schema()
+        .validate(Some(&payload("other/type", pjson!({ "repo": "crate" }))))
+        .unwrap_err();
+    assert_eq!(err.code, ProtocolError::CODE_UNKNOWN_PAYLOAD_TYPE);
+}
+
+#[test]
+fn shape_errors_are_invalid_payload() {
+    let schema = preview_schema();
+    let cases = [
+        (pjson!({}), "missing property 'repo'"),
+        (pjson!({ "repo": 1 }), "property 'repo' must be string"),
+        (
+            pjson!({ "repo": "crate", "depth": 1.5 }),
+            "property 'depth' must be integer",
+        ),
+        (
+            pjson!({ "repo": "crate", "extra": true }),
+            "unknown property 'extra'",
+        ),
+        (pjson!([1, 2]), "expected an object"),
+    ];
+    for (value, reason) in cases {
+        let err = schema
+            .validate(Some(&payload("git_autopilot/preview", value)))
+            .unwrap_err();
+        assert_eq!(err.code, ProtocolError::CODE_INVALID_PAYLOAD);
+        assert!(err.message.contains(reason), "{} / {}", err.message, reason);
+    }
+    assert!(schema.validate(None).is_err());
+}
+
+#[test]
+fn open_schema_accepts_extra_properties_and_any_type() {
+    let schema = PayloadSchema::new().required("id", PayloadFieldType::Any);
+    let value = payload("anything", pjson!({ "id": [1], "extra": null }));
+    assert!(schema.validate(Some(&value)).is_ok());
+    assert!(PayloadSchema::new().validate(None).is_ok());
+}
+
+#[test]
+fn schema_round_trips_with_defaults() {
+    let decoded: PayloadSchema =
+        common_json::from_json_str(r#"{"required":["repo"],"properties":{"repo":"string"}}"#)
+            .expect("deserialize");
+    assert!(decoded.additional_properties);
+    assert_eq!(
+        decoded.properties.get("repo"),
+        Some(&PayloadFieldType::String)
+    );
+
+    let schema = preview_schema();
+    let json = common_json::to_string(&schema).expect("serialize");
+    let back: PayloadSchema = common_json::from_json_str(&json).expect("deserialize");
+    assert_eq!(back, schema);
+}
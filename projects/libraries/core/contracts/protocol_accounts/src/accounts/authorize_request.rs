@@ -0,0 +1,12 @@
+// projects/libraries/core/contracts/protocol_accounts/src/accounts/authorize_request.rs
+use serde::{Deserialize, Serialize};
+
+use protocol::ProtocolId;
+
+/// Ask the accounts product whether `user_id` may perform `action` on `resource`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthorizeRequest {
+    pub user_id: ProtocolId,
+    pub action: String,
+    pub resource: String,
+}
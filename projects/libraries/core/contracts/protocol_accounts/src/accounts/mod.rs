@@ -2,6 +2,7 @@
 pub mod account_status;
 pub mod account_summary;
 pub mod accounts_list_response;
+pub mod authorize_request;
 pub mod create_account_request;
 pub mod login_request;
 pub mod login_response;
@@ -15,6 +16,7 @@ pub mod update_status_request;
 pub use account_status::AccountStatus;
 pub use account_summary::AccountSummary;
 pub use accounts_list_response::AccountsListResponse;
+pub use authorize_request::AuthorizeRequest;
 pub use create_account_request::CreateAccountRequest;
 pub use login_request::LoginRequest;
 pub use login_response::LoginResponse;
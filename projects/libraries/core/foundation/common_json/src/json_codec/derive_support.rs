@@ -0,0 +1,191 @@
+// projects/libraries/common_json/src/json_codec/derive_support.rs
+//! Helpers called by the code generated by `protocol_macros::{ToJson, FromJson}`.
+//! Not a stable API: use the traits instead.
+use crate::json_codec::from_json::type_mismatch;
+use crate::json_error_code::JsonErrorCode;
+
+pub use crate::json_codec::{FromJson, ToJson, ValidationTarget};
+pub use crate::json_error::{JsonError, JsonResult};
+pub use crate::{Json, JsonMap};
+
+pub fn expect_object<'a>(value: &'a Json, type_name: &str) -> JsonResult<&'a JsonMap> {
+    value
+        .as_object()
+        .ok_or_else(|| type_mismatch(&format!("object ({type_name})"), value))
+}
+
+/// Array of exactly `len` items (tuple structs and tuple variants).
+pub fn expect_array<'a>(value: &'a Json, len: usize, type_name: &str) -> JsonResult<&'a [Json]> {
+    let items = value
+        .as_array()
+        .ok_or_else(|| type_mismatch(&format!("array ({type_name})"), value))?;
+    if items.len() != len {
+        return Err(JsonError::new(JsonErrorCode::TypeMismatch).context(format!(
+            "expected {len} elements for {type_name}, found {}",
+            items.len()
+        )));
+    }
+    Ok(items)
+}
+
+/// Decode a field, using `FromJson::from_missing` when it is absent.
+pub fn field<T: FromJson>(object: &JsonMap, key: &str) -> JsonResult<T> {
+    match object.get(key) {
+        Some(value) => T::from_json_value(value).map_err(|e| e.at_field(key)),
+        None => T::from_missing().ok_or_else(|| {
+            JsonError::new(JsonErrorCode::MissingField)
+                .context(format!("missing field `{key}`"))
+                .at_field(key)
+        }),
+    }
+}
+
+/// Decode a field, falling back to `default` when it is absent.
+pub fn field_or_else<T: FromJson>(
+    object: &JsonMap,
+    key: &str,
+    default: impl FnOnce() -> T,
+) -> JsonResult<T> {
+    match object.get(key) {
+        Some(value) => T::from_json_value(value).map_err(|e| e.at_field(key)),
+        None => Ok(default()),
+    }
+}
+
+pub fn element<T: FromJson>(items: &[Json], index: usize) -> JsonResult<T> {
+    T::from_json_value(&items[index]).map_err(|e| e.at_index(index))
+}
+
+pub fn insert_field<T: ToJson + ?Sized>(
+    object: &mut JsonMap,
+    key: &str,
+    value: &T,
+) -> JsonResult<()> {
+    let value = value.to_json_value().map_err(|e| e.at_field(key))?;
+    object.insert(key.to_string(), value);
+    Ok(())
+}
+
+/// Merge the fields of a flattened value into `object`. `null` adds nothing.
+pub fn flatten_into<T: ToJson + ?Sized>(
+    object: &mut JsonMap,
+    field: &str,
+    value: &T,
+) -> JsonResult<()> {
+    match value.to_json_value().map_err(|e| e.at_field(field))? {
+        Json::Object(entries) => {
+            object.extend(entries);
+            Ok(())
+        }
+        Json::Null => Ok(()),
+        other => Err(type_mismatch("object (flatten)", &other).at_field(field)),
+    }
+}
+
+/// Value of the tag field of an internally or adjacently tagged enum.
+pub fn tag<'a>(object: &'a JsonMap, tag: &str) -> JsonResult<&'a str> {
+    match object.get(tag) {
+        Some(Json::String(name)) => Ok(name),
+        Some(other) => Err(type_mismatch("string", other).at_field(tag)),
+        None => Err(JsonError::new(JsonErrorCode::MissingField)
+            .context(format!("missing tag `{tag}`"))
+            .at_field(tag)),
+    }
+}
+
+/// Split an externally tagged enum: `"Unit"` or `{ "Variant": content }`.
+pub fn external_variant<'a>(
+    value: &'a Json,
+    type_name: &str,
+) -> JsonResult<(&'a str, Option<&'a Json>)> {
+    match value {
+        Json::String(name) => Ok((name, None)),
+        Json::Object(entries) if entries.len() == 1 => {
+            let (name, content) = entries.iter().next().expect("one entry");
+            Ok((name, Some(content)))
+        }
+        other => Err(type_mismatch(
+            &format!("string or single key object ({type_name})"),
+            other,
+        )),
+    }
+}
+
+pub fn missing_content(variant: &str) -> JsonError {
+    JsonError::new(JsonErrorCode::ValueIsMissing)
+        .context(format!("variant `{variant}` needs a value"))
+        .at_field(variant)
+}
+
+pub fn unknown_variant(found: &str, expected: &[&str]) -> JsonError {
+    JsonError::new(JsonErrorCode::UnknownVariant).context(format!(
+        "`{found}`, expected one of: {}",
+        expected.join(", ")
+    ))
+}
+
+fn invalid(field: &str, reason: String) -> JsonError {
+    JsonError::new(JsonErrorCode::ValidationFailed)
+        .context(reason)
+        .at_field(field)
+}
+
+pub fn validate_non_empty<T: ValidationTarget + ?Sized>(value: &T, field: &str) -> JsonResult<()> {
+    if value.present() && value.length() == Some(0) {
+        return Err(invalid(field, "must not be empty".to_string()));
+    }
+    Ok(())
+}
+
+pub fn validate_min<T: ValidationTarget + ?Sized>(
+    value: &T,
+    min: f64,
+    field: &str,
+) -> JsonResult<()> {
+    match value.number() {
+        Some(n) if value.present() && n < min => Err(invalid(field, format!("{n} is below {min}"))),
+        _ => Ok(()),
+    }
+}
+
+pub fn validate_max<T: ValidationTarget + ?Sized>(
+    value: &T,
+    max: f64,
+    field: &str,
+) -> JsonResult<()> {
+    match value.number() {
+        Some(n) if value.present() && n > max => Err(invalid(field, format!("{n} is above {max}"))),
+        _ => Ok(()),
+    }
+}
+
+pub fn validate_min_len<T: ValidationTarget + ?Sized>(
+    value: &T,
+    min: usize,
+    field: &str,
+) -> JsonResult<()> {
+    match value.length() {
+        Some(len) if value.present() && len < min => {
+            Err(invalid(field, format!("length {len} is below {min}")))
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn validate_max_len<T: ValidationTarget + ?Sized>(
+    value: &T,
+    max: usize,
+    field: &str,
+) -> JsonResult<()> {
+    match value.length() {
+        Some(len) if value.present() && len > max => {
+            Err(invalid(field, format!("length {len} is above {max}")))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Result of a `validate(with = "path")` function.
+pub fn validate_with(result: Result<(), String>, field: &str) -> JsonResult<()> {
+    result.map_err(|reason| invalid(field, reason))
+}
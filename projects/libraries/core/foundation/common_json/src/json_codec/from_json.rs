@@ -0,0 +1,178 @@
+// projects/libraries/common_json/src/json_codec/from_json.rs
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+use crate::Json;
+use crate::json_error::{JsonError, JsonResult};
+use crate::json_error_code::JsonErrorCode;
+
+/// Conversion from a `Json` value without going through serde.
+///
+/// Derive it with `#[derive(protocol_macros::FromJson)]`. Errors carry the
+/// path of the failing value (`JsonError::path`).
+pub trait FromJson: Sized {
+    fn from_json_value(value: &Json) -> JsonResult<Self>;
+
+    /// Value used when an object field is absent. `None` means the field is required.
+    fn from_missing() -> Option<Self> {
+        None
+    }
+}
+
+pub(crate) fn type_mismatch(expected: &str, found: &Json) -> JsonError {
+    let found = match found {
+        Json::Null => "null",
+        Json::Bool(_) => "bool",
+        Json::Number(_) => "number",
+        Json::String(_) => "string",
+        Json::Array(_) => "array",
+        Json::Object(_) => "object",
+    };
+    JsonError::new(JsonErrorCode::TypeMismatch)
+        .context(format!("expected {expected}, found {found}"))
+}
+
+impl FromJson for Json {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl FromJson for bool {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        value.as_bool().ok_or_else(|| type_mismatch("bool", value))
+    }
+}
+
+impl FromJson for String {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| type_mismatch("string", value))
+    }
+}
+
+impl FromJson for char {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        let s = value.as_str().ok_or_else(|| type_mismatch("char", value))?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(JsonError::new(JsonErrorCode::ExpectedSingleCharacter)),
+        }
+    }
+}
+
+impl FromJson for () {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        if value.is_null() {
+            Ok(())
+        } else {
+            Err(type_mismatch("null", value))
+        }
+    }
+}
+
+macro_rules! impl_from_json_int {
+    ($($ty:ty),*) => {
+        $(
+            impl FromJson for $ty {
+                fn from_json_value(value: &Json) -> JsonResult<Self> {
+                    let Json::Number(number) = value else {
+                        return Err(type_mismatch("integer", value));
+                    };
+                    let n = number.as_f64();
+                    if n.fract() != 0.0 || n < <$ty>::MIN as f64 || n > <$ty>::MAX as f64 {
+                        return Err(JsonError::new(JsonErrorCode::InvalidInteger).context(format!(
+                            "{n} is not a valid {}",
+                            stringify!($ty)
+                        )));
+                    }
+                    Ok(n as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_json_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl FromJson for f64 {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        match value {
+            Json::Number(number) => Ok(number.as_f64()),
+            other => Err(type_mismatch("number", other)),
+        }
+    }
+}
+
+impl FromJson for f32 {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        f64::from_json_value(value).map(|n| n as f32)
+    }
+}
+
+impl<T: FromJson> FromJson for Box<T> {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        T::from_json_value(value).map(Box::new)
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        match value {
+            Json::Null => Ok(None),
+            other => T::from_json_value(other).map(Some),
+        }
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        let items = value
+            .as_array()
+            .ok_or_else(|| type_mismatch("array", value))?;
+        items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| T::from_json_value(item).map_err(|e| e.at_index(i)))
+            .collect()
+    }
+}
+
+impl<T: FromJson, S: BuildHasher + Default> FromJson for HashMap<String, T, S> {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| type_mismatch("object", value))?;
+        object
+            .iter()
+            .map(|(key, v)| {
+                T::from_json_value(v)
+                    .map(|v| (key.clone(), v))
+                    .map_err(|e| e.at_field(key))
+            })
+            .collect()
+    }
+}
+
+impl<T: FromJson> FromJson for BTreeMap<String, T> {
+    fn from_json_value(value: &Json) -> JsonResult<Self> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| type_mismatch("object", value))?;
+        object
+            .iter()
+            .map(|(key, v)| {
+                T::from_json_value(v)
+                    .map(|v| (key.clone(), v))
+                    .map_err(|e| e.at_field(key))
+            })
+            .collect()
+    }
+}
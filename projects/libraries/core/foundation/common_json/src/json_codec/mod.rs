@@ -0,0 +1,13 @@
+// projects/libraries/common_json/src/json_codec/mod.rs
+//! Serde-free conversion traits, derived with `protocol_macros::{ToJson, FromJson}`.
+pub mod derive_support;
+mod from_json;
+mod to_json;
+mod validation_target;
+
+pub use from_json::FromJson;
+pub use to_json::ToJson;
+pub use validation_target::ValidationTarget;
+
+#[cfg(test)]
+mod tests;
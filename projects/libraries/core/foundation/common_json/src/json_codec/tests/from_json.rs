@@ -0,0 +1,46 @@
+// projects/libraries/common_json/src/json_codec/tests/from_json.rs
+use std::collections::BTreeMap;
+
+use crate::json_error_code::JsonErrorCode;
+use crate::{FromJson, Json, ToJson, pjson};
+
+#[test]
+fn test_primitives_round_trip() {
+    let value = vec![Some(1u32), None, Some(3)];
+    let json = value.to_json_value().expect("to json");
+    assert_eq!(json, pjson!([1, null, 3]));
+    assert_eq!(
+        Vec::<Option<u32>>::from_json_value(&json).expect("from json"),
+        value
+    );
+}
+
+#[test]
+fn test_integer_range_is_checked() {
+    let err = u8::from_json_value(&pjson!(300)).expect_err("out of range");
+    assert_eq!(err.code, JsonErrorCode::InvalidInteger);
+    assert!(i32::from_json_value(&pjson!(1.5)).is_err());
+}
+
+#[test]
+fn test_nested_error_path() {
+    let json = pjson!({ "a": [1, 2, "x"] });
+    let err = BTreeMap::<String, Vec<i64>>::from_json_value(&json).expect_err("type mismatch");
+    assert_eq!(err.path.as_deref(), Some("a[2]"));
+    assert!(err.to_string().contains("at `a[2]`"), "{err}");
+}
+
+#[test]
+fn test_option_is_optional_when_missing() {
+    assert_eq!(Option::<String>::from_missing(), Some(None));
+    assert_eq!(String::from_missing(), None);
+    assert_eq!(
+        Option::<String>::from_json_value(&Json::Null).expect("null"),
+        None
+    );
+}
+
+#[test]
+fn test_non_finite_float_is_rejected() {
+    assert!(f64::NAN.to_json_value().is_err());
+}
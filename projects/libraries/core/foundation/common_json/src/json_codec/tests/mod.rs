@@ -0,0 +1,2 @@
+// projects/libraries/common_json/src/json_codec/tests/mod.rs
+mod from_json;
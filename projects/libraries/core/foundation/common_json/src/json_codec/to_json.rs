@@ -0,0 +1,140 @@
+// projects/libraries/common_json/src/json_codec/to_json.rs
+use std::collections::{BTreeMap, HashMap};
+
+use crate::json_error::{JsonError, JsonResult};
+use crate::json_error_code::JsonErrorCode;
+use crate::{Json, JsonNumber};
+
+/// Conversion to a `Json` value without going through serde.
+///
+/// Derive it with `#[derive(protocol_macros::ToJson)]`. The method is not named
+/// `to_json` so it never clashes with the serde based `JsonSerializable`.
+pub trait ToJson {
+    fn to_json_value(&self) -> JsonResult<Json>;
+}
+
+impl ToJson for Json {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        Ok(self.clone())
+    }
+}
+
+impl ToJson for bool {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        Ok(Json::Bool(*self))
+    }
+}
+
+impl ToJson for String {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        Ok(Json::String(self.clone()))
+    }
+}
+
+impl ToJson for str {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        Ok(Json::String(self.to_string()))
+    }
+}
+
+impl ToJson for char {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        Ok(Json::String(self.to_string()))
+    }
+}
+
+impl ToJson for () {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        Ok(Json::Null)
+    }
+}
+
+macro_rules! impl_to_json_int {
+    ($($ty:ty),*) => {
+        $(
+            impl ToJson for $ty {
+                fn to_json_value(&self) -> JsonResult<Json> {
+                    Ok(Json::from(*self))
+                }
+            }
+        )*
+    };
+}
+
+impl_to_json_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_to_json_float {
+    ($($ty:ty),*) => {
+        $(
+            impl ToJson for $ty {
+                fn to_json_value(&self) -> JsonResult<Json> {
+                    JsonNumber::from_f64(f64::from(*self))
+                        .map(Json::Number)
+                        .ok_or_else(|| {
+                            JsonError::new(JsonErrorCode::Serialize)
+                                .context(format!("non finite number {self}"))
+                        })
+                }
+            }
+        )*
+    };
+}
+
+impl_to_json_float!(f32, f64);
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        (**self).to_json_value()
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for Box<T> {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        (**self).to_json_value()
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        match self {
+            Some(value) => value.to_json_value(),
+            None => Ok(Json::Null),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        self.iter()
+            .enumerate()
+            .map(|(i, item)| item.to_json_value().map_err(|e| e.at_index(i)))
+            .collect::<JsonResult<Vec<_>>>()
+            .map(Json::Array)
+    }
+}
+
+impl<T: ToJson, S> ToJson for HashMap<String, T, S> {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        map_to_json(self.iter())
+    }
+}
+
+impl<T: ToJson> ToJson for BTreeMap<String, T> {
+    fn to_json_value(&self) -> JsonResult<Json> {
+        map_to_json(self.iter())
+    }
+}
+
+fn map_to_json<'a, T: ToJson + 'a>(
+    entries: impl Iterator<Item = (&'a String, &'a T)>,
+) -> JsonResult<Json> {
+    entries
+        .map(|(key, value)| {
+            value
+                .to_json_value()
+                .map(|v| (key.clone(), v))
+                .map_err(|e| e.at_field(key))
+        })
+        .collect::<JsonResult<_>>()
+        .map(Json::Object)
+}
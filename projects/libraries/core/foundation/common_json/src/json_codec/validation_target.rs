@@ -0,0 +1,87 @@
+// projects/libraries/common_json/src/json_codec/validation_target.rs
+use std::collections::{BTreeMap, HashMap};
+
+/// What `#[json(validate(...))]` rules can inspect on a field.
+///
+/// - `length` backs `non_empty`, `min_len` and `max_len`.
+/// - `number` backs `min` and `max`.
+/// - Absent optional values (`present() == false`) are never validated.
+pub trait ValidationTarget {
+    fn present(&self) -> bool {
+        true
+    }
+
+    fn length(&self) -> Option<usize> {
+        None
+    }
+
+    fn number(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl ValidationTarget for String {
+    fn length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl<T> ValidationTarget for Vec<T> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K, V, S> ValidationTarget for HashMap<K, V, S> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K, V> ValidationTarget for BTreeMap<K, V> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: ValidationTarget> ValidationTarget for Option<T> {
+    fn present(&self) -> bool {
+        self.as_ref().is_some_and(ValidationTarget::present)
+    }
+
+    fn length(&self) -> Option<usize> {
+        self.as_ref().and_then(ValidationTarget::length)
+    }
+
+    fn number(&self) -> Option<f64> {
+        self.as_ref().and_then(ValidationTarget::number)
+    }
+}
+
+impl<T: ValidationTarget> ValidationTarget for Box<T> {
+    fn present(&self) -> bool {
+        (**self).present()
+    }
+
+    fn length(&self) -> Option<usize> {
+        (**self).length()
+    }
+
+    fn number(&self) -> Option<f64> {
+        (**self).number()
+    }
+}
+
+macro_rules! impl_validation_number {
+    ($($ty:ty),*) => {
+        $(
+            impl ValidationTarget for $ty {
+                fn number(&self) -> Option<f64> {
+                    Some(*self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_validation_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
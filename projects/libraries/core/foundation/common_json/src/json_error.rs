@@ -7,6 +7,8 @@ pub use crate::json_error_code::JsonErrorCode;
 pub struct JsonError {
     pub code: JsonErrorCode,
     pub context: Option<String>,
+    /// Location of the failing value, e.g. `user.emails[2]`.
+    pub path: Option<String>,
     #[serde(skip_serializing)]
     pub source: Option<Box<dyn std::error::Error + Send + Sync>>,
 }
@@ -16,6 +18,7 @@ impl JsonError {
         Self {
             code,
             context: None,
+            path: None,
             source: None,
         }
     }
@@ -30,6 +33,26 @@ impl JsonError {
         self
     }
 
+    /// Prefix the error path with an object field.
+    pub fn at_field(mut self, field: &str) -> Self {
+        self.path = Some(match self.path.take() {
+            None => field.to_string(),
+            Some(rest) if rest.starts_with('[') => format!("{field}{rest}"),
+            Some(rest) => format!("{field}.{rest}"),
+        });
+        self
+    }
+
+    /// Prefix the error path with an array index.
+    pub fn at_index(mut self, index: usize) -> Self {
+        self.path = Some(match self.path.take() {
+            None => format!("[{index}]"),
+            Some(rest) if rest.starts_with('[') => format!("[{index}]{rest}"),
+            Some(rest) => format!("[{index}].{rest}"),
+        });
+        self
+    }
+
     pub fn message(&self) -> &'static str {
         match self.code {
             JsonErrorCode::Serialize => "serialization error",
@@ -49,15 +72,21 @@ impl JsonError {
             JsonErrorCode::FieldNotFound => "field not found",
             JsonErrorCode::ExpectedSingleCharacter => "expected single character",
             JsonErrorCode::ValueIsMissing => "value is missing",
+            JsonErrorCode::UnknownVariant => "unknown variant",
+            JsonErrorCode::ValidationFailed => "validation failed",
         }
     }
 }
 
 impl fmt::Display for JsonError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())?;
+        if let Some(path) = &self.path {
+            write!(f, " at `{path}`")?;
+        }
         match &self.context {
-            Some(ctx) => write!(f, "{}: {}", self.message(), ctx),
-            None => write!(f, "{}", self.message()),
+            Some(ctx) => write!(f, ": {ctx}"),
+            None => Ok(()),
         }
     }
 }
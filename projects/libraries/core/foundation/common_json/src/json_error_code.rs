@@ -18,4 +18,6 @@ pub enum JsonErrorCode {
     FieldNotFound,
     ExpectedSingleCharacter,
     ValueIsMissing,
+    UnknownVariant,
+    ValidationFailed,
 }
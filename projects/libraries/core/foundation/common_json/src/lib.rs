@@ -10,6 +10,7 @@ pub mod json;
 pub mod json_access;
 pub mod json_access_mut;
 pub mod json_array_builder;
+pub mod json_codec;
 pub mod json_comparison;
 pub mod json_error;
 pub mod json_error_code;
@@ -50,6 +51,10 @@ pub use process::parse_json_stdout;
 pub use json::Json;
 pub use json_access::JsonAccess;
 pub use json_access_mut::JsonAccessMut;
+/// Support code for `protocol_macros::{ToJson, FromJson}`; not a public API.
+#[doc(hidden)]
+pub use json_codec::derive_support as __private;
+pub use json_codec::{FromJson, ToJson, ValidationTarget};
 pub use json_comparison::JsonComparison;
 pub use merge_strategy::MergeStrategy;
 pub use parser::parse_str;
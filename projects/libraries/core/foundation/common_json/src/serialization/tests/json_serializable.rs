@@ -35,6 +35,7 @@ mod tests {
                 .map_err(|_| JsonError {
                     code: JsonErrorCode::Io,
                     context: None,
+                    path: None,
                     source: None,
                 })
         }
@@ -44,6 +45,7 @@ mod tests {
             writer.write_all(pretty.as_bytes()).map_err(|_| JsonError {
                 code: JsonErrorCode::Io,
                 context: None,
+                path: None,
                 source: None,
             })
         }
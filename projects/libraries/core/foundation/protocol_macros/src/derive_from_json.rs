@@ -0,0 +1,262 @@
+// projects/libraries/protocol_macros/src/derive_from_json.rs
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident};
+
+use crate::json_attrs::{ContainerAttrs, FieldDefault, parse_container_attrs};
+use crate::json_fields::{
+    JsonField, named_fields, try_suffix, validations, variant_names, with_bound,
+};
+
+pub(crate) fn expand_from_json(input: DeriveInput) -> TokenStream {
+    match try_expand(&input) {
+        Ok(tokens) => tokens,
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+fn try_expand(input: &DeriveInput) -> Result<TokenStream, syn::Error> {
+    let container = parse_container_attrs(&input.attrs)?;
+    let type_name = input.ident.to_string();
+    let body = match &input.data {
+        Data::Struct(data) => struct_body(&data.fields, &container, &type_name)?,
+        Data::Enum(data) => enum_body(data, &container, &type_name)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "FromJson can only be derived for structs and enums",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let generics = with_bound(&input.generics, quote!(::common_json::FromJson));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::common_json::FromJson for #name #ty_generics #where_clause {
+            fn from_json_value(
+                __value: &::common_json::Json,
+            ) -> ::common_json::JsonResult<Self> {
+                #body
+            }
+        }
+    })
+}
+
+fn struct_body(
+    fields: &Fields,
+    container: &ContainerAttrs,
+    type_name: &str,
+) -> Result<TokenStream, syn::Error> {
+    if container.tag.is_some() {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`tag` is only supported on enums",
+        ));
+    }
+
+    Ok(match fields {
+        Fields::Named(named) => {
+            let fields = named_fields(&named.named, container.rename_all)?;
+            let decode = decode_named(&fields, quote!(Self), None);
+            quote! {
+                let __object = ::common_json::__private::expect_object(__value, #type_name)?;
+                #decode
+            }
+        }
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => quote! {
+            ::core::result::Result::Ok(Self(::common_json::FromJson::from_json_value(__value)?))
+        },
+        Fields::Unnamed(unnamed) => {
+            let len = unnamed.unnamed.len();
+            let items = (0..len).map(|i| quote!(::common_json::__private::element(__items, #i)?));
+            quote! {
+                let __items = ::common_json::__private::expect_array(__value, #len, #type_name)?;
+                ::core::result::Result::Ok(Self(#(#items),*))
+            }
+        }
+        Fields::Unit => quote! {
+            <() as ::common_json::FromJson>::from_json_value(__value)?;
+            ::core::result::Result::Ok(Self)
+        },
+    })
+}
+
+fn enum_body(
+    data: &syn::DataEnum,
+    container: &ContainerAttrs,
+    type_name: &str,
+) -> Result<TokenStream, syn::Error> {
+    let names = variant_names(&data.variants, container.rename_all)?;
+
+    let mut arms = Vec::new();
+    for (variant, json_name) in data.variants.iter().zip(&names) {
+        let ident = &variant.ident;
+        let variant_type = format!("{type_name}::{ident}");
+
+        let arm = match (&container.tag, &container.content, &variant.fields) {
+            (_, _, Fields::Unit) => quote! { ::core::result::Result::Ok(Self::#ident) },
+            // { "Variant": content }
+            (None, _, fields) => {
+                let decode = decode_content(ident, fields, &variant_type, Some(json_name))?;
+                quote! {
+                    let __content = __content
+                        .ok_or_else(|| ::common_json::__private::missing_content(#json_name))?;
+                    #decode
+                }
+            }
+            // { "tag": "Variant", "content": content }
+            (Some(_), Some(content_key), fields) => {
+                let decode = decode_content(ident, fields, &variant_type, Some(content_key))?;
+                quote! {
+                    let __content = __object
+                        .get(#content_key)
+                        .ok_or_else(|| ::common_json::__private::missing_content(#content_key))?;
+                    #decode
+                }
+            }
+            // { "tag": "Variant", ...fields }
+            (Some(_), None, Fields::Named(named)) => {
+                let fields = named_fields(&named.named, None)?;
+                decode_named(&fields, quote!(Self::#ident), None)
+            }
+            (Some(_), None, Fields::Unnamed(unnamed)) if unnamed.unnamed.len() == 1 => quote! {
+                ::core::result::Result::Ok(Self::#ident(::common_json::FromJson::from_json_value(__value)?))
+            },
+            (Some(_), None, Fields::Unnamed(_)) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "internally tagged enums only support unit, struct and newtype variants",
+                ));
+            }
+        };
+
+        arms.push(quote! { #json_name => { #arm } });
+    }
+
+    // Unit-only enums never read the content.
+    let all_unit = data
+        .variants
+        .iter()
+        .all(|v| matches!(v.fields, Fields::Unit));
+    let head = match &container.tag {
+        None if all_unit => quote! {
+            let (__name, _) = ::common_json::__private::external_variant(__value, #type_name)?;
+        },
+        None => quote! {
+            let (__name, __content) =
+                ::common_json::__private::external_variant(__value, #type_name)?;
+        },
+        Some(tag) => quote! {
+            let __object = ::common_json::__private::expect_object(__value, #type_name)?;
+            let __name = ::common_json::__private::tag(__object, #tag)?;
+        },
+    };
+
+    Ok(quote! {
+        #head
+        match __name {
+            #(#arms)*
+            __other => ::core::result::Result::Err(
+                ::common_json::__private::unknown_variant(__other, &[#(#names),*]),
+            ),
+        }
+    })
+}
+
+/// Decode a variant from `__content`, nesting errors under `prefix`.
+fn decode_content(
+    ident: &Ident,
+    fields: &Fields,
+    variant_type: &str,
+    prefix: Option<&str>,
+) -> Result<TokenStream, syn::Error> {
+    let try_ = try_suffix(prefix);
+    Ok(match fields {
+        Fields::Unit => quote! { ::core::result::Result::Ok(Self::#ident) },
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => quote! {
+            ::core::result::Result::Ok(Self::#ident(
+                ::common_json::FromJson::from_json_value(__content) #try_
+            ))
+        },
+        Fields::Unnamed(unnamed) => {
+            let len = unnamed.unnamed.len();
+            let items =
+                (0..len).map(|i| quote!(::common_json::__private::element(__items, #i) #try_));
+            quote! {
+                let __items = ::common_json::__private::expect_array(__content, #len, #variant_type) #try_;
+                ::core::result::Result::Ok(Self::#ident(#(#items),*))
+            }
+        }
+        Fields::Named(named) => {
+            let fields = named_fields(&named.named, None)?;
+            let decode = decode_named(&fields, quote!(Self::#ident), prefix);
+            quote! {
+                let __object = ::common_json::__private::expect_object(__content, #variant_type) #try_;
+                #decode
+            }
+        }
+    })
+}
+
+/// Decode named fields from `__object` (flattened fields from `__value`/`__content`)
+/// and build `constructor { .. }`.
+fn decode_named(
+    fields: &[JsonField],
+    constructor: TokenStream,
+    prefix: Option<&str>,
+) -> TokenStream {
+    let try_ = try_suffix(prefix);
+    // Flattened fields read the same object their parent was read from.
+    let source = match prefix {
+        Some(_) => quote!(__content),
+        None => quote!(__value),
+    };
+
+    let decodes = fields.iter().map(|f| {
+        let binding = f.binding();
+        let ty = f.ty;
+        let key = &f.key;
+
+        let default_value = match &f.attrs.default {
+            FieldDefault::Path(path) => quote!(#path()),
+            FieldDefault::Trait | FieldDefault::None => quote!(::core::default::Default::default()),
+        };
+
+        let value = if f.attrs.skip {
+            default_value
+        } else if f.attrs.flatten {
+            quote!(<#ty as ::common_json::FromJson>::from_json_value(#source) #try_)
+        } else if matches!(f.attrs.default, FieldDefault::None) {
+            quote!(::common_json::__private::field::<#ty>(__object, #key) #try_)
+        } else {
+            quote!(::common_json::__private::field_or_else::<#ty>(__object, #key, || #default_value) #try_)
+        };
+
+        let checks = validations(f, prefix);
+        quote! {
+            let #binding: #ty = #value;
+            #checks
+        }
+    });
+
+    let inits = fields.iter().map(|f| {
+        let ident = f.ident;
+        let binding = f.binding();
+        quote!(#ident: #binding)
+    });
+
+    // Avoid an unused variable when every field is skipped or flattened.
+    let object_use = if fields.iter().all(|f| f.attrs.skip || f.attrs.flatten) {
+        quote!(let _ = __object;)
+    } else {
+        quote!()
+    };
+
+    quote! {
+        #object_use
+        #(#decodes)*
+        ::core::result::Result::Ok(#constructor { #(#inits),* })
+    }
+}
@@ -0,0 +1,256 @@
+// projects/libraries/protocol_macros/src/derive_to_json.rs
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Ident};
+
+use crate::json_attrs::{ContainerAttrs, parse_container_attrs};
+use crate::json_fields::{JsonField, named_fields, variant_names, with_bound};
+
+pub(crate) fn expand_to_json(input: DeriveInput) -> TokenStream {
+    match try_expand(&input) {
+        Ok(tokens) => tokens,
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+fn try_expand(input: &DeriveInput) -> Result<TokenStream, syn::Error> {
+    let container = parse_container_attrs(&input.attrs)?;
+    let body = match &input.data {
+        Data::Struct(data) => struct_body(&data.fields, &container)?,
+        Data::Enum(data) => enum_body(data, &container)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "ToJson can only be derived for structs and enums",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    let generics = with_bound(&input.generics, quote!(::common_json::ToJson));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::common_json::ToJson for #name #ty_generics #where_clause {
+            fn to_json_value(&self) -> ::common_json::JsonResult<::common_json::Json> {
+                #body
+            }
+        }
+    })
+}
+
+fn struct_body(fields: &Fields, container: &ContainerAttrs) -> Result<TokenStream, syn::Error> {
+    if container.tag.is_some() {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`tag` is only supported on enums",
+        ));
+    }
+
+    Ok(match fields {
+        Fields::Named(named) => {
+            let fields = named_fields(&named.named, container.rename_all)?;
+            let inserts = insert_fields(&fields, |f| {
+                let ident = f.ident;
+                quote!(&self.#ident)
+            });
+            quote! {
+                let mut __object = ::common_json::JsonMap::new();
+                #inserts
+                ::core::result::Result::Ok(::common_json::Json::Object(__object))
+            }
+        }
+        // Newtype structs are transparent.
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => quote! {
+            ::common_json::ToJson::to_json_value(&self.0)
+        },
+        Fields::Unnamed(unnamed) => {
+            let items = (0..unnamed.unnamed.len()).map(|i| {
+                let index = syn::Index::from(i);
+                quote! {
+                    ::common_json::ToJson::to_json_value(&self.#index).map_err(|__e| __e.at_index(#i))?
+                }
+            });
+            quote! {
+                ::core::result::Result::Ok(::common_json::Json::Array(::std::vec![#(#items),*]))
+            }
+        }
+        Fields::Unit => quote! {
+            ::core::result::Result::Ok(::common_json::Json::Null)
+        },
+    })
+}
+
+fn enum_body(data: &syn::DataEnum, container: &ContainerAttrs) -> Result<TokenStream, syn::Error> {
+    let names = variant_names(&data.variants, container.rename_all)?;
+
+    let mut arms = Vec::new();
+    for (variant, json_name) in data.variants.iter().zip(&names) {
+        let ident = &variant.ident;
+        let (pattern, content) = variant_content(ident, &variant.fields)?;
+
+        let tag_entry = |tag: &str| {
+            quote! {
+                __object.insert(
+                    #tag.to_string(),
+                    ::common_json::Json::String(#json_name.to_string()),
+                );
+            }
+        };
+
+        let body = match (&container.tag, &container.content, &variant.fields) {
+            // "Variant"
+            (None, _, Fields::Unit) => quote! {
+                ::core::result::Result::Ok(::common_json::Json::String(#json_name.to_string()))
+            },
+            // { "Variant": content }
+            (None, _, _) => quote! {
+                let __content = #content?;
+                let mut __object = ::common_json::JsonMap::new();
+                __object.insert(#json_name.to_string(), __content);
+                ::core::result::Result::Ok(::common_json::Json::Object(__object))
+            },
+            // { "tag": "Variant" }
+            (Some(tag), _, Fields::Unit) => {
+                let tag_entry = tag_entry(tag);
+                quote! {
+                    let mut __object = ::common_json::JsonMap::new();
+                    #tag_entry
+                    ::core::result::Result::Ok(::common_json::Json::Object(__object))
+                }
+            }
+            // { "tag": "Variant", "content": content }
+            (Some(tag), Some(content_key), _) => {
+                let tag_entry = tag_entry(tag);
+                quote! {
+                    let __content = #content?;
+                    let mut __object = ::common_json::JsonMap::new();
+                    #tag_entry
+                    __object.insert(#content_key.to_string(), __content);
+                    ::core::result::Result::Ok(::common_json::Json::Object(__object))
+                }
+            }
+            // { "tag": "Variant", ...fields }
+            (Some(tag), None, Fields::Named(named)) => {
+                let tag_entry = tag_entry(tag);
+                let fields = named_fields(&named.named, None)?;
+                let inserts = insert_fields(&fields, |f| {
+                    let binding = f.binding();
+                    quote!(#binding)
+                });
+                quote! {
+                    let mut __object = ::common_json::JsonMap::new();
+                    #inserts
+                    #tag_entry
+                    ::core::result::Result::Ok(::common_json::Json::Object(__object))
+                }
+            }
+            // { "tag": "Variant", ...fields of the inner object }
+            (Some(tag), None, Fields::Unnamed(unnamed)) if unnamed.unnamed.len() == 1 => {
+                let tag_entry = tag_entry(tag);
+                quote! {
+                    let mut __object = ::common_json::JsonMap::new();
+                    ::common_json::__private::flatten_into(&mut __object, #json_name, __field_0)?;
+                    #tag_entry
+                    ::core::result::Result::Ok(::common_json::Json::Object(__object))
+                }
+            }
+            (Some(_), None, Fields::Unnamed(_)) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "internally tagged enums only support unit, struct and newtype variants",
+                ));
+            }
+        };
+
+        arms.push(quote! { #pattern => { #body } });
+    }
+
+    Ok(quote! {
+        match self {
+            #(#arms)*
+        }
+    })
+}
+
+/// Match pattern binding the variant fields, and the expression producing its content.
+fn variant_content(
+    ident: &Ident,
+    fields: &Fields,
+) -> Result<(TokenStream, TokenStream), syn::Error> {
+    Ok(match fields {
+        Fields::Unit => (
+            quote!(Self::#ident),
+            quote!(::core::result::Result::Ok(::common_json::Json::Null)),
+        ),
+        Fields::Unnamed(unnamed) => {
+            let bindings: Vec<Ident> = (0..unnamed.unnamed.len())
+                .map(|i| format_ident!("__field_{}", i))
+                .collect();
+            let content = if bindings.len() == 1 {
+                quote!(::common_json::ToJson::to_json_value(__field_0))
+            } else {
+                let items = bindings.iter().enumerate().map(|(i, b)| {
+                    quote! {
+                        ::common_json::ToJson::to_json_value(#b).map_err(|__e| __e.at_index(#i))?
+                    }
+                });
+                quote! {
+                    ::core::result::Result::<_, ::common_json::JsonError>::Ok(
+                        ::common_json::Json::Array(::std::vec![#(#items),*])
+                    )
+                }
+            };
+            (quote!(Self::#ident(#(#bindings),*)), content)
+        }
+        Fields::Named(named) => {
+            let fields = named_fields(&named.named, None)?;
+            let pattern_fields = fields.iter().filter(|f| !f.attrs.skip).map(|f| {
+                let field_ident = f.ident;
+                let binding = f.binding();
+                quote!(#field_ident: #binding)
+            });
+            let inserts = insert_fields(&fields, |f| {
+                let binding = f.binding();
+                quote!(#binding)
+            });
+            (
+                quote!(Self::#ident { #(#pattern_fields,)* .. }),
+                quote! {
+                    {
+                        let mut __object = ::common_json::JsonMap::new();
+                        #inserts
+                        ::core::result::Result::<_, ::common_json::JsonError>::Ok(
+                            ::common_json::Json::Object(__object)
+                        )
+                    }
+                },
+            )
+        }
+    })
+}
+
+/// Statements inserting `fields` into a local `__object`.
+fn insert_fields(fields: &[JsonField], access: impl Fn(&JsonField) -> TokenStream) -> TokenStream {
+    let statements = fields.iter().filter(|f| !f.attrs.skip).map(|f| {
+        let value = access(f);
+        let key = &f.key;
+        let field_name = f.ident.to_string();
+
+        let insert = if f.attrs.flatten {
+            quote! { ::common_json::__private::flatten_into(&mut __object, #field_name, #value)?; }
+        } else {
+            quote! { ::common_json::__private::insert_field(&mut __object, #key, #value)?; }
+        };
+
+        match &f.attrs.skip_serializing_if {
+            Some(predicate) => quote! {
+                if !#predicate(#value) {
+                    #insert
+                }
+            },
+            None => insert,
+        }
+    });
+    quote! { #(#statements)* }
+}
@@ -0,0 +1,242 @@
+// projects/libraries/protocol_macros/src/json_attrs.rs
+use syn::{Attribute, ExprPath, Ident, LitFloat, LitInt, LitStr, Token};
+
+use crate::enum_utils::to_snake_case;
+
+/// `#[json(rename_all = "...")]` conventions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    Snake,
+    Camel,
+    Pascal,
+    Kebab,
+    ScreamingSnake,
+    Lower,
+    Upper,
+}
+
+impl RenameRule {
+    fn parse(lit: &LitStr) -> Result<Self, syn::Error> {
+        match lit.value().as_str() {
+            "snake_case" => Ok(Self::Snake),
+            "camelCase" => Ok(Self::Camel),
+            "PascalCase" => Ok(Self::Pascal),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "lowercase" => Ok(Self::Lower),
+            "UPPERCASE" => Ok(Self::Upper),
+            other => Err(syn::Error::new_spanned(
+                lit,
+                format!(
+                    "unknown rename_all rule `{other}`, expected one of: snake_case, camelCase, \
+                     PascalCase, kebab-case, SCREAMING_SNAKE_CASE, lowercase, UPPERCASE"
+                ),
+            )),
+        }
+    }
+
+    /// Apply to a field (snake_case) or variant (PascalCase) name.
+    pub(crate) fn apply(self, name: &str) -> String {
+        let snake = to_snake_case(name);
+        let words: Vec<&str> = snake.split('_').filter(|w| !w.is_empty()).collect();
+        let capitalize = |w: &str| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        };
+
+        match self {
+            Self::Snake => words.join("_"),
+            Self::Kebab => words.join("-"),
+            Self::ScreamingSnake => words.join("_").to_ascii_uppercase(),
+            Self::Lower => words.concat(),
+            Self::Upper => words.concat().to_ascii_uppercase(),
+            Self::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| if i == 0 { w.to_string() } else { capitalize(w) })
+                .collect(),
+        }
+    }
+}
+
+/// Attributes on the struct or enum itself.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    pub(crate) rename_all: Option<RenameRule>,
+    /// Internally tagged enum: `{ "<tag>": "Variant", ...fields }`.
+    pub(crate) tag: Option<String>,
+    /// With `tag`, adjacently tagged: `{ "<tag>": "Variant", "<content>": value }`.
+    pub(crate) content: Option<String>,
+}
+
+/// Field level validation rules, checked by `FromJson` after decoding.
+pub(crate) enum Validation {
+    NonEmpty,
+    Min(f64),
+    Max(f64),
+    MinLen(usize),
+    MaxLen(usize),
+    With(ExprPath),
+}
+
+pub(crate) enum FieldDefault {
+    None,
+    Trait,
+    Path(ExprPath),
+}
+
+pub(crate) struct FieldAttrs {
+    pub(crate) rename: Option<String>,
+    pub(crate) default: FieldDefault,
+    pub(crate) skip: bool,
+    pub(crate) skip_serializing_if: Option<ExprPath>,
+    pub(crate) flatten: bool,
+    pub(crate) validations: Vec<Validation>,
+}
+
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    pub(crate) rename: Option<String>,
+}
+
+pub(crate) fn parse_container_attrs(attrs: &[Attribute]) -> Result<ContainerAttrs, syn::Error> {
+    let mut out = ContainerAttrs::default();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("json")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                out.rename_all = Some(RenameRule::parse(&meta.value()?.parse()?)?);
+                Ok(())
+            } else if meta.path.is_ident("tag") {
+                out.tag = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("content") {
+                out.content = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("unknown json attribute, expected `rename_all`, `tag` or `content`"))
+            }
+        })?;
+    }
+
+    if out.content.is_some() && out.tag.is_none() {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`content` requires `tag`",
+        ));
+    }
+    Ok(out)
+}
+
+pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<FieldAttrs, syn::Error> {
+    let mut out = FieldAttrs {
+        rename: None,
+        default: FieldDefault::None,
+        skip: false,
+        skip_serializing_if: None,
+        flatten: false,
+        validations: Vec::new(),
+    };
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("json")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                out.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("default") {
+                out.default = if meta.input.peek(Token![=]) {
+                    FieldDefault::Path(meta.value()?.parse::<LitStr>()?.parse()?)
+                } else {
+                    FieldDefault::Trait
+                };
+            } else if meta.path.is_ident("skip") {
+                out.skip = true;
+            } else if meta.path.is_ident("skip_serializing_if") {
+                out.skip_serializing_if = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else if meta.path.is_ident("flatten") {
+                out.flatten = true;
+            } else if meta.path.is_ident("validate") {
+                meta.parse_nested_meta(|rule| {
+                    let validation = if rule.path.is_ident("non_empty") {
+                        Validation::NonEmpty
+                    } else if rule.path.is_ident("min") {
+                        Validation::Min(parse_number(rule.value()?)?)
+                    } else if rule.path.is_ident("max") {
+                        Validation::Max(parse_number(rule.value()?)?)
+                    } else if rule.path.is_ident("min_len") {
+                        Validation::MinLen(rule.value()?.parse::<LitInt>()?.base10_parse()?)
+                    } else if rule.path.is_ident("max_len") {
+                        Validation::MaxLen(rule.value()?.parse::<LitInt>()?.base10_parse()?)
+                    } else if rule.path.is_ident("with") {
+                        Validation::With(rule.value()?.parse::<LitStr>()?.parse()?)
+                    } else {
+                        return Err(rule.error(
+                            "unknown validation, expected `non_empty`, `min`, `max`, `min_len`, \
+                             `max_len` or `with`",
+                        ));
+                    };
+                    out.validations.push(validation);
+                    Ok(())
+                })?;
+            } else {
+                return Err(meta.error(
+                    "unknown json attribute, expected `rename`, `default`, `skip`, \
+                     `skip_serializing_if`, `flatten` or `validate`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    if out.flatten && (out.rename.is_some() || out.skip) {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`flatten` cannot be combined with `rename` or `skip`",
+        ));
+    }
+    Ok(out)
+}
+
+pub(crate) fn parse_variant_attrs(attrs: &[Attribute]) -> Result<VariantAttrs, syn::Error> {
+    let mut out = VariantAttrs::default();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("json")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                out.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("unknown json attribute on variant, expected `rename`"))
+            }
+        })?;
+    }
+    Ok(out)
+}
+
+/// JSON key of a field or variant: explicit rename, then `rename_all`, then the identifier.
+pub(crate) fn json_name(ident: &Ident, rename: Option<&str>, rule: Option<RenameRule>) -> String {
+    if let Some(name) = rename {
+        return name.to_string();
+    }
+    let raw = ident.to_string();
+    let name = raw.strip_prefix("r#").unwrap_or(&raw);
+    match rule {
+        Some(rule) => rule.apply(name),
+        None => name.to_string(),
+    }
+}
+
+/// Integer or float literal, optionally negative.
+fn parse_number(input: syn::parse::ParseStream) -> Result<f64, syn::Error> {
+    let negative = input.peek(Token![-]);
+    if negative {
+        input.parse::<Token![-]>()?;
+    }
+    let value: f64 = if input.peek(LitFloat) {
+        input.parse::<LitFloat>()?.base10_parse()?
+    } else {
+        input.parse::<LitInt>()?.base10_parse()?
+    };
+    Ok(if negative { -value } else { value })
+}
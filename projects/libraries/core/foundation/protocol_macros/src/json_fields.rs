@@ -0,0 +1,128 @@
+// projects/libraries/protocol_macros/src/json_fields.rs
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Field, Generics, Ident, Type, parse_quote};
+
+use crate::json_attrs::{
+    FieldAttrs, RenameRule, Validation, VariantAttrs, json_name, parse_field_attrs,
+    parse_variant_attrs,
+};
+
+/// A named field with its parsed attributes and JSON key.
+pub(crate) struct JsonField<'a> {
+    pub(crate) ident: &'a Ident,
+    pub(crate) ty: &'a Type,
+    pub(crate) key: String,
+    pub(crate) attrs: FieldAttrs,
+}
+
+impl JsonField<'_> {
+    /// Local binding used by the generated code.
+    pub(crate) fn binding(&self) -> Ident {
+        format_ident!("__field_{}", self.ident)
+    }
+}
+
+pub(crate) fn named_fields<'a>(
+    fields: impl IntoIterator<Item = &'a Field>,
+    rule: Option<RenameRule>,
+) -> Result<Vec<JsonField<'a>>, syn::Error> {
+    let mut out = Vec::new();
+    let mut seen: HashMap<String, &Ident> = HashMap::new();
+
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let attrs = parse_field_attrs(&field.attrs)?;
+        let key = json_name(ident, attrs.rename.as_deref(), rule);
+
+        if !attrs.skip && !attrs.flatten {
+            if let Some(existing) = seen.get(&key) {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    format!("duplicate JSON key `{key}`, already used by field `{existing}`"),
+                ));
+            }
+            seen.insert(key.clone(), ident);
+        }
+
+        out.push(JsonField {
+            ident,
+            ty: &field.ty,
+            key,
+            attrs,
+        });
+    }
+    Ok(out)
+}
+
+/// JSON names of the variants of an enum, checked for collisions.
+pub(crate) fn variant_names(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+    rule: Option<RenameRule>,
+) -> Result<Vec<String>, syn::Error> {
+    let mut names = Vec::new();
+    let mut seen: HashMap<String, &Ident> = HashMap::new();
+
+    for variant in variants {
+        let attrs: VariantAttrs = parse_variant_attrs(&variant.attrs)?;
+        let name = json_name(&variant.ident, attrs.rename.as_deref(), rule);
+        if let Some(existing) = seen.get(&name) {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!("duplicate JSON variant name `{name}`, already used by `{existing}`"),
+            ));
+        }
+        seen.insert(name.clone(), &variant.ident);
+        names.push(name);
+    }
+    Ok(names)
+}
+
+/// Add `T: bound` for every type parameter.
+pub(crate) fn with_bound(generics: &Generics, bound: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let params: Vec<Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #bound));
+    }
+    generics
+}
+
+/// `?` or `.map_err(|e| e.at_field(prefix))?` when errors must be nested under a key.
+pub(crate) fn try_suffix(prefix: Option<&str>) -> TokenStream {
+    match prefix {
+        Some(key) => quote! { .map_err(|__e| __e.at_field(#key))? },
+        None => quote! { ? },
+    }
+}
+
+/// Validation calls for a decoded field binding.
+pub(crate) fn validations(field: &JsonField, prefix: Option<&str>) -> TokenStream {
+    let binding = field.binding();
+    let key = &field.key;
+    let try_ = try_suffix(prefix);
+    let checks = field.attrs.validations.iter().map(|rule| match rule {
+        Validation::NonEmpty => quote! {
+            ::common_json::__private::validate_non_empty(&#binding, #key) #try_;
+        },
+        Validation::Min(min) => quote! {
+            ::common_json::__private::validate_min(&#binding, #min, #key) #try_;
+        },
+        Validation::Max(max) => quote! {
+            ::common_json::__private::validate_max(&#binding, #max, #key) #try_;
+        },
+        Validation::MinLen(min) => quote! {
+            ::common_json::__private::validate_min_len(&#binding, #min, #key) #try_;
+        },
+        Validation::MaxLen(max) => quote! {
+            ::common_json::__private::validate_max_len(&#binding, #max, #key) #try_;
+        },
+        Validation::With(path) => quote! {
+            ::common_json::__private::validate_with(#path(&#binding), #key) #try_;
+        },
+    });
+    quote! { #(#checks)* }
+}
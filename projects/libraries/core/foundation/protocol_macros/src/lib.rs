@@ -4,11 +4,17 @@ use proc_macro::TokenStream;
 use syn::{DeriveInput, parse_macro_input};
 
 mod derive_enum_methods;
+mod derive_from_json;
+mod derive_to_json;
 mod display_mode;
 mod enum_utils;
+mod json_attrs;
+mod json_fields;
 mod parse_display_mode;
 
 use derive_enum_methods::expand_enum_methods;
+use derive_from_json::expand_from_json;
+use derive_to_json::expand_to_json;
 
 #[proc_macro_derive(EnumMethods, attributes(enum_methods))]
 pub fn derive_enum_methods(input: TokenStream) -> TokenStream {
@@ -16,6 +22,28 @@ pub fn derive_enum_methods(input: TokenStream) -> TokenStream {
     TokenStream::from(expand_enum_methods(input))
 }
 
+/// Serde-free `common_json::ToJson` implementation.
+///
+/// Attributes: container `#[json(rename_all = "..", tag = "..", content = "..")]`,
+/// field `#[json(rename = "..", skip, skip_serializing_if = "path", flatten)]`,
+/// variant `#[json(rename = "..")]`.
+#[proc_macro_derive(ToJson, attributes(json))]
+pub fn derive_to_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(expand_to_json(input))
+}
+
+/// Serde-free `common_json::FromJson` implementation.
+///
+/// Same attributes as `ToJson`, plus field `#[json(default)]`, `#[json(default = "path")]`
+/// and `#[json(validate(non_empty, min = 0, max = 10, min_len = 1, max_len = 64, with = "path"))]`.
+/// Errors are `JsonError`s carrying the path of the failing field.
+#[proc_macro_derive(FromJson, attributes(json))]
+pub fn derive_from_json(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(expand_from_json(input))
+}
+
 // parse_display_mode moved to module for clarity
 
 #[cfg(test)]
@@ -0,0 +1,259 @@
+//! Integration tests for the serde-free `ToJson` / `FromJson` derives
+// projects/libraries/protocol_macros/tests/json_derive_test.rs
+use std::collections::BTreeMap;
+
+use common_json::json_error_code::JsonErrorCode;
+use common_json::{FromJson, Json, ToJson, pjson};
+use protocol_macros::{FromJson, ToJson};
+
+fn default_port() -> u16 {
+    8080
+}
+
+fn no_spaces(value: &str) -> Result<(), String> {
+    if value.contains(' ') {
+        Err("must not contain spaces".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ToJson, FromJson)]
+struct Labels {
+    env: String,
+    #[json(default)]
+    team: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, ToJson, FromJson)]
+#[json(rename_all = "camelCase")]
+struct ServiceConfig {
+    #[json(validate(non_empty, max_len = 32, with = "no_spaces"))]
+    service_name: String,
+    #[json(default = "default_port", validate(min = 1, max = 65535))]
+    listen_port: u16,
+    #[json(rename = "tags")]
+    tag_list: Vec<String>,
+    #[json(skip)]
+    cache: Option<String>,
+    #[json(skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[json(flatten)]
+    labels: Labels,
+    #[json(default)]
+    weights: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, ToJson, FromJson)]
+struct UserId(u64);
+
+#[derive(Debug, Clone, PartialEq, ToJson, FromJson)]
+struct Point(i32, i32);
+
+#[derive(Debug, Clone, PartialEq, ToJson, FromJson)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Segment(Point, Point),
+    #[json(rename = "rect")]
+    Rectangle {
+        width: f64,
+        height: f64,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, ToJson, FromJson)]
+#[json(tag = "type", rename_all = "snake_case")]
+enum Event {
+    Started,
+    UserJoined {
+        user: UserId,
+        #[json(validate(min_len = 1))]
+        name: String,
+    },
+    Labeled(Labels),
+}
+
+#[derive(Debug, Clone, PartialEq, ToJson, FromJson)]
+#[json(tag = "kind", content = "data")]
+enum Message {
+    Ping,
+    Text(String),
+    Move { x: i32, y: i32 },
+}
+
+#[derive(Debug, Clone, PartialEq, ToJson, FromJson)]
+struct Envelope<T> {
+    items: Vec<T>,
+}
+
+fn sample_config() -> ServiceConfig {
+    ServiceConfig {
+        service_name: "engine".to_string(),
+        listen_port: 3030,
+        tag_list: vec!["core".to_string()],
+        cache: None,
+        owner: None,
+        labels: Labels {
+            env: "prod".to_string(),
+            team: None,
+        },
+        weights: BTreeMap::new(),
+    }
+}
+
+fn round_trip<T: ToJson + FromJson + PartialEq + std::fmt::Debug>(value: &T) -> Json {
+    let json = value.to_json_value().expect("to json");
+    let back = T::from_json_value(&json).expect("from json");
+    assert_eq!(&back, value);
+    json
+}
+
+#[test]
+fn test_struct_rename_skip_and_flatten() {
+    let mut config = sample_config();
+    let json = round_trip(&config);
+
+    let object = json.as_object().expect("object");
+    assert_eq!(object.get("serviceName"), Some(&pjson!("engine")));
+    assert_eq!(object.get("listenPort"), Some(&pjson!(3030)));
+    assert_eq!(object.get("tags"), Some(&pjson!(["core"])));
+    // flattened into the parent object
+    assert_eq!(object.get("env"), Some(&pjson!("prod")));
+    assert!(!object.contains_key("labels"));
+    // skipped / skip_serializing_if
+    assert!(!object.contains_key("cache"));
+    assert!(!object.contains_key("owner"));
+
+    config.cache = Some("warm".to_string());
+    let back =
+        ServiceConfig::from_json_value(&config.to_json_value().expect("json")).expect("from json");
+    assert_eq!(back.cache, None);
+}
+
+#[test]
+fn test_defaults_apply_to_missing_fields() {
+    let json = pjson!({ "serviceName": "accounts", "tags": [], "env": "dev" });
+    let config = ServiceConfig::from_json_value(&json).expect("from json");
+    assert_eq!(config.listen_port, 8080);
+    assert_eq!(config.labels.team, None);
+    assert!(config.weights.is_empty());
+}
+
+#[test]
+fn test_missing_required_field_reports_path() {
+    let json = pjson!({ "serviceName": "accounts", "env": "dev" });
+    let err = ServiceConfig::from_json_value(&json).expect_err("tags is required");
+    assert_eq!(err.code, JsonErrorCode::MissingField);
+    assert_eq!(err.path.as_deref(), Some("tags"));
+}
+
+#[test]
+fn test_nested_type_error_reports_full_path() {
+    let json = pjson!({ "serviceName": "a", "tags": ["ok", 3], "env": "dev" });
+    let err = ServiceConfig::from_json_value(&json).expect_err("bad tag");
+    assert_eq!(err.code, JsonErrorCode::TypeMismatch);
+    assert_eq!(err.path.as_deref(), Some("tags[1]"));
+
+    let json = pjson!({ "items": [{ "env": "a" }, { "env": 1 }] });
+    let err = Envelope::<Labels>::from_json_value(&json).expect_err("bad env");
+    assert_eq!(err.path.as_deref(), Some("items[1].env"));
+}
+
+#[test]
+fn test_validation_attributes() {
+    let cases = [
+        (
+            pjson!({ "serviceName": "", "tags": [], "env": "d" }),
+            "serviceName",
+        ),
+        (
+            pjson!({ "serviceName": "a b", "tags": [], "env": "d" }),
+            "serviceName",
+        ),
+        (
+            pjson!({ "serviceName": "a", "listenPort": 0, "tags": [], "env": "d" }),
+            "listenPort",
+        ),
+    ];
+    for (json, path) in cases {
+        let err = ServiceConfig::from_json_value(&json).expect_err("invalid");
+        assert_eq!(err.code, JsonErrorCode::ValidationFailed, "{err}");
+        assert_eq!(err.path.as_deref(), Some(path));
+    }
+}
+
+#[test]
+fn test_newtype_and_tuple_structs() {
+    assert_eq!(round_trip(&UserId(7)), pjson!(7));
+    assert_eq!(round_trip(&Point(1, -2)), pjson!([1, (-2)]));
+    assert!(Point::from_json_value(&pjson!([1])).is_err());
+}
+
+#[test]
+fn test_externally_tagged_enum() {
+    assert_eq!(round_trip(&Shape::Empty), pjson!("Empty"));
+    assert_eq!(round_trip(&Shape::Circle(1.5)), pjson!({ "Circle": 1.5 }));
+    assert_eq!(
+        round_trip(&Shape::Segment(Point(0, 0), Point(1, 1))),
+        pjson!({ "Segment": [[0, 0], [1, 1]] })
+    );
+    assert_eq!(
+        round_trip(&Shape::Rectangle {
+            width: 2.0,
+            height: 3.0
+        }),
+        pjson!({ "rect": { "width": 2.0, "height": 3.0 } })
+    );
+
+    let err = Shape::from_json_value(&pjson!({ "rect": { "width": 1 } })).expect_err("height");
+    assert_eq!(err.path.as_deref(), Some("rect.height"));
+
+    let err = Shape::from_json_value(&pjson!("Triangle")).expect_err("unknown");
+    assert_eq!(err.code, JsonErrorCode::UnknownVariant);
+}
+
+#[test]
+fn test_internally_tagged_enum() {
+    assert_eq!(round_trip(&Event::Started), pjson!({ "type": "started" }));
+    assert_eq!(
+        round_trip(&Event::UserJoined {
+            user: UserId(1),
+            name: "ada".to_string()
+        }),
+        pjson!({ "type": "user_joined", "user": 1, "name": "ada" })
+    );
+    let labeled = round_trip(&Event::Labeled(Labels {
+        env: "dev".to_string(),
+        team: Some("core".to_string()),
+    }));
+    assert_eq!(
+        labeled.as_object().expect("object").get("type"),
+        Some(&pjson!("labeled"))
+    );
+
+    let err = Event::from_json_value(&pjson!({ "type": "user_joined", "user": 1, "name": "" }))
+        .expect_err("empty name");
+    assert_eq!(err.code, JsonErrorCode::ValidationFailed);
+    assert_eq!(err.path.as_deref(), Some("name"));
+
+    let err = Event::from_json_value(&pjson!({ "user": 1 })).expect_err("no tag");
+    assert_eq!(err.path.as_deref(), Some("type"));
+}
+
+#[test]
+fn test_adjacently_tagged_enum() {
+    assert_eq!(round_trip(&Message::Ping), pjson!({ "kind": "Ping" }));
+    assert_eq!(
+        round_trip(&Message::Text("hi".to_string())),
+        pjson!({ "kind": "Text", "data": "hi" })
+    );
+    assert_eq!(
+        round_trip(&Message::Move { x: 1, y: 2 }),
+        pjson!({ "kind": "Move", "data": { "x": 1, "y": 2 } })
+    );
+
+    let err = Message::from_json_value(&pjson!({ "kind": "Move", "data": { "x": 1, "y": "2" } }))
+        .expect_err("bad y");
+    assert_eq!(err.path.as_deref(), Some("data.y"));
+}
@@ -1,34 +1,113 @@
 // projects/libraries/layers/domain/neural/src/generation/code_generator.rs
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use common_json::{JsonSerializable, to_string};
 use ndarray::{Array1, s};
+use rand::{SeedableRng, rngs::StdRng};
+use tracing::debug;
 
 use crate::{
-    generation::{GenerationConfig, GenerationError, apply_top_k, sample_categorical, softmax},
+    generation::{
+        GenerationConfig, GenerationError, apply_temperature, apply_top_k, apply_top_p,
+        generator_model::GeneratorModel, sample_categorical_with, softmax,
+    },
     network::neural_network::NeuralNetwork,
     tokenization::RustTokenizer,
+    transformer::{TransformerError, TransformerModel},
 };
 
+#[derive(Clone)]
 pub struct CodeGenerator {
-    model: NeuralNetwork,
+    model: GeneratorModel,
     tokenizer: RustTokenizer,
     config: GenerationConfig,
+    rng: StdRng,
 }
 
 impl CodeGenerator {
     pub fn new(model: NeuralNetwork, tokenizer: RustTokenizer, config: GenerationConfig) -> Self {
+        Self::with_model(GeneratorModel::FeedForward(model), tokenizer, config)
+    }
+
+    /// Generator backed by a transformer built for `tokenizer`'s vocabulary.
+    pub fn with_transformer(
+        model: TransformerModel,
+        tokenizer: RustTokenizer,
+        config: GenerationConfig,
+    ) -> Result<Self, GenerationError> {
+        model.check_vocab_size(&tokenizer)?;
+        Ok(Self::with_model(
+            GeneratorModel::Transformer(Box::new(model)),
+            tokenizer,
+            config,
+        ))
+    }
+
+    /// Load a transformer saved by [`CodeGenerator::save`] for the same vocabulary.
+    pub fn load_transformer(
+        model_path: &Path,
+        tokenizer: RustTokenizer,
+        config: GenerationConfig,
+    ) -> Result<Self, GenerationError> {
+        let model = TransformerModel::load(model_path, &tokenizer)?;
+        Self::with_transformer(model, tokenizer, config)
+    }
+
+    /// Load either model kind: transformer models are binary files tied to the
+    /// vocabulary, anything else is read as a JSON feed-forward network.
+    pub fn load(
+        model_path: &Path,
+        tokenizer: RustTokenizer,
+        config: GenerationConfig,
+    ) -> Result<Self, GenerationError> {
+        match Self::load_transformer(model_path, tokenizer.clone(), config.clone()) {
+            Err(GenerationError::ModelError(TransformerError::Persistence(_))) => {
+                let model = NeuralNetwork::load(model_path)?;
+                Ok(Self::new(model, tokenizer, config))
+            }
+            other => other,
+        }
+    }
+
+    fn with_model(
+        model: GeneratorModel,
+        tokenizer: RustTokenizer,
+        config: GenerationConfig,
+    ) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rand::rng()),
+        };
         Self {
             model,
             tokenizer,
             config,
+            rng,
         }
     }
 
     pub fn generate(&mut self, prompt: &str) -> Result<String, GenerationError> {
-        println!("Starting generation with prompt: {}", prompt);
-        let prompt_tokens = self.tokenizer.encode(prompt);
-        println!("Encoded tokens: {:?}", prompt_tokens);
-        let generated_tokens = self.generate_tokens(prompt_tokens)?;
-        println!("Generated tokens: {:?}", generated_tokens);
+        self.generate_with_cancel(prompt, None)
+    }
+
+    /// Like [`CodeGenerator::generate`], but stops with
+    /// [`GenerationError::Cancelled`] once `cancel` is set. The flag is
+    /// checked before every sampled token.
+    pub fn generate_cancellable(
+        &mut self,
+        prompt: &str,
+        cancel: &AtomicBool,
+    ) -> Result<String, GenerationError> {
+        self.generate_with_cancel(prompt, Some(cancel))
+    }
+
+    fn generate_with_cancel(
+        &mut self,
+        prompt: &str,
+        cancel: Option<&AtomicBool>,
+    ) -> Result<String, GenerationError> {
+        let (generated_tokens, _) = self.generate_ids_with_cancel(prompt, cancel)?;
         let code = self
             .tokenizer
             .decode(&generated_tokens)
@@ -37,16 +116,70 @@ impl CodeGenerator {
         Ok(code)
     }
 
-    fn generate_tokens(&mut self, mut tokens: Vec<usize>) -> Result<Vec<usize>, GenerationError> {
+    /// Token ids of the prompt followed by the generated tokens, and the
+    /// number of generated tokens.
+    pub fn generate_ids(&mut self, prompt: &str) -> Result<(Vec<usize>, usize), GenerationError> {
+        self.generate_ids_with_cancel(prompt, None)
+    }
+
+    fn generate_ids_with_cancel(
+        &mut self,
+        prompt: &str,
+        cancel: Option<&AtomicBool>,
+    ) -> Result<(Vec<usize>, usize), GenerationError> {
+        println!("Starting generation with prompt: {}", prompt);
+        let prompt_tokens = self.tokenizer.encode(prompt);
+        println!("Encoded tokens: {:?}", prompt_tokens);
+        let prompt_len = prompt_tokens.len();
+        let (generated_tokens, context_len) = match &self.model {
+            GeneratorModel::FeedForward(_) => {
+                (self.generate_tokens(prompt_tokens, cancel)?, prompt_len)
+            }
+            // The transformer continues the prompt without its closing EOS.
+            GeneratorModel::Transformer(_) => (
+                self.generate_with_cache(prompt_tokens, cancel)?,
+                prompt_len - 1,
+            ),
+        };
+        println!("Generated tokens: {:?}", generated_tokens);
+        let new_tokens = generated_tokens.len().saturating_sub(context_len);
+        Ok((generated_tokens, new_tokens))
+    }
+
+    /// Restart sampling from `seed`, making the next generation reproducible.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Copy of this generator sampling from its own RNG, seeded from this
+    /// one: forks of a seeded generator are reproducible but differ.
+    pub fn fork(&mut self) -> Self {
+        let mut fork = self.clone();
+        fork.rng = StdRng::from_rng(&mut self.rng);
+        fork
+    }
+
+    pub fn tokenizer(&self) -> &RustTokenizer {
+        &self.tokenizer
+    }
+
+    fn generate_tokens(
+        &mut self,
+        mut tokens: Vec<usize>,
+        cancel: Option<&AtomicBool>,
+    ) -> Result<Vec<usize>, GenerationError> {
         println!(
             "Starting token generation with initial tokens: {:?}",
             tokens
         );
         for _ in 0..self.config.max_new_tokens {
+            check_cancelled(cancel)?;
             let input = self.tokens_to_input(&tokens)?;
             println!("Input vector: {:?}", input);
-            let logits = self
-                .model
+            let GeneratorModel::FeedForward(model) = &mut self.model else {
+                return Err(GenerationError::ModelNotLoaded);
+            };
+            let logits = model
                 .forward(&input)
                 .map_err(GenerationError::NetworkError)?;
             println!("Logits: {:?}", logits);
@@ -62,6 +195,42 @@ impl CodeGenerator {
         Ok(tokens)
     }
 
+    /// Autoregressive decoding with the transformer: the prompt is run once to
+    /// fill the KV cache, then each step only processes the newest token.
+    fn generate_with_cache(
+        &mut self,
+        mut tokens: Vec<usize>,
+        cancel: Option<&AtomicBool>,
+    ) -> Result<Vec<usize>, GenerationError> {
+        let GeneratorModel::Transformer(model) = &self.model else {
+            return Err(GenerationError::ModelNotLoaded);
+        };
+        let max_seq_len = model.config().max_seq_len;
+
+        // `encode` closes the prompt with EOS; drop it so the model continues the text.
+        if tokens.len() > 1 && tokens.last() == Some(&self.tokenizer.eos_token()) {
+            tokens.pop();
+        }
+        // Keep the most recent tokens that fit, leaving room for one new token.
+        let context_start = tokens.len().saturating_sub(max_seq_len - 1);
+
+        let mut cache = model.new_cache();
+        let mut logits = model.forward_cached(&tokens[context_start..], &mut cache)?;
+
+        for _ in 0..self.config.max_new_tokens {
+            check_cancelled(cancel)?;
+            let last = logits.row(logits.nrows() - 1).to_owned();
+            let next_token = Self::sample_with(&self.config, &mut self.rng, &last)?;
+            debug!("Sampled next token: {}", next_token);
+            tokens.push(next_token);
+            if next_token == self.config.stop_token_id || cache.len() >= max_seq_len {
+                break;
+            }
+            logits = model.forward_cached(&[next_token], &mut cache)?;
+        }
+        Ok(tokens)
+    }
+
     fn tokens_to_input(&self, tokens: &[usize]) -> Result<Array1<f64>, GenerationError> {
         let context_size = 10.min(tokens.len());
         let recent_tokens = &tokens[tokens.len().saturating_sub(context_size)..];
@@ -77,7 +246,10 @@ impl CodeGenerator {
         }
 
         // Ensure the input vector matches the model's expected input size
-        let model_input_size = self.model.input_size();
+        let GeneratorModel::FeedForward(model) = &self.model else {
+            return Ok(input);
+        };
+        let model_input_size = model.input_size();
         if input.len() != model_input_size {
             let mut resized_input = Array1::<f64>::zeros(model_input_size);
             let copy_len = input.len().min(model_input_size);
@@ -90,31 +262,46 @@ impl CodeGenerator {
         Ok(input)
     }
 
-    fn sample_token(&self, logits: &Array1<f64>) -> Result<usize, GenerationError> {
-        println!("Logits before scaling: {:?}", logits);
-        let scaled_logits = logits / self.config.temperature as f64;
-        println!("Logits after scaling: {:?}", scaled_logits);
-        let mut probs = softmax(&scaled_logits);
-        println!("Probabilities after softmax: {:?}", probs);
+    fn sample_token(&mut self, logits: &Array1<f64>) -> Result<usize, GenerationError> {
+        Self::sample_with(&self.config, &mut self.rng, logits)
+    }
+
+    /// Temperature, then top-k, then top-p, then a categorical draw.
+    fn sample_with(
+        config: &GenerationConfig,
+        rng: &mut StdRng,
+        logits: &Array1<f64>,
+    ) -> Result<usize, GenerationError> {
+        let mut probs = softmax(&apply_temperature(logits, config.temperature as f64));
 
-        // Apply top-k filtering if configured
-        if let Some(k) = self.config.top_k {
+        if let Some(k) = config.top_k {
             probs = apply_top_k(&probs, k);
-            println!("Probabilities after top-k filtering: {:?}", probs);
+        }
+        if let Some(p) = config.top_p {
+            probs = apply_top_p(&probs, p as f64);
         }
 
-        let sampled_token = sample_categorical(&probs)?;
-        println!("Sampled token: {}", sampled_token);
+        let sampled_token = sample_categorical_with(&probs, rng)?;
+        debug!("Sampled token: {}", sampled_token);
         Ok(sampled_token)
     }
 
-    /// Serialize the neural network model to disk
-    pub fn save(&self, model_path: &std::path::Path) -> Result<(), std::io::Error> {
+    /// Serialize the model to disk: JSON for the feed-forward network, a
+    /// binary file tied to the tokenizer vocabulary for the transformer.
+    pub fn save(&self, model_path: &Path) -> Result<(), std::io::Error> {
         println!("Saving CodeGenerator model to {:?}", model_path);
 
+        let model = match &self.model {
+            GeneratorModel::FeedForward(model) => model,
+            GeneratorModel::Transformer(model) => {
+                return model
+                    .save(model_path, &self.tokenizer)
+                    .map_err(std::io::Error::other);
+            }
+        };
+
         // Serialize the neural network model
-        let serialized_model = self
-            .model
+        let serialized_model = model
             .to_json()
             .map_err(|e: common_json::JsonError| std::io::Error::other(e.to_string()))?;
 
@@ -135,23 +322,33 @@ impl CodeGenerator {
             // Tokenize the input example
             let tokens = self.tokenizer.encode(&example);
 
+            if let GeneratorModel::Transformer(model) = &mut self.model {
+                let max_seq_len = model.config().max_seq_len;
+                let loss = model.train_head(&tokens[..tokens.len().min(max_seq_len)], 0.01)?;
+                debug!("Next-token loss: {:.4}", loss);
+                continue;
+            }
+
             // Convert tokens to input vector
             let input = self.tokens_to_input(&tokens)?;
 
+            // Generate target vector (one-hot encoding for simplicity)
+            let target = self.tokens_to_input(&tokens)?;
+
+            let GeneratorModel::FeedForward(model) = &mut self.model else {
+                continue;
+            };
+
             // Forward pass
-            let output = self
-                .model
+            let output = model
                 .forward(&input)
                 .map_err(GenerationError::NetworkError)?;
 
-            // Generate target vector (one-hot encoding for simplicity)
-            let target = self.tokens_to_input(&tokens)?;
-
             // Calculate error (difference between output and target)
             let error = &target - &output;
 
             // Backward pass to update weights using the error
-            self.model
+            model
                 .backward(&error, 0.01)
                 .map_err(GenerationError::NetworkError)?;
         }
@@ -159,7 +356,26 @@ impl CodeGenerator {
         Ok(())
     }
 
-    pub fn get_model(&mut self) -> &mut NeuralNetwork {
-        &mut self.model
+    /// The feed-forward network, when this generator uses one.
+    pub fn get_model(&mut self) -> Option<&mut NeuralNetwork> {
+        match &mut self.model {
+            GeneratorModel::FeedForward(model) => Some(model),
+            GeneratorModel::Transformer(_) => None,
+        }
+    }
+
+    /// The transformer, when this generator uses one.
+    pub fn transformer(&self) -> Option<&TransformerModel> {
+        match &self.model {
+            GeneratorModel::FeedForward(_) => None,
+            GeneratorModel::Transformer(model) => Some(model),
+        }
+    }
+}
+
+fn check_cancelled(cancel: Option<&AtomicBool>) -> Result<(), GenerationError> {
+    match cancel {
+        Some(flag) if flag.load(Ordering::Relaxed) => Err(GenerationError::Cancelled),
+        _ => Ok(()),
     }
 }
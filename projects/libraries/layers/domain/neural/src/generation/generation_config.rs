@@ -9,6 +9,8 @@ pub struct GenerationConfig {
     pub top_k: Option<usize>,
     pub top_p: Option<f32>,
     pub stop_token_id: usize,
+    /// Seeds sampling for reproducible output; `None` draws from the thread RNG.
+    pub seed: Option<u64>,
 }
 
 impl Default for GenerationConfig {
@@ -19,6 +21,7 @@ impl Default for GenerationConfig {
             top_k: Some(50),
             top_p: Some(0.95),
             stop_token_id: 0,
+            seed: None,
         }
     }
 }
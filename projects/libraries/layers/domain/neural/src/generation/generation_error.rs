@@ -2,6 +2,7 @@
 use thiserror::Error;
 
 use crate::network::NetworkError;
+use crate::transformer::TransformerError;
 
 #[derive(Debug, Error)]
 #[error("{0}")]
@@ -12,9 +13,15 @@ pub enum GenerationError {
     #[error("Tokenization error: {0}")]
     TokenizationError(String),
 
+    #[error("Generation cancelled")]
+    Cancelled,
+
     #[error("Generation failed: {0}")]
     GenerationFailed(String),
 
     #[error("Network error: {0}")]
     NetworkError(#[from] NetworkError),
+
+    #[error("Model error: {0}")]
+    ModelError(#[from] TransformerError),
 }
@@ -0,0 +1,12 @@
+// projects/libraries/layers/domain/neural/src/generation/generator_model.rs
+use crate::network::neural_network::NeuralNetwork;
+use crate::transformer::TransformerModel;
+
+/// Model driving a [`CodeGenerator`](crate::generation::code_generator::CodeGenerator).
+#[derive(Clone)]
+pub(crate) enum GeneratorModel {
+    /// Bag-of-tokens feed-forward network over the last few tokens.
+    FeedForward(NeuralNetwork),
+    /// Attention model decoding with a KV cache.
+    Transformer(Box<TransformerModel>),
+}
@@ -2,10 +2,16 @@
 pub mod code_generator;
 pub mod generation_config;
 pub mod generation_error;
+mod generator_model;
 pub mod probabilities;
 pub mod sampling;
 
 pub use generation_config::GenerationConfig;
 pub use generation_error::GenerationError;
 pub use probabilities::softmax;
-pub use sampling::{apply_top_k, sample_categorical};
+pub use sampling::{
+    apply_temperature, apply_top_k, apply_top_p, sample_categorical, sample_categorical_with,
+};
+
+#[cfg(test)]
+mod tests;
@@ -1,6 +1,7 @@
 // projects/libraries/layers/domain/neural/src/generation/sampling.rs
 use crate::generation::GenerationError;
 use ndarray::Array1;
+use rand::Rng;
 
 /// Applies top-k filtering to an array of probabilities.
 pub fn apply_top_k(probs: &Array1<f64>, k: usize) -> Array1<f64> {
@@ -15,9 +16,71 @@ pub fn apply_top_k(probs: &Array1<f64>, k: usize) -> Array1<f64> {
     filtered
 }
 
+/// Applies nucleus (top-p) filtering: keeps the smallest set of most likely
+/// tokens whose cumulative probability reaches `p`, renormalized to sum to 1.
+pub fn apply_top_p(probs: &Array1<f64>, p: f64) -> Array1<f64> {
+    let total = probs.sum();
+    if total <= 0.0 {
+        return probs.clone();
+    }
+
+    let mut sorted_probs: Vec<(usize, f64)> = probs.iter().cloned().enumerate().collect();
+    sorted_probs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let threshold = p.clamp(0.0, 1.0) * total;
+    let mut filtered = Array1::<f64>::zeros(probs.len());
+    let mut cumsum = 0.0;
+    for (idx, prob) in sorted_probs {
+        filtered[idx] = prob;
+        cumsum += prob;
+        if cumsum >= threshold {
+            break;
+        }
+    }
+
+    let kept = filtered.sum();
+    filtered / kept
+}
+
+/// Scales logits by `1 / temperature`. A temperature of zero or less is
+/// greedy decoding: only the most likely token keeps a finite logit.
+pub fn apply_temperature(logits: &Array1<f64>, temperature: f64) -> Array1<f64> {
+    if temperature > 0.0 {
+        return logits / temperature;
+    }
+
+    let argmax = logits
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map_or(0, |(idx, _)| idx);
+    Array1::from_shape_fn(logits.len(), |idx| {
+        if idx == argmax {
+            0.0
+        } else {
+            f64::NEG_INFINITY
+        }
+    })
+}
+
 /// Samples a token index from an array of probabilities.
 pub fn sample_categorical(probs: &Array1<f64>) -> Result<usize, GenerationError> {
-    let sample: f64 = rand::random::<f64>();
+    sample_categorical_with(probs, &mut rand::rng())
+}
+
+/// Samples a token index using `rng`; the weights do not need to sum to 1.
+pub fn sample_categorical_with<R: Rng + ?Sized>(
+    probs: &Array1<f64>,
+    rng: &mut R,
+) -> Result<usize, GenerationError> {
+    let total = probs.sum();
+    if probs.is_empty() || !total.is_finite() || total <= 0.0 {
+        return Err(GenerationError::GenerationFailed(
+            "cannot sample from an empty distribution".to_string(),
+        ));
+    }
+
+    let sample: f64 = rng.random::<f64>() * total;
     let mut cumsum = 0.0;
     for (idx, &prob) in probs.iter().enumerate() {
         cumsum += prob;
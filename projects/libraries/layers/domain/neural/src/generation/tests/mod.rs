@@ -0,0 +1,2 @@
+// projects/libraries/layers/domain/neural/src/generation/tests/mod.rs
+mod code_generator;
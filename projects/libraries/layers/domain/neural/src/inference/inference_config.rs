@@ -0,0 +1,29 @@
+// projects/libraries/layers/domain/neural/src/inference/inference_config.rs
+use std::time::Duration;
+
+use crate::generation::GenerationConfig;
+
+#[derive(Debug, Clone)]
+pub struct InferenceConfig {
+    /// Requests generating at the same time; one model replica each.
+    pub max_concurrency: usize,
+    /// Requests allowed to wait for a free replica before new ones are rejected.
+    pub max_queue_len: usize,
+    /// Longest time a request may wait in the queue.
+    pub queue_timeout: Option<Duration>,
+    /// Deterministic mode: every request without its own seed samples from this one.
+    pub seed: Option<u64>,
+    pub generation: GenerationConfig,
+}
+
+impl Default for InferenceConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 2,
+            max_queue_len: 64,
+            queue_timeout: Some(Duration::from_secs(30)),
+            seed: None,
+            generation: GenerationConfig::default(),
+        }
+    }
+}
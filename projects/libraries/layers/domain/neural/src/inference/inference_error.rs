@@ -0,0 +1,20 @@
+// projects/libraries/layers/domain/neural/src/inference/inference_error.rs
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::generation::GenerationError;
+
+#[derive(Debug, Error)]
+pub enum InferenceError {
+    #[error("Failed to load model: {0}")]
+    Load(String),
+    #[error("Invalid inference configuration: {0}")]
+    InvalidConfig(String),
+    #[error("Inference queue is full ({capacity} waiting requests)")]
+    QueueFull { capacity: usize },
+    #[error("Request waited more than {0:?} for a free model")]
+    Timeout(Duration),
+    #[error("Generation failed: {0}")]
+    Generation(#[from] GenerationError),
+}
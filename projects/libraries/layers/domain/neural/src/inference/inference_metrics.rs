@@ -0,0 +1,57 @@
+// projects/libraries/layers/domain/neural/src/inference/inference_metrics.rs
+use std::time::Duration;
+
+use crate::inference::InferenceResponse;
+
+/// Counters of an [`InferenceSession`](crate::inference::InferenceSession) since it was created.
+#[derive(Debug, Clone, Default)]
+pub struct InferenceMetrics {
+    pub requests_completed: u64,
+    pub requests_failed: u64,
+    /// Turned away because the queue was full or the wait timed out.
+    pub requests_rejected: u64,
+    pub tokens_generated: u64,
+    pub total_queue_time: Duration,
+    pub total_inference_time: Duration,
+    pub max_inference_time: Duration,
+    /// Time since the session was created.
+    pub uptime: Duration,
+    /// Requests currently waiting for a replica.
+    pub queue_depth: usize,
+}
+
+impl InferenceMetrics {
+    pub(crate) fn record(&mut self, response: &InferenceResponse) {
+        self.requests_completed += 1;
+        self.tokens_generated += response.tokens_generated as u64;
+        self.total_queue_time += response.queue_time;
+        self.total_inference_time += response.inference_time;
+        self.max_inference_time = self.max_inference_time.max(response.inference_time);
+    }
+
+    pub fn average_inference_time(&self) -> Duration {
+        match u32::try_from(self.requests_completed) {
+            Ok(0) | Err(_) => Duration::ZERO,
+            Ok(count) => self.total_inference_time / count,
+        }
+    }
+
+    /// Completed requests per second of uptime.
+    pub fn requests_per_second(&self) -> f64 {
+        per_second(self.requests_completed, self.uptime)
+    }
+
+    /// Generated tokens per second spent generating.
+    pub fn tokens_per_second(&self) -> f64 {
+        per_second(self.tokens_generated, self.total_inference_time)
+    }
+}
+
+fn per_second(count: u64, elapsed: Duration) -> f64 {
+    let seconds = elapsed.as_secs_f64();
+    if seconds > 0.0 {
+        count as f64 / seconds
+    } else {
+        0.0
+    }
+}
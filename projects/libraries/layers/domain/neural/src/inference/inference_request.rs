@@ -0,0 +1,21 @@
+// projects/libraries/layers/domain/neural/src/inference/inference_request.rs
+#[derive(Debug, Clone)]
+pub struct InferenceRequest {
+    pub prompt: String,
+    /// Overrides the session seed for this request.
+    pub seed: Option<u64>,
+}
+
+impl InferenceRequest {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            seed: None,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
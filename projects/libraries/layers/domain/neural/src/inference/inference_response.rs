@@ -0,0 +1,18 @@
+// projects/libraries/layers/domain/neural/src/inference/inference_response.rs
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct InferenceResponse {
+    pub output: String,
+    pub tokens_generated: usize,
+    /// Time spent waiting for a free model replica.
+    pub queue_time: Duration,
+    /// Time spent generating.
+    pub inference_time: Duration,
+}
+
+impl InferenceResponse {
+    pub fn total_time(&self) -> Duration {
+        self.queue_time + self.inference_time
+    }
+}
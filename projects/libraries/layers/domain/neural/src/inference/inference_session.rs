@@ -0,0 +1,172 @@
+// projects/libraries/layers/domain/neural/src/inference/inference_session.rs
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+use tracing::debug;
+
+use crate::generation::code_generator::CodeGenerator;
+use crate::inference::{
+    InferenceConfig, InferenceError, InferenceMetrics, InferenceRequest, InferenceResponse,
+    replica_pool::ReplicaPool,
+};
+use crate::tokenization::RustTokenizer;
+
+struct SessionInner {
+    config: InferenceConfig,
+    pool: ReplicaPool,
+    metrics: Mutex<InferenceMetrics>,
+    started_at: Instant,
+}
+
+/// A loaded model shared between callers.
+///
+/// Cloning is cheap and every clone uses the same replicas, queue and metrics,
+/// so one session can serve the orchestrator and its agents from several threads.
+#[derive(Clone)]
+pub struct InferenceSession {
+    inner: Arc<SessionInner>,
+}
+
+impl InferenceSession {
+    /// Serve `generator`, forked once per allowed concurrent request so
+    /// every replica samples from its own RNG.
+    pub fn new(
+        mut generator: CodeGenerator,
+        config: InferenceConfig,
+    ) -> Result<Self, InferenceError> {
+        if config.max_concurrency == 0 {
+            return Err(InferenceError::InvalidConfig(
+                "max_concurrency must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut replicas: Vec<CodeGenerator> = (1..config.max_concurrency)
+            .map(|_| generator.fork())
+            .collect();
+        replicas.push(generator);
+        Ok(Self {
+            inner: Arc::new(SessionInner {
+                pool: ReplicaPool::new(replicas, config.max_queue_len),
+                config,
+                metrics: Mutex::new(InferenceMetrics::default()),
+                started_at: Instant::now(),
+            }),
+        })
+    }
+
+    /// Load a saved model and its tokenizer.
+    pub fn load(
+        model_path: &Path,
+        tokenizer_path: &Path,
+        config: InferenceConfig,
+    ) -> Result<Self, InferenceError> {
+        let tokenizer =
+            RustTokenizer::load(tokenizer_path).map_err(|e| InferenceError::Load(e.to_string()))?;
+        let generator = CodeGenerator::load(model_path, tokenizer, config.generation.clone())
+            .map_err(|e| InferenceError::Load(e.to_string()))?;
+        Self::new(generator, config)
+    }
+
+    pub fn config(&self) -> &InferenceConfig {
+        &self.inner.config
+    }
+
+    /// Run one request, waiting for a free replica if all are busy.
+    pub fn infer(&self, request: InferenceRequest) -> Result<InferenceResponse, InferenceError> {
+        let queued_at = Instant::now();
+        let mut replica = match self.inner.pool.acquire(self.inner.config.queue_timeout) {
+            Ok(replica) => replica,
+            Err(e) => {
+                self.update_metrics(|m| m.requests_rejected += 1);
+                return Err(e);
+            }
+        };
+        let queue_time = queued_at.elapsed();
+
+        let generator = replica.generator();
+        if let Some(seed) = request.seed.or(self.inner.config.seed) {
+            generator.reseed(seed);
+        }
+
+        let started_at = Instant::now();
+        let result = generator
+            .generate_ids(&request.prompt)
+            .and_then(|(ids, generated)| {
+                let output = generator.tokenizer().decode(&ids).map_err(|e| {
+                    crate::generation::GenerationError::TokenizationError(e.to_string())
+                })?;
+                Ok((output, generated))
+            });
+        drop(replica);
+
+        match result {
+            Ok((output, tokens_generated)) => {
+                let response = InferenceResponse {
+                    output,
+                    tokens_generated,
+                    queue_time,
+                    inference_time: started_at.elapsed(),
+                };
+                debug!(
+                    "Inference done: {} tokens in {:?} (queued {:?})",
+                    response.tokens_generated, response.inference_time, response.queue_time
+                );
+                self.update_metrics(|m| m.record(&response));
+                Ok(response)
+            }
+            Err(e) => {
+                self.update_metrics(|m| m.requests_failed += 1);
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Run a batch concurrently, at most `max_concurrency` at a time.
+    /// Results are in request order.
+    pub fn infer_batch(
+        &self,
+        requests: Vec<InferenceRequest>,
+    ) -> Vec<Result<InferenceResponse, InferenceError>> {
+        let workers = self.inner.config.max_concurrency.min(requests.len());
+        if workers <= 1 {
+            return requests.into_iter().map(|r| self.infer(r)).collect();
+        }
+
+        let chunk_size = requests.len().div_ceil(workers);
+        thread::scope(|scope| {
+            let handles: Vec<_> = requests
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|r| self.infer(r.clone()))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("inference worker panicked"))
+                .collect()
+        })
+    }
+
+    pub fn metrics(&self) -> InferenceMetrics {
+        let mut metrics = self
+            .inner
+            .metrics
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        metrics.uptime = self.inner.started_at.elapsed();
+        metrics.queue_depth = self.inner.pool.queue_depth();
+        metrics
+    }
+
+    fn update_metrics(&self, update: impl FnOnce(&mut InferenceMetrics)) {
+        update(&mut self.inner.metrics.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+}
@@ -1,4 +1,18 @@
 // projects/libraries/layers/domain/neural/src/inference/mod.rs
-pub mod run_inference;
+pub mod inference_config;
+pub mod inference_error;
+pub mod inference_metrics;
+pub mod inference_request;
+pub mod inference_response;
+pub mod inference_session;
+mod replica_pool;
 
-pub use run_inference::run_inference;
+pub use inference_config::InferenceConfig;
+pub use inference_error::InferenceError;
+pub use inference_metrics::InferenceMetrics;
+pub use inference_request::InferenceRequest;
+pub use inference_response::InferenceResponse;
+pub use inference_session::InferenceSession;
+
+#[cfg(test)]
+mod tests;
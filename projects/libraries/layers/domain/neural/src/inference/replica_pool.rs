@@ -0,0 +1,109 @@
+// projects/libraries/layers/domain/neural/src/inference/replica_pool.rs
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::generation::code_generator::CodeGenerator;
+use crate::inference::InferenceError;
+
+struct PoolState {
+    idle: Vec<CodeGenerator>,
+    waiting: usize,
+}
+
+/// Fixed set of model replicas handed out to one request at a time. Requests
+/// beyond the replica count wait in a bounded queue.
+pub(crate) struct ReplicaPool {
+    state: Mutex<PoolState>,
+    available: Condvar,
+    max_queue_len: usize,
+}
+
+/// A checked-out replica, returned to the pool on drop.
+pub(crate) struct Replica<'a> {
+    pool: &'a ReplicaPool,
+    generator: Option<CodeGenerator>,
+}
+
+impl ReplicaPool {
+    pub(crate) fn new(replicas: Vec<CodeGenerator>, max_queue_len: usize) -> Self {
+        Self {
+            state: Mutex::new(PoolState {
+                idle: replicas,
+                waiting: 0,
+            }),
+            available: Condvar::new(),
+            max_queue_len,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        // A panic during generation never leaves the pool state half-updated.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn queue_depth(&self) -> usize {
+        self.lock().waiting
+    }
+
+    pub(crate) fn acquire(&self, timeout: Option<Duration>) -> Result<Replica<'_>, InferenceError> {
+        let mut state = self.lock();
+        if let Some(generator) = state.idle.pop() {
+            return Ok(self.replica(generator));
+        }
+        if state.waiting >= self.max_queue_len {
+            return Err(InferenceError::QueueFull {
+                capacity: self.max_queue_len,
+            });
+        }
+
+        state.waiting += 1;
+        let deadline = timeout.map(|t| (Instant::now() + t, t));
+        let result = loop {
+            if let Some(generator) = state.idle.pop() {
+                break Ok(generator);
+            }
+            state = match deadline {
+                None => self
+                    .available
+                    .wait(state)
+                    .unwrap_or_else(|e| e.into_inner()),
+                Some((deadline, timeout)) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        break Err(InferenceError::Timeout(timeout));
+                    }
+                    self.available
+                        .wait_timeout(state, remaining)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+            };
+        };
+        state.waiting -= 1;
+        result.map(|generator| self.replica(generator))
+    }
+
+    fn replica(&self, generator: CodeGenerator) -> Replica<'_> {
+        Replica {
+            pool: self,
+            generator: Some(generator),
+        }
+    }
+}
+
+impl Replica<'_> {
+    pub(crate) fn generator(&mut self) -> &mut CodeGenerator {
+        self.generator
+            .as_mut()
+            .expect("replica is present until dropped")
+    }
+}
+
+impl Drop for Replica<'_> {
+    fn drop(&mut self) {
+        if let Some(generator) = self.generator.take() {
+            self.pool.lock().idle.push(generator);
+            self.pool.available.notify_one();
+        }
+    }
+}
@@ -1,15 +0,0 @@
-// projects/libraries/layers/domain/neural/src/inference/run_inference.rs
-// Function for inference
-
-pub fn run_inference(data: &str) -> String {
-    println!("Running inference on data: {}", data);
-
-    // Example: Simple inference logic that classifies input length
-    let result = if data.len() > 10 {
-        "Long input detected"
-    } else {
-        "Short input detected"
-    };
-
-    result.to_string()
-}
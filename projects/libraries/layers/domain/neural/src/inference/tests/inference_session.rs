@@ -0,0 +1,90 @@
+// projects/libraries/layers/domain/neural/src/inference/tests/inference_session.rs
+use crate::inference::tests::{test_generator, test_tokenizer};
+use crate::inference::{InferenceConfig, InferenceError, InferenceRequest, InferenceSession};
+
+fn seeded_session(max_concurrency: usize) -> InferenceSession {
+    let config = InferenceConfig {
+        max_concurrency,
+        seed: Some(99),
+        ..InferenceConfig::default()
+    };
+    InferenceSession::new(test_generator(), config).expect("session")
+}
+
+#[test]
+fn test_deterministic_mode_repeats_outputs() {
+    let session = seeded_session(1);
+    let first = session
+        .infer(InferenceRequest::new("fn main() {"))
+        .expect("inference");
+    let second = session
+        .infer(InferenceRequest::new("fn main() {"))
+        .expect("inference");
+
+    assert_eq!(first.output, second.output);
+    assert!(first.output.starts_with("fn main ( ) {"));
+    assert!(first.tokens_generated <= 6);
+}
+
+#[test]
+fn test_batch_keeps_request_order_across_replicas() {
+    let prompts = ["fn main() {", "let x = ", "fn x() {}", "let main;"];
+    let session = seeded_session(3);
+
+    let batch = session.infer_batch(prompts.iter().map(|p| InferenceRequest::new(*p)).collect());
+    let sequential = seeded_session(1);
+    for (result, prompt) in batch.into_iter().zip(prompts) {
+        let expected = sequential
+            .infer(InferenceRequest::new(prompt))
+            .expect("inference");
+        assert_eq!(result.expect("batch inference").output, expected.output);
+    }
+}
+
+#[test]
+fn test_metrics_track_requests_and_tokens() {
+    let session = seeded_session(2);
+    let shared = session.clone();
+    let responses = shared.infer_batch(vec![
+        InferenceRequest::new("fn main() {"),
+        InferenceRequest::new("let x = ").with_seed(1),
+    ]);
+    let tokens: usize = responses
+        .iter()
+        .map(|r| r.as_ref().expect("inference").tokens_generated)
+        .sum();
+
+    let metrics = session.metrics();
+    assert_eq!(metrics.requests_completed, 2);
+    assert_eq!(metrics.requests_failed, 0);
+    assert_eq!(metrics.tokens_generated, tokens as u64);
+    assert_eq!(metrics.queue_depth, 0);
+    assert!(metrics.requests_per_second() > 0.0);
+}
+
+#[test]
+fn test_load_from_saved_files() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let model_path = dir.path().join("model.bin");
+    let tokenizer_path = dir.path().join("tokenizer.json");
+    test_generator().save(&model_path).expect("save model");
+    test_tokenizer()
+        .save(&tokenizer_path)
+        .expect("save tokenizer");
+
+    let session = InferenceSession::load(&model_path, &tokenizer_path, InferenceConfig::default())
+        .expect("load");
+    assert!(session.infer(InferenceRequest::new("fn main")).is_ok());
+}
+
+#[test]
+fn test_rejects_zero_concurrency() {
+    let config = InferenceConfig {
+        max_concurrency: 0,
+        ..InferenceConfig::default()
+    };
+    assert!(matches!(
+        InferenceSession::new(test_generator(), config),
+        Err(InferenceError::InvalidConfig(_))
+    ));
+}
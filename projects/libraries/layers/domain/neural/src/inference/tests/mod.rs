@@ -0,0 +1,34 @@
+// projects/libraries/layers/domain/neural/src/inference/tests/mod.rs
+mod inference_session;
+mod replica_pool;
+
+use crate::generation::{GenerationConfig, code_generator::CodeGenerator};
+use crate::tokenization::RustTokenizer;
+use crate::transformer::{TransformerConfig, TransformerModel};
+
+pub(super) fn test_tokenizer() -> RustTokenizer {
+    RustTokenizer::new(
+        ["fn", "main", "(", ")", "{", "}", "let", "x", "=", ";"]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+    )
+}
+
+pub(super) fn test_generator() -> CodeGenerator {
+    let tokenizer = test_tokenizer();
+    let config = TransformerConfig {
+        d_model: 8,
+        num_heads: 2,
+        num_layers: 1,
+        d_ff: 16,
+        max_seq_len: 32,
+        ..TransformerConfig::for_vocab(tokenizer.vocab_size())
+    };
+    let model = TransformerModel::new_seeded(config, 11).expect("model");
+    let generation = GenerationConfig {
+        max_new_tokens: 6,
+        ..GenerationConfig::default()
+    };
+    CodeGenerator::with_transformer(model, tokenizer, generation).expect("generator")
+}
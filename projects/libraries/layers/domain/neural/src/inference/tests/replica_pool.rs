@@ -0,0 +1,37 @@
+// projects/libraries/layers/domain/neural/src/inference/tests/replica_pool.rs
+use std::time::Duration;
+
+use crate::inference::InferenceError;
+use crate::inference::replica_pool::ReplicaPool;
+use crate::inference::tests::test_generator;
+
+#[test]
+fn test_rejects_when_queue_is_full() {
+    let pool = ReplicaPool::new(vec![test_generator()], 0);
+    let _busy = pool.acquire(None).expect("first replica");
+
+    assert!(matches!(
+        pool.acquire(None),
+        Err(InferenceError::QueueFull { capacity: 0 })
+    ));
+}
+
+#[test]
+fn test_waiting_request_times_out() {
+    let pool = ReplicaPool::new(vec![test_generator()], 1);
+    let _busy = pool.acquire(None).expect("first replica");
+
+    let timeout = Duration::from_millis(20);
+    assert!(matches!(
+        pool.acquire(Some(timeout)),
+        Err(InferenceError::Timeout(t)) if t == timeout
+    ));
+    assert_eq!(pool.queue_depth(), 0);
+}
+
+#[test]
+fn test_replica_returns_to_pool_on_drop() {
+    let pool = ReplicaPool::new(vec![test_generator()], 0);
+    drop(pool.acquire(None).expect("first checkout"));
+    assert!(pool.acquire(None).is_ok(), "replica should be idle again");
+}
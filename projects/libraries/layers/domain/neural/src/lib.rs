@@ -6,6 +6,7 @@ pub mod network;
 pub mod solver;
 pub mod tokenization;
 pub mod training;
+pub mod transformer;
 
 #[cfg(test)]
 mod tests;
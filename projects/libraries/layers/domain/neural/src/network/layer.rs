@@ -1,10 +1,12 @@
 // projects/libraries/layers/domain/neural/src/network/layer.rs
 use ndarray::{Array1, Array2, Axis};
+use rand::Rng;
 use rand_distr::{Distribution, Normal};
 use serde::{Deserialize, Serialize};
+use tracing::trace;
 
 use crate::network::{
-    Activation, LayerConfig, network_error::NetworkError, weight_init::WeightInit,
+    Activation, LayerConfig, LayerGradients, network_error::NetworkError, weight_init::WeightInit,
 };
 
 /// Single layer of a neural network
@@ -25,8 +27,17 @@ pub struct Layer {
 
 impl Layer {
     pub fn new(config: LayerConfig) -> Self {
-        let (weights, biases) =
-            Self::initialize_weights(config.input_size, config.output_size, config.weight_init);
+        Self::new_with_rng(config, &mut rand::rng())
+    }
+
+    /// Create a layer drawing its initial weights from `rng`, for reproducible runs.
+    pub fn new_with_rng<R: Rng + ?Sized>(config: LayerConfig, rng: &mut R) -> Self {
+        let (weights, biases) = Self::initialize_weights(
+            config.input_size,
+            config.output_size,
+            config.weight_init,
+            rng,
+        );
 
         Self {
             weights,
@@ -38,10 +49,11 @@ impl Layer {
         }
     }
 
-    fn initialize_weights(
+    fn initialize_weights<R: Rng + ?Sized>(
         input_size: usize,
         output_size: usize,
         init_method: WeightInit,
+        rng: &mut R,
     ) -> (Array2<f64>, Array1<f64>) {
         let std_dev = match init_method {
             WeightInit::Xavier => (2.0 / (input_size + output_size) as f64).sqrt(),
@@ -56,9 +68,7 @@ impl Layer {
         };
 
         let normal = Normal::new(0.0, std_dev).expect("valid normal distribution");
-        let mut rng = rand::rng();
-
-        let weights = Array2::from_shape_fn((output_size, input_size), |_| normal.sample(&mut rng));
+        let weights = Array2::from_shape_fn((output_size, input_size), |_| normal.sample(rng));
 
         let biases = Array1::zeros(output_size);
 
@@ -66,8 +76,6 @@ impl Layer {
     }
 
     pub fn forward(&mut self, input: &Array1<f64>) -> Result<Array1<f64>, NetworkError> {
-        println!("Input dimensions: {}", input.len());
-        println!("Expected dimensions: {}", self.weights.ncols());
         if input.len() != self.weights.ncols() {
             return Err(NetworkError::DimensionMismatch {
                 expected: self.weights.ncols(),
@@ -75,30 +83,28 @@ impl Layer {
             });
         }
 
-        println!("Weights: {:?}", self.weights);
-        println!("Biases: {:?}", self.biases);
-
         // Cache input for backpropagation
         self.last_input = Some(input.clone());
 
         // Weighted sum
         let weighted_sum = self.weights.dot(input) + &self.biases;
-        println!("Weighted sum: {:?}", weighted_sum);
+        trace!("Weighted sum: {:?}", weighted_sum);
         self.last_weighted_sum = Some(weighted_sum.clone());
 
         // Apply activation
         let output = weighted_sum.mapv(|x| self.activation.apply(x));
-        println!("Output after activation: {:?}", output);
+        trace!("Output after activation: {:?}", output);
         self.last_output = Some(output.clone());
 
         Ok(output)
     }
 
-    pub fn backward(
-        &mut self,
+    /// Parameter gradients for `output_gradient`, and the gradient to pass to the
+    /// previous layer. Unlike [`Layer::backward`] the weights are left untouched.
+    pub fn gradients(
+        &self,
         output_gradient: &Array1<f64>,
-        learning_rate: f64,
-    ) -> Result<Array1<f64>, NetworkError> {
+    ) -> Result<(LayerGradients, Array1<f64>), NetworkError> {
         let input = self.last_input.as_ref().ok_or_else(|| {
             NetworkError::InvalidConfig("Must call forward() before backward()".to_string())
         })?;
@@ -117,14 +123,29 @@ impl Layer {
             .insert_axis(Axis(1))
             .dot(&input.clone().insert_axis(Axis(0)));
 
-        let bias_gradient = delta.clone();
-
-        // Calculate input gradient for previous layer (BEFORE update)
+        // Calculate input gradient for previous layer
         let input_gradient = self.weights.t().dot(&delta);
 
+        Ok((
+            LayerGradients {
+                weights: weight_gradient,
+                biases: delta,
+            },
+            input_gradient,
+        ))
+    }
+
+    pub fn backward(
+        &mut self,
+        output_gradient: &Array1<f64>,
+        learning_rate: f64,
+    ) -> Result<Array1<f64>, NetworkError> {
+        // Input gradient is computed BEFORE the update
+        let (gradients, input_gradient) = self.gradients(output_gradient)?;
+
         // Update parameters
-        self.weights = &self.weights - learning_rate * &weight_gradient;
-        self.biases = &self.biases - learning_rate * &bias_gradient;
+        self.weights = &self.weights - learning_rate * &gradients.weights;
+        self.biases = &self.biases - learning_rate * &gradients.biases;
 
         Ok(input_gradient)
     }
@@ -0,0 +1,30 @@
+// projects/libraries/layers/domain/neural/src/network/layer_gradients.rs
+use ndarray::{Array1, Array2};
+
+use crate::network::Layer;
+
+/// Parameter gradients of a single layer.
+#[derive(Debug, Clone)]
+pub struct LayerGradients {
+    pub weights: Array2<f64>,
+    pub biases: Array1<f64>,
+}
+
+impl LayerGradients {
+    pub fn zeros_like(layer: &Layer) -> Self {
+        Self {
+            weights: Array2::zeros(layer.weights.raw_dim()),
+            biases: Array1::zeros(layer.biases.raw_dim()),
+        }
+    }
+
+    pub fn accumulate(&mut self, other: &LayerGradients) {
+        self.weights += &other.weights;
+        self.biases += &other.biases;
+    }
+
+    pub fn scale(&mut self, factor: f64) {
+        self.weights *= factor;
+        self.biases *= factor;
+    }
+}
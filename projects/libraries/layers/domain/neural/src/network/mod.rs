@@ -1,6 +1,7 @@
 pub mod activation;
 pub mod layer;
 pub mod layer_config;
+pub mod layer_gradients;
 pub mod network_error;
 pub mod neural_network;
 pub mod weight_init;
@@ -8,6 +9,7 @@ pub mod weight_init;
 pub use activation::Activation;
 pub use layer::Layer;
 pub use layer_config::LayerConfig;
+pub use layer_gradients::LayerGradients;
 pub use network_error::NetworkError;
 pub use neural_network::SimpleNeuralNet;
 pub use weight_init::WeightInit;
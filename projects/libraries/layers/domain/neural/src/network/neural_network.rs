@@ -1,9 +1,10 @@
 // projects/libraries/layers/domain/neural/src/network/neural_network.rs
 use common_json::json;
 use ndarray::Array1;
+use rand::{SeedableRng, rngs::StdRng};
 use serde::{Deserialize, Serialize};
 
-use crate::network::{Layer, LayerConfig, NetworkError};
+use crate::network::{Layer, LayerConfig, LayerGradients, NetworkError};
 
 /// Multi-layer neural network
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +14,18 @@ pub struct NeuralNetwork {
 
 impl NeuralNetwork {
     pub fn new(layer_configs: Vec<LayerConfig>) -> Result<Self, NetworkError> {
+        Self::build(layer_configs, &mut rand::rng())
+    }
+
+    /// Same as [`NeuralNetwork::new`], with weights initialized from `seed`.
+    pub fn new_seeded(layer_configs: Vec<LayerConfig>, seed: u64) -> Result<Self, NetworkError> {
+        Self::build(layer_configs, &mut StdRng::seed_from_u64(seed))
+    }
+
+    fn build<R: rand::Rng + ?Sized>(
+        layer_configs: Vec<LayerConfig>,
+        rng: &mut R,
+    ) -> Result<Self, NetworkError> {
         if layer_configs.is_empty() {
             return Err(NetworkError::InvalidConfig(
                 "Network must have at least one layer".to_string(),
@@ -31,7 +44,10 @@ impl NeuralNetwork {
             }
         }
 
-        let layers = layer_configs.into_iter().map(Layer::new).collect();
+        let layers = layer_configs
+            .into_iter()
+            .map(|config| Layer::new_with_rng(config, rng))
+            .collect();
 
         Ok(Self { layers })
     }
@@ -83,6 +99,43 @@ impl NeuralNetwork {
         Ok(loss)
     }
 
+    /// MSE loss of the last forward pass and the gradients of every layer, first
+    /// layer first. The weights are left untouched so that gradients can be
+    /// accumulated over a mini-batch before an optimizer step.
+    pub fn gradients(
+        &self,
+        target: &Array1<f64>,
+    ) -> Result<(f64, Vec<LayerGradients>), NetworkError> {
+        let output = self
+            .layers
+            .last()
+            .and_then(|l| l.last_output.as_ref())
+            .ok_or_else(|| {
+                NetworkError::InvalidConfig("Must call forward() before backward()".to_string())
+            })?;
+        if output.len() != target.len() {
+            return Err(NetworkError::DimensionMismatch {
+                expected: output.len(),
+                actual: target.len(),
+            });
+        }
+
+        let mut gradient = output - target;
+        let n = gradient.len() as f64;
+        let loss = gradient.mapv(|x| x * x).sum() / n;
+        gradient = (2.0 / n) * gradient;
+
+        let mut gradients = Vec::with_capacity(self.layers.len());
+        for layer in self.layers.iter().rev() {
+            let (layer_gradients, input_gradient) = layer.gradients(&gradient)?;
+            gradients.push(layer_gradients);
+            gradient = input_gradient;
+        }
+        gradients.reverse();
+
+        Ok((loss, gradients))
+    }
+
     pub fn update_weights(&mut self, tokens: &[usize]) -> Result<(), NetworkError> {
         println!("Updating weights with {} tokens", tokens.len());
         // Placeholder implementation: updating layer weights
@@ -100,6 +153,10 @@ impl NeuralNetwork {
         self.layers.last().map(|l| l.weights.nrows()).unwrap_or(0)
     }
 
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
     pub fn layers_mut(&mut self) -> &mut Vec<Layer> {
         &mut self.layers
     }
@@ -51,3 +51,48 @@ fn test_forward_backward() {
     assert!(loss >= 0.0, "loss should be non-negative");
     assert!(loss.is_finite(), "loss should be finite");
 }
+
+#[test]
+fn test_gradients_match_backward_without_updating() {
+    let mut network = create_small_network().expect("network creation should succeed");
+    let input = Array1::from_vec(vec![1.0, 2.0]);
+    let target = Array1::from_vec(vec![1.0]);
+
+    network
+        .forward(&input)
+        .expect("forward pass should succeed");
+    let before = network.layers()[0].weights.clone();
+    let (loss, gradients) = network
+        .gradients(&target)
+        .expect("gradients should succeed");
+    assert_eq!(gradients.len(), 2, "one gradient per layer");
+    assert_eq!(
+        network.layers()[0].weights,
+        before,
+        "gradients must not touch weights"
+    );
+
+    let expected = &before - 0.01 * &gradients[0].weights;
+    let backward_loss = network
+        .backward(&target, 0.01)
+        .expect("backward pass should succeed");
+    assert_eq!(loss, backward_loss);
+    assert!(
+        network.layers()[0]
+            .weights
+            .iter()
+            .zip(expected.iter())
+            .all(|(a, b)| (a - b).abs() < 1e-12),
+        "backward should apply the same gradients"
+    );
+}
+
+#[test]
+fn test_seeded_networks_are_identical() {
+    let configs = || vec![create_test_layer_config(3, 2, Activation::Tanh)];
+    let a = crate::network::neural_network::NeuralNetwork::new_seeded(configs(), 7)
+        .expect("network creation should succeed");
+    let b = crate::network::neural_network::NeuralNetwork::new_seeded(configs(), 7)
+        .expect("network creation should succeed");
+    assert_eq!(a.layers()[0].weights, b.layers()[0].weights);
+}
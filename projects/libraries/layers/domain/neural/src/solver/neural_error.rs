@@ -7,6 +7,8 @@ pub enum NeuralError {
     GenerationError(String),
     #[error("Training error: {0}")]
     TrainingError(String),
+    #[error("Generation cancelled")]
+    Cancelled,
     #[error("Model not loaded")]
     ModelNotLoaded,
     #[error("IO error: {0}")]
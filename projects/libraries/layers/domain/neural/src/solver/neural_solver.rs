@@ -1,7 +1,9 @@
 // projects/libraries/layers/domain/neural/src/solver/neural_solver.rs
+use std::sync::atomic::AtomicBool;
+
 use crate::feedback::{FeedbackAdjuster, FeedbackConfig, UserFeedback};
+use crate::generation::GenerationError;
 use crate::generation::code_generator::CodeGenerator;
-use crate::network::neural_network::NeuralNetwork;
 use crate::tokenization::rust_tokenizer::RustTokenizer;
 
 use crate::solver::{NeuralError, SolverResult};
@@ -21,13 +23,15 @@ impl NeuralSolver {
         let tokenizer = RustTokenizer::load(tokenizer_path)
             .map_err(|e| NeuralError::GenerationError(e.to_string()))?;
 
-        let model = NeuralNetwork::load(model_path).map_err(|_| NeuralError::ModelNotLoaded)?;
-
-        let generator = CodeGenerator::new(
-            model,
+        let generator = CodeGenerator::load(
+            model_path,
             tokenizer.clone(),
             crate::generation::GenerationConfig::default(),
-        );
+        )
+        .map_err(|e| match e {
+            GenerationError::NetworkError(_) => NeuralError::ModelNotLoaded,
+            other => NeuralError::GenerationError(other.to_string()),
+        })?;
 
         let feedback_adjuster = FeedbackAdjuster::new(FeedbackConfig::default());
 
@@ -39,6 +43,24 @@ impl NeuralSolver {
     }
 
     pub fn solve(&mut self, input: &str) -> Result<SolverResult, NeuralError> {
+        self.solve_with_cancel(input, None)
+    }
+
+    /// Like [`NeuralSolver::solve`], but gives up with [`NeuralError::Cancelled`]
+    /// as soon as `cancel` is set.
+    pub fn solve_cancellable(
+        &mut self,
+        input: &str,
+        cancel: &AtomicBool,
+    ) -> Result<SolverResult, NeuralError> {
+        self.solve_with_cancel(input, Some(cancel))
+    }
+
+    fn solve_with_cancel(
+        &mut self,
+        input: &str,
+        cancel: Option<&AtomicBool>,
+    ) -> Result<SolverResult, NeuralError> {
         // Tokenize the input
         let input_tokens = self.tokenizer.encode(input);
         let tokenized_input = input_tokens
@@ -47,10 +69,16 @@ impl NeuralSolver {
             .collect::<Vec<String>>()
             .join(" ");
 
-        let output = self
-            .generator
-            .generate(&tokenized_input)
-            .map_err(|e| NeuralError::GenerationError(e.to_string()))?;
+        let generated = match cancel {
+            Some(cancel) => self
+                .generator
+                .generate_cancellable(&tokenized_input, cancel),
+            None => self.generator.generate(&tokenized_input),
+        };
+        let output = generated.map_err(|e| match e {
+            GenerationError::Cancelled => NeuralError::Cancelled,
+            other => NeuralError::GenerationError(other.to_string()),
+        })?;
 
         // Estimate confidence (simple heuristic)
         let confidence = self.estimate_confidence(&output);
@@ -157,7 +185,11 @@ impl NeuralSolver {
     }
 
     pub fn adjust_model(&mut self) -> Result<(), NeuralError> {
-        let model = self.generator.get_model();
+        let model = self.generator.get_model().ok_or_else(|| {
+            NeuralError::TrainingError(
+                "feedback adjustment requires a feed-forward model".to_string(),
+            )
+        })?;
         let tokenizer = &self.tokenizer;
 
         self.feedback_adjuster
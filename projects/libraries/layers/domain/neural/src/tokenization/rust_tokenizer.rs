@@ -1,6 +1,6 @@
 // projects/libraries/layers/domain/neural/src/tokenization/rust_tokenizer.rs
 use common_json::{JsonSerializable, json};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de::Error as _};
 use std::collections::HashMap;
 
 use crate::tokenization::TokenizationError;
@@ -8,6 +8,8 @@ use crate::tokenization::TokenizationError;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RustTokenizer {
     token_to_id: HashMap<String, usize>,
+    // JSON object keys are strings, so ids come back as text
+    #[serde(deserialize_with = "deserialize_id_to_token")]
     id_to_token: HashMap<usize, String>,
 
     // Special tokens
@@ -123,6 +125,34 @@ impl RustTokenizer {
         self.token_to_id.len()
     }
 
+    /// Stable hash of the vocabulary (token text and id), used to check that a
+    /// saved model is loaded with the tokenizer it was trained with.
+    pub fn vocab_fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut entries: Vec<(&usize, &String)> = self.id_to_token.iter().collect();
+        entries.sort_unstable_by_key(|(id, _)| **id);
+
+        let mut hash = FNV_OFFSET;
+        for (id, token) in entries {
+            for byte in (*id as u64)
+                .to_le_bytes()
+                .iter()
+                .chain(token.as_bytes())
+                .chain([0xff].iter())
+            {
+                hash ^= u64::from(*byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        hash
+    }
+
+    pub fn eos_token(&self) -> usize {
+        self.eos_token
+    }
+
     pub fn save(&self, path: &std::path::Path) -> Result<(), TokenizationError> {
         let json = self.to_json_string()?;
         std::fs::write(path, json)?;
@@ -135,3 +165,17 @@ impl RustTokenizer {
         Ok(tokenizer)
     }
 }
+
+fn deserialize_id_to_token<'de, D>(deserializer: D) -> Result<HashMap<usize, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    HashMap::<String, String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(id, token)| {
+            id.parse()
+                .map(|id| (id, token))
+                .map_err(|_| D::Error::custom(format!("invalid token id `{id}`")))
+        })
+        .collect()
+}
@@ -0,0 +1,23 @@
+// projects/libraries/layers/domain/neural/src/training/checkpoint_config.rs
+use std::path::PathBuf;
+
+/// Where and how often the trainer writes a [`TrainingCheckpoint`](crate::training::TrainingCheckpoint).
+#[derive(Debug, Clone)]
+pub struct CheckpointConfig {
+    pub path: PathBuf,
+    /// Write a checkpoint every `every_epochs` completed epochs.
+    pub every_epochs: usize,
+}
+
+impl CheckpointConfig {
+    pub fn new(path: impl Into<PathBuf>, every_epochs: usize) -> Self {
+        Self {
+            path: path.into(),
+            every_epochs: every_epochs.max(1),
+        }
+    }
+
+    pub(crate) fn is_due(&self, completed_epochs: usize) -> bool {
+        completed_epochs.is_multiple_of(self.every_epochs.max(1))
+    }
+}
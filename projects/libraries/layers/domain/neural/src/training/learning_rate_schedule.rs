@@ -0,0 +1,36 @@
+// projects/libraries/layers/domain/neural/src/training/learning_rate_schedule.rs
+use std::f64::consts::PI;
+
+/// Learning rate as a function of the epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LearningRateSchedule {
+    #[default]
+    Constant,
+    /// Multiply by `gamma` every `step_size` epochs.
+    StepDecay { step_size: usize, gamma: f64 },
+    /// Multiply by `gamma` every epoch.
+    Exponential { gamma: f64 },
+    /// Cosine annealing from the base rate down to `min_rate` over the run.
+    Cosine { min_rate: f64 },
+}
+
+impl LearningRateSchedule {
+    /// Learning rate for `epoch` (0-based) out of `total_epochs`.
+    pub fn rate(&self, base_rate: f64, epoch: usize, total_epochs: usize) -> f64 {
+        match *self {
+            Self::Constant => base_rate,
+            Self::StepDecay { step_size, gamma } => {
+                base_rate * gamma.powi((epoch / step_size.max(1)) as i32)
+            }
+            Self::Exponential { gamma } => base_rate * gamma.powi(epoch as i32),
+            Self::Cosine { min_rate } => {
+                let progress = if total_epochs <= 1 {
+                    0.0
+                } else {
+                    epoch.min(total_epochs - 1) as f64 / (total_epochs - 1) as f64
+                };
+                min_rate + 0.5 * (base_rate - min_rate) * (1.0 + (PI * progress).cos())
+            }
+        }
+    }
+}
@@ -1,12 +1,22 @@
 // projects/libraries/layers/domain/neural/src/training/mod.rs
 // Module for training models
+pub mod checkpoint_config;
+pub mod learning_rate_schedule;
+pub mod optimizer;
+pub mod optimizer_state;
 pub mod trainer;
+pub mod training_checkpoint;
 pub mod training_config;
 pub mod training_error;
 pub mod training_example;
 pub mod training_metrics;
 
+pub use checkpoint_config::CheckpointConfig;
+pub use learning_rate_schedule::LearningRateSchedule;
+pub use optimizer::Optimizer;
+pub use optimizer_state::OptimizerState;
 pub use trainer::Trainer;
+pub use training_checkpoint::TrainingCheckpoint;
 pub use training_config::TrainingConfig;
 pub use training_error::TrainingError;
 pub use training_example::TrainingExample;
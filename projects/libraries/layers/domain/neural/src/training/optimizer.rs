@@ -0,0 +1,38 @@
+// projects/libraries/layers/domain/neural/src/training/optimizer.rs
+use serde::{Deserialize, Serialize};
+
+/// Update rule applied after each mini-batch.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Optimizer {
+    /// Gradient descent; `momentum == 0.0` is plain SGD.
+    Sgd { momentum: f64 },
+    Adam {
+        beta1: f64,
+        beta2: f64,
+        epsilon: f64,
+    },
+}
+
+impl Optimizer {
+    pub fn sgd() -> Self {
+        Self::Sgd { momentum: 0.0 }
+    }
+
+    pub fn momentum(momentum: f64) -> Self {
+        Self::Sgd { momentum }
+    }
+
+    pub fn adam() -> Self {
+        Self::Adam {
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+        }
+    }
+}
+
+impl Default for Optimizer {
+    fn default() -> Self {
+        Self::adam()
+    }
+}
@@ -0,0 +1,132 @@
+// projects/libraries/layers/domain/neural/src/training/optimizer_state.rs
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    network::{LayerGradients, neural_network::NeuralNetwork},
+    training::{Optimizer, TrainingError},
+};
+
+/// Moment estimates kept between optimizer steps.
+///
+/// Parameters are stored flat, two tensors per layer (weights then biases),
+/// so the state can be checkpointed next to the network it belongs to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OptimizerState {
+    step: u64,
+    /// Velocity for SGD, first moment for Adam.
+    first_moments: Vec<Vec<f64>>,
+    /// Second moment, Adam only.
+    second_moments: Vec<Vec<f64>>,
+}
+
+impl OptimizerState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of optimizer steps applied so far.
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// Apply one update to `network` from gradients averaged over a batch.
+    pub fn apply(
+        &mut self,
+        optimizer: &Optimizer,
+        network: &mut NeuralNetwork,
+        gradients: &[LayerGradients],
+        learning_rate: f64,
+    ) -> Result<(), TrainingError> {
+        let layers = network.layers_mut();
+        if layers.len() != gradients.len() {
+            return Err(TrainingError::DimensionMismatch {
+                expected: layers.len(),
+                actual: gradients.len(),
+            });
+        }
+        self.ensure_shape(
+            layers
+                .iter()
+                .flat_map(|l| [l.weights.len(), l.biases.len()]),
+        )?;
+        self.step += 1;
+        let step = self.step;
+
+        for (index, (layer, grads)) in layers.iter_mut().zip(gradients).enumerate() {
+            let (first, second) = self.moments(index * 2);
+            update_tensor(
+                optimizer,
+                step,
+                learning_rate,
+                layer.weights.iter_mut().zip(grads.weights.iter()),
+                first,
+                second,
+            );
+            let (first, second) = self.moments(index * 2 + 1);
+            update_tensor(
+                optimizer,
+                step,
+                learning_rate,
+                layer.biases.iter_mut().zip(grads.biases.iter()),
+                first,
+                second,
+            );
+        }
+        Ok(())
+    }
+
+    fn moments(&mut self, slot: usize) -> (&mut [f64], &mut [f64]) {
+        (
+            &mut self.first_moments[slot],
+            &mut self.second_moments[slot],
+        )
+    }
+
+    /// Allocate moments on the first step, and reject a state restored for a
+    /// different network.
+    fn ensure_shape(&mut self, sizes: impl Iterator<Item = usize>) -> Result<(), TrainingError> {
+        let sizes: Vec<usize> = sizes.collect();
+        if self.first_moments.is_empty() {
+            self.first_moments = sizes.iter().map(|&n| vec![0.0; n]).collect();
+            self.second_moments = self.first_moments.clone();
+            return Ok(());
+        }
+
+        let current: Vec<usize> = self.first_moments.iter().map(Vec::len).collect();
+        if current != sizes {
+            return Err(TrainingError::InvalidInput(
+                "optimizer state does not match the network shape".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn update_tensor<'a>(
+    optimizer: &Optimizer,
+    step: u64,
+    learning_rate: f64,
+    params: impl Iterator<Item = (&'a mut f64, &'a f64)>,
+    first: &mut [f64],
+    second: &mut [f64],
+) {
+    for (i, (param, &grad)) in params.enumerate() {
+        *param -= match *optimizer {
+            Optimizer::Sgd { momentum } => {
+                first[i] = momentum * first[i] + grad;
+                learning_rate * first[i]
+            }
+            Optimizer::Adam {
+                beta1,
+                beta2,
+                epsilon,
+            } => {
+                first[i] = beta1 * first[i] + (1.0 - beta1) * grad;
+                second[i] = beta2 * second[i] + (1.0 - beta2) * grad * grad;
+                let m_hat = first[i] / (1.0 - beta1.powi(step as i32));
+                let v_hat = second[i] / (1.0 - beta2.powi(step as i32));
+                learning_rate * m_hat / (v_hat.sqrt() + epsilon)
+            }
+        };
+    }
+}
@@ -0,0 +1,20 @@
+// projects/libraries/layers/domain/neural/src/training/tests/learning_rate_schedule.rs
+use crate::training::LearningRateSchedule;
+
+#[test]
+fn test_step_decay() {
+    let schedule = LearningRateSchedule::StepDecay {
+        step_size: 2,
+        gamma: 0.5,
+    };
+    let rates: Vec<f64> = (0..5).map(|epoch| schedule.rate(0.1, epoch, 5)).collect();
+    assert_eq!(rates, vec![0.1, 0.1, 0.05, 0.05, 0.025]);
+}
+
+#[test]
+fn test_cosine_reaches_min_rate_on_last_epoch() {
+    let schedule = LearningRateSchedule::Cosine { min_rate: 0.001 };
+    assert!((schedule.rate(0.1, 0, 10) - 0.1).abs() < 1e-12);
+    assert!((schedule.rate(0.1, 9, 10) - 0.001).abs() < 1e-12);
+    assert!(schedule.rate(0.1, 5, 10) < schedule.rate(0.1, 4, 10));
+}
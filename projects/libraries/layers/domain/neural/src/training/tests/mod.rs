@@ -1,2 +1,4 @@
 // projects/libraries/layers/domain/neural/src/training/tests/mod.rs
+mod learning_rate_schedule;
+mod optimizer;
 mod trainer;
@@ -0,0 +1,73 @@
+// projects/libraries/layers/domain/neural/src/training/tests/optimizer.rs
+use ndarray::{Array1, Array2};
+
+use crate::network::{Activation, LayerGradients, neural_network::NeuralNetwork};
+use crate::tests::test_helpers::create_test_layer_config;
+use crate::training::{Optimizer, OptimizerState};
+
+fn single_weight_network() -> NeuralNetwork {
+    NeuralNetwork::new_seeded(vec![create_test_layer_config(1, 1, Activation::Linear)], 1)
+        .expect("network")
+}
+
+fn unit_gradient() -> Vec<LayerGradients> {
+    vec![LayerGradients {
+        weights: Array2::from_elem((1, 1), 1.0),
+        biases: Array1::from_elem(1, 1.0),
+    }]
+}
+
+#[test]
+fn test_momentum_accumulates_velocity() {
+    let mut network = single_weight_network();
+    let start = network.layers()[0].biases[0];
+    let mut state = OptimizerState::new();
+    let optimizer = Optimizer::momentum(0.9);
+
+    state
+        .apply(&optimizer, &mut network, &unit_gradient(), 0.1)
+        .expect("first step");
+    let after_first = network.layers()[0].biases[0];
+    state
+        .apply(&optimizer, &mut network, &unit_gradient(), 0.1)
+        .expect("second step");
+    let after_second = network.layers()[0].biases[0];
+
+    assert!((start - after_first - 0.1).abs() < 1e-12);
+    // velocity = 0.9 * 1.0 + 1.0
+    assert!((after_first - after_second - 0.19).abs() < 1e-12);
+    assert_eq!(state.step(), 2);
+}
+
+#[test]
+fn test_adam_first_step_is_learning_rate_sized() {
+    let mut network = single_weight_network();
+    let start = network.layers()[0].weights[[0, 0]];
+    let mut state = OptimizerState::new();
+
+    state
+        .apply(&Optimizer::adam(), &mut network, &unit_gradient(), 0.01)
+        .expect("step");
+
+    // Bias correction makes the first Adam step ~lr in the gradient direction.
+    let moved = start - network.layers()[0].weights[[0, 0]];
+    assert!((moved - 0.01).abs() < 1e-6, "moved by {moved}");
+}
+
+#[test]
+fn test_state_rejects_other_network_shape() {
+    let mut network = single_weight_network();
+    let mut state = OptimizerState::new();
+    state
+        .apply(&Optimizer::adam(), &mut network, &unit_gradient(), 0.01)
+        .expect("step");
+
+    let mut other =
+        NeuralNetwork::new(vec![create_test_layer_config(2, 1, Activation::Linear)]).expect("net");
+    let gradients = vec![LayerGradients::zeros_like(&other.layers()[0])];
+    assert!(
+        state
+            .apply(&Optimizer::adam(), &mut other, &gradients, 0.01)
+            .is_err()
+    );
+}
@@ -1,5 +1,9 @@
 // projects/libraries/layers/domain/neural/src/training/tests/trainer.rs
-use crate::training::{Trainer, TrainingConfig};
+use ndarray::Array1;
+
+use crate::network::Activation;
+use crate::tests::test_helpers::create_test_layer_config;
+use crate::training::{CheckpointConfig, Trainer, TrainingConfig, TrainingError, TrainingExample};
 
 #[test]
 fn test_tokenization() {
@@ -41,3 +45,122 @@ fn test_parse_data() {
         "second example should have input tokens"
     );
 }
+
+fn linear_examples(count: usize) -> Vec<TrainingExample> {
+    // y = 0.5 * a - 0.25 * b + 0.1
+    (0..count)
+        .map(|i| {
+            let a = (i % 7) as f64 / 7.0;
+            let b = (i % 5) as f64 / 5.0;
+            TrainingExample {
+                input: Array1::from_vec(vec![a, b]),
+                target: 0.5 * a - 0.25 * b + 0.1,
+            }
+        })
+        .collect()
+}
+
+fn regression_trainer(config: TrainingConfig) -> Trainer {
+    let layers = vec![create_test_layer_config(2, 1, Activation::Linear)];
+    Trainer::with_layers(layers, config).expect("trainer")
+}
+
+fn seeded_config(epochs: usize) -> TrainingConfig {
+    TrainingConfig {
+        learning_rate: 0.05,
+        epochs,
+        batch_size: 8,
+        early_stopping_patience: None,
+        seed: Some(42),
+        accuracy_tolerance: 0.05,
+        ..TrainingConfig::default()
+    }
+}
+
+#[test]
+fn test_mini_batch_adam_reduces_loss() {
+    let mut trainer = regression_trainer(seeded_config(60));
+    let metrics = trainer
+        .train_examples(linear_examples(64))
+        .expect("training should succeed");
+
+    assert_eq!(metrics.epochs_completed(), 60);
+    assert_eq!(metrics.val_accuracies.len(), 60);
+    assert_eq!(metrics.learning_rates.len(), 60);
+    let first = metrics.train_losses[0];
+    let last = *metrics.train_losses.last().expect("losses");
+    assert!(last < first / 10.0, "loss went from {first} to {last}");
+    assert!(metrics.val_accuracies.last().copied().unwrap_or(0.0) > 0.9);
+}
+
+#[test]
+fn test_seeded_runs_are_reproducible() {
+    let run = || {
+        regression_trainer(seeded_config(5))
+            .train_examples(linear_examples(32))
+            .expect("training should succeed")
+            .train_losses
+    };
+    assert_eq!(run(), run());
+}
+
+#[test]
+fn test_early_stopping_restores_best_epoch() {
+    let config = TrainingConfig {
+        learning_rate: 0.0,
+        early_stopping_patience: Some(2),
+        ..seeded_config(50)
+    };
+    let mut trainer = regression_trainer(config);
+    let metrics = trainer
+        .train_examples(linear_examples(32))
+        .expect("training should succeed");
+
+    // With a zero learning rate nothing improves after the first epoch.
+    assert!(metrics.stopped_early);
+    assert_eq!(metrics.epochs_completed(), 3);
+    assert_eq!(metrics.best_epoch, 0);
+}
+
+#[test]
+fn test_rejects_mismatched_input_size() {
+    let mut trainer = regression_trainer(seeded_config(1));
+    let examples = vec![TrainingExample {
+        input: Array1::from_vec(vec![1.0, 2.0, 3.0]),
+        target: 1.0,
+    }];
+    assert!(matches!(
+        trainer.train_examples(examples),
+        Err(TrainingError::DimensionMismatch {
+            expected: 2,
+            actual: 3
+        })
+    ));
+}
+
+#[test]
+fn test_resume_from_checkpoint_matches_uninterrupted_run() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("trainer.ckpt");
+
+    let uninterrupted = regression_trainer(seeded_config(6))
+        .train_examples(linear_examples(32))
+        .expect("training should succeed");
+
+    let first_half = TrainingConfig {
+        checkpoint: Some(CheckpointConfig::new(&path, 3)),
+        ..seeded_config(3)
+    };
+    regression_trainer(first_half)
+        .train_examples(linear_examples(32))
+        .expect("first half");
+
+    let mut resumed = regression_trainer(seeded_config(6));
+    assert_eq!(resumed.resume_from(&path).expect("resume"), 3);
+    let metrics = resumed
+        .train_examples(linear_examples(32))
+        .expect("second half");
+
+    assert_eq!(metrics.train_losses, uninterrupted.train_losses);
+    assert_eq!(metrics.best_epoch, uninterrupted.best_epoch);
+}
@@ -1,15 +1,24 @@
 // projects/libraries/layers/domain/neural/src/training/trainer.rs
+use std::path::Path;
+
 use ndarray::Array1;
+use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
 use tracing::{debug, info};
 
 use crate::{
-    network::{Activation, LayerConfig, SimpleNeuralNet, WeightInit},
-    training::{TrainingConfig, TrainingError, TrainingExample, TrainingMetrics},
+    network::{Activation, LayerConfig, LayerGradients, NetworkError, SimpleNeuralNet, WeightInit},
+    training::{
+        OptimizerState, TrainingCheckpoint, TrainingConfig, TrainingError, TrainingExample,
+        TrainingMetrics,
+    },
 };
 
 pub struct Trainer {
     config: TrainingConfig,
     network: SimpleNeuralNet,
+    optimizer_state: OptimizerState,
+    /// Checkpoint to continue from on the next `train` call.
+    resume_from: Option<TrainingCheckpoint>,
 }
 
 impl Trainer {
@@ -21,10 +30,45 @@ impl Trainer {
             weight_init: WeightInit::He,
         }];
 
-        Self {
+        Self::with_layers(layer_configs, config).expect("network init")
+    }
+
+    /// Trainer for an arbitrary stack of layers.
+    pub fn with_layers(
+        layer_configs: Vec<LayerConfig>,
+        config: TrainingConfig,
+    ) -> Result<Self, TrainingError> {
+        let network = match config.seed {
+            Some(seed) => SimpleNeuralNet::new_seeded(layer_configs, seed),
+            None => SimpleNeuralNet::new(layer_configs),
+        }
+        .map_err(network_error)?;
+
+        Ok(Self {
             config,
-            network: SimpleNeuralNet::new(layer_configs).expect("network init"),
+            network,
+            optimizer_state: OptimizerState::new(),
+            resume_from: None,
+        })
+    }
+
+    /// Load a checkpoint written by a previous run; the next call to
+    /// [`Trainer::train`] continues from its epoch. Returns that epoch.
+    pub fn resume_from(&mut self, path: &Path) -> Result<usize, TrainingError> {
+        let checkpoint = TrainingCheckpoint::load(path)?;
+        if checkpoint.network.input_size() != self.network.input_size() {
+            return Err(TrainingError::DimensionMismatch {
+                expected: self.network.input_size(),
+                actual: checkpoint.network.input_size(),
+            });
         }
+
+        let next_epoch = checkpoint.next_epoch;
+        self.network = checkpoint.network.clone();
+        self.optimizer_state = checkpoint.optimizer_state.clone();
+        self.resume_from = Some(checkpoint);
+        info!("Resuming training from epoch {}", next_epoch + 1);
+        Ok(next_epoch)
     }
 
     /// Parse raw text data into training examples
@@ -51,7 +95,11 @@ impl Trainer {
                     ))
                 })?;
 
-                let input = self.tokenize(input_text)?;
+                let mut input = self.tokenize(input_text)?;
+                // Pad or truncate to the network input size.
+                input = Array1::from_shape_fn(self.network.input_size(), |i| {
+                    input.get(i).copied().unwrap_or(0.0)
+                });
 
                 Ok(TrainingExample { input, target })
             })
@@ -78,96 +126,219 @@ impl Trainer {
     /// Split data into train and validation sets
     fn split_data(
         &self,
-        examples: Vec<TrainingExample>,
+        mut examples: Vec<TrainingExample>,
     ) -> (Vec<TrainingExample>, Vec<TrainingExample>) {
+        if self.config.shuffle {
+            examples.shuffle(&mut self.epoch_rng(None));
+        }
+
         let val_size = (examples.len() as f32 * self.config.validation_split) as usize;
         let train_size = examples.len() - val_size;
 
-        let (train, val) = examples.split_at(train_size);
-        (train.to_vec(), val.to_vec())
+        let val = examples.split_off(train_size);
+        (examples, val)
     }
 
-    /// Calculate loss on a dataset
-    fn calculate_loss(&mut self, examples: &[TrainingExample]) -> f64 {
-        let total_loss: f64 = examples
-            .iter()
-            .filter_map(|ex| {
-                match self.network.forward(&ex.input) {
-                    Ok(output) => {
-                        let error = ex.target - output[0];
-                        Some(error * error) // MSE
-                    }
-                    Err(e) => {
-                        debug!("Error during forward pass: {:?}", e);
-                        None
-                    }
-                }
-            })
-            .sum();
+    /// RNG for shuffling; derived from the seed and epoch so that a resumed
+    /// run replays the same batches.
+    fn epoch_rng(&self, epoch: Option<usize>) -> StdRng {
+        match self.config.seed {
+            Some(seed) => {
+                let offset = epoch.map_or(0, |e| e as u64 + 1);
+                StdRng::seed_from_u64(seed.wrapping_add(offset))
+            }
+            None => StdRng::from_rng(&mut rand::rng()),
+        }
+    }
+
+    fn target_for(&self, example: &TrainingExample) -> Array1<f64> {
+        Array1::from_elem(self.network.output_size(), example.target)
+    }
+
+    /// Mean loss and accuracy on a dataset
+    fn evaluate(&mut self, examples: &[TrainingExample]) -> Result<(f64, f64), TrainingError> {
+        let mut total_loss = 0.0;
+        let mut correct = 0usize;
+
+        for example in examples {
+            let output = self
+                .network
+                .forward(&example.input)
+                .map_err(network_error)?;
+            let n = output.len() as f64;
+            total_loss += output
+                .iter()
+                .map(|o| (example.target - o).powi(2))
+                .sum::<f64>()
+                / n;
+            if (output[0] - example.target).abs() <= self.config.accuracy_tolerance {
+                correct += 1;
+            }
+        }
 
-        total_loss / examples.len() as f64
+        let count = examples.len().max(1) as f64;
+        Ok((total_loss / count, correct as f64 / count))
+    }
+
+    /// One optimizer step on the averaged gradients of `batch`.
+    fn train_batch(
+        &mut self,
+        batch: &[&TrainingExample],
+        learning_rate: f64,
+    ) -> Result<(), TrainingError> {
+        let mut summed: Option<Vec<LayerGradients>> = None;
+
+        for example in batch {
+            self.network
+                .forward(&example.input)
+                .map_err(network_error)?;
+            let target = self.target_for(example);
+            let (loss, gradients) = self.network.gradients(&target).map_err(network_error)?;
+            debug!("Loss: {:.4}", loss);
+
+            match summed.as_mut() {
+                Some(sum) => sum
+                    .iter_mut()
+                    .zip(&gradients)
+                    .for_each(|(s, g)| s.accumulate(g)),
+                None => summed = Some(gradients),
+            }
+        }
+
+        let Some(mut gradients) = summed else {
+            return Ok(());
+        };
+        let scale = 1.0 / batch.len() as f64;
+        gradients.iter_mut().for_each(|g| g.scale(scale));
+
+        self.optimizer_state.apply(
+            &self.config.optimizer,
+            &mut self.network,
+            &gradients,
+            learning_rate,
+        )
     }
 
     /// Train the model
     pub fn train(&mut self, data: &str) -> Result<TrainingMetrics, TrainingError> {
+        let examples = self.parse_data(data)?;
+        self.train_examples(examples)
+    }
+
+    /// Train the model on already tokenized examples.
+    ///
+    /// On return the network holds the weights of the epoch with the best
+    /// validation loss.
+    pub fn train_examples(
+        &mut self,
+        examples: Vec<TrainingExample>,
+    ) -> Result<TrainingMetrics, TrainingError> {
         info!("Starting training");
 
-        // Parse and split data
-        let examples = self.parse_data(data)?;
+        let input_size = self.network.input_size();
+        if let Some(example) = examples.iter().find(|ex| ex.input.len() != input_size) {
+            return Err(TrainingError::DimensionMismatch {
+                expected: input_size,
+                actual: example.input.len(),
+            });
+        }
+
         let (train_examples, val_examples) = self.split_data(examples);
+        if train_examples.is_empty() {
+            return Err(TrainingError::InvalidInput(
+                "No training examples left after the validation split".to_string(),
+            ));
+        }
 
         info!("Training set: {} examples", train_examples.len());
         info!("Validation set: {} examples", val_examples.len());
 
-        let mut metrics = TrainingMetrics {
-            train_losses: Vec::with_capacity(self.config.epochs),
-            val_losses: Vec::with_capacity(self.config.epochs),
-            best_epoch: 0,
-            final_loss: f64::MAX,
-        };
+        let epochs = self.config.epochs;
+        let (start_epoch, mut metrics, mut best_val_loss, mut patience_counter, mut best_network) =
+            match self.resume_from.take() {
+                Some(checkpoint) => (
+                    checkpoint.next_epoch,
+                    checkpoint.metrics,
+                    checkpoint.best_val_loss,
+                    checkpoint.patience_counter,
+                    checkpoint.best_network,
+                ),
+                None => (
+                    0,
+                    TrainingMetrics::with_capacity(epochs),
+                    f64::MAX,
+                    0,
+                    self.network.clone(),
+                ),
+            };
 
-        let mut best_val_loss = f64::MAX;
-        let mut patience_counter = 0;
+        let batch_size = self.config.batch_size.max(1);
 
         // Training loop
-        for epoch in 0..self.config.epochs {
-            debug!("Epoch {}/{}", epoch + 1, self.config.epochs);
-
-            // Train on all examples
-            for example in &train_examples {
-                if let Ok(output) = self.network.forward(&example.input) {
-                    let error = example.target - output[0];
-                    debug!("Error: {:.4}", error);
-                    // Placeholder for backpropagation logic
-                    // self.network.backpropagate(&example.input, error, self.config.learning_rate);
-                } else {
-                    debug!("Skipping example due to forward pass error");
-                }
+        for epoch in start_epoch..epochs {
+            debug!("Epoch {}/{}", epoch + 1, epochs);
+            let learning_rate = self
+                .config
+                .schedule
+                .rate(self.config.learning_rate, epoch, epochs);
+
+            let mut order: Vec<&TrainingExample> = train_examples.iter().collect();
+            if self.config.shuffle {
+                order.shuffle(&mut self.epoch_rng(Some(epoch)));
+            }
+            for batch in order.chunks(batch_size) {
+                self.train_batch(batch, learning_rate)?;
             }
 
-            // Calculate losses
-            let train_loss = self.calculate_loss(&train_examples);
-            let val_loss = self.calculate_loss(&val_examples);
+            // Calculate losses; without a validation set the training loss is tracked.
+            let (train_loss, train_accuracy) = self.evaluate(&train_examples)?;
+            let (val_loss, val_accuracy) = if val_examples.is_empty() {
+                (train_loss, train_accuracy)
+            } else {
+                self.evaluate(&val_examples)?
+            };
 
             metrics.train_losses.push(train_loss);
             metrics.val_losses.push(val_loss);
+            metrics.train_accuracies.push(train_accuracy);
+            metrics.val_accuracies.push(val_accuracy);
+            metrics.learning_rates.push(learning_rate);
 
             info!(
-                "Epoch {}: train_loss={:.4}, val_loss={:.4}",
+                "Epoch {}: lr={:.6}, train_loss={:.4}, val_loss={:.4}, val_accuracy={:.2}",
                 epoch + 1,
+                learning_rate,
                 train_loss,
-                val_loss
+                val_loss,
+                val_accuracy
             );
 
             // Track best model
-            if val_loss < best_val_loss {
+            if val_loss < best_val_loss - self.config.min_delta {
                 best_val_loss = val_loss;
                 metrics.best_epoch = epoch;
+                best_network = self.network.clone();
                 patience_counter = 0;
             } else {
                 patience_counter += 1;
             }
 
+            if let Some(checkpoint) = &self.config.checkpoint
+                && checkpoint.is_due(epoch + 1)
+            {
+                TrainingCheckpoint {
+                    next_epoch: epoch + 1,
+                    network: self.network.clone(),
+                    best_network: best_network.clone(),
+                    optimizer_state: self.optimizer_state.clone(),
+                    metrics: metrics.clone(),
+                    best_val_loss,
+                    patience_counter,
+                }
+                .save(&checkpoint.path)?;
+                debug!("Checkpoint written to {}", checkpoint.path.display());
+            }
+
             // Early stopping
             if self
                 .config
@@ -175,10 +346,12 @@ impl Trainer {
                 .is_some_and(|patience| patience_counter >= patience)
             {
                 info!("Early stopping at epoch {}", epoch + 1);
+                metrics.stopped_early = true;
                 break;
             }
         }
 
+        self.network = best_network;
         metrics.final_loss = best_val_loss;
         info!(
             "Training complete. Best validation loss: {:.4}",
@@ -191,4 +364,13 @@ impl Trainer {
     pub fn network(&self) -> &SimpleNeuralNet {
         &self.network
     }
+
+    /// Run the trained network on one input.
+    pub fn predict(&mut self, input: &Array1<f64>) -> Result<Array1<f64>, TrainingError> {
+        self.network.forward(input).map_err(network_error)
+    }
+}
+
+fn network_error(error: NetworkError) -> TrainingError {
+    TrainingError::NetworkError(error.to_string())
 }
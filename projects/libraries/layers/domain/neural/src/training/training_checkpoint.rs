@@ -0,0 +1,47 @@
+// projects/libraries/layers/domain/neural/src/training/training_checkpoint.rs
+use std::path::Path;
+
+use common_binary::{BinaryOptions, read_binary, write_binary};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    network::neural_network::NeuralNetwork,
+    training::{OptimizerState, TrainingError, TrainingMetrics},
+};
+
+const CHECKPOINT_MAGIC: [u8; 4] = *b"NNCK";
+const CHECKPOINT_SCHEMA_ID: u64 = 1;
+
+/// Everything needed to resume an interrupted training run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingCheckpoint {
+    /// First epoch to run when resuming.
+    pub next_epoch: usize,
+    pub network: NeuralNetwork,
+    /// Weights of the epoch with the best validation loss so far.
+    pub best_network: NeuralNetwork,
+    pub optimizer_state: OptimizerState,
+    pub metrics: TrainingMetrics,
+    pub best_val_loss: f64,
+    pub patience_counter: usize,
+}
+
+impl TrainingCheckpoint {
+    fn options() -> BinaryOptions {
+        BinaryOptions {
+            magic: CHECKPOINT_MAGIC,
+            container_version: 1,
+            schema_id: CHECKPOINT_SCHEMA_ID,
+            verify_checksum: true,
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), TrainingError> {
+        write_binary(self, path, &Self::options())?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, TrainingError> {
+        Ok(read_binary(path, &Self::options())?)
+    }
+}
@@ -1,4 +1,6 @@
 // projects/libraries/layers/domain/neural/src/training/training_config.rs
+use crate::training::{CheckpointConfig, LearningRateSchedule, Optimizer};
+
 #[derive(Debug, Clone)]
 pub struct TrainingConfig {
     pub learning_rate: f64,
@@ -6,6 +8,17 @@ pub struct TrainingConfig {
     pub batch_size: usize,
     pub validation_split: f32,
     pub early_stopping_patience: Option<usize>,
+    /// Minimum validation loss improvement that resets the patience counter.
+    pub min_delta: f64,
+    pub optimizer: Optimizer,
+    pub schedule: LearningRateSchedule,
+    /// Shuffle examples before the split and between epochs.
+    pub shuffle: bool,
+    /// Seeds weight init and shuffling; `None` draws from the thread RNG.
+    pub seed: Option<u64>,
+    /// A prediction counts as correct when within this distance of the target.
+    pub accuracy_tolerance: f64,
+    pub checkpoint: Option<CheckpointConfig>,
 }
 
 impl Default for TrainingConfig {
@@ -16,6 +29,13 @@ impl Default for TrainingConfig {
             batch_size: 32,
             validation_split: 0.2,
             early_stopping_patience: Some(10),
+            min_delta: 0.0,
+            optimizer: Optimizer::default(),
+            schedule: LearningRateSchedule::default(),
+            shuffle: true,
+            seed: None,
+            accuracy_tolerance: 0.5,
+            checkpoint: None,
         }
     }
 }
@@ -9,4 +9,6 @@ pub enum TrainingError {
     NetworkError(String),
     #[error("Dimension mismatch: expected {expected}, got {actual}")]
     DimensionMismatch { expected: usize, actual: usize },
+    #[error("Checkpoint error: {0}")]
+    Checkpoint(#[from] common_binary::BinaryError),
 }
@@ -1,8 +1,34 @@
 // projects/libraries/layers/domain/neural/src/training/training_metrics.rs
-#[derive(Debug)]
+use serde::{Deserialize, Serialize};
+
+/// Per-epoch curves of a training run; index `i` is epoch `i`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrainingMetrics {
     pub train_losses: Vec<f64>,
     pub val_losses: Vec<f64>,
+    pub train_accuracies: Vec<f64>,
+    pub val_accuracies: Vec<f64>,
+    pub learning_rates: Vec<f64>,
     pub best_epoch: usize,
     pub final_loss: f64,
+    pub stopped_early: bool,
+}
+
+impl TrainingMetrics {
+    pub(crate) fn with_capacity(epochs: usize) -> Self {
+        Self {
+            train_losses: Vec::with_capacity(epochs),
+            val_losses: Vec::with_capacity(epochs),
+            train_accuracies: Vec::with_capacity(epochs),
+            val_accuracies: Vec::with_capacity(epochs),
+            learning_rates: Vec::with_capacity(epochs),
+            best_epoch: 0,
+            final_loss: f64::MAX,
+            stopped_early: false,
+        }
+    }
+
+    pub fn epochs_completed(&self) -> usize {
+        self.train_losses.len()
+    }
 }
@@ -0,0 +1,33 @@
+// projects/libraries/layers/domain/neural/src/transformer/embedding.rs
+use ndarray::Array2;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::transformer::{TransformerError, math::random_matrix};
+
+/// Token embedding table, one `d_model` row per vocabulary entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Embedding {
+    pub table: Array2<f64>,
+}
+
+impl Embedding {
+    pub fn new<R: Rng + ?Sized>(vocab_size: usize, d_model: usize, rng: &mut R) -> Self {
+        Self {
+            table: random_matrix(vocab_size, d_model, (1.0 / d_model as f64).sqrt(), rng),
+        }
+    }
+
+    pub fn vocab_size(&self) -> usize {
+        self.table.nrows()
+    }
+
+    /// `(tokens.len(), d_model)` matrix of embeddings.
+    pub fn lookup(&self, tokens: &[usize]) -> Result<Array2<f64>, TransformerError> {
+        let vocab_size = self.vocab_size();
+        if let Some(&token) = tokens.iter().find(|&&t| t >= vocab_size) {
+            return Err(TransformerError::TokenOutOfRange { token, vocab_size });
+        }
+        Ok(self.table.select(ndarray::Axis(0), tokens))
+    }
+}
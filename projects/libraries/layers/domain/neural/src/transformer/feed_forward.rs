@@ -0,0 +1,31 @@
+// projects/libraries/layers/domain/neural/src/transformer/feed_forward.rs
+use ndarray::{Array1, Array2};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::transformer::math::{gelu, linear, random_matrix};
+
+/// Position-wise `d_model -> d_ff -> d_model` MLP with GELU.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedForward {
+    pub w1: Array2<f64>,
+    pub b1: Array1<f64>,
+    pub w2: Array2<f64>,
+    pub b2: Array1<f64>,
+}
+
+impl FeedForward {
+    pub fn new<R: Rng + ?Sized>(d_model: usize, d_ff: usize, rng: &mut R) -> Self {
+        Self {
+            w1: random_matrix(d_ff, d_model, (2.0 / d_model as f64).sqrt(), rng),
+            b1: Array1::zeros(d_ff),
+            w2: random_matrix(d_model, d_ff, (1.0 / d_ff as f64).sqrt(), rng),
+            b2: Array1::zeros(d_model),
+        }
+    }
+
+    pub fn forward(&self, x: &Array2<f64>) -> Array2<f64> {
+        let hidden = linear(x, &self.w1, &self.b1).mapv(gelu);
+        linear(&hidden, &self.w2, &self.b2)
+    }
+}
@@ -0,0 +1,81 @@
+// projects/libraries/layers/domain/neural/src/transformer/kv_cache.rs
+use ndarray::{Array2, Axis};
+
+use crate::transformer::TransformerError;
+
+/// Keys and values of the tokens already processed by one attention layer.
+#[derive(Debug, Clone)]
+pub struct LayerKvCache {
+    pub(crate) keys: Array2<f64>,
+    pub(crate) values: Array2<f64>,
+}
+
+impl LayerKvCache {
+    pub fn new(d_model: usize) -> Self {
+        Self {
+            keys: Array2::zeros((0, d_model)),
+            values: Array2::zeros((0, d_model)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.nrows()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Fails without changing the cache when a row width differs from it.
+    pub(crate) fn append(
+        &mut self,
+        keys: &Array2<f64>,
+        values: &Array2<f64>,
+    ) -> Result<(), TransformerError> {
+        let expected = self.keys.ncols();
+        let mismatch = |actual| TransformerError::CacheWidthMismatch { expected, actual };
+        for actual in [keys.ncols(), values.ncols()] {
+            if actual != expected {
+                return Err(mismatch(actual));
+            }
+        }
+        self.keys
+            .append(Axis(0), keys.view())
+            .map_err(|_| mismatch(keys.ncols()))?;
+        self.values
+            .append(Axis(0), values.view())
+            .map_err(|_| mismatch(values.ncols()))
+    }
+}
+
+/// Per-layer caches for autoregressive decoding, so each new token only
+/// attends over stored keys/values instead of recomputing the prefix.
+#[derive(Debug, Clone)]
+pub struct KvCache {
+    pub(crate) layers: Vec<LayerKvCache>,
+}
+
+impl KvCache {
+    pub fn new(num_layers: usize, d_model: usize) -> Self {
+        Self {
+            layers: (0..num_layers)
+                .map(|_| LayerKvCache::new(d_model))
+                .collect(),
+        }
+    }
+
+    /// Number of cached positions.
+    pub fn len(&self) -> usize {
+        self.layers.first().map_or(0, LayerKvCache::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        for layer in &mut self.layers {
+            *layer = LayerKvCache::new(layer.keys.ncols());
+        }
+    }
+}
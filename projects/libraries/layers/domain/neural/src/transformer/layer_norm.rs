@@ -0,0 +1,34 @@
+// projects/libraries/layers/domain/neural/src/transformer/layer_norm.rs
+use ndarray::{Array1, Array2, Axis};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerNorm {
+    pub gamma: Array1<f64>,
+    pub beta: Array1<f64>,
+    pub epsilon: f64,
+}
+
+impl LayerNorm {
+    pub fn new(d_model: usize) -> Self {
+        Self {
+            gamma: Array1::ones(d_model),
+            beta: Array1::zeros(d_model),
+            epsilon: 1e-5,
+        }
+    }
+
+    /// Normalize every row to zero mean and unit variance, then scale and shift.
+    pub fn forward(&self, x: &Array2<f64>) -> Array2<f64> {
+        let mut out = x.clone();
+        for mut row in out.axis_iter_mut(Axis(0)) {
+            let mean = row.mean().unwrap_or(0.0);
+            let variance = row.mapv(|v| (v - mean).powi(2)).mean().unwrap_or(0.0);
+            let scale = 1.0 / (variance + self.epsilon).sqrt();
+            row.mapv_inplace(|v| (v - mean) * scale);
+            row *= &self.gamma;
+            row += &self.beta;
+        }
+        out
+    }
+}
@@ -0,0 +1,36 @@
+// projects/libraries/layers/domain/neural/src/transformer/math.rs
+use ndarray::{Array1, Array2, ArrayViewMut1};
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
+
+/// `(rows, cols)` matrix drawn from `N(0, std_dev²)`.
+pub(crate) fn random_matrix<R: Rng + ?Sized>(
+    rows: usize,
+    cols: usize,
+    std_dev: f64,
+    rng: &mut R,
+) -> Array2<f64> {
+    let normal = Normal::new(0.0, std_dev).expect("valid normal distribution");
+    Array2::from_shape_fn((rows, cols), |_| normal.sample(rng))
+}
+
+/// `x · wᵀ + b` for a batch of row vectors, weights stored `(out, in)`.
+pub(crate) fn linear(x: &Array2<f64>, weights: &Array2<f64>, bias: &Array1<f64>) -> Array2<f64> {
+    x.dot(&weights.t()) + bias
+}
+
+/// Numerically stable softmax over a row, in place.
+pub(crate) fn softmax_in_place(mut row: ArrayViewMut1<f64>) {
+    let max = row.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    row.mapv_inplace(|x| (x - max).exp());
+    let sum = row.sum();
+    if sum > 0.0 {
+        row /= sum;
+    }
+}
+
+/// tanh approximation of GELU.
+pub(crate) fn gelu(x: f64) -> f64 {
+    const SQRT_2_OVER_PI: f64 = 0.797_884_560_802_865_4;
+    0.5 * x * (1.0 + (SQRT_2_OVER_PI * (x + 0.044_715 * x.powi(3))).tanh())
+}
@@ -0,0 +1,27 @@
+// projects/libraries/layers/domain/neural/src/transformer/mod.rs
+// Small CPU-only transformer used for autoregressive code generation
+pub mod embedding;
+pub mod feed_forward;
+pub mod kv_cache;
+pub mod layer_norm;
+mod math;
+pub mod multi_head_attention;
+pub mod positional_encoding;
+pub mod transformer_block;
+pub mod transformer_config;
+pub mod transformer_error;
+pub mod transformer_model;
+
+pub use embedding::Embedding;
+pub use feed_forward::FeedForward;
+pub use kv_cache::{KvCache, LayerKvCache};
+pub use layer_norm::LayerNorm;
+pub use multi_head_attention::MultiHeadAttention;
+pub use positional_encoding::positional_encoding;
+pub use transformer_block::TransformerBlock;
+pub use transformer_config::TransformerConfig;
+pub use transformer_error::TransformerError;
+pub use transformer_model::TransformerModel;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+// projects/libraries/layers/domain/neural/src/transformer/multi_head_attention.rs
+use ndarray::{Array1, Array2, Axis, s};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::transformer::{
+    LayerKvCache, TransformerError,
+    math::{linear, random_matrix, softmax_in_place},
+};
+
+/// Causal multi-head self-attention.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiHeadAttention {
+    pub num_heads: usize,
+    pub w_q: Array2<f64>,
+    pub w_k: Array2<f64>,
+    pub w_v: Array2<f64>,
+    pub w_o: Array2<f64>,
+    pub b_o: Array1<f64>,
+}
+
+impl MultiHeadAttention {
+    pub fn new<R: Rng + ?Sized>(d_model: usize, num_heads: usize, rng: &mut R) -> Self {
+        let std_dev = (1.0 / d_model as f64).sqrt();
+        Self {
+            num_heads,
+            w_q: random_matrix(d_model, d_model, std_dev, rng),
+            w_k: random_matrix(d_model, d_model, std_dev, rng),
+            w_v: random_matrix(d_model, d_model, std_dev, rng),
+            w_o: random_matrix(d_model, d_model, std_dev, rng),
+            b_o: Array1::zeros(d_model),
+        }
+    }
+
+    /// Attend `x` (one row per new position) over itself and, when given,
+    /// the cached prefix. The keys/values of `x` are appended to `cache`.
+    pub fn forward(
+        &self,
+        x: &Array2<f64>,
+        cache: Option<&mut LayerKvCache>,
+    ) -> Result<Array2<f64>, TransformerError> {
+        let d_model = self.w_q.nrows();
+        let no_bias = Array1::zeros(d_model);
+        let queries = linear(x, &self.w_q, &no_bias);
+        let new_keys = linear(x, &self.w_k, &no_bias);
+        let new_values = linear(x, &self.w_v, &no_bias);
+
+        let (keys, values, offset) = match cache {
+            Some(cache) => {
+                let offset = cache.len();
+                cache.append(&new_keys, &new_values)?;
+                (cache.keys.view(), cache.values.view(), offset)
+            }
+            None => (new_keys.view(), new_values.view(), 0),
+        };
+
+        let head_dim = d_model / self.num_heads;
+        let scale = 1.0 / (head_dim as f64).sqrt();
+        let mut heads = Array2::zeros((x.nrows(), d_model));
+
+        for head in 0..self.num_heads {
+            let cols = s![.., head * head_dim..(head + 1) * head_dim];
+            let q = queries.slice(cols);
+            let k = keys.slice(cols);
+            let v = values.slice(cols);
+
+            let mut scores = q.dot(&k.t()) * scale;
+            for (row, mut weights) in scores.axis_iter_mut(Axis(0)).enumerate() {
+                // Position `offset + row` may only see itself and earlier tokens.
+                weights
+                    .slice_mut(s![offset + row + 1..])
+                    .fill(f64::NEG_INFINITY);
+                softmax_in_place(weights);
+            }
+            heads.slice_mut(cols).assign(&scores.dot(&v));
+        }
+
+        Ok(linear(&heads, &self.w_o, &self.b_o))
+    }
+}
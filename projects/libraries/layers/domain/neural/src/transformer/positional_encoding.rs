@@ -0,0 +1,16 @@
+// projects/libraries/layers/domain/neural/src/transformer/positional_encoding.rs
+use ndarray::Array2;
+
+/// Sinusoidal positional encoding for positions `start..start + len`.
+pub fn positional_encoding(start: usize, len: usize, d_model: usize) -> Array2<f64> {
+    Array2::from_shape_fn((len, d_model), |(row, col)| {
+        let position = (start + row) as f64;
+        let pair = (col / 2) as f64;
+        let angle = position / 10_000f64.powf(2.0 * pair / d_model as f64);
+        if col % 2 == 0 {
+            angle.sin()
+        } else {
+            angle.cos()
+        }
+    })
+}
@@ -0,0 +1,61 @@
+// projects/libraries/layers/domain/neural/src/transformer/tests/layers.rs
+use ndarray::{Array2, Axis};
+
+use crate::transformer::{
+    LayerKvCache, LayerNorm, TransformerConfig, TransformerError, positional_encoding,
+};
+
+#[test]
+fn test_layer_norm_normalizes_rows() {
+    let norm = LayerNorm::new(4);
+    let x = Array2::from_shape_vec((2, 4), vec![1.0, 2.0, 3.0, 4.0, -5.0, 0.0, 5.0, 10.0])
+        .expect("shape");
+
+    let out = norm.forward(&x);
+    for row in out.axis_iter(Axis(0)) {
+        let mean = row.mean().expect("mean");
+        let variance = row.mapv(|v| (v - mean).powi(2)).mean().expect("variance");
+        assert!(mean.abs() < 1e-9, "mean {mean}");
+        assert!((variance - 1.0).abs() < 1e-3, "variance {variance}");
+    }
+}
+
+#[test]
+fn test_positional_encoding_is_offset_consistent() {
+    let full = positional_encoding(0, 6, 8);
+    let tail = positional_encoding(4, 2, 8);
+    assert_eq!(full.slice(ndarray::s![4.., ..]), tail);
+    assert_eq!(full[[0, 0]], 0.0);
+    assert_eq!(full[[0, 1]], 1.0);
+}
+
+#[test]
+fn test_config_requires_divisible_heads() {
+    let config = TransformerConfig {
+        d_model: 10,
+        num_heads: 4,
+        ..TransformerConfig::for_vocab(8)
+    };
+    assert!(matches!(
+        config.validate(),
+        Err(TransformerError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_kv_cache_rejects_rows_of_another_width() {
+    let mut cache = LayerKvCache::new(4);
+    cache
+        .append(&Array2::zeros((2, 4)), &Array2::zeros((2, 4)))
+        .expect("append");
+    assert!(matches!(
+        cache.append(&Array2::zeros((1, 4)), &Array2::zeros((1, 3))),
+        Err(TransformerError::CacheWidthMismatch {
+            expected: 4,
+            actual: 3
+        })
+    ));
+    // Keys are not appended when the values are refused
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.values.nrows(), 2);
+}
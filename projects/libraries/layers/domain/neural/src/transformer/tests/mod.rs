@@ -0,0 +1,3 @@
+// projects/libraries/layers/domain/neural/src/transformer/tests/mod.rs
+mod layers;
+mod transformer_model;
@@ -0,0 +1,115 @@
+// projects/libraries/layers/domain/neural/src/transformer/tests/transformer_model.rs
+use ndarray::s;
+
+use crate::tokenization::RustTokenizer;
+use crate::transformer::{TransformerConfig, TransformerError, TransformerModel};
+
+fn small_config(vocab_size: usize) -> TransformerConfig {
+    TransformerConfig {
+        d_model: 16,
+        num_heads: 4,
+        num_layers: 2,
+        d_ff: 32,
+        max_seq_len: 16,
+        ..TransformerConfig::for_vocab(vocab_size)
+    }
+}
+
+fn small_model(vocab_size: usize) -> TransformerModel {
+    TransformerModel::new_seeded(small_config(vocab_size), 3).expect("model")
+}
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{a} != {b}");
+}
+
+#[test]
+fn test_kv_cached_decoding_matches_full_forward() {
+    let model = small_model(12);
+    let tokens = [2, 5, 7, 1, 9];
+
+    let full = model.forward(&tokens).expect("forward");
+    assert_eq!(full.dim(), (5, 12));
+
+    let mut cache = model.new_cache();
+    let prefill = model
+        .forward_cached(&tokens[..3], &mut cache)
+        .expect("prefill");
+    let step_4 = model
+        .forward_cached(&tokens[3..4], &mut cache)
+        .expect("step");
+    let step_5 = model
+        .forward_cached(&tokens[4..], &mut cache)
+        .expect("step");
+    assert_eq!(cache.len(), 5);
+
+    for (cached, row) in [(prefill.row(2), 2), (step_4.row(0), 3), (step_5.row(0), 4)] {
+        for (a, b) in cached.iter().zip(full.row(row)) {
+            assert_close(*a, *b);
+        }
+    }
+}
+
+#[test]
+fn test_attention_is_causal() {
+    let model = small_model(12);
+    let a = model.forward(&[2, 5, 7, 1]).expect("forward");
+    let b = model.forward(&[2, 5, 7, 11]).expect("forward");
+
+    // Changing the last token must not affect earlier positions.
+    for (x, y) in a.slice(s![..3, ..]).iter().zip(b.slice(s![..3, ..])) {
+        assert_close(*x, *y);
+    }
+    assert_ne!(a.row(3), b.row(3));
+}
+
+#[test]
+fn test_rejects_out_of_range_tokens_and_long_sequences() {
+    let model = small_model(12);
+    assert!(matches!(
+        model.forward(&[2, 12]),
+        Err(TransformerError::TokenOutOfRange {
+            token: 12,
+            vocab_size: 12
+        })
+    ));
+    assert!(matches!(
+        model.forward(&[1; 17]),
+        Err(TransformerError::SequenceTooLong { max_len: 16 })
+    ));
+}
+
+#[test]
+fn test_train_head_reduces_next_token_loss() {
+    let mut model = small_model(12);
+    let tokens = [2, 4, 5, 6, 7, 1];
+
+    let first = model.train_head(&tokens, 0.5).expect("train");
+    let mut last = first;
+    for _ in 0..50 {
+        last = model.train_head(&tokens, 0.5).expect("train");
+    }
+    assert!(last < first / 2.0, "loss went from {first} to {last}");
+}
+
+#[test]
+fn test_save_and_load_are_tied_to_the_vocabulary() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("model.bin");
+    let tokenizer = RustTokenizer::new(vec!["fn".into(), "main".into(), "(".into(), ")".into()]);
+    let model = small_model(tokenizer.vocab_size());
+
+    model.save(&path, &tokenizer).expect("save");
+    let loaded = TransformerModel::load(&path, &tokenizer).expect("load");
+    assert_eq!(
+        loaded.forward(&[2, 4, 5]).expect("forward"),
+        model.forward(&[2, 4, 5]).expect("forward")
+    );
+
+    // Same size, different tokens.
+    let other = RustTokenizer::new(vec!["fn".into(), "main".into(), "{".into(), "}".into()]);
+    assert!(matches!(
+        TransformerModel::load(&path, &other),
+        Err(TransformerError::VocabularyMismatch { .. })
+    ));
+}
@@ -0,0 +1,47 @@
+// projects/libraries/layers/domain/neural/src/transformer/transformer_block.rs
+use ndarray::Array2;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::transformer::{
+    FeedForward, LayerKvCache, LayerNorm, MultiHeadAttention, TransformerError,
+};
+
+/// Pre-norm block: `x + attn(ln(x))`, then `x + ff(ln(x))`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformerBlock {
+    pub attention_norm: LayerNorm,
+    pub attention: MultiHeadAttention,
+    pub feed_forward_norm: LayerNorm,
+    pub feed_forward: FeedForward,
+}
+
+impl TransformerBlock {
+    pub fn new<R: Rng + ?Sized>(
+        d_model: usize,
+        num_heads: usize,
+        d_ff: usize,
+        rng: &mut R,
+    ) -> Self {
+        Self {
+            attention_norm: LayerNorm::new(d_model),
+            attention: MultiHeadAttention::new(d_model, num_heads, rng),
+            feed_forward_norm: LayerNorm::new(d_model),
+            feed_forward: FeedForward::new(d_model, d_ff, rng),
+        }
+    }
+
+    pub fn forward(
+        &self,
+        x: &Array2<f64>,
+        cache: Option<&mut LayerKvCache>,
+    ) -> Result<Array2<f64>, TransformerError> {
+        let x = x + &self
+            .attention
+            .forward(&self.attention_norm.forward(x), cache)?;
+        Ok(&x
+            + &self
+                .feed_forward
+                .forward(&self.feed_forward_norm.forward(&x)))
+    }
+}
@@ -0,0 +1,56 @@
+// projects/libraries/layers/domain/neural/src/transformer/transformer_config.rs
+use serde::{Deserialize, Serialize};
+
+use crate::transformer::TransformerError;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransformerConfig {
+    pub vocab_size: usize,
+    pub d_model: usize,
+    pub num_heads: usize,
+    pub num_layers: usize,
+    /// Hidden size of the feed-forward sublayer.
+    pub d_ff: usize,
+    /// Longest sequence (prompt + generated tokens) the model accepts.
+    pub max_seq_len: usize,
+}
+
+impl TransformerConfig {
+    /// Defaults sized for a CPU, for a tokenizer with `vocab_size` tokens.
+    pub fn for_vocab(vocab_size: usize) -> Self {
+        Self {
+            vocab_size,
+            d_model: 64,
+            num_heads: 4,
+            num_layers: 2,
+            d_ff: 256,
+            max_seq_len: 512,
+        }
+    }
+
+    pub fn head_dim(&self) -> usize {
+        self.d_model / self.num_heads.max(1)
+    }
+
+    pub fn validate(&self) -> Result<(), TransformerError> {
+        let sizes = [
+            ("vocab_size", self.vocab_size),
+            ("d_model", self.d_model),
+            ("num_heads", self.num_heads),
+            ("d_ff", self.d_ff),
+            ("max_seq_len", self.max_seq_len),
+        ];
+        if let Some((name, _)) = sizes.iter().find(|(_, size)| *size == 0) {
+            return Err(TransformerError::InvalidConfig(format!(
+                "{name} must be greater than zero"
+            )));
+        }
+        if !self.d_model.is_multiple_of(self.num_heads) {
+            return Err(TransformerError::InvalidConfig(format!(
+                "d_model ({}) must be divisible by num_heads ({})",
+                self.d_model, self.num_heads
+            )));
+        }
+        Ok(())
+    }
+}
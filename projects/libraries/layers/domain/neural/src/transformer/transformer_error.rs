@@ -0,0 +1,20 @@
+// projects/libraries/layers/domain/neural/src/transformer/transformer_error.rs
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TransformerError {
+    #[error("Invalid transformer configuration: {0}")]
+    InvalidConfig(String),
+    #[error("Token {token} is outside the vocabulary of size {vocab_size}")]
+    TokenOutOfRange { token: usize, vocab_size: usize },
+    #[error("Sequence exceeds the maximum length of {max_len} tokens")]
+    SequenceTooLong { max_len: usize },
+    #[error("KV cache holds rows of width {expected}, got {actual}")]
+    CacheWidthMismatch { expected: usize, actual: usize },
+    #[error(
+        "Model was trained with a different vocabulary (expected fingerprint {expected:016x}, got {actual:016x})"
+    )]
+    VocabularyMismatch { expected: u64, actual: u64 },
+    #[error("Model persistence error: {0}")]
+    Persistence(#[from] common_binary::BinaryError),
+}
@@ -0,0 +1,204 @@
+// projects/libraries/layers/domain/neural/src/transformer/transformer_model.rs
+use std::path::Path;
+
+use common_binary::{BinaryOptions, read_binary, write_binary};
+use ndarray::{Array1, Array2, Axis};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+
+use crate::tokenization::RustTokenizer;
+use crate::transformer::{
+    Embedding, KvCache, LayerNorm, TransformerBlock, TransformerConfig, TransformerError,
+    math::{linear, random_matrix, softmax_in_place},
+    positional_encoding,
+};
+
+const MODEL_MAGIC: [u8; 4] = *b"NTRF";
+const MODEL_SCHEMA_ID: u64 = 1;
+
+/// Decoder-only transformer producing next-token logits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformerModel {
+    config: TransformerConfig,
+    embedding: Embedding,
+    blocks: Vec<TransformerBlock>,
+    final_norm: LayerNorm,
+    /// Output projection `(vocab_size, d_model)`.
+    lm_head: Array2<f64>,
+    lm_bias: Array1<f64>,
+}
+
+/// On-disk form: the model plus the vocabulary it was built for.
+#[derive(Serialize, Deserialize)]
+struct ModelFile {
+    vocab_fingerprint: u64,
+    model: TransformerModel,
+}
+
+impl TransformerModel {
+    pub fn new(config: TransformerConfig) -> Result<Self, TransformerError> {
+        Self::build(config, &mut rand::rng())
+    }
+
+    /// Same as [`TransformerModel::new`], with weights initialized from `seed`.
+    pub fn new_seeded(config: TransformerConfig, seed: u64) -> Result<Self, TransformerError> {
+        Self::build(config, &mut StdRng::seed_from_u64(seed))
+    }
+
+    fn build<R: Rng + ?Sized>(
+        config: TransformerConfig,
+        rng: &mut R,
+    ) -> Result<Self, TransformerError> {
+        config.validate()?;
+        let d_model = config.d_model;
+
+        let embedding = Embedding::new(config.vocab_size, d_model, rng);
+        let blocks = (0..config.num_layers)
+            .map(|_| TransformerBlock::new(d_model, config.num_heads, config.d_ff, rng))
+            .collect();
+        let lm_head = random_matrix(
+            config.vocab_size,
+            d_model,
+            (1.0 / d_model as f64).sqrt(),
+            rng,
+        );
+
+        Ok(Self {
+            embedding,
+            blocks,
+            final_norm: LayerNorm::new(d_model),
+            lm_head,
+            lm_bias: Array1::zeros(config.vocab_size),
+            config,
+        })
+    }
+
+    pub fn config(&self) -> &TransformerConfig {
+        &self.config
+    }
+
+    pub fn vocab_size(&self) -> usize {
+        self.config.vocab_size
+    }
+
+    /// Empty cache for [`TransformerModel::forward_cached`].
+    pub fn new_cache(&self) -> KvCache {
+        KvCache::new(self.config.num_layers, self.config.d_model)
+    }
+
+    /// Logits for every position of `tokens`, `(tokens.len(), vocab_size)`.
+    pub fn forward(&self, tokens: &[usize]) -> Result<Array2<f64>, TransformerError> {
+        let hidden = self.hidden_states(tokens, None)?;
+        Ok(linear(&hidden, &self.lm_head, &self.lm_bias))
+    }
+
+    /// Logits for `tokens` appended after the positions already in `cache`.
+    ///
+    /// Feeding a prompt and then one token at a time yields the same logits as
+    /// [`TransformerModel::forward`] on the whole sequence.
+    pub fn forward_cached(
+        &self,
+        tokens: &[usize],
+        cache: &mut KvCache,
+    ) -> Result<Array2<f64>, TransformerError> {
+        let hidden = self.hidden_states(tokens, Some(cache))?;
+        Ok(linear(&hidden, &self.lm_head, &self.lm_bias))
+    }
+
+    fn hidden_states(
+        &self,
+        tokens: &[usize],
+        mut cache: Option<&mut KvCache>,
+    ) -> Result<Array2<f64>, TransformerError> {
+        let start = cache.as_ref().map_or(0, |c| c.len());
+        if start + tokens.len() > self.config.max_seq_len {
+            return Err(TransformerError::SequenceTooLong {
+                max_len: self.config.max_seq_len,
+            });
+        }
+
+        let mut x = self.embedding.lookup(tokens)?
+            + positional_encoding(start, tokens.len(), self.config.d_model);
+        for (index, block) in self.blocks.iter().enumerate() {
+            let layer_cache = cache.as_deref_mut().map(|c| &mut c.layers[index]);
+            x = block.forward(&x, layer_cache)?;
+        }
+        Ok(self.final_norm.forward(&x))
+    }
+
+    /// One step of next-token cross-entropy training on `tokens`, updating the
+    /// output projection only. Returns the mean loss before the update.
+    pub fn train_head(
+        &mut self,
+        tokens: &[usize],
+        learning_rate: f64,
+    ) -> Result<f64, TransformerError> {
+        if tokens.len() < 2 {
+            return Ok(0.0);
+        }
+        let hidden = self.hidden_states(&tokens[..tokens.len() - 1], None)?;
+        let mut probs = linear(&hidden, &self.lm_head, &self.lm_bias);
+        probs.axis_iter_mut(Axis(0)).for_each(softmax_in_place);
+
+        let steps = hidden.nrows() as f64;
+        let mut loss = 0.0;
+        for (row, &target) in tokens[1..].iter().enumerate() {
+            loss -= probs[[row, target]].max(f64::MIN_POSITIVE).ln();
+            // d(loss)/d(logits) = softmax - one_hot(target)
+            probs[[row, target]] -= 1.0;
+        }
+        probs /= steps;
+
+        self.lm_head = &self.lm_head - learning_rate * probs.t().dot(&hidden);
+        self.lm_bias = &self.lm_bias - learning_rate * probs.sum_axis(Axis(0));
+        Ok(loss / steps)
+    }
+
+    fn options() -> BinaryOptions {
+        BinaryOptions {
+            magic: MODEL_MAGIC,
+            container_version: 1,
+            schema_id: MODEL_SCHEMA_ID,
+            verify_checksum: true,
+        }
+    }
+
+    /// Save the model together with the fingerprint of `tokenizer`'s vocabulary.
+    pub fn save(&self, path: &Path, tokenizer: &RustTokenizer) -> Result<(), TransformerError> {
+        self.check_vocab_size(tokenizer)?;
+        let file = ModelFile {
+            vocab_fingerprint: tokenizer.vocab_fingerprint(),
+            model: self.clone(),
+        };
+        write_binary(&file, path, &Self::options())?;
+        Ok(())
+    }
+
+    /// Load a model, rejecting it if it was saved for another vocabulary.
+    pub fn load(path: &Path, tokenizer: &RustTokenizer) -> Result<Self, TransformerError> {
+        let file: ModelFile = read_binary(path, &Self::options())?;
+        let actual = tokenizer.vocab_fingerprint();
+        if file.vocab_fingerprint != actual {
+            return Err(TransformerError::VocabularyMismatch {
+                expected: file.vocab_fingerprint,
+                actual,
+            });
+        }
+        file.model.check_vocab_size(tokenizer)?;
+        Ok(file.model)
+    }
+
+    pub(crate) fn check_vocab_size(
+        &self,
+        tokenizer: &RustTokenizer,
+    ) -> Result<(), TransformerError> {
+        if self.config.vocab_size != tokenizer.vocab_size() {
+            return Err(TransformerError::InvalidConfig(format!(
+                "model vocabulary has {} tokens, tokenizer has {}",
+                self.config.vocab_size,
+                tokenizer.vocab_size()
+            )));
+        }
+        Ok(())
+    }
+}
@@ -3,6 +3,8 @@ mod helpers;
 
 use helpers::{create_code_tokenizer, create_network_with_vocab};
 use neural::generation::{GenerationConfig, code_generator::CodeGenerator};
+use neural::tokenization::RustTokenizer;
+use neural::transformer::{TransformerConfig, TransformerModel};
 
 #[test]
 fn test_code_generator_integration() {
@@ -24,3 +26,60 @@ fn test_code_generator_integration() {
         "generated code should not be empty"
     );
 }
+
+fn transformer_generator(seed: u64) -> CodeGenerator {
+    let tokenizer = create_code_tokenizer();
+    let model_config = TransformerConfig {
+        d_model: 16,
+        num_heads: 2,
+        num_layers: 1,
+        d_ff: 32,
+        max_seq_len: 64,
+        ..TransformerConfig::for_vocab(tokenizer.vocab_size())
+    };
+    let model = TransformerModel::new_seeded(model_config, 5).expect("model creation");
+    let config = GenerationConfig {
+        max_new_tokens: 8,
+        seed: Some(seed),
+        ..GenerationConfig::default()
+    };
+    CodeGenerator::with_transformer(model, tokenizer, config).expect("generator")
+}
+
+#[test]
+fn test_transformer_generation_is_seeded() {
+    let prompt = "fn main() {";
+    let first = transformer_generator(1)
+        .generate(prompt)
+        .expect("generation");
+    let second = transformer_generator(1)
+        .generate(prompt)
+        .expect("generation");
+
+    assert_eq!(first, second, "same seed should give the same output");
+    assert!(
+        first.starts_with("fn main ( ) {"),
+        "prompt is kept: {first}"
+    );
+}
+
+#[test]
+fn test_transformer_generator_save_and_load() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("generator.bin");
+    let generator = transformer_generator(1);
+    generator.save(&path).expect("save");
+
+    let loaded = CodeGenerator::load_transformer(
+        &path,
+        create_code_tokenizer(),
+        GenerationConfig::default(),
+    )
+    .expect("load with the same vocabulary");
+    assert!(loaded.transformer().is_some());
+
+    let other_vocab = RustTokenizer::new(vec!["struct".to_string()]);
+    assert!(
+        CodeGenerator::load_transformer(&path, other_vocab, GenerationConfig::default()).is_err()
+    );
+}
@@ -1,6 +1,10 @@
 // projects/libraries/layers/domain/neural/tests/integration_sampling.rs
 use ndarray::Array1;
-use neural::generation::{apply_top_k, sample_categorical};
+use neural::generation::{
+    apply_temperature, apply_top_k, apply_top_p, sample_categorical, sample_categorical_with,
+    softmax,
+};
+use rand::{SeedableRng, rngs::StdRng};
 
 #[test]
 fn test_integration_apply_top_k() {
@@ -50,3 +54,38 @@ fn test_integration_sample_categorical() {
         "sampled token should be within valid range"
     );
 }
+
+#[test]
+fn test_integration_apply_top_p() {
+    let probs = Array1::from_vec(vec![0.1, 0.2, 0.3, 0.4]);
+    let filtered = apply_top_p(&probs, 0.6);
+
+    // 0.4 + 0.3 reaches 0.6; the rest is dropped and the kept mass renormalized.
+    assert_eq!(filtered[0], 0.0);
+    assert_eq!(filtered[1], 0.0);
+    assert!((filtered[2] - 0.3 / 0.7).abs() < 1e-12);
+    assert!((filtered[3] - 0.4 / 0.7).abs() < 1e-12);
+}
+
+#[test]
+fn test_integration_temperature() {
+    let logits = Array1::from_vec(vec![1.0, 3.0, 2.0]);
+    assert_eq!(apply_temperature(&logits, 2.0), logits.clone() / 2.0);
+
+    // Zero temperature is greedy.
+    let greedy = softmax(&apply_temperature(&logits, 0.0));
+    assert_eq!(greedy[1], 1.0);
+}
+
+#[test]
+fn test_integration_seeded_sampling_is_reproducible() {
+    let probs = Array1::from_vec(vec![0.25, 0.25, 0.25, 0.25]);
+    let draw = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..16)
+            .map(|_| sample_categorical_with(&probs, &mut rng).expect("sample"))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(draw(9), draw(9));
+    assert!(sample_categorical_with(&Array1::zeros(3), &mut StdRng::seed_from_u64(0)).is_err());
+}
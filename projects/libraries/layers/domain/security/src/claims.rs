@@ -1,7 +1,7 @@
 // projects/libraries/layers/domain/security/src/claims.rs
 use serde::{Deserialize, Serialize};
 
-use crate::Role;
+use crate::{Role, TokenUse};
 use protocol::ProtocolId;
 
 /// JWT claims (standard-ish).
@@ -20,4 +20,7 @@ pub struct Claims {
     /// Optional session id
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sid: Option<ProtocolId>,
+    /// Access or refresh token
+    #[serde(default)]
+    pub token_use: TokenUse,
 }
@@ -0,0 +1,111 @@
+// projects/libraries/layers/domain/security/src/file_revocation_store.rs
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use crate::{RevocationStore, TokenError};
+use protocol::ProtocolId;
+
+/// Revocation list persisted as an append-only text file.
+/// - One entry per line: `<jti hex> <expires_at_ms>`.
+/// - The whole file is loaded on open; `purge_expired` compacts it.
+pub struct FileRevocationStore {
+    path: PathBuf,
+    entries: Mutex<HashMap<ProtocolId, u64>>,
+}
+
+impl FileRevocationStore {
+    /// Open (or create) the store at `path`. Malformed lines are rejected.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, TokenError> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+
+        let mut entries = HashMap::new();
+        if path.exists() {
+            let reader = BufReader::new(File::open(&path).map_err(io_error)?);
+            for (index, line) in reader.lines().enumerate() {
+                let line = line.map_err(io_error)?;
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let (jti, expires_at_ms) = parse_line(line).ok_or_else(|| {
+                    TokenError::RevocationStore(format!("malformed line {}", index + 1))
+                })?;
+                entries.insert(jti, expires_at_ms);
+            }
+        }
+
+        Ok(Self {
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl RevocationStore for FileRevocationStore {
+    fn revoke(&self, jti: ProtocolId, expires_at_ms: u64) -> Result<(), TokenError> {
+        let mut entries = self.entries.lock().map_err(poisoned)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(file, "{} {}", jti.to_hex(), expires_at_ms).map_err(io_error)?;
+        entries.insert(jti, expires_at_ms);
+        Ok(())
+    }
+
+    fn is_revoked(&self, jti: &ProtocolId) -> Result<bool, TokenError> {
+        let entries = self.entries.lock().map_err(poisoned)?;
+        Ok(entries.contains_key(jti))
+    }
+
+    fn purge_expired(&self, now_ms: u64) -> Result<usize, TokenError> {
+        let mut entries = self.entries.lock().map_err(poisoned)?;
+        let before = entries.len();
+        entries.retain(|_, expires_at_ms| *expires_at_ms > now_ms);
+        let removed = before - entries.len();
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        // Rewrite into a sibling file then rename, so a crash never leaves a truncated list.
+        let tmp_path = self.path.with_extension("tmp");
+        {
+            let mut tmp = File::create(&tmp_path).map_err(io_error)?;
+            for (jti, expires_at_ms) in entries.iter() {
+                writeln!(tmp, "{} {}", jti.to_hex(), expires_at_ms).map_err(io_error)?;
+            }
+            tmp.sync_all().map_err(io_error)?;
+        }
+        fs::rename(&tmp_path, &self.path).map_err(io_error)?;
+        Ok(removed)
+    }
+}
+
+fn parse_line(line: &str) -> Option<(ProtocolId, u64)> {
+    let (jti, expires_at_ms) = line.split_once(' ')?;
+    let jti = ProtocolId::from_str(jti.trim()).ok()?;
+    let expires_at_ms = expires_at_ms.trim().parse().ok()?;
+    Some((jti, expires_at_ms))
+}
+
+fn io_error(e: std::io::Error) -> TokenError {
+    TokenError::RevocationStore(e.to_string())
+}
+
+fn poisoned<T>(_: std::sync::PoisonError<T>) -> TokenError {
+    TokenError::RevocationStore("lock poisoned".to_string())
+}
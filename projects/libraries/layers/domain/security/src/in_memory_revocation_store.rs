@@ -0,0 +1,50 @@
+// projects/libraries/layers/domain/security/src/in_memory_revocation_store.rs
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::{RevocationStore, TokenError};
+use protocol::ProtocolId;
+
+/// Process-local revocation list. Lost on restart.
+#[derive(Default)]
+pub struct InMemoryRevocationStore {
+    entries: Mutex<HashMap<ProtocolId, u64>>,
+}
+
+impl InMemoryRevocationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().map(|e| e.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl RevocationStore for InMemoryRevocationStore {
+    fn revoke(&self, jti: ProtocolId, expires_at_ms: u64) -> Result<(), TokenError> {
+        let mut entries = self.entries.lock().map_err(poisoned)?;
+        entries.insert(jti, expires_at_ms);
+        Ok(())
+    }
+
+    fn is_revoked(&self, jti: &ProtocolId) -> Result<bool, TokenError> {
+        let entries = self.entries.lock().map_err(poisoned)?;
+        Ok(entries.contains_key(jti))
+    }
+
+    fn purge_expired(&self, now_ms: u64) -> Result<usize, TokenError> {
+        let mut entries = self.entries.lock().map_err(poisoned)?;
+        let before = entries.len();
+        entries.retain(|_, expires_at_ms| *expires_at_ms > now_ms);
+        Ok(before - entries.len())
+    }
+}
+
+fn poisoned<T>(_: std::sync::PoisonError<T>) -> TokenError {
+    TokenError::RevocationStore("lock poisoned".to_string())
+}
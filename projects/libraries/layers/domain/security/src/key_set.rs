@@ -0,0 +1,91 @@
+// projects/libraries/layers/domain/security/src/key_set.rs
+use crate::{SigningKey, TokenError};
+
+/// Set of JWT keys addressed by `kid`.
+/// - Signing uses the most recently activated key that can sign.
+/// - Verification accepts any key that has not been retired yet.
+#[derive(Clone, Default)]
+pub struct KeySet {
+    keys: Vec<SigningKey>,
+}
+
+impl KeySet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a key. `kid`s must be unique within the set.
+    pub fn insert(&mut self, key: SigningKey) -> Result<(), TokenError> {
+        if self.get(key.kid()).is_some() {
+            return Err(TokenError::DuplicateKeyId(key.kid().to_string()));
+        }
+        self.keys.push(key);
+        Ok(())
+    }
+
+    pub fn get(&self, kid: &str) -> Option<&SigningKey> {
+        self.keys.iter().find(|k| k.kid() == kid)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn kids(&self) -> Vec<String> {
+        self.keys.iter().map(|k| k.kid().to_string()).collect()
+    }
+
+    /// Key used to sign tokens issued at `now_ms`.
+    /// Ties on `not_before_ms` go to the key inserted last.
+    pub fn signing_key(&self, now_ms: u64) -> Option<&SigningKey> {
+        self.keys
+            .iter()
+            .filter(|k| k.is_signing_at(now_ms))
+            .max_by_key(|k| k.not_before_ms())
+    }
+
+    /// Schedule `next` as the new signing key.
+    /// Every key that could sign before `next` activates stays valid for verification
+    /// for `grace_ms` after the activation, then retires.
+    /// `grace_ms` should be at least the longest token lifetime issued with the old keys.
+    pub fn rotate(&mut self, next: SigningKey, grace_ms: u64) -> Result<(), TokenError> {
+        if !next.can_sign() {
+            return Err(TokenError::InvalidKey(format!(
+                "key {} cannot sign",
+                next.kid()
+            )));
+        }
+
+        if self.get(next.kid()).is_some() {
+            return Err(TokenError::DuplicateKeyId(next.kid().to_string()));
+        }
+
+        let activation_ms = next.not_before_ms();
+        let retired_at_ms = activation_ms.saturating_add(grace_ms);
+        for key in self.keys.iter_mut() {
+            if key.can_sign() && key.not_before_ms() <= activation_ms {
+                key.retire_at(retired_at_ms);
+            }
+        }
+
+        self.keys.push(next);
+        Ok(())
+    }
+
+    /// Drop keys whose grace window is over. Returns the removed `kid`s.
+    pub fn prune(&mut self, now_ms: u64) -> Vec<String> {
+        let mut removed = Vec::new();
+        self.keys.retain(|k| {
+            let keep = k.is_verifying_at(now_ms);
+            if !keep {
+                removed.push(k.kid().to_string());
+            }
+            keep
+        });
+        removed
+    }
+}
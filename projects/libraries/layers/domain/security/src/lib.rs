@@ -1,15 +1,25 @@
 // projects/libraries/layers/domain/security/src/lib.rs
 pub mod claims;
+pub mod file_revocation_store;
+pub mod in_memory_revocation_store;
+pub mod key_set;
 pub mod password;
 pub mod password_error;
 pub mod permission_error;
 pub mod permissions;
+pub mod policy;
+pub mod revocation_store;
 pub mod role;
+pub mod signing_key;
 pub mod token;
 pub mod token_error;
 pub mod token_service;
+pub mod token_use;
 
 pub use claims::Claims;
+pub use file_revocation_store::FileRevocationStore;
+pub use in_memory_revocation_store::InMemoryRevocationStore;
+pub use key_set::KeySet;
 pub use password::{hash_password, verify_password};
 pub use password_error::PasswordError;
 pub use permission_error::PermissionError;
@@ -18,10 +28,14 @@ pub use permissions::{
     filter_allowed_permissions, has_all_permissions, has_any_permission, has_permission,
     missing_permissions,
 };
+pub use policy::{PolicyDecision, PolicyEngine, PolicyError, PolicyRequest};
+pub use revocation_store::RevocationStore;
 pub use security_core::{Permission, Role};
+pub use signing_key::SigningKey;
 pub use token::Token;
 pub use token_error::TokenError;
-pub use token_service::TokenService;
+pub use token_service::{DEFAULT_KEY_ID, TokenService};
+pub use token_use::TokenUse;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,27 @@
+// projects/libraries/layers/domain/security/src/policy/mod.rs
+pub mod pattern;
+pub mod policy_condition;
+pub mod policy_context;
+pub mod policy_decision;
+pub mod policy_effect;
+pub mod policy_engine;
+pub mod policy_error;
+pub mod policy_explanation;
+pub mod policy_request;
+pub mod policy_rule;
+pub mod policy_subject;
+
+pub use pattern::Pattern;
+pub use policy_condition::PolicyCondition;
+pub use policy_context::PolicyContext;
+pub use policy_decision::PolicyDecision;
+pub use policy_effect::PolicyEffect;
+pub use policy_engine::PolicyEngine;
+pub use policy_error::PolicyError;
+pub use policy_explanation::{PolicyExplanation, PolicyTraceEntry, RuleOutcome};
+pub use policy_request::PolicyRequest;
+pub use policy_rule::PolicyRule;
+pub use policy_subject::PolicySubject;
+
+#[cfg(test)]
+mod tests;
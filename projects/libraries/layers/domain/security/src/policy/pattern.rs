@@ -0,0 +1,85 @@
+// projects/libraries/layers/domain/security/src/policy/pattern.rs
+use serde::{Deserialize, Serialize};
+
+use crate::policy::PolicyError;
+
+/// Glob used for actions and resources.
+/// - `*` matches any run of characters inside one segment.
+/// - `**` matches anything, separators included.
+/// - Segments are split on `separator` (`/` for resources, `.` for actions).
+///
+/// Examples: `project:foo/*` matches `project:foo/main` but not `project:foo/a/b`;
+/// `project:foo/**` matches both; `accounts.*` matches `accounts.create`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pattern {
+    raw: String,
+    separator: char,
+}
+
+impl Pattern {
+    pub fn new(raw: &str, separator: char) -> Result<Self, PolicyError> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return Err(PolicyError::InvalidPattern("empty pattern".to_string()));
+        }
+        if raw.contains("***") {
+            return Err(PolicyError::InvalidPattern(raw.to_string()));
+        }
+        Ok(Self {
+            raw: raw.to_string(),
+            separator,
+        })
+    }
+
+    /// Resource pattern (`/` separated).
+    pub fn resource(raw: &str) -> Result<Self, PolicyError> {
+        Self::new(raw, '/')
+    }
+
+    /// Action pattern (`.` separated).
+    pub fn action(raw: &str) -> Result<Self, PolicyError> {
+        Self::new(raw, '.')
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// True if the pattern has no wildcard.
+    pub fn is_exact(&self) -> bool {
+        !self.raw.contains('*')
+    }
+
+    pub fn matches(&self, value: &str) -> bool {
+        glob_match(self.raw.as_bytes(), value.as_bytes(), self.separator as u8)
+    }
+}
+
+impl std::fmt::Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+fn glob_match(pattern: &[u8], value: &[u8], separator: u8) -> bool {
+    match pattern.first() {
+        None => value.is_empty(),
+        Some(b'*') if pattern.get(1) == Some(&b'*') => {
+            let rest = &pattern[2..];
+            (0..=value.len()).any(|i| glob_match(rest, &value[i..], separator))
+        }
+        Some(b'*') => {
+            let rest = &pattern[1..];
+            for i in 0..=value.len() {
+                if glob_match(rest, &value[i..], separator) {
+                    return true;
+                }
+                if value.get(i) == Some(&separator) {
+                    break;
+                }
+            }
+            false
+        }
+        Some(c) => value.first() == Some(c) && glob_match(&pattern[1..], &value[1..], separator),
+    }
+}
@@ -0,0 +1,38 @@
+// projects/libraries/layers/domain/security/src/policy/policy_condition.rs
+use serde::{Deserialize, Serialize};
+
+use crate::policy::PolicyContext;
+
+/// Extra requirement on the request context for a rule to match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "op")]
+pub enum PolicyCondition {
+    /// `context[key] == value`
+    Equals { key: String, value: String },
+    /// `context[key]` is one of `values`
+    OneOf { key: String, values: Vec<String> },
+    /// `context[key]` is set
+    Present { key: String },
+}
+
+impl PolicyCondition {
+    pub fn holds(&self, context: &PolicyContext) -> bool {
+        match self {
+            PolicyCondition::Equals { key, value } => context.get(key) == Some(value.as_str()),
+            PolicyCondition::OneOf { key, values } => context
+                .get(key)
+                .is_some_and(|v| values.iter().any(|candidate| candidate == v)),
+            PolicyCondition::Present { key } => context.get(key).is_some(),
+        }
+    }
+}
+
+impl std::fmt::Display for PolicyCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicyCondition::Equals { key, value } => write!(f, "{key} == {value}"),
+            PolicyCondition::OneOf { key, values } => write!(f, "{key} in [{}]", values.join(", ")),
+            PolicyCondition::Present { key } => write!(f, "{key} is set"),
+        }
+    }
+}
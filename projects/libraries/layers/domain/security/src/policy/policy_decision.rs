@@ -0,0 +1,38 @@
+// projects/libraries/layers/domain/security/src/policy/policy_decision.rs
+use serde::{Deserialize, Serialize};
+
+/// Final answer of the policy engine, with the reason that produced it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "reason")]
+pub enum PolicyDecision {
+    /// A deny rule matched (deny takes precedence over any allow).
+    Denied { rule_id: String },
+    /// An allow rule matched and no deny rule did.
+    Allowed { rule_id: String },
+    /// A subject grant matched and no deny rule did.
+    Granted { permission: String },
+    /// Nothing matched: default deny.
+    NoMatch,
+}
+
+impl PolicyDecision {
+    pub fn is_allowed(&self) -> bool {
+        matches!(
+            self,
+            PolicyDecision::Allowed { .. } | PolicyDecision::Granted { .. }
+        )
+    }
+}
+
+impl std::fmt::Display for PolicyDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicyDecision::Denied { rule_id } => write!(f, "denied by rule '{rule_id}'"),
+            PolicyDecision::Allowed { rule_id } => write!(f, "allowed by rule '{rule_id}'"),
+            PolicyDecision::Granted { permission } => {
+                write!(f, "allowed by subject grant '{permission}'")
+            }
+            PolicyDecision::NoMatch => write!(f, "denied: no matching rule"),
+        }
+    }
+}
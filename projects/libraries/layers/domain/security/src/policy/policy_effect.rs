@@ -0,0 +1,19 @@
+// projects/libraries/layers/domain/security/src/policy/policy_effect.rs
+use serde::{Deserialize, Serialize};
+
+/// Outcome a rule produces when it matches. `Deny` always wins over `Allow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyEffect {
+    Allow,
+    Deny,
+}
+
+impl std::fmt::Display for PolicyEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicyEffect::Allow => write!(f, "allow"),
+            PolicyEffect::Deny => write!(f, "deny"),
+        }
+    }
+}
@@ -0,0 +1,265 @@
+// projects/libraries/layers/domain/security/src/policy/policy_engine.rs
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::policy::{
+    Pattern, PolicyDecision, PolicyEffect, PolicyError, PolicyExplanation, PolicyRequest,
+    PolicyRule, PolicySubject, PolicyTraceEntry, RuleOutcome,
+};
+use crate::{Permission, Role};
+
+/// Attribute/resource based authorization on top of roles.
+/// - Rules are evaluated in insertion order; any matching deny wins, then any
+///   matching allow or subject grant; otherwise the request is denied.
+/// - Roles may inherit other roles (rules of the parent apply to the child).
+/// - Products register their own permissions as `<product>.<name>`.
+#[derive(Debug, Clone, Default)]
+pub struct PolicyEngine {
+    rules: Vec<PolicyRule>,
+    /// role -> direct parents
+    roles: BTreeMap<String, Vec<String>>,
+    /// product -> custom permission names
+    permissions: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl PolicyEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Engine equivalent to `Role::permissions` for the built-in roles:
+    /// guest <- user <- moderator <- admin, each level allowing its own
+    /// permissions on every resource.
+    pub fn with_builtin_roles() -> Self {
+        let mut engine = Self::new();
+        let chain = [Role::Guest, Role::User, Role::Moderator, Role::Admin];
+
+        let mut parent: Option<Role> = None;
+        for role in chain {
+            engine.roles.insert(
+                role.as_str().to_string(),
+                parent.iter().map(|p| p.as_str().to_string()).collect(),
+            );
+
+            // Each level only declares what its parent does not already allow.
+            let own = role
+                .permissions()
+                .iter()
+                .filter(|perm| parent.is_none_or(|p| !p.has_permission(**perm)))
+                .filter_map(|perm| Pattern::action(perm.as_str()).ok())
+                .collect();
+            engine.rules.push(PolicyRule {
+                id: format!("builtin:{}", role.as_str()),
+                effect: PolicyEffect::Allow,
+                subject: PolicySubject::Role(role.as_str().to_string()),
+                actions: own,
+                resources: Pattern::resource("**").into_iter().collect(),
+                conditions: Vec::new(),
+            });
+
+            parent = Some(role);
+        }
+        engine
+    }
+
+    /// Declare a role and its direct parents. Parents must already be defined.
+    /// Redefining a role replaces its parents.
+    pub fn define_role(&mut self, role: &str, parents: &[&str]) -> Result<(), PolicyError> {
+        let role = role.trim();
+        if role.is_empty() {
+            return Err(PolicyError::UnknownRole(String::new()));
+        }
+        for parent in parents {
+            if !self.roles.contains_key(*parent) {
+                return Err(PolicyError::UnknownRole(parent.to_string()));
+            }
+            if *parent == role || self.inherited_roles(parent).contains(role) {
+                return Err(PolicyError::RoleCycle(role.to_string()));
+            }
+        }
+
+        self.roles.insert(
+            role.to_string(),
+            parents.iter().map(|p| p.to_string()).collect(),
+        );
+        Ok(())
+    }
+
+    /// Register product specific permissions, usable as `<product>.<name>` actions.
+    pub fn register_permissions(
+        &mut self,
+        product: &str,
+        names: &[&str],
+    ) -> Result<(), PolicyError> {
+        let product = product.trim();
+        if product.is_empty() || product.contains('.') || product.contains('*') {
+            return Err(PolicyError::InvalidPermission(product.to_string()));
+        }
+        let entry = self.permissions.entry(product.to_string()).or_default();
+        for name in names {
+            let name = name.trim();
+            if name.is_empty() || name.contains('*') {
+                return Err(PolicyError::InvalidPermission(format!("{product}.{name}")));
+            }
+            entry.insert(name.to_string());
+        }
+        Ok(())
+    }
+
+    /// Is `action` a built-in permission or a registered product permission?
+    pub fn is_known_permission(&self, action: &str) -> bool {
+        if action.parse::<Permission>().is_ok() {
+            return true;
+        }
+        action
+            .split_once('.')
+            .and_then(|(product, name)| self.permissions.get(product).map(|p| p.contains(name)))
+            .unwrap_or(false)
+    }
+
+    /// Add a rule. Exact actions must be known permissions; role subjects must be defined.
+    pub fn add_rule(&mut self, rule: PolicyRule) -> Result<(), PolicyError> {
+        if self.rules.iter().any(|r| r.id == rule.id) {
+            return Err(PolicyError::DuplicateRule(rule.id));
+        }
+        if let PolicySubject::Role(role) = &rule.subject
+            && !self.roles.contains_key(role)
+        {
+            return Err(PolicyError::UnknownRole(role.clone()));
+        }
+        for action in rule.actions.iter().filter(|a| a.is_exact()) {
+            if !self.is_known_permission(action.as_str()) {
+                return Err(PolicyError::UnknownPermission(action.to_string()));
+            }
+        }
+
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    pub fn rules(&self) -> &[PolicyRule] {
+        &self.rules
+    }
+
+    /// Decide a request.
+    pub fn evaluate(&self, request: &PolicyRequest) -> PolicyDecision {
+        self.explain(request).decision
+    }
+
+    /// Shortcut for `evaluate(..).is_allowed()`.
+    pub fn is_allowed(&self, request: &PolicyRequest) -> bool {
+        self.evaluate(request).is_allowed()
+    }
+
+    /// Decide a request and record how every rule was evaluated.
+    pub fn explain(&self, request: &PolicyRequest) -> PolicyExplanation {
+        let effective_roles = self.effective_roles(&request.roles);
+
+        let mut trace = Vec::with_capacity(self.rules.len());
+        let mut first_deny: Option<&str> = None;
+        let mut first_allow: Option<&str> = None;
+
+        for rule in &self.rules {
+            let outcome = self.rule_outcome(rule, request, &effective_roles);
+            if outcome == RuleOutcome::Matched {
+                match rule.effect {
+                    PolicyEffect::Deny => {
+                        first_deny.get_or_insert(rule.id.as_str());
+                    }
+                    PolicyEffect::Allow => {
+                        first_allow.get_or_insert(rule.id.as_str());
+                    }
+                }
+            }
+            trace.push(PolicyTraceEntry {
+                rule_id: rule.id.clone(),
+                effect: rule.effect,
+                outcome,
+            });
+        }
+
+        let grant = request.grants.iter().find(|g| *g == &request.action);
+
+        let decision = match (first_deny, first_allow, grant) {
+            (Some(rule_id), _, _) => PolicyDecision::Denied {
+                rule_id: rule_id.to_string(),
+            },
+            (None, Some(rule_id), _) => PolicyDecision::Allowed {
+                rule_id: rule_id.to_string(),
+            },
+            (None, None, Some(permission)) => PolicyDecision::Granted {
+                permission: permission.clone(),
+            },
+            (None, None, None) => PolicyDecision::NoMatch,
+        };
+
+        PolicyExplanation {
+            subject: request.subject.clone(),
+            action: request.action.clone(),
+            resource: request.resource.clone(),
+            effective_roles,
+            trace,
+            decision,
+        }
+    }
+
+    /// Direct roles followed by every inherited role, without duplicates.
+    pub fn effective_roles(&self, roles: &[String]) -> Vec<String> {
+        let mut out: Vec<String> = Vec::new();
+        for role in roles {
+            if !out.contains(role) {
+                out.push(role.clone());
+            }
+        }
+        for role in roles {
+            for inherited in self.inherited_roles(role) {
+                if !out.contains(&inherited) {
+                    out.push(inherited);
+                }
+            }
+        }
+        out
+    }
+
+    fn inherited_roles(&self, role: &str) -> BTreeSet<String> {
+        let mut seen = BTreeSet::new();
+        let mut stack: Vec<&str> = self
+            .roles
+            .get(role)
+            .map(|p| p.iter().map(String::as_str).collect())
+            .unwrap_or_default();
+        while let Some(current) = stack.pop() {
+            if seen.insert(current.to_string())
+                && let Some(parents) = self.roles.get(current)
+            {
+                stack.extend(parents.iter().map(String::as_str));
+            }
+        }
+        seen
+    }
+
+    fn rule_outcome(
+        &self,
+        rule: &PolicyRule,
+        request: &PolicyRequest,
+        effective_roles: &[String],
+    ) -> RuleOutcome {
+        let subject_ok = match &rule.subject {
+            PolicySubject::Any => true,
+            PolicySubject::Role(role) => effective_roles.contains(role),
+            PolicySubject::Id(id) => *id == request.subject,
+        };
+        if !subject_ok {
+            return RuleOutcome::SubjectMismatch;
+        }
+        if !rule.matches_action(&request.action) {
+            return RuleOutcome::ActionMismatch;
+        }
+        if !rule.matches_resource(&request.resource) {
+            return RuleOutcome::ResourceMismatch;
+        }
+        if !rule.conditions.iter().all(|c| c.holds(&request.context)) {
+            return RuleOutcome::ConditionFailed;
+        }
+        RuleOutcome::Matched
+    }
+}
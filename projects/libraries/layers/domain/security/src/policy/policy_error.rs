@@ -0,0 +1,23 @@
+// projects/libraries/layers/domain/security/src/policy/policy_error.rs
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum PolicyError {
+    #[error("invalid pattern: {0}")]
+    InvalidPattern(String),
+
+    #[error("duplicate rule id: {0}")]
+    DuplicateRule(String),
+
+    #[error("unknown role: {0}")]
+    UnknownRole(String),
+
+    #[error("role inheritance cycle through: {0}")]
+    RoleCycle(String),
+
+    #[error("unknown permission: {0}")]
+    UnknownPermission(String),
+
+    #[error("invalid permission name: {0}")]
+    InvalidPermission(String),
+}
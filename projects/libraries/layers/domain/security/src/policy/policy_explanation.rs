@@ -0,0 +1,62 @@
+// projects/libraries/layers/domain/security/src/policy/policy_explanation.rs
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{PolicyDecision, PolicyEffect};
+
+/// Why a rule did or did not apply to a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleOutcome {
+    Matched,
+    SubjectMismatch,
+    ActionMismatch,
+    ResourceMismatch,
+    ConditionFailed,
+}
+
+/// Evaluation of one rule, in engine order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyTraceEntry {
+    pub rule_id: String,
+    pub effect: PolicyEffect,
+    pub outcome: RuleOutcome,
+}
+
+/// Audit record returned by `PolicyEngine::explain`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyExplanation {
+    pub subject: String,
+    pub action: String,
+    pub resource: String,
+    /// Direct roles followed by inherited ones.
+    pub effective_roles: Vec<String>,
+    pub trace: Vec<PolicyTraceEntry>,
+    pub decision: PolicyDecision,
+}
+
+impl PolicyExplanation {
+    pub fn matched_rules(&self) -> impl Iterator<Item = &PolicyTraceEntry> {
+        self.trace
+            .iter()
+            .filter(|entry| entry.outcome == RuleOutcome::Matched)
+    }
+}
+
+impl std::fmt::Display for PolicyExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} -> {} on {}: {}",
+            self.subject, self.action, self.resource, self.decision
+        )?;
+        writeln!(f, "  roles: [{}]", self.effective_roles.join(", "))?;
+        for entry in &self.trace {
+            writeln!(
+                f,
+                "  {} ({}): {:?}",
+                entry.rule_id, entry.effect, entry.outcome
+            )?;
+        }
+        Ok(())
+    }
+}
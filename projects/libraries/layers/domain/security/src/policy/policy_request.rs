@@ -0,0 +1,59 @@
+// projects/libraries/layers/domain/security/src/policy/policy_request.rs
+use crate::Role;
+use crate::policy::PolicyContext;
+
+/// `(subject, action, resource, context)` tuple submitted to the policy engine.
+#[derive(Debug, Clone, Default)]
+pub struct PolicyRequest {
+    /// Subject id (usually a user id).
+    pub subject: String,
+    /// Roles held directly; inherited roles are resolved by the engine.
+    pub roles: Vec<String>,
+    /// Permissions granted to this subject only (e.g. account extra permissions).
+    /// They allow on every resource but still lose against deny rules.
+    pub grants: Vec<String>,
+    /// Permission name, e.g. `write` or `accounts.create`.
+    pub action: String,
+    /// Resource identifier, e.g. `project:foo/main`.
+    pub resource: String,
+    pub context: PolicyContext,
+}
+
+impl PolicyRequest {
+    pub fn new(
+        subject: impl Into<String>,
+        action: impl Into<String>,
+        resource: impl Into<String>,
+    ) -> Self {
+        Self {
+            subject: subject.into(),
+            action: action.into(),
+            resource: resource.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_role(mut self, role: impl Into<String>) -> Self {
+        self.roles.push(role.into());
+        self
+    }
+
+    /// Shortcut for the built-in `security_core::Role`.
+    pub fn with_builtin_role(self, role: Role) -> Self {
+        self.with_role(role.as_str())
+    }
+
+    pub fn with_grants<I, S>(mut self, grants: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.grants.extend(grants.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_context(mut self, context: PolicyContext) -> Self {
+        self.context = context;
+        self
+    }
+}
@@ -0,0 +1,81 @@
+// projects/libraries/layers/domain/security/src/policy/policy_rule.rs
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{Pattern, PolicyCondition, PolicyEffect, PolicyError, PolicySubject};
+
+/// One allow/deny statement.
+/// A rule matches a request when its subject, one of its actions, one of its
+/// resources and all of its conditions match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyRule {
+    pub id: String,
+    pub effect: PolicyEffect,
+    pub subject: PolicySubject,
+    pub actions: Vec<Pattern>,
+    pub resources: Vec<Pattern>,
+    #[serde(default)]
+    pub conditions: Vec<PolicyCondition>,
+}
+
+impl PolicyRule {
+    /// Allow rule on every resource. Narrow it with `on`.
+    pub fn allow(id: &str, subject: PolicySubject, actions: &[&str]) -> Result<Self, PolicyError> {
+        Self::build(id, PolicyEffect::Allow, subject, actions)
+    }
+
+    /// Deny rule on every resource. Narrow it with `on`.
+    pub fn deny(id: &str, subject: PolicySubject, actions: &[&str]) -> Result<Self, PolicyError> {
+        Self::build(id, PolicyEffect::Deny, subject, actions)
+    }
+
+    /// Restrict the rule to resources matching these patterns.
+    pub fn on(mut self, resources: &[&str]) -> Result<Self, PolicyError> {
+        self.resources = resources
+            .iter()
+            .map(|r| Pattern::resource(r))
+            .collect::<Result<_, _>>()?;
+        Ok(self)
+    }
+
+    pub fn when(mut self, condition: PolicyCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn matches_action(&self, action: &str) -> bool {
+        self.actions.iter().any(|p| p.matches(action))
+    }
+
+    pub fn matches_resource(&self, resource: &str) -> bool {
+        self.resources.iter().any(|p| p.matches(resource))
+    }
+
+    fn build(
+        id: &str,
+        effect: PolicyEffect,
+        subject: PolicySubject,
+        actions: &[&str],
+    ) -> Result<Self, PolicyError> {
+        let id = id.trim();
+        if id.is_empty() {
+            return Err(PolicyError::InvalidPattern("empty rule id".to_string()));
+        }
+        if actions.is_empty() {
+            return Err(PolicyError::InvalidPattern(format!(
+                "rule {id} has no action"
+            )));
+        }
+
+        Ok(Self {
+            id: id.to_string(),
+            effect,
+            subject,
+            actions: actions
+                .iter()
+                .map(|a| Pattern::action(a))
+                .collect::<Result<_, _>>()?,
+            resources: vec![Pattern::resource("**")?],
+            conditions: Vec::new(),
+        })
+    }
+}
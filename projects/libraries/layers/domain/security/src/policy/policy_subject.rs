@@ -0,0 +1,24 @@
+// projects/libraries/layers/domain/security/src/policy/policy_subject.rs
+use serde::{Deserialize, Serialize};
+
+/// Who a rule applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "kind", content = "value")]
+pub enum PolicySubject {
+    /// Every subject.
+    Any,
+    /// Subjects holding this role, directly or through inheritance.
+    Role(String),
+    /// One subject, by id.
+    Id(String),
+}
+
+impl std::fmt::Display for PolicySubject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicySubject::Any => write!(f, "*"),
+            PolicySubject::Role(role) => write!(f, "role:{role}"),
+            PolicySubject::Id(id) => write!(f, "id:{id}"),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::policy::Pattern;
+
+#[test]
+fn test_single_star_stays_in_segment() {
+    let pattern = Pattern::resource("project:foo/*").expect("pattern");
+    assert!(pattern.matches("project:foo/main"));
+    assert!(!pattern.matches("project:foo/a/b"));
+    assert!(!pattern.matches("project:bar/main"));
+}
+
+#[test]
+fn test_double_star_crosses_segments() {
+    let pattern = Pattern::resource("project:foo/**").expect("pattern");
+    assert!(pattern.matches("project:foo/a/b"));
+    assert!(pattern.matches("project:foo/"));
+    assert!(
+        Pattern::resource("**")
+            .expect("pattern")
+            .matches("anything/at/all")
+    );
+}
+
+#[test]
+fn test_action_pattern_uses_dot_segments() {
+    let pattern = Pattern::action("accounts.*").expect("pattern");
+    assert!(pattern.matches("accounts.create"));
+    assert!(!pattern.matches("accounts.admin.create"));
+    assert!(!pattern.matches("engine.ping"));
+}
+
+#[test]
+fn test_invalid_patterns_are_rejected() {
+    assert!(Pattern::resource("").is_err());
+    assert!(Pattern::resource("a/***").is_err());
+}
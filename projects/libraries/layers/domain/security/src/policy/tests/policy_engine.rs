@@ -0,0 +1,190 @@
+use crate::policy::{
+    PolicyCondition, PolicyContext, PolicyDecision, PolicyEngine, PolicyError, PolicyRequest,
+    PolicyRule, PolicySubject, RuleOutcome,
+};
+use crate::{Permission, Role};
+
+fn request(role: Role, action: &str, resource: &str) -> PolicyRequest {
+    PolicyRequest::new("user-1", action, resource).with_builtin_role(role)
+}
+
+#[test]
+fn test_builtin_roles_match_role_permissions() {
+    let engine = PolicyEngine::with_builtin_roles();
+    for role in [Role::Guest, Role::User, Role::Moderator, Role::Admin] {
+        for perm in Permission::all() {
+            assert_eq!(
+                engine.is_allowed(&request(role, perm.as_str(), "project:any")),
+                role.has_permission(*perm),
+                "{role:?} / {perm:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_deny_takes_precedence_over_allow() {
+    let mut engine = PolicyEngine::with_builtin_roles();
+    engine
+        .add_rule(
+            PolicyRule::deny("freeze-foo", PolicySubject::Any, &["write", "delete"])
+                .expect("rule")
+                .on(&["project:foo/**"])
+                .expect("resources"),
+        )
+        .expect("add rule");
+
+    assert_eq!(
+        engine.evaluate(&request(Role::Admin, "write", "project:foo/main")),
+        PolicyDecision::Denied {
+            rule_id: "freeze-foo".to_string()
+        }
+    );
+    assert!(engine.is_allowed(&request(Role::Admin, "write", "project:bar/main")));
+}
+
+#[test]
+fn test_no_matching_rule_is_denied() {
+    let engine = PolicyEngine::new();
+    assert_eq!(
+        engine.evaluate(&PolicyRequest::new("user-1", "read", "project:foo")),
+        PolicyDecision::NoMatch
+    );
+}
+
+#[test]
+fn test_custom_role_inherits_parent_rules() {
+    let mut engine = PolicyEngine::with_builtin_roles();
+    engine
+        .define_role("release_manager", &["user"])
+        .expect("define role");
+    engine
+        .register_permissions("versioning", &["publish"])
+        .expect("register permissions");
+    engine
+        .add_rule(
+            PolicyRule::allow(
+                "release",
+                PolicySubject::Role("release_manager".to_string()),
+                &["versioning.publish"],
+            )
+            .expect("rule"),
+        )
+        .expect("add rule");
+
+    let req = |action: &str| {
+        PolicyRequest::new("user-1", action, "project:foo").with_role("release_manager")
+    };
+    assert!(engine.is_allowed(&req("versioning.publish")));
+    assert!(engine.is_allowed(&req("write")));
+    assert!(!engine.is_allowed(&req("delete")));
+}
+
+#[test]
+fn test_role_cycle_and_unknown_parent_are_rejected() {
+    let mut engine = PolicyEngine::new();
+    engine.define_role("a", &[]).expect("define a");
+    engine.define_role("b", &["a"]).expect("define b");
+
+    assert_eq!(
+        engine.define_role("a", &["b"]),
+        Err(PolicyError::RoleCycle("a".to_string()))
+    );
+    assert_eq!(
+        engine.define_role("c", &["missing"]),
+        Err(PolicyError::UnknownRole("missing".to_string()))
+    );
+}
+
+#[test]
+fn test_unknown_exact_permission_is_rejected() {
+    let mut engine = PolicyEngine::with_builtin_roles();
+    let rule = PolicyRule::allow("r", PolicySubject::Any, &["accounts.create"]).expect("rule");
+    assert_eq!(
+        engine.add_rule(rule.clone()),
+        Err(PolicyError::UnknownPermission(
+            "accounts.create".to_string()
+        ))
+    );
+
+    engine
+        .register_permissions("accounts", &["create"])
+        .expect("register permissions");
+    assert!(engine.add_rule(rule).is_ok());
+}
+
+#[test]
+fn test_conditions_use_request_context() {
+    let mut engine = PolicyEngine::with_builtin_roles();
+    engine
+        .add_rule(
+            PolicyRule::deny("no-prod-delete", PolicySubject::Any, &["delete"])
+                .expect("rule")
+                .when(PolicyCondition::Equals {
+                    key: "env".to_string(),
+                    value: "prod".to_string(),
+                }),
+        )
+        .expect("add rule");
+
+    let prod = request(Role::Admin, "delete", "project:foo")
+        .with_context(PolicyContext::new().with("env", "prod"));
+    let dev = request(Role::Admin, "delete", "project:foo")
+        .with_context(PolicyContext::new().with("env", "dev"));
+    assert!(!engine.is_allowed(&prod));
+    assert!(engine.is_allowed(&dev));
+}
+
+#[test]
+fn test_subject_grants_lose_against_deny() {
+    let mut engine = PolicyEngine::with_builtin_roles();
+    let granted = request(Role::Guest, "train", "project:foo").with_grants(["train"]);
+    assert_eq!(
+        engine.evaluate(&granted),
+        PolicyDecision::Granted {
+            permission: "train".to_string()
+        }
+    );
+
+    engine
+        .add_rule(
+            PolicyRule::deny(
+                "no-train",
+                PolicySubject::Id("user-1".to_string()),
+                &["train"],
+            )
+            .expect("rule"),
+        )
+        .expect("add rule");
+    assert!(!engine.is_allowed(&granted));
+}
+
+#[test]
+fn test_explain_records_every_rule() {
+    let engine = PolicyEngine::with_builtin_roles();
+    let explanation = engine.explain(&request(Role::User, "write", "project:foo"));
+
+    assert_eq!(
+        explanation.effective_roles,
+        vec!["user".to_string(), "guest".to_string()]
+    );
+    assert_eq!(explanation.trace.len(), engine.rules().len());
+    let matched: Vec<_> = explanation
+        .matched_rules()
+        .map(|e| e.rule_id.as_str())
+        .collect();
+    assert_eq!(matched, vec!["builtin:user"]);
+    assert_eq!(
+        explanation
+            .trace
+            .iter()
+            .find(|e| e.rule_id == "builtin:guest")
+            .map(|e| e.outcome),
+        Some(RuleOutcome::ActionMismatch)
+    );
+    assert!(
+        explanation
+            .to_string()
+            .contains("allowed by rule 'builtin:user'")
+    );
+}
@@ -0,0 +1,17 @@
+// projects/libraries/layers/domain/security/src/revocation_store.rs
+use crate::TokenError;
+use protocol::ProtocolId;
+
+/// Storage for revoked token ids (`jti`).
+/// `expires_at_ms` is the token expiration: past it, the entry is useless
+/// (the token is rejected anyway) and can be purged.
+pub trait RevocationStore: Send + Sync {
+    /// Mark a `jti` as revoked until `expires_at_ms`.
+    fn revoke(&self, jti: ProtocolId, expires_at_ms: u64) -> Result<(), TokenError>;
+
+    /// Is this `jti` revoked?
+    fn is_revoked(&self, jti: &ProtocolId) -> Result<bool, TokenError>;
+
+    /// Remove entries whose token already expired at `now_ms`. Returns how many were removed.
+    fn purge_expired(&self, now_ms: u64) -> Result<usize, TokenError>;
+}
@@ -0,0 +1,149 @@
+// projects/libraries/layers/domain/security/src/signing_key.rs
+use crate::TokenError;
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+
+/// A JWT key identified by `kid`.
+/// - Keys built from a public key only can verify but never sign.
+/// - `not_before_ms` schedules when the key starts signing.
+/// - `retired_at_ms` is set by rotation; once reached the key no longer verifies.
+#[derive(Clone)]
+pub struct SigningKey {
+    kid: String,
+    algorithm: Algorithm,
+    enc: Option<EncodingKey>,
+    dec: DecodingKey,
+    not_before_ms: u64,
+    retired_at_ms: Option<u64>,
+}
+
+impl SigningKey {
+    /// Shared-secret key. Same rules as `TokenService::new_hs256` (min 32 characters).
+    pub fn hs256(kid: &str, secret: &str) -> Result<Self, TokenError> {
+        let s = secret.trim();
+        if s.is_empty() {
+            return Err(TokenError::MissingSecret);
+        }
+        if s.len() < 32 {
+            return Err(TokenError::SecretTooShort);
+        }
+
+        Self::build(
+            kid,
+            Algorithm::HS256,
+            Some(EncodingKey::from_secret(s.as_bytes())),
+            DecodingKey::from_secret(s.as_bytes()),
+        )
+    }
+
+    /// RSA key pair from PEM (PKCS#1 or PKCS#8 private key, SPKI public key).
+    pub fn rs256_from_pem(
+        kid: &str,
+        private_pem: &[u8],
+        public_pem: &[u8],
+    ) -> Result<Self, TokenError> {
+        let enc = EncodingKey::from_rsa_pem(private_pem).map_err(invalid_key)?;
+        let dec = DecodingKey::from_rsa_pem(public_pem).map_err(invalid_key)?;
+        Self::build(kid, Algorithm::RS256, Some(enc), dec)
+    }
+
+    /// Verification-only RSA key (another service holds the private key).
+    pub fn rs256_public_pem(kid: &str, public_pem: &[u8]) -> Result<Self, TokenError> {
+        let dec = DecodingKey::from_rsa_pem(public_pem).map_err(invalid_key)?;
+        Self::build(kid, Algorithm::RS256, None, dec)
+    }
+
+    /// Ed25519 key pair from PEM (PKCS#8 private key, SPKI public key).
+    pub fn eddsa_from_pem(
+        kid: &str,
+        private_pem: &[u8],
+        public_pem: &[u8],
+    ) -> Result<Self, TokenError> {
+        let enc = EncodingKey::from_ed_pem(private_pem).map_err(invalid_key)?;
+        let dec = DecodingKey::from_ed_pem(public_pem).map_err(invalid_key)?;
+        Self::build(kid, Algorithm::EdDSA, Some(enc), dec)
+    }
+
+    /// Verification-only Ed25519 key.
+    pub fn eddsa_public_pem(kid: &str, public_pem: &[u8]) -> Result<Self, TokenError> {
+        let dec = DecodingKey::from_ed_pem(public_pem).map_err(invalid_key)?;
+        Self::build(kid, Algorithm::EdDSA, None, dec)
+    }
+
+    /// Schedule the key: it will only be picked for signing from `not_before_ms` on.
+    pub fn with_not_before(mut self, not_before_ms: u64) -> Self {
+        self.not_before_ms = not_before_ms;
+        self
+    }
+
+    pub fn kid(&self) -> &str {
+        &self.kid
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn not_before_ms(&self) -> u64 {
+        self.not_before_ms
+    }
+
+    pub fn retired_at_ms(&self) -> Option<u64> {
+        self.retired_at_ms
+    }
+
+    /// True if a private key / secret is available.
+    pub fn can_sign(&self) -> bool {
+        self.enc.is_some()
+    }
+
+    /// Can this key sign new tokens at `now_ms`?
+    pub fn is_signing_at(&self, now_ms: u64) -> bool {
+        self.can_sign() && self.not_before_ms <= now_ms && self.is_verifying_at(now_ms)
+    }
+
+    /// Are tokens signed with this key still accepted at `now_ms`?
+    pub fn is_verifying_at(&self, now_ms: u64) -> bool {
+        self.retired_at_ms.is_none_or(|retired| now_ms < retired)
+    }
+
+    pub(crate) fn encoding_key(&self) -> Option<&EncodingKey> {
+        self.enc.as_ref()
+    }
+
+    pub(crate) fn decoding_key(&self) -> &DecodingKey {
+        &self.dec
+    }
+
+    pub(crate) fn retire_at(&mut self, retired_at_ms: u64) {
+        // Never extend a retirement that is already scheduled earlier.
+        self.retired_at_ms = Some(
+            self.retired_at_ms
+                .map_or(retired_at_ms, |current| current.min(retired_at_ms)),
+        );
+    }
+
+    fn build(
+        kid: &str,
+        algorithm: Algorithm,
+        enc: Option<EncodingKey>,
+        dec: DecodingKey,
+    ) -> Result<Self, TokenError> {
+        let kid = kid.trim();
+        if kid.is_empty() {
+            return Err(TokenError::InvalidKey("empty kid".to_string()));
+        }
+
+        Ok(Self {
+            kid: kid.to_string(),
+            algorithm,
+            enc,
+            dec,
+            not_before_ms: 0,
+            retired_at_ms: None,
+        })
+    }
+}
+
+fn invalid_key(e: jsonwebtoken::errors::Error) -> TokenError {
+    TokenError::InvalidKey(e.to_string())
+}
@@ -5,6 +5,11 @@ use protocol::ProtocolId;
 
 use crate::{Role, Token};
 
+pub const ED_PRIVATE_PEM: &[u8] = include_bytes!("fixtures/ed.pem");
+pub const ED_PUBLIC_PEM: &[u8] = include_bytes!("fixtures/ed_pub.pem");
+pub const RSA_PRIVATE_PEM: &[u8] = include_bytes!("fixtures/rsa.pem");
+pub const RSA_PUBLIC_PEM: &[u8] = include_bytes!("fixtures/rsa_pub.pem");
+
 /// Helper to create a ProtocolId from a single byte pattern
 pub fn test_protocol_id(byte: u8) -> ProtocolId {
     ProtocolId::new(Id128::from_bytes_unchecked([byte; 16]))
@@ -0,0 +1,57 @@
+use crate::{KeySet, SigningKey, TokenError};
+
+use super::helpers::{ED_PUBLIC_PEM, RSA_PRIVATE_PEM, RSA_PUBLIC_PEM};
+
+#[test]
+fn test_duplicate_kid_is_rejected() {
+    let mut keys = KeySet::new();
+    keys.insert(SigningKey::hs256("k1", &"a".repeat(32)).expect("key"))
+        .expect("insert");
+    assert!(matches!(
+        keys.insert(SigningKey::hs256("k1", &"b".repeat(32)).expect("key")),
+        Err(TokenError::DuplicateKeyId(_))
+    ));
+}
+
+#[test]
+fn test_scheduled_key_signs_only_after_activation() {
+    let mut keys = KeySet::new();
+    keys.insert(
+        SigningKey::hs256("old", &"a".repeat(32))
+            .expect("key")
+            .with_not_before(100),
+    )
+    .expect("insert");
+    let next = SigningKey::rs256_from_pem("new", RSA_PRIVATE_PEM, RSA_PUBLIC_PEM)
+        .expect("rsa key")
+        .with_not_before(1_000);
+    keys.rotate(next, 500).expect("rotate");
+
+    assert_eq!(keys.signing_key(999).map(|k| k.kid()), Some("old"));
+    assert_eq!(keys.signing_key(1_000).map(|k| k.kid()), Some("new"));
+
+    let old = keys.get("old").expect("old key");
+    assert!(old.is_verifying_at(1_499));
+    assert!(!old.is_verifying_at(1_500));
+
+    assert_eq!(keys.prune(1_500), vec!["old".to_string()]);
+    assert_eq!(keys.kids(), vec!["new".to_string()]);
+}
+
+#[test]
+fn test_rotate_requires_private_key() {
+    let mut keys = KeySet::new();
+    let public_only = SigningKey::eddsa_public_pem("ed", ED_PUBLIC_PEM).expect("ed key");
+    assert!(matches!(
+        keys.rotate(public_only, 0),
+        Err(TokenError::InvalidKey(_))
+    ));
+}
+
+#[test]
+fn test_invalid_pem_is_rejected() {
+    assert!(matches!(
+        SigningKey::rs256_public_pem("rsa", b"not a pem"),
+        Err(TokenError::InvalidKey(_))
+    ));
+}
@@ -1,6 +1,8 @@
 mod helpers;
+mod key_set;
 mod password;
 mod permissions;
+mod revocation_store;
 mod role;
 mod token;
 mod token_service;
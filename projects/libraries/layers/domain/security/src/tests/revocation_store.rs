@@ -0,0 +1,47 @@
+use crate::{FileRevocationStore, InMemoryRevocationStore, RevocationStore};
+
+use super::helpers::test_protocol_id;
+
+#[test]
+fn test_in_memory_store_revoke_and_purge() {
+    let store = InMemoryRevocationStore::new();
+    store.revoke(test_protocol_id(1), 1_000).expect("revoke");
+    store.revoke(test_protocol_id(2), 5_000).expect("revoke");
+
+    assert!(store.is_revoked(&test_protocol_id(1)).expect("lookup"));
+    assert!(!store.is_revoked(&test_protocol_id(3)).expect("lookup"));
+
+    assert_eq!(store.purge_expired(2_000).expect("purge"), 1);
+    assert!(!store.is_revoked(&test_protocol_id(1)).expect("lookup"));
+    assert!(store.is_revoked(&test_protocol_id(2)).expect("lookup"));
+}
+
+#[test]
+fn test_file_store_persists_across_reopen() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("revoked.txt");
+
+    {
+        let store = FileRevocationStore::open(&path).expect("open store");
+        store.revoke(test_protocol_id(1), 1_000).expect("revoke");
+        store.revoke(test_protocol_id(2), 5_000).expect("revoke");
+    }
+
+    let store = FileRevocationStore::open(&path).expect("reopen store");
+    assert!(store.is_revoked(&test_protocol_id(1)).expect("lookup"));
+    assert!(store.is_revoked(&test_protocol_id(2)).expect("lookup"));
+
+    assert_eq!(store.purge_expired(2_000).expect("purge"), 1);
+    let store = FileRevocationStore::open(&path).expect("reopen store");
+    assert!(!store.is_revoked(&test_protocol_id(1)).expect("lookup"));
+    assert!(store.is_revoked(&test_protocol_id(2)).expect("lookup"));
+}
+
+#[test]
+fn test_file_store_rejects_malformed_lines() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("revoked.txt");
+    std::fs::write(&path, "not-a-jti\n").expect("write file");
+
+    assert!(FileRevocationStore::open(&path).is_err());
+}
@@ -1,8 +1,13 @@
-use crate::{Claims, Role, TokenError, TokenService};
+use crate::{Claims, KeySet, Role, SigningKey, TokenError, TokenService, TokenUse};
+use std::sync::Arc;
+
+use crate::InMemoryRevocationStore;
 use common_time::timestamp_utils::current_timestamp_ms;
 use jsonwebtoken::{Algorithm, EncodingKey, Header};
 
-use super::helpers::test_protocol_id;
+use super::helpers::{
+    ED_PRIVATE_PEM, ED_PUBLIC_PEM, RSA_PRIVATE_PEM, RSA_PUBLIC_PEM, test_protocol_id,
+};
 
 #[test]
 fn test_secret_too_short() {
@@ -27,6 +32,7 @@ fn test_expired_token() {
         iat: now_s.saturating_sub(10),
         exp: now_s.saturating_sub(2),
         sid: None,
+        token_use: TokenUse::Access,
     };
     let jwt = jsonwebtoken::encode(
         &Header::new(Algorithm::HS256),
@@ -70,3 +76,284 @@ fn test_issue_sets_expected_iat_exp_relationship() {
         exp_delta
     );
 }
+
+#[test]
+fn test_issue_sets_default_kid() {
+    let service = TokenService::new_hs256(&"a".repeat(32)).expect("token service init");
+    let jwt = service
+        .issue(test_protocol_id(7), Role::User, 60000, None)
+        .expect("issue token");
+
+    let header = jsonwebtoken::decode_header(&jwt).expect("decode header");
+    assert_eq!(header.kid.as_deref(), Some(crate::DEFAULT_KEY_ID));
+    assert_eq!(header.alg, Algorithm::HS256);
+}
+
+#[test]
+fn test_verify_accepts_legacy_token_without_kid() {
+    let secret = "a".repeat(32);
+    let service = TokenService::new_hs256(&secret).expect("token service init");
+
+    let now_s = current_timestamp_ms() / 1_000;
+    let claims = Claims {
+        sub: test_protocol_id(1),
+        jti: test_protocol_id(99),
+        role: Role::User,
+        iat: now_s,
+        exp: now_s + 60,
+        sid: None,
+        token_use: TokenUse::Access,
+    };
+    let jwt = jsonwebtoken::encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .expect("encode legacy token");
+
+    assert!(service.verify(&jwt).is_ok());
+}
+
+#[test]
+fn test_rs256_round_trip() {
+    let key =
+        SigningKey::rs256_from_pem("rsa-1", RSA_PRIVATE_PEM, RSA_PUBLIC_PEM).expect("rsa key");
+    let mut keys = KeySet::new();
+    keys.insert(key).expect("insert key");
+    let service = TokenService::with_key_set(keys, 0).expect("token service init");
+
+    let jwt = service
+        .issue(test_protocol_id(5), Role::Admin, 60000, None)
+        .expect("issue token");
+    let header = jsonwebtoken::decode_header(&jwt).expect("decode header");
+    assert_eq!(header.alg, Algorithm::RS256);
+
+    let token = service.verify(&jwt).expect("verify token");
+    assert_eq!(token.role, Role::Admin);
+}
+
+#[test]
+fn test_eddsa_verify_only_service() {
+    let signer_key =
+        SigningKey::eddsa_from_pem("ed-1", ED_PRIVATE_PEM, ED_PUBLIC_PEM).expect("ed key");
+    let mut signer_keys = KeySet::new();
+    signer_keys.insert(signer_key).expect("insert key");
+    let signer = TokenService::with_key_set(signer_keys, 0).expect("signer init");
+
+    let verifier_key = SigningKey::eddsa_public_pem("ed-1", ED_PUBLIC_PEM).expect("ed public key");
+    let mut verifier_keys = KeySet::new();
+    verifier_keys.insert(verifier_key).expect("insert key");
+    let verifier = TokenService::with_key_set(verifier_keys, 0).expect("verifier init");
+
+    let jwt = signer
+        .issue(test_protocol_id(5), Role::User, 60000, None)
+        .expect("issue token");
+    assert_eq!(
+        verifier.verify(&jwt).expect("verify token").subject_id,
+        test_protocol_id(5)
+    );
+    assert!(matches!(
+        verifier.issue(test_protocol_id(5), Role::User, 60000, None),
+        Err(TokenError::NoSigningKey)
+    ));
+}
+
+#[test]
+fn test_unknown_kid_is_rejected() {
+    let issuer = TokenService::new_hs256(&"a".repeat(32)).expect("issuer init");
+    let key = SigningKey::hs256("other", &"a".repeat(32)).expect("key");
+    let mut keys = KeySet::new();
+    keys.insert(key).expect("insert key");
+    let verifier = TokenService::with_key_set(keys, 0).expect("verifier init");
+
+    let jwt = issuer
+        .issue(test_protocol_id(5), Role::User, 60000, None)
+        .expect("issue token");
+    assert!(matches!(
+        verifier.verify(&jwt),
+        Err(TokenError::UnknownKeyId(kid)) if kid == crate::DEFAULT_KEY_ID
+    ));
+}
+
+#[test]
+fn test_rotation_keeps_old_tokens_valid_during_grace() {
+    let service = TokenService::new_hs256(&"a".repeat(32)).expect("token service init");
+    let old_jwt = service
+        .issue(test_protocol_id(5), Role::User, 60000, None)
+        .expect("issue token");
+
+    let next = SigningKey::eddsa_from_pem("ed-2", ED_PRIVATE_PEM, ED_PUBLIC_PEM).expect("ed key");
+    service.rotate_key(next, 60_000).expect("rotate");
+
+    assert_eq!(service.active_key_id().as_deref(), Some("ed-2"));
+    assert!(service.verify(&old_jwt).is_ok());
+
+    let new_jwt = service
+        .issue(test_protocol_id(5), Role::User, 60000, None)
+        .expect("issue token");
+    let header = jsonwebtoken::decode_header(&new_jwt).expect("decode header");
+    assert_eq!(header.kid.as_deref(), Some("ed-2"));
+    assert!(service.prune_retired_keys().is_empty());
+}
+
+#[test]
+fn test_rotation_without_grace_retires_old_key() {
+    let service = TokenService::new_hs256(&"a".repeat(32)).expect("token service init");
+    let old_jwt = service
+        .issue(test_protocol_id(5), Role::User, 60000, None)
+        .expect("issue token");
+
+    let next = SigningKey::hs256("next", &"b".repeat(32)).expect("key");
+    service.rotate_key(next, 0).expect("rotate");
+
+    assert!(matches!(
+        service.verify(&old_jwt),
+        Err(TokenError::UnknownKeyId(_))
+    ));
+    assert_eq!(
+        service.prune_retired_keys(),
+        vec![crate::DEFAULT_KEY_ID.to_string()]
+    );
+}
+
+#[test]
+fn test_revoked_token_is_rejected() {
+    let store = Arc::new(InMemoryRevocationStore::new());
+    let service = TokenService::new_hs256(&"a".repeat(32))
+        .expect("token service init")
+        .with_revocation_store(store.clone());
+
+    let jwt = service
+        .issue(test_protocol_id(5), Role::User, 60000, None)
+        .expect("issue token");
+    let token = service.verify(&jwt).expect("verify token");
+
+    service.revoke(&token).expect("revoke");
+    assert!(matches!(service.verify(&jwt), Err(TokenError::Revoked)));
+    assert_eq!(store.len(), 1);
+}
+
+#[test]
+fn test_revoke_without_store_fails() {
+    let service = TokenService::new_hs256(&"a".repeat(32)).expect("token service init");
+    let jwt = service
+        .issue(test_protocol_id(5), Role::User, 60000, None)
+        .expect("issue token");
+    let token = service.verify(&jwt).expect("verify token");
+
+    assert!(matches!(
+        service.revoke(&token),
+        Err(TokenError::RevocationUnavailable)
+    ));
+}
+
+#[test]
+fn test_refresh_issues_access_token_for_same_session() {
+    let service = TokenService::new_hs256(&"a".repeat(32)).expect("token service init");
+    let sid = test_protocol_id(42);
+    let refresh_jwt = service
+        .issue_refresh(test_protocol_id(5), Role::User, 600_000, sid)
+        .expect("issue refresh");
+
+    let access_jwt = service.refresh(&refresh_jwt, 60000).expect("refresh");
+    let token = service.verify(&access_jwt).expect("verify access");
+    assert_eq!(token.session_id, Some(sid));
+    assert_eq!(token.subject_id, test_protocol_id(5));
+}
+
+#[test]
+fn test_token_use_is_enforced() {
+    let service = TokenService::new_hs256(&"a".repeat(32)).expect("token service init");
+    let refresh_jwt = service
+        .issue_refresh(
+            test_protocol_id(5),
+            Role::User,
+            600_000,
+            test_protocol_id(42),
+        )
+        .expect("issue refresh");
+    let access_jwt = service
+        .issue(
+            test_protocol_id(5),
+            Role::User,
+            60000,
+            Some(test_protocol_id(42)),
+        )
+        .expect("issue access");
+
+    assert!(matches!(
+        service.verify(&refresh_jwt),
+        Err(TokenError::UnexpectedTokenUse)
+    ));
+    assert!(matches!(
+        service.refresh(&access_jwt, 60000),
+        Err(TokenError::UnexpectedTokenUse)
+    ));
+}
+
+#[test]
+fn test_revoked_refresh_token_cannot_refresh() {
+    let service = TokenService::new_hs256(&"a".repeat(32))
+        .expect("token service init")
+        .with_revocation_store(Arc::new(InMemoryRevocationStore::new()));
+    let refresh_jwt = service
+        .issue_refresh(
+            test_protocol_id(5),
+            Role::User,
+            600_000,
+            test_protocol_id(42),
+        )
+        .expect("issue refresh");
+    let refresh = service
+        .verify_refresh(&refresh_jwt)
+        .expect("verify refresh");
+
+    service.revoke(&refresh).expect("revoke");
+    assert!(matches!(
+        service.refresh(&refresh_jwt, 60000),
+        Err(TokenError::Revoked)
+    ));
+}
+
+#[test]
+fn test_revoked_session_rejects_all_its_tokens() {
+    let store = Arc::new(InMemoryRevocationStore::new());
+    let service = TokenService::new_hs256(&"a".repeat(32))
+        .expect("token service init")
+        .with_session_revocation_store(store.clone());
+    let sid = test_protocol_id(42);
+    let other_sid = test_protocol_id(43);
+    let access_jwt = service
+        .issue(test_protocol_id(5), Role::User, 60000, Some(sid))
+        .expect("issue access");
+    let refresh_jwt = service
+        .issue_refresh(test_protocol_id(5), Role::User, 600_000, sid)
+        .expect("issue refresh");
+    let other_jwt = service
+        .issue(test_protocol_id(5), Role::User, 60000, Some(other_sid))
+        .expect("issue other access");
+
+    service
+        .revoke_session(sid, current_timestamp_ms() + 600_000)
+        .expect("revoke session");
+
+    assert!(matches!(
+        service.verify(&access_jwt),
+        Err(TokenError::SessionRevoked)
+    ));
+    assert!(matches!(
+        service.refresh(&refresh_jwt, 60000),
+        Err(TokenError::SessionRevoked)
+    ));
+    assert!(service.verify(&other_jwt).is_ok());
+    assert_eq!(store.len(), 1);
+}
+
+#[test]
+fn test_revoke_session_without_store_fails() {
+    let service = TokenService::new_hs256(&"a".repeat(32)).expect("token service init");
+    assert!(matches!(
+        service.revoke_session(test_protocol_id(42), current_timestamp_ms() + 60000),
+        Err(TokenError::RevocationUnavailable)
+    ));
+}
@@ -35,4 +35,31 @@ pub enum TokenError {
 
     #[error("cannot renew expired token")]
     CannotRenewExpired,
+
+    #[error("invalid key: {0}")]
+    InvalidKey(String),
+
+    #[error("duplicate key id: {0}")]
+    DuplicateKeyId(String),
+
+    #[error("unknown key id: {0}")]
+    UnknownKeyId(String),
+
+    #[error("no active signing key")]
+    NoSigningKey,
+
+    #[error("token revoked")]
+    Revoked,
+
+    #[error("session revoked")]
+    SessionRevoked,
+
+    #[error("unexpected token use")]
+    UnexpectedTokenUse,
+
+    #[error("no revocation store configured")]
+    RevocationUnavailable,
+
+    #[error("revocation store error: {0}")]
+    RevocationStore(String),
 }
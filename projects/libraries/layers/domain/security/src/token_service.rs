@@ -1,17 +1,24 @@
 // projects/libraries/layers/domain/security/src/token_service.rs
-use crate::{Claims, Role, Token, TokenError};
+use std::sync::{Arc, RwLock};
+
+use crate::{Claims, KeySet, RevocationStore, Role, SigningKey, Token, TokenError, TokenUse};
 use common::common_id::CommonID;
 use common_time::timestamp_utils::current_timestamp_ms;
-use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{Algorithm, Header, Validation};
 use protocol::ProtocolId;
 
+/// `kid` given to the key built by `new_hs256`.
+pub const DEFAULT_KEY_ID: &str = "default";
+
 /// Service to issue/verify JWTs.
-/// - Stateless: no need for a store.
+/// - Keys live in a `KeySet` shared by all clones, so a rotation is seen everywhere.
+/// - Stateless unless a `RevocationStore` is attached (per token or per session).
 #[derive(Clone)]
 pub struct TokenService {
-    enc: EncodingKey,
-    dec: DecodingKey,
-    validation: Validation,
+    keys: Arc<RwLock<KeySet>>,
+    leeway_seconds: u64,
+    revocations: Option<Arc<dyn RevocationStore>>,
+    session_revocations: Option<Arc<dyn RevocationStore>>,
 }
 
 impl TokenService {
@@ -23,36 +30,200 @@ impl TokenService {
     /// secret: robust string (ENV). Minimum 32 characters recommended.
     /// leeway_seconds: grace window allowed after exp for clock skew handling.
     pub fn new_hs256_with_leeway(secret: &str, leeway_seconds: u64) -> Result<Self, TokenError> {
-        let s = secret.trim();
-        if s.is_empty() {
-            return Err(TokenError::MissingSecret);
-        }
-        if s.len() < 32 {
-            return Err(TokenError::SecretTooShort);
-        }
+        let mut keys = KeySet::new();
+        keys.insert(SigningKey::hs256(DEFAULT_KEY_ID, secret)?)?;
+        Self::with_key_set(keys, leeway_seconds)
+    }
 
-        let mut validation = Validation::new(Algorithm::HS256);
-        validation.validate_exp = true;
-        validation.required_spec_claims.insert("exp".to_string());
-        validation.required_spec_claims.insert("iat".to_string());
-        validation.required_spec_claims.insert("sub".to_string());
-        validation.required_spec_claims.insert("jti".to_string());
-        validation.leeway = leeway_seconds;
+    /// Build from a key set (HS256, RS256 and/or EdDSA keys).
+    /// A set with only public keys is valid: the service can then verify but not issue.
+    pub fn with_key_set(keys: KeySet, leeway_seconds: u64) -> Result<Self, TokenError> {
+        if keys.is_empty() {
+            return Err(TokenError::NoSigningKey);
+        }
 
         Ok(Self {
-            enc: EncodingKey::from_secret(s.as_bytes()),
-            dec: DecodingKey::from_secret(s.as_bytes()),
-            validation,
+            keys: Arc::new(RwLock::new(keys)),
+            leeway_seconds,
+            revocations: None,
+            session_revocations: None,
         })
     }
 
-    /// Issue a signed JWT. duration_ms must be > 0.
+    /// Attach a revocation store: `verify` then rejects revoked `jti`s.
+    pub fn with_revocation_store(mut self, store: Arc<dyn RevocationStore>) -> Self {
+        self.revocations = Some(store);
+        self
+    }
+
+    /// Attach a store of revoked session ids (`sid`): `verify` and `refresh`
+    /// then reject every token of a revoked session.
+    pub fn with_session_revocation_store(mut self, store: Arc<dyn RevocationStore>) -> Self {
+        self.session_revocations = Some(store);
+        self
+    }
+
+    /// Schedule `next` as the signing key (immediately if its `not_before` is in the past).
+    /// Current keys keep verifying for `grace_ms` after the activation.
+    pub fn rotate_key(&self, next: SigningKey, grace_ms: u64) -> Result<(), TokenError> {
+        let now_ms = current_timestamp_ms();
+        let next = if next.not_before_ms() < now_ms {
+            next.with_not_before(now_ms)
+        } else {
+            next
+        };
+
+        let mut keys = self.keys.write().map_err(|_| TokenError::NoSigningKey)?;
+        keys.rotate(next, grace_ms)
+    }
+
+    /// Remove keys whose rotation grace window is over. Returns the removed `kid`s.
+    pub fn prune_retired_keys(&self) -> Vec<String> {
+        match self.keys.write() {
+            Ok(mut keys) => keys.prune(current_timestamp_ms()),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// `kid` of the key currently used to sign, if any.
+    pub fn active_key_id(&self) -> Option<String> {
+        let keys = self.keys.read().ok()?;
+        keys.signing_key(current_timestamp_ms())
+            .map(|k| k.kid().to_string())
+    }
+
+    /// Issue a signed access JWT. duration_ms must be > 0.
     pub fn issue(
         &self,
         subject_id: ProtocolId,
         role: Role,
         duration_ms: u64,
         session_id: Option<ProtocolId>,
+    ) -> Result<String, TokenError> {
+        self.issue_with_use(subject_id, role, duration_ms, session_id, TokenUse::Access)
+    }
+
+    /// Issue a signed refresh JWT bound to `session_id`. duration_ms must be > 0.
+    /// Only `refresh` accepts it; `verify` rejects it.
+    pub fn issue_refresh(
+        &self,
+        subject_id: ProtocolId,
+        role: Role,
+        duration_ms: u64,
+        session_id: ProtocolId,
+    ) -> Result<String, TokenError> {
+        self.issue_with_use(
+            subject_id,
+            role,
+            duration_ms,
+            Some(session_id),
+            TokenUse::Refresh,
+        )
+    }
+
+    /// Exchange a valid refresh JWT for a new access JWT on the same session.
+    pub fn refresh(
+        &self,
+        refresh_jwt: &str,
+        access_duration_ms: u64,
+    ) -> Result<String, TokenError> {
+        let refresh = self.verify_with_use(refresh_jwt, TokenUse::Refresh)?;
+        let session_id = refresh.session_id.ok_or(TokenError::InvalidSessionId)?;
+
+        self.issue(
+            refresh.subject_id,
+            refresh.role,
+            access_duration_ms,
+            Some(session_id),
+        )
+    }
+
+    /// Verify an access JWT and return a strongly typed Token (convenient for app).
+    pub fn verify(&self, jwt: &str) -> Result<Token, TokenError> {
+        self.verify_with_use(jwt, TokenUse::Access)
+    }
+
+    /// Verify a refresh JWT without exchanging it.
+    pub fn verify_refresh(&self, jwt: &str) -> Result<Token, TokenError> {
+        self.verify_with_use(jwt, TokenUse::Refresh)
+    }
+
+    /// Revoke a verified token until it expires. Requires a revocation store.
+    pub fn revoke(&self, token: &Token) -> Result<(), TokenError> {
+        let store = self
+            .revocations
+            .as_ref()
+            .ok_or(TokenError::RevocationUnavailable)?;
+        store.revoke(token.value, token.expires_at_ms)
+    }
+
+    /// Revoke every token of session `session_id` issued until `expires_at_ms`
+    /// (the latest expiration a token of the session can have).
+    /// Requires a session revocation store.
+    pub fn revoke_session(
+        &self,
+        session_id: ProtocolId,
+        expires_at_ms: u64,
+    ) -> Result<(), TokenError> {
+        let store = self
+            .session_revocations
+            .as_ref()
+            .ok_or(TokenError::RevocationUnavailable)?;
+        store.revoke(session_id, expires_at_ms)
+    }
+
+    /// Drop revocation entries of already expired tokens and sessions.
+    /// No-op without a store.
+    pub fn purge_revocations(&self) -> Result<usize, TokenError> {
+        let now_ms = current_timestamp_ms();
+        let mut purged = 0;
+        for store in [&self.revocations, &self.session_revocations]
+            .into_iter()
+            .flatten()
+        {
+            purged += store.purge_expired(now_ms)?;
+        }
+        Ok(purged)
+    }
+
+    /// Renew an existing token (creates a new signed JWT)
+    pub fn renew(
+        &self,
+        old_token: &Token,
+        additional_duration_ms: u64,
+    ) -> Result<String, TokenError> {
+        if old_token.is_expired() {
+            return Err(TokenError::CannotRenewExpired);
+        }
+
+        let remaining_ms = old_token.time_until_expiry_ms().max(0) as u64;
+        let new_duration = remaining_ms
+            .checked_add(additional_duration_ms)
+            .ok_or(TokenError::TimestampOverflow)?;
+
+        self.issue(
+            old_token.subject_id,
+            old_token.role,
+            new_duration,
+            old_token.session_id,
+        )
+    }
+
+    /// Validate a token's claims.
+    pub fn validate_token(&self, token: &Token) -> Result<(), TokenError> {
+        if !CommonID::is_valid(token.subject_id.as_inner()) {
+            return Err(TokenError::InvalidSubjectIdValue);
+        }
+        Ok(())
+    }
+
+    fn issue_with_use(
+        &self,
+        subject_id: ProtocolId,
+        role: Role,
+        duration_ms: u64,
+        session_id: Option<ProtocolId>,
+        token_use: TokenUse,
     ) -> Result<String, TokenError> {
         if duration_ms == 0 {
             return Err(TokenError::InvalidDuration);
@@ -82,30 +253,62 @@ impl TokenService {
             iat: now_s,
             exp: exp_s,
             sid: session_id,
+            token_use,
         };
 
-        jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims, &self.enc)
-            .map_err(|e| TokenError::Jwt(e.to_string()))
+        let keys = self.keys.read().map_err(|_| TokenError::NoSigningKey)?;
+        let key = keys.signing_key(now_ms).ok_or(TokenError::NoSigningKey)?;
+        let enc = key.encoding_key().ok_or(TokenError::NoSigningKey)?;
+
+        let mut header = Header::new(key.algorithm());
+        header.kid = Some(key.kid().to_string());
+
+        jsonwebtoken::encode(&header, &claims, enc).map_err(|e| TokenError::Jwt(e.to_string()))
     }
 
-    /// Verify a JWT and return a strongly typed Token (convenient for app).
-    pub fn verify(&self, jwt: &str) -> Result<Token, TokenError> {
+    fn verify_with_use(&self, jwt: &str, expected_use: TokenUse) -> Result<Token, TokenError> {
         let jwt = jwt.trim();
         if jwt.is_empty() {
             return Err(TokenError::InvalidToken);
         }
 
-        let data =
-            jsonwebtoken::decode::<Claims>(jwt, &self.dec, &self.validation).map_err(|e| {
+        let header =
+            jsonwebtoken::decode_header(jwt).map_err(|e| TokenError::Jwt(e.to_string()))?;
+
+        let now_ms = current_timestamp_ms();
+        let data = {
+            let keys = self.keys.read().map_err(|_| TokenError::NoSigningKey)?;
+            // Tokens issued before key ids existed carry no `kid`: use the signing key.
+            let key = match header.kid.as_deref() {
+                Some(kid) => keys
+                    .get(kid)
+                    .ok_or_else(|| TokenError::UnknownKeyId(kid.to_string()))?,
+                None => keys.signing_key(now_ms).ok_or(TokenError::NoSigningKey)?,
+            };
+            if !key.is_verifying_at(now_ms) {
+                return Err(TokenError::UnknownKeyId(key.kid().to_string()));
+            }
+
+            jsonwebtoken::decode::<Claims>(
+                jwt,
+                key.decoding_key(),
+                &self.validation(key.algorithm()),
+            )
+            .map_err(|e| {
                 if e.kind() == &jsonwebtoken::errors::ErrorKind::ExpiredSignature {
                     TokenError::Expired
                 } else {
                     TokenError::Jwt(e.to_string())
                 }
-            })?;
+            })?
+        };
 
         let c = data.claims;
 
+        if c.token_use != expected_use {
+            return Err(TokenError::UnexpectedTokenUse);
+        }
+
         // Hardening: validate sub numeric + CommonID validation
         let subject_id = c.sub;
 
@@ -113,6 +316,19 @@ impl TokenService {
             return Err(TokenError::InvalidSubjectIdValue);
         }
 
+        if let Some(store) = &self.revocations
+            && store.is_revoked(&c.jti)?
+        {
+            return Err(TokenError::Revoked);
+        }
+
+        if let Some(store) = &self.session_revocations
+            && let Some(sid) = &c.sid
+            && store.is_revoked(sid)?
+        {
+            return Err(TokenError::SessionRevoked);
+        }
+
         let issued_at_ms = c.iat.saturating_mul(1000);
         let expires_at_ms = c.exp.saturating_mul(1000);
 
@@ -128,34 +344,14 @@ impl TokenService {
         })
     }
 
-    /// Renew an existing token (creates a new signed JWT)
-    pub fn renew(
-        &self,
-        old_token: &Token,
-        additional_duration_ms: u64,
-    ) -> Result<String, TokenError> {
-        if old_token.is_expired() {
-            return Err(TokenError::CannotRenewExpired);
-        }
-
-        let remaining_ms = old_token.time_until_expiry_ms().max(0) as u64;
-        let new_duration = remaining_ms
-            .checked_add(additional_duration_ms)
-            .ok_or(TokenError::TimestampOverflow)?;
-
-        self.issue(
-            old_token.subject_id,
-            old_token.role,
-            new_duration,
-            old_token.session_id,
-        )
-    }
-
-    /// Validate a token's claims.
-    pub fn validate_token(&self, token: &Token) -> Result<(), TokenError> {
-        if !CommonID::is_valid(token.subject_id.as_inner()) {
-            return Err(TokenError::InvalidSubjectIdValue);
-        }
-        Ok(())
+    fn validation(&self, algorithm: Algorithm) -> Validation {
+        let mut validation = Validation::new(algorithm);
+        validation.validate_exp = true;
+        validation.required_spec_claims.insert("exp".to_string());
+        validation.required_spec_claims.insert("iat".to_string());
+        validation.required_spec_claims.insert("sub".to_string());
+        validation.required_spec_claims.insert("jti".to_string());
+        validation.leeway = self.leeway_seconds;
+        validation
     }
 }
@@ -0,0 +1,14 @@
+// projects/libraries/layers/domain/security/src/token_use.rs
+use serde::{Deserialize, Serialize};
+
+/// What a JWT may be used for.
+/// Tokens issued before this claim existed deserialize as `Access`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenUse {
+    /// Short-lived token presented on every request.
+    #[default]
+    Access,
+    /// Long-lived token only accepted by `TokenService::refresh`; always bound to a session.
+    Refresh,
+}
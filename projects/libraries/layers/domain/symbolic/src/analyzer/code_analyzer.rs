@@ -1,6 +1,7 @@
 // projects/libraries/layers/domain/symbolic/src/analyzer/code_analyzer.rs
 // Implementation of the CodeAnalyzer structure
 
+#[derive(Clone)]
 pub struct CodeAnalyzer;
 
 impl Default for CodeAnalyzer {
@@ -0,0 +1,137 @@
+// projects/libraries/layers/domain/symbolic/src/linter/blocking_call_visitor.rs
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{Expr, ExprAsync, ExprCall, ExprClosure, ImplItemFn, ItemFn};
+
+use crate::linter::syntax_helpers::{path_tail, rewrite_line, source_slice};
+use crate::linter::{LintDiagnostic, LintFix, LintRule, LintSpan};
+use crate::validator::Severity;
+
+/// Std calls that block the executor thread when made from async code
+const BLOCKING_CALLS: &[&str] = &[
+    "thread::sleep",
+    "fs::read",
+    "fs::read_to_string",
+    "fs::read_dir",
+    "fs::write",
+    "fs::copy",
+    "fs::create_dir_all",
+    "fs::remove_file",
+    "fs::remove_dir_all",
+    "File::open",
+    "File::create",
+    "TcpStream::connect",
+    "TcpListener::bind",
+    "UdpSocket::bind",
+];
+
+/// Crates whose identically named functions are already async
+const ASYNC_CRATES: &[&str] = &["tokio", "async_std", "smol"];
+
+/// Visitor reporting blocking std calls inside async fns, blocks and closures
+pub struct BlockingCallVisitor<'src> {
+    lines: &'src [&'src str],
+    severity: Severity,
+    async_context: Vec<bool>,
+    pub diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'src> BlockingCallVisitor<'src> {
+    pub fn new(lines: &'src [&'src str], severity: Severity) -> Self {
+        Self {
+            lines,
+            severity,
+            async_context: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn in_async(&self) -> bool {
+        self.async_context.last().copied().unwrap_or(false)
+    }
+
+    fn with_context<F: FnOnce(&mut Self)>(&mut self, is_async: bool, visit: F) {
+        self.async_context.push(is_async);
+        visit(self);
+        self.async_context.pop();
+    }
+
+    /// `std::thread::sleep(d)` becomes `tokio::time::sleep(d).await`
+    fn sleep_fix(&self, call: &ExprCall, span: LintSpan) -> Option<LintFix> {
+        let func_span = call.func.span();
+        let call_end = call.span().end();
+        let arguments = source_slice(self.lines, func_span.end(), call_end)?;
+        let rewritten = rewrite_line(
+            self.lines,
+            func_span.start(),
+            call_end,
+            &format!("tokio::time::sleep{}.await", arguments),
+        )?;
+
+        LintFix::replace_line(
+            LintRule::BlockingInAsync,
+            span,
+            self.lines,
+            rewritten,
+            "Replace 'thread::sleep' with 'tokio::time::sleep(..).await'".to_string(),
+        )
+    }
+}
+
+impl<'ast> Visit<'ast> for BlockingCallVisitor<'_> {
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        self.with_context(item.sig.asyncness.is_some(), |this| {
+            syn::visit::visit_item_fn(this, item)
+        });
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast ImplItemFn) {
+        self.with_context(item.sig.asyncness.is_some(), |this| {
+            syn::visit::visit_impl_item_fn(this, item)
+        });
+    }
+
+    fn visit_expr_async(&mut self, block: &'ast ExprAsync) {
+        self.with_context(true, |this| syn::visit::visit_expr_async(this, block));
+    }
+
+    fn visit_expr_closure(&mut self, closure: &'ast ExprClosure) {
+        // Plain closures are usually handed to `spawn_blocking` and friends
+        self.with_context(closure.asyncness.is_some(), |this| {
+            syn::visit::visit_expr_closure(this, closure)
+        });
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if self.in_async()
+            && let Expr::Path(func) = call.func.as_ref()
+        {
+            let from_async_crate = func
+                .path
+                .segments
+                .first()
+                .is_some_and(|segment| ASYNC_CRATES.iter().any(|name| segment.ident == name));
+            let callee = path_tail(&func.path, 2);
+
+            if !from_async_crate && BLOCKING_CALLS.contains(&callee.as_str()) {
+                let span = LintSpan::from_span(call.span());
+                let mut diagnostic = LintDiagnostic::new(
+                    LintRule::BlockingInAsync,
+                    self.severity,
+                    format!(
+                        "'{}' blocks the executor inside async code; use the async equivalent or spawn_blocking",
+                        callee
+                    ),
+                    span,
+                );
+                if callee == "thread::sleep"
+                    && let Some(fix) = self.sleep_fix(call, span)
+                {
+                    diagnostic = diagnostic.with_fix(fix);
+                }
+                self.diagnostics.push(diagnostic);
+            }
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+}
@@ -0,0 +1,74 @@
+// projects/libraries/layers/domain/symbolic/src/linter/code_linter.rs
+use syn::File;
+use syn::visit::Visit;
+use tracing;
+
+use crate::linter::blocking_call_visitor::BlockingCallVisitor;
+use crate::linter::function_size_visitor::FunctionSizeVisitor;
+use crate::linter::shadowing_visitor::ShadowingVisitor;
+use crate::linter::unwrap_visitor::UnwrapVisitor;
+use crate::linter::wall_clock_visitor::WallClockVisitor;
+use crate::linter::{LintConfig, LintDiagnostic, LintError, LintRule};
+
+/// Runs the configured lint rules over Rust source code
+#[derive(Debug, Clone, Default)]
+pub struct CodeLinter {
+    config: LintConfig,
+}
+
+impl CodeLinter {
+    pub fn new(config: LintConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &LintConfig {
+        &self.config
+    }
+
+    /// Parses `code` and lints it; diagnostics are ordered by position
+    pub fn lint(&self, code: &str) -> Result<Vec<LintDiagnostic>, LintError> {
+        let syntax_tree = syn::parse_file(code)?;
+        Ok(self.lint_file(&syntax_tree, code))
+    }
+
+    /// Lints an already parsed file. `source` must be the text it was parsed from,
+    /// since fixes are computed against its lines.
+    pub fn lint_file(&self, syntax_tree: &File, source: &str) -> Vec<LintDiagnostic> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut diagnostics = Vec::new();
+
+        if let Some(severity) = self.config.severity_for(LintRule::UnwrapInLibrary) {
+            let mut visitor = UnwrapVisitor::new(&lines, severity);
+            visitor.visit_file(syntax_tree);
+            diagnostics.extend(visitor.diagnostics);
+        }
+
+        if let Some(severity) = self.config.severity_for(LintRule::WallClock) {
+            let mut visitor = WallClockVisitor::new(severity);
+            visitor.visit_file(syntax_tree);
+            diagnostics.extend(visitor.diagnostics);
+        }
+
+        if let Some(severity) = self.config.severity_for(LintRule::BlockingInAsync) {
+            let mut visitor = BlockingCallVisitor::new(&lines, severity);
+            visitor.visit_file(syntax_tree);
+            diagnostics.extend(visitor.diagnostics);
+        }
+
+        if let Some(severity) = self.config.severity_for(LintRule::LargeFunction) {
+            let mut visitor = FunctionSizeVisitor::new(severity, self.config.max_function_lines);
+            visitor.visit_file(syntax_tree);
+            diagnostics.extend(visitor.diagnostics);
+        }
+
+        if let Some(severity) = self.config.severity_for(LintRule::ShadowedBinding) {
+            let mut visitor = ShadowingVisitor::new(severity);
+            visitor.visit_file(syntax_tree);
+            diagnostics.extend(visitor.diagnostics);
+        }
+
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.span, diagnostic.rule.name()));
+        tracing::debug!("CodeLinter found {} diagnostics", diagnostics.len());
+        diagnostics
+    }
+}
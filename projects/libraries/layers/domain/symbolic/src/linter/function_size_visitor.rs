@@ -0,0 +1,61 @@
+// projects/libraries/layers/domain/symbolic/src/linter/function_size_visitor.rs
+use syn::visit::Visit;
+use syn::{Block, ImplItemFn, ItemFn, Signature, TraitItemFn};
+
+use crate::linter::{LintDiagnostic, LintRule, LintSpan};
+use crate::validator::Severity;
+
+/// Visitor reporting functions longer than a line budget
+pub struct FunctionSizeVisitor {
+    severity: Severity,
+    max_lines: usize,
+    pub diagnostics: Vec<LintDiagnostic>,
+}
+
+impl FunctionSizeVisitor {
+    pub fn new(severity: Severity, max_lines: usize) -> Self {
+        Self {
+            severity,
+            max_lines,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Measures from the `fn` keyword to the closing brace, ignoring doc comments
+    fn check(&mut self, sig: &Signature, block: &Block) {
+        let start = sig.fn_token.span.start();
+        let end = block.brace_token.span.close().end();
+        let lines = end.line.saturating_sub(start.line) + 1;
+
+        if lines > self.max_lines {
+            self.diagnostics.push(LintDiagnostic::new(
+                LintRule::LargeFunction,
+                self.severity,
+                format!(
+                    "Function '{}' spans {} lines (max {}); consider extracting helpers",
+                    sig.ident, lines, self.max_lines
+                ),
+                LintSpan::from_span(sig.ident.span()),
+            ));
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FunctionSizeVisitor {
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        self.check(&item.sig, &item.block);
+        syn::visit::visit_item_fn(self, item);
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast ImplItemFn) {
+        self.check(&item.sig, &item.block);
+        syn::visit::visit_impl_item_fn(self, item);
+    }
+
+    fn visit_trait_item_fn(&mut self, item: &'ast TraitItemFn) {
+        if let Some(block) = &item.default {
+            self.check(&item.sig, block);
+        }
+        syn::visit::visit_trait_item_fn(self, item);
+    }
+}
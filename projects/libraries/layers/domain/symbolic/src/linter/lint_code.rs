@@ -1,8 +1,7 @@
 // projects/libraries/layers/domain/symbolic/src/linter/lint_code.rs
-// Function for linting code
+use crate::linter::{CodeLinter, LintDiagnostic, LintError};
 
-pub fn lint_code(code: &str) -> Vec<String> {
-    println!("Linting code: {}", code);
-    // Placeholder for linting logic
-    vec!["No issues found".to_string()]
+/// Lints `code` with the default rule configuration
+pub fn lint_code(code: &str) -> Result<Vec<LintDiagnostic>, LintError> {
+    CodeLinter::default().lint(code)
 }
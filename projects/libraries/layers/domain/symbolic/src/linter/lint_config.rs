@@ -0,0 +1,91 @@
+// projects/libraries/layers/domain/symbolic/src/linter/lint_config.rs
+use std::collections::HashMap;
+
+use crate::linter::LintRule;
+use crate::validator::Severity;
+
+/// Default line budget for `LintRule::LargeFunction`
+pub const DEFAULT_MAX_FUNCTION_LINES: usize = 50;
+
+/// Per-rule settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleConfig {
+    pub enabled: bool,
+    pub severity: Severity,
+}
+
+/// Linter configuration: which rules run, at what severity, and rule thresholds
+#[derive(Debug, Clone)]
+pub struct LintConfig {
+    rules: HashMap<LintRule, RuleConfig>,
+    /// Maximum number of lines (signature to closing brace) before a function is reported
+    pub max_function_lines: usize,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        let rules = LintRule::ALL
+            .into_iter()
+            .map(|rule| {
+                (
+                    rule,
+                    RuleConfig {
+                        enabled: true,
+                        severity: rule.default_severity(),
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            rules,
+            max_function_lines: DEFAULT_MAX_FUNCTION_LINES,
+        }
+    }
+}
+
+impl LintConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the severity of a rule (and enables it)
+    pub fn with_severity(mut self, rule: LintRule, severity: Severity) -> Self {
+        self.rules.insert(
+            rule,
+            RuleConfig {
+                enabled: true,
+                severity,
+            },
+        );
+        self
+    }
+
+    /// Turns a rule off
+    pub fn disable(mut self, rule: LintRule) -> Self {
+        if let Some(config) = self.rules.get_mut(&rule) {
+            config.enabled = false;
+        }
+        self
+    }
+
+    /// Sets the line budget for `LintRule::LargeFunction`
+    pub fn with_max_function_lines(mut self, max_function_lines: usize) -> Self {
+        self.max_function_lines = max_function_lines;
+        self
+    }
+
+    /// Returns the configuration of a rule
+    pub fn rule(&self, rule: LintRule) -> RuleConfig {
+        self.rules.get(&rule).copied().unwrap_or(RuleConfig {
+            enabled: true,
+            severity: rule.default_severity(),
+        })
+    }
+
+    /// Severity to report for a rule, or `None` when the rule is disabled
+    pub fn severity_for(&self, rule: LintRule) -> Option<Severity> {
+        let config = self.rule(rule);
+        config.enabled.then_some(config.severity)
+    }
+}
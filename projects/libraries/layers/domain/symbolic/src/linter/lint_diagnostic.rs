@@ -0,0 +1,54 @@
+// projects/libraries/layers/domain/symbolic/src/linter/lint_diagnostic.rs
+use std::fmt;
+
+use crate::linter::{LintFix, LintRule, LintSpan};
+use crate::validator::Severity;
+
+/// A single finding reported by the linter
+#[derive(Debug, Clone)]
+pub struct LintDiagnostic {
+    /// Rule that produced the finding
+    pub rule: LintRule,
+    /// Severity after applying the configuration
+    pub severity: Severity,
+    /// Human-readable message
+    pub message: String,
+    /// Location of the offending code
+    pub span: LintSpan,
+    /// Optional machine-applicable fix
+    pub fix: Option<LintFix>,
+}
+
+impl LintDiagnostic {
+    pub fn new(rule: LintRule, severity: Severity, message: String, span: LintSpan) -> Self {
+        Self {
+            rule,
+            severity,
+            message,
+            span,
+            fix: None,
+        }
+    }
+
+    /// Attaches a fix suggestion
+    pub fn with_fix(mut self, fix: LintFix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+}
+
+impl fmt::Display for LintDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity_str = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+
+        write!(
+            f,
+            "[{}] {} {}: {}",
+            severity_str, self.span, self.rule, self.message
+        )
+    }
+}
@@ -0,0 +1,17 @@
+// projects/libraries/layers/domain/symbolic/src/linter/lint_error.rs
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LintError {
+    #[error("Parse error at line {line}: {message}")]
+    ParseError { line: usize, message: String },
+}
+
+impl From<syn::Error> for LintError {
+    fn from(error: syn::Error) -> Self {
+        LintError::ParseError {
+            line: error.span().start().line,
+            message: error.to_string(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// projects/libraries/layers/domain/symbolic/src/linter/lint_fix.rs
+use crate::linter::{LintRule, LintSpan};
+use crate::rules::RefactoringRule;
+
+/// Machine-applicable fix for a diagnostic.
+///
+/// The fix is expressed as a `RefactoringRule` that rewrites exactly one source line,
+/// plus the instruction that selects it. Register the rule on a `RulesEngine`
+/// (`add_refactoring_rule`) and pass `instruction` to `apply_refactoring`, with the
+/// source the diagnostic was computed on.
+#[derive(Debug, Clone)]
+pub struct LintFix {
+    /// Human-readable summary of the edit
+    pub description: String,
+    /// Instruction understood by `RulesEngine::apply_refactoring` once `rule` is registered
+    pub instruction: String,
+    /// Line-anchored rewrite rule
+    pub rule: RefactoringRule,
+    /// 1-based line rewritten by the fix
+    pub line: usize,
+    /// The rewritten source line
+    pub replacement_line: String,
+}
+
+impl LintFix {
+    /// Builds a fix replacing line `span.line` of `lines` with `replacement_line`.
+    ///
+    /// Returns `None` when the span falls outside `lines`.
+    pub fn replace_line(
+        lint_rule: LintRule,
+        span: LintSpan,
+        lines: &[&str],
+        replacement_line: String,
+        description: String,
+    ) -> Option<Self> {
+        let index = span.line.checked_sub(1)?;
+        let original_line = *lines.get(index)?;
+        // "lint_fix_<line>_<column>_<rule>" keeps instructions unambiguous under the
+        // engine's substring matching: the prefix anchors the start, the rule name the end.
+        let name = format!(
+            "lint_fix_{}_{}_{}",
+            span.line,
+            span.column,
+            lint_rule.name()
+        );
+        let instruction = name.replace('_', " ");
+
+        // Identical lines may appear elsewhere in the file: skip the ones above the
+        // reported line so that only this occurrence is rewritten. Counting occurrences
+        // rather than lines keeps the regex small in long files.
+        let line = format!("(?m:^){}(?m:$)", regex::escape(original_line));
+        let preceding = lines[..index]
+            .iter()
+            .filter(|l| **l == original_line)
+            .count();
+        let rule = RefactoringRule {
+            name,
+            pattern: format!(r"\A((?:(?s:.)*?{line}){{{preceding}}}(?s:.)*?(?m:^)){line}"),
+            replacement: format!("${{1}}{}", replacement_line.replace('$', "$$")),
+            description: description.clone(),
+        };
+
+        Some(Self {
+            description,
+            instruction,
+            rule,
+            line: span.line,
+            replacement_line,
+        })
+    }
+}
@@ -0,0 +1,63 @@
+// projects/libraries/layers/domain/symbolic/src/linter/lint_rule.rs
+use std::fmt;
+
+use crate::validator::Severity;
+
+/// Catalog of lint rules understood by the linter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    /// `.unwrap()` / `.expect()` outside tests and `main`
+    UnwrapInLibrary,
+    /// Direct reads of the wall clock (`SystemTime::now`, `Instant::now`, ...)
+    WallClock,
+    /// Blocking std calls (sleep, file and socket I/O) inside async code
+    BlockingInAsync,
+    /// Functions spanning more lines than the configured maximum
+    LargeFunction,
+    /// `let` bindings that shadow a binding from the same or an enclosing scope
+    ShadowedBinding,
+}
+
+impl LintRule {
+    /// Every rule in the catalog, in reporting order
+    pub const ALL: [LintRule; 5] = [
+        LintRule::UnwrapInLibrary,
+        LintRule::WallClock,
+        LintRule::BlockingInAsync,
+        LintRule::LargeFunction,
+        LintRule::ShadowedBinding,
+    ];
+
+    /// Stable snake_case identifier used in reports and configuration
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::UnwrapInLibrary => "unwrap_in_library",
+            LintRule::WallClock => "wall_clock",
+            LintRule::BlockingInAsync => "blocking_in_async",
+            LintRule::LargeFunction => "large_function",
+            LintRule::ShadowedBinding => "shadowed_binding",
+        }
+    }
+
+    /// Looks a rule up by its identifier
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rule| rule.name() == name)
+    }
+
+    /// Severity used when the configuration does not override it
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            LintRule::UnwrapInLibrary
+            | LintRule::WallClock
+            | LintRule::BlockingInAsync
+            | LintRule::LargeFunction => Severity::Warning,
+            LintRule::ShadowedBinding => Severity::Info,
+        }
+    }
+}
+
+impl fmt::Display for LintRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,37 @@
+// projects/libraries/layers/domain/symbolic/src/linter/lint_span.rs
+use proc_macro2::Span;
+use std::fmt;
+
+/// Source location of a diagnostic. Lines and columns are 1-based; columns count characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LintSpan {
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl LintSpan {
+    /// Converts a `proc_macro2` span (1-based lines, 0-based columns)
+    pub fn from_span(span: Span) -> Self {
+        let start = span.start();
+        let end = span.end();
+        Self {
+            line: start.line,
+            column: start.column + 1,
+            end_line: end.line,
+            end_column: end.column + 1,
+        }
+    }
+
+    /// Number of source lines covered by the span
+    pub fn line_count(&self) -> usize {
+        self.end_line.saturating_sub(self.line) + 1
+    }
+}
+
+impl fmt::Display for LintSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
@@ -1,5 +1,28 @@
 // projects/libraries/layers/domain/symbolic/src/linter/mod.rs
 // Module for linting rules
+mod blocking_call_visitor;
+pub mod code_linter;
+mod function_size_visitor;
 pub mod lint_code;
+pub mod lint_config;
+pub mod lint_diagnostic;
+pub mod lint_error;
+pub mod lint_fix;
+pub mod lint_rule;
+pub mod lint_span;
+mod shadowing_visitor;
+mod syntax_helpers;
+mod unwrap_visitor;
+mod wall_clock_visitor;
 
+pub use code_linter::CodeLinter;
 pub use lint_code::lint_code;
+pub use lint_config::{DEFAULT_MAX_FUNCTION_LINES, LintConfig, RuleConfig};
+pub use lint_diagnostic::LintDiagnostic;
+pub use lint_error::LintError;
+pub use lint_fix::LintFix;
+pub use lint_rule::LintRule;
+pub use lint_span::LintSpan;
+
+#[cfg(test)]
+mod tests;
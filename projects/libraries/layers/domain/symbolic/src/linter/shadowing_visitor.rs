@@ -0,0 +1,111 @@
+// projects/libraries/layers/domain/symbolic/src/linter/shadowing_visitor.rs
+use std::collections::HashSet;
+use syn::visit::Visit;
+use syn::{Arm, Block, ExprClosure, FnArg, ImplItemFn, ItemFn, Local, Pat, Signature};
+
+use crate::linter::{LintDiagnostic, LintRule, LintSpan};
+use crate::validator::Severity;
+use crate::validator::variable_collector::collect_pat_idents;
+
+/// Visitor reporting `let` bindings that shadow a binding still in scope
+pub struct ShadowingVisitor {
+    severity: Severity,
+    scopes: Vec<HashSet<String>>,
+    pub diagnostics: Vec<LintDiagnostic>,
+}
+
+impl ShadowingVisitor {
+    pub fn new(severity: Severity) -> Self {
+        Self {
+            severity,
+            scopes: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn bind_pattern(pat: &Pat, scope: &mut HashSet<String>) {
+        let mut idents = Vec::new();
+        collect_pat_idents(pat, &mut idents);
+        scope.extend(idents.into_iter().map(ToString::to_string));
+    }
+
+    fn with_scope<F: FnOnce(&mut Self)>(&mut self, scope: HashSet<String>, visit: F) {
+        self.scopes.push(scope);
+        visit(self);
+        self.scopes.pop();
+    }
+
+    /// Function bodies start from a clean scope stack seeded with the parameters
+    fn visit_fn(&mut self, sig: &Signature, block: &Block) {
+        let mut params = HashSet::new();
+        for input in &sig.inputs {
+            if let FnArg::Typed(pat_type) = input {
+                Self::bind_pattern(&pat_type.pat, &mut params);
+            }
+        }
+
+        let outer = std::mem::take(&mut self.scopes);
+        self.with_scope(params, |this| this.visit_block(block));
+        self.scopes = outer;
+    }
+}
+
+impl<'ast> Visit<'ast> for ShadowingVisitor {
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        self.visit_fn(&item.sig, &item.block);
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast ImplItemFn) {
+        self.visit_fn(&item.sig, &item.block);
+    }
+
+    fn visit_block(&mut self, block: &'ast Block) {
+        self.with_scope(HashSet::new(), |this| syn::visit::visit_block(this, block));
+    }
+
+    fn visit_expr_closure(&mut self, closure: &'ast ExprClosure) {
+        let mut params = HashSet::new();
+        for input in &closure.inputs {
+            Self::bind_pattern(input, &mut params);
+        }
+        self.with_scope(params, |this| this.visit_expr(&closure.body));
+    }
+
+    fn visit_arm(&mut self, arm: &'ast Arm) {
+        let mut bindings = HashSet::new();
+        Self::bind_pattern(&arm.pat, &mut bindings);
+        self.with_scope(bindings, |this| {
+            if let Some((_, guard)) = &arm.guard {
+                this.visit_expr(guard);
+            }
+            this.visit_expr(&arm.body);
+        });
+    }
+
+    fn visit_local(&mut self, local: &'ast Local) {
+        // The initializer still sees the previous binding: `let x = x + 1;`
+        if let Some(init) = &local.init {
+            self.visit_expr(&init.expr);
+            if let Some((_, diverge)) = &init.diverge {
+                self.visit_expr(diverge);
+            }
+        }
+
+        let mut idents = Vec::new();
+        collect_pat_idents(&local.pat, &mut idents);
+        for ident in idents {
+            let name = ident.to_string();
+            if !name.starts_with('_') && self.scopes.iter().any(|scope| scope.contains(&name)) {
+                self.diagnostics.push(LintDiagnostic::new(
+                    LintRule::ShadowedBinding,
+                    self.severity,
+                    format!("Binding '{}' shadows an earlier binding", name),
+                    LintSpan::from_span(ident.span()),
+                ));
+            }
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.insert(name);
+            }
+        }
+    }
+}
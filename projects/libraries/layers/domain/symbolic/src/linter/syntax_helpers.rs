@@ -0,0 +1,70 @@
+// projects/libraries/layers/domain/symbolic/src/linter/syntax_helpers.rs
+use proc_macro2::LineColumn;
+use syn::{Attribute, Path};
+
+/// True for `#[test]`, `#[tokio::test]` and similar test attributes
+pub(crate) fn is_test_fn(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "test")
+    })
+}
+
+/// True for items gated behind `#[cfg(test)]`
+pub(crate) fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|ident| ident == "test")
+    })
+}
+
+/// Last `n` segments of a path joined with `::`
+pub(crate) fn path_tail(path: &Path, n: usize) -> String {
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    segments[segments.len().saturating_sub(n)..].join("::")
+}
+
+/// Rewrites the single-line region `[start, end)` of `lines` with `replacement`.
+///
+/// Returns the rewritten line, or `None` when the region spans several lines
+/// or falls outside the source.
+pub(crate) fn rewrite_line(
+    lines: &[&str],
+    start: LineColumn,
+    end: LineColumn,
+    replacement: &str,
+) -> Option<String> {
+    if start.line != end.line || start.line == 0 {
+        return None;
+    }
+    let original = *lines.get(start.line - 1)?;
+    let chars: Vec<char> = original.chars().collect();
+    if start.column > end.column || end.column > chars.len() {
+        return None;
+    }
+
+    let mut rewritten: String = chars[..start.column].iter().collect();
+    rewritten.push_str(replacement);
+    rewritten.extend(&chars[end.column..]);
+    Some(rewritten)
+}
+
+/// Source text of the single-line region `[start, end)`
+pub(crate) fn source_slice(lines: &[&str], start: LineColumn, end: LineColumn) -> Option<String> {
+    if start.line != end.line || start.line == 0 {
+        return None;
+    }
+    let line = lines.get(start.line - 1)?;
+    let chars: Vec<char> = line.chars().collect();
+    chars
+        .get(start.column..end.column)
+        .map(|slice| slice.iter().collect())
+}
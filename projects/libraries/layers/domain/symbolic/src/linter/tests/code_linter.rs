@@ -0,0 +1,299 @@
+// projects/libraries/layers/domain/symbolic/src/linter/tests/code_linter.rs
+use super::test_helpers::TestResult;
+use crate::linter::{CodeLinter, LintConfig, LintDiagnostic, LintError, LintRule, lint_code};
+use crate::rules::RulesEngine;
+use crate::validator::Severity;
+
+fn diagnostics_for(diagnostics: &[LintDiagnostic], rule: LintRule) -> Vec<&LintDiagnostic> {
+    diagnostics.iter().filter(|d| d.rule == rule).collect()
+}
+
+#[test]
+fn test_clean_code_has_no_diagnostics() -> TestResult {
+    let code = r#"
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+"#;
+    assert!(lint_code(code)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_invalid_code_is_a_parse_error() {
+    let err = lint_code("fn broken( {").unwrap_err();
+    assert!(matches!(err, LintError::ParseError { line: 1, .. }));
+}
+
+#[test]
+fn test_unwrap_reports_line_and_column() -> TestResult {
+    let code = "pub fn first(values: &[u8]) -> u8 {\n    *values.first().unwrap()\n}\n";
+    let diagnostics = lint_code(code)?;
+
+    let unwraps = diagnostics_for(&diagnostics, LintRule::UnwrapInLibrary);
+    assert_eq!(unwraps.len(), 1);
+    assert_eq!(unwraps[0].severity, Severity::Warning);
+    assert_eq!(unwraps[0].span.line, 2);
+    assert_eq!(unwraps[0].span.column, 21);
+    // No `?` fix when the function does not return Result/Option
+    assert!(unwraps[0].fix.is_none());
+    assert!(
+        unwraps[0]
+            .to_string()
+            .starts_with("[warning] 2:21 unwrap_in_library")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_unwrap_ignored_in_tests_and_main() -> TestResult {
+    let code = r#"
+fn main() {
+    let _ = "1".parse::<u8>().unwrap();
+}
+
+#[test]
+fn parses() {
+    let _ = "1".parse::<u8>().expect("number");
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() -> u8 {
+        "1".parse().unwrap()
+    }
+}
+"#;
+    let diagnostics = lint_code(code)?;
+    assert!(diagnostics_for(&diagnostics, LintRule::UnwrapInLibrary).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_unwrap_fix_round_trips_through_rules_engine() -> TestResult {
+    let code = r#"pub fn parse(input: &str) -> Result<u8, std::num::ParseIntError> {
+    let value = input.trim().parse::<u8>().unwrap();
+    Ok(value)
+}
+"#;
+    let diagnostics = lint_code(code)?;
+    let fix = diagnostics_for(&diagnostics, LintRule::UnwrapInLibrary)[0]
+        .fix
+        .clone()
+        .expect("unwrap in a Result fn should carry a fix");
+    assert_eq!(
+        fix.replacement_line,
+        "    let value = input.trim().parse::<u8>()?;"
+    );
+
+    let mut engine = RulesEngine::new()?;
+    engine.add_refactoring_rule(
+        fix.rule.name,
+        fix.rule.pattern,
+        fix.rule.replacement,
+        fix.rule.description,
+    );
+    let result = engine.apply_refactoring(code, &fix.instruction)?;
+
+    assert!(result.code.contains("parse::<u8>()?;"));
+    assert!(!result.code.contains("unwrap"));
+    assert!(lint_code(&result.code)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_fix_only_rewrites_the_reported_line() -> TestResult {
+    let code = r#"pub fn first(a: Option<u8>, b: Option<u8>) -> Option<u8> {
+    let v = a.unwrap();
+    let v = b.unwrap();
+    let v = a.unwrap();
+    Some(v)
+}
+"#;
+    let diagnostics = lint_code(code)?;
+    let unwraps = diagnostics_for(&diagnostics, LintRule::UnwrapInLibrary);
+    assert_eq!(unwraps.len(), 3);
+
+    let fix = unwraps[2].fix.clone().expect("unwrap should carry a fix");
+    assert_eq!(fix.line, 4);
+    let mut engine = RulesEngine::new()?;
+    engine.add_refactoring_rule(
+        fix.rule.name,
+        fix.rule.pattern,
+        fix.rule.replacement,
+        fix.rule.description,
+    );
+    let result = engine.apply_refactoring(code, &fix.instruction)?;
+
+    let lines: Vec<&str> = result.code.lines().collect();
+    assert_eq!(lines[1], "    let v = a.unwrap();");
+    assert_eq!(lines[2], "    let v = b.unwrap();");
+    assert_eq!(lines[3], "    let v = a?;");
+    Ok(())
+}
+
+#[test]
+fn test_wall_clock_usage() -> TestResult {
+    let code = r#"
+use std::time::{Instant, SystemTime};
+
+pub fn stamp() -> (SystemTime, Instant) {
+    (SystemTime::now(), std::time::Instant::now())
+}
+"#;
+    let diagnostics = lint_code(code)?;
+    let clocks = diagnostics_for(&diagnostics, LintRule::WallClock);
+    assert_eq!(clocks.len(), 2);
+    assert!(clocks[0].message.contains("SystemTime::now"));
+    assert!(clocks[1].message.contains("Instant::now"));
+    assert_eq!(clocks[0].span.line, 5);
+    Ok(())
+}
+
+#[test]
+fn test_blocking_calls_only_reported_in_async_code() -> TestResult {
+    let code = r#"
+pub fn sync_read() -> String {
+    std::fs::read_to_string("a.txt").unwrap_or_default()
+}
+
+pub async fn async_read() -> String {
+    let handle = tokio::task::spawn_blocking(|| std::fs::read_to_string("b.txt"));
+    let _ = tokio::fs::read_to_string("c.txt").await;
+    std::fs::read_to_string("d.txt").unwrap_or_default()
+}
+"#;
+    let diagnostics = lint_code(code)?;
+    let blocking = diagnostics_for(&diagnostics, LintRule::BlockingInAsync);
+    assert_eq!(blocking.len(), 1);
+    assert_eq!(blocking[0].span.line, 9);
+    assert!(blocking[0].fix.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_blocking_sleep_fix() -> TestResult {
+    let code = r#"
+async fn wait(delay: std::time::Duration) {
+    std::thread::sleep(delay);
+}
+"#;
+    let diagnostics = lint_code(code)?;
+    let blocking = diagnostics_for(&diagnostics, LintRule::BlockingInAsync);
+    let fix = blocking[0].fix.as_ref().expect("sleep should carry a fix");
+    assert_eq!(fix.replacement_line, "    tokio::time::sleep(delay).await;");
+    Ok(())
+}
+
+#[test]
+fn test_large_function_threshold() -> TestResult {
+    let code = r#"
+/// Docs are not counted
+fn long() {
+    let a = 1;
+    let b = 2;
+    let _ = a + b;
+}
+
+fn short() {}
+"#;
+    let linter = CodeLinter::new(LintConfig::new().with_max_function_lines(4));
+    let diagnostics = linter.lint(code)?;
+    let large = diagnostics_for(&diagnostics, LintRule::LargeFunction);
+    assert_eq!(large.len(), 1);
+    assert!(large[0].message.contains("'long' spans 5 lines"));
+    assert_eq!((large[0].span.line, large[0].span.column), (3, 4));
+
+    let relaxed = CodeLinter::new(LintConfig::new().with_max_function_lines(5));
+    assert!(diagnostics_for(&relaxed.lint(code)?, LintRule::LargeFunction).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_shadowed_bindings() -> TestResult {
+    let code = r#"
+fn shadow(input: &str) -> usize {
+    let input = input.trim();
+    let count = input.len();
+    {
+        let count = count * 2;
+        let _count = count;
+    }
+    match Some(count) {
+        Some(n) => {
+            let n = n + 1;
+            n
+        }
+        None => 0,
+    }
+}
+
+fn independent() {
+    let count = 1;
+    let _ = count;
+}
+"#;
+    let diagnostics = lint_code(code)?;
+    let shadowed: Vec<(usize, &str)> = diagnostics_for(&diagnostics, LintRule::ShadowedBinding)
+        .iter()
+        .map(|d| (d.span.line, d.message.as_str()))
+        .collect();
+
+    assert_eq!(shadowed.len(), 3, "{:?}", shadowed);
+    assert_eq!(shadowed[0].0, 3);
+    assert!(shadowed[0].1.contains("'input'"));
+    assert_eq!(shadowed[1].0, 6);
+    assert_eq!(shadowed[2].0, 11);
+    assert!(shadowed.iter().all(|(_, m)| !m.contains("_count")));
+    assert_eq!(
+        diagnostics_for(&diagnostics, LintRule::ShadowedBinding)[0].severity,
+        Severity::Info
+    );
+    Ok(())
+}
+
+#[test]
+fn test_config_controls_rules_and_severity() -> TestResult {
+    let code = r#"
+pub fn now_and_unwrap(v: Option<u8>) -> Option<u8> {
+    let _ = std::time::SystemTime::now();
+    Some(v.unwrap())
+}
+"#;
+    let linter = CodeLinter::new(
+        LintConfig::new()
+            .disable(LintRule::WallClock)
+            .with_severity(LintRule::UnwrapInLibrary, Severity::Error),
+    );
+    let diagnostics = linter.lint(code)?;
+
+    assert!(diagnostics_for(&diagnostics, LintRule::WallClock).is_empty());
+    let unwraps = diagnostics_for(&diagnostics, LintRule::UnwrapInLibrary);
+    assert_eq!(unwraps.len(), 1);
+    assert_eq!(unwraps[0].severity, Severity::Error);
+    // Propagating fix is available because the function returns Option
+    assert_eq!(
+        unwraps[0].fix.as_ref().map(|f| f.replacement_line.as_str()),
+        Some("    Some(v?)")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_diagnostics_are_sorted_by_position() -> TestResult {
+    let code = r#"
+pub fn mixed(v: Option<u8>) -> u8 {
+    let x = v.unwrap();
+    let x = x + 1;
+    let _ = std::time::Instant::now();
+    x
+}
+"#;
+    let diagnostics = lint_code(code)?;
+    let lines: Vec<usize> = diagnostics.iter().map(|d| d.span.line).collect();
+    let mut sorted = lines.clone();
+    sorted.sort();
+    assert_eq!(lines, sorted);
+    assert_eq!(diagnostics.len(), 3);
+    Ok(())
+}
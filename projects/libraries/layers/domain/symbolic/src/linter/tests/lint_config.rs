@@ -0,0 +1,35 @@
+// projects/libraries/layers/domain/symbolic/src/linter/tests/lint_config.rs
+use crate::linter::{DEFAULT_MAX_FUNCTION_LINES, LintConfig, LintRule};
+use crate::validator::Severity;
+
+#[test]
+fn test_default_config_enables_every_rule() {
+    let config = LintConfig::default();
+
+    for rule in LintRule::ALL {
+        assert_eq!(config.severity_for(rule), Some(rule.default_severity()));
+    }
+    assert_eq!(config.max_function_lines, DEFAULT_MAX_FUNCTION_LINES);
+}
+
+#[test]
+fn test_severity_override_and_disable() {
+    let config = LintConfig::new()
+        .with_severity(LintRule::WallClock, Severity::Error)
+        .disable(LintRule::ShadowedBinding);
+
+    assert_eq!(
+        config.severity_for(LintRule::WallClock),
+        Some(Severity::Error)
+    );
+    assert_eq!(config.severity_for(LintRule::ShadowedBinding), None);
+    assert!(!config.rule(LintRule::ShadowedBinding).enabled);
+}
+
+#[test]
+fn test_rule_names_round_trip() {
+    for rule in LintRule::ALL {
+        assert_eq!(LintRule::from_name(rule.name()), Some(rule));
+    }
+    assert_eq!(LintRule::from_name("no_such_rule"), None);
+}
@@ -0,0 +1,6 @@
+// projects/libraries/layers/domain/symbolic/src/linter/tests/mod.rs
+mod code_linter;
+mod lint_config;
+
+// Re-export test helpers from the main tests module for convenience
+pub(crate) use crate::tests::test_helpers;
@@ -0,0 +1,150 @@
+// projects/libraries/layers/domain/symbolic/src/linter/unwrap_visitor.rs
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{
+    ExprAsync, ExprClosure, ExprMethodCall, ImplItemFn, ItemFn, ItemMod, ReturnType, Signature,
+    TraitItemFn, Type,
+};
+
+use crate::linter::syntax_helpers::{is_cfg_test, is_test_fn, rewrite_line};
+use crate::linter::{LintDiagnostic, LintFix, LintRule, LintSpan};
+use crate::validator::Severity;
+
+/// Whether `?` can replace an `unwrap()` in the enclosing body
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ReturnKind {
+    Propagating,
+    Other,
+}
+
+/// Visitor reporting `.unwrap()` / `.expect(..)` outside tests and `main`
+pub struct UnwrapVisitor<'src> {
+    lines: &'src [&'src str],
+    severity: Severity,
+    returns: Vec<ReturnKind>,
+    pub diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'src> UnwrapVisitor<'src> {
+    pub fn new(lines: &'src [&'src str], severity: Severity) -> Self {
+        Self {
+            lines,
+            severity,
+            returns: Vec::new(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn return_kind(sig: &Signature) -> ReturnKind {
+        match &sig.output {
+            ReturnType::Type(_, ty) => match ty.as_ref() {
+                Type::Path(type_path)
+                    if type_path.path.segments.last().is_some_and(|segment| {
+                        segment.ident == "Result" || segment.ident == "Option"
+                    }) =>
+                {
+                    ReturnKind::Propagating
+                }
+                _ => ReturnKind::Other,
+            },
+            ReturnType::Default => ReturnKind::Other,
+        }
+    }
+
+    fn skips_fn(attrs: &[syn::Attribute], sig: &Signature) -> bool {
+        is_test_fn(attrs) || is_cfg_test(attrs) || sig.ident == "main"
+    }
+
+    fn with_return<F: FnOnce(&mut Self)>(&mut self, kind: ReturnKind, visit: F) {
+        self.returns.push(kind);
+        visit(self);
+        self.returns.pop();
+    }
+}
+
+impl<'ast> Visit<'ast> for UnwrapVisitor<'_> {
+    fn visit_item_mod(&mut self, item: &'ast ItemMod) {
+        if !is_cfg_test(&item.attrs) {
+            syn::visit::visit_item_mod(self, item);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        if Self::skips_fn(&item.attrs, &item.sig) {
+            return;
+        }
+        self.with_return(Self::return_kind(&item.sig), |this| {
+            syn::visit::visit_item_fn(this, item)
+        });
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast ImplItemFn) {
+        if Self::skips_fn(&item.attrs, &item.sig) {
+            return;
+        }
+        self.with_return(Self::return_kind(&item.sig), |this| {
+            syn::visit::visit_impl_item_fn(this, item)
+        });
+    }
+
+    fn visit_trait_item_fn(&mut self, item: &'ast TraitItemFn) {
+        self.with_return(Self::return_kind(&item.sig), |this| {
+            syn::visit::visit_trait_item_fn(this, item)
+        });
+    }
+
+    fn visit_expr_closure(&mut self, closure: &'ast ExprClosure) {
+        self.with_return(ReturnKind::Other, |this| {
+            syn::visit::visit_expr_closure(this, closure)
+        });
+    }
+
+    fn visit_expr_async(&mut self, block: &'ast ExprAsync) {
+        self.with_return(ReturnKind::Other, |this| {
+            syn::visit::visit_expr_async(this, block)
+        });
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        syn::visit::visit_expr_method_call(self, call);
+
+        let method = call.method.to_string();
+        let is_unwrap = method == "unwrap" && call.args.is_empty();
+        let is_expect = method == "expect" && call.args.len() == 1;
+        // Free-standing code (constants, statics) has no enclosing function to report on
+        if !(is_unwrap || is_expect) || self.returns.is_empty() {
+            return;
+        }
+
+        let span = LintSpan::from_span(call.method.span());
+        let mut diagnostic = LintDiagnostic::new(
+            LintRule::UnwrapInLibrary,
+            self.severity,
+            format!(
+                "'.{}()' panics on failure; propagate or handle the error instead",
+                method
+            ),
+            span,
+        );
+
+        if self.returns.last() == Some(&ReturnKind::Propagating)
+            && let Some(rewritten) = rewrite_line(
+                self.lines,
+                call.dot_token.span().start(),
+                call.span().end(),
+                "?",
+            )
+            && let Some(fix) = LintFix::replace_line(
+                LintRule::UnwrapInLibrary,
+                span,
+                self.lines,
+                rewritten,
+                format!("Replace '.{}()' with '?'", method),
+            )
+        {
+            diagnostic = diagnostic.with_fix(fix);
+        }
+
+        self.diagnostics.push(diagnostic);
+    }
+}
@@ -0,0 +1,64 @@
+// projects/libraries/layers/domain/symbolic/src/linter/wall_clock_visitor.rs
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{Expr, ExprCall, ItemFn, ItemMod};
+
+use crate::linter::syntax_helpers::{is_cfg_test, is_test_fn, path_tail};
+use crate::linter::{LintDiagnostic, LintRule, LintSpan};
+use crate::validator::Severity;
+
+/// Clock reads that make code time-dependent and hard to test
+const WALL_CLOCK_CALLS: &[&str] = &[
+    "SystemTime::now",
+    "Instant::now",
+    "Utc::now",
+    "Local::now",
+    "OffsetDateTime::now_utc",
+];
+
+/// Visitor reporting direct wall-clock reads outside tests
+pub struct WallClockVisitor {
+    severity: Severity,
+    pub diagnostics: Vec<LintDiagnostic>,
+}
+
+impl WallClockVisitor {
+    pub fn new(severity: Severity) -> Self {
+        Self {
+            severity,
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for WallClockVisitor {
+    fn visit_item_mod(&mut self, item: &'ast ItemMod) {
+        if !is_cfg_test(&item.attrs) {
+            syn::visit::visit_item_mod(self, item);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        if !is_test_fn(&item.attrs) {
+            syn::visit::visit_item_fn(self, item);
+        }
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Expr::Path(func) = call.func.as_ref() {
+            let callee = path_tail(&func.path, 2);
+            if WALL_CLOCK_CALLS.contains(&callee.as_str()) {
+                self.diagnostics.push(LintDiagnostic::new(
+                    LintRule::WallClock,
+                    self.severity,
+                    format!(
+                        "'{}()' reads the wall clock; inject a clock or pass the time in",
+                        callee
+                    ),
+                    LintSpan::from_span(call.span()),
+                ));
+            }
+        }
+        syn::visit::visit_expr_call(self, call);
+    }
+}
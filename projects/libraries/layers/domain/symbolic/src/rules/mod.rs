@@ -2,19 +2,25 @@
 // Module for defining symbolic rules
 pub mod code_template;
 pub mod evaluate_rule;
+pub mod refactoring;
 pub mod refactoring_result;
 pub mod refactoring_rule;
+mod refactorings;
 pub mod rule;
 pub mod rules_engine;
 pub mod rules_error;
+mod source_edit;
+pub mod unified_diff;
 
 #[cfg(test)]
 pub mod tests;
 
 pub use code_template::CodeTemplate;
 pub use evaluate_rule::evaluate_rule;
+pub use refactoring::Refactoring;
 pub use refactoring_result::RefactoringResult;
 pub use refactoring_rule::RefactoringRule;
 pub use rule::Rule;
 pub use rules_engine::RulesEngine;
 pub use rules_error::RulesError;
+pub use unified_diff::{DEFAULT_DIFF_CONTEXT, unified_diff};
@@ -0,0 +1,156 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactoring.rs
+use regex::Regex;
+
+use crate::rules::RulesError;
+
+/// Structural (syn-based) refactorings understood by `RulesEngine::apply_refactoring`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refactoring {
+    /// Rename every occurrence of an identifier in the file, including inside macros
+    RenameSymbol { from: String, to: String },
+    /// Move the statements on lines `start_line..=end_line` into a new function
+    ExtractFunction {
+        start_line: usize,
+        end_line: usize,
+        name: String,
+    },
+    /// Replace a `let` binding's uses with its initializer and drop the binding
+    InlineVariable { name: String },
+    /// Rewrite two-arm `match`es with a catch-all arm as `if let`
+    MatchToIfLet { line: Option<usize> },
+    /// Add derives to a named type, or to every struct and enum when `target` is `None`
+    AddDerive {
+        traits: Vec<String>,
+        target: Option<String>,
+    },
+    /// Make a named item, or every private struct, public
+    MakePublic { target: Option<String> },
+    /// Make the named fields of a struct (or of every struct) public
+    MakeFieldsPublic { target: Option<String> },
+}
+
+const IDENT: &str = r"'?([A-Za-z_][A-Za-z0-9_]*)'?";
+
+impl Refactoring {
+    /// Parses a natural-language instruction such as `"rename variable 'x' to 'count'"`.
+    ///
+    /// Returns `Ok(None)` when the instruction does not describe a structural refactoring.
+    pub fn parse(instruction: &str) -> Result<Option<Self>, RulesError> {
+        let instruction = instruction.trim();
+
+        let rename = regex(&format!(
+            r"(?i)\brename\s+(?:(?:variable|var|function|fn|method|struct|enum|type|trait|field|const|symbol)\s+)?{IDENT}\s+to\s+{IDENT}"
+        ))?;
+        if let Some(caps) = rename.captures(instruction) {
+            return Ok(Some(Refactoring::RenameSymbol {
+                from: caps[1].to_string(),
+                to: caps[2].to_string(),
+            }));
+        }
+
+        let extract_lines_first = regex(&format!(
+            r"(?i)\bextract\s+(?:lines?\s+)?(\d+)\s*(?:-|\.\.=?|to)\s*(\d+)\s+(?:into|as)\s+(?:a\s+)?(?:new\s+)?(?:function|fn)\s+{IDENT}"
+        ))?;
+        if let Some(caps) = extract_lines_first.captures(instruction) {
+            return Ok(Some(Refactoring::ExtractFunction {
+                start_line: parse_line(&caps[1])?,
+                end_line: parse_line(&caps[2])?,
+                name: caps[3].to_string(),
+            }));
+        }
+
+        let extract_name_first = regex(&format!(
+            r"(?i)\bextract\s+(?:function|fn)\s+{IDENT}\s+from\s+lines?\s+(\d+)\s*(?:-|\.\.=?|to)\s*(\d+)"
+        ))?;
+        if let Some(caps) = extract_name_first.captures(instruction) {
+            return Ok(Some(Refactoring::ExtractFunction {
+                start_line: parse_line(&caps[2])?,
+                end_line: parse_line(&caps[3])?,
+                name: caps[1].to_string(),
+            }));
+        }
+
+        let inline = regex(&format!(r"(?i)\binline\s+(?:variable\s+|var\s+)?{IDENT}"))?;
+        if let Some(caps) = inline.captures(instruction) {
+            return Ok(Some(Refactoring::InlineVariable {
+                name: caps[1].to_string(),
+            }));
+        }
+
+        let match_to_if_let =
+            regex(r"(?i)\bmatch\s+(?:to|into)\s+if\s+let\b(?:\s+(?:at|on)\s+line\s+(\d+))?")?;
+        if let Some(caps) = match_to_if_let.captures(instruction) {
+            let line = caps.get(1).map(|m| parse_line(m.as_str())).transpose()?;
+            return Ok(Some(Refactoring::MatchToIfLet { line }));
+        }
+
+        let add_derive_list = regex(&format!(
+            r"(?i)\badd\s+derives?\s*\(?\s*([A-Za-z_][A-Za-z0-9_]*(?:\s*,\s*[A-Za-z_][A-Za-z0-9_]*)*)\s*\)?(?:\s+(?:to|on)\s+{IDENT})?"
+        ))?;
+        let add_named_derive = regex(&format!(
+            r"(?i)\badd\s+([A-Za-z_][A-Za-z0-9_]*)\s+derive(?:\s+(?:to|on)\s+{IDENT})?"
+        ))?;
+        if let Some(caps) = add_named_derive
+            .captures(instruction)
+            .or_else(|| add_derive_list.captures(instruction))
+        {
+            let traits = caps[1]
+                .split(',')
+                .map(|name| canonical_derive(name.trim()))
+                .collect();
+            return Ok(Some(Refactoring::AddDerive {
+                traits,
+                target: caps.get(2).map(|m| m.as_str().to_string()),
+            }));
+        }
+
+        let fields_public = regex(&format!(
+            r"(?i)\bmake\s+(?:the\s+)?fields\s+(?:of\s+{IDENT}\s+)?public\b"
+        ))?;
+        if let Some(caps) = fields_public.captures(instruction) {
+            return Ok(Some(Refactoring::MakeFieldsPublic {
+                target: caps.get(1).map(|m| m.as_str().to_string()),
+            }));
+        }
+
+        let make_public = regex(&format!(r"(?i)\bmake\s+(?:{IDENT}\s+)?public\b"))?;
+        if let Some(caps) = make_public.captures(instruction) {
+            return Ok(Some(Refactoring::MakePublic {
+                target: caps.get(1).map(|m| m.as_str().to_string()),
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+fn regex(pattern: &str) -> Result<Regex, RulesError> {
+    Regex::new(pattern).map_err(|e| RulesError::InvalidPattern(e.to_string()))
+}
+
+fn parse_line(text: &str) -> Result<usize, RulesError> {
+    text.parse()
+        .map_err(|_| RulesError::ParseError(format!("Invalid line number: {}", text)))
+}
+
+/// Maps lowercase spellings ("debug", "partialeq") to the derive's real name
+fn canonical_derive(name: &str) -> String {
+    const KNOWN: &[&str] = &[
+        "Debug",
+        "Clone",
+        "Copy",
+        "PartialEq",
+        "Eq",
+        "PartialOrd",
+        "Ord",
+        "Hash",
+        "Default",
+        "Serialize",
+        "Deserialize",
+    ];
+    KNOWN
+        .iter()
+        .find(|known| known.eq_ignore_ascii_case(name))
+        .map(|known| known.to_string())
+        .unwrap_or_else(|| name.to_string())
+}
@@ -1,7 +1,12 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactoring_result.rs
 // Refactoring result
 #[derive(Debug, Clone)]
 pub struct RefactoringResult {
     pub code: String,
     pub confidence: f64,
     pub changes_applied: Vec<String>,
+    /// Unified diff from the input to `code`
+    pub diff: String,
+    /// Whether `code` parses as a Rust file
+    pub verified: bool,
 }
@@ -0,0 +1,155 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactorings/add_derive.rs
+use proc_macro2::Span;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{Attribute, File, Ident, ItemEnum, ItemStruct, MacroDelimiter, Meta, Path, Token};
+
+use crate::rules::RulesError;
+use crate::rules::refactorings::RefactoringPlan;
+use crate::rules::source_edit::{SourceEdit, SourceText};
+
+/// A struct or enum that can receive derives
+struct DeriveTarget<'ast> {
+    ident: &'ast Ident,
+    attrs: &'ast [Attribute],
+    /// Where a new `#[derive(..)]` line goes: the visibility or item keyword
+    insert_at: Span,
+}
+
+#[derive(Default)]
+struct DeriveTargets<'ast> {
+    items: Vec<DeriveTarget<'ast>>,
+}
+
+impl<'ast> Visit<'ast> for DeriveTargets<'ast> {
+    fn visit_item_struct(&mut self, item: &'ast ItemStruct) {
+        self.items.push(DeriveTarget {
+            ident: &item.ident,
+            attrs: &item.attrs,
+            insert_at: item_start(&item.vis, item.struct_token.span),
+        });
+        syn::visit::visit_item_struct(self, item);
+    }
+
+    fn visit_item_enum(&mut self, item: &'ast ItemEnum) {
+        self.items.push(DeriveTarget {
+            ident: &item.ident,
+            attrs: &item.attrs,
+            insert_at: item_start(&item.vis, item.enum_token.span),
+        });
+        syn::visit::visit_item_enum(self, item);
+    }
+}
+
+fn item_start(vis: &syn::Visibility, keyword: Span) -> Span {
+    match vis {
+        syn::Visibility::Inherited => keyword,
+        _ => vis.span(),
+    }
+}
+
+/// Trait names already derived through `#[derive(..)]` attributes
+fn derived_traits(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .filter_map(|path| {
+            path.segments
+                .last()
+                .map(|segment| segment.ident.to_string())
+        })
+        .collect()
+}
+
+pub(crate) fn plan(
+    syntax_tree: &File,
+    source: &SourceText<'_>,
+    traits: &[String],
+    target: Option<&str>,
+) -> Result<RefactoringPlan, RulesError> {
+    let mut targets = DeriveTargets::default();
+    targets.visit_file(syntax_tree);
+
+    let candidates: Vec<&DeriveTarget> = targets
+        .items
+        .iter()
+        .filter(|item| target.is_none_or(|name| item.ident == name))
+        .collect();
+    if candidates.is_empty() {
+        return Err(RulesError::RefactoringFailed(match target {
+            Some(name) => format!("No struct or enum named '{}'", name),
+            None => "No struct or enum to derive on".to_string(),
+        }));
+    }
+
+    let mut edits = Vec::new();
+    let mut updated = Vec::new();
+    for item in candidates {
+        let existing = derived_traits(item.attrs);
+        let missing: Vec<&str> = traits
+            .iter()
+            .filter(|name| !existing.contains(name))
+            .map(String::as_str)
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+
+        let derive_list = item.attrs.iter().find_map(|attr| match &attr.meta {
+            Meta::List(list) if list.path.is_ident("derive") => Some(list),
+            _ => None,
+        });
+
+        match derive_list {
+            Some(list) => {
+                let MacroDelimiter::Paren(paren) = &list.delimiter else {
+                    continue;
+                };
+                let current = list.tokens.to_string();
+                let separator = if current.trim().is_empty() {
+                    ""
+                } else if current.trim_end().ends_with(',') {
+                    " "
+                } else {
+                    ", "
+                };
+                let at = source.offset(paren.span.close().start());
+                edits.push(SourceEdit::insert(
+                    at,
+                    format!("{}{}", separator, missing.join(", ")),
+                ));
+            }
+            None => {
+                let start = item.insert_at.start();
+                let indent = source.indentation(start.line);
+                edits.push(SourceEdit::insert(
+                    source.offset(start),
+                    format!("#[derive({})]\n{}", missing.join(", "), indent),
+                ));
+            }
+        }
+        updated.push(item.ident.to_string());
+    }
+
+    if edits.is_empty() {
+        return Err(RulesError::RefactoringFailed(format!(
+            "{} already derived",
+            traits.join(", ")
+        )));
+    }
+
+    Ok(RefactoringPlan {
+        edits,
+        summary: format!(
+            "Added derive({}) to {}",
+            traits.join(", "),
+            updated.join(", ")
+        ),
+    })
+}
@@ -0,0 +1,487 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactorings/extract_function.rs
+use proc_macro2::{TokenStream, TokenTree};
+use std::collections::HashMap;
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{
+    Block, Expr, ExprClosure, ExprPath, File, FnArg, ImplItemFn, Item, ItemFn, Local, Macro, Pat,
+    Signature, Stmt,
+};
+
+use crate::rules::RulesError;
+use crate::rules::refactorings::{RefactoringPlan, validate_ident};
+use crate::rules::source_edit::{SourceEdit, SourceText};
+
+fn failed(message: impl Into<String>) -> RulesError {
+    RulesError::RefactoringFailed(message.into())
+}
+
+fn lines_of(node: &impl Spanned) -> (usize, usize) {
+    let span = node.span();
+    (span.start().line, span.end().line)
+}
+
+/// The statements selected for extraction and the function that contains them
+struct Selection<'ast> {
+    sig: &'ast Signature,
+    fn_block: &'ast Block,
+    block: &'ast Block,
+    range: std::ops::Range<usize>,
+}
+
+impl Selection<'_> {
+    fn stmts(&self) -> &[Stmt] {
+        &self.block.stmts[self.range.clone()]
+    }
+
+    fn covered_lines(&self) -> usize {
+        let stmts = self.stmts();
+        match (stmts.first(), stmts.last()) {
+            (Some(first), Some(last)) => lines_of(last).1 - lines_of(first).0 + 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Finds the outermost run of statements lying entirely within the line range
+struct SelectionFinder<'ast> {
+    start_line: usize,
+    end_line: usize,
+    current_fn: Option<(&'ast Signature, &'ast Block)>,
+    best: Option<Selection<'ast>>,
+    partial_overlap: bool,
+}
+
+impl<'ast> SelectionFinder<'ast> {
+    fn visit_fn(&mut self, sig: &'ast Signature, block: &'ast Block) {
+        let outer = self.current_fn.replace((sig, block));
+        self.visit_block(block);
+        self.current_fn = outer;
+    }
+}
+
+impl<'ast> Visit<'ast> for SelectionFinder<'ast> {
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        self.visit_fn(&item.sig, &item.block);
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast ImplItemFn) {
+        self.visit_fn(&item.sig, &item.block);
+    }
+
+    fn visit_block(&mut self, block: &'ast Block) {
+        if let Some((sig, fn_block)) = self.current_fn {
+            let mut selected: Vec<usize> = Vec::new();
+            for (index, stmt) in block.stmts.iter().enumerate() {
+                let (first, last) = lines_of(stmt);
+                let inside = first >= self.start_line && last <= self.end_line;
+                let overlaps = first <= self.end_line && last >= self.start_line;
+                let contains_range = first <= self.start_line && last >= self.end_line;
+                if inside {
+                    selected.push(index);
+                } else if overlaps && !contains_range {
+                    self.partial_overlap = true;
+                }
+            }
+
+            if let (Some(&first), Some(&last)) = (selected.first(), selected.last()) {
+                let candidate = Selection {
+                    sig,
+                    fn_block,
+                    block,
+                    range: first..last + 1,
+                };
+                if self
+                    .best
+                    .as_ref()
+                    .is_none_or(|best| candidate.covered_lines() > best.covered_lines())
+                {
+                    self.best = Some(candidate);
+                }
+            }
+        }
+        syn::visit::visit_block(self, block);
+    }
+}
+
+/// Rejects selections whose control flow would change meaning inside a new function
+#[derive(Default)]
+struct ControlFlowCheck {
+    loop_depth: usize,
+    problem: Option<&'static str>,
+}
+
+impl<'ast> Visit<'ast> for ControlFlowCheck {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        match expr {
+            Expr::Return(_) => self.problem = Some("'return'"),
+            Expr::Try(_) => self.problem = Some("'?'"),
+            Expr::Break(_) | Expr::Continue(_) if self.loop_depth == 0 => {
+                self.problem = Some("'break' / 'continue'")
+            }
+            Expr::Path(path) if path.path.is_ident("self") => self.problem = Some("'self'"),
+            Expr::ForLoop(_) | Expr::While(_) | Expr::Loop(_) => {
+                self.loop_depth += 1;
+                syn::visit::visit_expr(self, expr);
+                self.loop_depth -= 1;
+                return;
+            }
+            _ => {}
+        }
+        syn::visit::visit_expr(self, expr);
+    }
+
+    fn visit_expr_closure(&mut self, _closure: &'ast ExprClosure) {
+        // A closure body has its own return / `?` scope
+    }
+
+    fn visit_item(&mut self, _item: &'ast Item) {}
+}
+
+/// Identifiers referenced as plain paths or macro tokens, in first-use order
+#[derive(Default)]
+struct NameUses {
+    names: Vec<String>,
+}
+
+impl NameUses {
+    fn record(&mut self, name: String) {
+        if !self.names.contains(&name) {
+            self.names.push(name);
+        }
+    }
+
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) => self.record(ident.to_string()),
+                TokenTree::Group(group) => self.visit_tokens(group.stream()),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for NameUses {
+    fn visit_expr_path(&mut self, expr: &'ast ExprPath) {
+        if let Some(ident) = expr.path.get_ident() {
+            self.record(ident.to_string());
+        }
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        self.visit_tokens(mac.tokens.clone());
+    }
+}
+
+/// A binding name with its source-level type annotation, if any
+struct TypedBinding {
+    name: String,
+    ty: Option<String>,
+    mutable: bool,
+}
+
+fn typed_binding(pat: &Pat, source: &SourceText<'_>) -> Option<TypedBinding> {
+    match pat {
+        Pat::Ident(pat_ident) => Some(TypedBinding {
+            name: pat_ident.ident.to_string(),
+            ty: None,
+            mutable: pat_ident.mutability.is_some(),
+        }),
+        Pat::Type(pat_type) => {
+            let mut binding = typed_binding(&pat_type.pat, source)?;
+            binding.ty = Some(source.span_text(pat_type.ty.span()).to_string());
+            Some(binding)
+        }
+        _ => None,
+    }
+}
+
+/// `let` bindings ending before a given line
+struct LocalsBefore<'a, 'src> {
+    line: usize,
+    source: &'a SourceText<'src>,
+    bindings: Vec<TypedBinding>,
+}
+
+impl<'ast> Visit<'ast> for LocalsBefore<'_, '_> {
+    fn visit_local(&mut self, local: &'ast Local) {
+        if lines_of(local).1 < self.line
+            && let Some(binding) = typed_binding(&local.pat, self.source)
+        {
+            self.bindings.push(binding);
+        }
+        syn::visit::visit_local(self, local);
+    }
+
+    fn visit_expr_closure(&mut self, _closure: &'ast ExprClosure) {}
+
+    fn visit_item(&mut self, _item: &'ast Item) {}
+}
+
+/// Top-level (or module-level) item containing a line, for placing the new function
+fn enclosing_item(items: &[Item], line: usize) -> Option<&Item> {
+    let item = items.iter().find(|item| {
+        let (first, last) = lines_of(*item);
+        first <= line && line <= last
+    })?;
+    if let Item::Mod(module) = item
+        && let Some((_, nested)) = &module.content
+    {
+        return enclosing_item(nested, line).or(Some(item));
+    }
+    Some(item)
+}
+
+/// Re-indents `text` so its first line's indentation becomes `indent`
+fn reindent(text: &str, base: &str, indent: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "{}{}",
+                    indent,
+                    line.strip_prefix(base).unwrap_or(line.trim_start())
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub(crate) fn plan(
+    syntax_tree: &File,
+    source: &SourceText<'_>,
+    start_line: usize,
+    end_line: usize,
+    name: &str,
+) -> Result<RefactoringPlan, RulesError> {
+    validate_ident(name)?;
+    if start_line == 0 || start_line > end_line {
+        return Err(failed(format!(
+            "Invalid line range {}-{}",
+            start_line, end_line
+        )));
+    }
+
+    let mut finder = SelectionFinder {
+        start_line,
+        end_line,
+        current_fn: None,
+        best: None,
+        partial_overlap: false,
+    };
+    finder.visit_file(syntax_tree);
+    let selection = finder.best.ok_or_else(|| {
+        failed(format!(
+            "No complete statements on lines {}-{}",
+            start_line, end_line
+        ))
+    })?;
+    if finder.partial_overlap {
+        return Err(failed("The line range cuts through a statement"));
+    }
+
+    let stmts = selection.stmts();
+    if selection.range.end == selection.block.stmts.len()
+        && matches!(stmts.last(), Some(Stmt::Expr(_, None)))
+    {
+        return Err(failed("Cannot extract the block's tail expression"));
+    }
+
+    let mut control_flow = ControlFlowCheck::default();
+    for stmt in stmts {
+        control_flow.visit_stmt(stmt);
+    }
+    if let Some(problem) = control_flow.problem {
+        return Err(failed(format!("Selection uses {}", problem)));
+    }
+
+    let name_taken = syntax_tree
+        .items
+        .iter()
+        .any(|item| matches!(item, Item::Fn(f) if f.sig.ident == name));
+    if name_taken {
+        return Err(failed(format!(
+            "A function named '{}' already exists",
+            name
+        )));
+    }
+
+    // Inputs: parameters and earlier locals the selection reads
+    let first_line = lines_of(&stmts[0]).0;
+    let last_line = stmts
+        .last()
+        .map(|stmt| lines_of(stmt).1)
+        .unwrap_or(first_line);
+
+    let mut outer: HashMap<String, TypedBinding> = HashMap::new();
+    for input in &selection.sig.inputs {
+        if let FnArg::Typed(pat_type) = input
+            && let Some(binding) = typed_binding(&pat_type.pat, source)
+        {
+            let ty = source.span_text(pat_type.ty.span()).to_string();
+            outer.insert(
+                binding.name.clone(),
+                TypedBinding {
+                    ty: Some(ty),
+                    ..binding
+                },
+            );
+        }
+    }
+    let mut locals = LocalsBefore {
+        line: first_line,
+        source,
+        bindings: Vec::new(),
+    };
+    locals.visit_block(selection.fn_block);
+    for binding in locals.bindings {
+        outer.insert(binding.name.clone(), binding);
+    }
+
+    let mut used = NameUses::default();
+    for stmt in stmts {
+        used.visit_stmt(stmt);
+    }
+    let mut params = Vec::new();
+    let mut args = Vec::new();
+    for used_name in &used.names {
+        if let Some(binding) = outer.get(used_name) {
+            let ty = binding.ty.as_ref().ok_or_else(|| {
+                failed(format!(
+                    "Cannot infer the type of '{}'; add a type annotation first",
+                    used_name
+                ))
+            })?;
+            params.push(format!(
+                "{}{}: {}",
+                if binding.mutable { "mut " } else { "" },
+                used_name,
+                ty
+            ));
+            args.push(used_name.clone());
+        }
+    }
+
+    // Outputs: bindings introduced by the selection and read afterwards
+    let mut later = NameUses::default();
+    for stmt in &selection.block.stmts[selection.range.end..] {
+        later.visit_stmt(stmt);
+    }
+    let mut outputs = Vec::new();
+    for stmt in stmts {
+        if let Stmt::Local(local) = stmt
+            && let Some(binding) = typed_binding(&local.pat, source)
+            && later.names.contains(&binding.name)
+        {
+            if binding.ty.is_none() {
+                return Err(failed(format!(
+                    "Cannot infer the type of '{}'; add a type annotation first",
+                    binding.name
+                )));
+            }
+            outputs.push(binding);
+        }
+    }
+
+    // The selected statements must own their lines so they can be moved verbatim
+    let stmts_start = source.offset(stmts[0].span().start());
+    let stmts_end = source.offset(stmts[stmts.len() - 1].span().end());
+    let block_text = source.lines(first_line, last_line);
+    let prefix = &block_text[..stmts_start - source.line_start(first_line)];
+    let suffix = &source.lines(last_line, last_line)[stmts_end - source.line_start(last_line)..];
+    if !prefix.trim().is_empty() || !suffix.trim().is_empty() {
+        return Err(failed(
+            "The selected statements share lines with other code",
+        ));
+    }
+
+    let item = enclosing_item(&syntax_tree.items, first_line)
+        .ok_or_else(|| failed("Selection is not inside an item"))?;
+    let (item_line, item_end_line) = lines_of(item);
+    let item_indent = source.indentation(item_line);
+    let body_indent = format!("{}    ", item_indent);
+    let call_indent = source.indentation(first_line);
+
+    let (return_type, return_expr, call_binding) = match outputs.as_slice() {
+        [] => (String::new(), None, String::new()),
+        [single] => (
+            format!(" -> {}", single.ty.clone().unwrap_or_default()),
+            Some(single.name.clone()),
+            format!(
+                "let {}{} = ",
+                if single.mutable { "mut " } else { "" },
+                single.name
+            ),
+        ),
+        many => (
+            format!(
+                " -> ({})",
+                many.iter()
+                    .map(|binding| binding.ty.clone().unwrap_or_default())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Some(format!(
+                "({})",
+                many.iter()
+                    .map(|binding| binding.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            format!(
+                "let ({}) = ",
+                many.iter()
+                    .map(|binding| format!(
+                        "{}{}",
+                        if binding.mutable { "mut " } else { "" },
+                        binding.name
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ),
+    };
+
+    let mut new_fn = format!(
+        "\n{}fn {}({}){} {{\n{}\n",
+        item_indent,
+        name,
+        params.join(", "),
+        return_type,
+        reindent(block_text, call_indent, &body_indent)
+    );
+    if let Some(expr) = return_expr {
+        new_fn.push_str(&format!("{}{}\n", body_indent, expr));
+    }
+    new_fn.push_str(&format!("{}}}\n", item_indent));
+
+    let call = format!(
+        "{}{}{}({});\n",
+        call_indent,
+        call_binding,
+        name,
+        args.join(", ")
+    );
+
+    Ok(RefactoringPlan {
+        edits: vec![
+            SourceEdit::replace(
+                source.line_start(first_line),
+                source.line_end(last_line),
+                call,
+            ),
+            SourceEdit::insert(source.line_end(item_end_line), new_fn),
+        ],
+        summary: format!(
+            "Extracted lines {}-{} into fn {}({})",
+            first_line,
+            last_line,
+            name,
+            args.join(", ")
+        ),
+    })
+}
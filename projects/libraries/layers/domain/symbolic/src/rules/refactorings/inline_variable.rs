@@ -0,0 +1,236 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactorings/inline_variable.rs
+use proc_macro2::{Span, TokenStream, TokenTree};
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{Block, Expr, ExprPath, FieldValue, File, Local, Macro, Member, Pat, Stmt};
+
+use crate::rules::RulesError;
+use crate::rules::refactorings::RefactoringPlan;
+use crate::rules::source_edit::{SourceEdit, SourceText};
+
+/// A `let name = init;` statement and the block that owns it
+struct Binding<'ast> {
+    block: &'ast Block,
+    index: usize,
+    local: &'ast Local,
+    init: &'ast Expr,
+}
+
+struct BindingFinder<'a, 'ast> {
+    name: &'a str,
+    bindings: Vec<Binding<'ast>>,
+    rejected: Option<String>,
+}
+
+impl<'ast> Visit<'ast> for BindingFinder<'_, 'ast> {
+    fn visit_block(&mut self, block: &'ast Block) {
+        for (index, stmt) in block.stmts.iter().enumerate() {
+            let Stmt::Local(local) = stmt else {
+                continue;
+            };
+            let pat = match &local.pat {
+                Pat::Type(pat_type) => pat_type.pat.as_ref(),
+                pat => pat,
+            };
+            let Pat::Ident(pat_ident) = pat else {
+                continue;
+            };
+            if pat_ident.ident != self.name {
+                continue;
+            }
+
+            match &local.init {
+                Some(init) if init.diverge.is_none() => {
+                    if pat_ident.mutability.is_some() || pat_ident.by_ref.is_some() {
+                        self.rejected =
+                            Some(format!("'{}' is mutable or bound by reference", self.name));
+                    }
+                    self.bindings.push(Binding {
+                        block,
+                        index,
+                        local,
+                        init: &init.expr,
+                    });
+                }
+                _ => self.rejected = Some(format!("'{}' has no plain initializer", self.name)),
+            }
+        }
+        syn::visit::visit_block(self, block);
+    }
+}
+
+/// Uses of the binding: plain paths, field shorthands and macro tokens
+struct UseCollector<'a> {
+    name: &'a str,
+    paths: Vec<Span>,
+    shorthands: Vec<Span>,
+    in_format_string: bool,
+}
+
+impl UseCollector<'_> {
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        let inline_arg = format!("{{{}", self.name);
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) if ident == self.name => self.paths.push(ident.span()),
+                TokenTree::Group(group) => self.visit_tokens(group.stream()),
+                TokenTree::Literal(literal) => {
+                    let text = literal.to_string();
+                    if text.contains(&format!("{}}}", inline_arg))
+                        || text.contains(&format!("{}:", inline_arg))
+                    {
+                        self.in_format_string = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for UseCollector<'_> {
+    fn visit_expr_path(&mut self, expr: &'ast ExprPath) {
+        if expr.qself.is_none() && expr.path.is_ident(self.name) {
+            self.paths.push(expr.path.span());
+        }
+    }
+
+    fn visit_field_value(&mut self, field: &'ast FieldValue) {
+        match &field.member {
+            Member::Named(ident) if field.colon_token.is_none() && ident == self.name => {
+                self.shorthands.push(ident.span());
+            }
+            _ => syn::visit::visit_field_value(self, field),
+        }
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        self.visit_tokens(mac.tokens.clone());
+    }
+}
+
+/// Initializers that can be substituted without parentheses
+fn is_atomic(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Lit(_)
+            | Expr::Path(_)
+            | Expr::Call(_)
+            | Expr::MethodCall(_)
+            | Expr::Field(_)
+            | Expr::Paren(_)
+            | Expr::Macro(_)
+            | Expr::Index(_)
+            | Expr::Tuple(_)
+            | Expr::Array(_)
+    )
+}
+
+/// Initializers that are cheap and side-effect free to evaluate more than once
+fn is_duplicable(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) | Expr::Path(_) => true,
+        Expr::Field(field) => is_duplicable(&field.base),
+        Expr::Paren(paren) => is_duplicable(&paren.expr),
+        _ => false,
+    }
+}
+
+/// Removes a statement together with its line when nothing else shares that line
+fn removal_edit(source: &SourceText<'_>, stmt_span: Span) -> SourceEdit {
+    let start = stmt_span.start();
+    let end = stmt_span.end();
+    let before = &source.lines(start.line, start.line)
+        [..source.offset(start) - source.line_start(start.line)];
+    let after_start = source.offset(end);
+    let after = &source.lines(end.line, end.line)[after_start - source.line_start(end.line)..];
+
+    if before.trim().is_empty() && after.trim().is_empty() {
+        SourceEdit::replace(source.line_start(start.line), source.line_end(end.line), "")
+    } else {
+        SourceEdit::replace_span(source, stmt_span, "")
+    }
+}
+
+pub(crate) fn plan(
+    syntax_tree: &File,
+    source: &SourceText<'_>,
+    name: &str,
+) -> Result<RefactoringPlan, RulesError> {
+    let mut finder = BindingFinder {
+        name,
+        bindings: Vec::new(),
+        rejected: None,
+    };
+    finder.visit_file(syntax_tree);
+
+    if let Some(reason) = finder.rejected {
+        return Err(RulesError::RefactoringFailed(format!(
+            "Cannot inline: {}",
+            reason
+        )));
+    }
+    let binding = match finder.bindings.as_slice() {
+        [binding] => binding,
+        [] => {
+            return Err(RulesError::RefactoringFailed(format!(
+                "No 'let {}' binding found",
+                name
+            )));
+        }
+        _ => {
+            return Err(RulesError::RefactoringFailed(format!(
+                "'{}' is bound more than once; rename the bindings first",
+                name
+            )));
+        }
+    };
+
+    let mut uses = UseCollector {
+        name,
+        paths: Vec::new(),
+        shorthands: Vec::new(),
+        in_format_string: false,
+    };
+    for stmt in &binding.block.stmts[binding.index + 1..] {
+        uses.visit_stmt(stmt);
+    }
+
+    if uses.in_format_string {
+        return Err(RulesError::RefactoringFailed(format!(
+            "'{}' is captured by a format string",
+            name
+        )));
+    }
+    let use_count = uses.paths.len() + uses.shorthands.len();
+    if use_count > 1 && !is_duplicable(binding.init) {
+        return Err(RulesError::RefactoringFailed(format!(
+            "'{}' is used {} times and its initializer would be evaluated repeatedly",
+            name, use_count
+        )));
+    }
+
+    let init_text = source.span_text(binding.init.span());
+    let value = if is_atomic(binding.init) {
+        init_text.to_string()
+    } else {
+        format!("({})", init_text)
+    };
+
+    let mut edits = vec![removal_edit(source, binding.local.span())];
+    edits.extend(
+        uses.paths
+            .iter()
+            .map(|span| SourceEdit::replace_span(source, *span, value.clone())),
+    );
+    edits.extend(
+        uses.shorthands
+            .iter()
+            .map(|span| SourceEdit::replace_span(source, *span, format!("{}: {}", name, value))),
+    );
+
+    Ok(RefactoringPlan {
+        edits,
+        summary: format!("Inlined '{}' into {} use(s)", name, use_count),
+    })
+}
@@ -0,0 +1,119 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactorings/make_public.rs
+use syn::{Fields, File, Item, Visibility};
+
+use crate::rules::RulesError;
+use crate::rules::refactorings::RefactoringPlan;
+use crate::rules::source_edit::{SourceEdit, SourceText};
+
+/// Name and keyword span of a top-level item that can be made public
+fn item_keyword(item: &Item) -> Option<(&Visibility, &syn::Ident, proc_macro2::Span)> {
+    match item {
+        Item::Struct(item) => Some((&item.vis, &item.ident, item.struct_token.span)),
+        Item::Enum(item) => Some((&item.vis, &item.ident, item.enum_token.span)),
+        Item::Fn(item) => Some((&item.vis, &item.sig.ident, item.sig.fn_token.span)),
+        Item::Trait(item) => Some((&item.vis, &item.ident, item.trait_token.span)),
+        Item::Type(item) => Some((&item.vis, &item.ident, item.type_token.span)),
+        Item::Const(item) => Some((&item.vis, &item.ident, item.const_token.span)),
+        _ => None,
+    }
+}
+
+/// `make public` makes private structs public; `make X public` targets any named item
+pub(crate) fn plan_items(
+    syntax_tree: &File,
+    source: &SourceText<'_>,
+    target: Option<&str>,
+) -> Result<RefactoringPlan, RulesError> {
+    let mut edits = Vec::new();
+    let mut names = Vec::new();
+
+    for item in &syntax_tree.items {
+        let Some((vis, ident, keyword)) = item_keyword(item) else {
+            continue;
+        };
+        let selected = match target {
+            Some(name) => ident == name,
+            None => matches!(item, Item::Struct(_)),
+        };
+        if selected && matches!(vis, Visibility::Inherited) {
+            // Insert before qualifiers such as `async` / `unsafe` by anchoring on the item start
+            let start = match item {
+                Item::Fn(item_fn) => item_fn
+                    .sig
+                    .constness
+                    .map(|token| token.span)
+                    .or(item_fn.sig.asyncness.map(|token| token.span))
+                    .or(item_fn.sig.unsafety.map(|token| token.span))
+                    .unwrap_or(keyword),
+                _ => keyword,
+            };
+            edits.push(SourceEdit::insert(source.offset(start.start()), "pub "));
+            names.push(ident.to_string());
+        }
+    }
+
+    if edits.is_empty() {
+        return Err(RulesError::RefactoringFailed(match target {
+            Some(name) => format!("No private item named '{}'", name),
+            None => "No private struct to make public".to_string(),
+        }));
+    }
+
+    Ok(RefactoringPlan {
+        edits,
+        summary: format!("Made {} public", names.join(", ")),
+    })
+}
+
+pub(crate) fn plan_fields(
+    syntax_tree: &File,
+    source: &SourceText<'_>,
+    target: Option<&str>,
+) -> Result<RefactoringPlan, RulesError> {
+    let mut edits = Vec::new();
+    let mut structs = Vec::new();
+
+    for item in &syntax_tree.items {
+        let Item::Struct(item_struct) = item else {
+            continue;
+        };
+        if target.is_some_and(|name| item_struct.ident != name) {
+            continue;
+        }
+        let Fields::Named(fields) = &item_struct.fields else {
+            continue;
+        };
+
+        let before = edits.len();
+        for field in fields
+            .named
+            .iter()
+            .filter(|field| matches!(field.vis, Visibility::Inherited))
+        {
+            if let Some(ident) = &field.ident {
+                edits.push(SourceEdit::insert(
+                    source.offset(ident.span().start()),
+                    "pub ",
+                ));
+            }
+        }
+        if edits.len() > before {
+            structs.push(item_struct.ident.to_string());
+        }
+    }
+
+    if edits.is_empty() {
+        return Err(RulesError::RefactoringFailed(
+            "No private named fields to make public".to_string(),
+        ));
+    }
+
+    Ok(RefactoringPlan {
+        summary: format!(
+            "Made {} fields of {} public",
+            edits.len(),
+            structs.join(", ")
+        ),
+        edits,
+    })
+}
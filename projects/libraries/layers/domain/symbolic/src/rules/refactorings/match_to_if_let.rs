@@ -0,0 +1,137 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactorings/match_to_if_let.rs
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{Expr, ExprMatch, File, Pat};
+
+use crate::rules::RulesError;
+use crate::rules::refactorings::RefactoringPlan;
+use crate::rules::source_edit::{SourceEdit, SourceText};
+
+/// Finds `match x { pat => .., _ => .. }` expressions, outermost first
+struct MatchCollector<'ast> {
+    line: Option<usize>,
+    matches: Vec<&'ast ExprMatch>,
+}
+
+impl<'ast> Visit<'ast> for MatchCollector<'ast> {
+    fn visit_expr_match(&mut self, expr: &'ast ExprMatch) {
+        let on_line = self
+            .line
+            .is_none_or(|line| expr.match_token.span.start().line == line);
+        if on_line && is_convertible(expr) {
+            // Nested matches would produce overlapping edits; convert them in a later pass
+            self.matches.push(expr);
+        } else {
+            syn::visit::visit_expr_match(self, expr);
+        }
+    }
+}
+
+fn is_convertible(expr: &ExprMatch) -> bool {
+    match expr.arms.as_slice() {
+        [first, second] => {
+            first.guard.is_none()
+                && second.guard.is_none()
+                && !matches!(first.pat, Pat::Wild(_))
+                && matches!(second.pat, Pat::Wild(_))
+        }
+        _ => false,
+    }
+}
+
+fn is_empty_body(body: &Expr) -> bool {
+    match body {
+        Expr::Block(block) => block.block.stmts.is_empty() && block.label.is_none(),
+        Expr::Tuple(tuple) => tuple.elems.is_empty(),
+        _ => false,
+    }
+}
+
+/// Removes up to `width` leading spaces from every line but the first
+fn dedent_tail(text: &str, width: usize) -> String {
+    text.split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            if index == 0 {
+                return line.to_string();
+            }
+            let spaces = line.len() - line.trim_start_matches(' ').len();
+            line[spaces.min(width)..].to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Source of an arm body as a block, re-indented to sit at the `match` level
+fn body_block(source: &SourceText<'_>, body: &Expr, indent: &str, shift: usize) -> String {
+    let text = source.span_text(body.span());
+    match body {
+        Expr::Block(block) if block.label.is_none() && block.attrs.is_empty() => {
+            dedent_tail(text, shift)
+        }
+        _ => format!(
+            "{{\n{}    {}\n{}}}",
+            indent,
+            dedent_tail(text, shift),
+            indent
+        ),
+    }
+}
+
+pub(crate) fn plan(
+    syntax_tree: &File,
+    source: &SourceText<'_>,
+    line: Option<usize>,
+) -> Result<RefactoringPlan, RulesError> {
+    let mut collector = MatchCollector {
+        line,
+        matches: Vec::new(),
+    };
+    collector.visit_file(syntax_tree);
+
+    if collector.matches.is_empty() {
+        return Err(RulesError::RefactoringFailed(match line {
+            Some(line) => format!("No two-arm match with a '_' arm on line {}", line),
+            None => "No two-arm match with a '_' arm found".to_string(),
+        }));
+    }
+
+    let mut edits = Vec::new();
+    for expr in &collector.matches {
+        let [arm, fallback] = expr.arms.as_slice() else {
+            continue;
+        };
+
+        let match_line = expr.match_token.span.start().line;
+        let indent = source.indentation(match_line);
+        let arm_indent = source.indentation(arm.pat.span().start().line);
+        let shift = arm_indent.len().saturating_sub(indent.len());
+
+        let pattern = source.span_text(arm.pat.span());
+        let scrutinee = source.span_text(expr.expr.span());
+        // A struct literal scrutinee would be parsed as the `if let` body
+        let scrutinee = if matches!(*expr.expr, Expr::Struct(_)) {
+            format!("({})", scrutinee)
+        } else {
+            scrutinee.to_string()
+        };
+
+        let mut replacement = format!(
+            "if let {} = {} {}",
+            pattern,
+            scrutinee,
+            body_block(source, &arm.body, indent, shift)
+        );
+        if !is_empty_body(&fallback.body) {
+            replacement.push_str(" else ");
+            replacement.push_str(&body_block(source, &fallback.body, indent, shift));
+        }
+
+        edits.push(SourceEdit::replace_span(source, expr.span(), replacement));
+    }
+
+    Ok(RefactoringPlan {
+        summary: format!("Converted {} match expression(s) to if let", edits.len()),
+        edits,
+    })
+}
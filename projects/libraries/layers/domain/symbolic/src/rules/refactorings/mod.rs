@@ -0,0 +1,55 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactorings/mod.rs
+// Structural refactorings computed as span-aware source edits
+mod add_derive;
+mod extract_function;
+mod inline_variable;
+mod make_public;
+mod match_to_if_let;
+mod rename_symbol;
+
+use syn::File;
+
+use crate::rules::source_edit::{SourceEdit, SourceText};
+use crate::rules::{Refactoring, RulesError};
+
+/// Edits implementing a refactoring, plus a one-line summary for `changes_applied`
+pub(crate) struct RefactoringPlan {
+    pub edits: Vec<SourceEdit>,
+    pub summary: String,
+}
+
+/// Computes the edits for `refactoring` against a parsed file and its source
+pub(crate) fn plan(
+    refactoring: &Refactoring,
+    syntax_tree: &File,
+    source: &SourceText<'_>,
+) -> Result<RefactoringPlan, RulesError> {
+    match refactoring {
+        Refactoring::RenameSymbol { from, to } => {
+            rename_symbol::plan(syntax_tree, source, from, to)
+        }
+        Refactoring::ExtractFunction {
+            start_line,
+            end_line,
+            name,
+        } => extract_function::plan(syntax_tree, source, *start_line, *end_line, name),
+        Refactoring::InlineVariable { name } => inline_variable::plan(syntax_tree, source, name),
+        Refactoring::MatchToIfLet { line } => match_to_if_let::plan(syntax_tree, source, *line),
+        Refactoring::AddDerive { traits, target } => {
+            add_derive::plan(syntax_tree, source, traits, target.as_deref())
+        }
+        Refactoring::MakePublic { target } => {
+            make_public::plan_items(syntax_tree, source, target.as_deref())
+        }
+        Refactoring::MakeFieldsPublic { target } => {
+            make_public::plan_fields(syntax_tree, source, target.as_deref())
+        }
+    }
+}
+
+/// Rejects names that are not valid Rust identifiers
+pub(crate) fn validate_ident(name: &str) -> Result<(), RulesError> {
+    syn::parse_str::<syn::Ident>(name)
+        .map(|_| ())
+        .map_err(|_| RulesError::RefactoringFailed(format!("'{}' is not a valid identifier", name)))
+}
@@ -0,0 +1,96 @@
+// projects/libraries/layers/domain/symbolic/src/rules/refactorings/rename_symbol.rs
+use proc_macro2::{Span, TokenStream, TokenTree};
+use syn::visit::Visit;
+use syn::{File, Ident, Lifetime, Macro};
+
+use crate::rules::RulesError;
+use crate::rules::refactorings::{RefactoringPlan, validate_ident};
+use crate::rules::source_edit::{SourceEdit, SourceText};
+
+/// Collects the span of every occurrence of an identifier
+struct IdentOccurrences<'a> {
+    name: &'a str,
+    spans: Vec<Span>,
+}
+
+impl IdentOccurrences<'_> {
+    /// Macro bodies are opaque token streams to syn; walk them by hand
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) if ident == self.name => self.spans.push(ident.span()),
+                TokenTree::Group(group) => self.visit_tokens(group.stream()),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for IdentOccurrences<'_> {
+    fn visit_ident(&mut self, ident: &'ast Ident) {
+        if ident == self.name {
+            self.spans.push(ident.span());
+        }
+    }
+
+    fn visit_lifetime(&mut self, _lifetime: &'ast Lifetime) {
+        // `'a` shares its ident with a binding named `a`; lifetimes are never renamed
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        syn::visit::visit_macro(self, mac);
+        self.visit_tokens(mac.tokens.clone());
+    }
+}
+
+pub(crate) fn plan(
+    syntax_tree: &File,
+    source: &SourceText<'_>,
+    from: &str,
+    to: &str,
+) -> Result<RefactoringPlan, RulesError> {
+    validate_ident(to)?;
+    if from == to {
+        return Err(RulesError::RefactoringFailed(format!(
+            "'{}' already has that name",
+            from
+        )));
+    }
+
+    let mut occurrences = IdentOccurrences {
+        name: from,
+        spans: Vec::new(),
+    };
+    occurrences.visit_file(syntax_tree);
+
+    let mut clashes = IdentOccurrences {
+        name: to,
+        spans: Vec::new(),
+    };
+    clashes.visit_file(syntax_tree);
+
+    if occurrences.spans.is_empty() {
+        return Err(RulesError::RefactoringFailed(format!(
+            "Symbol '{}' not found",
+            from
+        )));
+    }
+    if !clashes.spans.is_empty() {
+        return Err(RulesError::RefactoringFailed(format!(
+            "Renaming '{}' to '{}' would clash with an existing '{}'",
+            from, to, to
+        )));
+    }
+
+    let count = occurrences.spans.len();
+    let edits = occurrences
+        .spans
+        .into_iter()
+        .map(|span| SourceEdit::replace_span(source, span, to))
+        .collect();
+
+    Ok(RefactoringPlan {
+        edits,
+        summary: format!("Renamed '{}' to '{}' ({} occurrences)", from, to, count),
+    })
+}
@@ -2,11 +2,19 @@
 use regex::Regex;
 use std::collections::HashMap;
 
+use crate::rules::refactorings;
+use crate::rules::source_edit::{SourceText, apply_edits};
 use crate::rules::{
-    CodeTemplate, RefactoringRule, RulesError, refactoring_result::RefactoringResult,
+    CodeTemplate, DEFAULT_DIFF_CONTEXT, Refactoring, RefactoringRule, RulesError,
+    refactoring_result::RefactoringResult, unified_diff,
 };
 
+/// Lowest confidence feedback can push a template down to, so a weakened
+/// template still matches and can be reinforced again.
+const MIN_TEMPLATE_CONFIDENCE: f64 = 0.1;
+
 /// Rules engine for symbolic generation
+#[derive(Clone)]
 pub struct RulesEngine {
     templates: HashMap<String, Vec<CodeTemplate>>,
     refactoring_rules: Vec<RefactoringRule>,
@@ -21,7 +29,6 @@ impl RulesEngine {
 
         // Initialize with default templates
         engine.init_default_templates()?;
-        engine.init_refactoring_rules()?;
 
         Ok(engine)
     }
@@ -97,39 +104,6 @@ pub enum {name} {{
         Ok(())
     }
 
-    /// Initializes refactoring rules
-    fn init_refactoring_rules(&mut self) -> Result<(), RulesError> {
-        self.refactoring_rules.push(RefactoringRule {
-            name: "add_debug_derive".to_string(),
-            pattern: r"^(pub )?struct ".to_string(),
-            replacement: r"#[derive(Debug)]\n$0".to_string(),
-            description: "Add Debug derive to structs".to_string(),
-        });
-
-        self.refactoring_rules.push(RefactoringRule {
-            name: "add_clone_derive".to_string(),
-            pattern: r"^(pub )?struct ".to_string(),
-            replacement: r"#[derive(Clone)]\n$0".to_string(),
-            description: "Add Clone derive to structs".to_string(),
-        });
-
-        self.refactoring_rules.push(RefactoringRule {
-            name: "make_public".to_string(),
-            pattern: r"^struct ".to_string(),
-            replacement: r"pub struct ".to_string(),
-            description: "Make struct public".to_string(),
-        });
-
-        self.refactoring_rules.push(RefactoringRule {
-            name: "make_fields_public".to_string(),
-            pattern: r"    ([a-z_][a-z0-9_]*): ".to_string(),
-            replacement: r"    pub $1: ".to_string(),
-            description: "Make struct fields public".to_string(),
-        });
-
-        Ok(())
-    }
-
     /// Adds a template
     fn add_template(
         &mut self,
@@ -400,11 +374,89 @@ pub enum {name} {{
         0.0
     }
 
-    /// Applies a refactoring to the code
+    /// Moves the confidence of every template matching `prompt` by `delta`,
+    /// clamped between `MIN_TEMPLATE_CONFIDENCE` and 1. Adjusting every match,
+    /// not just the first, keeps the result independent of template order.
+    /// Returns the number of templates adjusted.
+    pub fn adjust_template_confidence(&mut self, prompt: &str, delta: f64) -> usize {
+        let prompt_lower = prompt.to_lowercase();
+        let mut adjusted = 0;
+
+        for templates in self.templates.values_mut() {
+            for template in templates
+                .iter_mut()
+                .filter(|template| prompt_lower.contains(&template.pattern))
+            {
+                template.confidence =
+                    (template.confidence + delta).clamp(MIN_TEMPLATE_CONFIDENCE, 1.0);
+                adjusted += 1;
+            }
+        }
+
+        tracing::debug!(
+            "rules_engine.adjust_template_confidence delta={} adjusted={}",
+            delta,
+            adjusted
+        );
+        adjusted
+    }
+
+    /// Applies a refactoring to the code.
+    ///
+    /// Instructions describing a structural refactoring (see `Refactoring::parse`) are
+    /// applied on the syntax tree; anything else falls back to the registered regex rules.
     pub fn apply_refactoring(
         &self,
         code: &str,
         instruction: &str,
+    ) -> Result<RefactoringResult, RulesError> {
+        if let Some(refactoring) = Refactoring::parse(instruction)? {
+            tracing::debug!("Instruction parsed as {:?}", refactoring);
+            return self.apply_structural_refactoring(code, &refactoring);
+        }
+
+        self.apply_rule_refactoring(code, instruction)
+    }
+
+    /// Applies a structural refactoring through span-aware edits, leaving untouched
+    /// code byte-for-byte identical. Fails if the result no longer parses.
+    pub fn apply_structural_refactoring(
+        &self,
+        code: &str,
+        refactoring: &Refactoring,
+    ) -> Result<RefactoringResult, RulesError> {
+        let syntax_tree =
+            syn::parse_file(code).map_err(|e| RulesError::ParseError(e.to_string()))?;
+        let source = SourceText::new(code);
+        let plan = refactorings::plan(refactoring, &syntax_tree, &source)?;
+        let result_code = apply_edits(code, plan.edits)?;
+
+        if let Err(e) = syn::parse_file(&result_code) {
+            tracing::error!(
+                "rules_engine.apply_structural_refactoring produced invalid code error={}",
+                e
+            );
+            return Err(RulesError::VerificationFailed(e.to_string()));
+        }
+
+        tracing::info!(
+            "rules_engine.apply_structural_refactoring success change={}",
+            plan.summary
+        );
+        Ok(RefactoringResult {
+            diff: unified_diff(code, &result_code, DEFAULT_DIFF_CONTEXT),
+            code: result_code,
+            confidence: 0.95,
+            changes_applied: vec![plan.summary],
+            verified: true,
+        })
+    }
+
+    /// Applies the registered regex rules whose name appears in the instruction
+    fn apply_rule_refactoring(
+        &self,
+        code: &str,
+        instruction: &str,
     ) -> Result<RefactoringResult, RulesError> {
         let instruction_lower = instruction.to_lowercase();
         let mut result_code = code.to_string();
@@ -451,6 +503,8 @@ pub enum {name} {{
             changes.len()
         );
         Ok(RefactoringResult {
+            diff: unified_diff(code, &result_code, DEFAULT_DIFF_CONTEXT),
+            verified: syn::parse_file(&result_code).is_ok(),
             code: result_code,
             confidence: 0.85,
             changes_applied: changes,
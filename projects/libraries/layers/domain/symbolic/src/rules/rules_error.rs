@@ -11,4 +11,8 @@ pub enum RulesError {
     GenerationFailed(String),
     #[error("Parse error: {0}")]
     ParseError(String),
+    #[error("Refactoring failed: {0}")]
+    RefactoringFailed(String),
+    #[error("Refactored code no longer parses: {0}")]
+    VerificationFailed(String),
 }
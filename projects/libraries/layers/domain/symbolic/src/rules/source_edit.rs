@@ -0,0 +1,123 @@
+// projects/libraries/layers/domain/symbolic/src/rules/source_edit.rs
+use proc_macro2::{LineColumn, Span};
+
+use crate::rules::RulesError;
+
+/// Source text indexed by line so `proc_macro2` positions map to byte offsets
+pub(crate) struct SourceText<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceText<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(text.match_indices('\n').map(|(index, _)| index + 1));
+        Self { text, line_starts }
+    }
+
+    /// Byte offset of a 1-based line / 0-based character column
+    pub(crate) fn offset(&self, position: LineColumn) -> usize {
+        let Some(&line_start) = self.line_starts.get(position.line.saturating_sub(1)) else {
+            return self.text.len();
+        };
+        self.text[line_start..]
+            .char_indices()
+            .nth(position.column)
+            .map(|(index, _)| line_start + index)
+            .unwrap_or(self.text.len())
+    }
+
+    pub(crate) fn slice(&self, start: LineColumn, end: LineColumn) -> &'a str {
+        &self.text[self.offset(start)..self.offset(end)]
+    }
+
+    pub(crate) fn span_text(&self, span: Span) -> &'a str {
+        self.slice(span.start(), span.end())
+    }
+
+    /// Byte offset of the start of a 1-based line
+    pub(crate) fn line_start(&self, line: usize) -> usize {
+        self.line_starts
+            .get(line.saturating_sub(1))
+            .copied()
+            .unwrap_or(self.text.len())
+    }
+
+    /// Byte offset just past the newline ending a 1-based line
+    pub(crate) fn line_end(&self, line: usize) -> usize {
+        self.line_starts
+            .get(line)
+            .copied()
+            .unwrap_or(self.text.len())
+    }
+
+    /// Leading whitespace of a 1-based line
+    pub(crate) fn indentation(&self, line: usize) -> &'a str {
+        let start = self.line_start(line);
+        let rest = &self.text[start..self.line_end(line)];
+        let trimmed = rest.trim_start_matches([' ', '\t']);
+        &rest[..rest.len() - trimmed.len()]
+    }
+
+    /// Full text of lines `first..=last`, including the trailing newline
+    pub(crate) fn lines(&self, first: usize, last: usize) -> &'a str {
+        &self.text[self.line_start(first)..self.line_end(last)]
+    }
+}
+
+/// A replacement of the byte range `start..end`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SourceEdit {
+    pub start: usize,
+    pub end: usize,
+    pub replacement: String,
+}
+
+impl SourceEdit {
+    pub(crate) fn replace(start: usize, end: usize, replacement: impl Into<String>) -> Self {
+        Self {
+            start,
+            end,
+            replacement: replacement.into(),
+        }
+    }
+
+    pub(crate) fn insert(at: usize, text: impl Into<String>) -> Self {
+        Self::replace(at, at, text)
+    }
+
+    pub(crate) fn replace_span(
+        source: &SourceText<'_>,
+        span: Span,
+        replacement: impl Into<String>,
+    ) -> Self {
+        Self::replace(
+            source.offset(span.start()),
+            source.offset(span.end()),
+            replacement,
+        )
+    }
+}
+
+/// Applies non-overlapping edits; everything outside the edited ranges is kept verbatim
+pub(crate) fn apply_edits(text: &str, mut edits: Vec<SourceEdit>) -> Result<String, RulesError> {
+    edits.sort_by_key(|edit| (edit.start, edit.end));
+
+    let mut result = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for edit in &edits {
+        if edit.start < cursor || edit.end < edit.start || edit.end > text.len() {
+            return Err(RulesError::RefactoringFailed(format!(
+                "Overlapping or invalid edit at byte {}",
+                edit.start
+            )));
+        }
+        result.push_str(&text[cursor..edit.start]);
+        result.push_str(&edit.replacement);
+        cursor = edit.end;
+    }
+    result.push_str(&text[cursor..]);
+
+    Ok(result)
+}
@@ -1,5 +1,8 @@
 // projects/libraries/layers/domain/symbolic/src/rules/tests/mod.rs
+mod refactoring_test;
 mod rules_engine_test;
+mod structural_refactoring_test;
+mod unified_diff_test;
 
 // Re-export test helpers from the main tests module for convenience
 pub(crate) use crate::tests::test_helpers;
@@ -0,0 +1,94 @@
+// projects/libraries/layers/domain/symbolic/src/rules/tests/refactoring_test.rs
+use super::test_helpers::TestResult;
+use crate::rules::Refactoring;
+
+#[test]
+fn test_parse_rename() -> TestResult {
+    assert_eq!(
+        Refactoring::parse("rename variable 'x' to 'count'")?,
+        Some(Refactoring::RenameSymbol {
+            from: "x".to_string(),
+            to: "count".to_string(),
+        })
+    );
+    assert_eq!(
+        Refactoring::parse("Rename total to sum")?,
+        Some(Refactoring::RenameSymbol {
+            from: "total".to_string(),
+            to: "sum".to_string(),
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_extract_function_both_orders() -> TestResult {
+    let expected = Some(Refactoring::ExtractFunction {
+        start_line: 3,
+        end_line: 5,
+        name: "compute".to_string(),
+    });
+    assert_eq!(
+        Refactoring::parse("extract lines 3-5 into function compute")?,
+        expected
+    );
+    assert_eq!(
+        Refactoring::parse("extract fn compute from lines 3 to 5")?,
+        expected
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_inline_and_match() -> TestResult {
+    assert_eq!(
+        Refactoring::parse("inline variable tmp")?,
+        Some(Refactoring::InlineVariable {
+            name: "tmp".to_string()
+        })
+    );
+    assert_eq!(
+        Refactoring::parse("convert match to if let")?,
+        Some(Refactoring::MatchToIfLet { line: None })
+    );
+    assert_eq!(
+        Refactoring::parse("convert match to if let at line 7")?,
+        Some(Refactoring::MatchToIfLet { line: Some(7) })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_derives_and_visibility() -> TestResult {
+    assert_eq!(
+        Refactoring::parse("add debug derive")?,
+        Some(Refactoring::AddDerive {
+            traits: vec!["Debug".to_string()],
+            target: None,
+        })
+    );
+    assert_eq!(
+        Refactoring::parse("add derive(Clone, partialeq) to User")?,
+        Some(Refactoring::AddDerive {
+            traits: vec!["Clone".to_string(), "PartialEq".to_string()],
+            target: Some("User".to_string()),
+        })
+    );
+    assert_eq!(
+        Refactoring::parse("make fields public")?,
+        Some(Refactoring::MakeFieldsPublic { target: None })
+    );
+    assert_eq!(
+        Refactoring::parse("make Config public")?,
+        Some(Refactoring::MakePublic {
+            target: Some("Config".to_string())
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_unknown_instruction() -> TestResult {
+    assert_eq!(Refactoring::parse("lint fix 3 5 unwrap in library")?, None);
+    Ok(())
+}
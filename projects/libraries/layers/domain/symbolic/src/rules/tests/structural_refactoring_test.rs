@@ -0,0 +1,319 @@
+// projects/libraries/layers/domain/symbolic/src/rules/tests/structural_refactoring_test.rs
+use super::test_helpers::TestResult;
+use crate::rules::{Refactoring, RulesEngine, RulesError};
+
+fn create_engine() -> RulesEngine {
+    RulesEngine::new().expect("Failed to create RulesEngine")
+}
+
+#[test]
+fn test_rename_symbol_preserves_formatting() -> TestResult {
+    let engine = create_engine();
+    let code = r#"// totals
+fn total<'x>(x: &'x [u32]) -> u32 {
+    let   x_sum = x.iter().sum::<u32>();   // keep spacing
+    println!("{}", x.len());
+    x_sum
+}
+"#;
+    let result = engine.apply_refactoring(code, "rename variable 'x' to 'values'")?;
+
+    assert_eq!(
+        result.code,
+        r#"// totals
+fn total<'x>(values: &'x [u32]) -> u32 {
+    let   x_sum = values.iter().sum::<u32>();   // keep spacing
+    println!("{}", values.len());
+    x_sum
+}
+"#
+    );
+    assert!(result.verified);
+    assert_eq!(result.confidence, 0.95);
+    assert_eq!(
+        result.changes_applied,
+        vec!["Renamed 'x' to 'values' (3 occurrences)"]
+    );
+    assert!(result.diff.contains("-fn total<'x>(x: &'x [u32]) -> u32 {"));
+    assert!(
+        result
+            .diff
+            .contains("+fn total<'x>(values: &'x [u32]) -> u32 {")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rename_rejects_clash_and_missing_symbol() {
+    let engine = create_engine();
+    let code = "fn f(a: u8, b: u8) -> u8 { a + b }\n";
+
+    let clash = engine.apply_refactoring(code, "rename a to b");
+    assert!(matches!(clash, Err(RulesError::RefactoringFailed(_))));
+
+    let missing = engine.apply_refactoring(code, "rename zzz to y");
+    assert!(matches!(missing, Err(RulesError::RefactoringFailed(_))));
+}
+
+#[test]
+fn test_structural_refactoring_requires_valid_input() {
+    let engine = create_engine();
+    let result = engine.apply_refactoring("fn broken( {", "rename a to b");
+    assert!(matches!(result, Err(RulesError::ParseError(_))));
+}
+
+#[test]
+fn test_add_derive_merges_into_existing_attribute() -> TestResult {
+    let engine = create_engine();
+    let code = r#"/// A user
+#[derive(Clone)]
+pub struct User {
+    name: String,
+}
+
+enum Role {
+    Admin,
+}
+"#;
+    let result = engine.apply_refactoring(code, "add derive Debug, Clone")?;
+
+    assert_eq!(
+        result.code,
+        r#"/// A user
+#[derive(Clone, Debug)]
+pub struct User {
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+enum Role {
+    Admin,
+}
+"#
+    );
+    assert_eq!(
+        result.changes_applied,
+        vec!["Added derive(Debug, Clone) to User, Role"]
+    );
+
+    let again = engine.apply_refactoring(&result.code, "add derive Debug, Clone");
+    assert!(matches!(again, Err(RulesError::RefactoringFailed(_))));
+    Ok(())
+}
+
+#[test]
+fn test_make_public_and_fields_public() -> TestResult {
+    let engine = create_engine();
+    let code = "struct Point {\n    x: i32,\n    pub y: i32,\n}\n\nasync fn load() {}\n";
+
+    let result = engine.apply_refactoring(code, "make public")?;
+    assert!(result.code.starts_with("pub struct Point {"));
+    assert!(result.code.contains("\nasync fn load() {}"));
+
+    let result = engine.apply_refactoring(code, "make load public")?;
+    assert!(result.code.contains("pub async fn load() {}"));
+
+    let result = engine.apply_refactoring(code, "make fields public")?;
+    assert!(result.code.contains("    pub x: i32,\n    pub y: i32,"));
+    assert_eq!(
+        result.changes_applied,
+        vec!["Made 1 fields of Point public"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_match_to_if_let() -> TestResult {
+    let engine = create_engine();
+    let code = r#"fn show(value: Option<u8>) -> u8 {
+    match value {
+        Some(v) => {
+            println!("{}", v);
+        }
+        _ => {}
+    }
+    match value {
+        Some(v) => v,
+        _ => 0,
+    }
+}
+"#;
+    let result = engine.apply_refactoring(code, "convert match to if let")?;
+
+    assert_eq!(
+        result.code,
+        r#"fn show(value: Option<u8>) -> u8 {
+    if let Some(v) = value {
+        println!("{}", v);
+    }
+    if let Some(v) = value {
+        v
+    } else {
+        0
+    }
+}
+"#
+    );
+    assert_eq!(
+        result.changes_applied,
+        vec!["Converted 2 match expression(s) to if let"]
+    );
+
+    let only_second = engine.apply_refactoring(code, "convert match to if let at line 8")?;
+    assert!(
+        only_second
+            .code
+            .contains("    match value {\n        Some(v) => {")
+    );
+    assert!(
+        only_second
+            .code
+            .contains("    if let Some(v) = value {\n        v\n")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_match_with_more_arms_is_left_alone() {
+    let engine = create_engine();
+    let code = "fn f(v: u8) -> u8 {\n    match v {\n        0 => 1,\n        1 => 2,\n        _ => 3,\n    }\n}\n";
+    let result = engine.apply_refactoring(code, "convert match to if let");
+    assert!(matches!(result, Err(RulesError::RefactoringFailed(_))));
+}
+
+#[test]
+fn test_inline_variable() -> TestResult {
+    let engine = create_engine();
+    let code = r#"fn area(width: u32, height: u32) -> Rect {
+    let size = width * height;
+    println!("{}", size);
+    Rect { size }
+}
+"#;
+    let result = engine.apply_refactoring(code, "inline variable size");
+    // Used twice with a non-trivial initializer: refuse to duplicate the computation
+    assert!(matches!(result, Err(RulesError::RefactoringFailed(_))));
+
+    let code = r#"fn area(width: u32, height: u32) -> Rect {
+    let size = width * height;
+    Rect { size }
+}
+
+fn scale(factor: u32) -> u32 {
+    let base = 10;
+    base * factor + base
+}
+"#;
+    let result = engine.apply_refactoring(code, "inline variable size")?;
+    assert_eq!(
+        result.code,
+        r#"fn area(width: u32, height: u32) -> Rect {
+    Rect { size: (width * height) }
+}
+
+fn scale(factor: u32) -> u32 {
+    let base = 10;
+    base * factor + base
+}
+"#
+    );
+
+    let result = engine.apply_refactoring(code, "inline base")?;
+    assert!(result.code.contains("    10 * factor + 10\n"));
+    assert!(!result.code.contains("let base"));
+    Ok(())
+}
+
+#[test]
+fn test_inline_rejects_format_capture() {
+    let engine = create_engine();
+    let code = "fn f() {\n    let name = \"x\";\n    println!(\"{name}\");\n}\n";
+    let result = engine.apply_refactoring(code, "inline name");
+    assert!(matches!(result, Err(RulesError::RefactoringFailed(_))));
+}
+
+#[test]
+fn test_extract_function() -> TestResult {
+    let engine = create_engine();
+    let code = r#"fn report(items: &[u32], label: &str) -> String {
+    let scale: u32 = 2;
+    let total: u32 = items.iter().sum::<u32>() * scale;
+    let line: String = format!("{}: {}", label, total);
+    println!("{}", line);
+    line
+}
+"#;
+    let result = engine.apply_refactoring(code, "extract lines 3-4 into function summarize")?;
+
+    assert_eq!(
+        result.code,
+        r#"fn report(items: &[u32], label: &str) -> String {
+    let scale: u32 = 2;
+    let line = summarize(items, scale, label);
+    println!("{}", line);
+    line
+}
+
+fn summarize(items: &[u32], scale: u32, label: &str) -> String {
+    let total: u32 = items.iter().sum::<u32>() * scale;
+    let line: String = format!("{}: {}", label, total);
+    line
+}
+"#
+    );
+    assert!(result.verified);
+    Ok(())
+}
+
+#[test]
+fn test_extract_function_rejections() {
+    let engine = create_engine();
+    let code = r#"fn run(input: Option<u32>) -> Option<u32> {
+    let value = input?;
+    let doubled = value * 2;
+    Some(doubled)
+}
+"#;
+    // `?` would change meaning inside the new function
+    let result = engine.apply_refactoring(code, "extract lines 2-2 into function take");
+    assert!(matches!(result, Err(RulesError::RefactoringFailed(_))));
+
+    // `value` has no type annotation to build the parameter list from
+    let result = engine.apply_refactoring(code, "extract lines 3-3 into function double");
+    assert!(matches!(result, Err(RulesError::RefactoringFailed(msg)) if msg.contains("'value'")));
+
+    // Tail expressions stay where they are
+    let result = engine.apply_refactoring(code, "extract lines 4-4 into function wrap");
+    assert!(matches!(result, Err(RulesError::RefactoringFailed(_))));
+}
+
+#[test]
+fn test_apply_structural_refactoring_directly() -> TestResult {
+    let engine = create_engine();
+    let result = engine.apply_structural_refactoring(
+        "struct A;\n",
+        &Refactoring::AddDerive {
+            traits: vec!["Default".to_string()],
+            target: Some("A".to_string()),
+        },
+    )?;
+    assert_eq!(result.code, "#[derive(Default)]\nstruct A;\n");
+    Ok(())
+}
+
+#[test]
+fn test_regex_rules_still_apply_for_unstructured_instructions() -> TestResult {
+    let mut engine = create_engine();
+    engine.add_refactoring_rule(
+        "todo_to_unimplemented".to_string(),
+        r"todo!\(\)".to_string(),
+        "unimplemented!()".to_string(),
+        "Replace todo!() with unimplemented!()".to_string(),
+    );
+    let result = engine.apply_refactoring("fn f() { todo!() }\n", "todo to unimplemented")?;
+    assert_eq!(result.code, "fn f() { unimplemented!() }\n");
+    assert_eq!(result.confidence, 0.85);
+    assert!(result.verified);
+    assert!(result.diff.contains("+fn f() { unimplemented!() }"));
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// projects/libraries/layers/domain/symbolic/src/rules/tests/unified_diff_test.rs
+use crate::rules::unified_diff;
+
+#[test]
+fn test_identical_texts_have_empty_diff() {
+    assert_eq!(unified_diff("a\nb\n", "a\nb\n", 3), "");
+}
+
+#[test]
+fn test_single_line_change() {
+    let before = "one\ntwo\nthree\nfour\nfive\nsix\n";
+    let after = "one\ntwo\nthree\nFOUR\nfive\nsix\n";
+
+    assert_eq!(
+        unified_diff(before, after, 1),
+        "--- original\n+++ refactored\n@@ -3,3 +3,3 @@\n three\n-four\n+FOUR\n five\n"
+    );
+}
+
+#[test]
+fn test_insertion_and_separate_hunks() {
+    let before = "a\nb\nc\nd\ne\nf\ng\nh\n";
+    let after = "a\nnew\nb\nc\nd\ne\nf\ng\nh\nend\n";
+    let diff = unified_diff(before, after, 1);
+
+    assert_eq!(diff.matches("@@").count(), 4, "{}", diff);
+    assert!(diff.contains("@@ -1,2 +1,3 @@\n a\n+new\n b\n"));
+    assert!(diff.contains("@@ -8,1 +9,2 @@\n h\n+end\n"));
+}
@@ -0,0 +1,140 @@
+// projects/libraries/layers/domain/symbolic/src/rules/unified_diff.rs
+
+/// Lines of context shown around each change
+pub const DEFAULT_DIFF_CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Renders a line-based unified diff (`--- original` / `+++ refactored`).
+///
+/// Returns an empty string when both texts are identical.
+pub fn unified_diff(before: &str, after: &str, context: usize) -> String {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+    let ops = diff_ops(&old, &new);
+
+    let changes: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, DiffOp::Equal(..)))
+        .map(|(index, _)| index)
+        .collect();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    // Group changes whose context windows touch into hunks of op indices
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &index in &changes {
+        let start = index.saturating_sub(context);
+        let end = (index + context + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut output = String::from("--- original\n+++ refactored\n");
+    for (start, end) in hunks {
+        let (old_start, new_start) = positions_before(&ops, start);
+        let hunk = &ops[start..end];
+        let old_len = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Insert(_)))
+            .count();
+        let new_len = hunk
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Delete(_)))
+            .count();
+
+        output.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_len),
+            hunk_range(new_start, new_len)
+        ));
+        for op in hunk {
+            match *op {
+                DiffOp::Equal(i, _) => output.push_str(&format!(" {}\n", old[i])),
+                DiffOp::Delete(i) => output.push_str(&format!("-{}\n", old[i])),
+                DiffOp::Insert(j) => output.push_str(&format!("+{}\n", new[j])),
+            }
+        }
+    }
+
+    output
+}
+
+/// `start,len` with the 1-based start line; empty ranges point at the preceding line
+fn hunk_range(start: usize, len: usize) -> String {
+    if len == 0 {
+        format!("{},0", start)
+    } else {
+        format!("{},{}", start + 1, len)
+    }
+}
+
+/// Number of old and new lines consumed before op `index`
+fn positions_before(ops: &[DiffOp], index: usize) -> (usize, usize) {
+    ops[..index].iter().fold((0, 0), |(old, new), op| match op {
+        DiffOp::Equal(..) => (old + 1, new + 1),
+        DiffOp::Delete(_) => (old + 1, new),
+        DiffOp::Insert(_) => (old, new + 1),
+    })
+}
+
+/// Longest-common-subsequence line diff; the shared prefix and suffix are
+/// stripped first so refactorings touching a few lines stay cheap.
+fn diff_ops(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    // lcs[i][j] = LCS length of old_mid[i..] and new_mid[j..]
+    let mut lcs = vec![vec![0usize; new_mid.len() + 1]; old_mid.len() + 1];
+    for i in (0..old_mid.len()).rev() {
+        for j in (0..new_mid.len()).rev() {
+            lcs[i][j] = if old_mid[i] == new_mid[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<DiffOp> = (0..prefix).map(|i| DiffOp::Equal(i, i)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < old_mid.len() || j < new_mid.len() {
+        if i < old_mid.len() && j < new_mid.len() && old_mid[i] == new_mid[j] {
+            ops.push(DiffOp::Equal(prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if i < old_mid.len() && (j == new_mid.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push(DiffOp::Delete(prefix + i));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(prefix + j));
+            j += 1;
+        }
+    }
+    let old_tail = old.len() - suffix;
+    let new_tail = new.len() - suffix;
+    ops.extend((0..suffix).map(|k| DiffOp::Equal(old_tail + k, new_tail + k)));
+
+    ops
+}
@@ -2,14 +2,19 @@
 use crate::feedback_symbolic::SymbolicFeedback;
 use crate::validator::ValidationError;
 use crate::{
-    analyzer::CodeAnalyzer, rules::RulesEngine, solver_result::SolverResult,
+    analyzer::CodeAnalyzer, linter::CodeLinter, rules::RulesEngine, solver_result::SolverResult,
     symbolic_error::SymbolicError, validator::CodeValidator,
 };
 use tracing;
 
+/// Confidence change applied to matching templates per feedback verdict.
+const RULE_CONFIDENCE_STEP: f64 = 0.05;
+
 /// Symbolic solver - internal orchestration of symbolic
+#[derive(Clone)]
 pub struct SymbolicSolver {
     analyzer: CodeAnalyzer,
+    linter: CodeLinter,
     rules: RulesEngine,
     validator: CodeValidator,
 }
@@ -18,6 +23,7 @@ impl SymbolicSolver {
     pub fn new() -> Result<Self, SymbolicError> {
         Ok(Self {
             analyzer: CodeAnalyzer::new(),
+            linter: CodeLinter::default(),
             rules: RulesEngine::new().map_err(|e| SymbolicError::GenerationError(e.to_string()))?,
             validator: CodeValidator::new()
                 .map_err(|e| SymbolicError::ValidationError(e.to_string()))?,
@@ -72,8 +78,14 @@ impl SymbolicSolver {
         })
     }
 
-    fn lint(&self, _code: &str) -> Result<SolverResult, SymbolicError> {
-        let issues: Vec<String> = vec!["Issue 1".to_string(), "Issue 2".to_string()];
+    fn lint(&self, code: &str) -> Result<SolverResult, SymbolicError> {
+        let issues: Vec<String> = self
+            .linter
+            .lint(code)
+            .map_err(|e| SymbolicError::AnalysisError(e.to_string()))?
+            .iter()
+            .map(ToString::to_string)
+            .collect();
         let output = if issues.is_empty() {
             "No issues found".to_string()
         } else {
@@ -162,13 +174,19 @@ impl SymbolicSolver {
         Ok(())
     }
 
-    pub fn reinforce_rule(&mut self, input: &str) {
+    /// Raises the confidence of the templates matching `input`.
+    /// Returns the number of templates adjusted.
+    pub fn reinforce_rule(&mut self, input: &str) -> usize {
         tracing::info!(input=%input, "Reinforcing rule");
-        // Logic to reinforce a rule
+        self.rules
+            .adjust_template_confidence(input, RULE_CONFIDENCE_STEP)
     }
 
-    pub fn weaken_rule(&mut self, input: &str) {
+    /// Lowers the confidence of the templates matching `input`.
+    /// Returns the number of templates adjusted.
+    pub fn weaken_rule(&mut self, input: &str) -> usize {
         tracing::info!(input=%input, "Weakening rule");
-        // Logic to weaken a rule
+        self.rules
+            .adjust_template_confidence(input, -RULE_CONFIDENCE_STEP)
     }
 }
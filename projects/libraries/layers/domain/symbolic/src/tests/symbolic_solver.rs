@@ -1,5 +1,6 @@
 // projects/libraries/layers/domain/symbolic/src/tests/symbolic_solver.rs
 use super::test_helpers::TestResult;
+use crate::feedback_symbolic::SymbolicFeedback;
 use crate::symbolic_error::SymbolicError;
 use crate::symbolic_solver::SymbolicSolver;
 
@@ -57,8 +58,25 @@ fn test_solve_generation_no_template() -> TestResult {
 fn test_solve_linting_path() -> TestResult {
     let solver = SymbolicSolver::new()?;
     let result = solver.solve("fn main() {}", "linting", None)?;
-    assert!(result.output.contains("Found 2 issues"));
-    assert_eq!(result.metadata.as_deref(), Some("2 issues"));
+    assert_eq!(result.output, "No issues found");
+    assert_eq!(result.metadata.as_deref(), Some("0 issues"));
+
+    let result = solver.solve(
+        "fn load(v: Option<u8>) -> u8 { v.unwrap() }",
+        "linting",
+        None,
+    )?;
+    assert!(result.output.contains("Found 1 issues"));
+    assert!(result.output.contains("unwrap_in_library"));
+    assert_eq!(result.metadata.as_deref(), Some("1 issues"));
+    Ok(())
+}
+
+#[test]
+fn test_solve_linting_rejects_invalid_code() -> TestResult {
+    let solver = SymbolicSolver::new()?;
+    let err = solver.solve("fn broken( {", "linting", None).unwrap_err();
+    assert!(matches!(err, SymbolicError::AnalysisError(_)));
     Ok(())
 }
 
@@ -89,7 +107,7 @@ fn test_solve_refactor_success() -> TestResult {
     let result = solver.solve(code, "refactoring", Some("add debug derive"))?;
     assert!(result.output.contains("#[derive(Debug)]"));
     assert!(result.metadata.is_some());
-    assert_eq!(result.confidence, 0.85);
+    assert_eq!(result.confidence, 0.95);
     Ok(())
 }
 
@@ -121,3 +139,27 @@ fn test_validate_code_valid() -> TestResult {
     assert!(validation.is_valid);
     Ok(())
 }
+
+#[test]
+fn test_feedback_adjusts_template_confidence() -> TestResult {
+    let mut solver = SymbolicSolver::new()?;
+    let prompt = "create struct MyType";
+
+    solver.adjust_rules(prompt, SymbolicFeedback::new(false, None))?;
+    let weakened = solver.solve(prompt, "generation", None)?.confidence;
+    assert!((weakened - 0.85).abs() < 1e-9);
+
+    for _ in 0..5 {
+        assert_eq!(solver.reinforce_rule(prompt), 1);
+    }
+    let reinforced = solver.solve(prompt, "generation", None)?.confidence;
+    assert_eq!(reinforced, 1.0);
+    Ok(())
+}
+
+#[test]
+fn test_unmatched_feedback_changes_nothing() -> TestResult {
+    let mut solver = SymbolicSolver::new()?;
+    assert_eq!(solver.weaken_rule("no template here"), 0);
+    Ok(())
+}
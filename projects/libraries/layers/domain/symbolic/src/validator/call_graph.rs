@@ -0,0 +1,367 @@
+// projects/libraries/layers/domain/symbolic/src/validator/call_graph.rs
+use proc_macro2::{TokenStream, TokenTree};
+use std::collections::{BTreeSet, VecDeque};
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
+use syn::{Attribute, Expr, ExprMethodCall, ImplItem, Item, Macro, Meta, Path, Token};
+
+use crate::validator::module_tree::{ModuleId, ModuleTree};
+use crate::validator::symbol::{Resolution, SymbolId, SymbolKind};
+use crate::validator::symbol_table::SymbolTable;
+
+/// Reference graph between the symbols of a crate.
+///
+/// An edge `a -> b` means the body or signature of `a` names `b`: a call, a
+/// type in a signature or field, a constructor, or a macro invocation. Method
+/// calls on a receiver link to every method with that name, since receiver
+/// types are not inferred.
+#[derive(Debug, Clone, Default)]
+pub struct CallGraph {
+    callees: Vec<BTreeSet<SymbolId>>,
+    callers: Vec<BTreeSet<SymbolId>>,
+    /// Symbols named by module-level code without an owning symbol, such as
+    /// top-level macro invocations or `impl` blocks for external types
+    anchored: BTreeSet<SymbolId>,
+}
+
+impl CallGraph {
+    pub fn build(tree: &ModuleTree, table: &SymbolTable) -> Self {
+        let count = table.symbols().len();
+        let mut graph = Self {
+            callees: vec![BTreeSet::new(); count],
+            callers: vec![BTreeSet::new(); count],
+            anchored: BTreeSet::new(),
+        };
+
+        for module in tree.modules() {
+            for item in &module.items {
+                graph.add_item(table, module.id, item);
+            }
+        }
+
+        tracing::debug!(
+            "Built call graph symbols={} edges={}",
+            count,
+            graph.edge_count()
+        );
+        graph
+    }
+
+    fn add_item(&mut self, table: &SymbolTable, module: ModuleId, item: &Item) {
+        let owner_of = |ident: &syn::Ident| {
+            table.symbol_at(module, &ident.to_string(), ident.span().start().line)
+        };
+
+        match item {
+            Item::Fn(item_fn) => {
+                let owner = owner_of(&item_fn.sig.ident);
+                self.collect(table, module, None, owner, |collector| {
+                    collector.visit_item_fn(item_fn)
+                });
+            }
+            Item::Struct(item_struct) => {
+                let owner = owner_of(&item_struct.ident);
+                self.collect(table, module, owner, owner, |collector| {
+                    collector.visit_item_struct(item_struct)
+                });
+            }
+            Item::Enum(item_enum) => {
+                let owner = owner_of(&item_enum.ident);
+                self.collect(table, module, owner, owner, |collector| {
+                    collector.visit_item_enum(item_enum)
+                });
+            }
+            Item::Union(item_union) => {
+                let owner = owner_of(&item_union.ident);
+                self.collect(table, module, owner, owner, |collector| {
+                    collector.visit_item_union(item_union)
+                });
+            }
+            Item::Trait(item_trait) => {
+                let owner = owner_of(&item_trait.ident);
+                self.collect(table, module, None, owner, |collector| {
+                    collector.visit_item_trait(item_trait)
+                });
+            }
+            Item::Const(item_const) => {
+                let owner = owner_of(&item_const.ident);
+                self.collect(table, module, None, owner, |collector| {
+                    collector.visit_item_const(item_const)
+                });
+            }
+            Item::Static(item_static) => {
+                let owner = owner_of(&item_static.ident);
+                self.collect(table, module, None, owner, |collector| {
+                    collector.visit_item_static(item_static)
+                });
+            }
+            Item::Type(item_type) => {
+                let owner = owner_of(&item_type.ident);
+                self.collect(table, module, None, owner, |collector| {
+                    collector.visit_item_type(item_type)
+                });
+            }
+            Item::Impl(item_impl) => {
+                let self_type = table.impl_owner(module, item_impl);
+                self.collect(table, module, self_type, self_type, |collector| {
+                    collector.visit_generics(&item_impl.generics);
+                    if let Some((_, trait_path, _)) = &item_impl.trait_ {
+                        collector.visit_path(trait_path);
+                    }
+                    collector.visit_type(&item_impl.self_ty);
+                });
+                for impl_item in &item_impl.items {
+                    let owner = match impl_item {
+                        ImplItem::Fn(method) => owner_of(&method.sig.ident),
+                        _ => self_type,
+                    };
+                    self.collect(table, module, self_type, owner, |collector| {
+                        collector.visit_impl_item(impl_item)
+                    });
+                }
+            }
+            // `macro_rules!` bodies are templates; only invocations count
+            Item::Macro(item_macro) if item_macro.ident.is_none() => {
+                self.collect(table, module, None, None, |collector| {
+                    collector.visit_item_macro(item_macro)
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn collect(
+        &mut self,
+        table: &SymbolTable,
+        module: ModuleId,
+        self_type: Option<SymbolId>,
+        owner: Option<SymbolId>,
+        visit: impl FnOnce(&mut ReferenceCollector<'_>),
+    ) {
+        let mut collector = ReferenceCollector {
+            table,
+            module,
+            self_type,
+            found: BTreeSet::new(),
+        };
+        visit(&mut collector);
+
+        match owner {
+            Some(owner) => {
+                for target in collector.found {
+                    if target != owner {
+                        self.callees[owner].insert(target);
+                        self.callers[target].insert(owner);
+                    }
+                }
+            }
+            None => self.anchored.extend(collector.found),
+        }
+    }
+
+    /// Symbols referenced by `id`
+    pub fn callees(&self, id: SymbolId) -> &BTreeSet<SymbolId> {
+        &self.callees[id]
+    }
+
+    /// Symbols that reference `id`
+    pub fn callers(&self, id: SymbolId) -> &BTreeSet<SymbolId> {
+        &self.callers[id]
+    }
+
+    /// Symbols referenced from module-level code that has no owning symbol
+    pub fn anchored(&self) -> &BTreeSet<SymbolId> {
+        &self.anchored
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.callees.iter().map(BTreeSet::len).sum()
+    }
+
+    /// Every symbol reachable from `roots`, the roots included
+    pub fn reachable_from(&self, roots: impl IntoIterator<Item = SymbolId>) -> BTreeSet<SymbolId> {
+        let mut reached = BTreeSet::new();
+        let mut queue: VecDeque<SymbolId> = roots.into_iter().collect();
+        while let Some(id) = queue.pop_front() {
+            if reached.insert(id) {
+                queue.extend(self.callees[id].iter().copied());
+            }
+        }
+        reached
+    }
+}
+
+/// Collects the crate symbols named inside one item
+struct ReferenceCollector<'a> {
+    table: &'a SymbolTable,
+    module: ModuleId,
+    /// Type `Self` refers to inside an `impl` or type definition
+    self_type: Option<SymbolId>,
+    found: BTreeSet<SymbolId>,
+}
+
+impl ReferenceCollector<'_> {
+    fn add_segments(&mut self, segments: &[String]) {
+        if segments.first().is_some_and(|first| first == "Self") {
+            if let Some(self_type) = self.self_type {
+                self.found.insert(self_type);
+                if let Some(method) = segments
+                    .get(1)
+                    .and_then(|name| self.table.method(self_type, name))
+                {
+                    self.found.insert(method);
+                }
+            }
+            return;
+        }
+        // Every prefix counts: `Type::new` references both `Type` and `new`
+        for end in 1..=segments.len() {
+            if let Some(Resolution::Symbol(id)) =
+                self.table.resolve_path(self.module, &segments[..end])
+            {
+                self.found.insert(id);
+            }
+        }
+    }
+
+    /// Fallback for macro arguments that are not plain expressions
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        let mut after_dot = false;
+        for token in tokens {
+            match token {
+                TokenTree::Ident(ident) => {
+                    let name = ident.to_string();
+                    if after_dot {
+                        self.found.extend(self.table.methods_named(&name));
+                    } else {
+                        self.add_segments(&[name]);
+                    }
+                    after_dot = false;
+                }
+                TokenTree::Group(group) => {
+                    self.visit_tokens(group.stream());
+                    after_dot = false;
+                }
+                TokenTree::Punct(punct) => after_dot = punct.as_char() == '.',
+                TokenTree::Literal(_) => after_dot = false,
+            }
+        }
+    }
+
+    /// Paths named in attribute strings, as in `#[serde(with = "codec")]`
+    fn visit_attribute_strings(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Literal(literal) => {
+                    let text = literal.to_string();
+                    let Some(value) = text.strip_prefix('"').and_then(|t| t.strip_suffix('"'))
+                    else {
+                        continue;
+                    };
+                    if let Ok(path) = syn::parse_str::<Path>(value) {
+                        let segments: Vec<String> = path
+                            .segments
+                            .iter()
+                            .map(|segment| segment.ident.to_string())
+                            .collect();
+                        self.add_segments(&segments);
+                    }
+                }
+                TokenTree::Group(group) => self.visit_attribute_strings(group.stream()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Names captured inline by format strings, as in `format!("{name}")`
+    fn visit_format_captures(&mut self, tokens: TokenStream) {
+        for token in tokens {
+            match token {
+                TokenTree::Literal(literal) => {
+                    for name in format_captures(&literal.to_string()) {
+                        self.add_segments(&[name]);
+                    }
+                }
+                TokenTree::Group(group) => self.visit_format_captures(group.stream()),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn format_captures(literal: &str) -> Vec<String> {
+    let mut captures = Vec::new();
+    let mut rest = literal;
+    while let Some(open) = rest.find('{') {
+        rest = &rest[open + 1..];
+        if let Some(escaped) = rest.strip_prefix('{') {
+            rest = escaped;
+            continue;
+        }
+        let end = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let name = &rest[..end];
+        let starts_ident = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_');
+        if starts_ident && rest[end..].starts_with(['}', ':']) {
+            captures.push(name.to_string());
+        }
+        rest = &rest[end..];
+    }
+    captures
+}
+
+impl<'ast> Visit<'ast> for ReferenceCollector<'_> {
+    fn visit_path(&mut self, path: &'ast Path) {
+        if path.leading_colon.is_none() {
+            let segments: Vec<String> = path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect();
+            self.add_segments(&segments);
+        }
+        syn::visit::visit_path(self, path);
+    }
+
+    fn visit_attribute(&mut self, attr: &'ast Attribute) {
+        if let Meta::List(list) = &attr.meta {
+            self.visit_attribute_strings(list.tokens.clone());
+        }
+        syn::visit::visit_attribute(self, attr);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        self.found
+            .extend(self.table.methods_named(&call.method.to_string()));
+        syn::visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        // `macro_rules!` macros are textually scoped; match them by name
+        if let Some(name) = mac.path.get_ident() {
+            self.found.extend(
+                self.table
+                    .symbols()
+                    .iter()
+                    .filter(|symbol| symbol.kind == SymbolKind::Macro && *name == symbol.name)
+                    .map(|symbol| symbol.id),
+            );
+        } else {
+            self.visit_path(&mac.path);
+        }
+
+        self.visit_format_captures(mac.tokens.clone());
+        match mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            Ok(args) => {
+                for arg in &args {
+                    self.visit_expr(arg);
+                }
+            }
+            Err(_) => self.visit_tokens(mac.tokens.clone()),
+        }
+    }
+}
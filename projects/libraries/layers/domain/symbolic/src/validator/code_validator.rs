@@ -8,6 +8,7 @@ use common::custom_uuid::Id128;
 use regex::Regex;
 
 /// Rust code validator
+#[derive(Clone)]
 pub struct CodeValidator {
     strict_mode: bool,
     expect_re: Regex,
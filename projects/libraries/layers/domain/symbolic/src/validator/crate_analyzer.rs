@@ -0,0 +1,485 @@
+// projects/libraries/layers/domain/symbolic/src/validator/crate_analyzer.rs
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use crate::validator::ValidationError;
+use crate::validator::call_graph::CallGraph;
+use crate::validator::crate_issue::CrateIssue;
+use crate::validator::module_tree::{CrateKind, ModuleId, ModuleTree};
+use crate::validator::semantic_issue::{SemanticIssueType, Severity};
+use crate::validator::symbol::{Resolution, Symbol, SymbolId, SymbolKind, SymbolVisibility};
+use crate::validator::symbol_table::SymbolTable;
+
+/// Result of analyzing a whole crate
+#[derive(Debug, Clone)]
+pub struct CrateAnalysis {
+    pub tree: ModuleTree,
+    pub symbols: SymbolTable,
+    pub call_graph: CallGraph,
+    /// Symbols that are alive regardless of uses: the public API, `main`,
+    /// tests, trait impl methods and pinned items
+    pub roots: BTreeSet<SymbolId>,
+    /// Symbols reachable from `roots`
+    pub reachable: BTreeSet<SymbolId>,
+    /// Groups of modules whose `use` declarations form a cycle
+    pub import_cycles: Vec<Vec<ModuleId>>,
+    pub issues: Vec<CrateIssue>,
+}
+
+impl CrateAnalysis {
+    /// Looks a symbol up by its full path, e.g. `crate::rules::RulesEngine`
+    pub fn symbol(&self, path: &str) -> Option<&Symbol> {
+        self.symbols.find(path).map(|id| self.symbols.symbol(id))
+    }
+
+    /// Symbols whose body or signature names the symbol at `path`
+    pub fn references_to(&self, path: &str) -> Vec<&Symbol> {
+        self.symbols
+            .find(path)
+            .map(|id| {
+                self.call_graph
+                    .callers(id)
+                    .iter()
+                    .map(|caller| self.symbols.symbol(*caller))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// False for symbols nothing alive refers to
+    pub fn is_reachable(&self, path: &str) -> bool {
+        self.symbols
+            .find(path)
+            .is_some_and(|id| self.reachable.contains(&id))
+    }
+
+    pub fn issues_of_type(&self, issue_type: SemanticIssueType) -> Vec<&CrateIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.issue_type == issue_type)
+            .collect()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.severity == Severity::Error)
+    }
+}
+
+/// Analyzer for semantic issues that span the modules of a crate
+pub struct CrateAnalyzer {
+    strict_mode: bool,
+}
+
+impl Default for CrateAnalyzer {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl CrateAnalyzer {
+    pub fn new(strict_mode: bool) -> Self {
+        Self { strict_mode }
+    }
+
+    /// Loads and analyzes the Cargo package in `package_dir`
+    pub fn analyze_package(&self, package_dir: &Path) -> Result<CrateAnalysis, ValidationError> {
+        let tree = ModuleTree::load_package(package_dir)?;
+        Ok(self.analyze(tree))
+    }
+
+    pub fn analyze(&self, tree: ModuleTree) -> CrateAnalysis {
+        let symbols = SymbolTable::build(&tree);
+        let call_graph = CallGraph::build(&tree, &symbols);
+
+        let exported = exported_symbols(&tree, &symbols);
+        let roots = root_symbols(&tree, &symbols, &call_graph, &exported);
+        let reachable = call_graph.reachable_from(roots.iter().copied());
+        let import_cycles = import_cycles(&tree, &symbols);
+
+        let mut analysis = CrateAnalysis {
+            tree,
+            symbols,
+            call_graph,
+            roots,
+            reachable,
+            import_cycles,
+            issues: Vec::new(),
+        };
+
+        let mut issues = Vec::new();
+        issues.extend(self.check_missing_modules(&analysis));
+        issues.extend(self.check_unresolved_imports(&analysis));
+        issues.extend(self.check_dead_code(&analysis));
+        issues.extend(self.check_unused_public_items(&analysis, &exported));
+        issues.extend(self.check_import_cycles(&analysis));
+        issues.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+        analysis.issues = issues;
+
+        tracing::info!(
+            "Analyzed crate {} modules={} symbols={} issues={}",
+            analysis.tree.crate_name,
+            analysis.tree.modules().len(),
+            analysis.symbols.symbols().len(),
+            analysis.issues.len()
+        );
+        analysis
+    }
+
+    fn warning(&self) -> Severity {
+        if self.strict_mode {
+            Severity::Error
+        } else {
+            Severity::Warning
+        }
+    }
+
+    fn check_missing_modules(&self, analysis: &CrateAnalysis) -> Vec<CrateIssue> {
+        analysis
+            .tree
+            .missing_modules()
+            .iter()
+            .map(|missing| {
+                let parent = analysis.tree.module(missing.parent);
+                let candidates: Vec<String> = missing
+                    .candidates
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect();
+                CrateIssue::new(
+                    SemanticIssueType::MissingModule,
+                    Severity::Error,
+                    format!(
+                        "Module '{}' has no file (looked for {})",
+                        missing.name,
+                        candidates.join(", ")
+                    ),
+                    parent.path_string(),
+                    missing.file.clone(),
+                    missing.line,
+                )
+            })
+            .collect()
+    }
+
+    fn check_unresolved_imports(&self, analysis: &CrateAnalysis) -> Vec<CrateIssue> {
+        analysis
+            .symbols
+            .imports()
+            .iter()
+            .filter(|import| import.is_crate_local())
+            .filter(|import| analysis.symbols.resolve_import(import).is_none())
+            .map(|import| {
+                let module = analysis.tree.module(import.module);
+                CrateIssue::new(
+                    SemanticIssueType::UnresolvedImport,
+                    self.warning(),
+                    format!("Unresolved import '{}'", import.path_string()),
+                    module.path_string(),
+                    module.file.clone(),
+                    import.line,
+                )
+            })
+            .collect()
+    }
+
+    fn check_dead_code(&self, analysis: &CrateAnalysis) -> Vec<CrateIssue> {
+        let reachable = &analysis.reachable;
+        analysis
+            .symbols
+            .symbols()
+            .iter()
+            .filter(|symbol| !reachable.contains(&symbol.id) && !symbol.name.starts_with('_'))
+            // A dead type is reported once, not once per method
+            .filter(|symbol| {
+                symbol
+                    .parent
+                    .is_none_or(|parent| reachable.contains(&parent))
+            })
+            .map(|symbol| {
+                CrateIssue::new(
+                    SemanticIssueType::DeadCode,
+                    self.warning(),
+                    format!(
+                        "{} '{}' is never used",
+                        capitalize(symbol.kind.name()),
+                        symbol.path
+                    ),
+                    analysis.tree.module(symbol.module).path_string(),
+                    symbol.file.clone(),
+                    symbol.line,
+                )
+                .with_symbol(&symbol.path)
+            })
+            .collect()
+    }
+
+    /// `pub` and `pub(crate)` items outside the public API that nothing outside
+    /// their module (or its children, which can see private items) uses
+    fn check_unused_public_items(
+        &self,
+        analysis: &CrateAnalysis,
+        exported: &BTreeSet<SymbolId>,
+    ) -> Vec<CrateIssue> {
+        let tree = &analysis.tree;
+        analysis
+            .symbols
+            .symbols()
+            .iter()
+            .filter(|symbol| {
+                matches!(
+                    symbol.visibility,
+                    SymbolVisibility::Public | SymbolVisibility::Crate
+                ) && !exported.contains(&symbol.id)
+                    && analysis.reachable.contains(&symbol.id)
+                    && !analysis.roots.contains(&symbol.id)
+                    && !analysis.call_graph.anchored().contains(&symbol.id)
+            })
+            .filter(|symbol| {
+                analysis.call_graph.callers(symbol.id).iter().all(|caller| {
+                    tree.is_ancestor(symbol.module, analysis.symbols.symbol(*caller).module)
+                })
+            })
+            .map(|symbol| {
+                let module = tree.module(symbol.module);
+                let visibility = match symbol.visibility {
+                    SymbolVisibility::Crate => "pub(crate)",
+                    _ => "pub",
+                };
+                CrateIssue::new(
+                    SemanticIssueType::UnusedPublicItem,
+                    Severity::Info,
+                    format!(
+                        "{} {} '{}' is only used inside '{}'; it can be private",
+                        visibility,
+                        symbol.kind,
+                        symbol.path,
+                        module.path_string()
+                    ),
+                    module.path_string(),
+                    symbol.file.clone(),
+                    symbol.line,
+                )
+                .with_symbol(&symbol.path)
+            })
+            .collect()
+    }
+
+    fn check_import_cycles(&self, analysis: &CrateAnalysis) -> Vec<CrateIssue> {
+        analysis
+            .import_cycles
+            .iter()
+            .map(|cycle| {
+                let first = analysis.tree.module(cycle[0]);
+                let line = analysis
+                    .symbols
+                    .imports()
+                    .iter()
+                    .filter(|import| import.module == first.id)
+                    .map(|import| import.line)
+                    .min()
+                    .unwrap_or(0);
+                let names: Vec<String> = cycle
+                    .iter()
+                    .map(|id| analysis.tree.module(*id).path_string())
+                    .collect();
+                CrateIssue::new(
+                    SemanticIssueType::ImportCycle,
+                    self.warning(),
+                    format!("Import cycle between {}", names.join(", ")),
+                    first.path_string(),
+                    first.file.clone(),
+                    line,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Items reachable from outside a library: `pub` items along a `pub` module
+/// chain, `pub use` re-exports from such modules, and their `pub` methods
+fn exported_symbols(tree: &ModuleTree, symbols: &SymbolTable) -> BTreeSet<SymbolId> {
+    let mut exported = BTreeSet::new();
+    if tree.kind != CrateKind::Library {
+        return exported;
+    }
+
+    for symbol in symbols.symbols() {
+        let public = match symbol.kind {
+            SymbolKind::Macro => symbol.is_pinned,
+            _ => symbol.visibility == SymbolVisibility::Public,
+        };
+        if public && symbol.kind != SymbolKind::Method && tree.is_exported(symbol.module) {
+            exported.insert(symbol.id);
+        }
+    }
+
+    for import in symbols.imports() {
+        if import.visibility != SymbolVisibility::Public || !tree.is_exported(import.module) {
+            continue;
+        }
+        match symbols.resolve_import(import) {
+            Some(Resolution::Symbol(id)) => {
+                exported.insert(id);
+            }
+            Some(Resolution::Module(module)) if import.is_glob() => {
+                exported.extend(
+                    symbols
+                        .symbols()
+                        .iter()
+                        .filter(|symbol| {
+                            symbol.visibility == SymbolVisibility::Public
+                                && symbol.module == module
+                                && symbol.kind != SymbolKind::Method
+                        })
+                        .map(|symbol| symbol.id),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    let methods: Vec<SymbolId> = symbols
+        .symbols()
+        .iter()
+        .filter(|symbol| {
+            symbol.visibility == SymbolVisibility::Public
+                && symbol
+                    .parent
+                    .is_some_and(|parent| exported.contains(&parent))
+        })
+        .map(|symbol| symbol.id)
+        .collect();
+    exported.extend(methods);
+    exported
+}
+
+fn root_symbols(
+    tree: &ModuleTree,
+    symbols: &SymbolTable,
+    call_graph: &CallGraph,
+    exported: &BTreeSet<SymbolId>,
+) -> BTreeSet<SymbolId> {
+    let mut roots: BTreeSet<SymbolId> = exported.clone();
+    roots.extend(call_graph.anchored().iter().copied());
+    roots.extend(
+        symbols
+            .symbols()
+            .iter()
+            .filter(|symbol| {
+                symbol.is_test
+                    || symbol.is_trait_impl
+                    || symbol.is_pinned
+                    || (tree.kind == CrateKind::Binary
+                        && symbol.module == tree.root()
+                        && symbol.kind == SymbolKind::Function
+                        && symbol.name == "main")
+            })
+            .map(|symbol| symbol.id),
+    );
+    roots
+}
+
+/// Strongly connected groups in the graph of `use` edges between modules.
+///
+/// Imports between a module and its ancestors (`use super::..` in a child,
+/// `pub use child::..` in a parent) are the normal way of structuring a crate
+/// and are left out, as are test modules and imports of whole modules.
+fn import_cycles(tree: &ModuleTree, symbols: &SymbolTable) -> Vec<Vec<ModuleId>> {
+    let count = tree.modules().len();
+    let mut edges: Vec<BTreeSet<ModuleId>> = vec![BTreeSet::new(); count];
+
+    for import in symbols.imports() {
+        let from = import.module;
+        if tree.module(from).is_test {
+            continue;
+        }
+        let Some(Resolution::Symbol(id)) = symbols.resolve_import(import) else {
+            continue;
+        };
+        let to = symbols.symbol(id).module;
+        if tree.module(to).is_test || tree.is_ancestor(from, to) || tree.is_ancestor(to, from) {
+            continue;
+        }
+        edges[from].insert(to);
+    }
+
+    let mut tarjan = Tarjan {
+        edges: &edges,
+        index: 0,
+        indices: HashMap::new(),
+        low_links: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: BTreeSet::new(),
+        components: Vec::new(),
+    };
+    for module in 0..count {
+        if !tarjan.indices.contains_key(&module) {
+            tarjan.connect(module);
+        }
+    }
+
+    let mut cycles: Vec<Vec<ModuleId>> = tarjan
+        .components
+        .into_iter()
+        .filter(|component| component.len() > 1)
+        .map(|mut component| {
+            component.sort_unstable();
+            component
+        })
+        .collect();
+    cycles.sort();
+    cycles
+}
+
+struct Tarjan<'a> {
+    edges: &'a [BTreeSet<ModuleId>],
+    index: usize,
+    indices: HashMap<ModuleId, usize>,
+    low_links: HashMap<ModuleId, usize>,
+    stack: Vec<ModuleId>,
+    on_stack: BTreeSet<ModuleId>,
+    components: Vec<Vec<ModuleId>>,
+}
+
+impl Tarjan<'_> {
+    fn connect(&mut self, module: ModuleId) {
+        self.indices.insert(module, self.index);
+        self.low_links.insert(module, self.index);
+        self.index += 1;
+        self.stack.push(module);
+        self.on_stack.insert(module);
+
+        for &next in self.edges[module].iter() {
+            if !self.indices.contains_key(&next) {
+                self.connect(next);
+                let low = self.low_links[&module].min(self.low_links[&next]);
+                self.low_links.insert(module, low);
+            } else if self.on_stack.contains(&next) {
+                let low = self.low_links[&module].min(self.indices[&next]);
+                self.low_links.insert(module, low);
+            }
+        }
+
+        if self.low_links[&module] == self.indices[&module] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack.remove(&member);
+                component.push(member);
+                if member == module {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,64 @@
+// projects/libraries/layers/domain/symbolic/src/validator/crate_issue.rs
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::validator::semantic_issue::{SemanticIssueType, Severity};
+
+/// A semantic issue found by crate-level analysis, located by file and module
+#[derive(Debug, Clone)]
+pub struct CrateIssue {
+    pub issue_type: SemanticIssueType,
+    pub severity: Severity,
+    pub message: String,
+    /// Module path, e.g. `crate::rules`
+    pub module: String,
+    pub file: PathBuf,
+    pub line: usize,
+    /// Full path of the symbol the issue is about, if any
+    pub symbol: Option<String>,
+}
+
+impl CrateIssue {
+    pub fn new(
+        issue_type: SemanticIssueType,
+        severity: Severity,
+        message: String,
+        module: String,
+        file: PathBuf,
+        line: usize,
+    ) -> Self {
+        Self {
+            issue_type,
+            severity,
+            message,
+            module,
+            file,
+            line,
+            symbol: None,
+        }
+    }
+
+    pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+}
+
+impl fmt::Display for CrateIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity_str = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+
+        write!(
+            f,
+            "[{}] {}:{}: {}",
+            severity_str,
+            self.file.display(),
+            self.line,
+            self.message
+        )
+    }
+}
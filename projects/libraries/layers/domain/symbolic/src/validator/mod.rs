@@ -1,19 +1,31 @@
 // projects/libraries/layers/domain/symbolic/src/validator/mod.rs
 // Module for code validation
+pub mod call_graph;
 pub mod code_validator;
+pub mod crate_analyzer;
+pub mod crate_issue;
 mod dead_code_visitor;
 mod import_visitor;
+pub mod module_tree;
 pub mod semantic_analyzer;
 pub mod semantic_issue;
+pub mod symbol;
+pub mod symbol_table;
 mod type_visitor;
 pub mod validation_error;
 pub mod validation_result;
-mod variable_collector;
+pub(crate) mod variable_collector;
 mod variable_visitor;
 
+pub use call_graph::CallGraph;
 pub use code_validator::CodeValidator;
+pub use crate_analyzer::{CrateAnalysis, CrateAnalyzer};
+pub use crate_issue::CrateIssue;
+pub use module_tree::{CrateKind, MissingModule, Module, ModuleId, ModuleTree};
 pub use semantic_analyzer::SemanticAnalyzer;
 pub use semantic_issue::{SemanticIssue, SemanticIssueType, Severity};
+pub use symbol::{Import, Resolution, Symbol, SymbolId, SymbolKind, SymbolVisibility};
+pub use symbol_table::SymbolTable;
 pub use validation_error::ValidationError;
 pub use validation_result::ValidationResult;
 
@@ -0,0 +1,378 @@
+// projects/libraries/layers/domain/symbolic/src/validator/module_tree.rs
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::{Attribute, Expr, Item, ItemMod, Lit, Meta, Visibility};
+
+use crate::validator::ValidationError;
+
+/// Index of a module inside a `ModuleTree`
+pub type ModuleId = usize;
+
+/// Whether the crate root is a library or a binary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrateKind {
+    Library,
+    Binary,
+}
+
+/// A module of the crate, either file-backed or inline
+#[derive(Clone)]
+pub struct Module {
+    pub id: ModuleId,
+    /// Path segments starting with `crate`
+    pub path: Vec<String>,
+    /// File holding the module's items
+    pub file: PathBuf,
+    /// Line of the `mod` declaration in the parent (0 for the crate root)
+    pub line: usize,
+    pub parent: Option<ModuleId>,
+    pub children: Vec<ModuleId>,
+    /// Declared `pub` (the root counts as public)
+    pub is_public: bool,
+    /// Gated behind `#[cfg(test)]`, directly or through an ancestor
+    pub is_test: bool,
+    pub items: Vec<Item>,
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // syn items only implement Debug with its `extra-traits` feature
+        f.debug_struct("Module")
+            .field("id", &self.id)
+            .field("path", &self.path)
+            .field("file", &self.file)
+            .field("line", &self.line)
+            .field("parent", &self.parent)
+            .field("children", &self.children)
+            .field("is_public", &self.is_public)
+            .field("is_test", &self.is_test)
+            .field("items", &self.items.len())
+            .finish()
+    }
+}
+
+impl Module {
+    pub fn name(&self) -> &str {
+        self.path.last().map(String::as_str).unwrap_or("crate")
+    }
+
+    pub fn path_string(&self) -> String {
+        self.path.join("::")
+    }
+}
+
+/// A `mod name;` declaration whose file could not be found
+#[derive(Debug, Clone)]
+pub struct MissingModule {
+    pub parent: ModuleId,
+    pub name: String,
+    pub file: PathBuf,
+    pub line: usize,
+    /// Candidate files that were tried
+    pub candidates: Vec<PathBuf>,
+}
+
+/// The module tree of a crate, following `mod` declarations from the crate root
+#[derive(Debug, Clone)]
+pub struct ModuleTree {
+    pub crate_name: String,
+    pub kind: CrateKind,
+    modules: Vec<Module>,
+    missing: Vec<MissingModule>,
+}
+
+impl ModuleTree {
+    /// Loads the library target of a Cargo package (or its binary if it has no library)
+    pub fn load_package(package_dir: &Path) -> Result<Self, ValidationError> {
+        let manifest_path = package_dir.join("Cargo.toml");
+        let manifest = read_file(&manifest_path)?;
+        let manifest: toml::Table =
+            toml::from_str(&manifest).map_err(|e| ValidationError::ParseError {
+                line: 0,
+                message: format!("{}: {}", manifest_path.display(), e),
+            })?;
+
+        let package_name = manifest
+            .get("package")
+            .and_then(|package| package.get("name"))
+            .and_then(|name| name.as_str())
+            .ok_or_else(|| {
+                ValidationError::MissingElement(format!(
+                    "[package] name in {}",
+                    manifest_path.display()
+                ))
+            })?;
+        let lib = manifest.get("lib");
+        let crate_name = lib
+            .and_then(|lib| lib.get("name"))
+            .and_then(|name| name.as_str())
+            .unwrap_or(package_name)
+            .replace('-', "_");
+
+        let lib_path = lib
+            .and_then(|lib| lib.get("path"))
+            .and_then(|path| path.as_str())
+            .map(|path| package_dir.join(path))
+            .unwrap_or_else(|| package_dir.join("src/lib.rs"));
+        let (root_file, kind) = if lib_path.is_file() {
+            (lib_path, CrateKind::Library)
+        } else {
+            (package_dir.join("src/main.rs"), CrateKind::Binary)
+        };
+
+        Self::build(crate_name, root_file, kind, &mut |path| {
+            fs::read_to_string(path).ok()
+        })
+    }
+
+    /// Loads a crate from its root file on disk
+    pub fn load(crate_name: &str, root_file: &Path) -> Result<Self, ValidationError> {
+        let kind = kind_of(root_file);
+        Self::build(
+            crate_name.to_string(),
+            root_file.to_path_buf(),
+            kind,
+            &mut |path| fs::read_to_string(path).ok(),
+        )
+    }
+
+    /// Builds a tree from in-memory sources keyed by path
+    pub fn from_sources(
+        crate_name: &str,
+        root_file: impl Into<PathBuf>,
+        sources: &HashMap<PathBuf, String>,
+    ) -> Result<Self, ValidationError> {
+        let root_file = root_file.into();
+        let kind = kind_of(&root_file);
+        Self::build(crate_name.to_string(), root_file, kind, &mut |path| {
+            sources.get(path).cloned()
+        })
+    }
+
+    fn build(
+        crate_name: String,
+        root_file: PathBuf,
+        kind: CrateKind,
+        read: &mut dyn FnMut(&Path) -> Option<String>,
+    ) -> Result<Self, ValidationError> {
+        let source = read(&root_file).ok_or_else(|| {
+            ValidationError::MissingElement(format!("crate root {}", root_file.display()))
+        })?;
+        let file = parse(&root_file, &source)?;
+        let dir = root_file
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let mut tree = Self {
+            crate_name,
+            kind,
+            modules: Vec::new(),
+            missing: Vec::new(),
+        };
+        let root = Module {
+            id: 0,
+            path: vec!["crate".to_string()],
+            file: root_file,
+            line: 0,
+            parent: None,
+            children: Vec::new(),
+            is_public: true,
+            is_test: false,
+            items: file.items,
+        };
+        tree.add_module(root, dir, read)?;
+        tracing::debug!(
+            "Loaded module tree crate={} modules={}",
+            tree.crate_name,
+            tree.modules.len()
+        );
+        Ok(tree)
+    }
+
+    /// Registers a module, then loads the modules it declares. `dir` is where
+    /// its `mod child;` files live.
+    fn add_module(
+        &mut self,
+        mut module: Module,
+        dir: PathBuf,
+        read: &mut dyn FnMut(&Path) -> Option<String>,
+    ) -> Result<ModuleId, ValidationError> {
+        let id = self.modules.len();
+        module.id = id;
+        let declarations: Vec<ItemMod> = module
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Mod(item_mod) => Some(item_mod.clone()),
+                _ => None,
+            })
+            .collect();
+        let parent_path = module.path.clone();
+        let parent_file = module.file.clone();
+        let parent_is_test = module.is_test;
+        self.modules.push(module);
+
+        for item_mod in declarations {
+            let name = item_mod.ident.to_string();
+            let line = item_mod.mod_token.span.start().line;
+            let is_test = parent_is_test || is_cfg_test(&item_mod.attrs);
+            let mut path = parent_path.clone();
+            path.push(name.clone());
+
+            let (file, items, child_dir) = match item_mod.content {
+                Some((_, items)) => (parent_file.clone(), items, dir.join(&name)),
+                None => {
+                    let candidates = match path_attribute(&item_mod.attrs) {
+                        Some(custom) => vec![dir.join(custom)],
+                        None => vec![
+                            dir.join(format!("{}.rs", name)),
+                            dir.join(&name).join("mod.rs"),
+                        ],
+                    };
+                    let found = candidates.iter().find_map(|candidate| {
+                        read(candidate).map(|text| (candidate.clone(), text))
+                    });
+                    let Some((file, text)) = found else {
+                        self.missing.push(MissingModule {
+                            parent: id,
+                            name,
+                            file: parent_file.clone(),
+                            line,
+                            candidates,
+                        });
+                        continue;
+                    };
+                    let parsed = parse(&file, &text)?;
+                    let child_dir = if file.file_name().is_some_and(|f| f == "mod.rs") {
+                        file.parent().map(Path::to_path_buf).unwrap_or_default()
+                    } else {
+                        dir.join(&name)
+                    };
+                    (file, parsed.items, child_dir)
+                }
+            };
+
+            let child = Module {
+                id: 0,
+                path,
+                file,
+                line,
+                parent: Some(id),
+                children: Vec::new(),
+                is_public: matches!(item_mod.vis, Visibility::Public(_)),
+                is_test,
+                items,
+            };
+            let child_id = self.add_module(child, child_dir, read)?;
+            self.modules[id].children.push(child_id);
+        }
+
+        Ok(id)
+    }
+
+    pub fn root(&self) -> ModuleId {
+        0
+    }
+
+    pub fn modules(&self) -> &[Module] {
+        &self.modules
+    }
+
+    pub fn module(&self, id: ModuleId) -> &Module {
+        &self.modules[id]
+    }
+
+    /// `mod` declarations without a backing file
+    pub fn missing_modules(&self) -> &[MissingModule] {
+        &self.missing
+    }
+
+    /// Looks a module up by path, e.g. `crate::rules::tests`
+    pub fn find(&self, path: &str) -> Option<ModuleId> {
+        self.modules
+            .iter()
+            .find(|module| module.path_string() == path)
+            .map(|module| module.id)
+    }
+
+    pub fn child(&self, id: ModuleId, name: &str) -> Option<ModuleId> {
+        self.modules[id]
+            .children
+            .iter()
+            .copied()
+            .find(|child| self.modules[*child].name() == name)
+    }
+
+    /// True when `ancestor` is `id` or one of its parents
+    pub fn is_ancestor(&self, ancestor: ModuleId, id: ModuleId) -> bool {
+        let mut current = Some(id);
+        while let Some(module) = current {
+            if module == ancestor {
+                return true;
+            }
+            current = self.modules[module].parent;
+        }
+        false
+    }
+
+    /// True when every module from the root down to `id` is declared `pub`
+    pub fn is_exported(&self, id: ModuleId) -> bool {
+        let mut current = Some(id);
+        while let Some(module) = current {
+            if !self.modules[module].is_public {
+                return false;
+            }
+            current = self.modules[module].parent;
+        }
+        true
+    }
+}
+
+fn kind_of(root_file: &Path) -> CrateKind {
+    if root_file.file_name().is_some_and(|name| name == "main.rs") {
+        CrateKind::Binary
+    } else {
+        CrateKind::Library
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, ValidationError> {
+    fs::read_to_string(path)
+        .map_err(|e| ValidationError::MissingElement(format!("{}: {}", path.display(), e)))
+}
+
+fn parse(path: &Path, source: &str) -> Result<syn::File, ValidationError> {
+    syn::parse_file(source).map_err(|e| ValidationError::ParseError {
+        line: e.span().start().line,
+        message: format!("{}: {}", path.display(), e),
+    })
+}
+
+/// `#[path = "..."]` on a module declaration
+fn path_attribute(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::NameValue(name_value) if name_value.path.is_ident("path") => {
+            match &name_value.value {
+                Expr::Lit(expr_lit) => match &expr_lit.lit {
+                    Lit::Str(value) => Some(value.value()),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+/// True for items gated behind `#[cfg(test)]`
+pub(crate) fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|ident| ident == "test")
+    })
+}
@@ -36,6 +36,14 @@ pub enum SemanticIssueType {
     DeadCode,
     /// Type inconsistency or mismatch
     TypeInconsistency,
+    /// A `pub` item that is never used outside its own module
+    UnusedPublicItem,
+    /// Modules that import from each other in a cycle
+    ImportCycle,
+    /// A crate-local `use` path that does not resolve
+    UnresolvedImport,
+    /// A `mod` declaration without a backing file
+    MissingModule,
 }
 
 impl SemanticIssue {
@@ -0,0 +1,154 @@
+// projects/libraries/layers/domain/symbolic/src/validator/symbol.rs
+use std::fmt;
+use std::path::PathBuf;
+use syn::Visibility;
+
+use crate::validator::module_tree::ModuleId;
+
+/// Index of a symbol inside a `SymbolTable`
+pub type SymbolId = usize;
+
+/// Kind of item a symbol was collected from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    Function,
+    /// Function defined in an `impl` block
+    Method,
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    Const,
+    Static,
+    TypeAlias,
+    /// `macro_rules!` definition
+    Macro,
+}
+
+impl SymbolKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SymbolKind::Function => "function",
+            SymbolKind::Method => "method",
+            SymbolKind::Struct => "struct",
+            SymbolKind::Enum => "enum",
+            SymbolKind::Union => "union",
+            SymbolKind::Trait => "trait",
+            SymbolKind::Const => "const",
+            SymbolKind::Static => "static",
+            SymbolKind::TypeAlias => "type alias",
+            SymbolKind::Macro => "macro",
+        }
+    }
+
+    /// Kinds that can own methods or variants
+    pub fn is_type(&self) -> bool {
+        matches!(
+            self,
+            SymbolKind::Struct
+                | SymbolKind::Enum
+                | SymbolKind::Union
+                | SymbolKind::Trait
+                | SymbolKind::TypeAlias
+        )
+    }
+}
+
+impl fmt::Display for SymbolKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Declared visibility of an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolVisibility {
+    /// `pub`
+    Public,
+    /// `pub(crate)`
+    Crate,
+    /// `pub(super)`, `pub(self)` or `pub(in path)`
+    Restricted,
+    Private,
+}
+
+impl SymbolVisibility {
+    pub fn from_syn(vis: &Visibility) -> Self {
+        match vis {
+            Visibility::Public(_) => SymbolVisibility::Public,
+            Visibility::Restricted(restricted) if restricted.path.is_ident("crate") => {
+                SymbolVisibility::Crate
+            }
+            Visibility::Restricted(_) => SymbolVisibility::Restricted,
+            Visibility::Inherited => SymbolVisibility::Private,
+        }
+    }
+}
+
+/// A named item of the crate
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub id: SymbolId,
+    pub name: String,
+    /// Fully qualified path, e.g. `crate::rules::RulesEngine::apply`
+    pub path: String,
+    pub kind: SymbolKind,
+    pub visibility: SymbolVisibility,
+    /// Module the item is written in (for methods, the module of the `impl`)
+    pub module: ModuleId,
+    /// Owning type of a method, when the `impl` target could be resolved
+    pub parent: Option<SymbolId>,
+    pub file: PathBuf,
+    pub line: usize,
+    /// `#[test]` function or item inside a `#[cfg(test)]` module
+    pub is_test: bool,
+    /// Method of a trait implementation, reachable through the trait
+    pub is_trait_impl: bool,
+    /// `#[no_mangle]`, `#[export_name]`, `#[macro_export]` or `#[allow(dead_code)]`
+    pub is_pinned: bool,
+}
+
+/// What a path resolved to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resolution {
+    Module(ModuleId),
+    Symbol(SymbolId),
+    /// A name from outside the crate: a dependency, `std` or the prelude
+    External,
+}
+
+/// A single binding introduced by a `use` declaration
+#[derive(Debug, Clone)]
+pub struct Import {
+    /// Module the `use` is written in
+    pub module: ModuleId,
+    /// Name bound in the module; `None` for glob imports
+    pub binding: Option<String>,
+    /// Imported path, e.g. `["crate", "rules", "RulesEngine"]`
+    pub segments: Vec<String>,
+    pub visibility: SymbolVisibility,
+    pub line: usize,
+}
+
+impl Import {
+    pub fn is_glob(&self) -> bool {
+        self.binding.is_none()
+    }
+
+    /// Imports rooted in this crate (`crate::`, `self::`, `super::`)
+    pub fn is_crate_local(&self) -> bool {
+        matches!(
+            self.segments.first().map(String::as_str),
+            Some("crate" | "self" | "super")
+        )
+    }
+
+    pub fn path_string(&self) -> String {
+        let path = self.segments.join("::");
+        if self.is_glob() {
+            format!("{}::*", path)
+        } else {
+            path
+        }
+    }
+}
@@ -0,0 +1,501 @@
+// projects/libraries/layers/domain/symbolic/src/validator/symbol_table.rs
+use std::collections::HashMap;
+use syn::{Attribute, ImplItem, Item, ItemImpl, Type, UseTree};
+
+use crate::validator::module_tree::{Module, ModuleId, ModuleTree, is_cfg_test};
+use crate::validator::symbol::{
+    Import, Resolution, Symbol, SymbolId, SymbolKind, SymbolVisibility,
+};
+
+/// Bound on import chains followed while resolving a single path
+const MAX_RESOLUTION_DEPTH: usize = 16;
+
+/// Module links needed for path resolution, detached from the syntax trees
+#[derive(Debug, Clone)]
+struct ModuleLinks {
+    parent: Option<ModuleId>,
+    children: HashMap<String, ModuleId>,
+}
+
+/// Items, methods and imports of every module in a crate
+#[derive(Debug, Clone)]
+pub struct SymbolTable {
+    crate_name: String,
+    modules: Vec<ModuleLinks>,
+    symbols: Vec<Symbol>,
+    imports: Vec<Import>,
+    /// Module-level items by (module, name)
+    items: HashMap<(ModuleId, String), Vec<SymbolId>>,
+    /// Methods by (owning type, name)
+    methods: HashMap<(SymbolId, String), SymbolId>,
+    /// Methods by name alone, for `receiver.method()` calls
+    methods_by_name: HashMap<String, Vec<SymbolId>>,
+    variants: HashMap<SymbolId, Vec<String>>,
+    /// Symbols by (module, name, line), to map syntax back to symbols
+    locations: HashMap<(ModuleId, String, usize), SymbolId>,
+}
+
+impl SymbolTable {
+    /// Collects every module-level item, `impl` method and `use` binding of the tree
+    pub fn build(tree: &ModuleTree) -> Self {
+        let mut table = Self {
+            crate_name: tree.crate_name.clone(),
+            modules: tree
+                .modules()
+                .iter()
+                .map(|module| ModuleLinks {
+                    parent: module.parent,
+                    children: module
+                        .children
+                        .iter()
+                        .map(|child| (tree.module(*child).name().to_string(), *child))
+                        .collect(),
+                })
+                .collect(),
+            symbols: Vec::new(),
+            imports: Vec::new(),
+            items: HashMap::new(),
+            methods: HashMap::new(),
+            methods_by_name: HashMap::new(),
+            variants: HashMap::new(),
+            locations: HashMap::new(),
+        };
+
+        for module in tree.modules() {
+            for item in &module.items {
+                table.collect_item(module, item);
+            }
+        }
+        // Methods come second so `impl` targets can resolve through imports
+        for module in tree.modules() {
+            for item in &module.items {
+                if let Item::Impl(item_impl) = item {
+                    table.collect_impl(module, item_impl);
+                }
+            }
+        }
+
+        tracing::debug!(
+            "Built symbol table symbols={} imports={}",
+            table.symbols.len(),
+            table.imports.len()
+        );
+        table
+    }
+
+    fn collect_item(&mut self, module: &Module, item: &Item) {
+        let (ident, kind, vis, attrs) = match item {
+            Item::Fn(item) => (
+                &item.sig.ident,
+                SymbolKind::Function,
+                &item.vis,
+                &item.attrs,
+            ),
+            Item::Struct(item) => (&item.ident, SymbolKind::Struct, &item.vis, &item.attrs),
+            Item::Enum(item) => (&item.ident, SymbolKind::Enum, &item.vis, &item.attrs),
+            Item::Union(item) => (&item.ident, SymbolKind::Union, &item.vis, &item.attrs),
+            Item::Trait(item) => (&item.ident, SymbolKind::Trait, &item.vis, &item.attrs),
+            Item::Const(item) => (&item.ident, SymbolKind::Const, &item.vis, &item.attrs),
+            Item::Static(item) => (&item.ident, SymbolKind::Static, &item.vis, &item.attrs),
+            Item::Type(item) => (&item.ident, SymbolKind::TypeAlias, &item.vis, &item.attrs),
+            Item::Macro(item) => {
+                let Some(ident) = &item.ident else {
+                    return;
+                };
+                let visibility = if has_attr(&item.attrs, "macro_export") {
+                    SymbolVisibility::Public
+                } else {
+                    SymbolVisibility::Private
+                };
+                self.add_symbol(
+                    module,
+                    ident,
+                    SymbolKind::Macro,
+                    visibility,
+                    &item.attrs,
+                    None,
+                );
+                return;
+            }
+            Item::Use(item_use) => {
+                let visibility = SymbolVisibility::from_syn(&item_use.vis);
+                let line = item_use.use_token.span.start().line;
+                self.collect_use(module.id, &item_use.tree, Vec::new(), visibility, line);
+                return;
+            }
+            _ => return,
+        };
+        let id = self.add_symbol(
+            module,
+            ident,
+            kind,
+            SymbolVisibility::from_syn(vis),
+            attrs,
+            None,
+        );
+        if let Item::Enum(item_enum) = item {
+            self.variants.insert(
+                id,
+                item_enum
+                    .variants
+                    .iter()
+                    .map(|variant| variant.ident.to_string())
+                    .collect(),
+            );
+        }
+    }
+
+    fn collect_use(
+        &mut self,
+        module: ModuleId,
+        tree: &UseTree,
+        mut prefix: Vec<String>,
+        visibility: SymbolVisibility,
+        line: usize,
+    ) {
+        match tree {
+            UseTree::Path(path) => {
+                prefix.push(path.ident.to_string());
+                self.collect_use(module, &path.tree, prefix, visibility, line);
+            }
+            UseTree::Name(name) => {
+                let binding = if name.ident == "self" {
+                    prefix.last().cloned()
+                } else {
+                    prefix.push(name.ident.to_string());
+                    Some(name.ident.to_string())
+                };
+                if let Some(binding) = binding {
+                    self.imports.push(Import {
+                        module,
+                        binding: Some(binding),
+                        segments: prefix,
+                        visibility,
+                        line,
+                    });
+                }
+            }
+            UseTree::Rename(rename) => {
+                if rename.ident != "self" {
+                    prefix.push(rename.ident.to_string());
+                }
+                self.imports.push(Import {
+                    module,
+                    binding: Some(rename.rename.to_string()),
+                    segments: prefix,
+                    visibility,
+                    line,
+                });
+            }
+            UseTree::Glob(_) => self.imports.push(Import {
+                module,
+                binding: None,
+                segments: prefix,
+                visibility,
+                line,
+            }),
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.collect_use(module, tree, prefix.clone(), visibility, line);
+                }
+            }
+        }
+    }
+
+    fn collect_impl(&mut self, module: &Module, item_impl: &ItemImpl) {
+        let owner = self.impl_owner(module.id, item_impl);
+        let is_trait_impl = item_impl.trait_.is_some();
+
+        for impl_item in &item_impl.items {
+            let ImplItem::Fn(method) = impl_item else {
+                continue;
+            };
+            let visibility = if is_trait_impl {
+                SymbolVisibility::Public
+            } else {
+                SymbolVisibility::from_syn(&method.vis)
+            };
+            let id = self.add_symbol(
+                module,
+                &method.sig.ident,
+                SymbolKind::Method,
+                visibility,
+                &method.attrs,
+                owner,
+            );
+            self.symbols[id].is_trait_impl = is_trait_impl;
+            if owner.is_none() {
+                // Keep a readable path even when the target type is external
+                self.symbols[id].path = format!(
+                    "{}::{}::{}",
+                    module.path_string(),
+                    type_name(&item_impl.self_ty).unwrap_or_else(|| "_".to_string()),
+                    method.sig.ident
+                );
+            }
+        }
+    }
+
+    /// The crate type an `impl` block targets, if it is defined in this crate
+    pub(crate) fn impl_owner(&self, module: ModuleId, item_impl: &ItemImpl) -> Option<SymbolId> {
+        let segments = type_path(&item_impl.self_ty)?;
+        match self.resolve_path(module, &segments)? {
+            Resolution::Symbol(id) if self.symbols[id].kind.is_type() => Some(id),
+            _ => None,
+        }
+    }
+
+    fn add_symbol(
+        &mut self,
+        module: &Module,
+        ident: &syn::Ident,
+        kind: SymbolKind,
+        visibility: SymbolVisibility,
+        attrs: &[Attribute],
+        parent: Option<SymbolId>,
+    ) -> SymbolId {
+        let id = self.symbols.len();
+        let name = ident.to_string();
+        let line = ident.span().start().line;
+        let path = match parent {
+            Some(parent) => format!("{}::{}", self.symbols[parent].path, name),
+            None => format!("{}::{}", module.path_string(), name),
+        };
+
+        self.symbols.push(Symbol {
+            id,
+            name: name.clone(),
+            path,
+            kind,
+            visibility,
+            module: module.id,
+            parent,
+            file: module.file.clone(),
+            line,
+            is_test: module.is_test || is_test_attr(attrs) || is_cfg_test(attrs),
+            is_trait_impl: false,
+            is_pinned: is_pinned(attrs),
+        });
+
+        match parent {
+            Some(parent) => {
+                self.methods.insert((parent, name.clone()), id);
+            }
+            None if kind != SymbolKind::Method => {
+                self.items
+                    .entry((module.id, name.clone()))
+                    .or_default()
+                    .push(id);
+            }
+            None => {}
+        }
+        if kind == SymbolKind::Method {
+            self.methods_by_name
+                .entry(name.clone())
+                .or_default()
+                .push(id);
+        }
+        self.locations.insert((module.id, name, line), id);
+        id
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        &self.symbols[id]
+    }
+
+    /// Looks a symbol up by its full path, e.g. `crate::rules::RulesEngine::new`
+    pub fn find(&self, path: &str) -> Option<SymbolId> {
+        self.symbols
+            .iter()
+            .find(|symbol| symbol.path == path)
+            .map(|symbol| symbol.id)
+    }
+
+    pub fn imports(&self) -> &[Import] {
+        &self.imports
+    }
+
+    /// Every method with the given name, whatever its owning type
+    pub fn methods_named(&self, name: &str) -> &[SymbolId] {
+        self.methods_by_name
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn method(&self, owner: SymbolId, name: &str) -> Option<SymbolId> {
+        self.methods.get(&(owner, name.to_string())).copied()
+    }
+
+    /// Symbol declared by the identifier `name` on `line` of `module`
+    pub(crate) fn symbol_at(&self, module: ModuleId, name: &str, line: usize) -> Option<SymbolId> {
+        self.locations
+            .get(&(module, name.to_string(), line))
+            .copied()
+    }
+
+    /// Resolves a path as written inside `module`
+    pub fn resolve_path(&self, module: ModuleId, segments: &[String]) -> Option<Resolution> {
+        self.resolve_in(module, segments, 0)
+    }
+
+    /// Resolves the target of an import from the module that declares it
+    pub fn resolve_import(&self, import: &Import) -> Option<Resolution> {
+        self.resolve_path(import.module, &import.segments)
+    }
+
+    fn resolve_in(
+        &self,
+        module: ModuleId,
+        segments: &[String],
+        depth: usize,
+    ) -> Option<Resolution> {
+        if depth > MAX_RESOLUTION_DEPTH {
+            return None;
+        }
+        let (first, rest) = segments.split_first()?;
+        let mut current = match first.as_str() {
+            "crate" => Resolution::Module(0),
+            "self" => Resolution::Module(module),
+            "super" => Resolution::Module(self.modules[module].parent?),
+            name if name == self.crate_name => Resolution::Module(0),
+            // Names that are not bound in the crate come from dependencies or the prelude
+            name => self
+                .lookup(module, name, depth)
+                .unwrap_or(Resolution::External),
+        };
+
+        for segment in rest {
+            current = match current {
+                Resolution::Module(id) => match segment.as_str() {
+                    "super" => Resolution::Module(self.modules[id].parent?),
+                    "self" => Resolution::Module(id),
+                    name => self.lookup(id, name, depth)?,
+                },
+                Resolution::Symbol(id) => {
+                    if let Some(method) = self.method(id, segment) {
+                        Resolution::Symbol(method)
+                    } else if self
+                        .variants
+                        .get(&id)
+                        .is_some_and(|variants| variants.contains(segment))
+                    {
+                        // Variants resolve to their enum
+                        Resolution::Symbol(id)
+                    } else {
+                        return None;
+                    }
+                }
+                Resolution::External => Resolution::External,
+            };
+        }
+        Some(current)
+    }
+
+    /// Resolves a single name in a module's namespace: items, child modules,
+    /// named imports, then glob imports
+    fn lookup(&self, module: ModuleId, name: &str, depth: usize) -> Option<Resolution> {
+        if let Some(ids) = self.items.get(&(module, name.to_string())) {
+            let id = ids
+                .iter()
+                .copied()
+                .find(|id| self.symbols[*id].kind != SymbolKind::Macro)
+                .unwrap_or(ids[0]);
+            return Some(Resolution::Symbol(id));
+        }
+        if let Some(child) = self.modules[module].children.get(name) {
+            return Some(Resolution::Module(*child));
+        }
+
+        let imports = self.imports.iter().filter(|import| import.module == module);
+        for import in imports.clone() {
+            if import.binding.as_deref() == Some(name)
+                && let Some(resolution) = self.resolve_in(module, &import.segments, depth + 1)
+            {
+                return Some(resolution);
+            }
+        }
+        for import in imports.filter(|import| import.is_glob()) {
+            match self.resolve_in(module, &import.segments, depth + 1) {
+                Some(Resolution::Module(target)) if target != module => {
+                    if let Some(resolution) = self.lookup(target, name, depth + 1) {
+                        return Some(resolution);
+                    }
+                }
+                Some(Resolution::Symbol(id))
+                    if self
+                        .variants
+                        .get(&id)
+                        .is_some_and(|variants| variants.iter().any(|v| v == name)) =>
+                {
+                    return Some(Resolution::Symbol(id));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Path segments of a type, without generic arguments
+fn type_path(ty: &Type) -> Option<Vec<String>> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => Some(
+            type_path
+                .path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect(),
+        ),
+        Type::Reference(reference) => type_path(&reference.elem),
+        Type::Paren(paren) => type_path(&paren.elem),
+        Type::Group(group) => type_path(&group.elem),
+        _ => None,
+    }
+}
+
+fn type_name(ty: &Type) -> Option<String> {
+    type_path(ty).and_then(|segments| segments.last().cloned())
+}
+
+fn has_attr(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident(name))
+}
+
+/// `#[test]`, `#[tokio::test]` and similar test attributes
+fn is_test_attr(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "test" || segment.ident == "bench")
+    })
+}
+
+/// Items the compiler or the author keeps alive regardless of uses
+fn is_pinned(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let path = attr.path();
+        if path.is_ident("no_mangle") || path.is_ident("export_name") {
+            return true;
+        }
+        // `#[unsafe(no_mangle)]` (edition 2024) and `#[allow(dead_code)]`
+        (path.is_ident("unsafe") || path.is_ident("allow"))
+            && attr
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                )
+                .is_ok_and(|paths| {
+                    paths.iter().any(|path| {
+                        path.is_ident("no_mangle")
+                            || path.is_ident("export_name")
+                            || path.is_ident("dead_code")
+                    })
+                })
+    }) || has_attr(attrs, "macro_export")
+}
@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::test_helpers::TestResult;
+use crate::validator::{
+    CrateAnalysis, CrateAnalyzer, CrateKind, ModuleTree, Resolution, SemanticIssueType, Severity,
+    SymbolKind, SymbolTable, ValidationError,
+};
+
+fn sources(files: &[(&str, &str)]) -> HashMap<PathBuf, String> {
+    files
+        .iter()
+        .map(|(path, text)| (PathBuf::from(path), text.to_string()))
+        .collect()
+}
+
+fn analyze(files: &[(&str, &str)]) -> Result<CrateAnalysis, ValidationError> {
+    let tree = ModuleTree::from_sources("demo", files[0].0, &sources(files))?;
+    Ok(CrateAnalyzer::new(false).analyze(tree))
+}
+
+fn issue_symbols(analysis: &CrateAnalysis, issue_type: SemanticIssueType) -> Vec<String> {
+    analysis
+        .issues_of_type(issue_type)
+        .iter()
+        .filter_map(|issue| issue.symbol.clone())
+        .collect()
+}
+
+#[test]
+fn test_module_tree_follows_mod_declarations() -> TestResult {
+    let files = sources(&[
+        (
+            "src/lib.rs",
+            "pub mod engine;\nmod util;\nmod inline { mod nested; }",
+        ),
+        ("src/engine/mod.rs", "pub mod parser;"),
+        ("src/engine/parser.rs", "pub fn parse() {}"),
+        ("src/util.rs", "mod helpers;"),
+        ("src/util/helpers.rs", "fn help() {}"),
+        ("src/inline/nested.rs", "fn nested() {}"),
+    ]);
+    let tree = ModuleTree::from_sources("demo", "src/lib.rs", &files)?;
+
+    assert_eq!(tree.kind, CrateKind::Library);
+    assert_eq!(tree.modules().len(), 7);
+    assert!(tree.missing_modules().is_empty());
+
+    let parser = tree.find("crate::engine::parser").ok_or("parser module")?;
+    assert_eq!(
+        tree.module(parser).file,
+        PathBuf::from("src/engine/parser.rs")
+    );
+    assert!(tree.is_exported(parser));
+
+    let helpers = tree.find("crate::util::helpers").ok_or("helpers module")?;
+    assert_eq!(
+        tree.module(helpers).file,
+        PathBuf::from("src/util/helpers.rs")
+    );
+    assert!(!tree.is_exported(helpers));
+
+    let nested = tree.find("crate::inline::nested").ok_or("nested module")?;
+    assert_eq!(
+        tree.module(nested).file,
+        PathBuf::from("src/inline/nested.rs")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_module_tree_honours_path_attribute_and_cfg_test() -> TestResult {
+    let files = sources(&[
+        (
+            "src/main.rs",
+            "#[path = \"platform/unix.rs\"]\nmod platform;\n#[cfg(test)]\nmod tests;\nfn main() {}",
+        ),
+        ("src/platform/unix.rs", "pub fn open() {}"),
+        ("src/tests.rs", "mod cases { fn case() {} }"),
+    ]);
+    let tree = ModuleTree::from_sources("demo", "src/main.rs", &files)?;
+
+    assert_eq!(tree.kind, CrateKind::Binary);
+    let platform = tree.find("crate::platform").ok_or("platform module")?;
+    assert_eq!(
+        tree.module(platform).file,
+        PathBuf::from("src/platform/unix.rs")
+    );
+    let cases = tree.find("crate::tests::cases").ok_or("cases module")?;
+    assert!(tree.module(cases).is_test);
+    Ok(())
+}
+
+#[test]
+fn test_module_tree_rejects_invalid_syntax() {
+    let files = sources(&[("src/lib.rs", "mod broken;"), ("src/broken.rs", "fn (")]);
+    let result = ModuleTree::from_sources("demo", "src/lib.rs", &files);
+    assert!(matches!(result, Err(ValidationError::ParseError { .. })));
+}
+
+#[test]
+fn test_symbol_table_resolves_imports_and_reexports() -> TestResult {
+    let files = sources(&[
+        (
+            "src/lib.rs",
+            "mod engine;\npub use engine::Engine as Core;\nuse crate::engine::*;",
+        ),
+        (
+            "src/engine.rs",
+            "pub struct Engine;\nimpl Engine { pub fn start(&self) {} }\npub enum Mode { Fast, Slow }",
+        ),
+    ]);
+    let tree = ModuleTree::from_sources("demo", "src/lib.rs", &files)?;
+    let table = SymbolTable::build(&tree);
+    let root = tree.root();
+    let path = |text: &str| text.split("::").map(String::from).collect::<Vec<_>>();
+
+    let engine = table.find("crate::engine::Engine").ok_or("Engine symbol")?;
+    let start = table
+        .find("crate::engine::Engine::start")
+        .ok_or("start symbol")?;
+    let mode = table.find("crate::engine::Mode").ok_or("Mode symbol")?;
+    assert_eq!(table.symbol(start).kind, SymbolKind::Method);
+    assert_eq!(table.symbol(start).parent, Some(engine));
+
+    assert_eq!(
+        table.resolve_path(root, &path("Core")),
+        Some(Resolution::Symbol(engine))
+    );
+    assert_eq!(
+        table.resolve_path(root, &path("Core::start")),
+        Some(Resolution::Symbol(start))
+    );
+    // Through the glob import, variants resolve to their enum
+    assert_eq!(
+        table.resolve_path(root, &path("Mode::Fast")),
+        Some(Resolution::Symbol(mode))
+    );
+    assert_eq!(
+        table.resolve_path(root, &path("demo::engine")),
+        tree.find("crate::engine").map(Resolution::Module)
+    );
+    assert_eq!(table.resolve_path(root, &path("engine::Missing")), None);
+    assert_eq!(
+        table.resolve_path(root, &path("std::fmt::Display")),
+        Some(Resolution::External)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_call_graph_links_calls_across_modules() -> TestResult {
+    let analysis = analyze(&[
+        (
+            "src/main.rs",
+            "mod store;\nuse store::Store;\nfn main() {\n    let store = Store::open();\n    store.save();\n    println!(\"{}\", store::count());\n}",
+        ),
+        (
+            "src/store.rs",
+            "pub struct Store;\nimpl Store {\n    pub fn open() -> Self { Self }\n    pub fn save(&self) {}\n}\npub fn count() -> usize { 0 }",
+        ),
+    ])?;
+
+    let callers: Vec<String> = analysis
+        .references_to("crate::store::Store::open")
+        .iter()
+        .map(|symbol| symbol.path.clone())
+        .collect();
+    assert_eq!(callers, vec!["crate::main".to_string()]);
+    // `receiver.save()` and macro arguments count as references
+    assert!(analysis.is_reachable("crate::store::Store::save"));
+    assert!(analysis.is_reachable("crate::store::count"));
+    assert!(
+        analysis
+            .issues_of_type(SemanticIssueType::DeadCode)
+            .is_empty()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_reports_cross_module_dead_code() -> TestResult {
+    let analysis = analyze(&[
+        ("src/main.rs", "mod jobs;\nfn main() { jobs::run(); }"),
+        (
+            "src/jobs.rs",
+            "pub fn run() { schedule(); }\nfn schedule() {}\npub fn cancel() { cleanup(); }\nfn cleanup() {}\nfn _ignored() {}\nstruct Orphan;\nimpl Orphan { fn poke(&self) {} }",
+        ),
+    ])?;
+
+    let mut dead = issue_symbols(&analysis, SemanticIssueType::DeadCode);
+    dead.sort();
+    // Methods of a dead type are not reported separately
+    assert_eq!(
+        dead,
+        vec![
+            "crate::jobs::Orphan",
+            "crate::jobs::cancel",
+            "crate::jobs::cleanup"
+        ]
+    );
+    let issue = analysis.issues_of_type(SemanticIssueType::DeadCode)[0];
+    assert_eq!(issue.file, PathBuf::from("src/jobs.rs"));
+    assert_eq!(issue.severity, Severity::Warning);
+    Ok(())
+}
+
+#[test]
+fn test_library_public_api_and_tests_are_roots() -> TestResult {
+    let analysis = analyze(&[
+        (
+            "src/lib.rs",
+            "mod internal;\npub mod api;\npub use internal::exported;\nimpl std::fmt::Display for api::Client {\n    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { internal::render(f) }\n}\n#[cfg(test)]\nmod tests { use super::*; #[test] fn it_works() { internal::tested(); } }",
+        ),
+        (
+            "src/api.rs",
+            "pub struct Client;\nimpl Client { pub fn connect() {} }",
+        ),
+        (
+            "src/internal.rs",
+            "pub fn exported() {}\npub fn render(_f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { Ok(()) }\npub(crate) fn tested() {}\n#[allow(dead_code)]\nfn kept() {}\nfn unused() {}",
+        ),
+    ])?;
+
+    assert_eq!(
+        issue_symbols(&analysis, SemanticIssueType::DeadCode),
+        vec!["crate::internal::unused"]
+    );
+    let client = analysis
+        .symbol("crate::api::Client")
+        .ok_or("Client symbol")?;
+    assert!(analysis.roots.contains(&client.id));
+    Ok(())
+}
+
+#[test]
+fn test_reports_public_items_only_used_in_their_module() -> TestResult {
+    let analysis = analyze(&[
+        ("src/main.rs", "mod parser;\nfn main() { parser::parse(); }"),
+        (
+            "src/parser.rs",
+            "pub fn parse() { tokenize(); }\npub fn tokenize() { helper(); }\npub(crate) fn helper() {}\n#[cfg(test)]\nmod tests { #[test] fn t() { super::helper(); } }",
+        ),
+    ])?;
+
+    let unused = analysis.issues_of_type(SemanticIssueType::UnusedPublicItem);
+    let symbols: Vec<_> = unused
+        .iter()
+        .filter_map(|issue| issue.symbol.clone())
+        .collect();
+    // `helper` is used from a child module, which could see it while private too
+    assert_eq!(
+        symbols,
+        vec!["crate::parser::tokenize", "crate::parser::helper"]
+    );
+    assert!(unused.iter().all(|issue| issue.severity == Severity::Info));
+    assert!(unused[1].message.contains("pub(crate) function"));
+    Ok(())
+}
+
+#[test]
+fn test_reports_import_cycles_between_sibling_modules() -> TestResult {
+    let analysis = analyze(&[
+        ("src/lib.rs", "pub mod a;\npub mod b;\npub mod c;"),
+        (
+            "src/a.rs",
+            "use crate::b::B;\npub struct A(pub Option<Box<B>>);",
+        ),
+        ("src/b.rs", "use crate::c::C;\npub struct B(pub C);"),
+        (
+            "src/c.rs",
+            "use crate::a::A;\nuse super::a;\npub struct C(pub Option<Box<A>>);",
+        ),
+    ])?;
+
+    assert_eq!(analysis.import_cycles.len(), 1);
+    let cycles = analysis.issues_of_type(SemanticIssueType::ImportCycle);
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(
+        cycles[0].message,
+        "Import cycle between crate::a, crate::b, crate::c"
+    );
+    assert_eq!(
+        (cycles[0].file.clone(), cycles[0].line),
+        ("src/a.rs".into(), 1)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parent_child_imports_are_not_cycles() -> TestResult {
+    let analysis = analyze(&[
+        (
+            "src/lib.rs",
+            "mod error;\npub mod parser;\npub use error::Error;\npub use parser::Parser;",
+        ),
+        ("src/error.rs", "pub struct Error;"),
+        (
+            "src/parser.rs",
+            "use crate::Error;\npub struct Parser;\nimpl Parser { pub fn run(&self) -> Result<(), Error> { Ok(()) } }",
+        ),
+    ])?;
+
+    assert!(analysis.import_cycles.is_empty());
+    assert!(analysis.issues.is_empty(), "{:?}", analysis.issues);
+    Ok(())
+}
+
+#[test]
+fn test_reports_missing_modules_and_unresolved_imports() -> TestResult {
+    let files = sources(&[(
+        "src/main.rs",
+        "mod absent;\nuse crate::nowhere::Thing;\nuse std::collections::HashMap;\nfn main() {}",
+    )]);
+    let tree = ModuleTree::from_sources("demo", "src/main.rs", &files)?;
+    let analysis = CrateAnalyzer::new(true).analyze(tree);
+
+    let missing = analysis.issues_of_type(SemanticIssueType::MissingModule);
+    assert_eq!(missing.len(), 1);
+    assert!(missing[0].message.contains("src/absent.rs"));
+
+    // External paths are not checked; strict mode raises warnings to errors
+    let unresolved = analysis.issues_of_type(SemanticIssueType::UnresolvedImport);
+    assert_eq!(unresolved.len(), 1);
+    assert_eq!(unresolved[0].line, 2);
+    assert_eq!(unresolved[0].severity, Severity::Error);
+    assert!(analysis.has_errors());
+    Ok(())
+}
+
+#[test]
+fn test_analyze_package_from_disk() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    fs::create_dir_all(dir.path().join("src/net"))?;
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"demo-net\"\nversion = \"0.1.0\"\n",
+    )?;
+    fs::write(
+        dir.path().join("src/lib.rs"),
+        "mod net;\npub use demo_net::net::connect;",
+    )?;
+    fs::write(
+        dir.path().join("src/net/mod.rs"),
+        "mod socket;\npub use socket::connect;",
+    )?;
+    fs::write(
+        dir.path().join("src/net/socket.rs"),
+        "pub fn connect() { retry(); }\nfn retry() {}\nfn stale() {}",
+    )?;
+
+    let analysis = CrateAnalyzer::default().analyze_package(dir.path())?;
+
+    assert_eq!(analysis.tree.crate_name, "demo_net");
+    assert_eq!(analysis.tree.modules().len(), 3);
+    assert_eq!(
+        issue_symbols(&analysis, SemanticIssueType::DeadCode),
+        vec!["crate::net::socket::stale"]
+    );
+    let issue = analysis.issues_of_type(SemanticIssueType::DeadCode)[0];
+    assert_eq!(
+        issue.to_string(),
+        format!(
+            "[warning] {}:3: Function 'crate::net::socket::stale' is never used",
+            dir.path().join("src/net/socket.rs").display()
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn test_analyze_package_without_manifest_fails() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let result = CrateAnalyzer::default().analyze_package(dir.path());
+    assert!(matches!(result, Err(ValidationError::MissingElement(_))));
+    Ok(())
+}
@@ -1,4 +1,5 @@
 mod code_validator;
+mod crate_analyzer;
 mod semantic_validator;
 
 // Re-export test helpers from the main tests module for convenience
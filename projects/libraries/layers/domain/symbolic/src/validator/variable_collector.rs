@@ -1,7 +1,7 @@
 // projects/libraries/layers/domain/symbolic/src/validator/variable_collector.rs
 use std::collections::HashMap;
 use syn::visit::Visit;
-use syn::{Local, Pat, Stmt};
+use syn::{Ident, Local, Pat, Stmt};
 
 /// Visitor to collect variable declarations
 pub struct VariableCollector {
@@ -16,59 +16,55 @@ impl VariableCollector {
             current_line: 0,
         }
     }
+}
 
-    /// Recursively collect all identifier bindings from a pattern
-    fn collect_idents_from_pat(&mut self, pat: &Pat) {
-        match pat {
-            Pat::Ident(pat_ident) => {
-                let var_name = pat_ident.ident.to_string();
-                self.declared_variables.insert(var_name, self.current_line);
-            }
-            Pat::Tuple(pat_tuple) => {
-                for elem in &pat_tuple.elems {
-                    self.collect_idents_from_pat(elem);
-                }
-            }
-            Pat::Struct(pat_struct) => {
-                for field in &pat_struct.fields {
-                    self.collect_idents_from_pat(&field.pat);
-                }
-            }
-            Pat::TupleStruct(pat_tuple_struct) => {
-                for elem in &pat_tuple_struct.elems {
-                    self.collect_idents_from_pat(elem);
-                }
-            }
-            Pat::Slice(pat_slice) => {
-                for elem in &pat_slice.elems {
-                    self.collect_idents_from_pat(elem);
-                }
+/// Recursively collects every identifier bound by a pattern, including destructuring
+pub(crate) fn collect_pat_idents<'a>(pat: &'a Pat, out: &mut Vec<&'a Ident>) {
+    match pat {
+        Pat::Ident(pat_ident) => out.push(&pat_ident.ident),
+        Pat::Tuple(pat_tuple) => {
+            for elem in &pat_tuple.elems {
+                collect_pat_idents(elem, out);
             }
-            Pat::Reference(pat_ref) => {
-                self.collect_idents_from_pat(&pat_ref.pat);
+        }
+        Pat::Struct(pat_struct) => {
+            for field in &pat_struct.fields {
+                collect_pat_idents(&field.pat, out);
             }
-            Pat::Type(pat_type) => {
-                self.collect_idents_from_pat(&pat_type.pat);
+        }
+        Pat::TupleStruct(pat_tuple_struct) => {
+            for elem in &pat_tuple_struct.elems {
+                collect_pat_idents(elem, out);
             }
-            Pat::Paren(pat_paren) => {
-                self.collect_idents_from_pat(&pat_paren.pat);
+        }
+        Pat::Slice(pat_slice) => {
+            for elem in &pat_slice.elems {
+                collect_pat_idents(elem, out);
             }
-            Pat::Or(pat_or) => {
-                // All cases must bind the same identifiers; collect from the first
-                if let Some(first_case) = pat_or.cases.first() {
-                    self.collect_idents_from_pat(first_case);
-                }
+        }
+        Pat::Reference(pat_ref) => collect_pat_idents(&pat_ref.pat, out),
+        Pat::Type(pat_type) => collect_pat_idents(&pat_type.pat, out),
+        Pat::Paren(pat_paren) => collect_pat_idents(&pat_paren.pat, out),
+        Pat::Or(pat_or) => {
+            // All cases must bind the same identifiers; collect from the first
+            if let Some(first_case) = pat_or.cases.first() {
+                collect_pat_idents(first_case, out);
             }
-            // Patterns that do not introduce new bindings are ignored
-            _ => {}
         }
+        // Patterns that do not introduce new bindings are ignored
+        _ => {}
     }
 }
 
 impl<'ast> Visit<'ast> for VariableCollector {
     fn visit_local(&mut self, local: &'ast Local) {
         // Extract variable names from patterns, including destructuring
-        self.collect_idents_from_pat(&local.pat);
+        let mut idents = Vec::new();
+        collect_pat_idents(&local.pat, &mut idents);
+        for ident in idents {
+            self.declared_variables
+                .insert(ident.to_string(), self.current_line);
+        }
         syn::visit::visit_local(self, local);
     }
 
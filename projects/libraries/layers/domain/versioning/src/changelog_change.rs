@@ -0,0 +1,27 @@
+// projects/libraries/layers/domain/versioning/src/changelog_change.rs
+
+use crate::modification_category::ModificationCategory;
+use crate::modification_entry::ModificationEntry;
+use serde::{Deserialize, Serialize};
+
+/// One modification in a `ChangelogDocument`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangelogChange {
+    pub category: ModificationCategory,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+}
+
+impl From<&ModificationEntry> for ChangelogChange {
+    fn from(entry: &ModificationEntry) -> Self {
+        Self {
+            category: *entry.get_category(),
+            description: entry.get_description().to_string(),
+            scope: entry.get_scope().map(str::to_string),
+            reference: entry.get_reference().map(str::to_string),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// projects/libraries/layers/domain/versioning/src/changelog_document.rs
+
+use crate::changelog_release::ChangelogRelease;
+use crate::revision_log::RevisionLog;
+use serde::{Deserialize, Serialize};
+
+/// Shape of the JSON changelog, newest release first
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangelogDocument {
+    pub project: String,
+    pub releases: Vec<ChangelogRelease>,
+}
+
+impl From<&RevisionLog> for ChangelogDocument {
+    fn from(log: &RevisionLog) -> Self {
+        Self {
+            project: log.get_project_title().to_string(),
+            releases: log
+                .get_entries()
+                .iter()
+                .map(ChangelogRelease::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// projects/libraries/layers/domain/versioning/src/changelog_release.rs
+
+use crate::changelog_change::ChangelogChange;
+use crate::revision_entry::RevisionEntry;
+use serde::{Deserialize, Serialize};
+
+/// One release in a `ChangelogDocument`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangelogRelease {
+    /// Full release identifier, e.g. `1.2.0-rc.1+build.5`
+    pub version: String,
+    /// Release date as `YYYY-MM-DD`
+    pub date: String,
+    pub pre_release: bool,
+    pub contributors: Vec<String>,
+    pub changes: Vec<ChangelogChange>,
+}
+
+impl From<&RevisionEntry> for ChangelogRelease {
+    fn from(entry: &RevisionEntry) -> Self {
+        Self {
+            version: entry.get_release().to_string(),
+            date: entry.get_timestamp().format("%Y-%m-%d").to_string(),
+            pre_release: entry.get_release().is_pre_release(),
+            contributors: entry.get_contributors().to_vec(),
+            changes: entry
+                .get_modifications()
+                .iter()
+                .map(ChangelogChange::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+// projects/libraries/layers/domain/versioning/src/commit_ingestion.rs
+
+use crate::conventional_commit::ConventionalCommit;
+use crate::modification_entry::ModificationEntry;
+use crate::release_bump::ReleaseBump;
+use crate::release_id::ReleaseId;
+
+/// Conventional commits read from a `git log` text dump.
+///
+/// Both the default `git log` layout (`commit <hash>`, `Author:` and an
+/// indented message) and one subject per line (`git log --oneline`, or
+/// `--format=%s`) are understood. Commits keep the order of the dump.
+#[derive(Debug, Clone, Default)]
+pub struct CommitIngestion {
+    commits: Vec<ConventionalCommit>,
+    skipped: Vec<String>,
+}
+
+impl CommitIngestion {
+    pub fn from_git_log(git_log: &str) -> Self {
+        let is_full_layout = git_log.lines().any(|line| full_layout_hash(line).is_some());
+        let raw_commits = if is_full_layout {
+            split_full_layout(git_log)
+        } else {
+            split_oneline_layout(git_log)
+        };
+
+        let mut ingestion = Self::default();
+        for raw in raw_commits {
+            match ConventionalCommit::parse(&raw.message) {
+                Ok(mut commit) => {
+                    if let Some(hash) = raw.hash {
+                        commit = commit.with_hash(hash);
+                    }
+                    if let Some(author) = raw.author {
+                        commit = commit.with_author(author);
+                    }
+                    ingestion.commits.push(commit);
+                }
+                Err(_) => {
+                    let subject = raw.message.trim().lines().next().unwrap_or_default();
+                    if !subject.is_empty() {
+                        ingestion.skipped.push(subject.to_string());
+                    }
+                }
+            }
+        }
+        ingestion
+    }
+
+    /// Commits that follow the conventional format
+    pub fn commits(&self) -> &[ConventionalCommit] {
+        &self.commits
+    }
+
+    /// Subjects of commits that do not follow the conventional format
+    /// (merge commits, for instance)
+    pub fn skipped(&self) -> &[String] {
+        &self.skipped
+    }
+
+    /// Changelog entries of the commits that have a category
+    pub fn modifications(&self) -> Vec<ModificationEntry> {
+        self.commits
+            .iter()
+            .filter_map(ConventionalCommit::to_modification)
+            .collect()
+    }
+
+    /// Authors of the commits that produce changelog entries, first seen first
+    pub fn contributors(&self) -> Vec<String> {
+        let mut contributors: Vec<String> = Vec::new();
+        for commit in &self.commits {
+            if commit.category().is_some()
+                && let Some(author) = commit.author()
+                && !contributors.iter().any(|known| known == author)
+            {
+                contributors.push(author.to_string());
+            }
+        }
+        contributors
+    }
+
+    /// Strongest bump required by the commits, `None` when no commit
+    /// affects the changelog. Before 1.0.0 breaking changes only require a
+    /// feature bump.
+    pub fn suggested_bump(&self, current: &ReleaseId) -> Option<ReleaseBump> {
+        let bump = self
+            .commits
+            .iter()
+            .filter_map(ConventionalCommit::bump)
+            .max()?;
+        if bump == ReleaseBump::Major && current.first_tier() == 0 {
+            return Some(ReleaseBump::Feature);
+        }
+        Some(bump)
+    }
+}
+
+struct RawCommit {
+    hash: Option<String>,
+    author: Option<String>,
+    message: String,
+}
+
+fn is_commit_hash(token: &str) -> bool {
+    (7..=64).contains(&token.len()) && token.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Hash of a `commit <hash> [(refs)]` line
+fn full_layout_hash(line: &str) -> Option<&str> {
+    let hash = line.strip_prefix("commit ")?.split_whitespace().next()?;
+    is_commit_hash(hash).then_some(hash)
+}
+
+fn split_full_layout(git_log: &str) -> Vec<RawCommit> {
+    let mut commits: Vec<RawCommit> = Vec::new();
+    for line in git_log.lines() {
+        if let Some(hash) = full_layout_hash(line) {
+            commits.push(RawCommit {
+                hash: Some(hash.to_string()),
+                author: None,
+                message: String::new(),
+            });
+            continue;
+        }
+        let Some(commit) = commits.last_mut() else {
+            continue;
+        };
+        if let Some(author) = line.strip_prefix("Author:") {
+            // "Name <email>" -> "Name"
+            let name = author.split('<').next().unwrap_or_default().trim();
+            if !name.is_empty() {
+                commit.author = Some(name.to_string());
+            }
+        } else if let Some(message_line) = line.strip_prefix("    ") {
+            commit.message.push_str(message_line);
+            commit.message.push('\n');
+        } else if line.trim().is_empty() && !commit.message.is_empty() {
+            commit.message.push('\n');
+        }
+        // Other header lines (Date:, Merge:) and --stat output are ignored
+    }
+    commits
+}
+
+fn split_oneline_layout(git_log: &str) -> Vec<RawCommit> {
+    git_log
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (hash, subject) = match line.split_once(' ') {
+                Some((hash, subject)) if is_commit_hash(hash) => (Some(hash), subject.trim()),
+                _ => (None, line),
+            };
+            // Drop a `--decorate` ref list such as "(HEAD -> main, tag: v1.0.0)"
+            let subject = match subject.strip_prefix('(') {
+                Some(decorated) if hash.is_some() => decorated
+                    .split_once(')')
+                    .map_or(subject, |(_, rest)| rest.trim()),
+                _ => subject,
+            };
+            RawCommit {
+                hash: hash.map(str::to_string),
+                author: None,
+                message: subject.to_string(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,11 @@
+// projects/libraries/layers/domain/versioning/src/commit_parse_error.rs
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CommitParseError {
+    #[error("Empty commit message")]
+    EmptyMessage,
+    #[error("Not a conventional commit header: {0}")]
+    InvalidHeader(String),
+    #[error("Missing description in commit header: {0}")]
+    MissingDescription(String),
+}
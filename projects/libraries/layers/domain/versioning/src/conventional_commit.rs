@@ -0,0 +1,216 @@
+// projects/libraries/layers/domain/versioning/src/conventional_commit.rs
+
+use crate::commit_parse_error::CommitParseError;
+use crate::modification_category::ModificationCategory;
+use crate::modification_entry::ModificationEntry;
+use crate::release_bump::ReleaseBump;
+use serde::{Deserialize, Serialize};
+
+/// A commit message following the Conventional Commits format:
+/// `type(scope)!: description`, an optional body and optional footers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConventionalCommit {
+    kind: String,
+    scope: Option<String>,
+    breaking: bool,
+    description: String,
+    body: Option<String>,
+    footers: Vec<(String, String)>,
+    hash: Option<String>,
+    author: Option<String>,
+}
+
+impl ConventionalCommit {
+    /// Parse a full commit message
+    pub fn parse(message: &str) -> Result<Self, CommitParseError> {
+        let message = message.trim();
+        let (header, rest) = match message.split_once('\n') {
+            Some((header, rest)) => (header.trim(), rest),
+            None => (message, ""),
+        };
+        if header.is_empty() {
+            return Err(CommitParseError::EmptyMessage);
+        }
+
+        let (prefix, description) = header
+            .split_once(':')
+            .ok_or_else(|| CommitParseError::InvalidHeader(header.to_string()))?;
+        let description = description.trim();
+        if description.is_empty() {
+            return Err(CommitParseError::MissingDescription(header.to_string()));
+        }
+
+        let (prefix, mut breaking) = match prefix.strip_suffix('!') {
+            Some(prefix) => (prefix, true),
+            None => (prefix, false),
+        };
+        let (kind, scope) = match prefix.split_once('(') {
+            Some((kind, scope)) => {
+                let scope = scope
+                    .strip_suffix(')')
+                    .filter(|scope| !scope.trim().is_empty() && !scope.contains(['(', ')']))
+                    .ok_or_else(|| CommitParseError::InvalidHeader(header.to_string()))?;
+                (kind, Some(scope.trim().to_string()))
+            }
+            None => (prefix, None),
+        };
+        if kind.is_empty() || !kind.bytes().all(|b| b.is_ascii_alphabetic() || b == b'-') {
+            return Err(CommitParseError::InvalidHeader(header.to_string()));
+        }
+
+        let (body, footers) = split_body_and_footers(rest);
+        breaking |= footers.iter().any(|(token, _)| is_breaking_token(token));
+
+        Ok(Self {
+            kind: kind.to_ascii_lowercase(),
+            scope,
+            breaking,
+            description: description.to_string(),
+            body,
+            footers,
+            hash: None,
+            author: None,
+        })
+    }
+
+    pub fn with_hash(mut self, hash: String) -> Self {
+        self.hash = Some(hash);
+        self
+    }
+
+    pub fn with_author(mut self, author: String) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Commit type, lowercased (`feat`, `fix`, ...)
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    /// Marked with `!` or a `BREAKING CHANGE` footer
+    pub fn is_breaking(&self) -> bool {
+        self.breaking
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Footers as (token, value) pairs, in message order
+    pub fn footers(&self) -> &[(String, String)] {
+        &self.footers
+    }
+
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// Changelog category, or `None` for changes that are not user facing
+    /// (`docs`, `style`, `test`, `chore`, `ci`, `build`, `revert`, ...)
+    pub fn category(&self) -> Option<ModificationCategory> {
+        if self.breaking {
+            return Some(ModificationCategory::BreakingModification);
+        }
+        match self.kind.as_str() {
+            "feat" | "feature" => Some(ModificationCategory::NewCapability),
+            "fix" if self.scope.as_deref() == Some("security") => {
+                Some(ModificationCategory::SecurityUpdate)
+            }
+            "fix" | "bugfix" => Some(ModificationCategory::CorrectionApplied),
+            "security" | "sec" => Some(ModificationCategory::SecurityUpdate),
+            "perf" | "refactor" => Some(ModificationCategory::Enhancement),
+            "deprecate" | "deprecation" => Some(ModificationCategory::DeprecationNotice),
+            _ => None,
+        }
+    }
+
+    /// Release tier this commit requires on its own
+    pub fn bump(&self) -> Option<ReleaseBump> {
+        match self.category()? {
+            ModificationCategory::BreakingModification => Some(ReleaseBump::Major),
+            ModificationCategory::NewCapability => Some(ReleaseBump::Feature),
+            _ => Some(ReleaseBump::Correction),
+        }
+    }
+
+    /// Changelog entry for this commit, if it has a category
+    pub fn to_modification(&self) -> Option<ModificationEntry> {
+        let mut entry = ModificationEntry::create(self.description.clone(), self.category()?);
+        if let Some(scope) = &self.scope {
+            entry = entry.with_scope(scope.clone());
+        }
+        if let Some(hash) = &self.hash {
+            entry = entry.with_reference(hash.clone());
+        }
+        Some(entry)
+    }
+}
+
+fn is_breaking_token(token: &str) -> bool {
+    token == "BREAKING CHANGE" || token == "BREAKING-CHANGE"
+}
+
+/// Footer token at the start of `line`: `Token: value` or `Token #value`
+fn footer_start(line: &str) -> Option<(&str, &str)> {
+    let (token, value) = line.split_once(": ").or_else(|| line.split_once(" #"))?;
+    let valid = is_breaking_token(token)
+        || (!token.is_empty()
+            && token
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-'));
+    valid.then_some((token, value))
+}
+
+/// The last paragraph holds the footers when its first line starts one
+fn split_body_and_footers(rest: &str) -> (Option<String>, Vec<(String, String)>) {
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in rest.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(line.trim_end());
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join("\n"));
+    }
+
+    let mut footers: Vec<(String, String)> = Vec::new();
+    if let Some(last) = paragraphs.last()
+        && last.lines().next().and_then(footer_start).is_some()
+    {
+        for line in last.lines() {
+            match (footer_start(line), footers.last_mut()) {
+                (Some((token, value)), _) => {
+                    footers.push((token.to_string(), value.trim().to_string()))
+                }
+                (None, Some((_, value))) => {
+                    value.push('\n');
+                    value.push_str(line.trim());
+                }
+                (None, None) => {}
+            }
+        }
+        paragraphs.pop();
+    }
+
+    let body = (!paragraphs.is_empty()).then(|| paragraphs.join("\n\n"));
+    (body, footers)
+}
@@ -1,10 +1,23 @@
 // projects/libraries/layers/domain/versioning/src/document_builder.rs
 
+use crate::changelog_document::ChangelogDocument;
 use crate::modification_category::ModificationCategory;
+use crate::modification_entry::ModificationEntry;
 use crate::output_format::OutputFormat;
 use crate::revision_log::RevisionLog;
+use common_json::to_string_pretty;
 use std::fmt::Write;
 
+/// Keep a Changelog sections, in the order the format prescribes
+const CHANGELOG_SECTIONS: [&str; 6] = [
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+
 /// Builds documentation for revision history
 pub struct DocumentBuilder {
     format_style: OutputFormat,
@@ -20,6 +33,8 @@ impl DocumentBuilder {
         match self.format_style {
             OutputFormat::Markdown => self.build_markdown(log),
             OutputFormat::PlainText => self.build_plain_text(log),
+            OutputFormat::Json => self.build_json(log),
+            OutputFormat::KeepAChangelog => self.build_keep_a_changelog(log),
         }
     }
 
@@ -140,4 +155,94 @@ impl DocumentBuilder {
 
         output
     }
+
+    fn build_json(&self, log: &RevisionLog) -> String {
+        let mut output = to_string_pretty(&ChangelogDocument::from(log))
+            .expect("changelog document only holds strings and plain enums");
+        output.push('\n');
+        output
+    }
+
+    fn build_keep_a_changelog(&self, log: &RevisionLog) -> String {
+        let mut output = String::new();
+
+        writeln!(&mut output, "# Changelog").unwrap();
+        writeln!(&mut output).unwrap();
+        writeln!(
+            &mut output,
+            "All notable changes to {} will be documented in this file.",
+            log.get_project_title()
+        )
+        .unwrap();
+        writeln!(&mut output).unwrap();
+        writeln!(
+            &mut output,
+            "The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),"
+        )
+        .unwrap();
+        writeln!(
+            &mut output,
+            "and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html)."
+        )
+        .unwrap();
+
+        for entry in log.get_entries() {
+            writeln!(&mut output).unwrap();
+            writeln!(
+                &mut output,
+                "## [{}] - {}",
+                entry.get_release(),
+                entry.get_timestamp().format("%Y-%m-%d")
+            )
+            .unwrap();
+
+            for section in CHANGELOG_SECTIONS {
+                let mut mods: Vec<_> = entry
+                    .get_modifications()
+                    .iter()
+                    .filter(|m| m.get_category().changelog_section() == section)
+                    .collect();
+                if mods.is_empty() {
+                    continue;
+                }
+                // Breaking changes lead their section
+                mods.sort_by_key(|m| {
+                    *m.get_category() != ModificationCategory::BreakingModification
+                });
+
+                writeln!(&mut output).unwrap();
+                writeln!(&mut output, "### {}", section).unwrap();
+                writeln!(&mut output).unwrap();
+                for modification in mods {
+                    writeln!(&mut output, "- {}", changelog_line(modification)).unwrap();
+                }
+            }
+        }
+
+        output
+    }
+}
+
+fn changelog_line(modification: &ModificationEntry) -> String {
+    let mut line = String::new();
+    if *modification.get_category() == ModificationCategory::BreakingModification {
+        line.push_str("**BREAKING:** ");
+    }
+    if let Some(scope) = modification.get_scope() {
+        write!(&mut line, "**{}:** ", scope).unwrap();
+    }
+    line.push_str(modification.get_description());
+    if let Some(reference) = modification.get_reference() {
+        write!(&mut line, " ({})", short_reference(reference)).unwrap();
+    }
+    line
+}
+
+/// Commit hashes are shortened to the usual seven characters
+fn short_reference(reference: &str) -> &str {
+    if reference.len() > 7 && reference.bytes().all(|b| b.is_ascii_hexdigit()) {
+        &reference[..7]
+    } else {
+        reference
+    }
 }
@@ -1,8 +1,15 @@
 // projects/libraries/layers/domain/versioning/src/lib.rs
+pub mod changelog_change;
+pub mod changelog_document;
+pub mod changelog_release;
+pub mod commit_ingestion;
+pub mod commit_parse_error;
+pub mod conventional_commit;
 pub mod document_builder;
 pub mod modification_category;
 pub mod modification_entry;
 pub mod output_format;
+pub mod release_bump;
 pub mod release_id;
 pub mod release_id_error;
 pub mod release_tracker;
@@ -10,10 +17,17 @@ pub mod revision_entry;
 pub mod revision_log;
 mod tests;
 
+pub use changelog_change::ChangelogChange;
+pub use changelog_document::ChangelogDocument;
+pub use changelog_release::ChangelogRelease;
+pub use commit_ingestion::CommitIngestion;
+pub use commit_parse_error::CommitParseError;
+pub use conventional_commit::ConventionalCommit;
 pub use document_builder::DocumentBuilder;
 pub use modification_category::ModificationCategory;
 pub use modification_entry::ModificationEntry;
 pub use output_format::OutputFormat;
+pub use release_bump::ReleaseBump;
 pub use release_id::ReleaseId;
 pub use release_id_error::ReleaseIdError;
 pub use release_tracker::ReleaseTracker;
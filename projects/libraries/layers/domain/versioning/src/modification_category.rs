@@ -24,4 +24,16 @@ impl ModificationCategory {
             Self::DeprecationNotice => "Deprecated",
         }
     }
+
+    /// Keep a Changelog section the category is listed under. Breaking
+    /// changes go to "Changed" and are flagged on the line itself.
+    pub fn changelog_section(&self) -> &'static str {
+        match self {
+            Self::NewCapability => "Added",
+            Self::BreakingModification | Self::Enhancement => "Changed",
+            Self::DeprecationNotice => "Deprecated",
+            Self::CorrectionApplied => "Fixed",
+            Self::SecurityUpdate => "Security",
+        }
+    }
 }
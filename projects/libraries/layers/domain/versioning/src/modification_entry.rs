@@ -4,10 +4,16 @@ use crate::modification_category::ModificationCategory;
 use serde::{Deserialize, Serialize};
 
 /// Represents a single modification entry in the revision log
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ModificationEntry {
     description: String,
     category: ModificationCategory,
+    /// Area of the project the change touches, e.g. a conventional commit scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    /// Identifier of the change's origin, e.g. a commit hash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
 }
 
 impl ModificationEntry {
@@ -15,9 +21,21 @@ impl ModificationEntry {
         Self {
             description,
             category,
+            scope: None,
+            reference: None,
         }
     }
 
+    pub fn with_scope(mut self, scope: String) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    pub fn with_reference(mut self, reference: String) -> Self {
+        self.reference = Some(reference);
+        self
+    }
+
     pub fn get_description(&self) -> &str {
         &self.description
     }
@@ -25,4 +43,12 @@ impl ModificationEntry {
     pub fn get_category(&self) -> &ModificationCategory {
         &self.category
     }
+
+    pub fn get_scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    pub fn get_reference(&self) -> Option<&str> {
+        self.reference.as_deref()
+    }
 }
@@ -4,4 +4,8 @@
 pub enum OutputFormat {
     Markdown,
     PlainText,
+    /// `ChangelogDocument` serialized as pretty JSON
+    Json,
+    /// Markdown following https://keepachangelog.com/en/1.1.0/
+    KeepAChangelog,
 }
@@ -0,0 +1,11 @@
+// projects/libraries/layers/domain/versioning/src/release_bump.rs
+
+use serde::{Deserialize, Serialize};
+
+/// Which tier of a `ReleaseId` a release advances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ReleaseBump {
+    Correction,
+    Feature,
+    Major,
+}
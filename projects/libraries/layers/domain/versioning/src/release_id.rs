@@ -1,18 +1,27 @@
 // projects/libraries/layers/domain/versioning/src/release_id.rs
 
+use crate::release_bump::ReleaseBump;
 use crate::release_id_error::ReleaseIdError;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
 
 /// Custom version identifier using a three-tier numbering scheme
 /// Tier 1: Major changes (breaking compatibility)
 /// Tier 2: Feature additions (backward compatible)
 /// Tier 3: Corrections and refinements
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+///
+/// An optional pre-release (`1.2.0-rc.1`) ranks below the plain release, and
+/// optional build metadata (`1.2.0+build.5`) is ignored for precedence.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ReleaseId {
     tier_one: u32,
     tier_two: u32,
     tier_three: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pre_release: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    build_metadata: Option<String>,
 }
 
 impl ReleaseId {
@@ -22,6 +31,8 @@ impl ReleaseId {
             tier_one,
             tier_two,
             tier_three,
+            pre_release: None,
+            build_metadata: None,
         }
     }
 
@@ -30,6 +41,22 @@ impl ReleaseId {
         Self::build(1, 0, 0)
     }
 
+    /// Attach a pre-release such as `alpha` or `rc.1`
+    pub fn with_pre_release(mut self, pre_release: &str) -> Result<Self, ReleaseIdError> {
+        validate_identifiers(pre_release, true)
+            .map_err(|_| ReleaseIdError::InvalidPreRelease(pre_release.to_string()))?;
+        self.pre_release = Some(pre_release.to_string());
+        Ok(self)
+    }
+
+    /// Attach build metadata such as `build.5` or `sha.1a2b3c`
+    pub fn with_build_metadata(mut self, build_metadata: &str) -> Result<Self, ReleaseIdError> {
+        validate_identifiers(build_metadata, false)
+            .map_err(|_| ReleaseIdError::InvalidBuildMetadata(build_metadata.to_string()))?;
+        self.build_metadata = Some(build_metadata.to_string());
+        Ok(self)
+    }
+
     /// Get the first tier value
     pub fn first_tier(&self) -> u32 {
         self.tier_one
@@ -45,24 +72,104 @@ impl ReleaseId {
         self.tier_three
     }
 
+    /// Get the pre-release, if any
+    pub fn pre_release(&self) -> Option<&str> {
+        self.pre_release.as_deref()
+    }
+
+    /// Get the build metadata, if any
+    pub fn build_metadata(&self) -> Option<&str> {
+        self.build_metadata.as_deref()
+    }
+
+    pub fn is_pre_release(&self) -> bool {
+        self.pre_release.is_some()
+    }
+
+    /// The same three tiers without pre-release or build metadata
+    pub fn stable(&self) -> Self {
+        Self::build(self.tier_one, self.tier_two, self.tier_three)
+    }
+
     /// Advance to next major tier (resets others)
     pub fn advance_major(&self) -> Self {
-        Self::build(self.tier_one + 1, 0, 0)
+        self.advance(ReleaseBump::Major)
     }
 
     /// Advance to next feature tier (resets corrections)
     pub fn advance_feature(&self) -> Self {
-        Self::build(self.tier_one, self.tier_two + 1, 0)
+        self.advance(ReleaseBump::Feature)
     }
 
     /// Advance correction tier
     pub fn advance_correction(&self) -> Self {
-        Self::build(self.tier_one, self.tier_two, self.tier_three + 1)
+        self.advance(ReleaseBump::Correction)
     }
 
-    /// Parse from string format "X.Y.Z"
+    /// Advance by `bump`. A pre-release whose tiers already cover the bump
+    /// (e.g. `2.0.0-rc.1` for a major bump) is finalized instead.
+    pub fn advance(&self, bump: ReleaseBump) -> Self {
+        if self.is_pre_release() && self.covers(bump) {
+            return self.stable();
+        }
+        match bump {
+            ReleaseBump::Major => Self::build(self.tier_one + 1, 0, 0),
+            ReleaseBump::Feature => Self::build(self.tier_one, self.tier_two + 1, 0),
+            ReleaseBump::Correction => {
+                Self::build(self.tier_one, self.tier_two, self.tier_three + 1)
+            }
+        }
+    }
+
+    /// Next pre-release on the `label` channel for `bump`.
+    ///
+    /// Continuing a pre-release that covers the bump on the same channel
+    /// increments its counter (`rc.1` -> `rc.2`); otherwise the target release
+    /// starts at `label.1`.
+    pub fn advance_pre_release(
+        &self,
+        bump: ReleaseBump,
+        label: &str,
+    ) -> Result<Self, ReleaseIdError> {
+        let base = match &self.pre_release {
+            Some(current) if self.covers(bump) => {
+                if let Some(counter) = channel_counter(current, label) {
+                    return self
+                        .stable()
+                        .with_pre_release(&format!("{}.{}", label, counter + 1));
+                }
+                self.stable()
+            }
+            _ => self.advance(bump),
+        };
+        base.with_pre_release(&format!("{}.1", label))
+    }
+
+    /// Compare ignoring build metadata, as release precedence is defined
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        (self.tier_one, self.tier_two, self.tier_three)
+            .cmp(&(other.tier_one, other.tier_two, other.tier_three))
+            .then_with(|| match (&self.pre_release, &other.pre_release) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => cmp_pre_release(a, b),
+            })
+    }
+
+    /// Parse from string format "X.Y.Z", optionally followed by
+    /// "-pre.release" and "+build.metadata"
     pub fn parse_str(input: &str) -> Result<Self, ReleaseIdError> {
-        let segments: Vec<&str> = input.split('.').collect();
+        let (rest, build_metadata) = match input.split_once('+') {
+            Some((rest, build)) => (rest, Some(build)),
+            None => (input, None),
+        };
+        let (core, pre_release) = match rest.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (rest, None),
+        };
+
+        let segments: Vec<&str> = core.split('.').collect();
 
         if segments.len() != 3 {
             return Err(ReleaseIdError::InvalidFormat);
@@ -78,18 +185,56 @@ impl ReleaseId {
             .parse::<u32>()
             .map_err(|_| ReleaseIdError::InvalidNumber)?;
 
-        Ok(Self::build(tier_one, tier_two, tier_three))
+        let mut release = Self::build(tier_one, tier_two, tier_three);
+        if let Some(pre_release) = pre_release {
+            release = release.with_pre_release(pre_release)?;
+        }
+        if let Some(build_metadata) = build_metadata {
+            release = release.with_build_metadata(build_metadata)?;
+        }
+        Ok(release)
     }
 
     /// Check if this represents a breaking change from another version
     pub fn breaks_compatibility_with(&self, other: &Self) -> bool {
         self.tier_one != other.tier_one
     }
+
+    /// Whether a pre-release of these tiers already includes a `bump`
+    fn covers(&self, bump: ReleaseBump) -> bool {
+        match bump {
+            ReleaseBump::Major => self.tier_two == 0 && self.tier_three == 0,
+            ReleaseBump::Feature => self.tier_three == 0,
+            ReleaseBump::Correction => true,
+        }
+    }
+}
+
+impl PartialOrd for ReleaseId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ReleaseId {
+    /// Precedence order; build metadata only breaks ties so that the order
+    /// stays consistent with equality.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build_metadata.cmp(&other.build_metadata))
+    }
 }
 
 impl fmt::Display for ReleaseId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}.{}.{}", self.tier_one, self.tier_two, self.tier_three)
+        write!(f, "{}.{}.{}", self.tier_one, self.tier_two, self.tier_three)?;
+        if let Some(pre_release) = &self.pre_release {
+            write!(f, "-{}", pre_release)?;
+        }
+        if let Some(build_metadata) = &self.build_metadata {
+            write!(f, "+{}", build_metadata)?;
+        }
+        Ok(())
     }
 }
 
@@ -100,3 +245,59 @@ impl std::str::FromStr for ReleaseId {
         Self::parse_str(s)
     }
 }
+
+/// Dot-separated, non-empty `[0-9A-Za-z-]` identifiers; numeric pre-release
+/// identifiers must not have leading zeros.
+fn validate_identifiers(value: &str, pre_release: bool) -> Result<(), ()> {
+    for identifier in value.split('.') {
+        if identifier.is_empty()
+            || !identifier
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        {
+            return Err(());
+        }
+        if pre_release
+            && identifier.len() > 1
+            && identifier.starts_with('0')
+            && identifier.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(());
+        }
+    }
+    Ok(())
+}
+
+/// Numeric identifiers compare numerically and rank below alphanumeric
+/// ones; a shorter prefix ranks lower.
+fn cmp_pre_release(a: &str, b: &str) -> Ordering {
+    let mut left = a.split('.');
+    let mut right = b.split('.');
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+                    (Ok(x), Ok(y)) => x.cmp(&y),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => x.cmp(y),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+/// Counter of `pre_release` when it is `label` or `label.N`
+fn channel_counter(pre_release: &str, label: &str) -> Option<u64> {
+    let rest = pre_release.strip_prefix(label)?;
+    if rest.is_empty() {
+        return Some(0);
+    }
+    rest.strip_prefix('.')?.parse().ok()
+}
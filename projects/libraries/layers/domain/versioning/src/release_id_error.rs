@@ -6,4 +6,8 @@ pub enum ReleaseIdError {
     InvalidFormat,
     #[error("Invalid number in version")]
     InvalidNumber,
+    #[error("Invalid pre-release identifier: {0}")]
+    InvalidPreRelease(String),
+    #[error("Invalid build metadata: {0}")]
+    InvalidBuildMetadata(String),
 }
@@ -1,8 +1,11 @@
 // projects/libraries/layers/domain/versioning/src/release_tracker.rs
 
+use crate::commit_ingestion::CommitIngestion;
 use crate::modification_category::ModificationCategory;
 use crate::modification_entry::ModificationEntry;
+use crate::release_bump::ReleaseBump;
 use crate::release_id::ReleaseId;
+use crate::release_id_error::ReleaseIdError;
 use crate::revision_entry::RevisionEntry;
 use crate::revision_log::RevisionLog;
 use chrono::Utc;
@@ -25,7 +28,7 @@ impl ReleaseTracker {
         let initial_release = ReleaseId::initial();
         let mut revision_log = RevisionLog::initialize(project_name);
 
-        let mut entry = RevisionEntry::create(initial_release, Utc::now());
+        let mut entry = RevisionEntry::create(initial_release.clone(), Utc::now());
         entry.append_modification(ModificationEntry::create(
             "Initial release".to_string(),
             ModificationCategory::NewCapability,
@@ -54,8 +57,7 @@ impl ReleaseTracker {
         modifications: Vec<ModificationEntry>,
         contributors: Vec<String>,
     ) {
-        self.current_release = self.current_release.advance_major();
-        self.create_entry(modifications, contributors);
+        self.register_release(ReleaseBump::Major, modifications, contributors);
     }
 
     /// Register a new feature release with changes
@@ -64,8 +66,7 @@ impl ReleaseTracker {
         modifications: Vec<ModificationEntry>,
         contributors: Vec<String>,
     ) {
-        self.current_release = self.current_release.advance_feature();
-        self.create_entry(modifications, contributors);
+        self.register_release(ReleaseBump::Feature, modifications, contributors);
     }
 
     /// Register a correction release with changes
@@ -74,12 +75,69 @@ impl ReleaseTracker {
         modifications: Vec<ModificationEntry>,
         contributors: Vec<String>,
     ) {
-        self.current_release = self.current_release.advance_correction();
+        self.register_release(ReleaseBump::Correction, modifications, contributors);
+    }
+
+    /// Register a release advancing the tier selected by `bump`
+    pub fn register_release(
+        &mut self,
+        bump: ReleaseBump,
+        modifications: Vec<ModificationEntry>,
+        contributors: Vec<String>,
+    ) {
+        self.current_release = self.current_release.advance(bump);
         self.create_entry(modifications, contributors);
     }
 
+    /// Register a pre-release on the `label` channel (e.g. `rc`) of the
+    /// release selected by `bump`
+    pub fn register_pre_release(
+        &mut self,
+        bump: ReleaseBump,
+        label: &str,
+        modifications: Vec<ModificationEntry>,
+        contributors: Vec<String>,
+    ) -> Result<(), ReleaseIdError> {
+        self.current_release = self.current_release.advance_pre_release(bump, label)?;
+        self.create_entry(modifications, contributors);
+        Ok(())
+    }
+
+    /// Register a release from ingested commits, using the suggested bump
+    /// and the commit authors as contributors. With `pre_release`, a
+    /// pre-release on that channel is registered instead.
+    ///
+    /// Returns the new release, or `None` when no commit affects the
+    /// changelog.
+    pub fn release_from_commits(
+        &mut self,
+        ingestion: &CommitIngestion,
+        pre_release: Option<&str>,
+    ) -> Result<Option<ReleaseId>, ReleaseIdError> {
+        let Some(bump) = ingestion.suggested_bump(&self.current_release) else {
+            return Ok(None);
+        };
+        let modifications = ingestion.modifications();
+        let contributors = ingestion.contributors();
+        match pre_release {
+            Some(label) => self.register_pre_release(bump, label, modifications, contributors)?,
+            None => self.register_release(bump, modifications, contributors),
+        }
+        Ok(Some(self.current_release.clone()))
+    }
+
+    /// Parse a `git log` text dump and register the release it calls for,
+    /// see `CommitIngestion` and `release_from_commits`
+    pub fn ingest_git_log(
+        &mut self,
+        git_log: &str,
+        pre_release: Option<&str>,
+    ) -> Result<Option<ReleaseId>, ReleaseIdError> {
+        self.release_from_commits(&CommitIngestion::from_git_log(git_log), pre_release)
+    }
+
     fn create_entry(&mut self, modifications: Vec<ModificationEntry>, contributors: Vec<String>) {
-        let mut entry = RevisionEntry::create(self.current_release, Utc::now());
+        let mut entry = RevisionEntry::create(self.current_release.clone(), Utc::now());
 
         for modification in modifications {
             entry.append_modification(modification);
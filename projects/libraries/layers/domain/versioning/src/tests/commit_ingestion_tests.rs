@@ -0,0 +1,126 @@
+use crate::commit_ingestion::CommitIngestion;
+use crate::modification_category::ModificationCategory;
+use crate::release_bump::ReleaseBump;
+use crate::release_id::ReleaseId;
+use crate::tests::test_helpers::*;
+
+const FULL_LOG: &str = "\
+commit 9f8e7d6c5b4a39281706f5e4d3c2b1a098765432 (HEAD -> main)
+Author: Alice <alice@example.com>
+Date:   Mon Jan 6 10:00:00 2025 +0100
+
+    feat(api): add pagination
+
+    Pages default to 50 items.
+
+    Refs #12
+
+commit 1234567890abcdef1234567890abcdef12345678
+Merge: 9f8e7d6 abcdef0
+Author: Bob <bob@example.com>
+Date:   Sun Jan 5 09:00:00 2025 +0100
+
+    Merge branch 'fix-login'
+
+commit abcdef0123456789abcdef0123456789abcdef01
+Author: Bob <bob@example.com>
+Date:   Sat Jan 4 08:00:00 2025 +0100
+
+    fix: reject expired tokens
+
+ src/auth.rs | 4 ++--
+ 1 file changed, 2 insertions(+), 2 deletions(-)
+
+commit 0123456789abcdef0123456789abcdef01234567
+Author: Carol <carol@example.com>
+Date:   Fri Jan 3 07:00:00 2025 +0100
+
+    docs: clarify setup
+";
+
+#[test]
+fn reads_full_git_log() {
+    let ingestion = CommitIngestion::from_git_log(FULL_LOG);
+
+    let commits = ingestion.commits();
+    assert_eq!(commits.len(), 3);
+    assert_eq!(commits[0].description(), "add pagination");
+    assert_eq!(commits[0].body(), Some("Pages default to 50 items."));
+    assert_eq!(commits[0].author(), Some(CONTRIBUTOR_ALICE));
+    assert_eq!(
+        commits[1].hash(),
+        Some("abcdef0123456789abcdef0123456789abcdef01")
+    );
+    assert_eq!(commits[1].description(), "reject expired tokens");
+    assert_eq!(
+        ingestion.skipped(),
+        &["Merge branch 'fix-login'".to_string()]
+    );
+}
+
+#[test]
+fn modifications_and_contributors_skip_internal_commits() {
+    let ingestion = CommitIngestion::from_git_log(FULL_LOG);
+
+    let modifications = ingestion.modifications();
+    assert_eq!(modifications.len(), 2);
+    assert_eq!(
+        modifications[0].get_category(),
+        &ModificationCategory::NewCapability
+    );
+    assert_eq!(
+        modifications[1].get_category(),
+        &ModificationCategory::CorrectionApplied
+    );
+    // Carol only touched docs
+    assert_eq!(
+        ingestion.contributors(),
+        vec![CONTRIBUTOR_ALICE.to_string(), CONTRIBUTOR_BOB.to_string()]
+    );
+}
+
+#[test]
+fn reads_oneline_git_log() {
+    let ingestion = CommitIngestion::from_git_log(
+        "a1b2c3d (HEAD -> main, tag: v1.1.0) fix(parser): trailing comma\n\
+         e4f5a6b feat!: new config format\n\
+         \n\
+         0f1e2d3 WIP\n",
+    );
+
+    let commits = ingestion.commits();
+    assert_eq!(commits.len(), 2);
+    assert_eq!(commits[0].hash(), Some("a1b2c3d"));
+    assert_eq!(commits[0].scope(), Some("parser"));
+    assert!(commits[1].is_breaking());
+    assert_eq!(ingestion.skipped(), &["WIP".to_string()]);
+}
+
+#[test]
+fn reads_subject_only_log() {
+    let ingestion = CommitIngestion::from_git_log("perf: cache lookups\nchore: bump deps\n");
+    assert_eq!(ingestion.commits().len(), 2);
+    assert_eq!(ingestion.commits()[0].hash(), None);
+    assert_eq!(ingestion.modifications().len(), 1);
+}
+
+#[test]
+fn suggests_strongest_bump() {
+    let current = ReleaseId::build(1, 4, 0);
+    let bump = |log: &str| CommitIngestion::from_git_log(log).suggested_bump(&current);
+
+    assert_eq!(bump("fix: a\nperf: b"), Some(ReleaseBump::Correction));
+    assert_eq!(bump("fix: a\nfeat: b"), Some(ReleaseBump::Feature));
+    assert_eq!(bump("feat: a\nfix!: b"), Some(ReleaseBump::Major));
+    assert_eq!(bump("docs: a\nci: b"), None);
+    assert_eq!(bump(""), None);
+}
+
+#[test]
+fn breaking_change_before_one_zero_is_feature_bump() {
+    let ingestion = CommitIngestion::from_git_log("feat!: redesign");
+    assert_eq!(
+        ingestion.suggested_bump(&ReleaseId::build(0, 3, 1)),
+        Some(ReleaseBump::Feature)
+    );
+}
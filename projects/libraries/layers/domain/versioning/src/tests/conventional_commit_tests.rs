@@ -0,0 +1,117 @@
+use crate::commit_parse_error::CommitParseError;
+use crate::conventional_commit::ConventionalCommit;
+use crate::modification_category::ModificationCategory;
+use crate::release_bump::ReleaseBump;
+
+fn parse(message: &str) -> ConventionalCommit {
+    ConventionalCommit::parse(message).expect("failed to parse commit")
+}
+
+#[test]
+fn parses_header_parts() {
+    let commit = parse("feat(api): add pagination");
+    assert_eq!(commit.kind(), "feat");
+    assert_eq!(commit.scope(), Some("api"));
+    assert_eq!(commit.description(), "add pagination");
+    assert!(!commit.is_breaking());
+    assert_eq!(commit.body(), None);
+}
+
+#[test]
+fn exclamation_mark_marks_breaking() {
+    let commit = parse("refactor(core)!: drop legacy loader");
+    assert!(commit.is_breaking());
+    assert_eq!(
+        commit.category(),
+        Some(ModificationCategory::BreakingModification)
+    );
+    assert_eq!(commit.bump(), Some(ReleaseBump::Major));
+}
+
+#[test]
+fn parses_body_and_footers() {
+    let commit = parse(
+        "fix: handle empty input\n\nThe parser crashed on empty files.\n\nSecond paragraph.\n\nReviewed-by: Alice\nRefs #42\nBREAKING CHANGE: empty input now returns an error\n  instead of an empty list",
+    );
+    assert_eq!(
+        commit.body(),
+        Some("The parser crashed on empty files.\n\nSecond paragraph.")
+    );
+    assert_eq!(
+        commit.footers(),
+        &[
+            ("Reviewed-by".to_string(), "Alice".to_string()),
+            ("Refs".to_string(), "42".to_string()),
+            (
+                "BREAKING CHANGE".to_string(),
+                "empty input now returns an error\ninstead of an empty list".to_string()
+            ),
+        ]
+    );
+    assert!(commit.is_breaking());
+}
+
+#[test]
+fn classifies_commit_types() {
+    let cases = [
+        ("feat: a", Some(ModificationCategory::NewCapability)),
+        ("Fix: a", Some(ModificationCategory::CorrectionApplied)),
+        (
+            "fix(security): a",
+            Some(ModificationCategory::SecurityUpdate),
+        ),
+        ("security: a", Some(ModificationCategory::SecurityUpdate)),
+        ("perf: a", Some(ModificationCategory::Enhancement)),
+        ("refactor: a", Some(ModificationCategory::Enhancement)),
+        (
+            "deprecate: a",
+            Some(ModificationCategory::DeprecationNotice),
+        ),
+        ("docs: a", None),
+        ("chore(deps): a", None),
+    ];
+    for (message, category) in cases {
+        assert_eq!(parse(message).category(), category, "{}", message);
+    }
+}
+
+#[test]
+fn bump_follows_category() {
+    assert_eq!(parse("feat: a").bump(), Some(ReleaseBump::Feature));
+    assert_eq!(parse("perf: a").bump(), Some(ReleaseBump::Correction));
+    assert_eq!(parse("ci: a").bump(), None);
+}
+
+#[test]
+fn modification_carries_scope_and_hash() {
+    let commit = parse("feat(ui): dark mode").with_hash("1a2b3c4d5e".to_string());
+    let modification = commit.to_modification().expect("feat has a category");
+    assert_eq!(modification.get_description(), "dark mode");
+    assert_eq!(modification.get_scope(), Some("ui"));
+    assert_eq!(modification.get_reference(), Some("1a2b3c4d5e"));
+    assert!(parse("test: more").to_modification().is_none());
+}
+
+#[test]
+fn rejects_non_conventional_messages() {
+    assert_eq!(
+        ConventionalCommit::parse("   "),
+        Err(CommitParseError::EmptyMessage)
+    );
+    assert!(matches!(
+        ConventionalCommit::parse("Merge branch 'main' into feature"),
+        Err(CommitParseError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        ConventionalCommit::parse("Update README: typo"),
+        Err(CommitParseError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        ConventionalCommit::parse("feat(): a"),
+        Err(CommitParseError::InvalidHeader(_))
+    ));
+    assert!(matches!(
+        ConventionalCommit::parse("feat:   "),
+        Err(CommitParseError::MissingDescription(_))
+    ));
+}
@@ -1,4 +1,6 @@
+use crate::changelog_document::ChangelogDocument;
 use crate::document_builder::DocumentBuilder;
+use crate::modification_category::ModificationCategory;
 use crate::output_format::OutputFormat;
 use crate::release_id::ReleaseId;
 use crate::tests::test_helpers::*;
@@ -142,3 +144,77 @@ fn multiple_releases_in_order() {
     assert!(pos_2_0_0 < pos_1_5_0);
     assert!(pos_1_5_0 < pos_1_0_0);
 }
+
+#[test]
+fn json_round_trips_as_changelog_document() {
+    let builder = DocumentBuilder::with_format(OutputFormat::Json);
+    let log = revision_log_with_entries(vec![
+        revision_entry_with_mods(
+            ReleaseId::parse_str("1.1.0-rc.1").expect("valid release id"),
+            vec![new_feature_mod("Search").with_scope("api".to_string())],
+        ),
+        revision_entry_with_contributors(release_id_1_0_0(), vec![CONTRIBUTOR_ALICE.to_string()]),
+    ]);
+
+    let output = builder.generate_document(&log);
+    let document: ChangelogDocument =
+        common_json::from_str(&output).expect("output should be valid json");
+
+    assert_eq!(document.project, TEST_PROJECT_NAME);
+    assert_eq!(document.releases.len(), 2);
+    let rc = &document.releases[0];
+    assert_eq!(rc.version, "1.1.0-rc.1");
+    assert_eq!(rc.date, "2025-01-01");
+    assert!(rc.pre_release);
+    assert_eq!(rc.changes[0].category, ModificationCategory::NewCapability);
+    assert_eq!(rc.changes[0].scope.as_deref(), Some("api"));
+    assert_eq!(document.releases[1].contributors, [CONTRIBUTOR_ALICE]);
+}
+
+#[test]
+fn keep_a_changelog_layout() {
+    let builder = DocumentBuilder::with_format(OutputFormat::KeepAChangelog);
+    let log = revision_log_with_entries(vec![revision_entry_with_mods(
+        release_id_2_0_0(),
+        vec![
+            bug_fix_mod("Crash on empty input")
+                .with_reference("abcdef0123456789abcdef0123456789abcdef01".to_string()),
+            enhancement_mod("Faster startup"),
+            breaking_mod("Config moved").with_scope("cli".to_string()),
+            new_feature_mod(MOD_NEW_FEATURE),
+            security_mod(MOD_SECURITY_PATCH),
+        ],
+    )]);
+
+    let output = builder.generate_document(&log);
+    let expected = format!(
+        "# Changelog
+
+All notable changes to {} will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+
+## [2.0.0] - 2025-01-01
+
+### Added
+
+- {}
+
+### Changed
+
+- **BREAKING:** **cli:** Config moved
+- Faster startup
+
+### Fixed
+
+- Crash on empty input (abcdef0)
+
+### Security
+
+- {}
+",
+        TEST_PROJECT_NAME, MOD_NEW_FEATURE, MOD_SECURITY_PATCH
+    );
+    assert_eq!(output, expected);
+}
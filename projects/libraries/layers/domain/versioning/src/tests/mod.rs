@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod test_helpers;
 
+#[cfg(test)]
+mod commit_ingestion_tests;
+#[cfg(test)]
+mod conventional_commit_tests;
 #[cfg(test)]
 mod document_builder_tests;
 #[cfg(test)]
@@ -1,4 +1,7 @@
+use crate::release_bump::ReleaseBump;
 use crate::release_id::ReleaseId;
+use crate::release_id_error::ReleaseIdError;
+use std::cmp::Ordering;
 
 #[test]
 fn can_create_release_id() {
@@ -96,3 +99,108 @@ fn invalid_format_rejected() {
     assert!(ReleaseId::parse_str("1.2.3.4").is_err());
     assert!(ReleaseId::parse_str("abc.def.ghi").is_err());
 }
+
+#[test]
+fn parses_pre_release_and_build_metadata() {
+    let parsed = ReleaseId::parse_str("2.1.0-rc.1+build.5").expect("failed to parse release id");
+    assert_eq!(parsed.second_tier(), 1);
+    assert_eq!(parsed.pre_release(), Some("rc.1"));
+    assert_eq!(parsed.build_metadata(), Some("build.5"));
+    assert_eq!(parsed.to_string(), "2.1.0-rc.1+build.5");
+}
+
+#[test]
+fn invalid_pre_release_rejected() {
+    assert!(matches!(
+        ReleaseId::parse_str("1.0.0-"),
+        Err(ReleaseIdError::InvalidPreRelease(_))
+    ));
+    assert!(matches!(
+        ReleaseId::parse_str("1.0.0-rc.01"),
+        Err(ReleaseIdError::InvalidPreRelease(_))
+    ));
+    assert!(matches!(
+        ReleaseId::parse_str("1.0.0+build..1"),
+        Err(ReleaseIdError::InvalidBuildMetadata(_))
+    ));
+    assert!(ReleaseId::parse_str("1.0.0+build.007").is_ok());
+}
+
+#[test]
+fn pre_release_precedence() {
+    let ordered: Vec<ReleaseId> = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+    ]
+    .iter()
+    .map(|s| s.parse().expect("failed to parse release id"))
+    .collect();
+
+    for pair in ordered.windows(2) {
+        assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+    }
+}
+
+#[test]
+fn build_metadata_ignored_for_precedence() {
+    let plain = ReleaseId::build(1, 0, 0);
+    let built = plain
+        .clone()
+        .with_build_metadata("sha.abc")
+        .expect("valid build metadata");
+    assert_eq!(plain.cmp_precedence(&built), Ordering::Equal);
+    assert_ne!(plain, built);
+}
+
+#[test]
+fn advancing_pre_release_finalizes_covered_bump() {
+    let rc: ReleaseId = "2.0.0-rc.2".parse().expect("failed to parse release id");
+    assert_eq!(rc.advance_major().to_string(), "2.0.0");
+    assert_eq!(rc.advance_correction().to_string(), "2.0.0");
+
+    let patch_rc: ReleaseId = "2.0.1-rc.1".parse().expect("failed to parse release id");
+    assert_eq!(patch_rc.advance_feature().to_string(), "2.1.0");
+}
+
+#[test]
+fn advance_pre_release_counts_on_channel() {
+    let release = ReleaseId::build(1, 4, 2);
+    let first = release
+        .advance_pre_release(ReleaseBump::Feature, "rc")
+        .expect("valid label");
+    assert_eq!(first.to_string(), "1.5.0-rc.1");
+
+    let second = first
+        .advance_pre_release(ReleaseBump::Correction, "rc")
+        .expect("valid label");
+    assert_eq!(second.to_string(), "1.5.0-rc.2");
+
+    let beta = second
+        .advance_pre_release(ReleaseBump::Feature, "beta")
+        .expect("valid label");
+    assert_eq!(beta.to_string(), "1.5.0-beta.1");
+
+    let major = beta
+        .advance_pre_release(ReleaseBump::Major, "rc")
+        .expect("valid label");
+    assert_eq!(major.to_string(), "2.0.0-rc.1");
+
+    assert!(
+        release
+            .advance_pre_release(ReleaseBump::Major, "r c")
+            .is_err()
+    );
+}
+
+#[test]
+fn legacy_serialized_release_id_loads() {
+    let parsed: ReleaseId = common_json::from_str(r#"{"tier_one":1,"tier_two":2,"tier_three":3}"#)
+        .expect("failed to deserialize release id");
+    assert_eq!(parsed, ReleaseId::build(1, 2, 3));
+}
@@ -1,3 +1,4 @@
+use crate::release_bump::ReleaseBump;
 use crate::release_id::ReleaseId;
 use crate::release_tracker::ReleaseTracker;
 use crate::tests::test_helpers::*;
@@ -115,3 +116,103 @@ fn loaded_tracker_preserves_history() {
     assert_eq!(loaded.log().get_entries().len(), 3); // Initial + 2 new
     assert_eq!(loaded.active_release().to_string(), "2.1.0");
 }
+
+#[test]
+fn ingest_git_log_registers_suggested_release() {
+    let mut tracker = ReleaseTracker::initialize(ALT_PROJECT_NAME.to_string());
+
+    let release = tracker
+        .ingest_git_log(
+            "a1b2c3d feat(api): add search\nb2c3d4e fix: typo in error\nc3d4e5f docs: readme",
+            None,
+        )
+        .expect("valid ingestion");
+
+    assert_eq!(release, Some(ReleaseId::build(1, 1, 0)));
+    let entry = tracker
+        .log()
+        .most_recent()
+        .expect("log should have entries");
+    assert_eq!(entry.get_release(), &ReleaseId::build(1, 1, 0));
+    assert_eq!(entry.get_modifications().len(), 2);
+    assert_eq!(
+        entry.get_modifications()[0].get_reference(),
+        Some("a1b2c3d")
+    );
+}
+
+#[test]
+fn ingest_without_changelog_commits_registers_nothing() {
+    let mut tracker = ReleaseTracker::initialize(ALT_PROJECT_NAME.to_string());
+
+    let release = tracker
+        .ingest_git_log("chore: tidy\nMerge branch 'main'", None)
+        .expect("valid ingestion");
+
+    assert_eq!(release, None);
+    assert_eq!(tracker.active_release(), &ReleaseId::initial());
+    assert_eq!(tracker.log().get_entries().len(), 1);
+}
+
+#[test]
+fn pre_releases_lead_to_final_release() {
+    let mut tracker = ReleaseTracker::initialize(ALT_PROJECT_NAME.to_string());
+
+    tracker
+        .ingest_git_log("feat!: new storage format", Some("rc"))
+        .expect("valid ingestion");
+    assert_eq!(tracker.active_release().to_string(), "2.0.0-rc.1");
+
+    tracker
+        .ingest_git_log("fix: migration of empty stores", Some("rc"))
+        .expect("valid ingestion");
+    assert_eq!(tracker.active_release().to_string(), "2.0.0-rc.2");
+
+    tracker.register_release(ReleaseBump::Major, vec![], vec![]);
+    assert_eq!(tracker.active_release().to_string(), "2.0.0");
+
+    let releases: Vec<String> = tracker
+        .log()
+        .get_entries()
+        .iter()
+        .map(|entry| entry.get_release().to_string())
+        .collect();
+    assert_eq!(releases, ["2.0.0", "2.0.0-rc.2", "2.0.0-rc.1", "1.0.0"]);
+}
+
+#[test]
+fn invalid_pre_release_label_leaves_tracker_unchanged() {
+    let mut tracker = ReleaseTracker::initialize(ALT_PROJECT_NAME.to_string());
+
+    assert!(
+        tracker
+            .register_pre_release(ReleaseBump::Feature, "not valid", vec![], vec![])
+            .is_err()
+    );
+    assert_eq!(tracker.active_release(), &ReleaseId::initial());
+    assert_eq!(tracker.log().get_entries().len(), 1);
+}
+
+#[test]
+fn pre_release_survives_persistence() {
+    let temp_file = NamedTempFile::new().expect("failed to create temp file");
+    let temp_path = temp_file.path();
+
+    let mut tracker = ReleaseTracker::initialize(TEST_PROJECT_NAME.to_string());
+    tracker
+        .register_pre_release(
+            ReleaseBump::Feature,
+            "beta",
+            vec![new_feature_mod(MOD_NEW_FEATURE).with_scope("cli".to_string())],
+            vec![],
+        )
+        .expect("valid label");
+    tracker
+        .persist_to_file(temp_path)
+        .expect("failed to persist tracker");
+
+    let loaded = ReleaseTracker::load_from_file(temp_path).expect("failed to load tracker");
+    assert_eq!(loaded.active_release().to_string(), "1.1.0-beta.1");
+    let entry = loaded.log().most_recent().expect("log should have entries");
+    assert_eq!(entry.get_modifications()[0].get_scope(), Some("cli"));
+}
@@ -35,7 +35,7 @@ fn category_labels_are_correct() {
 fn can_create_revision_entry() {
     let release = release_id_1_2_3();
     let timestamp = Utc::now();
-    let entry = RevisionEntry::create(release, timestamp);
+    let entry = RevisionEntry::create(release.clone(), timestamp);
 
     assert_eq!(entry.get_release(), &release);
     assert_eq!(entry.get_modifications().len(), 0);
@@ -121,7 +121,7 @@ fn can_find_specific_entry() {
     let target_release = ReleaseId::build(1, 5, 3);
 
     log.append_entry(basic_revision_entry(release_id_1_0_0()));
-    log.append_entry(basic_revision_entry(target_release));
+    log.append_entry(basic_revision_entry(target_release.clone()));
     log.append_entry(basic_revision_entry(release_id_2_0_0()));
 
     let found = log.find_entry(&target_release);
@@ -6,9 +6,16 @@ use std::path::Path;
 use tracing::warn;
 
 use crate::{
-    ai_error::AiError, ai_orchestrator::AiOrchestrator,
-    feedbacks::public_api_feedback::FeedbackInput, solver_strategy::SolverStrategy, task::Task,
+    ai_error::AiError,
+    ai_orchestrator::AiOrchestrator,
+    feedbacks::public_api_feedback::FeedbackInput,
+    feedbacks::store::{FeedbackQuery, FeedbackStore, RetrainReport},
+    hybrid_config::HybridConfig,
+    solver_strategy::SolverStrategy,
+    strategy_stats::StrategyStats,
+    task::Task,
     task_result::TaskResult,
+    task_type::TaskType,
 };
 
 pub struct AiBody {
@@ -63,6 +70,42 @@ impl AiBody {
         self.solve_with_strategy(task, SolverStrategy::Hybrid)
     }
 
+    /// Budget and acceptance rules used by `SolverStrategy::Hybrid`.
+    pub fn hybrid_config(&self) -> &HybridConfig {
+        &self.orchestrator.hybrid
+    }
+
+    pub fn set_hybrid_config(&mut self, config: HybridConfig) {
+        self.orchestrator.hybrid = config;
+    }
+
+    // --- Strategy selection ---
+
+    /// Makes automatic strategy selection reproducible from `seed`.
+    pub fn seed_strategy_selection(&mut self, seed: u64) {
+        self.orchestrator.dispatcher.reseed(seed);
+    }
+
+    /// Statistics the dispatcher learned for a task type, per strategy.
+    pub fn strategy_stats(&self, task_type: TaskType) -> Vec<(SolverStrategy, StrategyStats)> {
+        self.orchestrator.dispatcher.stats(task_type)
+    }
+
+    /// Loads strategy statistics from `path` if it exists and keeps the file
+    /// up to date after every solve and feedback, so selection improves across sessions.
+    pub fn persist_strategy_stats(&mut self, path: &Path) -> Result<(), AiError> {
+        self.orchestrator.dispatcher.persist_to(path)
+    }
+
+    pub fn save_strategy_stats(&self, path: &Path) -> Result<(), AiError> {
+        self.orchestrator.dispatcher.save(path)
+    }
+
+    /// Replaces the learned strategy statistics with the ones saved at `path`.
+    pub fn load_strategy_stats(&mut self, path: &Path) -> Result<(), AiError> {
+        self.orchestrator.dispatcher.load(path)
+    }
+
     // --- Feedback public API (primitives, not exposing internal types) ---
 
     /// Train (neural + symbolic) via simple verdict.
@@ -101,6 +144,40 @@ impl AiBody {
         self.orchestrator.adjust_with_trace(&req, result.trace())
     }
 
+    // --- Feedback store ---
+
+    /// Backs the feedback store with the JSONL file at `path`, loading the
+    /// records it holds and merging in the ones collected so far.
+    pub fn open_feedback_store(&mut self, path: &Path) -> Result<(), AiError> {
+        let mut store = FeedbackStore::open(path)?;
+        for record in self.orchestrator.feedback_store.records() {
+            store.insert(record.clone())?;
+        }
+        self.orchestrator.feedback_store = store;
+        Ok(())
+    }
+
+    /// Every feedback given through `adjust`, `adjust_with_result` and
+    /// `train_with_verdict`.
+    pub fn feedback_store(&self) -> &FeedbackStore {
+        &self.orchestrator.feedback_store
+    }
+
+    /// Mutable access for curation: inserting, removing or importing records.
+    pub fn feedback_store_mut(&mut self) -> &mut FeedbackStore {
+        &mut self.orchestrator.feedback_store
+    }
+
+    /// Replays the curated feedback matching `query` into the symbolic rule
+    /// weights and the neural model, which is saved to `model_path`.
+    pub fn retrain_from_store(
+        &mut self,
+        query: &FeedbackQuery,
+        model_path: &Path,
+    ) -> Result<RetrainReport, AiError> {
+        self.orchestrator.retrain_from_store(query, model_path)
+    }
+
     // --- Simplified API (unchanged on usage side) ---
 
     pub fn generate_code(&mut self, prompt: &str) -> Result<String, AiError> {
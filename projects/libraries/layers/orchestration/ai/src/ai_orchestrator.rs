@@ -4,9 +4,15 @@ use tracing::{debug, info};
 
 use crate::{
     ai_error::AiError,
+    candidate_scorer::CandidateScorer,
     dispatcher::Dispatcher,
     feedbacks::internal::internal_feedback_input::InternalFeedbackInput,
-    feedbacks::{ai_feedback::AiFeedback, public_api_feedback::FeedbackInput},
+    feedbacks::{
+        ai_feedback::AiFeedback,
+        public_api_feedback::FeedbackInput,
+        store::{FeedbackRecord, FeedbackStore},
+    },
+    hybrid_config::HybridConfig,
     solve_trace::SolveTrace,
     task::Task,
     task_result::TaskResult,
@@ -17,6 +23,9 @@ use crate::{
 pub(crate) struct AiOrchestrator {
     pub(crate) feedback: AiFeedback,
     pub(crate) dispatcher: Dispatcher,
+    pub(crate) hybrid: HybridConfig,
+    pub(crate) scorer: CandidateScorer,
+    pub(crate) feedback_store: FeedbackStore,
 }
 
 impl AiOrchestrator {
@@ -26,6 +35,9 @@ impl AiOrchestrator {
         Ok(Self {
             feedback: AiFeedback::new()?,
             dispatcher: Dispatcher::new(),
+            hybrid: HybridConfig::default(),
+            scorer: CandidateScorer::new()?,
+            feedback_store: FeedbackStore::new(),
         })
     }
 
@@ -58,6 +70,10 @@ impl AiOrchestrator {
     /// Only accepts primitives.
     pub(crate) fn adjust(&mut self, feedback_input: &FeedbackInput<'_>) -> Result<(), AiError> {
         let internal_feedback_input = InternalFeedbackInput::from(feedback_input);
+        self.feedback_store.insert(FeedbackRecord::from_feedback(
+            &internal_feedback_input,
+            None,
+        ))?;
         self.feedback.adjust(&internal_feedback_input)
     }
 
@@ -68,6 +84,13 @@ impl AiOrchestrator {
         trace: &SolveTrace,
     ) -> Result<(), AiError> {
         let internal_feedback_input = InternalFeedbackInput::from(feedback_input);
+        self.feedback_store.insert(FeedbackRecord::from_feedback(
+            &internal_feedback_input,
+            Some(trace),
+        ))?;
+        if let Some(reward) = internal_feedback_input.verdict.reward() {
+            self.dispatcher.record_feedback(trace, reward);
+        }
         self.feedback
             .adjust_with_trace(&internal_feedback_input, trace)
     }
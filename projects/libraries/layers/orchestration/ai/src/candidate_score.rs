@@ -0,0 +1,24 @@
+// projects/libraries/layers/orchestration/ai/src/candidate_score.rs
+
+/// How good a solver output looks to `CodeValidator`.
+///
+/// For code-producing tasks, `total` is 0 when the output does not parse,
+/// otherwise `0.5 + 0.3 * confidence + 0.2 / (1 + issues)`, halved when the
+/// optional compile check failed. For other tasks it is the confidence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateScore {
+    /// Whether the output parsed as a Rust file.
+    pub parsed: bool,
+
+    /// Semantic issues and warnings reported by the validator.
+    pub issues: usize,
+
+    /// Result of the compile check; `None` when it did not run or did not
+    /// finish before the deadline.
+    pub compiled: Option<bool>,
+
+    /// Confidence reported by the solver.
+    pub confidence: f64,
+
+    pub total: f64,
+}
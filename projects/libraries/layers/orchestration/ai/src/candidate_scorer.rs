@@ -0,0 +1,158 @@
+// projects/libraries/layers/orchestration/ai/src/candidate_scorer.rs
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use symbolic::SymbolicError;
+use symbolic::validator::CodeValidator;
+use tracing::{debug, warn};
+
+use crate::ai_error::AiError;
+use crate::candidate_score::CandidateScore;
+
+/// Interval at which a running compile check is polled.
+const COMPILE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Distinguishes the scratch directories of concurrent compile checks.
+static COMPILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Scores hybrid candidates with the symbolic `CodeValidator`.
+pub(crate) struct CandidateScorer {
+    validator: CodeValidator,
+}
+
+impl CandidateScorer {
+    pub fn new() -> Result<Self, AiError> {
+        let validator =
+            CodeValidator::new().map_err(|e| SymbolicError::ValidationError(e.to_string()))?;
+        Ok(Self { validator })
+    }
+
+    /// Scores `output`. The compile check, when enabled, is killed at `deadline`.
+    pub fn score(
+        &self,
+        output: &str,
+        confidence: f64,
+        produces_code: bool,
+        compile_check: bool,
+        deadline: Instant,
+    ) -> CandidateScore {
+        let confidence = if confidence.is_finite() {
+            confidence.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        if !produces_code {
+            return CandidateScore {
+                parsed: false,
+                issues: 0,
+                compiled: None,
+                confidence,
+                total: confidence,
+            };
+        }
+
+        let (parsed, issues) = match self.validator.validate(output) {
+            Ok(validation) if validation.is_valid => (true, validation.warnings.len()),
+            Ok(_) => (false, 0),
+            Err(e) => {
+                warn!(?e, "Candidate validation error");
+                (false, 0)
+            }
+        };
+        if !parsed {
+            return CandidateScore {
+                parsed,
+                issues,
+                compiled: None,
+                confidence,
+                total: 0.0,
+            };
+        }
+
+        let compiled = if compile_check {
+            compile(output, deadline)
+        } else {
+            None
+        };
+        let mut total = 0.5 + 0.3 * confidence + 0.2 / (1.0 + issues as f64);
+        if compiled == Some(false) {
+            total *= 0.5;
+        }
+        debug!(issues, ?compiled, total, "Scored hybrid candidate");
+
+        CandidateScore {
+            parsed,
+            issues,
+            compiled,
+            confidence,
+            total,
+        }
+    }
+}
+
+/// Type-checks `code` as a library crate with `rustc`. `None` when `rustc`
+/// could not run or did not finish before `deadline`.
+fn compile(code: &str, deadline: Instant) -> Option<bool> {
+    let dir = std::env::temp_dir().join(format!(
+        "ai_hybrid_{}_{}_{}",
+        std::process::id(),
+        COMPILE_COUNTER.fetch_add(1, Ordering::Relaxed),
+        &blake3::hash(code.as_bytes()).to_hex()[..16]
+    ));
+    let result = fs::create_dir_all(&dir)
+        .and_then(|_| fs::write(dir.join("lib.rs"), code))
+        .map_err(|e| warn!(?e, "Failed to write compile check sources"))
+        .ok()
+        .and_then(|_| run_rustc(&dir, deadline));
+    if let Err(e) = fs::remove_dir_all(&dir) {
+        debug!(?e, "Failed to remove compile check directory");
+    }
+    result
+}
+
+fn run_rustc(dir: &Path, deadline: Instant) -> Option<bool> {
+    let mut child = match Command::new("rustc")
+        .args([
+            "--edition",
+            "2024",
+            "--crate-type",
+            "lib",
+            "--emit",
+            "metadata",
+        ])
+        .args(["--cap-lints", "allow", "--out-dir"])
+        .arg(dir)
+        .arg(dir.join("lib.rs"))
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            warn!(?e, "Failed to start rustc for the compile check");
+            return None;
+        }
+    };
+
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Some(status.success()),
+            Ok(None) if Instant::now() >= deadline => {
+                debug!("Compile check hit the hybrid deadline");
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+            Ok(None) => thread::sleep(COMPILE_POLL_INTERVAL),
+            Err(e) => {
+                warn!(?e, "Failed to wait for rustc");
+                let _ = child.kill();
+                return None;
+            }
+        }
+    }
+}
@@ -1,91 +1,265 @@
-// projects/libraries/layers/orchestration/ai/src/dispatch.rs
-use crate::SolverStrategy;
+// projects/libraries/layers/orchestration/ai/src/dispatcher.rs
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common_json::{from_json_str, to_string_pretty};
+use rand::{SeedableRng, rngs::StdRng};
+use rand_distr::{Beta, Distribution};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::ai_error::AiError;
+use crate::solve_trace::SolveTrace;
+use crate::solver_strategy::SolverStrategy;
+use crate::strategy_stats::StrategyStats;
 use crate::task::Task;
+use crate::task_result::TaskResult;
 use crate::task_type::TaskType;
 
-#[allow(dead_code)]
-pub struct Dispatcher {
-    // Statistics to improve decision-making
-    symbolic_success_rate: f64,
-    neural_success_rate: f64,
+/// Version of the persisted statistics file.
+const STATS_VERSION: u32 = 1;
+
+/// Prior successes of the strategy each task type used before statistics
+/// existed, so a fresh dispatcher mostly behaves like the old fixed table.
+const DEFAULT_STRATEGY_PRIOR: f64 = 6.0;
+
+/// A feedback verdict weighs as much as this many solve outcomes.
+const FEEDBACK_WEIGHT: f64 = 2.0;
+
+/// Largest score penalty applied to slow strategies.
+const LATENCY_WEIGHT: f64 = 0.1;
+
+/// Mean latency at which a strategy gets the full penalty.
+const LATENCY_BUDGET_MS: f64 = 10_000.0;
+
+/// On-disk layout of the statistics.
+#[derive(Serialize, Deserialize)]
+struct StatsFile {
+    version: u32,
+    entries: Vec<StatsEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StatsEntry {
+    task_type: TaskType,
+    strategy: SolverStrategy,
+    stats: StrategyStats,
+}
+
+/// Chooses a `SolverStrategy` per task with Thompson sampling over the
+/// success statistics of each (task type, strategy) pair.
+pub(crate) struct Dispatcher {
+    stats: HashMap<(TaskType, SolverStrategy), StrategyStats>,
+    rng: StdRng,
+    /// File the statistics are written to after every update.
+    persist_path: Option<PathBuf>,
 }
 
 impl Dispatcher {
     pub fn new() -> Self {
         Self {
-            symbolic_success_rate: 0.9,
-            neural_success_rate: 0.8,
+            stats: HashMap::new(),
+            rng: StdRng::from_rng(&mut rand::rng()),
+            persist_path: None,
         }
     }
 
-    /// Decides which strategy to use based on the task type
-    pub fn decide_strategy(&self, task: &Task, neural_available: bool) -> SolverStrategy {
-        match task.task_type() {
+    /// Restarts sampling from `seed`, making the next decisions reproducible.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// The strategy a task type used before any statistics were collected.
+    pub fn default_strategy(task_type: TaskType) -> SolverStrategy {
+        match task_type {
             // Static analysis = always symbolic
             TaskType::CodeAnalysis | TaskType::Linting | TaskType::Documentation => {
                 SolverStrategy::SymbolicOnly
             }
-
             // Simple generation = symbolic first
-            TaskType::SimpleGeneration => {
-                if neural_available {
-                    SolverStrategy::SymbolicThenNeural
-                } else {
-                    SolverStrategy::SymbolicOnly
-                }
-            }
-
+            TaskType::SimpleGeneration => SolverStrategy::SymbolicThenNeural,
             // Complex generation = neural with validation
-            TaskType::ComplexGeneration => {
-                if neural_available {
-                    SolverStrategy::NeuralWithSymbolicValidation
-                } else {
-                    SolverStrategy::SymbolicOnly
-                }
-            }
-
+            TaskType::ComplexGeneration => SolverStrategy::NeuralWithSymbolicValidation,
             // Refactoring = hybrid (best of both)
-            TaskType::Refactoring => {
-                if neural_available {
-                    SolverStrategy::Hybrid
-                } else {
-                    SolverStrategy::SymbolicOnly
-                }
-            }
-
+            TaskType::Refactoring => SolverStrategy::Hybrid,
             // Intent parsing = neural if available
-            TaskType::IntentParsing => {
-                if neural_available {
-                    SolverStrategy::NeuralOnly
-                } else {
-                    SolverStrategy::SymbolicOnly
-                }
-            }
+            TaskType::IntentParsing => SolverStrategy::NeuralOnly,
         }
     }
 
-    /// Updates success statistics
-    #[allow(dead_code)]
-    pub fn update_stats(&mut self, strategy: SolverStrategy, success: bool) {
-        // Update using moving average
-        let alpha = 0.1; // Adjustment factor for moving average
+    /// Strategies worth trying for a task type.
+    pub fn candidates(task_type: TaskType, neural_available: bool) -> Vec<SolverStrategy> {
+        if !neural_available || Self::default_strategy(task_type) == SolverStrategy::SymbolicOnly {
+            return vec![SolverStrategy::SymbolicOnly];
+        }
+        SolverStrategy::ALL.to_vec()
+    }
 
-        match strategy {
-            SolverStrategy::SymbolicOnly | SolverStrategy::SymbolicThenNeural => {
-                if success {
-                    self.symbolic_success_rate = self.symbolic_success_rate * (1.0 - alpha) + alpha;
-                } else {
-                    self.symbolic_success_rate *= 1.0 - alpha;
-                }
-            }
-            SolverStrategy::NeuralOnly | SolverStrategy::NeuralWithSymbolicValidation => {
-                if success {
-                    self.neural_success_rate = self.neural_success_rate * (1.0 - alpha) + alpha;
-                } else {
-                    self.neural_success_rate *= 1.0 - alpha;
-                }
+    /// Decides which strategy to use, sampling a success rate for every
+    /// candidate from its Beta posterior and taking the best latency-adjusted one.
+    pub fn decide_strategy(&mut self, task: &Task, neural_available: bool) -> SolverStrategy {
+        let task_type = *task.task_type();
+        let candidates = Self::candidates(task_type, neural_available);
+        if let [only] = candidates.as_slice() {
+            return *only;
+        }
+
+        let mut best = (Self::default_strategy(task_type), f64::NEG_INFINITY);
+        for strategy in candidates {
+            let stats = self.stats_for(task_type, strategy);
+            let sampled = match Beta::new(stats.alpha, stats.beta) {
+                Ok(beta) => beta.sample(&mut self.rng),
+                Err(_) => stats.success_rate(),
+            };
+            let latency_penalty = stats
+                .mean_latency_ms
+                .map(|latency| LATENCY_WEIGHT * (latency / LATENCY_BUDGET_MS).min(1.0))
+                .unwrap_or(0.0);
+            let score = sampled - latency_penalty;
+            debug!(
+                ?strategy,
+                sampled, latency_penalty, "Sampled strategy score"
+            );
+            if score > best.1 {
+                best = (strategy, score);
             }
-            _ => {}
+        }
+        best.0
+    }
+
+    /// Statistics of a strategy, or its prior when it never ran.
+    pub fn stats_for(&self, task_type: TaskType, strategy: SolverStrategy) -> StrategyStats {
+        self.stats
+            .get(&(task_type, strategy))
+            .cloned()
+            .unwrap_or_else(|| Self::prior(task_type, strategy))
+    }
+
+    /// Statistics of every strategy that ran or received feedback for a task type.
+    pub fn stats(&self, task_type: TaskType) -> Vec<(SolverStrategy, StrategyStats)> {
+        SolverStrategy::ALL
+            .into_iter()
+            .filter_map(|strategy| {
+                self.stats
+                    .get(&(task_type, strategy))
+                    .map(|stats| (strategy, stats.clone()))
+            })
+            .collect()
+    }
+
+    fn prior(task_type: TaskType, strategy: SolverStrategy) -> StrategyStats {
+        if strategy == Self::default_strategy(task_type) {
+            StrategyStats::with_prior(DEFAULT_STRATEGY_PRIOR, 1.0)
+        } else {
+            StrategyStats::new()
+        }
+    }
+
+    fn entry(&mut self, task_type: TaskType, strategy: SolverStrategy) -> &mut StrategyStats {
+        self.stats
+            .entry((task_type, strategy))
+            .or_insert_with(|| Self::prior(task_type, strategy))
+    }
+
+    /// Credits a successful solve. The reward is the result's confidence,
+    /// halved when symbolic validation rejected the neural output.
+    pub fn record_success(&mut self, result: &TaskResult) {
+        let trace = result.trace();
+        let Some(task_type) = trace.task_type else {
+            return;
+        };
+        let mut reward = result.confidence;
+        if trace.validation_passed == Some(false) {
+            reward *= 0.5;
+        }
+        self.entry(task_type, trace.strategy)
+            .record_run(true, reward, trace.elapsed_ms);
+        self.autosave();
+    }
+
+    pub fn record_failure(
+        &mut self,
+        task_type: TaskType,
+        strategy: SolverStrategy,
+        elapsed_ms: Option<u64>,
+    ) {
+        self.entry(task_type, strategy)
+            .record_run(false, 0.0, elapsed_ms);
+        self.autosave();
+    }
+
+    /// Credits a feedback verdict to the strategy that produced the result.
+    pub fn record_feedback(&mut self, trace: &SolveTrace, reward: f64) {
+        let Some(task_type) = trace.task_type else {
+            return;
+        };
+        self.entry(task_type, trace.strategy)
+            .record_feedback(reward, FEEDBACK_WEIGHT);
+        self.autosave();
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AiError> {
+        let mut entries: Vec<StatsEntry> = self
+            .stats
+            .iter()
+            .map(|((task_type, strategy), stats)| StatsEntry {
+                task_type: *task_type,
+                strategy: *strategy,
+                stats: stats.clone(),
+            })
+            .collect();
+        // Stable file contents for identical statistics
+        entries.sort_by_key(|entry| {
+            (
+                TaskType::ALL.iter().position(|t| *t == entry.task_type),
+                SolverStrategy::ALL
+                    .iter()
+                    .position(|s| *s == entry.strategy),
+            )
+        });
+
+        let json = to_string_pretty(&StatsFile {
+            version: STATS_VERSION,
+            entries,
+        })?;
+        fs::write(path, json).map_err(|e| AiError::TaskError(e.to_string()))
+    }
+
+    /// Replaces the statistics with the ones saved at `path`.
+    pub fn load(&mut self, path: &Path) -> Result<(), AiError> {
+        let json = fs::read_to_string(path).map_err(|e| AiError::TaskError(e.to_string()))?;
+        let file: StatsFile = from_json_str(&json)?;
+        if file.version != STATS_VERSION {
+            return Err(AiError::TaskError(format!(
+                "Unsupported strategy statistics version {} (expected {})",
+                file.version, STATS_VERSION
+            )));
+        }
+        self.stats = file
+            .entries
+            .into_iter()
+            .map(|entry| ((entry.task_type, entry.strategy), entry.stats))
+            .collect();
+        Ok(())
+    }
+
+    /// Loads the statistics at `path` if the file exists, then saves them
+    /// there after every update.
+    pub fn persist_to(&mut self, path: &Path) -> Result<(), AiError> {
+        if path.exists() {
+            self.load(path)?;
+        }
+        self.persist_path = Some(path.to_path_buf());
+        Ok(())
+    }
+
+    fn autosave(&self) {
+        if let Some(path) = &self.persist_path
+            && let Err(e) = self.save(path)
+        {
+            // Losing an update must not fail the solve that produced it
+            warn!(?e, path = %path.display(), "Failed to persist strategy statistics");
         }
     }
 }
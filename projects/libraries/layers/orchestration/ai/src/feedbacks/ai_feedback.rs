@@ -1,4 +1,6 @@
 // projects/libraries/layers/orchestration/ai/src/ai_feedback.rs
+use std::sync::Arc;
+
 use neural::NeuralSolver;
 use neural::feedback::FeedbackType;
 use symbolic::{feedback_symbolic::SymbolicFeedback, symbolic_solver::SymbolicSolver};
@@ -26,7 +28,8 @@ use crate::solver_strategy::SolverStrategy;
 /// - `NeuralSolver`: Handles neural model training and adjustments.
 /// - `InternalFeedbackInput`: Provides structured feedback data to process.
 pub(crate) struct AiFeedback {
-    symbolic: SymbolicSolver,
+    /// Shared with a hybrid race that may outlive the solve that started it
+    symbolic: Arc<SymbolicSolver>,
     neural: Option<NeuralSolver>,
 }
 
@@ -35,7 +38,7 @@ impl AiFeedback {
     /// The neural solver is not loaded by default and must be initialized separately.
     pub(crate) fn new() -> Result<Self, AiError> {
         Ok(Self {
-            symbolic: SymbolicSolver::new()?,
+            symbolic: Arc::new(SymbolicSolver::new()?),
             neural: None,
         })
     }
@@ -59,9 +62,10 @@ impl AiFeedback {
         &self.symbolic
     }
 
-    /// Provides mutable access to the symbolic solver.
+    /// Provides mutable access to the symbolic solver, copying it first if
+    /// a hybrid race still runs on the current one.
     pub(crate) fn symbolic_mut(&mut self) -> &mut SymbolicSolver {
-        &mut self.symbolic
+        Arc::make_mut(&mut self.symbolic)
     }
     #[allow(dead_code)]
     /// Provides immutable access to the neural solver, if it is loaded.
@@ -76,6 +80,11 @@ impl AiFeedback {
             .ok_or_else(|| AiError::TaskError("Neural model not loaded".into()))
     }
 
+    /// Splits the borrow so both solvers can run at the same time.
+    pub(crate) fn solvers_mut(&mut self) -> (&Arc<SymbolicSolver>, Option<&mut NeuralSolver>) {
+        (&self.symbolic, self.neural.as_mut())
+    }
+
     /// Saves the neural model from the `AiFeedback` instance.
     ///
     /// # Parameters:
@@ -198,7 +207,8 @@ impl AiFeedback {
             )),
         };
 
-        self.symbolic.adjust_rules(task_input, symbolic_feedback)?;
+        self.symbolic_mut()
+            .adjust_rules(task_input, symbolic_feedback)?;
         Ok(())
     }
 
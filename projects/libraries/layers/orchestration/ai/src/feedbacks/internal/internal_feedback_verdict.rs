@@ -35,6 +35,19 @@ impl<'a> InternalFeedbackVerdict<'a> {
         }
     }
 
+    /// Reward credited to the strategy that produced the judged output,
+    /// or `None` when there is no verdict.
+    pub fn reward(&self) -> Option<f64> {
+        match self {
+            InternalFeedbackVerdict::Correct => Some(1.0),
+            InternalFeedbackVerdict::Partial { .. } => Some(0.5),
+            InternalFeedbackVerdict::Incorrect { .. } | InternalFeedbackVerdict::Rejected => {
+                Some(0.0)
+            }
+            InternalFeedbackVerdict::NoFeedback => None,
+        }
+    }
+
     /// Returns whether the verdict is positive (e.g., Correct).
     pub fn is_positive(&self) -> bool {
         matches!(self, InternalFeedbackVerdict::Correct)
@@ -3,3 +3,4 @@ pub mod ai_feedback;
 pub mod conversions;
 pub mod internal;
 pub mod public_api_feedback;
+pub mod store;
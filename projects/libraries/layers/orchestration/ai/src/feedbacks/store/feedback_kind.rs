@@ -0,0 +1,41 @@
+// projects/libraries/layers/orchestration/ai/src/feedbacks/store/feedback_kind.rs
+use serde::{Deserialize, Serialize};
+
+use crate::feedbacks::internal::internal_feedback_verdict::InternalFeedbackVerdict;
+
+/// Verdict of a stored feedback record, without its payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackKind {
+    Correct,
+    Incorrect,
+    Partial,
+    Rejected,
+    NoFeedback,
+}
+
+impl FeedbackKind {
+    /// Whether replaying the verdict can teach the solvers anything.
+    pub fn is_trainable(&self) -> bool {
+        matches!(
+            self,
+            FeedbackKind::Correct | FeedbackKind::Incorrect | FeedbackKind::Partial
+        )
+    }
+
+    pub fn is_positive(&self) -> bool {
+        matches!(self, FeedbackKind::Correct)
+    }
+}
+
+impl From<&InternalFeedbackVerdict<'_>> for FeedbackKind {
+    fn from(verdict: &InternalFeedbackVerdict<'_>) -> Self {
+        match verdict {
+            InternalFeedbackVerdict::Correct => FeedbackKind::Correct,
+            InternalFeedbackVerdict::Incorrect { .. } => FeedbackKind::Incorrect,
+            InternalFeedbackVerdict::Partial { .. } => FeedbackKind::Partial,
+            InternalFeedbackVerdict::Rejected => FeedbackKind::Rejected,
+            InternalFeedbackVerdict::NoFeedback => FeedbackKind::NoFeedback,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// projects/libraries/layers/orchestration/ai/src/feedbacks/store/feedback_query.rs
+use crate::feedbacks::store::{FeedbackKind, FeedbackRecord};
+use crate::solve_winner::SolveWinner;
+use crate::solver_strategy::SolverStrategy;
+use crate::task_type::TaskType;
+
+/// Filter over stored feedback. Every criterion that is set must match;
+/// the default query matches everything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeedbackQuery {
+    pub task_type: Option<TaskType>,
+    /// Accepted verdicts; empty accepts all.
+    pub kinds: Vec<FeedbackKind>,
+    pub strategy: Option<SolverStrategy>,
+    pub winner: Option<SolveWinner>,
+    /// Inclusive lower bound on `created_at_unix_secs`.
+    pub since_unix_secs: Option<u64>,
+    /// Exclusive upper bound on `created_at_unix_secs`.
+    pub until_unix_secs: Option<u64>,
+    /// Substring of the task input or input.
+    pub input_contains: Option<String>,
+    pub source: Option<String>,
+    pub min_occurrences: u32,
+    pub limit: Option<usize>,
+}
+
+impl FeedbackQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records carrying a trainable verdict (correct, incorrect, partial).
+    pub fn trainable() -> Self {
+        Self::new().kinds([
+            FeedbackKind::Correct,
+            FeedbackKind::Incorrect,
+            FeedbackKind::Partial,
+        ])
+    }
+
+    pub fn task_type(mut self, task_type: TaskType) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub fn kind(mut self, kind: FeedbackKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    pub fn kinds(mut self, kinds: impl IntoIterator<Item = FeedbackKind>) -> Self {
+        self.kinds.extend(kinds);
+        self
+    }
+
+    pub fn strategy(mut self, strategy: SolverStrategy) -> Self {
+        self.strategy = Some(strategy);
+        self
+    }
+
+    pub fn winner(mut self, winner: SolveWinner) -> Self {
+        self.winner = Some(winner);
+        self
+    }
+
+    pub fn since(mut self, unix_secs: u64) -> Self {
+        self.since_unix_secs = Some(unix_secs);
+        self
+    }
+
+    pub fn until(mut self, unix_secs: u64) -> Self {
+        self.until_unix_secs = Some(unix_secs);
+        self
+    }
+
+    pub fn input_contains(mut self, text: impl Into<String>) -> Self {
+        self.input_contains = Some(text.into());
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn min_occurrences(mut self, occurrences: u32) -> Self {
+        self.min_occurrences = occurrences;
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn matches(&self, record: &FeedbackRecord) -> bool {
+        let path = record.solver_path.as_ref();
+        self.task_type.is_none_or(|t| record.task_type == Some(t))
+            && (self.kinds.is_empty() || self.kinds.contains(&record.kind))
+            && self
+                .strategy
+                .is_none_or(|s| path.is_some_and(|p| p.strategy == s))
+            && self
+                .winner
+                .is_none_or(|w| path.is_some_and(|p| p.winner == Some(w)))
+            && self
+                .since_unix_secs
+                .is_none_or(|since| record.created_at_unix_secs >= since)
+            && self
+                .until_unix_secs
+                .is_none_or(|until| record.created_at_unix_secs < until)
+            && self
+                .input_contains
+                .as_deref()
+                .is_none_or(|text| record.task_input.contains(text) || record.input.contains(text))
+            && self
+                .source
+                .as_deref()
+                .is_none_or(|source| record.source.as_deref() == Some(source))
+            && record.occurrences >= self.min_occurrences
+    }
+}
@@ -0,0 +1,135 @@
+// projects/libraries/layers/orchestration/ai/src/feedbacks/store/feedback_record.rs
+use serde::{Deserialize, Serialize};
+
+use crate::feedbacks::internal::internal_feedback_input::InternalFeedbackInput;
+use crate::feedbacks::store::{FeedbackKind, SolverPath};
+use crate::solve_trace::SolveTrace;
+use crate::task_type::TaskType;
+
+/// One judged solver output, as kept by `FeedbackStore`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedbackRecord {
+    /// Content hash of the judged output and its verdict; see `FeedbackRecord::compute_id`.
+    pub id: String,
+
+    pub task_type: Option<TaskType>,
+    pub task_input: String,
+    pub input: String,
+    pub generated_output: String,
+    pub kind: FeedbackKind,
+
+    /// Expected output of an `Incorrect` verdict or correction of a `Partial` one.
+    pub expected_output: Option<String>,
+
+    pub solver_path: Option<SolverPath>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    pub created_at_unix_secs: u64,
+    pub updated_at_unix_secs: u64,
+
+    /// How many times the same feedback was submitted.
+    pub occurrences: u32,
+}
+
+impl FeedbackRecord {
+    pub fn new(
+        task_type: Option<TaskType>,
+        task_input: impl Into<String>,
+        input: impl Into<String>,
+        generated_output: impl Into<String>,
+        kind: FeedbackKind,
+        expected_output: Option<String>,
+    ) -> Self {
+        let now = now_unix_secs();
+        let mut record = Self {
+            id: String::new(),
+            task_type,
+            task_input: task_input.into(),
+            input: input.into(),
+            generated_output: generated_output.into(),
+            kind,
+            expected_output,
+            solver_path: None,
+            confidence: None,
+            rationale: None,
+            source: None,
+            created_at_unix_secs: now,
+            updated_at_unix_secs: now,
+            occurrences: 1,
+        };
+        record.id = record.compute_id();
+        record
+    }
+
+    pub(crate) fn from_feedback(
+        feedback: &InternalFeedbackInput<'_>,
+        trace: Option<&SolveTrace>,
+    ) -> Self {
+        let mut record = Self::new(
+            trace.and_then(|trace| trace.task_type),
+            feedback.task_input.as_ref(),
+            feedback.input.as_ref(),
+            feedback.generated_output.as_ref(),
+            FeedbackKind::from(&feedback.verdict),
+            feedback.verdict.stable_payload().map(str::to_string),
+        );
+        record.solver_path = trace.map(SolverPath::from);
+        record.confidence = feedback.meta.confidence;
+        record.rationale = feedback.meta.rationale.as_deref().map(str::to_string);
+        record.source = feedback.meta.source.as_deref().map(str::to_string);
+        record
+    }
+
+    pub fn with_solver_path(mut self, solver_path: SolverPath) -> Self {
+        self.solver_path = Some(solver_path);
+        self
+    }
+
+    /// Hash of the fields that make two submissions the same feedback: the
+    /// task, the judged output and the verdict. Timestamps, metadata and the
+    /// solver path are left out.
+    pub fn compute_id(&self) -> String {
+        let task_type = self
+            .task_type
+            .map(|task_type| format!("{:?}", task_type))
+            .unwrap_or_default();
+        let kind = format!("{:?}", self.kind);
+
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"ai_feedback_record_v1\0");
+        for field in [
+            task_type.as_str(),
+            self.task_input.as_str(),
+            self.input.as_str(),
+            self.generated_output.as_str(),
+            kind.as_str(),
+            self.expected_output.as_deref().unwrap_or(""),
+        ] {
+            hasher.update(&(field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+
+    /// Output the solvers should have produced, when the verdict tells.
+    pub fn target_output(&self) -> Option<&str> {
+        match self.kind {
+            FeedbackKind::Correct => Some(&self.generated_output),
+            FeedbackKind::Incorrect | FeedbackKind::Partial => self.expected_output.as_deref(),
+            FeedbackKind::Rejected | FeedbackKind::NoFeedback => None,
+        }
+    }
+}
+
+pub(crate) fn now_unix_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,238 @@
+// projects/libraries/layers/orchestration/ai/src/feedbacks/store/feedback_store.rs
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use common_json::{from_json_str, to_string};
+use neural::feedback::{FeedbackType, UserFeedback};
+use tracing::{debug, info};
+
+use crate::ai_error::AiError;
+use crate::feedbacks::store::feedback_record::now_unix_secs;
+use crate::feedbacks::store::{FeedbackKind, FeedbackQuery, FeedbackRecord, ImportSummary};
+
+/// Deduplicated collection of feedback records.
+///
+/// Records are identified by `FeedbackRecord::id`; submitting the same
+/// feedback again only bumps its occurrence count and update time. A store
+/// opened on a file rewrites it as JSON lines after every change.
+#[derive(Debug, Default)]
+pub struct FeedbackStore {
+    records: Vec<FeedbackRecord>,
+    index: HashMap<String, usize>,
+    path: Option<PathBuf>,
+}
+
+impl FeedbackStore {
+    /// In-memory store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store backed by the JSONL file at `path`, loading it if it exists.
+    pub fn open(path: &Path) -> Result<Self, AiError> {
+        let mut store = Self::new();
+        if path.exists() {
+            let summary = store.merge_jsonl(path)?;
+            info!(
+                records = store.len(),
+                duplicates = summary.duplicates,
+                path = %path.display(),
+                "Opened feedback store"
+            );
+        }
+        store.path = Some(path.to_path_buf());
+        Ok(store)
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&FeedbackRecord> {
+        self.index.get(id).map(|&i| &self.records[i])
+    }
+
+    /// Records in insertion order.
+    pub fn records(&self) -> &[FeedbackRecord] {
+        &self.records
+    }
+
+    /// Adds a record, or merges it into the stored one with the same id.
+    /// Returns whether the record was new.
+    pub fn insert(&mut self, record: FeedbackRecord) -> Result<bool, AiError> {
+        let inserted = self.merge(record);
+        self.persist()?;
+        Ok(inserted)
+    }
+
+    pub fn remove(&mut self, id: &str) -> Result<Option<FeedbackRecord>, AiError> {
+        let Some(position) = self.index.remove(id) else {
+            return Ok(None);
+        };
+        let record = self.records.remove(position);
+        self.reindex();
+        self.persist()?;
+        Ok(Some(record))
+    }
+
+    /// Matching records, oldest first (ties broken by id), up to the query limit.
+    pub fn query(&self, query: &FeedbackQuery) -> Vec<&FeedbackRecord> {
+        let mut matching: Vec<&FeedbackRecord> = self
+            .records
+            .iter()
+            .filter(|record| query.matches(record))
+            .collect();
+        matching
+            .sort_by(|a, b| (a.created_at_unix_secs, &a.id).cmp(&(b.created_at_unix_secs, &b.id)));
+        if let Some(limit) = query.limit {
+            matching.truncate(limit);
+        }
+        matching
+    }
+
+    /// Writes the records matching `query` to `path` as JSON lines, in
+    /// `query` order. Returns the number of records written.
+    pub fn export_jsonl(&self, path: &Path, query: &FeedbackQuery) -> Result<usize, AiError> {
+        let records = self.query(query);
+        write_jsonl(path, records.iter().copied())?;
+        Ok(records.len())
+    }
+
+    /// Merges the records of a JSONL file into the store. Lines written by
+    /// `AiBody::append_training_example` are accepted too.
+    pub fn import_jsonl(&mut self, path: &Path) -> Result<ImportSummary, AiError> {
+        let summary = self.merge_jsonl(path)?;
+        self.persist()?;
+        Ok(summary)
+    }
+
+    fn merge_jsonl(&mut self, path: &Path) -> Result<ImportSummary, AiError> {
+        let file = File::open(path).map_err(|e| AiError::TaskError(e.to_string()))?;
+        let mut summary = ImportSummary::default();
+        for (number, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(|e| AiError::TaskError(e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record = parse_line(&line).map_err(|e| {
+                AiError::TaskError(format!("{}:{}: {}", path.display(), number + 1, e))
+            })?;
+            if self.merge(record) {
+                summary.inserted += 1;
+            } else {
+                summary.duplicates += 1;
+            }
+        }
+        Ok(summary)
+    }
+
+    fn merge(&mut self, mut record: FeedbackRecord) -> bool {
+        // Ids from files are not trusted; they are recomputed from the content
+        record.id = record.compute_id();
+        let Some(&position) = self.index.get(&record.id) else {
+            self.index.insert(record.id.clone(), self.records.len());
+            self.records.push(record);
+            return true;
+        };
+
+        let existing = &mut self.records[position];
+        debug!(id = %existing.id, "Merging duplicate feedback");
+        existing.occurrences = existing
+            .occurrences
+            .saturating_add(record.occurrences.max(1));
+        existing.created_at_unix_secs = existing
+            .created_at_unix_secs
+            .min(record.created_at_unix_secs);
+        existing.updated_at_unix_secs = existing
+            .updated_at_unix_secs
+            .max(record.updated_at_unix_secs)
+            .max(now_unix_secs());
+        if existing.solver_path.is_none() {
+            existing.solver_path = record.solver_path;
+        }
+        existing.confidence = record.confidence.or(existing.confidence);
+        existing.rationale = record.rationale.or(existing.rationale.take());
+        existing.source = record.source.or(existing.source.take());
+        false
+    }
+
+    fn reindex(&mut self) {
+        self.index = self
+            .records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| (record.id.clone(), i))
+            .collect();
+    }
+
+    fn persist(&self) -> Result<(), AiError> {
+        match &self.path {
+            Some(path) => write_jsonl(path, self.records.iter()),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Result<FeedbackRecord, AiError> {
+    match from_json_str::<FeedbackRecord>(line) {
+        Ok(record) => Ok(record),
+        Err(record_error) => match from_json_str::<UserFeedback>(line) {
+            Ok(legacy) => Ok(from_user_feedback(legacy)),
+            Err(_) => Err(record_error.into()),
+        },
+    }
+}
+
+/// Converts a replay buffer line, which carries no task information.
+fn from_user_feedback(feedback: UserFeedback) -> FeedbackRecord {
+    let metadata = feedback.feedback_type.metadata().clone();
+    let (kind, expected_output) = match feedback.feedback_type {
+        FeedbackType::Correct { .. } => (FeedbackKind::Correct, None),
+        FeedbackType::Incorrect {
+            expected_output, ..
+        } => (FeedbackKind::Incorrect, Some(expected_output)),
+        FeedbackType::Partial { correction, .. } => (FeedbackKind::Partial, Some(correction)),
+    };
+    let mut record = FeedbackRecord::new(
+        None,
+        feedback.input.clone(),
+        feedback.input,
+        feedback.generated_output,
+        kind,
+        expected_output,
+    );
+    if feedback.timestamp_unix_secs != 0 {
+        record.created_at_unix_secs = feedback.timestamp_unix_secs;
+        record.updated_at_unix_secs = feedback.timestamp_unix_secs;
+    }
+    record.confidence = metadata.confidence;
+    record.rationale = metadata.rationale;
+    record.source = metadata.source;
+    record
+}
+
+/// Writes through a temporary file so a crash never leaves a truncated store.
+fn write_jsonl<'a>(
+    path: &Path,
+    records: impl Iterator<Item = &'a FeedbackRecord>,
+) -> Result<(), AiError> {
+    let io_error = |e: std::io::Error| AiError::TaskError(e.to_string());
+    let tmp_path = path.with_extension("jsonl.tmp");
+    let mut writer = BufWriter::new(File::create(&tmp_path).map_err(io_error)?);
+    for record in records {
+        writeln!(writer, "{}", to_string(record)?).map_err(io_error)?;
+    }
+    writer.flush().map_err(io_error)?;
+    drop(writer);
+    fs::rename(&tmp_path, path).map_err(io_error)
+}
@@ -0,0 +1,11 @@
+// projects/libraries/layers/orchestration/ai/src/feedbacks/store/import_summary.rs
+
+/// Outcome of `FeedbackStore::import_jsonl`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// Records that were not in the store yet.
+    pub inserted: usize,
+
+    /// Records merged into an existing one.
+    pub duplicates: usize,
+}
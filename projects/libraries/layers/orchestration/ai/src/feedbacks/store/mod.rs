@@ -0,0 +1,16 @@
+// projects/libraries/layers/orchestration/ai/src/feedbacks/store/mod.rs
+pub mod feedback_kind;
+pub mod feedback_query;
+pub mod feedback_record;
+pub mod feedback_store;
+pub mod import_summary;
+pub mod retrain_report;
+pub mod solver_path;
+
+pub use feedback_kind::FeedbackKind;
+pub use feedback_query::FeedbackQuery;
+pub use feedback_record::FeedbackRecord;
+pub use feedback_store::FeedbackStore;
+pub use import_summary::ImportSummary;
+pub use retrain_report::RetrainReport;
+pub use solver_path::SolverPath;
@@ -0,0 +1,18 @@
+// projects/libraries/layers/orchestration/ai/src/feedbacks/store/retrain_report.rs
+
+/// Outcome of `AiBody::retrain_from_store`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetrainReport {
+    /// Trainable records selected by the query.
+    pub records: usize,
+
+    /// Symbolic templates adjusted, summed over all records.
+    pub symbolic_adjustments: usize,
+
+    /// Distinct target outputs the neural model was trained on.
+    pub neural_examples: usize,
+
+    /// Whether the neural model was trained and saved. False when no model
+    /// is loaded or there was nothing to train on.
+    pub neural_trained: bool,
+}
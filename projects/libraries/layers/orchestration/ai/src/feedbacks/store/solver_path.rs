@@ -0,0 +1,26 @@
+// projects/libraries/layers/orchestration/ai/src/feedbacks/store/solver_path.rs
+use serde::{Deserialize, Serialize};
+
+use crate::solve_trace::SolveTrace;
+use crate::solve_winner::SolveWinner;
+use crate::solver_strategy::SolverStrategy;
+
+/// How the judged output was produced, condensed from its `SolveTrace`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SolverPath {
+    pub strategy: SolverStrategy,
+    pub winner: Option<SolveWinner>,
+    pub fallback_used: bool,
+    pub validation_passed: Option<bool>,
+}
+
+impl From<&SolveTrace> for SolverPath {
+    fn from(trace: &SolveTrace) -> Self {
+        Self {
+            strategy: trace.strategy,
+            winner: trace.winner,
+            fallback_used: trace.fallback_used,
+            validation_passed: trace.validation_passed,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// projects/libraries/layers/orchestration/ai/src/hybrid_config.rs
+use std::time::Duration;
+
+/// Budget and acceptance rules of the `Hybrid` strategy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HybridConfig {
+    /// Time both solvers get before the best finished candidate is used.
+    pub deadline: Duration,
+
+    /// A candidate scoring at least this much is accepted as soon as it
+    /// finishes, and the other solver is cancelled.
+    pub accept_score: f64,
+
+    /// Compile code candidates with `rustc` while scoring. Off by default
+    /// since it needs a toolchain and costs hundreds of milliseconds.
+    pub compile_check: bool,
+}
+
+impl Default for HybridConfig {
+    fn default() -> Self {
+        Self {
+            deadline: Duration::from_secs(10),
+            accept_score: 0.95,
+            compile_check: false,
+        }
+    }
+}
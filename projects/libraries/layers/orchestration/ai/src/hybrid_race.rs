@@ -0,0 +1,193 @@
+// projects/libraries/layers/orchestration/ai/src/hybrid_race.rs
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Instant;
+
+use tracing::{debug, info, warn};
+
+use crate::candidate_score::CandidateScore;
+use crate::candidate_scorer::CandidateScorer;
+use crate::hybrid_config::HybridConfig;
+use crate::solve_winner::SolveWinner;
+
+/// Output of one solver, before scoring.
+#[derive(Debug, Clone)]
+pub(crate) struct Attempt {
+    pub output: String,
+    pub confidence: f64,
+}
+
+/// What happened to one side of the race.
+#[derive(Debug, Clone, Default)]
+pub(crate) enum Entry {
+    /// Still running when the race ended; it was told to stop.
+    #[default]
+    Cancelled,
+    /// No solver of this kind is loaded, so it did not take part.
+    NotRun,
+    Failed(String),
+    Finished {
+        attempt: Attempt,
+        score: CandidateScore,
+        elapsed_ms: u64,
+    },
+}
+
+impl Entry {
+    pub fn score(&self) -> Option<f64> {
+        match self {
+            Entry::Finished { score, .. } => Some(score.total),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RaceReport {
+    pub symbolic: Entry,
+    pub neural: Entry,
+    /// A candidate reached `accept_score` before the other solver finished.
+    pub accepted_early: bool,
+    pub deadline_exceeded: bool,
+}
+
+impl RaceReport {
+    pub fn entry(&self, solver: SolveWinner) -> &Entry {
+        match solver {
+            SolveWinner::Symbolic => &self.symbolic,
+            SolveWinner::Neural => &self.neural,
+        }
+    }
+
+    fn entry_mut(&mut self, solver: SolveWinner) -> &mut Entry {
+        match solver {
+            SolveWinner::Symbolic => &mut self.symbolic,
+            SolveWinner::Neural => &mut self.neural,
+        }
+    }
+
+    /// Best-scored finished candidate; symbolic wins ties.
+    pub fn winner(&self) -> Option<SolveWinner> {
+        match (self.symbolic.score(), self.neural.score()) {
+            (Some(symbolic), Some(neural)) if neural > symbolic => Some(SolveWinner::Neural),
+            (Some(_), _) => Some(SolveWinner::Symbolic),
+            (None, Some(_)) => Some(SolveWinner::Neural),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Runs the solvers on their own threads and scores each candidate as it
+/// arrives. Without a neural solver, symbolic runs alone under the deadline.
+///
+/// The race ends when every solver finished, when a candidate reaches
+/// `config.accept_score`, or at `config.deadline`; solvers still running
+/// then have their cancel flag set. Neural stops at its flag and is waited
+/// for. Symbolic runs on a detached thread instead, since it may not check
+/// its flag: the race returns without it and its late result is dropped.
+pub(crate) fn run_race<S, N>(
+    config: &HybridConfig,
+    scorer: &CandidateScorer,
+    produces_code: bool,
+    symbolic: S,
+    neural: Option<N>,
+) -> RaceReport
+where
+    S: FnOnce(&AtomicBool) -> Result<Attempt, String> + Send + 'static,
+    N: FnOnce(&AtomicBool) -> Result<Attempt, String> + Send,
+{
+    let started = Instant::now();
+    let deadline = started + config.deadline;
+    let cancel_symbolic = Arc::new(AtomicBool::new(false));
+    let cancel_neural = AtomicBool::new(false);
+    let cancel = |solver: SolveWinner| match solver {
+        SolveWinner::Symbolic => cancel_symbolic.store(true, Ordering::Relaxed),
+        SolveWinner::Neural => cancel_neural.store(true, Ordering::Relaxed),
+    };
+
+    thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+        let symbolic_tx = tx.clone();
+        let symbolic_cancel = Arc::clone(&cancel_symbolic);
+        thread::spawn(move || {
+            let result = symbolic(&symbolic_cancel);
+            // The race may be over already
+            let _ = symbolic_tx.send((SolveWinner::Symbolic, result, started.elapsed()));
+        });
+
+        let mut report = RaceReport::default();
+        let mut pending = vec![SolveWinner::Symbolic];
+        match neural {
+            Some(neural) => {
+                let neural_cancel = &cancel_neural;
+                scope.spawn(move || {
+                    let result = neural(neural_cancel);
+                    let _ = tx.send((SolveWinner::Neural, result, started.elapsed()));
+                });
+                pending.push(SolveWinner::Neural);
+            }
+            None => {
+                drop(tx);
+                report.neural = Entry::NotRun;
+            }
+        }
+        while !pending.is_empty() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let (solver, result, elapsed) = match rx.recv_timeout(remaining) {
+                Ok(message) => message,
+                Err(RecvTimeoutError::Timeout) => {
+                    info!(?pending, "Hybrid deadline reached");
+                    report.deadline_exceeded = true;
+                    break;
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    warn!(?pending, "Hybrid solver thread ended without a result");
+                    for solver in pending.drain(..) {
+                        *report.entry_mut(solver) =
+                            Entry::Failed("Solver thread panicked".to_string());
+                    }
+                    break;
+                }
+            };
+            pending.retain(|p| *p != solver);
+
+            let entry = match result {
+                Ok(attempt) => {
+                    let score = scorer.score(
+                        &attempt.output,
+                        attempt.confidence,
+                        produces_code,
+                        config.compile_check,
+                        deadline,
+                    );
+                    debug!(?solver, total = score.total, "Hybrid candidate finished");
+                    if score.total >= config.accept_score && !pending.is_empty() {
+                        report.accepted_early = true;
+                    }
+                    Entry::Finished {
+                        attempt,
+                        score,
+                        elapsed_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+                    }
+                }
+                Err(e) => {
+                    debug!(?solver, error = %e, "Hybrid candidate failed");
+                    Entry::Failed(e)
+                }
+            };
+            *report.entry_mut(solver) = entry;
+            if report.accepted_early {
+                break;
+            }
+        }
+
+        // Whatever is still running lost
+        for solver in pending {
+            cancel(solver);
+            *report.entry_mut(solver) = Entry::Cancelled;
+        }
+        report
+    })
+}
@@ -3,30 +3,45 @@
 // Public modules
 pub mod ai_body;
 pub mod ai_error;
+pub mod candidate_score;
 pub mod feedbacks;
+pub mod hybrid_config;
 pub mod solve_decision;
 pub mod solve_error_kind;
 pub mod solve_trace;
 pub mod solve_winner;
 pub mod solver_strategy;
+pub mod strategy_stats;
 pub mod task;
 pub mod task_result;
 pub mod task_type;
 
 // Internal modules (not part of public API)
 mod ai_orchestrator;
+mod candidate_scorer;
 mod dispatcher;
+mod hybrid_race;
 mod solver;
 mod training;
 
 // Re-exports for ergonomic usage
 pub use ai_body::AiBody;
 pub use ai_error::AiError;
+pub use candidate_score::CandidateScore;
+pub use feedbacks::store::{
+    FeedbackKind, FeedbackQuery, FeedbackRecord, FeedbackStore, ImportSummary, RetrainReport,
+    SolverPath,
+};
+pub use hybrid_config::HybridConfig;
 pub use solve_decision::SolveDecision;
 pub use solve_error_kind::SolveErrorKind;
 pub use solve_trace::SolveTrace;
 pub use solve_winner::SolveWinner;
 pub use solver_strategy::SolverStrategy;
+pub use strategy_stats::StrategyStats;
 pub use task::Task;
 pub use task_result::TaskResult;
 pub use task_type::TaskType;
+
+#[cfg(test)]
+mod tests;
@@ -17,9 +17,16 @@ pub enum SolveDecision {
     /// Neural output rejected/invalid; fallback to symbolic.
     NeuralInvalidFallbackToSymbolic,
 
-    /// Hybrid compared both and chose the best confidence.
+    /// Hybrid scored both and chose the best.
     HybridBestOfBoth,
 
+    /// Hybrid accepted the first candidate that scored high enough and
+    /// cancelled the other solver.
+    HybridAcceptedFirst,
+
+    /// Hybrid ran out of time and used the best candidate it had.
+    HybridDeadlineReached,
+
     /// Only one solver was available or succeeded.
     OnlyOneSolverAvailable,
 }
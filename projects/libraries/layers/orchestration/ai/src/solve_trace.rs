@@ -2,6 +2,7 @@
 use crate::solve_decision::SolveDecision;
 use crate::solve_winner::SolveWinner;
 use crate::solver_strategy::SolverStrategy;
+use crate::task_type::TaskType;
 
 /// Structured trace of how a task was solved.
 /// This is the missing piece to make neurosymbolic feedback "path-aware".
@@ -9,6 +10,12 @@ use crate::solver_strategy::SolverStrategy;
 pub struct SolveTrace {
     pub strategy: SolverStrategy,
 
+    /// Type of the solved task, used to credit the strategy that handled it.
+    pub task_type: Option<TaskType>,
+
+    /// Wall time spent solving, in milliseconds.
+    pub elapsed_ms: Option<u64>,
+
     /// Which solver produced the final output.
     pub winner: Option<SolveWinner>,
 
@@ -23,10 +30,22 @@ pub struct SolveTrace {
     pub symbolic_failed: bool,
     pub neural_failed: bool,
 
+    /// Whether each solver was stopped, or its result discarded, because
+    /// the other one won or the hybrid deadline passed.
+    pub symbolic_cancelled: bool,
+    pub neural_cancelled: bool,
+
     /// Confidence values when available.
     pub symbolic_confidence: Option<f64>,
     pub neural_confidence: Option<f64>,
 
+    /// Hybrid candidate scores (see `CandidateScore`) when available.
+    pub symbolic_score: Option<f64>,
+    pub neural_score: Option<f64>,
+
+    /// Whether the hybrid deadline passed before both solvers finished.
+    pub deadline_exceeded: bool,
+
     /// Whether the symbolic system validated a neural output.
     pub symbolic_validated_neural: bool,
 
@@ -41,14 +60,21 @@ impl SolveTrace {
     pub fn new(strategy: SolverStrategy) -> Self {
         Self {
             strategy,
+            task_type: None,
+            elapsed_ms: None,
             winner: None,
             fallback_used: false,
             symbolic_ran: false,
             neural_ran: false,
             symbolic_failed: false,
             neural_failed: false,
+            symbolic_cancelled: false,
+            neural_cancelled: false,
             symbolic_confidence: None,
             neural_confidence: None,
+            symbolic_score: None,
+            neural_score: None,
+            deadline_exceeded: false,
             symbolic_validated_neural: false,
             validation_passed: None,
             decision: SolveDecision::default(),
@@ -1,6 +1,18 @@
 // projects/libraries/layers/orchestration/ai/src/solve_winner.rs
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SolveWinner {
     Symbolic,
     Neural,
 }
+
+impl SolveWinner {
+    /// The competing solver
+    pub fn other(&self) -> Self {
+        match self {
+            SolveWinner::Symbolic => SolveWinner::Neural,
+            SolveWinner::Neural => SolveWinner::Symbolic,
+        }
+    }
+}
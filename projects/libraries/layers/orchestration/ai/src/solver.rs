@@ -1,11 +1,20 @@
 //projects/libraries/layers/orchestration/ai/src/solver.rs
 use neural::NeuralError;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::Instant;
 use tracing::{info, warn};
 
 use crate::{
-    ai_error::AiError, ai_orchestrator::AiOrchestrator, solve_decision::SolveDecision,
-    solve_trace::SolveTrace, solve_winner::SolveWinner, solver_strategy::SolverStrategy,
-    task::Task, task_result::TaskResult,
+    ai_error::AiError,
+    ai_orchestrator::AiOrchestrator,
+    hybrid_race::{Attempt, Entry, run_race},
+    solve_decision::SolveDecision,
+    solve_trace::SolveTrace,
+    solve_winner::SolveWinner,
+    solver_strategy::SolverStrategy,
+    task::Task,
+    task_result::TaskResult,
 };
 
 impl AiOrchestrator {
@@ -25,12 +34,31 @@ impl AiOrchestrator {
         task: &Task,
         strategy: SolverStrategy,
     ) -> Result<TaskResult, AiError> {
-        match strategy {
+        let started = Instant::now();
+        let result = match strategy {
             SolverStrategy::SymbolicOnly => self.solve_symbolic(task),
             SolverStrategy::NeuralOnly => self.solve_neural(task),
             SolverStrategy::SymbolicThenNeural => self.solve_symbolic_then_neural(task),
             SolverStrategy::NeuralWithSymbolicValidation => self.solve_neural_with_validation(task),
             SolverStrategy::Hybrid => self.solve_hybrid(task),
+        };
+        let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        match result {
+            Ok(mut result) => {
+                result.trace.task_type = Some(*task.task_type());
+                result.trace.elapsed_ms = Some(elapsed_ms);
+                self.dispatcher.record_success(&result);
+                Ok(result)
+            }
+            Err(e) => {
+                // A missing model says nothing about how well the strategy works
+                if !strategy.uses_neural() || self.feedback.has_neural() {
+                    self.dispatcher
+                        .record_failure(*task.task_type(), strategy, Some(elapsed_ms));
+                }
+                Err(e)
+            }
         }
     }
 
@@ -197,86 +225,126 @@ impl AiOrchestrator {
     pub(crate) fn solve_hybrid(&mut self, task: &Task) -> Result<TaskResult, AiError> {
         info!("Running hybrid strategy");
 
-        let symbolic_result = {
-            self.feedback
-                .symbolic()
-                .solve(task.input(), task.task_type_str(), task.context())
-        };
-
-        let neural_result = match self.feedback.neural_mut() {
-            Ok(neural) => neural.solve(task.input()),
-            Err(_) => Err(NeuralError::ModelNotLoaded),
-        };
+        let (symbolic, neural) = self.feedback.solvers_mut();
+        let symbolic = Arc::clone(symbolic);
+        let input = task.input().to_string();
+        let task_type = task.task_type_str().to_string();
+        let context = task.context().map(str::to_string);
+        let neural_available = neural.is_some();
+        let report = run_race(
+            &self.hybrid,
+            &self.scorer,
+            task.task_type().produces_code(),
+            move |_| {
+                // Symbolic solving cannot be interrupted; the race stops
+                // waiting for it at the deadline
+                symbolic
+                    .solve(&input, &task_type, context.as_deref())
+                    .map(|result| Attempt {
+                        output: result.output,
+                        confidence: result.confidence,
+                    })
+                    .map_err(|e| e.to_string())
+            },
+            neural.map(|neural| {
+                |cancel: &AtomicBool| {
+                    neural
+                        .solve_cancellable(task.input(), cancel)
+                        .map(|result| Attempt {
+                            output: result.output,
+                            confidence: result.confidence,
+                        })
+                        .map_err(|e| e.to_string())
+                }
+            }),
+        );
 
-        match (symbolic_result, neural_result) {
-            (Ok(sym), Ok(neu)) => {
-                let mut trace = SolveTrace::new(SolverStrategy::Hybrid);
-                trace.symbolic_ran = true;
-                trace.neural_ran = true;
-                trace.symbolic_confidence = Some(sym.confidence);
-                trace.neural_confidence = Some(neu.confidence);
-                trace.decision = SolveDecision::HybridBestOfBoth;
-                if sym.confidence >= neu.confidence {
-                    info!(
-                        "Symbolic won: {:.2} vs {:.2}",
-                        sym.confidence, neu.confidence
-                    );
-                    trace.winner = Some(SolveWinner::Symbolic);
-                    Ok(TaskResult::new(
-                        sym.output,
-                        sym.confidence,
-                        SolverStrategy::Hybrid,
-                        Some(format!("Symbolic (conf: {:.2})", sym.confidence)),
-                        trace,
-                    ))
-                } else {
-                    info!("Neural won: {:.2} vs {:.2}", neu.confidence, sym.confidence);
-                    trace.winner = Some(SolveWinner::Neural);
-                    Ok(TaskResult::new(
-                        neu.output,
-                        neu.confidence,
-                        SolverStrategy::Hybrid,
-                        Some(format!("Neural (conf: {:.2})", neu.confidence)),
-                        trace,
-                    ))
+        let mut trace = SolveTrace::new(SolverStrategy::Hybrid);
+        trace.symbolic_ran = true;
+        trace.neural_ran = neural_available;
+        trace.deadline_exceeded = report.deadline_exceeded;
+        for (solver, entry) in [
+            (SolveWinner::Symbolic, &report.symbolic),
+            (SolveWinner::Neural, &report.neural),
+        ] {
+            let (failed, cancelled, confidence, score) = match entry {
+                Entry::Finished { attempt, score, .. } => {
+                    (false, false, Some(attempt.confidence), Some(score.total))
+                }
+                Entry::Failed(_) => (true, false, None, None),
+                Entry::Cancelled => (false, true, None, None),
+                Entry::NotRun => (false, false, None, None),
+            };
+            match solver {
+                SolveWinner::Symbolic => {
+                    trace.symbolic_failed = failed;
+                    trace.symbolic_cancelled = cancelled;
+                    trace.symbolic_confidence = confidence;
+                    trace.symbolic_score = score;
+                }
+                SolveWinner::Neural => {
+                    trace.neural_failed = failed;
+                    trace.neural_cancelled = cancelled;
+                    trace.neural_confidence = confidence;
+                    trace.neural_score = score;
                 }
             }
-            (Ok(sym), Err(e)) => {
-                warn!(?e, "Neural failed, using symbolic");
-                let mut trace = SolveTrace::new(SolverStrategy::Hybrid);
-                trace.symbolic_ran = true;
-                trace.symbolic_confidence = Some(sym.confidence);
-                trace.fallback_used = true;
-                trace.winner = Some(SolveWinner::Symbolic);
-                trace.decision = SolveDecision::OnlyOneSolverAvailable;
-                Ok(TaskResult::new(
-                    sym.output,
-                    sym.confidence,
-                    SolverStrategy::Hybrid,
-                    Some("Only symbolic available".into()),
-                    trace,
-                ))
-            }
-            (Err(e), Ok(neu)) => {
-                warn!(?e, "Symbolic failed, using neural");
-                let mut trace = SolveTrace::new(SolverStrategy::Hybrid);
-                trace.neural_ran = true;
-                trace.neural_confidence = Some(neu.confidence);
-                trace.fallback_used = true;
-                trace.winner = Some(SolveWinner::Neural);
-                trace.decision = SolveDecision::OnlyOneSolverAvailable;
-                Ok(TaskResult::new(
-                    neu.output,
-                    neu.confidence,
-                    SolverStrategy::Hybrid,
-                    Some("Only neural available".into()),
-                    trace,
-                ))
-            }
-            (Err(e1), Err(e2)) => Err(AiError::TaskError(format!(
-                "Both failed. Symbolic: {}, Neural: {}",
-                e1, e2
-            ))),
         }
+
+        let Some(winner) = report.winner() else {
+            let describe = |entry: &Entry| match entry {
+                Entry::Failed(e) => e.clone(),
+                Entry::NotRun => NeuralError::ModelNotLoaded.to_string(),
+                _ => "cancelled at the deadline".to_string(),
+            };
+            return Err(AiError::TaskError(format!(
+                "Both failed. Symbolic: {}, Neural: {}",
+                describe(&report.symbolic),
+                describe(&report.neural)
+            )));
+        };
+        let Entry::Finished {
+            attempt,
+            score,
+            elapsed_ms,
+        } = report.entry(winner).clone()
+        else {
+            return Err(AiError::TaskError("Hybrid winner has no result".into()));
+        };
+
+        let loser_finished = matches!(report.entry(winner.other()), Entry::Finished { .. });
+        trace.winner = Some(winner);
+        trace.fallback_used = !loser_finished && !report.accepted_early;
+        trace.decision = if report.accepted_early {
+            SolveDecision::HybridAcceptedFirst
+        } else if report.deadline_exceeded {
+            SolveDecision::HybridDeadlineReached
+        } else if loser_finished {
+            SolveDecision::HybridBestOfBoth
+        } else {
+            SolveDecision::OnlyOneSolverAvailable
+        };
+        info!(
+            ?winner,
+            score = score.total,
+            elapsed_ms,
+            decision = ?trace.decision,
+            "Hybrid winner chosen"
+        );
+
+        let label = match winner {
+            SolveWinner::Symbolic => "Symbolic",
+            SolveWinner::Neural => "Neural",
+        };
+        Ok(TaskResult::new(
+            attempt.output,
+            attempt.confidence,
+            SolverStrategy::Hybrid,
+            Some(format!(
+                "{} (conf: {:.2}, score: {:.2})",
+                label, attempt.confidence, score.total
+            )),
+            trace,
+        ))
     }
 }
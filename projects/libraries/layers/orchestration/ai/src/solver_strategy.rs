@@ -1,6 +1,8 @@
-/// projects/libraries/layers/orchestration/ai/src/solver_strategy.rs
+// projects/libraries/layers/orchestration/ai/src/solver_strategy.rs
+use serde::{Deserialize, Serialize};
+
 /// Neuro-symbolic orchestration strategies
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SolverStrategy {
     /// Use only the symbolic solver
     SymbolicOnly,
@@ -17,3 +19,18 @@ pub enum SolverStrategy {
     /// Execute both in parallel, choose the best result
     Hybrid,
 }
+
+impl SolverStrategy {
+    pub const ALL: [SolverStrategy; 5] = [
+        SolverStrategy::SymbolicOnly,
+        SolverStrategy::NeuralOnly,
+        SolverStrategy::SymbolicThenNeural,
+        SolverStrategy::NeuralWithSymbolicValidation,
+        SolverStrategy::Hybrid,
+    ];
+
+    /// Whether the strategy needs a loaded neural model
+    pub fn uses_neural(&self) -> bool {
+        !matches!(self, SolverStrategy::SymbolicOnly)
+    }
+}
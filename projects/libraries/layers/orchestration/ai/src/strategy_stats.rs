@@ -0,0 +1,92 @@
+// projects/libraries/layers/orchestration/ai/src/strategy_stats.rs
+use serde::{Deserialize, Serialize};
+
+/// Smoothing factor of the latency moving average.
+const LATENCY_SMOOTHING: f64 = 0.2;
+
+/// Success and latency statistics of one strategy on one task type.
+///
+/// Success is tracked as a Beta(`alpha`, `beta`) posterior: each outcome adds
+/// its reward in `[0, 1]` to `alpha` and the remainder to `beta`, so the
+/// strategy chooser can sample from it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategyStats {
+    pub alpha: f64,
+    pub beta: f64,
+
+    /// Solve attempts, successful or not.
+    pub runs: u64,
+
+    /// Attempts that returned an error.
+    pub failures: u64,
+
+    /// Feedback verdicts credited to this strategy.
+    pub feedback_count: u64,
+
+    /// Exponential moving average of solve latency, once a run was timed.
+    pub mean_latency_ms: Option<f64>,
+}
+
+impl StrategyStats {
+    /// Uniform prior: nothing is known about the strategy yet.
+    pub fn new() -> Self {
+        Self::with_prior(1.0, 1.0)
+    }
+
+    pub fn with_prior(alpha: f64, beta: f64) -> Self {
+        Self {
+            alpha,
+            beta,
+            runs: 0,
+            failures: 0,
+            feedback_count: 0,
+            mean_latency_ms: None,
+        }
+    }
+
+    /// Records a solve attempt. `reward` is ignored for failed attempts.
+    pub fn record_run(&mut self, success: bool, reward: f64, latency_ms: Option<u64>) {
+        self.runs += 1;
+        if success {
+            self.add_reward(reward, 1.0);
+        } else {
+            self.failures += 1;
+            self.beta += 1.0;
+        }
+
+        if let Some(latency) = latency_ms {
+            let latency = latency as f64;
+            self.mean_latency_ms = Some(match self.mean_latency_ms {
+                Some(mean) => mean + LATENCY_SMOOTHING * (latency - mean),
+                None => latency,
+            });
+        }
+    }
+
+    /// Records a feedback verdict, counted `weight` times.
+    pub fn record_feedback(&mut self, reward: f64, weight: f64) {
+        self.feedback_count += 1;
+        self.add_reward(reward, weight);
+    }
+
+    fn add_reward(&mut self, reward: f64, weight: f64) {
+        let reward = if reward.is_finite() {
+            reward.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.alpha += reward * weight;
+        self.beta += (1.0 - reward) * weight;
+    }
+
+    /// Posterior mean of the success rate.
+    pub fn success_rate(&self) -> f64 {
+        self.alpha / (self.alpha + self.beta)
+    }
+}
+
+impl Default for StrategyStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
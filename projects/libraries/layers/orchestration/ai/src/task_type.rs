@@ -1,7 +1,7 @@
 // projects/libraries/layers/orchestration/ai/src/task_type.rs
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TaskType {
     CodeAnalysis,
     Linting,
@@ -11,3 +11,22 @@ pub enum TaskType {
     Refactoring,
     IntentParsing,
 }
+
+impl TaskType {
+    pub const ALL: [TaskType; 7] = [
+        TaskType::CodeAnalysis,
+        TaskType::Linting,
+        TaskType::Documentation,
+        TaskType::SimpleGeneration,
+        TaskType::ComplexGeneration,
+        TaskType::Refactoring,
+        TaskType::IntentParsing,
+    ];
+    /// Task types whose output is Rust source rather than a report
+    pub fn produces_code(&self) -> bool {
+        matches!(
+            self,
+            TaskType::SimpleGeneration | TaskType::ComplexGeneration | TaskType::Refactoring
+        )
+    }
+}
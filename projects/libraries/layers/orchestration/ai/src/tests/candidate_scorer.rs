@@ -0,0 +1,77 @@
+// projects/libraries/layers/orchestration/ai/src/tests/candidate_scorer.rs
+use std::time::{Duration, Instant};
+
+use crate::candidate_scorer::CandidateScorer;
+
+fn deadline() -> Instant {
+    Instant::now() + Duration::from_secs(30)
+}
+
+#[test]
+fn test_unparsable_code_scores_zero() {
+    let scorer = CandidateScorer::new().unwrap();
+    let score = scorer.score("fn broken( {", 1.0, true, false, deadline());
+    assert!(!score.parsed);
+    assert_eq!(score.total, 0.0);
+}
+
+#[test]
+fn test_clean_code_beats_code_with_issues() {
+    let scorer = CandidateScorer::new().unwrap();
+    let clean = scorer.score(
+        "fn add(a: i32, b: i32) -> i32 { a + b }",
+        0.8,
+        true,
+        false,
+        deadline(),
+    );
+    let noisy = scorer.score(
+        "fn add(a: i32, b: i32) -> i32 { println!(\"{}\", a); todo!() }",
+        0.8,
+        true,
+        false,
+        deadline(),
+    );
+    assert!(clean.parsed && noisy.parsed);
+    assert_eq!(clean.issues, 0);
+    assert!(noisy.issues > 0);
+    assert!(clean.total > noisy.total);
+    assert_eq!(clean.total, 0.5 + 0.3 * 0.8 + 0.2);
+}
+
+#[test]
+fn test_non_code_tasks_score_confidence() {
+    let scorer = CandidateScorer::new().unwrap();
+    let score = scorer.score("Analysis successful", 0.7, false, false, deadline());
+    assert_eq!(score.total, 0.7);
+    assert!(score.compiled.is_none());
+}
+
+#[test]
+fn test_compile_check_is_skipped_after_deadline() {
+    let scorer = CandidateScorer::new().unwrap();
+    let score = scorer.score("fn ok() {}", 1.0, true, true, Instant::now());
+    assert!(score.parsed);
+    // Either rustc was not found or it was killed at the (past) deadline
+    assert_ne!(score.compiled, Some(false));
+}
+
+#[test]
+fn test_compile_check_halves_ill_typed_code() {
+    let scorer = CandidateScorer::new().unwrap();
+    let good = scorer.score("pub fn ok() -> i32 { 1 }", 1.0, true, true, deadline());
+    let bad = scorer.score(
+        "pub fn bad() -> i32 { \"one\" }",
+        1.0,
+        true,
+        true,
+        deadline(),
+    );
+    // Without a toolchain the check does not run
+    if good.compiled.is_none() {
+        return;
+    }
+    assert_eq!(good.compiled, Some(true));
+    assert_eq!(bad.compiled, Some(false));
+    assert_eq!(bad.total, good.total * 0.5);
+}
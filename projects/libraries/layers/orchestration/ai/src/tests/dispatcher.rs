@@ -0,0 +1,205 @@
+// projects/libraries/layers/orchestration/ai/src/tests/dispatcher.rs
+use std::fs;
+
+use crate::dispatcher::Dispatcher;
+use crate::solve_trace::SolveTrace;
+use crate::solver_strategy::SolverStrategy;
+use crate::task::Task;
+use crate::task_result::TaskResult;
+use crate::task_type::TaskType;
+
+fn refactoring_task() -> Task {
+    Task::new_refactoring("fn main() {}".to_string(), "rename".to_string())
+}
+
+fn result(task_type: TaskType, strategy: SolverStrategy, confidence: f64) -> TaskResult {
+    let mut trace = SolveTrace::new(strategy);
+    trace.task_type = Some(task_type);
+    trace.elapsed_ms = Some(50);
+    TaskResult::new(String::new(), confidence, strategy, None, trace)
+}
+
+fn decisions(dispatcher: &mut Dispatcher, task: &Task, count: usize) -> Vec<SolverStrategy> {
+    (0..count)
+        .map(|_| dispatcher.decide_strategy(task, true))
+        .collect()
+}
+
+#[test]
+fn test_without_neural_is_symbolic_only() {
+    let mut dispatcher = Dispatcher::new();
+    let task = refactoring_task();
+    for _ in 0..20 {
+        assert_eq!(
+            dispatcher.decide_strategy(&task, false),
+            SolverStrategy::SymbolicOnly
+        );
+    }
+}
+
+#[test]
+fn test_static_analysis_is_always_symbolic() {
+    let mut dispatcher = Dispatcher::new();
+    let task = Task::new_code_analysis("fn main() {}".to_string());
+    for _ in 0..20 {
+        assert_eq!(
+            dispatcher.decide_strategy(&task, true),
+            SolverStrategy::SymbolicOnly
+        );
+    }
+    assert_eq!(
+        Dispatcher::candidates(TaskType::Linting, true),
+        vec![SolverStrategy::SymbolicOnly]
+    );
+}
+
+#[test]
+fn test_same_seed_same_decisions() {
+    let task = refactoring_task();
+    let mut first = Dispatcher::new();
+    let mut second = Dispatcher::new();
+    first.reseed(7);
+    second.reseed(7);
+    assert_eq!(
+        decisions(&mut first, &task, 50),
+        decisions(&mut second, &task, 50)
+    );
+}
+
+#[test]
+fn test_fresh_dispatcher_favours_default_strategy() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.reseed(1);
+    let chosen = decisions(&mut dispatcher, &refactoring_task(), 200);
+    let defaults = chosen
+        .iter()
+        .filter(|strategy| **strategy == SolverStrategy::Hybrid)
+        .count();
+    assert!(defaults > 100, "default chosen {} times", defaults);
+}
+
+#[test]
+fn test_learns_from_outcomes() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.reseed(3);
+    for _ in 0..30 {
+        dispatcher.record_failure(TaskType::Refactoring, SolverStrategy::Hybrid, Some(100));
+        dispatcher.record_success(&result(
+            TaskType::Refactoring,
+            SolverStrategy::SymbolicThenNeural,
+            0.95,
+        ));
+    }
+
+    let chosen = decisions(&mut dispatcher, &refactoring_task(), 100);
+    let learned = chosen
+        .iter()
+        .filter(|strategy| **strategy == SolverStrategy::SymbolicThenNeural)
+        .count();
+    // Untried strategies still get explored now and then
+    assert!(learned > 60, "learned strategy chosen {} times", learned);
+    assert!(!chosen.contains(&SolverStrategy::Hybrid));
+}
+
+#[test]
+fn test_success_updates_stats() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.record_success(&result(
+        TaskType::SimpleGeneration,
+        SolverStrategy::NeuralOnly,
+        1.0,
+    ));
+
+    let stats = dispatcher.stats(TaskType::SimpleGeneration);
+    assert_eq!(stats.len(), 1);
+    let (strategy, stats) = &stats[0];
+    assert_eq!(*strategy, SolverStrategy::NeuralOnly);
+    assert_eq!(stats.runs, 1);
+    assert_eq!(stats.alpha, 2.0);
+    assert_eq!(stats.mean_latency_ms, Some(50.0));
+}
+
+#[test]
+fn test_rejected_validation_halves_reward() {
+    let mut dispatcher = Dispatcher::new();
+    let mut task_result = result(TaskType::ComplexGeneration, SolverStrategy::NeuralOnly, 1.0);
+    task_result.trace.validation_passed = Some(false);
+    dispatcher.record_success(&task_result);
+
+    let stats = dispatcher.stats_for(TaskType::ComplexGeneration, SolverStrategy::NeuralOnly);
+    assert_eq!(stats.alpha, 1.5);
+    assert_eq!(stats.beta, 1.5);
+}
+
+#[test]
+fn test_untyped_trace_is_ignored() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.record_feedback(&SolveTrace::new(SolverStrategy::Hybrid), 1.0);
+    for task_type in TaskType::ALL {
+        assert!(dispatcher.stats(task_type).is_empty());
+    }
+}
+
+#[test]
+fn test_feedback_is_credited_to_trace_strategy() {
+    let mut dispatcher = Dispatcher::new();
+    let mut trace = SolveTrace::new(SolverStrategy::Hybrid);
+    trace.task_type = Some(TaskType::Refactoring);
+
+    let before = dispatcher.stats_for(TaskType::Refactoring, SolverStrategy::Hybrid);
+    dispatcher.record_feedback(&trace, 1.0);
+    let after = dispatcher.stats_for(TaskType::Refactoring, SolverStrategy::Hybrid);
+
+    assert!(after.alpha > before.alpha);
+    assert_eq!(after.beta, before.beta);
+    assert_eq!(after.feedback_count, 1);
+}
+
+#[test]
+fn test_save_and_load_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("strategy_stats.json");
+
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.record_failure(TaskType::Refactoring, SolverStrategy::Hybrid, Some(10));
+    dispatcher.record_success(&result(
+        TaskType::IntentParsing,
+        SolverStrategy::NeuralOnly,
+        0.8,
+    ));
+    dispatcher.save(&path).unwrap();
+
+    let mut loaded = Dispatcher::new();
+    loaded.load(&path).unwrap();
+    for task_type in TaskType::ALL {
+        assert_eq!(loaded.stats(task_type), dispatcher.stats(task_type));
+    }
+}
+
+#[test]
+fn test_persist_to_saves_every_update() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("strategy_stats.json");
+
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.persist_to(&path).unwrap();
+    assert!(!path.exists());
+    dispatcher.record_failure(TaskType::Refactoring, SolverStrategy::Hybrid, None);
+    assert!(path.exists());
+
+    let mut restored = Dispatcher::new();
+    restored.persist_to(&path).unwrap();
+    let stats = restored.stats_for(TaskType::Refactoring, SolverStrategy::Hybrid);
+    assert_eq!(stats.runs, 1);
+    assert_eq!(stats.failures, 1);
+}
+
+#[test]
+fn test_load_rejects_unknown_version() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("strategy_stats.json");
+    fs::write(&path, r#"{"version": 99, "entries": []}"#).unwrap();
+
+    let mut dispatcher = Dispatcher::new();
+    assert!(dispatcher.load(&path).is_err());
+}
@@ -0,0 +1,274 @@
+// projects/libraries/layers/orchestration/ai/src/tests/feedback_store.rs
+use std::fs;
+
+use crate::ai_body::AiBody;
+use crate::feedbacks::public_api_feedback::FeedbackInput;
+use crate::feedbacks::store::{
+    FeedbackKind, FeedbackQuery, FeedbackRecord, FeedbackStore, SolverPath,
+};
+use crate::solve_winner::SolveWinner;
+use crate::solver_strategy::SolverStrategy;
+use crate::task::Task;
+use crate::task_type::TaskType;
+
+fn record(task_input: &str, kind: FeedbackKind, created_at: u64) -> FeedbackRecord {
+    let expected = match kind {
+        FeedbackKind::Incorrect | FeedbackKind::Partial => Some(format!("{} fixed", task_input)),
+        _ => None,
+    };
+    let mut record = FeedbackRecord::new(
+        Some(TaskType::SimpleGeneration),
+        task_input,
+        task_input,
+        format!("{} output", task_input),
+        kind,
+        expected,
+    );
+    record.created_at_unix_secs = created_at;
+    record.updated_at_unix_secs = created_at;
+    record
+}
+
+fn hybrid_path(winner: SolveWinner) -> SolverPath {
+    SolverPath {
+        strategy: SolverStrategy::Hybrid,
+        winner: Some(winner),
+        fallback_used: false,
+        validation_passed: None,
+    }
+}
+
+#[test]
+fn test_duplicate_feedback_is_merged() {
+    let mut store = FeedbackStore::new();
+    assert!(
+        store
+            .insert(record("a", FeedbackKind::Correct, 10))
+            .unwrap()
+    );
+    let mut again = record("a", FeedbackKind::Correct, 20);
+    again.rationale = Some("seen twice".to_string());
+    assert!(!store.insert(again).unwrap());
+
+    assert_eq!(store.len(), 1);
+    let stored = &store.records()[0];
+    assert_eq!(stored.occurrences, 2);
+    assert_eq!(stored.created_at_unix_secs, 10);
+    assert!(stored.updated_at_unix_secs >= 20);
+    assert_eq!(stored.rationale.as_deref(), Some("seen twice"));
+}
+
+#[test]
+fn test_different_verdicts_are_distinct() {
+    let mut store = FeedbackStore::new();
+    store.insert(record("a", FeedbackKind::Correct, 1)).unwrap();
+    store
+        .insert(record("a", FeedbackKind::Incorrect, 1))
+        .unwrap();
+    assert_eq!(store.len(), 2);
+}
+
+#[test]
+fn test_query_filters_and_orders() {
+    let mut store = FeedbackStore::new();
+    store
+        .insert(
+            record("late", FeedbackKind::Correct, 30)
+                .with_solver_path(hybrid_path(SolveWinner::Neural)),
+        )
+        .unwrap();
+    store
+        .insert(
+            record("early", FeedbackKind::Incorrect, 10)
+                .with_solver_path(hybrid_path(SolveWinner::Symbolic)),
+        )
+        .unwrap();
+    store
+        .insert(record("middle", FeedbackKind::Rejected, 20))
+        .unwrap();
+
+    let all: Vec<&str> = store
+        .query(&FeedbackQuery::new())
+        .iter()
+        .map(|r| r.task_input.as_str())
+        .collect();
+    assert_eq!(all, ["early", "middle", "late"]);
+
+    assert_eq!(store.query(&FeedbackQuery::trainable()).len(), 2);
+    assert_eq!(
+        store.query(&FeedbackQuery::new().winner(SolveWinner::Neural))[0].task_input,
+        "late"
+    );
+    assert_eq!(
+        store
+            .query(&FeedbackQuery::new().strategy(SolverStrategy::Hybrid))
+            .len(),
+        2
+    );
+    assert_eq!(
+        store.query(&FeedbackQuery::new().since(20).until(30)).len(),
+        1
+    );
+    assert_eq!(
+        store
+            .query(&FeedbackQuery::new().input_contains("arl"))
+            .len(),
+        1
+    );
+    assert!(
+        store
+            .query(&FeedbackQuery::new().task_type(TaskType::Refactoring))
+            .is_empty()
+    );
+    assert_eq!(
+        store.query(&FeedbackQuery::new().limit(1))[0].task_input,
+        "early"
+    );
+}
+
+#[test]
+fn test_export_and_import_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("export.jsonl");
+
+    let mut store = FeedbackStore::new();
+    store.insert(record("a", FeedbackKind::Correct, 1)).unwrap();
+    store.insert(record("b", FeedbackKind::Partial, 2)).unwrap();
+    store
+        .insert(record("c", FeedbackKind::NoFeedback, 3))
+        .unwrap();
+    assert_eq!(
+        store
+            .export_jsonl(&path, &FeedbackQuery::trainable())
+            .unwrap(),
+        2
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 2);
+
+    let mut imported = FeedbackStore::new();
+    let summary = imported.import_jsonl(&path).unwrap();
+    assert_eq!((summary.inserted, summary.duplicates), (2, 0));
+    assert_eq!(imported.records(), &store.records()[..2]);
+
+    let summary = imported.import_jsonl(&path).unwrap();
+    assert_eq!((summary.inserted, summary.duplicates), (0, 2));
+}
+
+#[test]
+fn test_import_recomputes_ids() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tampered.jsonl");
+    let mut tampered = record("a", FeedbackKind::Correct, 1);
+    let id = tampered.id.clone();
+    tampered.id = "forged".to_string();
+    fs::write(&path, common_json::to_string(&tampered).unwrap()).unwrap();
+
+    let mut store = FeedbackStore::new();
+    store.import_jsonl(&path).unwrap();
+    assert!(store.get("forged").is_none());
+    assert!(store.get(&id).is_some());
+}
+
+#[test]
+fn test_import_accepts_replay_buffer_lines() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("replay.jsonl");
+    let ai = AiBody::new().unwrap();
+    ai.append_training_example(
+        &path,
+        r#"{"input":"sum","generated_output":"fn sum() {}","feedback_type":{"kind":"incorrect","expected_output":"fn sum(a: i32) {}"},"timestamp_unix_secs":42}"#,
+    )
+    .unwrap();
+
+    let mut store = FeedbackStore::new();
+    store.import_jsonl(&path).unwrap();
+    let imported = &store.records()[0];
+    assert_eq!(imported.kind, FeedbackKind::Incorrect);
+    assert_eq!(imported.target_output(), Some("fn sum(a: i32) {}"));
+    assert_eq!(imported.created_at_unix_secs, 42);
+}
+
+#[test]
+fn test_open_persists_changes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("store.jsonl");
+
+    let mut store = FeedbackStore::open(&path).unwrap();
+    store.insert(record("a", FeedbackKind::Correct, 1)).unwrap();
+    let b = record("b", FeedbackKind::Correct, 2);
+    store.insert(b.clone()).unwrap();
+    store.remove(&b.id).unwrap();
+
+    let reopened = FeedbackStore::open(&path).unwrap();
+    assert_eq!(reopened.records(), store.records());
+    assert_eq!(reopened.len(), 1);
+}
+
+#[test]
+fn test_adjust_with_result_records_solver_path() {
+    let mut ai = AiBody::new().unwrap();
+    let task = Task::new_code_analysis("fn main() {}".to_string());
+    let result = ai.solve(&task).unwrap();
+    ai.adjust_with_result(
+        FeedbackInput::incorrect(task.input(), task.input(), &result.output, "Looks fine"),
+        &result,
+    )
+    .unwrap();
+
+    let stored = &ai.feedback_store().records()[0];
+    assert_eq!(stored.task_type, Some(TaskType::CodeAnalysis));
+    assert_eq!(stored.expected_output.as_deref(), Some("Looks fine"));
+    let path = stored.solver_path.as_ref().unwrap();
+    assert_eq!(path.strategy, SolverStrategy::SymbolicOnly);
+    assert_eq!(path.winner, Some(SolveWinner::Symbolic));
+}
+
+#[test]
+fn test_negative_verdict_has_no_made_up_expected_output() {
+    let mut ai = AiBody::new().unwrap();
+    let task = ai.create_task("create struct Point");
+    ai.train_with_verdict(&task, "create struct Point", "struct Point;", false)
+        .unwrap();
+
+    let stored = &ai.feedback_store().records()[0];
+    assert_eq!(stored.kind, FeedbackKind::Incorrect);
+    assert_eq!(stored.expected_output, None);
+    assert_eq!(stored.target_output(), None);
+    assert_eq!(stored.source.as_deref(), Some("user"));
+}
+
+#[test]
+fn test_retrain_replays_into_symbolic_rules() {
+    let dir = tempfile::tempdir().unwrap();
+    let model_path = dir.path().join("model.bin");
+    let prompt = "create struct Point";
+
+    let mut store = FeedbackStore::new();
+    for created_at in 0..3 {
+        let mut negative = record(prompt, FeedbackKind::Incorrect, created_at);
+        negative.generated_output = format!("attempt {}", created_at);
+        store.insert(negative).unwrap();
+    }
+    store
+        .insert(record("unrelated", FeedbackKind::Rejected, 5))
+        .unwrap();
+
+    let confidences: Vec<f64> = (0..2)
+        .map(|_| {
+            let mut ai = AiBody::new().unwrap();
+            for stored in store.records() {
+                ai.feedback_store_mut().insert(stored.clone()).unwrap();
+            }
+            let report = ai
+                .retrain_from_store(&FeedbackQuery::new(), &model_path)
+                .unwrap();
+            assert_eq!(report.records, 3);
+            assert_eq!(report.symbolic_adjustments, 3);
+            assert!(!report.neural_trained);
+            ai.solve(&ai.create_task(prompt)).unwrap().confidence
+        })
+        .collect();
+
+    assert!((confidences[0] - 0.75).abs() < 1e-9);
+    assert_eq!(confidences[0], confidences[1]);
+}
@@ -0,0 +1,163 @@
+// projects/libraries/layers/orchestration/ai/src/tests/hybrid.rs
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::ai_body::AiBody;
+use crate::candidate_scorer::CandidateScorer;
+use crate::hybrid_config::HybridConfig;
+use crate::hybrid_race::{Attempt, Entry, run_race};
+use crate::solve_decision::SolveDecision;
+use crate::solve_winner::SolveWinner;
+use crate::solver_strategy::SolverStrategy;
+use crate::task::Task;
+
+const CLEAN: &str = "fn add(a: i32, b: i32) -> i32 { a + b }";
+const NOISY: &str = "fn add(a: i32, b: i32) -> i32 { todo!() }";
+
+fn config(deadline_ms: u64) -> HybridConfig {
+    HybridConfig {
+        deadline: Duration::from_millis(deadline_ms),
+        ..HybridConfig::default()
+    }
+}
+
+fn attempt(output: &str, confidence: f64) -> Result<Attempt, String> {
+    Ok(Attempt {
+        output: output.to_string(),
+        confidence,
+    })
+}
+
+/// Runs until cancelled, failing the test if that takes more than 5 seconds.
+fn wait_for_cancel(cancel: &AtomicBool) -> Result<Attempt, String> {
+    let started = Instant::now();
+    while !cancel.load(Ordering::Relaxed) {
+        assert!(
+            started.elapsed() < Duration::from_secs(5),
+            "never cancelled"
+        );
+        thread::sleep(Duration::from_millis(5));
+    }
+    Err("cancelled".to_string())
+}
+
+#[test]
+fn test_best_score_wins_when_both_finish() {
+    let scorer = CandidateScorer::new().unwrap();
+    let report = run_race(
+        &config(5_000),
+        &scorer,
+        true,
+        |_| attempt(NOISY, 0.9),
+        Some(|_: &AtomicBool| attempt(CLEAN, 0.6)),
+    );
+    assert!(!report.accepted_early);
+    assert!(!report.deadline_exceeded);
+    assert_eq!(report.winner(), Some(SolveWinner::Neural));
+}
+
+#[test]
+fn test_high_score_cancels_the_other_solver() {
+    let scorer = CandidateScorer::new().unwrap();
+    let started = Instant::now();
+    let report = run_race(
+        &config(5_000),
+        &scorer,
+        true,
+        |_| attempt(CLEAN, 1.0),
+        Some(wait_for_cancel),
+    );
+    assert!(report.accepted_early);
+    assert!(matches!(report.neural, Entry::Cancelled));
+    assert_eq!(report.winner(), Some(SolveWinner::Symbolic));
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn test_deadline_keeps_finished_candidate() {
+    let scorer = CandidateScorer::new().unwrap();
+    let report = run_race(
+        &config(100),
+        &scorer,
+        true,
+        wait_for_cancel,
+        Some(|_: &AtomicBool| attempt(NOISY, 0.5)),
+    );
+    assert!(report.deadline_exceeded);
+    assert!(matches!(report.symbolic, Entry::Cancelled));
+    assert_eq!(report.winner(), Some(SolveWinner::Neural));
+}
+
+#[test]
+fn test_slow_symbolic_does_not_hold_the_deadline() {
+    let scorer = CandidateScorer::new().unwrap();
+    let started = Instant::now();
+    let report = run_race(
+        &config(100),
+        &scorer,
+        true,
+        |_| {
+            // Ignores its cancel flag, like the symbolic solver
+            thread::sleep(Duration::from_secs(3));
+            attempt(CLEAN, 1.0)
+        },
+        Some(|_: &AtomicBool| attempt(NOISY, 0.5)),
+    );
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert!(report.deadline_exceeded);
+    assert!(matches!(report.symbolic, Entry::Cancelled));
+    assert_eq!(report.winner(), Some(SolveWinner::Neural));
+}
+
+#[test]
+fn test_symbolic_runs_alone_without_neural() {
+    let scorer = CandidateScorer::new().unwrap();
+    let report = run_race(
+        &config(5_000),
+        &scorer,
+        true,
+        |_| attempt(CLEAN, 1.0),
+        None::<fn(&AtomicBool) -> Result<Attempt, String>>,
+    );
+    assert!(!report.accepted_early);
+    assert!(!report.deadline_exceeded);
+    assert!(matches!(report.neural, Entry::NotRun));
+    assert_eq!(report.winner(), Some(SolveWinner::Symbolic));
+}
+
+#[test]
+fn test_failures_leave_no_winner() {
+    let scorer = CandidateScorer::new().unwrap();
+    let report = run_race(
+        &config(5_000),
+        &scorer,
+        true,
+        |_| Err("symbolic".to_string()),
+        Some(|_: &AtomicBool| Err("neural".to_string())),
+    );
+    assert!(matches!(report.symbolic, Entry::Failed(_)));
+    assert!(matches!(report.neural, Entry::Failed(_)));
+    assert_eq!(report.winner(), None);
+}
+
+#[test]
+fn test_hybrid_without_neural_uses_symbolic() {
+    let mut ai = AiBody::new().unwrap();
+    let task = Task::new_refactoring(
+        "fn old_name() {}\nfn main() { old_name(); }".to_string(),
+        "rename old_name to new_name".to_string(),
+    );
+    let result = ai.solve_hybrid(&task).unwrap();
+    assert!(result.output.contains("new_name"));
+    assert_eq!(result.strategy_used, SolverStrategy::Hybrid);
+
+    let trace = result.trace();
+    assert_eq!(trace.winner, Some(SolveWinner::Symbolic));
+    assert!(!trace.neural_ran);
+    assert!(!trace.neural_cancelled);
+    assert!(!trace.neural_failed);
+    assert!(trace.symbolic_score.is_some());
+    assert!(trace.neural_score.is_none());
+    assert_eq!(trace.decision, SolveDecision::OnlyOneSolverAvailable);
+}
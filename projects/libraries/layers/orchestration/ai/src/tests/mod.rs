@@ -0,0 +1,6 @@
+// projects/libraries/layers/orchestration/ai/src/tests/mod.rs
+mod candidate_scorer;
+mod dispatcher;
+mod feedback_store;
+mod hybrid;
+mod strategy_stats;
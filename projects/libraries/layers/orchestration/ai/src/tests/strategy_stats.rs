@@ -0,0 +1,60 @@
+// projects/libraries/layers/orchestration/ai/src/tests/strategy_stats.rs
+use crate::strategy_stats::StrategyStats;
+
+#[test]
+fn test_new_stats_are_uniform() {
+    let stats = StrategyStats::new();
+    assert_eq!(stats.alpha, 1.0);
+    assert_eq!(stats.beta, 1.0);
+    assert_eq!(stats.success_rate(), 0.5);
+    assert!(stats.mean_latency_ms.is_none());
+}
+
+#[test]
+fn test_record_run_splits_reward() {
+    let mut stats = StrategyStats::new();
+    stats.record_run(true, 0.75, None);
+    assert_eq!(stats.runs, 1);
+    assert_eq!(stats.failures, 0);
+    assert_eq!(stats.alpha, 1.75);
+    assert_eq!(stats.beta, 1.25);
+}
+
+#[test]
+fn test_failed_run_counts_as_zero_reward() {
+    let mut stats = StrategyStats::new();
+    stats.record_run(false, 1.0, None);
+    assert_eq!(stats.failures, 1);
+    assert_eq!(stats.alpha, 1.0);
+    assert_eq!(stats.beta, 2.0);
+}
+
+#[test]
+fn test_reward_is_clamped() {
+    let mut stats = StrategyStats::new();
+    stats.record_run(true, 3.0, None);
+    stats.record_run(true, f64::NAN, None);
+    assert_eq!(stats.alpha, 2.0);
+    assert_eq!(stats.beta, 2.0);
+}
+
+#[test]
+fn test_latency_moving_average() {
+    let mut stats = StrategyStats::new();
+    stats.record_run(true, 1.0, Some(100));
+    assert_eq!(stats.mean_latency_ms, Some(100.0));
+    stats.record_run(true, 1.0, Some(200));
+    assert_eq!(stats.mean_latency_ms, Some(120.0));
+    stats.record_run(true, 1.0, None);
+    assert_eq!(stats.mean_latency_ms, Some(120.0));
+}
+
+#[test]
+fn test_feedback_is_weighted() {
+    let mut stats = StrategyStats::new();
+    stats.record_feedback(0.5, 2.0);
+    assert_eq!(stats.feedback_count, 1);
+    assert_eq!(stats.runs, 0);
+    assert_eq!(stats.alpha, 2.0);
+    assert_eq!(stats.beta, 2.0);
+}
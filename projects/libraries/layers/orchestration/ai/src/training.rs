@@ -1,7 +1,13 @@
 // projects/libraries/layers/orchestration/ai/src/training.rs
-use crate::{ai_error::AiError, ai_orchestrator::AiOrchestrator, task::Task};
+use crate::{
+    ai_error::AiError,
+    ai_orchestrator::AiOrchestrator,
+    feedbacks::store::{FeedbackKind, FeedbackQuery, FeedbackRecord, RetrainReport},
+    task::Task,
+};
 use common_json::{from_json_str, to_string};
 use neural::feedback::{FeedbackMetadata, FeedbackType, UserFeedback};
+use std::collections::HashSet;
 use std::fs::OpenOptions;
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
@@ -9,6 +15,9 @@ use symbolic::feedback_symbolic::SymbolicFeedback;
 use tracing::{info, warn};
 
 impl AiOrchestrator {
+    /// Trains from a bare verdict. A negative verdict carries no expected
+    /// output, so it only weakens the symbolic rules; the neural model has
+    /// nothing to learn from it.
     pub(crate) fn train_with_verdict(
         &mut self,
         task: &Task,
@@ -16,26 +25,43 @@ impl AiOrchestrator {
         generated_output: &str,
         ok: bool,
     ) -> Result<(), AiError> {
+        let kind = if ok {
+            FeedbackKind::Correct
+        } else {
+            FeedbackKind::Incorrect
+        };
+        let mut record = FeedbackRecord::new(
+            Some(*task.task_type()),
+            task.input(),
+            input,
+            generated_output,
+            kind,
+            None,
+        );
+        record.source = Some("user".to_string());
+        self.feedback_store.insert(record)?;
+
+        if !ok {
+            info!("Negative verdict without expected output; adjusting symbolic rules only");
+            self.feedback.symbolic_mut().adjust_rules(
+                task.input(),
+                SymbolicFeedback {
+                    is_positive: false,
+                    metadata: Some(format!("Input: {}, Output: {}", input, generated_output)),
+                },
+            )?;
+            return Ok(());
+        }
+
         let feedback = UserFeedback::new(
             input,
             generated_output,
-            if ok {
-                FeedbackType::Correct {
-                    metadata: FeedbackMetadata {
-                        confidence: Some(1.0),
-                        rationale: Some("Positive feedback".to_string()),
-                        source: Some("user".to_string()),
-                    },
-                }
-            } else {
-                FeedbackType::Incorrect {
-                    expected_output: "<unknown>".to_string(),
-                    metadata: FeedbackMetadata {
-                        confidence: Some(0.0),
-                        rationale: Some("Negative feedback without expected output".to_string()),
-                        source: Some("user".to_string()),
-                    },
-                }
+            FeedbackType::Correct {
+                metadata: FeedbackMetadata {
+                    confidence: Some(1.0),
+                    rationale: Some("Positive feedback".to_string()),
+                    source: Some("user".to_string()),
+                },
             },
         );
         self.train_with_feedback_neural(task, &feedback)
@@ -73,6 +99,57 @@ impl AiOrchestrator {
         Ok(())
     }
 
+    /// Replays the trainable records matching `query`, oldest first, into
+    /// the symbolic rule weights (once per record), then trains the neural
+    /// model on their distinct target outputs and saves it to `model_path`.
+    /// The same store and query always produce the same updates.
+    pub(crate) fn retrain_from_store(
+        &mut self,
+        query: &FeedbackQuery,
+        model_path: &Path,
+    ) -> Result<RetrainReport, AiError> {
+        let records: Vec<FeedbackRecord> = self
+            .feedback_store
+            .query(query)
+            .into_iter()
+            .filter(|record| record.kind.is_trainable())
+            .cloned()
+            .collect();
+        let mut report = RetrainReport {
+            records: records.len(),
+            ..RetrainReport::default()
+        };
+        info!(records = records.len(), "Retraining from feedback store");
+
+        let symbolic = self.feedback.symbolic_mut();
+        for record in &records {
+            report.symbolic_adjustments += if record.kind.is_positive() {
+                symbolic.reinforce_rule(&record.task_input)
+            } else {
+                symbolic.weaken_rule(&record.task_input)
+            };
+        }
+
+        let mut seen = HashSet::new();
+        let examples: Vec<String> = records
+            .iter()
+            .filter_map(FeedbackRecord::target_output)
+            .filter(|target| seen.insert(*target))
+            .map(str::to_string)
+            .collect();
+        match self.feedback.neural_mut() {
+            Ok(neural) if !examples.is_empty() => {
+                report.neural_examples = examples.len();
+                neural.train(examples, model_path)?;
+                report.neural_trained = true;
+            }
+            Ok(_) => info!("No target outputs to train the neural model on"),
+            Err(_) => warn!("Neural model not loaded, skipping neural retraining"),
+        }
+
+        Ok(report)
+    }
+
     pub(crate) fn train_neural(
         &mut self,
         training_data: impl IntoIterator<Item = String>,
@@ -11,7 +11,7 @@ use anyhow::Context;
 use bytes::Bytes;
 use futures_util::{SinkExt, StreamExt};
 use protocol::protocol_id::ProtocolId;
-use protocol::{Command, CommandType, Metadata, Payload};
+use protocol::{Command, CommandType, Metadata, Payload, SchemaVersionRange};
 use security::{Role, TokenService};
 use serde::Serialize;
 use tokio_tungstenite::tungstenite::Message;
@@ -39,6 +39,7 @@ struct BackendHello {
     instance_id: ProtocolId,
     capabilities: Vec<String>,
     routes: Vec<String>,
+    schema_versions: SchemaVersionRange,
 }
 
 #[tokio::main]
@@ -114,6 +115,7 @@ async fn main() -> anyhow::Result<()> {
         instance_id,
         capabilities: vec!["auth".to_string(), "accounts".to_string()],
         routes: vec![],
+        schema_versions: SchemaVersionRange::current(),
     };
 
     let payload = Payload {
@@ -122,7 +124,7 @@ async fn main() -> anyhow::Result<()> {
     };
 
     let cmd = Command {
-        metadata: Metadata::now(),
+        metadata: Metadata::now().with_schema_version(protocol::PROTOCOL_SCHEMA_VERSION),
         command_type: CommandType::Create,
         action: Some("backend.hello".to_string()),
         payload: Some(payload),
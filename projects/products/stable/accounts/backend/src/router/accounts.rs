@@ -3,8 +3,8 @@ use std::str::FromStr;
 
 use protocol::{Command, Event, Metadata};
 use protocol_accounts::{
-    AccountStatus, AccountsListResponse, CreateAccountRequest, ResetPasswordRequest,
-    UpdateAccountRequest, UpdateStatusRequest,
+    AccountStatus, AccountsListResponse, AuthorizeRequest, CreateAccountRequest,
+    ResetPasswordRequest, UpdateAccountRequest, UpdateStatusRequest,
 };
 use security::Role;
 
@@ -15,7 +15,9 @@ use crate::router::helpers::{
 use crate::store::account_manager::AccountManager;
 use crate::store::account_store_error::AccountStoreError;
 
-use super::command_router::{PAYLOAD_ACCOUNT, PAYLOAD_ACCOUNTS_LIST, PAYLOAD_OK};
+use super::command_router::{
+    PAYLOAD_ACCOUNT, PAYLOAD_ACCOUNTS_LIST, PAYLOAD_AUTHORIZATION, PAYLOAD_OK,
+};
 
 pub async fn handle_list_users(meta: &Metadata, manager: &AccountManager) -> Event {
     let users = manager.list().await.into_iter().collect::<Vec<_>>();
@@ -149,3 +151,38 @@ pub async fn handle_reset_password(
         Err(err) => map_store_error(meta, err),
     }
 }
+
+/// Policy decision for an account, with the full evaluation trace for auditing.
+/// The caller (`metadata.client_id`, set by the engine) may only ask about its
+/// own account unless it holds `accounts.authorize` on the target account.
+pub async fn handle_authorize(meta: &Metadata, cmd: &Command, manager: &AccountManager) -> Event {
+    let req: AuthorizeRequest = match payload_as(cmd) {
+        Ok(r) => r,
+        Err(msg) => return err_event(meta, 400, &msg),
+    };
+
+    let Some(caller) = meta.client_id else {
+        return err_event(meta, 401, "Missing caller identity");
+    };
+    if caller != req.user_id {
+        let resource = format!("account:{}", req.user_id);
+        match manager
+            .authorize(&caller, "accounts.authorize", &resource)
+            .await
+        {
+            Ok(explanation) if explanation.decision.is_allowed() => {}
+            Ok(_) | Err(AccountStoreError::NotFound) => {
+                return err_event(meta, 403, "Not allowed to authorize for another account");
+            }
+            Err(err) => return map_store_error(meta, err),
+        }
+    }
+
+    match manager
+        .authorize(&req.user_id, &req.action, &req.resource)
+        .await
+    {
+        Ok(explanation) => ok_payload(meta, "Authorization", PAYLOAD_AUTHORIZATION, explanation),
+        Err(err) => map_store_error(meta, err),
+    }
+}
@@ -15,6 +15,7 @@ const ACTION_CREATE_USER: &str = "accounts.create";
 const ACTION_UPDATE_USER: &str = "accounts.update";
 const ACTION_UPDATE_STATUS: &str = "accounts.update_status";
 const ACTION_RESET_PASSWORD: &str = "accounts.reset_password";
+const ACTION_AUTHORIZE: &str = "accounts.authorize";
 
 pub const PAYLOAD_SETUP_STATUS: &str = "accounts/setup_status";
 pub const PAYLOAD_SETUP_ADMIN: &str = "accounts/setup_admin";
@@ -22,6 +23,7 @@ pub const PAYLOAD_LOGIN: &str = "accounts/login";
 pub const PAYLOAD_ACCOUNTS_LIST: &str = "accounts/list";
 pub const PAYLOAD_ACCOUNT: &str = "accounts/account";
 pub const PAYLOAD_OK: &str = "accounts/ok";
+pub const PAYLOAD_AUTHORIZATION: &str = "accounts/authorization";
 
 pub async fn handle_command(
     cmd: Command,
@@ -44,6 +46,7 @@ pub async fn handle_command(
         ACTION_UPDATE_USER => accounts::handle_update_user(&meta, &cmd, manager).await,
         ACTION_UPDATE_STATUS => accounts::handle_update_status(&meta, &cmd, manager).await,
         ACTION_RESET_PASSWORD => accounts::handle_reset_password(&meta, &cmd, manager).await,
+        ACTION_AUTHORIZE => accounts::handle_authorize(&meta, &cmd, manager).await,
         _ => helpers::err_event(&meta, 404, "Unsupported action"),
     }
 }
@@ -117,6 +117,7 @@ pub fn map_store_error(meta: &Metadata, err: AccountStoreError) -> Event {
         AccountStoreError::Json(e) => err_event(meta, 500, &format!("JSON error: {e}")),
         AccountStoreError::Password(e) => err_event(meta, 500, &format!("Password error: {e}")),
         AccountStoreError::InvalidConfig(_) => err_event(meta, 500, "Server configuration error"),
+        AccountStoreError::Policy(_) => err_event(meta, 500, "Server configuration error"),
         AccountStoreError::BufferFull { .. } => {
             err_event(meta, 503, "Audit subsystem temporarily unavailable")
         }
@@ -1,7 +1,9 @@
 // projects/products/stable/accounts/backend/src/store/account_manager.rs
 use common_json::{from_json_str, to_string};
 use common_time::timestamp_utils::current_timestamp_ms;
-use security::{Permission, Role};
+use security::policy::PolicyExplanation;
+use security::policy::{PolicyError, PolicyRule, PolicySubject};
+use security::{Permission, PolicyEngine, PolicyRequest, Role};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::{collections::HashMap, path::PathBuf, sync::Arc};
 use tokio::sync::RwLock;
@@ -23,8 +25,20 @@ pub struct AccountManager {
     state: Arc<RwLock<HashMap<ProtocolId, AccountRecord>>>,
     dirty: Arc<AtomicBool>,
     audit_buffer: Arc<AuditBuffer>,
+    policy: Arc<PolicyEngine>,
 }
 
+/// Product permissions registered in the policy engine as `accounts.<name>`.
+pub const ACCOUNTS_PERMISSIONS: &[&str] = &[
+    "list",
+    "get",
+    "create",
+    "update",
+    "update_status",
+    "reset_password",
+    "authorize",
+];
+
 impl AccountManager {
     pub fn default_data_dir() -> PathBuf {
         PathBuf::from("projects")
@@ -69,9 +83,37 @@ impl AccountManager {
             state: Arc::new(RwLock::new(users)),
             dirty: Arc::new(AtomicBool::new(false)),
             audit_buffer,
+            policy: Arc::new(Self::default_policy()?),
         })
     }
 
+    /// Built-in roles plus the accounts product permissions, granted to Admin.
+    pub fn default_policy() -> Result<PolicyEngine, PolicyError> {
+        let mut policy = PolicyEngine::with_builtin_roles();
+        policy.register_permissions("accounts", ACCOUNTS_PERMISSIONS)?;
+        policy.add_rule(PolicyRule::allow(
+            "accounts:admin",
+            PolicySubject::Role(Role::Admin.as_str().to_string()),
+            &["accounts.*"],
+        )?)?;
+        Ok(policy)
+    }
+
+    /// Explain whether `user_id` may perform `action` on `resource`.
+    /// The account role and its extra permissions (as subject grants) are used.
+    pub async fn authorize(
+        &self,
+        user_id: &ProtocolId,
+        action: &str,
+        resource: &str,
+    ) -> Result<PolicyExplanation, AccountStoreError> {
+        let users = self.state.read().await;
+        let user = users.get(user_id).ok_or(AccountStoreError::NotFound)?;
+        Ok(self
+            .policy
+            .explain(&self.policy_request(user, action, resource)))
+    }
+
     pub async fn save(&self) -> Result<(), AccountStoreError> {
         let users = self.state.read().await;
         let file = AccountsFile {
@@ -319,13 +361,20 @@ impl AccountManager {
     }
 
     fn effective_permissions(&self, user: &AccountRecord) -> Vec<Permission> {
-        let mut perms = user.role.permissions().to_vec();
-        for perm in &user.extra_permissions {
-            if !perms.contains(perm) {
-                perms.push(*perm);
-            }
-        }
-        perms
+        Permission::all()
+            .iter()
+            .filter(|perm| {
+                self.policy
+                    .is_allowed(&self.policy_request(user, perm.as_str(), "**"))
+            })
+            .copied()
+            .collect()
+    }
+
+    fn policy_request(&self, user: &AccountRecord, action: &str, resource: &str) -> PolicyRequest {
+        PolicyRequest::new(user.user_id.to_string(), action, resource)
+            .with_builtin_role(user.role)
+            .with_grants(user.extra_permissions.iter().map(|p| p.as_str()))
     }
 
     async fn append_audit(&self, entry: AuditEntry) -> Result<(), AccountStoreError> {
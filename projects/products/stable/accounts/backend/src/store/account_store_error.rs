@@ -24,6 +24,8 @@ pub enum AccountStoreError {
     Password(String),
     #[error("invalid configuration: {0}")]
     InvalidConfig(String),
+    #[error("policy error: {0}")]
+    Policy(#[from] security::policy::PolicyError),
     #[error("audit buffer is full (max pending entries: {max_pending_entries})")]
     BufferFull { max_pending_entries: usize },
 }
@@ -1,10 +1,10 @@
 // projects/products/stable/accounts/backend/src/store/tests/account_manager.rs
-use crate::store::account_manager::AccountManager;
+use crate::store::account_manager::{ACCOUNTS_PERMISSIONS, AccountManager};
 use crate::store::audit_buffer_config::AuditBufferConfig;
 use common_time::timestamp_utils::current_timestamp_ms;
 use lazy_static::lazy_static;
 use protocol::ProtocolId;
-use security::Role;
+use security::{Permission, Role};
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::time::Duration;
@@ -444,3 +444,89 @@ async fn test_audit_entries_maintain_order() {
     // Cleanup
     tokio::fs::remove_dir_all(manager.data_dir()).await.ok();
 }
+
+#[tokio::test]
+async fn test_authorize_uses_role_and_extra_permissions() {
+    let manager = create_test_manager()
+        .await
+        .expect("Failed to create test manager");
+    let user_id = ProtocolId::default();
+
+    manager
+        .create(
+            user_id,
+            test_password(),
+            Role::Guest,
+            vec![Permission::Train],
+            "test_actor",
+        )
+        .await
+        .expect("Failed to create test user");
+
+    let read = manager
+        .authorize(&user_id, "read", "project:foo")
+        .await
+        .expect("Failed to authorize read");
+    assert!(read.decision.is_allowed(), "Guest role should allow read");
+
+    let train = manager
+        .authorize(&user_id, "train", "project:foo")
+        .await
+        .expect("Failed to authorize train");
+    assert!(
+        train.decision.is_allowed(),
+        "Extra permission should be granted"
+    );
+
+    let create = manager
+        .authorize(&user_id, "accounts.create", "account:any")
+        .await
+        .expect("Failed to authorize accounts.create");
+    assert!(
+        !create.decision.is_allowed(),
+        "Guest should not create accounts"
+    );
+
+    let summary = manager.get(&user_id).await.expect("Failed to get user");
+    assert_eq!(summary.permissions, vec!["Read", "Train"]);
+
+    // Cleanup
+    tokio::fs::remove_dir_all(manager.data_dir()).await.ok();
+}
+
+#[test]
+fn test_default_policy_rules_are_valid() {
+    let policy = AccountManager::default_policy().expect("built-in policy should be valid");
+    assert!(policy.rules().iter().any(|r| r.id == "accounts:admin"));
+    for name in ACCOUNTS_PERMISSIONS {
+        assert!(policy.is_known_permission(&format!("accounts.{name}")));
+    }
+}
+
+#[tokio::test]
+async fn test_admin_is_granted_accounts_permissions() {
+    let manager = create_test_manager()
+        .await
+        .expect("Failed to create test manager");
+    let user_id = ProtocolId::default();
+
+    manager
+        .create(user_id, test_password(), Role::Admin, vec![], "test_actor")
+        .await
+        .expect("Failed to create test admin");
+
+    for name in ACCOUNTS_PERMISSIONS {
+        let action = format!("accounts.{name}");
+        let explanation = manager
+            .authorize(&user_id, &action, "account:any")
+            .await
+            .expect("Failed to authorize admin");
+        assert!(
+            explanation.decision.is_allowed(),
+            "Admin should be allowed {action}"
+        );
+    }
+
+    // Cleanup
+    tokio::fs::remove_dir_all(manager.data_dir()).await.ok();
+}
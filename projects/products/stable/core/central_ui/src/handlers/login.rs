@@ -1,4 +1,6 @@
 //projects/products/core/central_ui/src/handlers/login.rs
+use std::net::SocketAddr;
+
 use protocol_accounts::LoginRequest;
 
 use crate::handlers::response_with_status;
@@ -6,6 +8,7 @@ use crate::login_input::LoginInput;
 
 pub(crate) async fn handle_login(
     req: LoginInput,
+    remote: Option<SocketAddr>,
     client: reqwest::Client,
     engine_base: String,
 ) -> Result<impl warp::Reply, warp::Rejection> {
@@ -18,12 +21,12 @@ pub(crate) async fn handle_login(
     };
 
     let url = format!("{engine_base}/auth/login");
-    let resp = client
-        .post(url)
-        .json(&body)
-        .send()
-        .await
-        .map_err(|_| warp::reject())?;
+    let mut request = client.post(url).json(&body);
+    // The engine throttles logins per client address
+    if let Some(remote) = remote {
+        request = request.header("x-forwarded-for", remote.ip().to_string());
+    }
+    let resp = request.send().await.map_err(|_| warp::reject())?;
     let status = resp.status();
     let body = resp.bytes().await.map_err(|_| warp::reject())?;
     Ok(response_with_status(body, status))
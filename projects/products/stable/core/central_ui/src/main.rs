@@ -46,6 +46,7 @@ async fn main() {
         .and(warp::path!("login"))
         .and(warp::post())
         .and(warp::body::json())
+        .and(warp::addr::remote())
         .and(with_client(client.clone()))
         .and(with_engine_base(engine_base.clone()))
         .and_then(handle_login);
@@ -0,0 +1,62 @@
+// projects/products/stable/core/engine/src/auth/audit_entry.rs
+use serde::{Deserialize, Serialize};
+
+use super::AuditOutcome;
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct AuditEntry {
+    /// Position in the log, assigned when recorded
+    #[serde(default)]
+    pub(crate) seq: u64,
+    #[serde(default)]
+    pub(crate) timestamp_ms: u64,
+    /// Dotted action name, e.g. `auth.login` or `accounts.create`
+    pub(crate) action: String,
+    pub(crate) outcome: AuditOutcome,
+    /// Subject who performed the action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) actor: Option<String>,
+    /// Account, session or product the action applies to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) target: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) ip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) detail: Option<String>,
+}
+
+impl AuditEntry {
+    pub(crate) fn new(action: impl Into<String>, outcome: AuditOutcome) -> Self {
+        Self {
+            seq: 0,
+            timestamp_ms: 0,
+            action: action.into(),
+            outcome,
+            actor: None,
+            target: None,
+            ip: None,
+            detail: None,
+        }
+    }
+
+    pub(crate) fn with_actor(mut self, actor: impl ToString) -> Self {
+        self.actor = Some(actor.to_string());
+        self
+    }
+
+    pub(crate) fn with_target(mut self, target: impl ToString) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+
+    pub(crate) fn with_ip(mut self, ip: Option<&str>) -> Self {
+        self.ip = ip.map(str::to_string);
+        self
+    }
+
+    pub(crate) fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
@@ -0,0 +1,115 @@
+// projects/products/stable/core/engine/src/auth/audit_log.rs
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use common_json::{from_json_str, to_string};
+use tracing::{info, warn};
+
+use super::{AuditEntry, AuditLogError, AuditQuery};
+use crate::line_writer::LineWriter;
+
+/// Entries kept in memory for queries; the file keeps all of them
+pub(crate) const DEFAULT_RETAINED_AUDIT_ENTRIES: usize = 50_000;
+
+/// Append-only log of authentication and administration actions.
+///
+/// A log opened on a file appends one JSON line per entry and never
+/// rewrites it; lines are written by a [`LineWriter`], so recording never
+/// waits on the disk. Queries see the most recent retained entries.
+#[derive(Debug)]
+pub(crate) struct AuditLog {
+    entries: VecDeque<AuditEntry>,
+    last_seq: u64,
+    retained: usize,
+    writer: Option<LineWriter>,
+}
+
+impl AuditLog {
+    /// In-memory log
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            last_seq: 0,
+            retained: DEFAULT_RETAINED_AUDIT_ENTRIES,
+            writer: None,
+        }
+    }
+
+    pub(crate) fn with_retained(mut self, retained: usize) -> Self {
+        self.retained = retained.max(1);
+        self
+    }
+
+    /// Appends to the JSONL file at `path`, loading the entries it already
+    /// holds. Unreadable lines (e.g. a write cut by a crash) are skipped.
+    pub(crate) fn open(mut self, path: &Path) -> Result<Self, AuditLogError> {
+        if path.exists() {
+            let file = File::open(path)?;
+            let mut skipped = 0usize;
+            for line in BufReader::new(file).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match from_json_str::<AuditEntry>(&line) {
+                    Ok(entry) => self.push(entry),
+                    Err(_) => skipped += 1,
+                }
+            }
+            if skipped > 0 {
+                warn!(path = %path.display(), skipped, "Skipped unreadable audit log lines");
+            }
+            info!(path = %path.display(), last_seq = self.last_seq, "Audit log loaded");
+        } else if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        self.writer = Some(LineWriter::spawn(path, "audit-log-writer")?);
+        Ok(self)
+    }
+
+    /// Numbers, timestamps and appends `entry`
+    pub(crate) fn record(
+        &mut self,
+        mut entry: AuditEntry,
+        now_ms: u64,
+    ) -> Result<AuditEntry, AuditLogError> {
+        entry.seq = self.last_seq + 1;
+        entry.timestamp_ms = now_ms;
+        if let Some(writer) = &self.writer {
+            let line =
+                to_string(&entry).map_err(|e| AuditLogError::Serialization(e.to_string()))?;
+            writer.append(line);
+        }
+        self.push(entry.clone());
+        Ok(entry)
+    }
+
+    /// Entries matching `query`, most recent first
+    pub(crate) fn query(&self, query: &AuditQuery) -> Vec<AuditEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| query.matches(entry))
+            .take(query.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+
+    fn push(&mut self, entry: AuditEntry) {
+        self.last_seq = self.last_seq.max(entry.seq);
+        self.entries.push_back(entry);
+        while self.entries.len() > self.retained {
+            self.entries.pop_front();
+        }
+    }
+}
+
+impl Default for AuditLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
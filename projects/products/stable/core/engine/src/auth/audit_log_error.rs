@@ -0,0 +1,11 @@
+// projects/products/stable/core/engine/src/auth/audit_log_error.rs
+
+/// Errors of the append-only audit log
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum AuditLogError {
+    #[error("audit log I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("audit log serialization error: {0}")]
+    Serialization(String),
+}
@@ -0,0 +1,28 @@
+// projects/products/stable/core/engine/src/auth/audit_outcome.rs
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Result of an audited action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AuditOutcome {
+    Success,
+    /// The action was attempted and failed (bad credentials, backend error)
+    Failure,
+    /// The action was refused before being attempted (rate limit, invalid request)
+    Denied,
+}
+
+impl FromStr for AuditOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "success" => Ok(Self::Success),
+            "failure" => Ok(Self::Failure),
+            "denied" => Ok(Self::Denied),
+            other => Err(format!("Unknown audit outcome '{other}'")),
+        }
+    }
+}
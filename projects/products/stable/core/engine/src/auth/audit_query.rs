@@ -0,0 +1,40 @@
+// projects/products/stable/core/engine/src/auth/audit_query.rs
+use super::{AuditEntry, AuditOutcome};
+
+/// Filter for the audit log; unset fields match every entry
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AuditQuery {
+    /// Exact action, or a namespace: `accounts` matches `accounts.create`
+    pub(crate) action: Option<String>,
+    pub(crate) actor: Option<String>,
+    pub(crate) target: Option<String>,
+    pub(crate) outcome: Option<AuditOutcome>,
+    pub(crate) since_ms: Option<u64>,
+    pub(crate) until_ms: Option<u64>,
+    /// Most recent entries only; unlimited when unset
+    pub(crate) limit: Option<usize>,
+}
+
+impl AuditQuery {
+    pub(crate) fn matches(&self, entry: &AuditEntry) -> bool {
+        self.action.as_deref().is_none_or(|action| {
+            entry.action == action
+                || entry
+                    .action
+                    .strip_prefix(action)
+                    .is_some_and(|rest| rest.starts_with('.'))
+        }) && self
+            .actor
+            .as_deref()
+            .is_none_or(|actor| entry.actor.as_deref() == Some(actor))
+            && self
+                .target
+                .as_deref()
+                .is_none_or(|target| entry.target.as_deref() == Some(target))
+            && self.outcome.is_none_or(|outcome| outcome == entry.outcome)
+            && self
+                .since_ms
+                .is_none_or(|since| entry.timestamp_ms >= since)
+            && self.until_ms.is_none_or(|until| entry.timestamp_ms < until)
+    }
+}
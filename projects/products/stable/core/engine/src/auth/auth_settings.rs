@@ -0,0 +1,12 @@
+// projects/products/stable/core/engine/src/auth/auth_settings.rs
+use super::RateLimit;
+
+/// Login throttling and client address settings
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AuthSettings {
+    pub(crate) login_ip_limit: RateLimit,
+    pub(crate) login_account_limit: RateLimit,
+    /// Take the client IP from `X-Forwarded-For` (engine behind a proxy
+    /// such as central_ui); otherwise the socket address is used
+    pub(crate) trust_forwarded_for: bool,
+}
@@ -0,0 +1,15 @@
+// projects/products/stable/core/engine/src/auth/engine_policy.rs
+use security::PolicyEngine;
+use security::policy::PolicyError;
+
+use super::PolicyRuleConfig;
+
+/// Built-in roles plus the configured rules. Any invalid rule is an error so
+/// that a mistyped deny rule never leaves a resource open.
+pub(crate) fn engine_policy(rules: &[PolicyRuleConfig]) -> Result<PolicyEngine, PolicyError> {
+    let mut policy = PolicyEngine::with_builtin_roles();
+    for rule in rules {
+        policy.add_rule(rule.to_rule()?)?;
+    }
+    Ok(policy)
+}
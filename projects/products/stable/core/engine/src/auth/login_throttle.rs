@@ -0,0 +1,51 @@
+// projects/products/stable/core/engine/src/auth/login_throttle.rs
+use super::{RateLimit, RateLimiter};
+
+/// Login attempts per client IP per minute
+pub(crate) const DEFAULT_LOGIN_IP_LIMIT: RateLimit = RateLimit::new(20, 60_000);
+/// Failed logins per account per 15 minutes
+pub(crate) const DEFAULT_LOGIN_ACCOUNT_LIMIT: RateLimit = RateLimit::new(5, 15 * 60_000);
+
+/// Login rate limits: every attempt counts against the client IP, failed
+/// attempts count against the account until a successful login.
+#[derive(Debug)]
+pub(crate) struct LoginThrottle {
+    per_ip: RateLimiter,
+    per_account: RateLimiter,
+}
+
+impl LoginThrottle {
+    pub(crate) fn new(ip_limit: RateLimit, account_limit: RateLimit) -> Self {
+        Self {
+            per_ip: RateLimiter::new(ip_limit),
+            per_account: RateLimiter::new(account_limit),
+        }
+    }
+
+    /// Records an attempt unless the IP or the account is throttled, in
+    /// which case the milliseconds to wait are returned
+    pub(crate) fn attempt(
+        &mut self,
+        ip: Option<&str>,
+        account: &str,
+        now_ms: u64,
+    ) -> Result<(), u64> {
+        let ip_wait = ip.and_then(|ip| self.per_ip.retry_after_ms(ip, now_ms));
+        let account_wait = self.per_account.retry_after_ms(account, now_ms);
+        if let Some(wait) = ip_wait.max(account_wait) {
+            return Err(wait);
+        }
+        if let Some(ip) = ip {
+            self.per_ip.record(ip, now_ms);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn failed(&mut self, account: &str, now_ms: u64) {
+        self.per_account.record(account, now_ms);
+    }
+
+    pub(crate) fn succeeded(&mut self, account: &str) {
+        self.per_account.reset(account);
+    }
+}
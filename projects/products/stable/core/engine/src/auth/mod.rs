@@ -0,0 +1,32 @@
+// projects/products/stable/core/engine/src/auth/mod.rs
+mod audit_entry;
+mod audit_log;
+mod audit_log_error;
+mod audit_outcome;
+mod audit_query;
+mod auth_settings;
+mod engine_policy;
+mod login_throttle;
+mod policy_rule_config;
+mod rate_limit;
+mod rate_limiter;
+mod session_info;
+mod session_registry;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use audit_entry::AuditEntry;
+pub(crate) use audit_log::{AuditLog, DEFAULT_RETAINED_AUDIT_ENTRIES};
+pub(crate) use audit_log_error::AuditLogError;
+pub(crate) use audit_outcome::AuditOutcome;
+pub(crate) use audit_query::AuditQuery;
+pub(crate) use auth_settings::AuthSettings;
+pub(crate) use engine_policy::engine_policy;
+pub(crate) use login_throttle::{
+    DEFAULT_LOGIN_ACCOUNT_LIMIT, DEFAULT_LOGIN_IP_LIMIT, LoginThrottle,
+};
+pub(crate) use policy_rule_config::PolicyRuleConfig;
+pub(crate) use rate_limit::RateLimit;
+pub(crate) use rate_limiter::RateLimiter;
+pub(crate) use session_info::SessionInfo;
+pub(crate) use session_registry::SessionRegistry;
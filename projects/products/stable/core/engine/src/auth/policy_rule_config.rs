@@ -0,0 +1,35 @@
+// projects/products/stable/core/engine/src/auth/policy_rule_config.rs
+use security::policy::{PolicyEffect, PolicyError, PolicyRule, PolicySubject};
+use serde::Deserialize;
+
+/// One rule of the `ENGINE_POLICY_PATH` file, added on top of the built-in
+/// roles. Resources default to every resource.
+///
+/// ```json
+/// { "id": "no-git-for-users", "effect": "deny", "subject": { "kind": "role", "value": "user" },
+///   "actions": ["*"], "resources": ["route:git_autopilot.*"] }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct PolicyRuleConfig {
+    pub(crate) id: String,
+    pub(crate) effect: PolicyEffect,
+    pub(crate) subject: PolicySubject,
+    pub(crate) actions: Vec<String>,
+    #[serde(default)]
+    pub(crate) resources: Vec<String>,
+}
+
+impl PolicyRuleConfig {
+    pub(crate) fn to_rule(&self) -> Result<PolicyRule, PolicyError> {
+        let actions: Vec<&str> = self.actions.iter().map(String::as_str).collect();
+        let rule = match self.effect {
+            PolicyEffect::Allow => PolicyRule::allow(&self.id, self.subject.clone(), &actions)?,
+            PolicyEffect::Deny => PolicyRule::deny(&self.id, self.subject.clone(), &actions)?,
+        };
+        if self.resources.is_empty() {
+            return Ok(rule);
+        }
+        let resources: Vec<&str> = self.resources.iter().map(String::as_str).collect();
+        rule.on(&resources)
+    }
+}
@@ -0,0 +1,17 @@
+// projects/products/stable/core/engine/src/auth/rate_limit.rs
+
+/// Attempts allowed per key within a sliding window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RateLimit {
+    pub(crate) max_attempts: usize,
+    pub(crate) window_ms: u64,
+}
+
+impl RateLimit {
+    pub(crate) const fn new(max_attempts: usize, window_ms: u64) -> Self {
+        Self {
+            max_attempts,
+            window_ms,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// projects/products/stable/core/engine/src/auth/rate_limiter.rs
+use std::collections::{HashMap, VecDeque};
+
+use super::RateLimit;
+
+/// Keys tracked before idle ones are dropped
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Sliding-window attempt counter per key (IP address, account id, ...)
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    attempts: HashMap<String, VecDeque<u64>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            attempts: HashMap::new(),
+        }
+    }
+
+    /// Milliseconds until `key` may try again; `None` when it may try now
+    pub(crate) fn retry_after_ms(&mut self, key: &str, now_ms: u64) -> Option<u64> {
+        let window_ms = self.limit.window_ms;
+        let attempts = self.attempts.get_mut(key)?;
+        expire(attempts, now_ms, window_ms);
+        if attempts.len() < self.limit.max_attempts {
+            return None;
+        }
+        let oldest = attempts.front().copied().unwrap_or(now_ms);
+        Some((oldest + window_ms).saturating_sub(now_ms).max(1))
+    }
+
+    pub(crate) fn record(&mut self, key: &str, now_ms: u64) {
+        if self.attempts.len() >= PRUNE_THRESHOLD {
+            self.prune(now_ms);
+        }
+        let attempts = self.attempts.entry(key.to_string()).or_default();
+        expire(attempts, now_ms, self.limit.window_ms);
+        attempts.push_back(now_ms);
+    }
+
+    /// Forgets the attempts of `key` (e.g. after a successful login)
+    pub(crate) fn reset(&mut self, key: &str) {
+        self.attempts.remove(key);
+    }
+
+    fn prune(&mut self, now_ms: u64) {
+        let window_ms = self.limit.window_ms;
+        self.attempts.retain(|_, attempts| {
+            expire(attempts, now_ms, window_ms);
+            !attempts.is_empty()
+        });
+    }
+}
+
+fn expire(attempts: &mut VecDeque<u64>, now_ms: u64, window_ms: u64) {
+    while attempts
+        .front()
+        .is_some_and(|at| at.saturating_add(window_ms) <= now_ms)
+    {
+        attempts.pop_front();
+    }
+}
@@ -0,0 +1,18 @@
+// projects/products/stable/core/engine/src/auth/session_info.rs
+use protocol::protocol_id::ProtocolId;
+use security::Role;
+use serde::Serialize;
+
+/// A login session (`sid`) seen by the engine
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct SessionInfo {
+    pub(crate) session_id: ProtocolId,
+    pub(crate) subject_id: String,
+    pub(crate) role: Role,
+    pub(crate) created_ms: u64,
+    /// Expiration of the latest token seen for the session
+    pub(crate) expires_at_ms: u64,
+    pub(crate) last_seen_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ip: Option<String>,
+}
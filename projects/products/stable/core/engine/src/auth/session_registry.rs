@@ -0,0 +1,90 @@
+// projects/products/stable/core/engine/src/auth/session_registry.rs
+use std::collections::HashMap;
+
+use protocol::protocol_id::ProtocolId;
+use security::Token;
+use tokio::sync::broadcast;
+
+use super::SessionInfo;
+
+/// Killed session ids buffered for open connections
+const KILL_BUFFER: usize = 64;
+
+/// Sessions opened through the engine (login) or used on a WebSocket
+/// connection since it started, until they expire or are killed.
+#[derive(Debug)]
+pub(crate) struct SessionRegistry {
+    sessions: HashMap<ProtocolId, SessionInfo>,
+    killed: broadcast::Sender<ProtocolId>,
+}
+
+impl SessionRegistry {
+    pub(crate) fn new() -> Self {
+        let (killed, _) = broadcast::channel(KILL_BUFFER);
+        Self {
+            sessions: HashMap::new(),
+            killed,
+        }
+    }
+
+    /// Records a use of `token`'s session. Tokens without `sid` are ignored.
+    pub(crate) fn touch(&mut self, token: &Token, ip: Option<&str>, now_ms: u64) {
+        let Some(session_id) = token.session_id else {
+            return;
+        };
+        let session = self
+            .sessions
+            .entry(session_id)
+            .or_insert_with(|| SessionInfo {
+                session_id,
+                subject_id: token.subject_id.to_string(),
+                role: token.role,
+                created_ms: now_ms,
+                expires_at_ms: token.expires_at_ms,
+                last_seen_ms: now_ms,
+                ip: None,
+            });
+        session.expires_at_ms = session.expires_at_ms.max(token.expires_at_ms);
+        session.last_seen_ms = now_ms;
+        if let Some(ip) = ip {
+            session.ip = Some(ip.to_string());
+        }
+    }
+
+    pub(crate) fn get(&self, session_id: &ProtocolId) -> Option<&SessionInfo> {
+        self.sessions.get(session_id)
+    }
+
+    /// Unexpired sessions, optionally of one subject, most recently seen first.
+    /// Expired sessions are dropped.
+    pub(crate) fn list(&mut self, subject_id: Option<&str>, now_ms: u64) -> Vec<SessionInfo> {
+        self.sessions
+            .retain(|_, session| session.expires_at_ms > now_ms);
+        let mut sessions: Vec<SessionInfo> = self
+            .sessions
+            .values()
+            .filter(|session| subject_id.is_none_or(|s| s == session.subject_id))
+            .cloned()
+            .collect();
+        sessions.sort_by_key(|session| std::cmp::Reverse(session.last_seen_ms));
+        sessions
+    }
+
+    /// Forgets the session and tells open connections using it to close
+    pub(crate) fn kill(&mut self, session_id: &ProtocolId) -> Option<SessionInfo> {
+        // No open connection is not an error
+        let _ = self.killed.send(*session_id);
+        self.sessions.remove(session_id)
+    }
+
+    /// Ids of sessions killed from now on
+    pub(crate) fn subscribe_kills(&self) -> broadcast::Receiver<ProtocolId> {
+        self.killed.subscribe()
+    }
+}
+
+impl Default for SessionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,113 @@
+// projects/products/stable/core/engine/src/auth/tests/audit_log.rs
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::auth::{AuditEntry, AuditLog, AuditOutcome, AuditQuery};
+
+fn temp_path(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!(
+        "engine_audit_{}_{}_{}.jsonl",
+        std::process::id(),
+        name,
+        n
+    ))
+}
+
+fn sample(log: &mut AuditLog) {
+    let entries = [
+        AuditEntry::new("auth.login", AuditOutcome::Failure).with_actor("alice"),
+        AuditEntry::new("auth.login", AuditOutcome::Success).with_actor("alice"),
+        AuditEntry::new("accounts.create", AuditOutcome::Success)
+            .with_actor("admin")
+            .with_target("bob"),
+        AuditEntry::new("accounts.update_status", AuditOutcome::Failure)
+            .with_actor("admin")
+            .with_target("bob"),
+    ];
+    for (i, entry) in entries.into_iter().enumerate() {
+        log.record(entry, 100 * (i as u64 + 1)).unwrap();
+    }
+}
+
+#[test]
+fn record_numbers_and_timestamps_entries() {
+    let mut log = AuditLog::new();
+    let first = log
+        .record(AuditEntry::new("auth.login", AuditOutcome::Success), 42)
+        .unwrap();
+    let second = log
+        .record(AuditEntry::new("auth.login", AuditOutcome::Success), 43)
+        .unwrap();
+
+    assert_eq!((first.seq, first.timestamp_ms), (1, 42));
+    assert_eq!(second.seq, 2);
+}
+
+#[test]
+fn query_filters_newest_first() {
+    let mut log = AuditLog::new();
+    sample(&mut log);
+
+    let accounts = log.query(&AuditQuery {
+        action: Some("accounts".to_string()),
+        ..AuditQuery::default()
+    });
+    let actions: Vec<&str> = accounts.iter().map(|e| e.action.as_str()).collect();
+    assert_eq!(actions, ["accounts.update_status", "accounts.create"]);
+
+    let failures = log.query(&AuditQuery {
+        outcome: Some(AuditOutcome::Failure),
+        actor: Some("alice".to_string()),
+        ..AuditQuery::default()
+    });
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].seq, 1);
+
+    let window = log.query(&AuditQuery {
+        since_ms: Some(200),
+        until_ms: Some(400),
+        limit: Some(1),
+        ..AuditQuery::default()
+    });
+    assert_eq!(window.len(), 1);
+    assert_eq!(window[0].timestamp_ms, 300);
+
+    // A namespace does not match actions that merely share a prefix
+    let none = log.query(&AuditQuery {
+        action: Some("auth.log".to_string()),
+        ..AuditQuery::default()
+    });
+    assert!(none.is_empty());
+}
+
+#[test]
+fn open_appends_and_reloads_the_log() {
+    let path = temp_path("reload");
+    {
+        let mut log = AuditLog::new().open(&path).unwrap();
+        sample(&mut log);
+    }
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .and_then(|mut f| std::io::Write::write_all(&mut f, b"{\"seq\":9,\"act\n"))
+        .unwrap();
+
+    let mut log = AuditLog::new().with_retained(2).open(&path).unwrap();
+    let entries = log.query(&AuditQuery::default());
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].seq, 4);
+
+    let next = log
+        .record(AuditEntry::new("session.kill", AuditOutcome::Success), 900)
+        .unwrap();
+    assert_eq!(next.seq, 5);
+    // Dropping the log waits for its writer
+    drop(log);
+    let lines = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(lines.lines().count(), 6);
+
+    let _ = std::fs::remove_file(&path);
+}
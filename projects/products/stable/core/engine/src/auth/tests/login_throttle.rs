@@ -0,0 +1,48 @@
+// projects/products/stable/core/engine/src/auth/tests/login_throttle.rs
+use crate::auth::{LoginThrottle, RateLimit, RateLimiter};
+
+#[test]
+fn limiter_blocks_until_the_oldest_attempt_leaves_the_window() {
+    let mut limiter = RateLimiter::new(RateLimit::new(2, 1_000));
+    assert_eq!(limiter.retry_after_ms("a", 0), None);
+    limiter.record("a", 0);
+    limiter.record("a", 400);
+
+    assert_eq!(limiter.retry_after_ms("a", 500), Some(500));
+    assert_eq!(limiter.retry_after_ms("b", 500), None);
+    assert_eq!(limiter.retry_after_ms("a", 1_000), None);
+}
+
+#[test]
+fn limiter_reset_forgets_attempts() {
+    let mut limiter = RateLimiter::new(RateLimit::new(1, 1_000));
+    limiter.record("a", 0);
+    assert!(limiter.retry_after_ms("a", 10).is_some());
+
+    limiter.reset("a");
+    assert_eq!(limiter.retry_after_ms("a", 10), None);
+}
+
+#[test]
+fn throttle_counts_every_attempt_per_ip() {
+    let mut throttle = LoginThrottle::new(RateLimit::new(2, 60_000), RateLimit::new(5, 60_000));
+    assert!(throttle.attempt(Some("10.0.0.1"), "alice", 0).is_ok());
+    assert!(throttle.attempt(Some("10.0.0.1"), "bob", 1).is_ok());
+
+    assert_eq!(throttle.attempt(Some("10.0.0.1"), "carol", 2), Err(59_998));
+    assert!(throttle.attempt(Some("10.0.0.2"), "carol", 2).is_ok());
+    // Unknown client address: only the account limit applies
+    assert!(throttle.attempt(None, "carol", 2).is_ok());
+}
+
+#[test]
+fn throttle_counts_failures_per_account_until_success() {
+    let mut throttle = LoginThrottle::new(RateLimit::new(100, 60_000), RateLimit::new(2, 60_000));
+    throttle.failed("alice", 0);
+    throttle.failed("alice", 1);
+    assert!(throttle.attempt(Some("10.0.0.9"), "alice", 2).is_err());
+    assert!(throttle.attempt(Some("10.0.0.9"), "bob", 2).is_ok());
+
+    throttle.succeeded("alice");
+    assert!(throttle.attempt(Some("10.0.0.9"), "alice", 3).is_ok());
+}
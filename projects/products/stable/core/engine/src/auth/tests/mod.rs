@@ -0,0 +1,3 @@
+mod audit_log;
+mod login_throttle;
+mod session_registry;
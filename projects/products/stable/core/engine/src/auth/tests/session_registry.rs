@@ -0,0 +1,66 @@
+// projects/products/stable/core/engine/src/auth/tests/session_registry.rs
+use common::Id128;
+use protocol::protocol_id::ProtocolId;
+use security::{Role, Token};
+
+use crate::auth::SessionRegistry;
+
+fn id(byte: u8) -> ProtocolId {
+    ProtocolId::new(Id128::from_bytes_unchecked([byte; 16]))
+}
+
+fn token(subject: u8, session: Option<u8>, expires_at_ms: u64) -> Token {
+    Token {
+        value: id(200),
+        subject_id: id(subject),
+        role: Role::User,
+        issued_at_ms: 0,
+        expires_at_ms,
+        session_id: session.map(id),
+    }
+}
+
+#[test]
+fn touch_tracks_sessions_with_a_sid() {
+    let mut sessions = SessionRegistry::new();
+    sessions.touch(&token(1, Some(10), 1_000), Some("10.0.0.1"), 5);
+    sessions.touch(&token(1, Some(10), 2_000), None, 7);
+    sessions.touch(&token(2, None, 1_000), None, 8);
+
+    let list = sessions.list(None, 10);
+    assert_eq!(list.len(), 1);
+    let session = &list[0];
+    assert_eq!(session.session_id, id(10));
+    assert_eq!(session.created_ms, 5);
+    assert_eq!(session.last_seen_ms, 7);
+    assert_eq!(session.expires_at_ms, 2_000);
+    assert_eq!(session.ip.as_deref(), Some("10.0.0.1"));
+}
+
+#[test]
+fn list_drops_expired_sessions_and_filters_by_subject() {
+    let mut sessions = SessionRegistry::new();
+    sessions.touch(&token(1, Some(10), 1_000), None, 1);
+    sessions.touch(&token(1, Some(11), 5_000), None, 2);
+    sessions.touch(&token(2, Some(12), 5_000), None, 3);
+
+    let all = sessions.list(None, 2_000);
+    let ids: Vec<ProtocolId> = all.iter().map(|s| s.session_id).collect();
+    assert_eq!(ids, [id(12), id(11)]);
+    assert!(sessions.get(&id(10)).is_none());
+
+    let mine = sessions.list(Some(&id(1).to_string()), 2_000);
+    assert_eq!(mine.len(), 1);
+    assert_eq!(mine[0].session_id, id(11));
+}
+
+#[test]
+fn kill_removes_the_session_and_notifies_connections() {
+    let mut sessions = SessionRegistry::new();
+    sessions.touch(&token(1, Some(10), 1_000), None, 1);
+    let mut kills = sessions.subscribe_kills();
+
+    assert!(sessions.kill(&id(10)).is_some());
+    assert!(sessions.kill(&id(10)).is_none());
+    assert_eq!(kills.try_recv().unwrap(), id(10));
+}
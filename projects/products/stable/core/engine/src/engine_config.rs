@@ -1,9 +1,15 @@
 // projects/products/stable/core/engine/src/config.rs
 use std::{env, net::IpAddr, path::PathBuf};
 
-use anyhow::bail;
+use anyhow::{Context, bail};
 
 use crate::CorsConfig;
+use crate::auth::{
+    AuthSettings, DEFAULT_LOGIN_ACCOUNT_LIMIT, DEFAULT_LOGIN_IP_LIMIT,
+    DEFAULT_RETAINED_AUDIT_ENTRIES, PolicyRuleConfig, RateLimit,
+};
+use crate::jobs::{DEFAULT_COMPACT_AFTER_LINES, DEFAULT_RETAINED_EVENTS, DEFAULT_RETAINED_JOBS};
+use crate::runtime::{BackendLimits, LoadBalancing};
 
 #[derive(Debug)]
 pub(crate) struct EngineConfig {
@@ -13,6 +19,25 @@ pub(crate) struct EngineConfig {
     pub(crate) cors: CorsConfig,
     pub(crate) jwt_secret: String,
     pub(crate) allow_insecure_secret: bool,
+    pub(crate) backend_balancing: LoadBalancing,
+    pub(crate) backend_limits: BackendLimits,
+    /// JSONL file of the job store; jobs are kept in memory only when unset
+    pub(crate) jobs_path: Option<PathBuf>,
+    /// Job events kept for replay to reconnecting clients
+    pub(crate) jobs_retained_events: usize,
+    /// Finished jobs kept in memory and in the job store file
+    pub(crate) jobs_retained_jobs: usize,
+    /// Lines appended to the job store file before it is compacted again
+    pub(crate) jobs_compact_after_lines: usize,
+    pub(crate) auth: AuthSettings,
+    /// JSONL audit log; kept in memory only when unset
+    pub(crate) audit_path: Option<PathBuf>,
+    /// Audit entries kept in memory for queries
+    pub(crate) audit_retained_entries: usize,
+    /// Revoked session ids; kept in memory only when unset
+    pub(crate) revoked_sessions_path: Option<PathBuf>,
+    /// Rules added to the built-in roles, from `ENGINE_POLICY_PATH`
+    pub(crate) policy_rules: Vec<PolicyRuleConfig>,
 }
 
 impl EngineConfig {
@@ -51,6 +76,85 @@ impl EngineConfig {
             None => bail!("ENGINE_JWT_SECRET is required"),
         };
 
+        let backend_balancing = match Self::env_var("ENGINE_BACKEND_BALANCING") {
+            Some(raw) => raw.parse::<LoadBalancing>().map_err(anyhow::Error::msg)?,
+            None => LoadBalancing::default(),
+        };
+
+        let defaults = BackendLimits::default();
+        let backend_limits = BackendLimits {
+            queue_capacity: Self::env_usize(
+                "ENGINE_BACKEND_QUEUE_CAPACITY",
+                defaults.queue_capacity,
+            )?
+            .max(1),
+            max_pending_requests: Self::env_usize(
+                "ENGINE_MAX_PENDING_REQUESTS",
+                defaults.max_pending_requests,
+            )?,
+            default_timeout_ms: Self::env_u64(
+                "ENGINE_BACKEND_TIMEOUT_MS",
+                defaults.default_timeout_ms,
+            )?,
+            max_timeout_ms: Self::env_u64(
+                "ENGINE_BACKEND_MAX_TIMEOUT_MS",
+                defaults.max_timeout_ms,
+            )?,
+        };
+
+        let jobs_path = Self::env_path("ENGINE_JOBS_PATH", "engine_jobs.jsonl");
+
+        let jobs_retained_events =
+            Self::env_usize("ENGINE_JOBS_RETAINED_EVENTS", DEFAULT_RETAINED_EVENTS)?;
+        let jobs_retained_jobs =
+            Self::env_usize("ENGINE_JOBS_RETAINED_JOBS", DEFAULT_RETAINED_JOBS)?;
+        let jobs_compact_after_lines = Self::env_usize(
+            "ENGINE_JOBS_COMPACT_AFTER_LINES",
+            DEFAULT_COMPACT_AFTER_LINES,
+        )?;
+
+        let auth = AuthSettings {
+            login_ip_limit: RateLimit::new(
+                Self::env_usize("ENGINE_LOGIN_IP_LIMIT", DEFAULT_LOGIN_IP_LIMIT.max_attempts)?,
+                Self::env_u64(
+                    "ENGINE_LOGIN_IP_WINDOW_MS",
+                    DEFAULT_LOGIN_IP_LIMIT.window_ms,
+                )?,
+            ),
+            login_account_limit: RateLimit::new(
+                Self::env_usize(
+                    "ENGINE_LOGIN_ACCOUNT_LIMIT",
+                    DEFAULT_LOGIN_ACCOUNT_LIMIT.max_attempts,
+                )?,
+                Self::env_u64(
+                    "ENGINE_LOGIN_ACCOUNT_WINDOW_MS",
+                    DEFAULT_LOGIN_ACCOUNT_LIMIT.window_ms,
+                )?,
+            ),
+            trust_forwarded_for: Self::env_var("ENGINE_TRUST_FORWARDED_FOR").as_deref()
+                == Some("1"),
+        };
+
+        let audit_path = Self::env_path("ENGINE_AUDIT_PATH", "engine_audit.jsonl");
+        let audit_retained_entries = Self::env_usize(
+            "ENGINE_AUDIT_RETAINED_ENTRIES",
+            DEFAULT_RETAINED_AUDIT_ENTRIES,
+        )?;
+        let revoked_sessions_path = Self::env_path(
+            "ENGINE_REVOKED_SESSIONS_PATH",
+            "engine_revoked_sessions.txt",
+        );
+
+        let policy_rules = match Self::env_var("ENGINE_POLICY_PATH") {
+            Some(path) => {
+                let raw = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read ENGINE_POLICY_PATH {path}"))?;
+                common_json::from_json_str::<Vec<PolicyRuleConfig>>(&raw)
+                    .map_err(|e| anyhow::anyhow!("Invalid policy rules in {path}: {e}"))?
+            }
+            None => Vec::new(),
+        };
+
         Ok(Self {
             host,
             port,
@@ -58,6 +162,31 @@ impl EngineConfig {
             cors,
             jwt_secret,
             allow_insecure_secret,
+            backend_balancing,
+            backend_limits,
+            jobs_path,
+            jobs_retained_events,
+            jobs_retained_jobs,
+            jobs_compact_after_lines,
+            auth,
+            audit_path,
+            audit_retained_entries,
+            revoked_sessions_path,
+            policy_rules,
+        })
+    }
+
+    /// Path from `key`, else `file_name` in `$HOME/.automation_project`
+    pub(crate) fn env_path(key: &str, file_name: &str) -> Option<PathBuf> {
+        Self::env_var(key).map(PathBuf::from).or_else(|| {
+            env::var("HOME")
+                .or_else(|_| env::var("USERPROFILE"))
+                .ok()
+                .map(|home| {
+                    PathBuf::from(home)
+                        .join(".automation_project")
+                        .join(file_name)
+                })
         })
     }
 
@@ -74,6 +203,18 @@ impl EngineConfig {
             .unwrap_or(default))
     }
 
+    pub(crate) fn env_usize(key: &str, default: usize) -> anyhow::Result<usize> {
+        Ok(Self::env_var(key)
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(default))
+    }
+
+    pub(crate) fn env_u64(key: &str, default: u64) -> anyhow::Result<u64> {
+        Ok(Self::env_var(key)
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(default))
+    }
+
     pub(crate) fn env_ip(key: &str, default: &str) -> anyhow::Result<IpAddr> {
         Ok(Self::env_var(key)
             .and_then(|v| v.parse::<IpAddr>().ok())
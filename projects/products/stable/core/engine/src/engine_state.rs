@@ -1,11 +1,14 @@
 // projects/products/stable/core/engine/src/engine_state.rs
 use std::sync::Arc;
 
-use security::TokenService;
-use tokio::sync::RwLock;
+use security::{PolicyEngine, TokenService};
+use tokio::sync::{Mutex, RwLock};
+use tracing::warn;
 
 use crate::Registry;
-use crate::runtime::BackendRegistry;
+use crate::auth::{AuditEntry, AuditLog, AuthSettings, LoginThrottle, SessionRegistry};
+use crate::jobs::JobStore;
+use crate::runtime::{BackendLimits, BackendMetrics, BackendRegistry, LoadBalancing};
 use protocol::{Event, ProtocolId};
 use std::collections::HashMap;
 use tokio::sync::oneshot;
@@ -14,17 +17,61 @@ use tokio::sync::oneshot;
 pub(crate) struct EngineState {
     pub(crate) registry: Arc<RwLock<Registry>>,
     pub(crate) token_service: Arc<TokenService>,
+    pub(crate) policy: Arc<PolicyEngine>,
     pub(crate) backend_registry: Arc<RwLock<BackendRegistry>>,
     pub(crate) pending_requests: Arc<RwLock<HashMap<ProtocolId, oneshot::Sender<Event>>>>,
+    pub(crate) backend_limits: BackendLimits,
+    pub(crate) backend_metrics: Arc<BackendMetrics>,
+    pub(crate) jobs: Arc<RwLock<JobStore>>,
+    pub(crate) auth_settings: AuthSettings,
+    pub(crate) login_throttle: Arc<Mutex<LoginThrottle>>,
+    pub(crate) audit_log: Arc<RwLock<AuditLog>>,
+    pub(crate) sessions: Arc<RwLock<SessionRegistry>>,
 }
 
 impl EngineState {
-    pub(crate) fn new(registry: Registry, token_service: TokenService) -> Self {
+    pub(crate) fn new(
+        registry: Registry,
+        token_service: TokenService,
+        backend_balancing: LoadBalancing,
+        backend_limits: BackendLimits,
+        jobs: JobStore,
+        auth_settings: AuthSettings,
+        audit_log: AuditLog,
+    ) -> Self {
         Self {
             registry: Arc::new(RwLock::new(registry)),
             token_service: Arc::new(token_service),
-            backend_registry: Arc::new(RwLock::new(BackendRegistry::new())),
+            policy: Arc::new(PolicyEngine::with_builtin_roles()),
+            backend_registry: Arc::new(RwLock::new(BackendRegistry::with_balancing(
+                backend_balancing,
+            ))),
             pending_requests: Arc::new(RwLock::new(HashMap::new())),
+            backend_limits,
+            backend_metrics: Arc::new(BackendMetrics::default()),
+            jobs: Arc::new(RwLock::new(jobs)),
+            auth_settings,
+            login_throttle: Arc::new(Mutex::new(LoginThrottle::new(
+                auth_settings.login_ip_limit,
+                auth_settings.login_account_limit,
+            ))),
+            audit_log: Arc::new(RwLock::new(audit_log)),
+            sessions: Arc::new(RwLock::new(SessionRegistry::new())),
+        }
+    }
+
+    /// Replaces the built-in roles policy, e.g. with configured rules added
+    pub(crate) fn with_policy(mut self, policy: PolicyEngine) -> Self {
+        self.policy = Arc::new(policy);
+        self
+    }
+
+    /// Appends to the audit log. A failed write is logged, not returned:
+    /// the audited action has already happened.
+    pub(crate) async fn audit(&self, entry: AuditEntry) {
+        let now_ms = protocol::Metadata::current_timestamp_ms();
+        if let Err(e) = self.audit_log.write().await.record(entry, now_ms) {
+            warn!(error = %e, "Failed to write audit log entry");
         }
     }
 }
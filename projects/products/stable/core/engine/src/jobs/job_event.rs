@@ -0,0 +1,25 @@
+// projects/products/stable/core/engine/src/jobs/job_event.rs
+use protocol::protocol_id::ProtocolId;
+use protocol::{EventType, Payload};
+use serde::{Deserialize, Serialize};
+
+use super::JobStatus;
+
+/// One step of a job, numbered by a store-wide cursor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JobEvent {
+    /// Increases by one for every event of the store; clients resume after
+    /// the last `seq` they saw
+    pub(crate) seq: u64,
+    pub(crate) job_id: ProtocolId,
+    pub(crate) timestamp_ms: u64,
+    pub(crate) event_type: EventType,
+    /// Job status once this event is applied
+    pub(crate) status: JobStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pct: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) payload: Option<Payload>,
+}
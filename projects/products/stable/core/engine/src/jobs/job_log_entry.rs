@@ -0,0 +1,13 @@
+// projects/products/stable/core/engine/src/jobs/job_log_entry.rs
+use serde::{Deserialize, Serialize};
+
+use super::{JobEvent, JobRecord};
+
+/// One line of the job store file
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum JobLogEntry {
+    /// A job as it was created; its events follow
+    Job(JobRecord),
+    Event(JobEvent),
+}
@@ -0,0 +1,27 @@
+// projects/products/stable/core/engine/src/jobs/job_query.rs
+use protocol::protocol_id::ProtocolId;
+
+use super::{JobRecord, JobStatus};
+
+/// Filter for the job history; unset fields match every job
+#[derive(Debug, Clone, Default)]
+pub(crate) struct JobQuery {
+    pub(crate) product_id: Option<ProtocolId>,
+    pub(crate) status: Option<JobStatus>,
+    pub(crate) subject_id: Option<String>,
+    pub(crate) action: Option<String>,
+    /// Most recent jobs only; unlimited when unset
+    pub(crate) limit: Option<usize>,
+}
+
+impl JobQuery {
+    pub(crate) fn matches(&self, job: &JobRecord) -> bool {
+        self.product_id.is_none_or(|p| p == job.product_id)
+            && self.status.is_none_or(|s| s == job.status)
+            && self
+                .subject_id
+                .as_deref()
+                .is_none_or(|s| s == job.subject_id)
+            && self.action.as_deref().is_none_or(|a| a == job.action)
+    }
+}
@@ -0,0 +1,71 @@
+// projects/products/stable/core/engine/src/jobs/job_record.rs
+use common_json::Json;
+use protocol::protocol_id::ProtocolId;
+use serde::{Deserialize, Serialize};
+
+use super::{JobEvent, JobStatus};
+
+/// Current state of one job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JobRecord {
+    pub(crate) job_id: ProtocolId,
+    pub(crate) product_id: ProtocolId,
+    pub(crate) action: String,
+    /// Subject of the token that started the job
+    pub(crate) subject_id: String,
+    pub(crate) status: JobStatus,
+    pub(crate) created_ms: u64,
+    pub(crate) updated_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) progress_pct: Option<u8>,
+    /// `seq` of the latest event of the job
+    pub(crate) last_seq: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) result: Option<Json>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) error: Option<String>,
+}
+
+impl JobRecord {
+    pub(crate) fn new(
+        job_id: ProtocolId,
+        product_id: ProtocolId,
+        action: &str,
+        subject_id: &str,
+        now_ms: u64,
+    ) -> Self {
+        Self {
+            job_id,
+            product_id,
+            action: action.to_string(),
+            subject_id: subject_id.to_string(),
+            status: JobStatus::Running,
+            created_ms: now_ms,
+            updated_ms: now_ms,
+            progress_pct: None,
+            last_seq: 0,
+            result: None,
+            error: None,
+        }
+    }
+
+    /// Folds `event` into the record
+    pub(crate) fn apply(&mut self, event: &JobEvent) {
+        self.status = event.status;
+        self.updated_ms = event.timestamp_ms;
+        self.last_seq = event.seq;
+        if event.pct.is_some() {
+            self.progress_pct = event.pct;
+        }
+        match event.status {
+            JobStatus::Succeeded => {
+                self.progress_pct = Some(100);
+                self.result = event.payload.as_ref().and_then(|p| p.payload.clone());
+            }
+            JobStatus::Failed | JobStatus::Cancelled => {
+                self.error = event.message.clone();
+            }
+            JobStatus::Running => {}
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// projects/products/stable/core/engine/src/jobs/job_replay.rs
+use serde::Serialize;
+
+use super::JobEvent;
+
+/// Events missed by a client resuming after a cursor
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct JobReplay {
+    pub(crate) events: Vec<JobEvent>,
+    /// Latest `seq` of the store when the replay was taken
+    pub(crate) last_seq: u64,
+    /// Events after the cursor were dropped by retention; the client should
+    /// reload job states instead of relying on the events alone
+    pub(crate) truncated: bool,
+}
@@ -0,0 +1,48 @@
+// projects/products/stable/core/engine/src/jobs/job_status.rs
+use protocol::EventType;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Lifecycle of a job started through the engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum JobStatus {
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    /// No event changes a finished job anymore
+    pub(crate) fn is_terminal(&self) -> bool {
+        !matches!(self, Self::Running)
+    }
+
+    /// Status of a job after an event of `event_type`
+    pub(crate) fn after(self, event_type: EventType) -> Self {
+        if self.is_terminal() {
+            return self;
+        }
+        match event_type {
+            EventType::Completed | EventType::JobFinished => Self::Succeeded,
+            EventType::Error | EventType::Failed => Self::Failed,
+            EventType::Stopped => Self::Cancelled,
+            _ => self,
+        }
+    }
+}
+
+impl FromStr for JobStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "running" => Ok(Self::Running),
+            "succeeded" => Ok(Self::Succeeded),
+            "failed" => Ok(Self::Failed),
+            "cancelled" => Ok(Self::Cancelled),
+            other => Err(format!("Unknown job status '{other}'")),
+        }
+    }
+}
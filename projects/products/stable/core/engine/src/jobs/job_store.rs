@@ -0,0 +1,392 @@
+// projects/products/stable/core/engine/src/jobs/job_store.rs
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use common_json::{from_json_str, to_string};
+use protocol::protocol_id::ProtocolId;
+use protocol::{Event, EventType, Payload};
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+use super::{JobEvent, JobLogEntry, JobQuery, JobRecord, JobReplay, JobStatus, JobStoreError};
+use crate::line_writer::{LineWriter, write_lines};
+
+/// Events kept for replay; older ones are dropped
+pub(crate) const DEFAULT_RETAINED_EVENTS: usize = 10_000;
+/// Finished jobs kept; the oldest finished ones are dropped, running jobs
+/// are always kept
+pub(crate) const DEFAULT_RETAINED_JOBS: usize = 10_000;
+/// Lines appended since the last compaction before the file may be
+/// compacted again; it also waits for the file to hold twice the live lines
+pub(crate) const DEFAULT_COMPACT_AFTER_LINES: usize = 50_000;
+/// Live events buffered per subscriber before it has to catch up from the store
+const SUBSCRIBER_BUFFER: usize = 256;
+/// Error of the jobs failed on open because they were running when the
+/// engine stopped
+pub(crate) const INTERRUPTED_MESSAGE: &str = "interrupted by an engine restart";
+
+/// Jobs started through the engine and their events.
+///
+/// A store opened on a file appends one JSON line per job and per event, so
+/// job states and the event cursor survive engine restarts. The file is
+/// compacted to the retained jobs and events when the store is opened and
+/// whenever enough lines were appended since. Lines are serialized by the
+/// store and written by a [`LineWriter`], outside of the lock guarding the
+/// store; so are compactions.
+#[derive(Debug)]
+pub(crate) struct JobStore {
+    jobs: HashMap<ProtocolId, JobRecord>,
+    events: VecDeque<JobEvent>,
+    /// Finished jobs, oldest finish first
+    finished: VecDeque<ProtocolId>,
+    last_seq: u64,
+    retained_events: usize,
+    retained_jobs: usize,
+    compact_after_lines: usize,
+    /// Lines appended since the file was last compacted, and the number of
+    /// lines it was compacted to
+    appended_lines: usize,
+    compacted_lines: usize,
+    path: Option<PathBuf>,
+    writer: Option<LineWriter>,
+    live: broadcast::Sender<JobEvent>,
+}
+
+impl JobStore {
+    /// In-memory store
+    pub(crate) fn new() -> Self {
+        let (live, _) = broadcast::channel(SUBSCRIBER_BUFFER);
+        Self {
+            jobs: HashMap::new(),
+            events: VecDeque::new(),
+            finished: VecDeque::new(),
+            last_seq: 0,
+            retained_events: DEFAULT_RETAINED_EVENTS,
+            retained_jobs: DEFAULT_RETAINED_JOBS,
+            compact_after_lines: DEFAULT_COMPACT_AFTER_LINES,
+            appended_lines: 0,
+            compacted_lines: 0,
+            path: None,
+            writer: None,
+            live,
+        }
+    }
+
+    pub(crate) fn with_retained_events(mut self, retained_events: usize) -> Self {
+        self.retained_events = retained_events.max(1);
+        self
+    }
+
+    pub(crate) fn with_retained_jobs(mut self, retained_jobs: usize) -> Self {
+        self.retained_jobs = retained_jobs.max(1);
+        self
+    }
+
+    pub(crate) fn with_compact_after_lines(mut self, compact_after_lines: usize) -> Self {
+        self.compact_after_lines = compact_after_lines.max(1);
+        self
+    }
+
+    /// Backs the store with the JSONL file at `path`, loading it if it
+    /// exists. Unreadable lines (e.g. a write cut by a crash) are skipped.
+    /// Jobs still running in the file can no longer report progress; they
+    /// get a `Failed` event at `now_ms`.
+    pub(crate) fn open(mut self, path: &Path, now_ms: u64) -> Result<Self, JobStoreError> {
+        if path.exists() {
+            let file = File::open(path)?;
+            let mut skipped = 0usize;
+            for line in BufReader::new(file).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match from_json_str::<JobLogEntry>(&line) {
+                    Ok(JobLogEntry::Job(job)) => {
+                        self.last_seq = self.last_seq.max(job.last_seq);
+                        self.jobs.insert(job.job_id, job);
+                    }
+                    Ok(JobLogEntry::Event(event)) => self.apply(event),
+                    Err(_) => skipped += 1,
+                }
+            }
+            if skipped > 0 {
+                warn!(skipped, path = %path.display(), "Skipped unreadable job store lines");
+            }
+            self.fail_interrupted(now_ms);
+            self.reload_finished();
+            info!(
+                jobs = self.jobs.len(),
+                events = self.events.len(),
+                last_seq = self.last_seq,
+                path = %path.display(),
+                "Opened job store"
+            );
+        }
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let lines = self.snapshot()?;
+        self.compacted_lines = lines.len();
+        write_lines(path, &lines)?;
+        self.path = Some(path.to_path_buf());
+        self.writer = Some(LineWriter::spawn(path, "job-store-writer")?);
+        Ok(self)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Latest event cursor
+    pub(crate) fn last_seq(&self) -> u64 {
+        self.last_seq
+    }
+
+    pub(crate) fn get(&self, job_id: &ProtocolId) -> Option<&JobRecord> {
+        self.jobs.get(job_id)
+    }
+
+    /// Jobs matching `query`, most recently created first
+    pub(crate) fn list(&self, query: &JobQuery) -> Vec<JobRecord> {
+        let mut jobs: Vec<JobRecord> = self
+            .jobs
+            .values()
+            .filter(|job| query.matches(job))
+            .cloned()
+            .collect();
+        jobs.sort_by(|a, b| {
+            b.created_ms
+                .cmp(&a.created_ms)
+                .then_with(|| b.job_id.to_hex().cmp(&a.job_id.to_hex()))
+        });
+        if let Some(limit) = query.limit {
+            jobs.truncate(limit);
+        }
+        jobs
+    }
+
+    /// Registers a new running job and its `Started` event
+    pub(crate) fn create(&mut self, job: JobRecord) -> Result<JobEvent, JobStoreError> {
+        if self.jobs.contains_key(&job.job_id) {
+            return Err(JobStoreError::DuplicateJob(job.job_id));
+        }
+        let job_id = job.job_id;
+        let created_ms = job.created_ms;
+        self.append(&JobLogEntry::Job(job.clone()))?;
+        self.jobs.insert(job_id, job);
+
+        let started = self.next_event(job_id, EventType::Started, JobStatus::Running, created_ms);
+        self.commit(started.clone())?;
+        Ok(started)
+    }
+
+    /// Records an event of a known, unfinished job. Returns `None` for
+    /// unknown or finished jobs.
+    pub(crate) fn record(
+        &mut self,
+        job_id: &ProtocolId,
+        event_type: EventType,
+        message: Option<String>,
+        pct: Option<u8>,
+        payload: Option<Payload>,
+        now_ms: u64,
+    ) -> Result<Option<JobEvent>, JobStoreError> {
+        let Some(job) = self.jobs.get(job_id) else {
+            return Ok(None);
+        };
+        if job.status.is_terminal() {
+            return Ok(None);
+        }
+        let status = job.status.after(event_type);
+        let mut event = self.next_event(*job_id, event_type, status, now_ms);
+        event.message = message;
+        event.pct = pct.map(|p| p.min(100));
+        event.payload = payload;
+        self.commit(event.clone())?;
+        Ok(Some(event))
+    }
+
+    /// Records a protocol event reported for the job by its backend
+    pub(crate) fn record_event(
+        &mut self,
+        job_id: &ProtocolId,
+        event: &Event,
+        now_ms: u64,
+    ) -> Result<Option<JobEvent>, JobStoreError> {
+        self.record(
+            job_id,
+            event.event_type,
+            event.message.clone(),
+            event.pct,
+            event.payload.clone(),
+            now_ms,
+        )
+    }
+
+    /// Events after `after_seq`, optionally of one job only
+    pub(crate) fn replay(&self, after_seq: u64, job_id: Option<&ProtocolId>) -> JobReplay {
+        let oldest_retained = self.events.front().map_or(self.last_seq + 1, |e| e.seq);
+        let events = self
+            .events
+            .iter()
+            .filter(|e| e.seq > after_seq)
+            .filter(|e| job_id.is_none_or(|id| *id == e.job_id))
+            .cloned()
+            .collect();
+        JobReplay {
+            events,
+            last_seq: self.last_seq,
+            truncated: after_seq + 1 < oldest_retained && after_seq < self.last_seq,
+        }
+    }
+
+    /// Live events, from the next recorded one on
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<JobEvent> {
+        self.live.subscribe()
+    }
+
+    fn fail_interrupted(&mut self, now_ms: u64) {
+        let mut interrupted: Vec<(u64, ProtocolId)> = self
+            .jobs
+            .values()
+            .filter(|job| !job.status.is_terminal())
+            .map(|job| (job.created_ms, job.job_id))
+            .collect();
+        if interrupted.is_empty() {
+            return;
+        }
+        interrupted.sort_by_key(|(created_ms, job_id)| (*created_ms, job_id.to_hex()));
+        warn!(
+            jobs = interrupted.len(),
+            "Marking jobs interrupted by the engine restart as failed"
+        );
+        for (_, job_id) in interrupted {
+            let mut event = self.next_event(job_id, EventType::Failed, JobStatus::Failed, now_ms);
+            event.message = Some(INTERRUPTED_MESSAGE.to_string());
+            self.apply(event);
+        }
+    }
+
+    /// Rebuilds the finish order from the loaded jobs, whose finishing
+    /// events may have been dropped by retention, and applies retention
+    fn reload_finished(&mut self) {
+        let mut finished: Vec<(u64, ProtocolId)> = self
+            .jobs
+            .values()
+            .filter(|job| job.status.is_terminal())
+            .map(|job| (job.last_seq, job.job_id))
+            .collect();
+        finished.sort_by_key(|(last_seq, _)| *last_seq);
+        self.finished = finished.into_iter().map(|(_, job_id)| job_id).collect();
+        self.drop_old_jobs();
+    }
+
+    fn drop_old_jobs(&mut self) {
+        while self.finished.len() > self.retained_jobs {
+            if let Some(job_id) = self.finished.pop_front() {
+                self.jobs.remove(&job_id);
+            }
+        }
+    }
+
+    fn next_event(
+        &self,
+        job_id: ProtocolId,
+        event_type: EventType,
+        status: JobStatus,
+        now_ms: u64,
+    ) -> JobEvent {
+        JobEvent {
+            seq: self.last_seq + 1,
+            job_id,
+            timestamp_ms: now_ms,
+            event_type,
+            status,
+            message: None,
+            pct: None,
+            payload: None,
+        }
+    }
+
+    fn commit(&mut self, event: JobEvent) -> Result<(), JobStoreError> {
+        self.append(&JobLogEntry::Event(event.clone()))?;
+        self.apply(event.clone());
+        // No subscriber is not an error
+        let _ = self.live.send(event);
+        self.compact_if_grown()
+    }
+
+    fn apply(&mut self, event: JobEvent) {
+        self.last_seq = self.last_seq.max(event.seq);
+        if let Some(job) = self.jobs.get_mut(&event.job_id) {
+            let was_finished = job.status.is_terminal();
+            job.apply(&event);
+            if !was_finished && job.status.is_terminal() {
+                self.finished.push_back(event.job_id);
+                self.drop_old_jobs();
+            }
+        }
+        self.events.push_back(event);
+        while self.events.len() > self.retained_events {
+            self.events.pop_front();
+        }
+    }
+
+    fn append(&mut self, entry: &JobLogEntry) -> Result<(), JobStoreError> {
+        let Some(writer) = &self.writer else {
+            return Ok(());
+        };
+        let line = to_string(entry).map_err(|e| JobStoreError::Serialization(e.to_string()))?;
+        writer.append(line);
+        self.appended_lines += 1;
+        Ok(())
+    }
+
+    /// Queues a compaction once the appended lines reach the threshold and
+    /// the file holds at least twice its compacted size
+    fn compact_if_grown(&mut self) -> Result<(), JobStoreError> {
+        if self.writer.is_none()
+            || self.appended_lines < self.compact_after_lines
+            || self.appended_lines < self.compacted_lines
+        {
+            return Ok(());
+        }
+        let lines = self.snapshot()?;
+        info!(
+            appended = self.appended_lines,
+            lines = lines.len(),
+            "Compacting job store"
+        );
+        self.compacted_lines = lines.len();
+        self.appended_lines = 0;
+        if let Some(writer) = &self.writer {
+            writer.rewrite(lines);
+        }
+        Ok(())
+    }
+
+    /// Lines of the retained jobs and of their retained events
+    fn snapshot(&self) -> Result<Vec<String>, JobStoreError> {
+        let mut jobs: Vec<&JobRecord> = self.jobs.values().collect();
+        jobs.sort_by_key(|job| job.created_ms);
+        let jobs = jobs.into_iter().map(|job| JobLogEntry::Job(job.clone()));
+        let events = self
+            .events
+            .iter()
+            .filter(|event| self.jobs.contains_key(&event.job_id))
+            .map(|event| JobLogEntry::Event(event.clone()));
+        jobs.chain(events)
+            .map(|entry| to_string(&entry).map_err(|e| JobStoreError::Serialization(e.to_string())))
+            .collect()
+    }
+}
+
+impl Default for JobStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,15 @@
+// projects/products/stable/core/engine/src/jobs/job_store_error.rs
+use protocol::protocol_id::ProtocolId;
+
+/// Errors of the persistent job store
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum JobStoreError {
+    #[error("job store I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("job store serialization error: {0}")]
+    Serialization(String),
+
+    #[error("job {0} already exists")]
+    DuplicateJob(ProtocolId),
+}
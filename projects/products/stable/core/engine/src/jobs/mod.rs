@@ -0,0 +1,22 @@
+// projects/products/stable/core/engine/src/jobs/mod.rs
+mod job_event;
+mod job_log_entry;
+mod job_query;
+mod job_record;
+mod job_replay;
+mod job_status;
+mod job_store;
+mod job_store_error;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use job_event::JobEvent;
+pub(crate) use job_log_entry::JobLogEntry;
+pub(crate) use job_query::JobQuery;
+pub(crate) use job_record::JobRecord;
+pub(crate) use job_replay::JobReplay;
+pub(crate) use job_status::JobStatus;
+pub(crate) use job_store::{
+    DEFAULT_COMPACT_AFTER_LINES, DEFAULT_RETAINED_EVENTS, DEFAULT_RETAINED_JOBS, JobStore,
+};
+pub(crate) use job_store_error::JobStoreError;
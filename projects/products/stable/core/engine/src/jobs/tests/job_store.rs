@@ -0,0 +1,318 @@
+// projects/products/stable/core/engine/src/jobs/tests/job_store.rs
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::Id128;
+use common_json::pjson;
+use protocol::protocol_id::ProtocolId;
+use protocol::{EventType, Payload};
+
+use crate::jobs::job_store::INTERRUPTED_MESSAGE;
+use crate::jobs::{JobQuery, JobRecord, JobStatus, JobStore, JobStoreError};
+
+fn id(byte: u8) -> ProtocolId {
+    ProtocolId::new(Id128::from_bytes_unchecked([byte; 16]))
+}
+
+fn job(job: u8, product: u8, subject: &str, created_ms: u64) -> JobRecord {
+    JobRecord::new(id(job), id(product), "x.run", subject, created_ms)
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!(
+        "engine_jobs_{}_{}_{}.jsonl",
+        std::process::id(),
+        name,
+        n
+    ))
+}
+
+fn progress(store: &mut JobStore, job: u8, pct: u8) -> u64 {
+    store
+        .record(&id(job), EventType::Progress, None, Some(pct), None, 10)
+        .unwrap()
+        .unwrap()
+        .seq
+}
+
+#[test]
+fn events_drive_the_job_status() {
+    let mut store = JobStore::new();
+    let started = store.create(job(1, 9, "alice", 1)).unwrap();
+    assert_eq!(started.seq, 1);
+    assert_eq!(started.status, JobStatus::Running);
+
+    assert_eq!(progress(&mut store, 1, 40), 2);
+    assert_eq!(store.get(&id(1)).unwrap().progress_pct, Some(40));
+
+    let result = Payload {
+        payload_type: Some("x/result".to_string()),
+        payload: Some(pjson!({ "ok": true })),
+    };
+    let done = store
+        .record(&id(1), EventType::Completed, None, None, Some(result), 20)
+        .unwrap()
+        .unwrap();
+    assert_eq!(done.status, JobStatus::Succeeded);
+
+    let record = store.get(&id(1)).unwrap();
+    assert_eq!(record.status, JobStatus::Succeeded);
+    assert_eq!(record.progress_pct, Some(100));
+    assert_eq!(record.result, Some(pjson!({ "ok": true })));
+    assert_eq!(record.updated_ms, 20);
+
+    // Finished jobs ignore late events, unknown jobs are ignored too
+    assert!(
+        store
+            .record(&id(1), EventType::Progress, None, Some(50), None, 30)
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        store
+            .record(&id(2), EventType::Progress, None, None, None, 30)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn failure_keeps_the_error_message() {
+    let mut store = JobStore::new();
+    store.create(job(1, 9, "alice", 1)).unwrap();
+    store
+        .record(
+            &id(1),
+            EventType::Failed,
+            Some("disk full".to_string()),
+            None,
+            None,
+            5,
+        )
+        .unwrap();
+
+    let record = store.get(&id(1)).unwrap();
+    assert_eq!(record.status, JobStatus::Failed);
+    assert_eq!(record.error.as_deref(), Some("disk full"));
+}
+
+#[test]
+fn duplicate_job_is_rejected() {
+    let mut store = JobStore::new();
+    store.create(job(1, 9, "alice", 1)).unwrap();
+    assert!(matches!(
+        store.create(job(1, 9, "alice", 2)),
+        Err(JobStoreError::DuplicateJob(_))
+    ));
+}
+
+#[test]
+fn history_query_filters_and_sorts_newest_first() {
+    let mut store = JobStore::new();
+    store.create(job(1, 9, "alice", 1)).unwrap();
+    store.create(job(2, 9, "bob", 2)).unwrap();
+    store.create(job(3, 8, "alice", 3)).unwrap();
+    store
+        .record(&id(2), EventType::Completed, None, None, None, 4)
+        .unwrap();
+
+    let all: Vec<ProtocolId> = store
+        .list(&JobQuery::default())
+        .into_iter()
+        .map(|j| j.job_id)
+        .collect();
+    assert_eq!(all, vec![id(3), id(2), id(1)]);
+
+    let alice = JobQuery {
+        subject_id: Some("alice".to_string()),
+        ..JobQuery::default()
+    };
+    assert_eq!(store.list(&alice).len(), 2);
+
+    let running_on_9 = JobQuery {
+        product_id: Some(id(9)),
+        status: Some(JobStatus::Running),
+        ..JobQuery::default()
+    };
+    let jobs = store.list(&running_on_9);
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].job_id, id(1));
+
+    let latest = JobQuery {
+        limit: Some(1),
+        ..JobQuery::default()
+    };
+    assert_eq!(store.list(&latest)[0].job_id, id(3));
+}
+
+#[test]
+fn replay_returns_events_after_the_cursor() {
+    let mut store = JobStore::new();
+    store.create(job(1, 9, "alice", 1)).unwrap();
+    store.create(job(2, 9, "alice", 1)).unwrap();
+    progress(&mut store, 1, 10);
+    progress(&mut store, 2, 20);
+
+    let replay = store.replay(2, None);
+    let seqs: Vec<u64> = replay.events.iter().map(|e| e.seq).collect();
+    assert_eq!(seqs, vec![3, 4]);
+    assert_eq!(replay.last_seq, 4);
+    assert!(!replay.truncated);
+
+    let only_job_2 = store.replay(0, Some(&id(2)));
+    let seqs: Vec<u64> = only_job_2.events.iter().map(|e| e.seq).collect();
+    assert_eq!(seqs, vec![2, 4]);
+
+    assert!(store.replay(4, None).events.is_empty());
+}
+
+#[test]
+fn replay_reports_events_dropped_by_retention() {
+    let mut store = JobStore::new().with_retained_events(2);
+    store.create(job(1, 9, "alice", 1)).unwrap();
+    for pct in [10, 20, 30] {
+        progress(&mut store, 1, pct);
+    }
+
+    let replay = store.replay(0, None);
+    assert!(replay.truncated);
+    assert_eq!(replay.events.len(), 2);
+    assert!(!store.replay(2, None).truncated);
+    assert!(!store.replay(4, None).truncated);
+}
+
+#[tokio::test]
+async fn subscribers_receive_new_events() {
+    let mut store = JobStore::new();
+    let mut live = store.subscribe();
+    store.create(job(1, 9, "alice", 1)).unwrap();
+    progress(&mut store, 1, 50);
+
+    assert_eq!(live.recv().await.unwrap().seq, 1);
+    let event = live.recv().await.unwrap();
+    assert_eq!(event.seq, 2);
+    assert_eq!(event.pct, Some(50));
+}
+
+#[test]
+fn reopened_store_keeps_jobs_and_cursor() {
+    let path = temp_path("reopen");
+    {
+        let mut store = JobStore::new().open(&path, 0).unwrap();
+        store.create(job(1, 9, "alice", 1)).unwrap();
+        progress(&mut store, 1, 60);
+        store.create(job(2, 9, "bob", 2)).unwrap();
+        store
+            .record(&id(2), EventType::Completed, None, None, None, 3)
+            .unwrap();
+    }
+
+    let mut store = JobStore::new().open(&path, 20).unwrap();
+    assert_eq!(store.len(), 2);
+    assert_eq!(store.get(&id(2)).unwrap().status, JobStatus::Succeeded);
+
+    // A job still running when the engine stopped will never finish
+    assert_eq!(store.last_seq(), 5);
+    let interrupted = store.get(&id(1)).unwrap();
+    assert_eq!(interrupted.status, JobStatus::Failed);
+    assert_eq!(interrupted.progress_pct, Some(60));
+    assert_eq!(interrupted.error.as_deref(), Some(INTERRUPTED_MESSAGE));
+    assert_eq!(interrupted.updated_ms, 20);
+    assert!(
+        store
+            .record(&id(1), EventType::Progress, None, Some(80), None, 21)
+            .unwrap()
+            .is_none()
+    );
+
+    let replay = store.replay(2, None);
+    let seqs: Vec<u64> = replay.events.iter().map(|e| e.seq).collect();
+    assert_eq!(seqs, vec![3, 4, 5]);
+    assert_eq!(replay.events[2].event_type, EventType::Failed);
+
+    // The failure is persisted: a second restart does not fail it again
+    drop(store);
+    let store = JobStore::new().open(&path, 30).unwrap();
+    assert_eq!(store.last_seq(), 5);
+    assert_eq!(store.get(&id(1)).unwrap().updated_ms, 20);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn unreadable_lines_are_skipped_on_open() {
+    let path = temp_path("torn");
+    {
+        let mut store = JobStore::new().open(&path, 0).unwrap();
+        store.create(job(1, 9, "alice", 1)).unwrap();
+    }
+    let mut content = std::fs::read_to_string(&path).unwrap();
+    content.push_str("{\"event\":{\"seq\":");
+    std::fs::write(&path, content).unwrap();
+
+    let store = JobStore::new().open(&path, 2).unwrap();
+    assert_eq!(store.len(), 1);
+    // Started, then failed as interrupted
+    assert_eq!(store.last_seq(), 2);
+    // Compaction dropped the torn line
+    let compacted = std::fs::read_to_string(&path).unwrap();
+    assert!(compacted.lines().all(|line| line.ends_with('}')));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+fn finish(store: &mut JobStore, job: u8, now_ms: u64) {
+    store
+        .record(&id(job), EventType::Completed, None, None, None, now_ms)
+        .unwrap()
+        .unwrap();
+}
+
+#[test]
+fn oldest_finished_jobs_are_dropped_beyond_retention() {
+    let mut store = JobStore::new().with_retained_jobs(2);
+    for n in 1..=4 {
+        store.create(job(n, 9, "alice", n as u64)).unwrap();
+    }
+    finish(&mut store, 2, 10);
+    finish(&mut store, 1, 11);
+    finish(&mut store, 3, 12);
+
+    // Job 2 finished first; the running job 4 is kept regardless
+    assert_eq!(store.len(), 3);
+    assert!(store.get(&id(2)).is_none());
+    assert!(store.get(&id(1)).is_some());
+    assert!(store.get(&id(4)).is_some());
+}
+
+#[test]
+fn file_is_compacted_in_the_background_once_grown() {
+    let path = temp_path("grown");
+    {
+        let mut store = JobStore::new()
+            .with_retained_jobs(1)
+            .with_compact_after_lines(10)
+            .open(&path, 0)
+            .unwrap();
+        for n in 1..=5 {
+            store.create(job(n, 9, "alice", n as u64)).unwrap();
+            for pct in [20, 40, 60] {
+                progress(&mut store, n, pct);
+            }
+            finish(&mut store, n, 10 + n as u64);
+        }
+    }
+
+    // 30 lines were appended; only job 5 and its events are live
+    let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+    assert!(lines < 12, "file has {lines} lines");
+
+    let store = JobStore::new().open(&path, 100).unwrap();
+    assert_eq!(store.len(), 1);
+    assert_eq!(store.get(&id(5)).unwrap().status, JobStatus::Succeeded);
+    assert_eq!(store.last_seq(), 25);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1 @@
+mod job_store;
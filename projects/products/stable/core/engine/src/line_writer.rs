@@ -0,0 +1,105 @@
+// projects/products/stable/core/engine/src/line_writer.rs
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+use tracing::warn;
+
+/// Work queued for the writer thread
+#[derive(Debug)]
+enum WriteOp {
+    /// One line, newline included
+    Append(String),
+    /// Replaces the whole file with these lines
+    Rewrite(Vec<String>),
+}
+
+/// Appends lines to a JSONL file on a dedicated thread, so callers holding
+/// the lock of the job store or the audit log never wait on the disk. Lines
+/// are written in the order they were queued; dropping the writer waits for
+/// pending ones.
+#[derive(Debug)]
+pub(crate) struct LineWriter {
+    ops: Option<Sender<WriteOp>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl LineWriter {
+    /// Opens `path` for appending and starts the thread `name` writing to it
+    pub(crate) fn spawn(path: &Path, name: &str) -> io::Result<Self> {
+        let mut file = open_append(path)?;
+        let path = path.to_path_buf();
+        let (ops, pending) = mpsc::channel::<WriteOp>();
+        let thread = thread::Builder::new()
+            .name(name.to_string())
+            .spawn(move || {
+                let path_text = path.display().to_string();
+                for op in pending {
+                    let written = match op {
+                        WriteOp::Append(line) => file.write_all(line.as_bytes()),
+                        // The old handle still points at the replaced file
+                        WriteOp::Rewrite(lines) => write_lines(&path, &lines)
+                            .and_then(|()| open_append(&path))
+                            .map(|reopened| file = reopened),
+                    };
+                    if let Err(e) = written {
+                        warn!(path = %path_text, error = %e, "Failed to write lines");
+                    }
+                }
+            })?;
+        Ok(Self {
+            ops: Some(ops),
+            thread: Some(thread),
+        })
+    }
+
+    /// Queues `line` (without its newline) for writing
+    pub(crate) fn append(&self, mut line: String) {
+        line.push('\n');
+        self.send(WriteOp::Append(line));
+    }
+
+    /// Queues a rewrite of the file with `lines`, after the lines already
+    /// queued
+    pub(crate) fn rewrite(&self, lines: Vec<String>) {
+        self.send(WriteOp::Rewrite(lines));
+    }
+
+    fn send(&self, op: WriteOp) {
+        if let Some(ops) = &self.ops {
+            // The thread only stops once `ops` is dropped
+            let _ = ops.send(op);
+        }
+    }
+}
+
+impl Drop for LineWriter {
+    fn drop(&mut self) {
+        self.ops.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Replaces `path` with `lines` through a temporary file, so a crash leaves
+/// either the old or the new content
+pub(crate) fn write_lines(path: &Path, lines: &[String]) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    {
+        let mut writer = BufWriter::new(File::create(&tmp)?);
+        for line in lines {
+            writeln!(writer, "{line}")?;
+        }
+        writer.flush()?;
+    }
+    fs::rename(&tmp, path)
+}
@@ -1,9 +1,12 @@
 // projects/products/stable/core/engine/src/main.rs
+mod auth;
 mod bootstrap;
 mod const_values;
 mod cors_config;
 mod engine_config;
 mod engine_state;
+mod jobs;
+mod line_writer;
 mod registry;
 mod requires;
 mod routes;
@@ -17,14 +20,21 @@ pub(crate) use const_values::*;
 pub(crate) use cors_config::CorsConfig;
 pub(crate) use engine_state::EngineState;
 pub(crate) use registry::Registry;
-pub(crate) use requires::{require_permission, require_project_exists};
+pub(crate) use requires::{
+    action_permission, require_jobs_access, require_permission, require_project_exists,
+};
 pub(crate) use runtime::*;
 
+use std::sync::Arc;
+
 use anyhow::Context;
-use security::TokenService;
+use protocol::Metadata;
+use security::{FileRevocationStore, InMemoryRevocationStore, RevocationStore, TokenService};
 use tracing::{error, info, warn};
 
+use crate::auth::{AuditLog, engine_policy};
 use crate::engine_config::EngineConfig;
+use crate::jobs::JobStore;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -36,9 +46,29 @@ async fn main() -> anyhow::Result<()> {
         warn!("ENGINE_ALLOW_INSECURE_SECRET=1 is set; default secret may be used (INSECURE)");
     }
 
-    // Secret JWT
-    let token_service =
-        TokenService::new_hs256(&config.jwt_secret).context("ENGINE_JWT_SECRET invalid")?;
+    // Secret JWT; killed sessions stay revoked across restarts
+    let revoked_sessions: Arc<dyn RevocationStore> = match &config.revoked_sessions_path {
+        Some(path) => match FileRevocationStore::open(path) {
+            Ok(store) => Arc::new(store),
+            Err(e) => {
+                warn!(
+                    revoked_sessions_path = %path.display(),
+                    error = %e,
+                    "Failed to open revoked sessions. Killed sessions will not survive a restart."
+                );
+                Arc::new(InMemoryRevocationStore::new())
+            }
+        },
+        None => Arc::new(InMemoryRevocationStore::new()),
+    };
+    let token_service = TokenService::new_hs256(&config.jwt_secret)
+        .context("ENGINE_JWT_SECRET invalid")?
+        .with_session_revocation_store(revoked_sessions);
+    match token_service.purge_revocations() {
+        Ok(0) => {}
+        Ok(purged) => info!(purged, "Purged expired session revocations"),
+        Err(e) => warn!(error = %e, "Failed to purge session revocations"),
+    }
 
     // Registry auto
     let registry = Registry::load(&config.projects_dir).unwrap_or_else(|e| {
@@ -92,8 +122,69 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    // Job store
+    let new_jobs = || {
+        JobStore::new()
+            .with_retained_events(config.jobs_retained_events)
+            .with_retained_jobs(config.jobs_retained_jobs)
+            .with_compact_after_lines(config.jobs_compact_after_lines)
+    };
+    let jobs = match &config.jobs_path {
+        Some(path) => new_jobs()
+            .open(path, Metadata::current_timestamp_ms())
+            .unwrap_or_else(|e| {
+                warn!(
+                    jobs_path = %path.display(),
+                    error = %e,
+                    "Failed to open job store. Jobs will not survive a restart."
+                );
+                new_jobs()
+            }),
+        None => {
+            warn!("No job store path. Jobs will not survive a restart.");
+            new_jobs()
+        }
+    };
+    // Audit log
+    let audit_log = match &config.audit_path {
+        Some(path) => AuditLog::new()
+            .with_retained(config.audit_retained_entries)
+            .open(path)
+            .unwrap_or_else(|e| {
+                warn!(
+                    audit_path = %path.display(),
+                    error = %e,
+                    "Failed to open audit log. Audit entries will not survive a restart."
+                );
+                AuditLog::new().with_retained(config.audit_retained_entries)
+            }),
+        None => {
+            warn!("No audit log path. Audit entries will not survive a restart.");
+            AuditLog::new().with_retained(config.audit_retained_entries)
+        }
+    };
+
+    info!(
+        jobs = jobs.len(),
+        last_seq = jobs.last_seq(),
+        "Job store ready"
+    );
+
+    // Authorization: built-in roles plus the configured rules
+    let policy = engine_policy(&config.policy_rules).context("Invalid ENGINE_POLICY_PATH rules")?;
+    info!(rules = config.policy_rules.len(), "Policy rules loaded");
+
     // State
-    let state = EngineState::new(registry, token_service);
+    let state = EngineState::new(
+        registry,
+        token_service,
+        config.backend_balancing,
+        config.backend_limits,
+        jobs,
+        config.auth,
+        audit_log,
+    )
+    .with_policy(policy);
 
     // Routes
     let routes = routes::build_routes(state, config.cors);
@@ -107,6 +198,10 @@ async fn main() -> anyhow::Result<()> {
         "Health check: http://{}:{}/health",
         config.host, config.port
     );
+    info!(
+        "Backend metrics: GET http://{}:{}/metrics",
+        config.host, config.port
+    );
     info!(
         "Login endpoint: POST http://{}:{}/auth/login",
         config.host, config.port
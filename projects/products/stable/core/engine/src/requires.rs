@@ -1,6 +1,7 @@
 // projects/products/stable/core/engine/src/requires.rs
 use protocol::Command;
-use security::{Permission, Token};
+use security::{Permission, PolicyRequest, Token};
+use tracing::debug;
 
 use crate::EngineState;
 
@@ -16,10 +17,52 @@ pub(crate) async fn require_project_exists(
     Ok(())
 }
 
-/// Checks the token's permissions
-pub(crate) fn require_permission(token: &Token, perm: Permission) -> Result<(), String> {
-    if !token.role.permissions().contains(&perm) {
-        return Err(format!("Missing permission: {:?}", perm));
+/// Checks the token's permission on a resource through the policy engine.
+pub(crate) fn require_permission(
+    state: &EngineState,
+    token: &Token,
+    perm: Permission,
+    resource: &str,
+) -> Result<(), String> {
+    let request = PolicyRequest::new(token.subject_id.to_string(), perm.as_str(), resource)
+        .with_builtin_role(token.role);
+    let explanation = state.policy.explain(&request);
+    if !explanation.decision.is_allowed() {
+        debug!(explanation = %explanation, "Policy denied request");
+        return Err(format!(
+            "Missing permission: {:?} on {} ({})",
+            perm, resource, explanation.decision
+        ));
     }
     Ok(())
 }
+
+/// Permission an action needs, from the verb in its name: reads need
+/// `Read`, updates `Write`, deletions `Delete` and anything else `Execute`.
+pub(crate) fn action_permission(action: &str) -> Permission {
+    if action.contains("read") || action.contains("get") {
+        Permission::Read
+    } else if action.contains("write") || action.contains("update") {
+        Permission::Write
+    } else if action.contains("delete") {
+        Permission::Delete
+    } else {
+        Permission::Execute
+    }
+}
+
+/// Resource guarding the job history and job event subscriptions.
+pub(crate) const JOBS_RESOURCE: &str = "engine:jobs";
+
+/// Checks read access to jobs and returns the subject whose jobs the token
+/// may see: `None` when it may see every job (admin on the jobs resource).
+pub(crate) fn require_jobs_access(
+    state: &EngineState,
+    token: &Token,
+) -> Result<Option<String>, String> {
+    require_permission(state, token, Permission::Read, JOBS_RESOURCE)?;
+    if require_permission(state, token, Permission::Admin, JOBS_RESOURCE).is_ok() {
+        return Ok(None);
+    }
+    Ok(Some(token.subject_id.to_string()))
+}
@@ -5,7 +5,9 @@ use warp::{Reply, http::StatusCode};
 use super::auth::{normalize_user_id, validate_user_id};
 use super::helpers::{event_to_http, http_error, require_admin};
 use crate::EngineState;
-use crate::routes::http_forwarder::{accounts_product_id, forward_to_backend, payload_from};
+use crate::routes::http_forwarder::{
+    accounts_product_id, audit_rejected, forward_audited, forward_to_backend, payload_from,
+};
 use protocol_accounts::{
     CreateAccountRequest, ResetPasswordRequest, UpdateAccountRequest, UpdateStatusRequest,
 };
@@ -27,7 +29,7 @@ pub(crate) async fn list_accounts(
     let payload = payload_from(pjson!({}), None);
     let event = match forward_to_backend(&product_id, "accounts.list", payload, &state).await {
         Ok(ev) => ev,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(e) => return Ok(http_error(e.http_status(), e.to_string())),
     };
 
     Ok(event_to_http(event, StatusCode::OK))
@@ -56,7 +58,7 @@ pub(crate) async fn get_account(
     let payload = payload_from(pjson!({ "user_id": user_id.to_string() }), None);
     let event = match forward_to_backend(&product_id, "accounts.get", payload, &state).await {
         Ok(ev) => ev,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(e) => return Ok(http_error(e.http_status(), e.to_string())),
     };
 
     Ok(event_to_http(event, StatusCode::OK))
@@ -68,19 +70,25 @@ pub(crate) async fn create_account(
     headers: warp::http::HeaderMap,
     state: EngineState,
 ) -> Result<impl Reply, warp::Rejection> {
-    let _token = match require_admin(&headers, &state) {
+    let token = match require_admin(&headers, &state) {
         Ok(token) => token,
         Err(err) => return Ok(err),
     };
 
     let user_id = match validate_user_id(req.user_id) {
         Ok(id) => id,
-        Err(e) => return Ok(http_error(StatusCode::BAD_REQUEST, e)),
+        Err(e) => {
+            audit_rejected("accounts.create", &token, req.user_id, e, &state).await;
+            return Ok(http_error(StatusCode::BAD_REQUEST, e));
+        }
     };
 
     let product_id = match accounts_product_id() {
         Ok(id) => id,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(msg) => {
+            audit_rejected("accounts.create", &token, user_id, &msg, &state).await;
+            return Ok(http_error(StatusCode::BAD_GATEWAY, msg));
+        }
     };
     let payload = payload_from(
         CreateAccountRequest {
@@ -91,9 +99,18 @@ pub(crate) async fn create_account(
         },
         None,
     );
-    let event = match forward_to_backend(&product_id, "accounts.create", payload, &state).await {
+    let event = match forward_audited(
+        &product_id,
+        "accounts.create",
+        payload,
+        &token,
+        user_id,
+        &state,
+    )
+    .await
+    {
         Ok(ev) => ev,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(e) => return Ok(http_error(e.http_status(), e.to_string())),
     };
 
     Ok(event_to_http(event, StatusCode::CREATED))
@@ -106,19 +123,25 @@ pub(crate) async fn update_account(
     headers: warp::http::HeaderMap,
     state: EngineState,
 ) -> Result<impl Reply, warp::Rejection> {
-    let _token = match require_admin(&headers, &state) {
+    let token = match require_admin(&headers, &state) {
         Ok(token) => token,
         Err(err) => return Ok(err),
     };
 
     let user_id = match normalize_user_id(&user_id) {
         Ok(id) => id,
-        Err(e) => return Ok(http_error(StatusCode::BAD_REQUEST, e)),
+        Err(e) => {
+            audit_rejected("accounts.update", &token, &user_id, e, &state).await;
+            return Ok(http_error(StatusCode::BAD_REQUEST, e));
+        }
     };
 
     let product_id = match accounts_product_id() {
         Ok(id) => id,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(msg) => {
+            audit_rejected("accounts.update", &token, user_id, &msg, &state).await;
+            return Ok(http_error(StatusCode::BAD_GATEWAY, msg));
+        }
     };
     let payload = payload_from(
         pjson!({
@@ -128,9 +151,18 @@ pub(crate) async fn update_account(
         }),
         None,
     );
-    let event = match forward_to_backend(&product_id, "accounts.update", payload, &state).await {
+    let event = match forward_audited(
+        &product_id,
+        "accounts.update",
+        payload,
+        &token,
+        user_id,
+        &state,
+    )
+    .await
+    {
         Ok(ev) => ev,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(e) => return Ok(http_error(e.http_status(), e.to_string())),
     };
 
     Ok(event_to_http(event, StatusCode::OK))
@@ -143,19 +175,25 @@ pub(crate) async fn update_status(
     headers: warp::http::HeaderMap,
     state: EngineState,
 ) -> Result<impl Reply, warp::Rejection> {
-    let _token = match require_admin(&headers, &state) {
+    let token = match require_admin(&headers, &state) {
         Ok(token) => token,
         Err(err) => return Ok(err),
     };
 
     let user_id = match normalize_user_id(&user_id) {
         Ok(id) => id,
-        Err(e) => return Ok(http_error(StatusCode::BAD_REQUEST, e)),
+        Err(e) => {
+            audit_rejected("accounts.update_status", &token, &user_id, e, &state).await;
+            return Ok(http_error(StatusCode::BAD_REQUEST, e));
+        }
     };
 
     let product_id = match accounts_product_id() {
         Ok(id) => id,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(msg) => {
+            audit_rejected("accounts.update_status", &token, user_id, &msg, &state).await;
+            return Ok(http_error(StatusCode::BAD_GATEWAY, msg));
+        }
     };
     let payload = payload_from(
         pjson!({
@@ -164,11 +202,19 @@ pub(crate) async fn update_status(
         }),
         None,
     );
-    let event =
-        match forward_to_backend(&product_id, "accounts.update_status", payload, &state).await {
-            Ok(ev) => ev,
-            Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
-        };
+    let event = match forward_audited(
+        &product_id,
+        "accounts.update_status",
+        payload,
+        &token,
+        user_id,
+        &state,
+    )
+    .await
+    {
+        Ok(ev) => ev,
+        Err(e) => return Ok(http_error(e.http_status(), e.to_string())),
+    };
 
     Ok(event_to_http(event, StatusCode::OK))
 }
@@ -180,19 +226,25 @@ pub(crate) async fn reset_password(
     headers: warp::http::HeaderMap,
     state: EngineState,
 ) -> Result<impl Reply, warp::Rejection> {
-    let _token = match require_admin(&headers, &state) {
+    let token = match require_admin(&headers, &state) {
         Ok(token) => token,
         Err(err) => return Ok(err),
     };
 
     let user_id = match normalize_user_id(&user_id) {
         Ok(id) => id,
-        Err(e) => return Ok(http_error(StatusCode::BAD_REQUEST, e)),
+        Err(e) => {
+            audit_rejected("accounts.reset_password", &token, &user_id, e, &state).await;
+            return Ok(http_error(StatusCode::BAD_REQUEST, e));
+        }
     };
 
     let product_id = match accounts_product_id() {
         Ok(id) => id,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(msg) => {
+            audit_rejected("accounts.reset_password", &token, user_id, &msg, &state).await;
+            return Ok(http_error(StatusCode::BAD_GATEWAY, msg));
+        }
     };
     let payload = payload_from(
         pjson!({
@@ -201,11 +253,19 @@ pub(crate) async fn reset_password(
         }),
         None,
     );
-    let event =
-        match forward_to_backend(&product_id, "accounts.reset_password", payload, &state).await {
-            Ok(ev) => ev,
-            Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
-        };
+    let event = match forward_audited(
+        &product_id,
+        "accounts.reset_password",
+        payload,
+        &token,
+        user_id,
+        &state,
+    )
+    .await
+    {
+        Ok(ev) => ev,
+        Err(e) => return Ok(http_error(e.http_status(), e.to_string())),
+    };
 
     Ok(event_to_http(event, StatusCode::OK))
 }
@@ -0,0 +1,61 @@
+// projects/products/stable/core/engine/src/routes/audit.rs
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use common_json::{pjson, to_value};
+use warp::{Reply, http::StatusCode};
+
+use super::helpers::{http_error, require_admin};
+use crate::EngineState;
+use crate::auth::{AuditOutcome, AuditQuery};
+
+/// Audit log, most recent first (admin only). Filters: `action` (exact or
+/// namespace), `actor`, `target`, `outcome`, `since` / `until` (ms), `limit`.
+pub(crate) async fn list_audit(
+    query: HashMap<String, String>,
+    headers: warp::http::HeaderMap,
+    state: EngineState,
+) -> Result<impl Reply, warp::Rejection> {
+    if let Err(err) = require_admin(&headers, &state) {
+        return Ok(err.into_response());
+    }
+
+    let mut filter = AuditQuery {
+        action: query.get("action").cloned(),
+        actor: query.get("actor").cloned(),
+        target: query.get("target").cloned(),
+        ..AuditQuery::default()
+    };
+    if let Some(outcome) = query.get("outcome") {
+        match AuditOutcome::from_str(outcome) {
+            Ok(outcome) => filter.outcome = Some(outcome),
+            Err(e) => return Ok(http_error(StatusCode::BAD_REQUEST, e).into_response()),
+        }
+    }
+    for (key, slot) in [
+        ("since", &mut filter.since_ms),
+        ("until", &mut filter.until_ms),
+    ] {
+        if let Some(raw) = query.get(key) {
+            match raw.parse::<u64>() {
+                Ok(ms) => *slot = Some(ms),
+                Err(_) => {
+                    let msg = format!("Invalid {key}");
+                    return Ok(http_error(StatusCode::BAD_REQUEST, msg).into_response());
+                }
+            }
+        }
+    }
+    if let Some(limit) = query.get("limit") {
+        match limit.parse::<usize>() {
+            Ok(limit) => filter.limit = Some(limit),
+            Err(_) => {
+                return Ok(http_error(StatusCode::BAD_REQUEST, "Invalid limit").into_response());
+            }
+        }
+    }
+
+    let entries = state.audit_log.read().await.query(&filter);
+    let entries = to_value(&entries).unwrap_or_else(|_| pjson!([]));
+    Ok(warp::reply::json(&pjson!({ "entries": entries })).into_response())
+}
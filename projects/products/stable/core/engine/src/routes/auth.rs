@@ -1,15 +1,23 @@
 // projects/products/stable/core/engine/src/routes/auth.rs
+use common::Id128;
+use common_json::from_value;
+use common_time::timestamp_utils::current_timestamp_ms;
 use identity::UserId;
-use protocol::ProtocolId;
+use protocol::{EventType, ProtocolId};
+use std::net::SocketAddr;
 use std::str::FromStr;
 use tracing::warn;
 use warp::{Reply, http::StatusCode};
 
-use super::helpers::{event_to_http, http_error};
+use super::helpers::{client_ip, event_to_http, http_error};
 use crate::EngineState;
+use crate::auth::{AuditEntry, AuditOutcome};
 use crate::const_values::{DEFAULT_DURATION_MS, LOGIN_MAX_DURATION_MS};
 use crate::routes::http_forwarder::{accounts_product_id, forward_to_backend, payload_from};
-use protocol_accounts::LoginRequest;
+use protocol_accounts::{LoginRequest, LoginResponse};
+
+/// Audit action of login attempts
+pub(crate) const LOGIN_AUDIT_ACTION: &str = "auth.login";
 
 /// Validate a ProtocolId as a user id
 pub(crate) fn validate_user_id(id: ProtocolId) -> Result<ProtocolId, &'static str> {
@@ -27,21 +35,59 @@ pub(crate) fn normalize_user_id(input: &str) -> Result<ProtocolId, &'static str>
     parse_user_id(input)
 }
 
-/// Login handler - forward to accounts backend
+/// Login handler - forward to accounts backend.
+/// Throttled per client IP and per account; every attempt is audited and a
+/// successful login opens a new session (`sid`) chosen by the engine.
 pub(crate) async fn login(
     req: LoginRequest,
+    remote: Option<SocketAddr>,
+    headers: warp::http::HeaderMap,
     state: EngineState,
-) -> Result<impl Reply, warp::Rejection> {
+) -> Result<warp::reply::Response, warp::Rejection> {
+    let ip = client_ip(&headers, remote, state.auth_settings.trust_forwarded_for);
+    let ip = ip.as_deref();
+    let account = req.user_id.to_string();
+    let audit = |outcome: AuditOutcome, detail: &str| {
+        AuditEntry::new(LOGIN_AUDIT_ACTION, outcome)
+            .with_actor(&account)
+            .with_ip(ip)
+            .with_detail(detail)
+    };
+
+    let now_ms = current_timestamp_ms();
+    if let Err(retry_after_ms) = state
+        .login_throttle
+        .lock()
+        .await
+        .attempt(ip, &account, now_ms)
+    {
+        warn!(user_id = %account, ip = ?ip, "Login throttled");
+        state
+            .audit(audit(AuditOutcome::Denied, "Too many login attempts"))
+            .await;
+        let retry_after_s = retry_after_ms.div_ceil(1000).to_string();
+        return Ok(warp::reply::with_header(
+            http_error(StatusCode::TOO_MANY_REQUESTS, "Too many login attempts"),
+            "retry-after",
+            retry_after_s,
+        )
+        .into_response());
+    }
+
     if req.password.trim().is_empty() {
         warn!("Login failed: empty password for user_id={}", req.user_id);
-        return Ok(http_error(StatusCode::UNAUTHORIZED, "Invalid credentials"));
+        state.login_throttle.lock().await.failed(&account, now_ms);
+        state
+            .audit(audit(AuditOutcome::Failure, "Empty password"))
+            .await;
+        return Ok(http_error(StatusCode::UNAUTHORIZED, "Invalid credentials").into_response());
     }
 
     let user_id = match validate_user_id(req.user_id) {
         Ok(id) => id,
         Err(e) => {
             warn!("Login failed: invalid user_id={}: {}", req.user_id, e);
-            return Ok(http_error(StatusCode::BAD_REQUEST, e));
+            return Ok(http_error(StatusCode::BAD_REQUEST, e).into_response());
         }
     };
 
@@ -59,16 +105,50 @@ pub(crate) async fn login(
 
     let mut req = req;
     req.duration_ms = Some(duration_ms);
+    // Sessions are named by the engine, never by the client
+    req.session_id = Some(ProtocolId::new(Id128::new(0, None, None)));
 
     let product_id = match accounts_product_id() {
         Ok(id) => id,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg).into_response()),
     };
     let payload = payload_from(req, None);
     let event = match forward_to_backend(&product_id, "accounts.login", payload, &state).await {
         Ok(ev) => ev,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(e) => {
+            state
+                .audit(audit(AuditOutcome::Failure, &e.to_string()))
+                .await;
+            return Ok(http_error(e.http_status(), e.to_string()).into_response());
+        }
     };
 
-    Ok(event_to_http(event, StatusCode::OK))
+    let issued = event
+        .payload
+        .as_ref()
+        .and_then(|p| p.payload.clone())
+        .filter(|_| event.event_type != EventType::Error)
+        .and_then(|value| from_value::<LoginResponse>(value).ok())
+        .and_then(|response| state.token_service.verify(&response.jwt).ok());
+    match issued {
+        Some(token) => {
+            state.login_throttle.lock().await.succeeded(&account);
+            state.sessions.write().await.touch(&token, ip, now_ms);
+            let mut entry = audit(AuditOutcome::Success, "Logged in");
+            if let Some(session_id) = token.session_id {
+                entry = entry.with_target(session_id);
+            }
+            state.audit(entry).await;
+        }
+        None => {
+            let detail = event
+                .message
+                .clone()
+                .unwrap_or_else(|| "Invalid credentials".to_string());
+            state.login_throttle.lock().await.failed(&account, now_ms);
+            state.audit(audit(AuditOutcome::Failure, &detail)).await;
+        }
+    }
+
+    Ok(event_to_http(event, StatusCode::OK).into_response())
 }
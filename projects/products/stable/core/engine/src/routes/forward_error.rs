@@ -0,0 +1,47 @@
+// projects/products/stable/core/engine/src/routes/forward_error.rs
+use protocol::ProtocolError;
+use warp::http::StatusCode;
+
+/// Why a request could not be answered by a backend
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ForwardError {
+    #[error("Backend not available")]
+    NotAvailable,
+
+    #[error("Backend connection closed")]
+    ConnectionClosed,
+
+    /// Nothing was sent; the caller may retry later
+    #[error("Overloaded: {0}")]
+    Overloaded(String),
+
+    #[error("Backend request deadline exceeded")]
+    DeadlineExceeded { deadline_ms: u64 },
+
+    #[error("Backend response channel closed")]
+    ResponseLost,
+
+    #[error("Failed to serialize command: {0}")]
+    Serialization(String),
+}
+
+impl ForwardError {
+    pub(crate) fn http_status(&self) -> StatusCode {
+        match self {
+            Self::Overloaded(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::DeadlineExceeded { .. } => StatusCode::GATEWAY_TIMEOUT,
+            _ => StatusCode::BAD_GATEWAY,
+        }
+    }
+
+    /// Protocol error for the flow-control failures, `None` for the others
+    pub(crate) fn protocol_error(&self) -> Option<ProtocolError> {
+        match self {
+            Self::Overloaded(reason) => Some(ProtocolError::overloaded(reason)),
+            Self::DeadlineExceeded { deadline_ms } => {
+                Some(ProtocolError::deadline_exceeded(*deadline_ms))
+            }
+            _ => None,
+        }
+    }
+}
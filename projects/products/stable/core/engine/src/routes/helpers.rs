@@ -24,18 +24,51 @@ pub(crate) fn bearer_token(headers: &warp::http::HeaderMap) -> Option<String> {
         .map(|token| token.trim().to_string())
 }
 
-/// Validate admin role from token
-pub(crate) fn require_admin(
+/// Client address: the first `X-Forwarded-For` hop when the engine trusts
+/// its proxy, else the socket peer
+pub(crate) fn client_ip(
+    headers: &warp::http::HeaderMap,
+    remote: Option<std::net::SocketAddr>,
+    trust_forwarded_for: bool,
+) -> Option<String> {
+    if trust_forwarded_for
+        && let Some(forwarded) = headers
+            .get("x-forwarded-for")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split(',').next())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    {
+        return Some(forwarded.to_string());
+    }
+    remote.map(|addr| addr.ip().to_string())
+}
+
+/// Resource checked by `require_admin` for engine administration endpoints.
+pub(crate) const ADMIN_RESOURCE: &str = "engine:admin";
+
+/// Verify the bearer token of the request
+pub(crate) fn require_token(
     headers: &warp::http::HeaderMap,
     state: &crate::EngineState,
 ) -> Result<security::Token, WithStatus<warp::reply::Json>> {
     let token = bearer_token(headers)
         .ok_or_else(|| http_error(StatusCode::UNAUTHORIZED, "Missing token"))?;
-    let token = state
+    state
         .token_service
         .verify(&token)
-        .map_err(|_| http_error(StatusCode::UNAUTHORIZED, "Invalid token"))?;
-    if token.role != security::Role::Admin {
+        .map_err(|_| http_error(StatusCode::UNAUTHORIZED, "Invalid token"))
+}
+
+/// Validate admin permission from token (policy engine decides)
+pub(crate) fn require_admin(
+    headers: &warp::http::HeaderMap,
+    state: &crate::EngineState,
+) -> Result<security::Token, WithStatus<warp::reply::Json>> {
+    let token = require_token(headers, state)?;
+    if crate::require_permission(state, &token, security::Permission::Admin, ADMIN_RESOURCE)
+        .is_err()
+    {
         return Err(http_error(StatusCode::FORBIDDEN, "Admin role required"));
     }
     Ok(token)
@@ -4,14 +4,18 @@
 //! This module adapts HTTP request/response to the Command/Event protocol.
 
 use protocol::protocol_id::ProtocolId;
-use protocol::{Command, CommandType, Event, Metadata, Payload};
+use protocol::{Command, CommandType, Event, EventType, Metadata, Payload};
+use security::Token;
 use std::str::FromStr;
+use tokio::sync::mpsc::error::TrySendError;
 use tokio::sync::oneshot;
 use tokio::time::Duration;
+use tracing::warn;
 
+use crate::auth::{AuditEntry, AuditOutcome};
 use crate::engine_state::EngineState;
-
-const DEFAULT_BACKEND_TIMEOUT_MS: u64 = 5_000;
+use crate::routes::forward_error::ForwardError;
+use crate::runtime::SelectedBackend;
 
 pub(crate) fn accounts_product_id() -> Result<ProtocolId, String> {
     let raw = std::env::var("ENGINE_ACCOUNTS_PRODUCT_ID")
@@ -28,28 +32,99 @@ pub(crate) fn payload_from<T: serde::Serialize>(value: T, payload_type: Option<&
     }
 }
 
-/// Forward a request to a backend via the WS routing system.
-/// Creates a Command, routes it, and extracts the response.
+/// Forward a request to a backend via the WS routing system, with the
+/// default deadline.
 pub(crate) async fn forward_to_backend(
     product_id: &ProtocolId,
     action: &str,
     payload: Payload,
     state: &EngineState,
-) -> Result<Event, String> {
-    let metadata = Metadata::now();
-    let request_id = metadata.request_id;
+) -> Result<Event, ForwardError> {
+    forward_with_origin(product_id, action, payload, None, state).await
+}
 
-    let (tx, rx) = oneshot::channel();
-    {
-        let mut pending = state.pending_requests.write().await;
-        pending.insert(request_id, tx);
-    }
+/// Forward an administration request on behalf of `actor` and record its
+/// outcome in the audit log under `action`.
+pub(crate) async fn forward_audited(
+    product_id: &ProtocolId,
+    action: &str,
+    payload: Payload,
+    actor: &Token,
+    target: impl ToString,
+    state: &EngineState,
+) -> Result<Event, ForwardError> {
+    let result = forward_to_backend(product_id, action, payload, state).await;
+    let entry = match &result {
+        Ok(event) if event.event_type == EventType::Error => {
+            AuditEntry::new(action, AuditOutcome::Failure).with_detail(
+                event
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| "Backend returned an error".to_string()),
+            )
+        }
+        Ok(_) => AuditEntry::new(action, AuditOutcome::Success),
+        Err(e) => AuditEntry::new(action, AuditOutcome::Failure).with_detail(e.to_string()),
+    };
+    state
+        .audit(entry.with_actor(actor.subject_id).with_target(target))
+        .await;
+    result
+}
 
-    let sender = {
-        let registry = state.backend_registry.read().await;
-        registry.sender_for_product(product_id)
+/// Record an administration request on behalf of `actor` that was refused
+/// before reaching the backend (invalid input, no accounts product).
+pub(crate) async fn audit_rejected(
+    action: &str,
+    actor: &Token,
+    target: impl ToString,
+    detail: impl Into<String>,
+    state: &EngineState,
+) {
+    state
+        .audit(
+            AuditEntry::new(action, AuditOutcome::Denied)
+                .with_actor(actor.subject_id)
+                .with_target(target)
+                .with_detail(detail),
+        )
+        .await;
+}
+
+/// Forward a request to a backend via the WS routing system.
+/// Creates a Command, routes it to an instance of the product, and extracts
+/// the response.
+///
+/// `origin` is the metadata of the caller's command: its `deadline_ms` is
+/// capped by the engine limits and forwarded with its `job_id` and
+/// `client_id` (the authenticated caller) in the command metadata. Requests
+/// are refused as overloaded, without being sent, when the pending table or every
+/// instance queue is full.
+///
+/// A send that fails means the instance's connection is gone before the
+/// backend saw the command, so the instance is dropped and the command is
+/// retried on another one. Timeouts are not retried since the backend may
+/// already be acting on the command; it is sent a cancellation instead.
+pub(crate) async fn forward_with_origin(
+    product_id: &ProtocolId,
+    action: &str,
+    payload: Payload,
+    origin: Option<&Metadata>,
+    state: &EngineState,
+) -> Result<Event, ForwardError> {
+    let limits = state.backend_limits;
+    let metrics = &state.backend_metrics;
+    let now_ms = Metadata::current_timestamp_ms();
+    let deadline_ms = limits.deadline_ms(origin.and_then(|m| m.deadline_ms), now_ms);
+    if deadline_ms <= now_ms {
+        metrics.record_deadline_exceeded();
+        return Err(ForwardError::DeadlineExceeded { deadline_ms });
     }
-    .ok_or_else(|| "Backend not available".to_string())?;
+
+    let mut metadata = Metadata::now().with_deadline_ms(deadline_ms);
+    metadata.job_id = origin.and_then(|m| m.job_id);
+    metadata.client_id = origin.and_then(|m| m.client_id);
+    let request_id = metadata.request_id;
 
     let cmd = Command {
         metadata,
@@ -57,21 +132,108 @@ pub(crate) async fn forward_to_backend(
         action: Some(action.to_string()),
         payload: Some(payload),
     };
+    let cmd_json =
+        common_json::to_string(&cmd).map_err(|e| ForwardError::Serialization(e.to_string()))?;
 
-    let cmd_json = common_json::to_string(&cmd).map_err(|e| e.to_string())?;
-    if sender.send(cmd_json).is_err() {
+    let (tx, rx) = oneshot::channel();
+    {
         let mut pending = state.pending_requests.write().await;
-        pending.remove(&request_id);
-        return Err("Backend connection closed".to_string());
+        if pending.len() >= limits.max_pending_requests {
+            metrics.record_overloaded();
+            warn!(
+                product_id = %product_id,
+                action,
+                pending = pending.len(),
+                "Too many pending backend requests"
+            );
+            return Err(ForwardError::Overloaded(
+                "too many pending requests".to_string(),
+            ));
+        }
+        pending.insert(request_id, tx);
     }
 
-    match tokio::time::timeout(Duration::from_millis(DEFAULT_BACKEND_TIMEOUT_MS), rx).await {
-        Ok(Ok(event)) => Ok(event),
-        Ok(Err(_)) => Err("Backend response channel closed".to_string()),
-        Err(_) => {
+    let mut tried: Vec<String> = Vec::new();
+    let mut saw_full = false;
+    let backend = loop {
+        let selected = {
+            let registry = state.backend_registry.read().await;
+            saw_full |= registry.is_saturated(product_id, &tried);
+            registry.select_for_product(product_id, &tried)
+        };
+        let Some(selected) = selected else {
             let mut pending = state.pending_requests.write().await;
             pending.remove(&request_id);
-            Err("Backend request timed out".to_string())
+            return Err(if saw_full {
+                metrics.record_overloaded();
+                warn!(product_id = %product_id, action, "All backend queues are full");
+                ForwardError::Overloaded("backend queues are full".to_string())
+            } else if tried.is_empty() {
+                ForwardError::NotAvailable
+            } else {
+                ForwardError::ConnectionClosed
+            });
+        };
+        match selected.sender.try_send(cmd_json.clone()) {
+            Ok(()) => break selected,
+            Err(TrySendError::Full(_)) => {
+                // Raced with other requests for the last slot; nothing was sent
+                saw_full = true;
+            }
+            Err(TrySendError::Closed(_)) => {
+                warn!(
+                    product_id = %product_id,
+                    instance_id = %selected.instance_id,
+                    action,
+                    "Backend connection closed, retrying on another instance"
+                );
+                state
+                    .backend_registry
+                    .write()
+                    .await
+                    .deregister(&selected.instance_id);
+            }
         }
+        tried.push(selected.instance_id.clone());
+    };
+
+    let remaining_ms = deadline_ms.saturating_sub(Metadata::current_timestamp_ms());
+    let result = tokio::time::timeout(Duration::from_millis(remaining_ms), rx).await;
+    let registry = state.backend_registry.read().await;
+    match result {
+        Ok(Ok(event)) => {
+            registry.record_success(&backend.instance_id);
+            Ok(event)
+        }
+        Ok(Err(_)) => {
+            registry.record_failure(&backend.instance_id);
+            Err(ForwardError::ResponseLost)
+        }
+        Err(_) => {
+            registry.record_failure(&backend.instance_id);
+            drop(registry);
+            state.pending_requests.write().await.remove(&request_id);
+            metrics.record_deadline_exceeded();
+            cancel_request(&backend, request_id, state);
+            Err(ForwardError::DeadlineExceeded { deadline_ms })
+        }
+    }
+}
+
+/// Tells the backend nobody waits for `request_id` anymore. Best effort: a
+/// full or closed queue drops the cancellation.
+fn cancel_request(backend: &SelectedBackend, request_id: ProtocolId, state: &EngineState) {
+    let cancel = Command::cancel_request(request_id);
+    let Ok(cancel_json) = common_json::to_string(&cancel) else {
+        return;
+    };
+    match backend.sender.try_send(cancel_json) {
+        Ok(()) => state.backend_metrics.record_cancelled(),
+        Err(e) => warn!(
+            instance_id = %backend.instance_id,
+            request_id = %request_id,
+            error = %e,
+            "Failed to send request cancellation to backend"
+        ),
     }
 }
@@ -0,0 +1,127 @@
+// projects/products/stable/core/engine/src/routes/jobs.rs
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use common_json::{pjson, to_value};
+use protocol::ProtocolId;
+use warp::{Reply, http::StatusCode};
+
+use super::helpers::{http_error, require_token};
+use crate::jobs::{JobQuery, JobStatus};
+use crate::{EngineState, require_jobs_access};
+
+/// Job history, most recent first. Filters: `product_id`, `status`,
+/// `action`, `limit`. Non-admin callers only see their own jobs.
+pub(crate) async fn list_jobs(
+    query: HashMap<String, String>,
+    headers: warp::http::HeaderMap,
+    state: EngineState,
+) -> Result<impl Reply, warp::Rejection> {
+    let token = match require_token(&headers, &state) {
+        Ok(token) => token,
+        Err(err) => return Ok(err.into_response()),
+    };
+    let subject_id = match require_jobs_access(&state, &token) {
+        Ok(subject_id) => subject_id,
+        Err(e) => return Ok(http_error(StatusCode::FORBIDDEN, e).into_response()),
+    };
+
+    let mut filter = JobQuery {
+        subject_id,
+        action: query.get("action").cloned(),
+        ..JobQuery::default()
+    };
+    if let Some(product_id) = query.get("product_id") {
+        match ProtocolId::from_str(product_id) {
+            Ok(id) => filter.product_id = Some(id),
+            Err(_) => {
+                return Ok(
+                    http_error(StatusCode::BAD_REQUEST, "Invalid product_id").into_response()
+                );
+            }
+        }
+    }
+    if let Some(status) = query.get("status") {
+        match JobStatus::from_str(status) {
+            Ok(status) => filter.status = Some(status),
+            Err(e) => return Ok(http_error(StatusCode::BAD_REQUEST, e).into_response()),
+        }
+    }
+    if let Some(limit) = query.get("limit") {
+        match limit.parse::<usize>() {
+            Ok(limit) => filter.limit = Some(limit),
+            Err(_) => {
+                return Ok(http_error(StatusCode::BAD_REQUEST, "Invalid limit").into_response());
+            }
+        }
+    }
+
+    let jobs = state.jobs.read().await.list(&filter);
+    let jobs = to_value(&jobs).unwrap_or_else(|_| pjson!([]));
+    Ok(warp::reply::json(&pjson!({ "jobs": jobs })).into_response())
+}
+
+/// One job with its current status, progress and result
+pub(crate) async fn get_job(
+    job_id: String,
+    headers: warp::http::HeaderMap,
+    state: EngineState,
+) -> Result<impl Reply, warp::Rejection> {
+    let token = match require_token(&headers, &state) {
+        Ok(token) => token,
+        Err(err) => return Ok(err.into_response()),
+    };
+    let subject_id = match require_jobs_access(&state, &token) {
+        Ok(subject_id) => subject_id,
+        Err(e) => return Ok(http_error(StatusCode::FORBIDDEN, e).into_response()),
+    };
+    let Ok(job_id) = ProtocolId::from_str(&job_id) else {
+        return Ok(http_error(StatusCode::BAD_REQUEST, "Invalid job_id").into_response());
+    };
+
+    let jobs = state.jobs.read().await;
+    // Jobs of other subjects are reported as missing, not forbidden
+    match jobs
+        .get(&job_id)
+        .filter(|job| subject_id.as_deref().is_none_or(|s| s == job.subject_id))
+    {
+        Some(job) => Ok(warp::reply::json(job).into_response()),
+        None => Ok(http_error(StatusCode::NOT_FOUND, "Job not found").into_response()),
+    }
+}
+
+/// Retained events of one job after the `after` cursor (0 when unset)
+pub(crate) async fn get_job_events(
+    job_id: String,
+    query: HashMap<String, String>,
+    headers: warp::http::HeaderMap,
+    state: EngineState,
+) -> Result<impl Reply, warp::Rejection> {
+    let token = match require_token(&headers, &state) {
+        Ok(token) => token,
+        Err(err) => return Ok(err.into_response()),
+    };
+    let subject_id = match require_jobs_access(&state, &token) {
+        Ok(subject_id) => subject_id,
+        Err(e) => return Ok(http_error(StatusCode::FORBIDDEN, e).into_response()),
+    };
+    let Ok(job_id) = ProtocolId::from_str(&job_id) else {
+        return Ok(http_error(StatusCode::BAD_REQUEST, "Invalid job_id").into_response());
+    };
+    let after_seq = match query.get("after").map(|a| a.parse::<u64>()) {
+        None => 0,
+        Some(Ok(after)) => after,
+        Some(Err(_)) => {
+            return Ok(http_error(StatusCode::BAD_REQUEST, "Invalid after cursor").into_response());
+        }
+    };
+
+    let jobs = state.jobs.read().await;
+    let visible = jobs
+        .get(&job_id)
+        .is_some_and(|job| subject_id.as_deref().is_none_or(|s| s == job.subject_id));
+    if !visible {
+        return Ok(http_error(StatusCode::NOT_FOUND, "Job not found").into_response());
+    }
+    Ok(warp::reply::json(&jobs.replay(after_seq, Some(&job_id))).into_response())
+}
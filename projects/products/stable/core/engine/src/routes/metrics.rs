@@ -0,0 +1,47 @@
+// projects/products/stable/core/engine/src/routes/metrics.rs
+use common_json::{pjson, to_value};
+use warp::Reply;
+
+use super::helpers::require_admin;
+use crate::EngineState;
+
+/// Backend flow-control metrics: pending requests, per-instance queue depth
+/// and counters of refused, timed out and cancelled requests (admin only)
+pub(crate) async fn backend_metrics(
+    headers: warp::http::HeaderMap,
+    state: EngineState,
+) -> Result<impl Reply, warp::Rejection> {
+    if let Err(err) = require_admin(&headers, &state) {
+        return Ok(err.into_response());
+    }
+
+    let pending_requests = state.pending_requests.read().await.len();
+    let backends = state.backend_registry.read().await.queue_depths();
+    let backends = to_value(&backends).unwrap_or_else(|_| pjson!([]));
+    let limits = state.backend_limits;
+    let metrics = &state.backend_metrics;
+
+    Ok(warp::reply::json(&pjson!({
+        "pending_requests": pending_requests,
+        "max_pending_requests": limits.max_pending_requests,
+        "overloaded_total": metrics.overloaded(),
+        "deadline_exceeded_total": metrics.deadline_exceeded(),
+        "cancelled_total": metrics.cancelled(),
+        "backends": backends
+    }))
+    .into_response())
+}
+
+/// Routing table built from backend registrations (admin only)
+pub(crate) async fn backend_routes(
+    headers: warp::http::HeaderMap,
+    state: EngineState,
+) -> Result<impl Reply, warp::Rejection> {
+    if let Err(err) = require_admin(&headers, &state) {
+        return Ok(err.into_response());
+    }
+
+    let routes = state.backend_registry.read().await.route_entries();
+    let routes = to_value(&routes).unwrap_or_else(|_| pjson!([]));
+    Ok(warp::reply::json(&pjson!({ "routes": routes })).into_response())
+}
@@ -2,11 +2,16 @@
 
 // Module declarations
 pub(crate) mod accounts;
+pub(crate) mod audit;
 pub(crate) mod auth;
+pub(crate) mod forward_error;
 pub(crate) mod helpers;
 pub(crate) mod http_forwarder;
+pub(crate) mod jobs;
+pub(crate) mod metrics;
 pub(crate) mod orchestration;
 pub(crate) mod projects;
+pub(crate) mod sessions;
 pub(crate) mod setup;
 
 // Re-exports from helpers
@@ -15,6 +20,16 @@ pub(crate) use helpers::http_error;
 // Re-exports from auth
 pub(crate) use auth::login;
 
+// Re-exports from audit and sessions
+pub(crate) use audit::list_audit;
+pub(crate) use sessions::{kill_session, list_sessions};
+
+// Re-exports from jobs
+pub(crate) use jobs::{get_job, get_job_events, list_jobs};
+
+// Re-exports from metrics
+pub(crate) use metrics::{backend_metrics, backend_routes};
+
 // Re-exports from setup
 pub(crate) use setup::{health, setup_admin, setup_status};
 
@@ -1,11 +1,13 @@
 // projects/products/stable/core/engine/src/routes/orchestration.rs
-use std::{collections::HashMap, convert::Infallible};
+use std::{collections::HashMap, convert::Infallible, net::SocketAddr};
 
 use warp::{Filter, Reply, http::StatusCode};
 
+use super::helpers::client_ip;
 use super::{
-    create_account, get_account, health, http_error, list_accounts, list_projects, login,
-    reset_password, setup_admin, setup_status, start_project, stop_project, update_account,
+    backend_metrics, backend_routes, create_account, get_account, get_job, get_job_events, health,
+    http_error, kill_session, list_accounts, list_audit, list_jobs, list_projects, list_sessions,
+    login, reset_password, setup_admin, setup_status, start_project, stop_project, update_account,
     update_status,
 };
 use crate::{CorsConfig, EngineState};
@@ -18,11 +20,19 @@ fn ws_route(
         .and(warp::get())
         .and(warp::ws())
         .and(warp::query::<HashMap<String, String>>())
-        .map(move |ws: warp::ws::Ws, q: HashMap<String, String>| {
-            let jwt = q.get("token").cloned().unwrap_or_default();
-            let st = state.clone();
-            ws.on_upgrade(move |socket| crate::ws::ws_handle(socket, st, jwt))
-        })
+        .and(warp::addr::remote())
+        .and(warp::header::headers_cloned())
+        .map(
+            move |ws: warp::ws::Ws,
+                  q: HashMap<String, String>,
+                  remote: Option<SocketAddr>,
+                  headers: warp::http::HeaderMap| {
+                let jwt = q.get("token").cloned().unwrap_or_default();
+                let ip = client_ip(&headers, remote, state.auth_settings.trust_forwarded_for);
+                let st = state.clone();
+                ws.on_upgrade(move |socket| crate::ws::ws_handle(socket, st, jwt, ip))
+            },
+        )
 }
 
 /// Error recovery handler
@@ -64,9 +74,63 @@ pub(crate) fn build_routes(
 
     let health_route = warp::path!("health").and(warp::get()).and_then(health);
 
+    let metrics_route = warp::path!("metrics")
+        .and(warp::get())
+        .and(warp::header::headers_cloned())
+        .and(with_state.clone())
+        .and_then(backend_metrics);
+
+    let routes_route = warp::path!("routes")
+        .and(warp::get())
+        .and(warp::header::headers_cloned())
+        .and(with_state.clone())
+        .and_then(backend_routes);
+
+    let jobs_route = warp::path!("jobs")
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::header::headers_cloned())
+        .and(with_state.clone())
+        .and_then(list_jobs);
+
+    let job_route = warp::path!("jobs" / String)
+        .and(warp::get())
+        .and(warp::header::headers_cloned())
+        .and(with_state.clone())
+        .and_then(get_job);
+
+    let job_events_route = warp::path!("jobs" / String / "events")
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::header::headers_cloned())
+        .and(with_state.clone())
+        .and_then(get_job_events);
+
+    let audit_route = warp::path!("audit")
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::header::headers_cloned())
+        .and(with_state.clone())
+        .and_then(list_audit);
+
+    let sessions_route = warp::path!("sessions")
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::header::headers_cloned())
+        .and(with_state.clone())
+        .and_then(list_sessions);
+
+    let session_kill_route = warp::path!("sessions" / String)
+        .and(warp::delete())
+        .and(warp::header::headers_cloned())
+        .and(with_state.clone())
+        .and_then(kill_session);
+
     let login_route = warp::path!("auth" / "login")
         .and(warp::post())
         .and(warp::body::json())
+        .and(warp::addr::remote())
+        .and(warp::header::headers_cloned())
         .and(with_state.clone())
         .and_then(login);
 
@@ -140,6 +204,14 @@ pub(crate) fn build_routes(
     let websocket_route = ws_route(state);
 
     let base = health_route
+        .or(metrics_route)
+        .or(routes_route)
+        .or(jobs_route)
+        .or(job_route)
+        .or(job_events_route)
+        .or(audit_route)
+        .or(sessions_route)
+        .or(session_kill_route)
         .or(login_route)
         .or(setup_route)
         .or(setup_status_route)
@@ -155,7 +227,7 @@ pub(crate) fn build_routes(
         .or(websocket_route);
 
     let mut cors_mw = warp::cors()
-        .allow_methods(["GET", "POST", "PATCH", "OPTIONS"])
+        .allow_methods(["GET", "POST", "PATCH", "DELETE", "OPTIONS"])
         .allow_headers(["content-type", "authorization"])
         .max_age(60);
 
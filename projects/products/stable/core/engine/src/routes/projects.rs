@@ -7,7 +7,7 @@ use warp::{Reply, http::StatusCode};
 
 use super::helpers::{event_to_http, http_error, require_admin};
 use crate::EngineState;
-use crate::routes::http_forwarder::{forward_to_backend, payload_from};
+use crate::routes::http_forwarder::{forward_audited, payload_from};
 
 /// List all projects from registry
 pub(crate) async fn list_projects(state: EngineState) -> Result<impl Reply, warp::Rejection> {
@@ -22,7 +22,7 @@ pub(crate) async fn start_project(
     headers: warp::http::HeaderMap,
     state: EngineState,
 ) -> Result<impl Reply, warp::Rejection> {
-    let _token = match require_admin(&headers, &state) {
+    let token = match require_admin(&headers, &state) {
         Ok(token) => token,
         Err(err) => return Ok(err),
     };
@@ -33,9 +33,18 @@ pub(crate) async fn start_project(
     };
 
     let payload = payload_from(pjson!({}), None);
-    let event = match forward_to_backend(&product_id, "project.start", payload, &state).await {
+    let event = match forward_audited(
+        &product_id,
+        "project.start",
+        payload,
+        &token,
+        product_id,
+        &state,
+    )
+    .await
+    {
         Ok(ev) => ev,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(e) => return Ok(http_error(e.http_status(), e.to_string())),
     };
 
     Ok(event_to_http(event, StatusCode::OK))
@@ -47,7 +56,7 @@ pub(crate) async fn stop_project(
     headers: warp::http::HeaderMap,
     state: EngineState,
 ) -> Result<impl Reply, warp::Rejection> {
-    let _token = match require_admin(&headers, &state) {
+    let token = match require_admin(&headers, &state) {
         Ok(token) => token,
         Err(err) => return Ok(err),
     };
@@ -58,9 +67,18 @@ pub(crate) async fn stop_project(
     };
 
     let payload = payload_from(pjson!({}), None);
-    let event = match forward_to_backend(&product_id, "project.stop", payload, &state).await {
+    let event = match forward_audited(
+        &product_id,
+        "project.stop",
+        payload,
+        &token,
+        product_id,
+        &state,
+    )
+    .await
+    {
         Ok(ev) => ev,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(e) => return Ok(http_error(e.http_status(), e.to_string())),
     };
 
     Ok(event_to_http(event, StatusCode::OK))
@@ -0,0 +1,101 @@
+// projects/products/stable/core/engine/src/routes/sessions.rs
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use common_json::{pjson, to_value};
+use common_time::timestamp_utils::current_timestamp_ms;
+use protocol::ProtocolId;
+use tracing::{info, warn};
+use warp::{Reply, http::StatusCode};
+
+use super::helpers::{http_error, require_admin};
+use crate::EngineState;
+use crate::auth::{AuditEntry, AuditOutcome};
+use crate::const_values::LOGIN_MAX_DURATION_MS;
+
+/// Audit action of a session kill
+const SESSION_KILL_AUDIT_ACTION: &str = "session.kill";
+
+/// Active sessions, most recently seen first (admin only).
+/// `subject_id` restricts the list to one account.
+pub(crate) async fn list_sessions(
+    query: HashMap<String, String>,
+    headers: warp::http::HeaderMap,
+    state: EngineState,
+) -> Result<impl Reply, warp::Rejection> {
+    if let Err(err) = require_admin(&headers, &state) {
+        return Ok(err.into_response());
+    }
+
+    let sessions = state.sessions.write().await.list(
+        query.get("subject_id").map(String::as_str),
+        current_timestamp_ms(),
+    );
+    let sessions = to_value(&sessions).unwrap_or_else(|_| pjson!([]));
+    Ok(warp::reply::json(&pjson!({ "sessions": sessions })).into_response())
+}
+
+/// Kill a session (admin only): every token of the `sid` is revoked and
+/// WebSocket connections using it are closed. Unknown sessions (e.g. opened
+/// before an engine restart) are revoked too.
+pub(crate) async fn kill_session(
+    session_id: String,
+    headers: warp::http::HeaderMap,
+    state: EngineState,
+) -> Result<impl Reply, warp::Rejection> {
+    let token = match require_admin(&headers, &state) {
+        Ok(token) => token,
+        Err(err) => return Ok(err.into_response()),
+    };
+    let Ok(session_id) = ProtocolId::from_str(&session_id) else {
+        return Ok(http_error(StatusCode::BAD_REQUEST, "Invalid session_id").into_response());
+    };
+
+    let now_ms = current_timestamp_ms();
+    let mut sessions = state.sessions.write().await;
+    // A token of the session may have been issued up to the login maximum
+    // after the last one the engine saw
+    let expires_at_ms = sessions
+        .get(&session_id)
+        .map_or(0, |session| session.expires_at_ms)
+        .max(now_ms.saturating_add(LOGIN_MAX_DURATION_MS));
+    if let Err(e) = state
+        .token_service
+        .revoke_session(session_id, expires_at_ms)
+    {
+        drop(sessions);
+        warn!(session_id = %session_id, error = %e, "Failed to revoke session");
+        state
+            .audit(
+                AuditEntry::new(SESSION_KILL_AUDIT_ACTION, AuditOutcome::Failure)
+                    .with_actor(token.subject_id)
+                    .with_target(session_id)
+                    .with_detail(e.to_string()),
+            )
+            .await;
+        return Ok(http_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to revoke session",
+        )
+        .into_response());
+    }
+    let killed = sessions.kill(&session_id);
+    drop(sessions);
+
+    info!(session_id = %session_id, known = killed.is_some(), "Session killed");
+    let mut entry = AuditEntry::new(SESSION_KILL_AUDIT_ACTION, AuditOutcome::Success)
+        .with_actor(token.subject_id)
+        .with_target(session_id);
+    if let Some(session) = &killed {
+        entry = entry.with_detail(format!("subject {}", session.subject_id));
+    }
+    state.audit(entry).await;
+
+    let session = to_value(&killed).unwrap_or(pjson!(null));
+    Ok(warp::reply::json(&pjson!({
+        "session_id": session_id.to_string(),
+        "revoked": true,
+        "session": session
+    }))
+    .into_response())
+}
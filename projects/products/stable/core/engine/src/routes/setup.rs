@@ -1,14 +1,19 @@
 // projects/products/stable/core/engine/src/routes/setup.rs
 use common_json::pjson;
+use protocol::EventType;
 use tracing::warn;
 use warp::{Reply, http::StatusCode};
 
 use super::auth::validate_user_id;
 use super::helpers::{event_to_http, http_error};
+use crate::auth::{AuditEntry, AuditOutcome};
 use crate::routes::http_forwarder::{accounts_product_id, forward_to_backend, payload_from};
 use crate::{BootstrapError, EngineState, consume_claim, setup_complete, validate_claim};
 use protocol_accounts::{SetupAdminRequest, SetupStatusResponse};
 
+/// Audit action of the owner admin setup
+const SETUP_AUDIT_ACTION: &str = "setup.admin";
+
 /// Health check endpoint
 pub(crate) async fn health() -> Result<impl Reply, warp::Rejection> {
     Ok(warp::reply::json(&pjson!({
@@ -27,6 +32,13 @@ pub(crate) async fn setup_admin(
     }
 
     if let Err(err) = validate_claim(&req.claim) {
+        state
+            .audit(
+                AuditEntry::new(SETUP_AUDIT_ACTION, AuditOutcome::Denied)
+                    .with_actor(req.user_id)
+                    .with_detail(err.to_string()),
+            )
+            .await;
         return Ok(match err {
             BootstrapError::ClaimMissing
             | BootstrapError::ClaimInvalid
@@ -58,8 +70,27 @@ pub(crate) async fn setup_admin(
     let event = match forward_to_backend(&product_id, "accounts.setup_admin", payload, &state).await
     {
         Ok(ev) => ev,
-        Err(msg) => return Ok(http_error(StatusCode::BAD_GATEWAY, msg)),
+        Err(e) => {
+            state
+                .audit(
+                    AuditEntry::new(SETUP_AUDIT_ACTION, AuditOutcome::Failure)
+                        .with_actor(user_id)
+                        .with_detail(e.to_string()),
+                )
+                .await;
+            return Ok(http_error(e.http_status(), e.to_string()));
+        }
     };
+    if event.event_type == EventType::Error {
+        state
+            .audit(
+                AuditEntry::new(SETUP_AUDIT_ACTION, AuditOutcome::Failure)
+                    .with_actor(user_id)
+                    .with_detail(event.message.clone().unwrap_or_default()),
+            )
+            .await;
+        return Ok(event_to_http(event, StatusCode::CREATED));
+    }
 
     if let Err(e) = consume_claim() {
         warn!(error = %e, "Setup failed to consume claim");
@@ -69,6 +100,9 @@ pub(crate) async fn setup_admin(
         ));
     }
 
+    state
+        .audit(AuditEntry::new(SETUP_AUDIT_ACTION, AuditOutcome::Success).with_actor(user_id))
+        .await;
     Ok(event_to_http(event, StatusCode::CREATED))
 }
 
@@ -1,7 +1,24 @@
 // projects/products/stable/core/engine/src/runtime/backend_connection.rs
-use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::mpsc::Sender;
 
+/// Outgoing queue of a backend WebSocket connection; bounded so a slow
+/// backend cannot make the engine buffer without limit
 #[derive(Clone)]
 pub(crate) struct BackendConnection {
-    pub(crate) sender: UnboundedSender<String>,
+    pub(crate) sender: Sender<String>,
+}
+
+impl BackendConnection {
+    /// Messages waiting to be written to the socket
+    pub(crate) fn queue_depth(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+
+    pub(crate) fn queue_capacity(&self) -> usize {
+        self.sender.max_capacity()
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.sender.capacity() == 0
+    }
 }
@@ -0,0 +1,21 @@
+// projects/products/stable/core/engine/src/runtime/backend_health.rs
+
+/// Routing state of a backend instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BackendHealth {
+    Healthy,
+    /// Too many consecutive failures; only used when no healthy instance is left
+    Unhealthy,
+    /// Deregistering: finishes in-flight requests but receives no new ones
+    Draining,
+}
+
+impl BackendHealth {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Unhealthy => "unhealthy",
+            Self::Draining => "draining",
+        }
+    }
+}
@@ -1,4 +1,7 @@
 // projects/products/stable/core/engine/src/runtime/backend_info.rs
+use std::collections::BTreeMap;
+
+use protocol::PayloadSchema;
 use protocol::protocol_id::ProtocolId;
 
 /// Information about a registered backend service
@@ -8,4 +11,6 @@ pub(crate) struct BackendInfo {
     pub(crate) instance_id: ProtocolId,
     pub(crate) capabilities: Vec<String>,
     pub(crate) routes: Vec<String>,
+    /// action -> payload schema published for it
+    pub(crate) schemas: BTreeMap<String, PayloadSchema>,
 }
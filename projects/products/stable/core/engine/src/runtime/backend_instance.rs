@@ -0,0 +1,53 @@
+// projects/products/stable/core/engine/src/runtime/backend_instance.rs
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+use tokio::sync::Notify;
+
+use super::{BackendConnection, BackendHealth, BackendInfo};
+
+/// Consecutive failed requests after which an instance is considered unhealthy
+pub(crate) const UNHEALTHY_AFTER_FAILURES: u32 = 3;
+
+/// One connected instance of a product backend
+pub(crate) struct BackendInstance {
+    pub(crate) info: BackendInfo,
+    pub(crate) connection: BackendConnection,
+    pub(crate) draining: bool,
+    pub(crate) inflight: Arc<AtomicUsize>,
+    /// Notified when the last in-flight request ends
+    pub(crate) idle: Arc<Notify>,
+    pub(crate) consecutive_failures: AtomicU32,
+}
+
+impl BackendInstance {
+    pub(crate) fn new(info: BackendInfo, connection: BackendConnection) -> Self {
+        Self {
+            info,
+            connection,
+            draining: false,
+            inflight: Arc::new(AtomicUsize::new(0)),
+            idle: Arc::new(Notify::new()),
+            consecutive_failures: AtomicU32::new(0),
+        }
+    }
+
+    pub(crate) fn health(&self) -> BackendHealth {
+        if self.draining {
+            BackendHealth::Draining
+        } else if self.consecutive_failures.load(Ordering::Relaxed) >= UNHEALTHY_AFTER_FAILURES {
+            BackendHealth::Unhealthy
+        } else {
+            BackendHealth::Healthy
+        }
+    }
+
+    pub(crate) fn inflight(&self) -> usize {
+        self.inflight.load(Ordering::Relaxed)
+    }
+
+    /// The connection's WebSocket task has ended
+    pub(crate) fn is_closed(&self) -> bool {
+        self.connection.sender.is_closed()
+    }
+}
@@ -0,0 +1,41 @@
+// projects/products/stable/core/engine/src/runtime/backend_limits.rs
+
+/// Messages buffered per backend connection before it counts as overloaded
+pub(crate) const DEFAULT_BACKEND_QUEUE_CAPACITY: usize = 256;
+/// Requests waiting for a backend answer, engine wide
+pub(crate) const DEFAULT_MAX_PENDING_REQUESTS: usize = 4_096;
+/// Deadline used when the caller does not supply one
+pub(crate) const DEFAULT_BACKEND_TIMEOUT_MS: u64 = 5_000;
+/// Longest a caller-supplied deadline may keep a request pending
+pub(crate) const DEFAULT_MAX_BACKEND_TIMEOUT_MS: u64 = 60_000;
+
+/// Flow-control limits applied when forwarding requests to backends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BackendLimits {
+    pub(crate) queue_capacity: usize,
+    pub(crate) max_pending_requests: usize,
+    pub(crate) default_timeout_ms: u64,
+    pub(crate) max_timeout_ms: u64,
+}
+
+impl BackendLimits {
+    /// Absolute deadline for a request started at `now_ms`: the caller's
+    /// deadline when given, capped to `max_timeout_ms` from now.
+    pub(crate) fn deadline_ms(&self, requested: Option<u64>, now_ms: u64) -> u64 {
+        let latest = now_ms.saturating_add(self.max_timeout_ms);
+        requested
+            .unwrap_or_else(|| now_ms.saturating_add(self.default_timeout_ms))
+            .min(latest)
+    }
+}
+
+impl Default for BackendLimits {
+    fn default() -> Self {
+        Self {
+            queue_capacity: DEFAULT_BACKEND_QUEUE_CAPACITY,
+            max_pending_requests: DEFAULT_MAX_PENDING_REQUESTS,
+            default_timeout_ms: DEFAULT_BACKEND_TIMEOUT_MS,
+            max_timeout_ms: DEFAULT_MAX_BACKEND_TIMEOUT_MS,
+        }
+    }
+}
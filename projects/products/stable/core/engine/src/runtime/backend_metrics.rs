@@ -0,0 +1,38 @@
+// projects/products/stable/core/engine/src/runtime/backend_metrics.rs
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Flow-control counters since engine start
+#[derive(Debug, Default)]
+pub(crate) struct BackendMetrics {
+    overloaded: AtomicU64,
+    deadline_exceeded: AtomicU64,
+    cancelled: AtomicU64,
+}
+
+impl BackendMetrics {
+    /// A request was refused because queues or the pending table were full
+    pub(crate) fn record_overloaded(&self) {
+        self.overloaded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_deadline_exceeded(&self) {
+        self.deadline_exceeded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// A cancellation was sent to a backend for an abandoned request
+    pub(crate) fn record_cancelled(&self) {
+        self.cancelled.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn overloaded(&self) -> u64 {
+        self.overloaded.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn deadline_exceeded(&self) -> u64 {
+        self.deadline_exceeded.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn cancelled(&self) -> u64 {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
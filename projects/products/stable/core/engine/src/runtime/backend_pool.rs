@@ -0,0 +1,123 @@
+// projects/products/stable/core/engine/src/runtime/backend_pool.rs
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{BackendHealth, BackendInstance, InflightGuard, LoadBalancing, SelectedBackend};
+
+/// All registered instances of one product
+#[derive(Default)]
+pub(crate) struct BackendPool {
+    pub(crate) instances: Vec<BackendInstance>,
+    cursor: AtomicUsize,
+}
+
+impl BackendPool {
+    pub(crate) fn get(&self, instance_id: &str) -> Option<&BackendInstance> {
+        self.instances
+            .iter()
+            .find(|i| i.info.instance_id.to_hex() == instance_id)
+    }
+
+    pub(crate) fn get_mut(&mut self, instance_id: &str) -> Option<&mut BackendInstance> {
+        self.instances
+            .iter_mut()
+            .find(|i| i.info.instance_id.to_hex() == instance_id)
+    }
+
+    /// Adds the instance, replacing a previous registration with the same id
+    pub(crate) fn upsert(&mut self, instance: BackendInstance) -> bool {
+        let instance_id = instance.info.instance_id.to_hex();
+        match self.get_mut(&instance_id) {
+            Some(existing) => {
+                *existing = instance;
+                false
+            }
+            None => {
+                self.instances.push(instance);
+                true
+            }
+        }
+    }
+
+    pub(crate) fn remove(&mut self, instance_id: &str) -> Option<BackendInstance> {
+        let position = self
+            .instances
+            .iter()
+            .position(|i| i.info.instance_id.to_hex() == instance_id)?;
+        Some(self.instances.remove(position))
+    }
+
+    /// Drops closed connections and drained instances with nothing in flight.
+    /// Returns the ids of the removed instances.
+    pub(crate) fn prune(&mut self) -> Vec<String> {
+        let mut removed = Vec::new();
+        self.instances.retain(|i| {
+            let drained = i.draining && i.inflight() == 0;
+            let keep = !(i.is_closed() || drained);
+            if !keep {
+                removed.push(i.info.instance_id.to_hex());
+            }
+            keep
+        });
+        removed
+    }
+
+    /// Instances that could take a request if their queue had room
+    fn routable<'a>(&'a self, exclude: &'a [String]) -> impl Iterator<Item = &'a BackendInstance> {
+        self.instances
+            .iter()
+            .filter(|i| i.health() != BackendHealth::Draining && !i.is_closed())
+            .filter(|i| !exclude.contains(&i.info.instance_id.to_hex()))
+    }
+
+    /// Every routable instance outside `exclude` has a full queue
+    pub(crate) fn is_saturated(&self, exclude: &[String]) -> bool {
+        let mut routable = self.routable(exclude).peekable();
+        routable.peek().is_some() && routable.all(|i| i.connection.is_full())
+    }
+
+    /// Picks an instance that is not draining, closed, full or in `exclude`.
+    /// Healthy instances are preferred; unhealthy ones are only used when
+    /// no healthy instance is left.
+    pub(crate) fn select(
+        &self,
+        balancing: LoadBalancing,
+        exclude: &[String],
+    ) -> Option<SelectedBackend> {
+        let available: Vec<&BackendInstance> = self
+            .routable(exclude)
+            .filter(|i| !i.connection.is_full())
+            .collect();
+        let healthy: Vec<&BackendInstance> = available
+            .iter()
+            .copied()
+            .filter(|i| i.health() == BackendHealth::Healthy)
+            .collect();
+        let candidates = if healthy.is_empty() {
+            available
+        } else {
+            healthy
+        };
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let turn = self.cursor.fetch_add(1, Ordering::Relaxed);
+        let chosen = match balancing {
+            LoadBalancing::RoundRobin => candidates[turn % candidates.len()],
+            LoadBalancing::LeastInflight => {
+                let least = candidates.iter().map(|i| i.inflight()).min()?;
+                let tied: Vec<&BackendInstance> = candidates
+                    .into_iter()
+                    .filter(|i| i.inflight() == least)
+                    .collect();
+                tied[turn % tied.len()]
+            }
+        };
+
+        Some(SelectedBackend {
+            instance_id: chosen.info.instance_id.to_hex(),
+            sender: chosen.connection.sender.clone(),
+            _inflight: InflightGuard::acquire(&chosen.inflight, &chosen.idle),
+        })
+    }
+}
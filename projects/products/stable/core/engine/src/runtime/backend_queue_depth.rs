@@ -0,0 +1,13 @@
+// projects/products/stable/core/engine/src/runtime/backend_queue_depth.rs
+use serde::Serialize;
+
+/// Queue usage of one backend instance, as reported by `/metrics`
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct BackendQueueDepth {
+    pub(crate) product_id: String,
+    pub(crate) instance_id: String,
+    pub(crate) health: String,
+    pub(crate) queue_depth: usize,
+    pub(crate) queue_capacity: usize,
+    pub(crate) inflight: usize,
+}
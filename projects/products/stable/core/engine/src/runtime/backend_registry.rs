@@ -1,65 +1,264 @@
 // projects/products/stable/core/engine/src/runtime/backend_registry_impl.rs
 use std::collections::HashMap;
-use tokio::sync::mpsc::UnboundedSender;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::{Notify, RwLock};
 
 use crate::BackendConnection;
 use protocol::protocol_id::ProtocolId;
-use tracing::info;
+use tracing::{info, warn};
 
-use super::BackendInfo;
+use super::{
+    BackendInfo, BackendInstance, BackendPool, BackendQueueDepth, LoadBalancing, RegistrationError,
+    RouteEntry, RouteTable, SelectedBackend, UNHEALTHY_AFTER_FAILURES,
+};
 
 /// Registry for managing connected backend services
+///
+/// Each product has a pool of instances; requests are spread over the pool
+/// according to the configured `LoadBalancing`. The routes and capabilities
+/// declared by the instances form the `RouteTable` used to dispatch actions.
 pub(crate) struct BackendRegistry {
-    backends: HashMap<String, BackendConnection>,
-    by_product: HashMap<String, String>,
+    pools: HashMap<String, BackendPool>,
+    /// instance id -> product id
+    by_instance: HashMap<String, String>,
+    balancing: LoadBalancing,
+    routes: RouteTable,
 }
 
 impl BackendRegistry {
     pub(crate) fn new() -> Self {
+        Self::with_balancing(LoadBalancing::default())
+    }
+
+    pub(crate) fn with_balancing(balancing: LoadBalancing) -> Self {
         Self {
-            backends: HashMap::new(),
-            by_product: HashMap::new(),
+            pools: HashMap::new(),
+            by_instance: HashMap::new(),
+            balancing,
+            routes: RouteTable::default(),
         }
     }
 
+    /// Adds the instance to its product pool. Refused when it declares an
+    /// action that is reserved or served by another product.
     pub(crate) fn register_with_sender(
         &mut self,
-        product_id: ProtocolId,
-        instance_id: ProtocolId,
-        capabilities: Vec<String>,
-        routes: Vec<String>,
-        sender: UnboundedSender<String>,
-    ) {
-        let backend_info = BackendInfo {
-            product_id,
-            instance_id,
-            capabilities,
-            routes,
-        };
+        backend_info: BackendInfo,
+        sender: Sender<String>,
+    ) -> Result<(), RegistrationError> {
+        let product_id = backend_info.product_id;
+        let instance_id = backend_info.instance_id;
+        let instance_key = instance_id.to_hex();
+        // Routes of closed connections must not block their replacement
+        self.prune();
+        // Checked without this instance so that it may change its own routes
+        RouteTable::build(
+            self.infos()
+                .filter(|info| info.instance_id.to_hex() != instance_key),
+        )
+        .check(&backend_info)?;
+
+        let product_key = product_id.to_hex();
+
+        // An instance id moving to another product leaves its old pool
+        if let Some(previous) = self.by_instance.get(&instance_key)
+            && *previous != product_key
+            && let Some(pool) = self.pools.get_mut(previous)
+        {
+            pool.remove(&instance_key);
+        }
+        self.by_instance
+            .insert(instance_key.clone(), product_key.clone());
+
+        let pool = self.pools.entry(product_key).or_default();
+        let added = pool.upsert(BackendInstance::new(
+            backend_info.clone(),
+            BackendConnection { sender },
+        ));
         info!(
             product_id = %backend_info.product_id,
             instance_id = %backend_info.instance_id,
             capabilities = backend_info.capabilities.len(),
             routes = backend_info.routes.len(),
+            reconnected = !added,
+            pool_size = pool.instances.len(),
             "Backend registered"
         );
-        self.backends
-            .insert(instance_id.to_hex(), BackendConnection { sender });
-        self.by_product
-            .insert(product_id.to_hex(), instance_id.to_hex());
+        self.prune();
+        Ok(())
+    }
+
+    fn infos(&self) -> impl Iterator<Item = &BackendInfo> {
+        self.pools
+            .values()
+            .flat_map(|pool| pool.instances.iter().map(|i| &i.info))
+    }
+
+    /// Recomputes the routing table after instances came or went
+    fn rebuild_routes(&mut self) {
+        let mut infos: Vec<&BackendInfo> = self.infos().collect();
+        // Deterministic order: the schema of the last instance id wins
+        infos.sort_by_key(|info| info.instance_id.to_hex());
+        self.routes = RouteTable::build(infos);
     }
 
-    pub(crate) fn sender_for_product(
+    /// Table entry serving `action`, if a backend declared it
+    pub(crate) fn resolve_action(&self, action: &str) -> Option<&RouteEntry> {
+        self.routes.resolve(action)
+    }
+
+    /// Routing table for introspection
+    pub(crate) fn route_entries(&self) -> Vec<RouteEntry> {
+        self.routes.entries()
+    }
+
+    /// Picks an instance of the product for one request, skipping `exclude`
+    pub(crate) fn select_for_product(
         &self,
         product_id: &ProtocolId,
-    ) -> Option<UnboundedSender<String>> {
-        let instance_id = self.by_product.get(&product_id.to_hex())?;
-        self.backends.get(instance_id).map(|b| b.sender.clone())
+        exclude: &[String],
+    ) -> Option<SelectedBackend> {
+        self.pools
+            .get(&product_id.to_hex())?
+            .select(self.balancing, exclude)
+    }
+
+    /// The product has instances to route to, but all their queues are full
+    pub(crate) fn is_saturated(&self, product_id: &ProtocolId, exclude: &[String]) -> bool {
+        self.pools
+            .get(&product_id.to_hex())
+            .is_some_and(|pool| pool.is_saturated(exclude))
+    }
+
+    fn instance(&self, instance_id: &str) -> Option<&BackendInstance> {
+        let product = self.by_instance.get(instance_id)?;
+        self.pools.get(product)?.get(instance_id)
+    }
+
+    pub(crate) fn record_success(&self, instance_id: &str) {
+        if let Some(instance) = self.instance(instance_id) {
+            instance.consecutive_failures.store(0, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn record_failure(&self, instance_id: &str) {
+        if let Some(instance) = self.instance(instance_id) {
+            let failures = instance
+                .consecutive_failures
+                .fetch_add(1, Ordering::Relaxed)
+                + 1;
+            if failures == UNHEALTHY_AFTER_FAILURES {
+                warn!(
+                    product_id = %instance.info.product_id,
+                    instance_id,
+                    failures,
+                    "Backend instance marked unhealthy"
+                );
+            }
+        }
+    }
+
+    /// Stops routing new requests to the instance. It is removed right away
+    /// when nothing is in flight, otherwise by `remove_when_drained` or when
+    /// its connection closes. Returns the number of requests still in
+    /// flight, or `None` for an unknown instance.
+    pub(crate) fn begin_drain(&mut self, instance_id: &str) -> Option<usize> {
+        let product = self.by_instance.get(instance_id)?;
+        let instance = self.pools.get_mut(product)?.get_mut(instance_id)?;
+        instance.draining = true;
+        let inflight = instance.inflight();
+        info!(
+            product_id = %instance.info.product_id,
+            instance_id,
+            inflight,
+            "Backend draining"
+        );
+        self.prune();
+        Some(inflight)
+    }
+
+    /// Signal of a draining instance's last in-flight request ending
+    fn drain_signal(&self, instance_id: &str) -> Option<Arc<Notify>> {
+        self.instance(instance_id)
+            .filter(|i| i.draining)
+            .map(|i| Arc::clone(&i.idle))
+    }
+
+    /// Removes the draining instance as soon as its last in-flight request
+    /// ends. Returns once it is gone, including when it re-registered or its
+    /// connection closed in the meantime.
+    pub(crate) async fn remove_when_drained(registry: Arc<RwLock<Self>>, instance_id: String) {
+        loop {
+            let Some(idle) = registry.read().await.drain_signal(&instance_id) else {
+                return;
+            };
+            idle.notified().await;
+            registry.write().await.prune();
+        }
+    }
+
+    /// Removes the instance right away, e.g. when its connection is gone
+    pub(crate) fn deregister(&mut self, instance_id: &str) -> bool {
+        let Some(product) = self.by_instance.remove(instance_id) else {
+            return false;
+        };
+        let removed = self
+            .pools
+            .get_mut(&product)
+            .and_then(|pool| pool.remove(instance_id))
+            .is_some();
+        if removed {
+            info!(product_id = %product, instance_id, "Backend deregistered");
+        }
+        if self
+            .pools
+            .get(&product)
+            .is_some_and(|p| p.instances.is_empty())
+        {
+            self.pools.remove(&product);
+        }
+        self.rebuild_routes();
+        removed
+    }
+
+    /// Removes closed connections and fully drained instances
+    pub(crate) fn prune(&mut self) {
+        for (product, pool) in &mut self.pools {
+            for instance_id in pool.prune() {
+                info!(product_id = %product, instance_id = %instance_id, "Backend removed");
+                self.by_instance.remove(&instance_id);
+            }
+        }
+        self.pools.retain(|_, pool| !pool.instances.is_empty());
+        self.rebuild_routes();
+    }
+
+    /// Queue usage of every registered instance, sorted by product and instance
+    pub(crate) fn queue_depths(&self) -> Vec<BackendQueueDepth> {
+        let mut depths: Vec<BackendQueueDepth> = self
+            .pools
+            .iter()
+            .flat_map(|(product, pool)| {
+                pool.instances.iter().map(move |i| BackendQueueDepth {
+                    product_id: product.clone(),
+                    instance_id: i.info.instance_id.to_hex(),
+                    health: i.health().as_str().to_string(),
+                    queue_depth: i.connection.queue_depth(),
+                    queue_capacity: i.connection.queue_capacity(),
+                    inflight: i.inflight(),
+                })
+            })
+            .collect();
+        depths
+            .sort_by(|a, b| (&a.product_id, &a.instance_id).cmp(&(&b.product_id, &b.instance_id)));
+        depths
     }
 
     /// Count the number of registered backends
     pub(crate) fn count(&self) -> usize {
-        self.backends.len()
+        self.pools.values().map(|p| p.instances.len()).sum()
     }
 }
 
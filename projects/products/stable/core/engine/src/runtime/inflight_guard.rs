@@ -0,0 +1,32 @@
+// projects/products/stable/core/engine/src/runtime/inflight_guard.rs
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::sync::Notify;
+
+/// Counts one in-flight request on an instance until dropped. Dropping the
+/// last one notifies `idle`.
+#[derive(Debug)]
+pub(crate) struct InflightGuard {
+    counter: Arc<AtomicUsize>,
+    idle: Arc<Notify>,
+}
+
+impl InflightGuard {
+    pub(crate) fn acquire(counter: &Arc<AtomicUsize>, idle: &Arc<Notify>) -> Self {
+        counter.fetch_add(1, Ordering::Relaxed);
+        Self {
+            counter: Arc::clone(counter),
+            idle: Arc::clone(idle),
+        }
+    }
+}
+
+impl Drop for InflightGuard {
+    fn drop(&mut self) {
+        if self.counter.fetch_sub(1, Ordering::AcqRel) == 1 {
+            // Stores a permit when nobody waits yet, so the wakeup is not lost
+            self.idle.notify_one();
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// projects/products/stable/core/engine/src/runtime/load_balancing.rs
+use std::str::FromStr;
+
+/// How `forward_to_backend` picks an instance within a product pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum LoadBalancing {
+    RoundRobin,
+    /// Fewest in-flight requests, round-robin among ties
+    #[default]
+    LeastInflight,
+}
+
+impl FromStr for LoadBalancing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "round_robin" => Ok(Self::RoundRobin),
+            "least_inflight" => Ok(Self::LeastInflight),
+            other => Err(format!(
+                "Unknown load balancing '{other}' (expected round_robin or least_inflight)"
+            )),
+        }
+    }
+}
@@ -1,8 +1,36 @@
 // projects/products/stable/core/engine/src/runtime/mod.rs
 pub(crate) mod backend_connection;
+mod backend_health;
 mod backend_info;
+mod backend_instance;
+mod backend_limits;
+mod backend_metrics;
+mod backend_pool;
+mod backend_queue_depth;
 mod backend_registry;
+mod inflight_guard;
+mod load_balancing;
+mod registration_error;
+mod route_entry;
+mod route_kind;
+mod route_table;
+mod selected_backend;
+#[cfg(test)]
+mod tests;
 
 pub(crate) use backend_connection::BackendConnection;
+pub(crate) use backend_health::BackendHealth;
 pub(crate) use backend_info::BackendInfo;
+pub(crate) use backend_instance::{BackendInstance, UNHEALTHY_AFTER_FAILURES};
+pub(crate) use backend_limits::BackendLimits;
+pub(crate) use backend_metrics::BackendMetrics;
+pub(crate) use backend_pool::BackendPool;
+pub(crate) use backend_queue_depth::BackendQueueDepth;
 pub(crate) use backend_registry::BackendRegistry;
+pub(crate) use inflight_guard::InflightGuard;
+pub(crate) use load_balancing::LoadBalancing;
+pub(crate) use registration_error::RegistrationError;
+pub(crate) use route_entry::RouteEntry;
+pub(crate) use route_kind::RouteKind;
+pub(crate) use route_table::{RouteTable, is_ws_dispatchable};
+pub(crate) use selected_backend::SelectedBackend;
@@ -0,0 +1,14 @@
+// projects/products/stable/core/engine/src/runtime/registration_error.rs
+
+/// Why a backend registration was refused
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub(crate) enum RegistrationError {
+    #[error("'{0}' is reserved by the engine")]
+    ReservedRoute(String),
+
+    #[error("'{route}' is already served by product {product_id}")]
+    RouteConflict { route: String, product_id: String },
+
+    #[error("schema published for '{0}', which is not a declared route")]
+    UndeclaredSchema(String),
+}
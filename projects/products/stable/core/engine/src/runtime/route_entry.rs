@@ -0,0 +1,17 @@
+// projects/products/stable/core/engine/src/runtime/route_entry.rs
+use protocol::PayloadSchema;
+use protocol::protocol_id::ProtocolId;
+use serde::Serialize;
+
+use super::RouteKind;
+
+/// One line of the routing table
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct RouteEntry {
+    pub(crate) action: String,
+    pub(crate) kind: RouteKind,
+    pub(crate) product_id: ProtocolId,
+    /// Instances of the product declaring the action
+    pub(crate) instances: usize,
+    pub(crate) schema: Option<PayloadSchema>,
+}
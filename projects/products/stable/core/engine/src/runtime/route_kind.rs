@@ -0,0 +1,12 @@
+// projects/products/stable/core/engine/src/runtime/route_kind.rs
+use serde::Serialize;
+
+/// How an action was declared by a backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RouteKind {
+    /// An exact action, e.g. `git_autopilot.preview`
+    Route,
+    /// A namespace: every `<capability>.*` action without an exact route
+    Capability,
+}
@@ -0,0 +1,113 @@
+// projects/products/stable/core/engine/src/runtime/route_table.rs
+use std::collections::BTreeMap;
+
+use super::{BackendInfo, RegistrationError, RouteEntry, RouteKind};
+
+/// Namespaces handled by the engine itself; backends cannot declare them
+pub(crate) const RESERVED_NAMESPACES: &[&str] = &["engine", "backend", "project"];
+
+/// Namespaces served through the engine's HTTP routes only, which add
+/// admin checks, login throttling and auditing; WS commands never reach them
+pub(crate) const HTTP_ONLY_NAMESPACES: &[&str] = &["accounts", "auth"];
+
+/// The WS dispatcher may forward `action` to the backend declaring it
+pub(crate) fn is_ws_dispatchable(action: &str) -> bool {
+    !HTTP_ONLY_NAMESPACES.contains(&namespace(action))
+}
+
+/// Actions declared by registered backends, mapped to the product serving them
+#[derive(Debug, Default)]
+pub(crate) struct RouteTable {
+    /// action or capability -> entry
+    entries: BTreeMap<(RouteKind, String), RouteEntry>,
+}
+
+impl RouteTable {
+    /// Table of the given instances; the first product to declare an action
+    /// keeps it, which `check` guarantees is the only one.
+    pub(crate) fn build<'a>(instances: impl IntoIterator<Item = &'a BackendInfo>) -> Self {
+        let mut table = Self::default();
+        for info in instances {
+            let product_id = info.product_id;
+            let declared = info
+                .routes
+                .iter()
+                .map(|r| (RouteKind::Route, r))
+                .chain(info.capabilities.iter().map(|c| (RouteKind::Capability, c)));
+            for (kind, action) in declared {
+                let entry = table
+                    .entries
+                    .entry((kind, action.clone()))
+                    .or_insert_with(|| RouteEntry {
+                        action: action.clone(),
+                        kind,
+                        product_id,
+                        instances: 0,
+                        schema: None,
+                    });
+                if entry.product_id != product_id {
+                    continue;
+                }
+                entry.instances += 1;
+                if let Some(schema) = info.schemas.get(action) {
+                    entry.schema = Some(schema.clone());
+                }
+            }
+        }
+        table
+    }
+
+    /// Checks a registration against the table: reserved namespaces, actions
+    /// owned by another product and schemas for undeclared actions are refused.
+    pub(crate) fn check(&self, info: &BackendInfo) -> Result<(), RegistrationError> {
+        let declared = info
+            .routes
+            .iter()
+            .map(|r| (RouteKind::Route, r))
+            .chain(info.capabilities.iter().map(|c| (RouteKind::Capability, c)));
+        for (kind, action) in declared {
+            if RESERVED_NAMESPACES.contains(&namespace(action)) {
+                return Err(RegistrationError::ReservedRoute(action.clone()));
+            }
+            if let Some(entry) = self.entries.get(&(kind, action.clone()))
+                && entry.product_id != info.product_id
+            {
+                return Err(RegistrationError::RouteConflict {
+                    route: action.clone(),
+                    product_id: entry.product_id.to_hex(),
+                });
+            }
+        }
+        if let Some(action) = info
+            .schemas
+            .keys()
+            .find(|action| !info.routes.contains(action) && !info.capabilities.contains(action))
+        {
+            return Err(RegistrationError::UndeclaredSchema(action.clone()));
+        }
+        Ok(())
+    }
+
+    /// Entry serving `action`: its exact route, else the capability of its
+    /// namespace
+    pub(crate) fn resolve(&self, action: &str) -> Option<&RouteEntry> {
+        self.entries
+            .get(&(RouteKind::Route, action.to_string()))
+            .or_else(|| {
+                let (namespace, _) = action.split_once('.')?;
+                self.entries
+                    .get(&(RouteKind::Capability, namespace.to_string()))
+            })
+    }
+
+    /// Every entry, routes first, sorted by action
+    pub(crate) fn entries(&self) -> Vec<RouteEntry> {
+        self.entries.values().cloned().collect()
+    }
+}
+
+/// `git_autopilot` for `git_autopilot.preview`; a capability is its own
+/// namespace
+fn namespace(action: &str) -> &str {
+    action.split_once('.').map_or(action, |(ns, _)| ns)
+}
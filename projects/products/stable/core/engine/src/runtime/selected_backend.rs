@@ -0,0 +1,12 @@
+// projects/products/stable/core/engine/src/runtime/selected_backend.rs
+use tokio::sync::mpsc::Sender;
+
+use super::InflightGuard;
+
+/// Instance chosen for one request; counts as in flight while held
+#[derive(Debug)]
+pub(crate) struct SelectedBackend {
+    pub(crate) instance_id: String,
+    pub(crate) sender: Sender<String>,
+    pub(crate) _inflight: InflightGuard,
+}
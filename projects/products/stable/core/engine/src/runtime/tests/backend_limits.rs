@@ -0,0 +1,26 @@
+// projects/products/stable/core/engine/src/runtime/tests/backend_limits.rs
+use crate::runtime::BackendLimits;
+
+fn limits() -> BackendLimits {
+    BackendLimits {
+        default_timeout_ms: 5_000,
+        max_timeout_ms: 60_000,
+        ..BackendLimits::default()
+    }
+}
+
+#[test]
+fn default_deadline_applies_without_caller_deadline() {
+    assert_eq!(limits().deadline_ms(None, 1_000), 6_000);
+}
+
+#[test]
+fn caller_deadline_is_kept_within_the_cap() {
+    assert_eq!(limits().deadline_ms(Some(3_000), 1_000), 3_000);
+    assert_eq!(limits().deadline_ms(Some(500_000), 1_000), 61_000);
+}
+
+#[test]
+fn past_caller_deadline_stays_in_the_past() {
+    assert_eq!(limits().deadline_ms(Some(500), 1_000), 500);
+}
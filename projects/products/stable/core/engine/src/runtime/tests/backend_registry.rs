@@ -0,0 +1,263 @@
+// projects/products/stable/core/engine/src/runtime/tests/backend_registry.rs
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::Id128;
+use protocol::protocol_id::ProtocolId;
+use tokio::sync::RwLock;
+use tokio::sync::mpsc::{Receiver, channel};
+
+use crate::runtime::{BackendInfo, BackendRegistry, LoadBalancing, UNHEALTHY_AFTER_FAILURES};
+
+fn id(byte: u8) -> ProtocolId {
+    ProtocolId::new(Id128::from_bytes_unchecked([byte; 16]))
+}
+
+fn register(registry: &mut BackendRegistry, product: u8, instance: u8) -> Receiver<String> {
+    register_with_capacity(registry, product, instance, 8)
+}
+
+fn register_with_capacity(
+    registry: &mut BackendRegistry,
+    product: u8,
+    instance: u8,
+    capacity: usize,
+) -> Receiver<String> {
+    let (tx, rx) = channel(capacity);
+    let info = BackendInfo {
+        product_id: id(product),
+        instance_id: id(instance),
+        capabilities: vec![],
+        routes: vec![],
+        schemas: Default::default(),
+    };
+    registry.register_with_sender(info, tx).unwrap();
+    rx
+}
+
+fn pick(registry: &BackendRegistry, product: u8) -> Option<String> {
+    registry
+        .select_for_product(&id(product), &[])
+        .map(|selected| selected.instance_id)
+}
+
+#[test]
+fn second_instance_joins_the_pool() {
+    let mut registry = BackendRegistry::with_balancing(LoadBalancing::RoundRobin);
+    let _a = register(&mut registry, 1, 10);
+    let _b = register(&mut registry, 1, 11);
+
+    assert_eq!(registry.count(), 2);
+    let first = pick(&registry, 1).unwrap();
+    let second = pick(&registry, 1).unwrap();
+    assert_ne!(first, second);
+    assert_eq!(pick(&registry, 1).unwrap(), first);
+}
+
+#[test]
+fn reconnecting_instance_replaces_its_connection() {
+    let mut registry = BackendRegistry::new();
+    let old = register(&mut registry, 1, 10);
+    drop(old);
+    let _new = register(&mut registry, 1, 10);
+
+    assert_eq!(registry.count(), 1);
+    let selected = registry.select_for_product(&id(1), &[]).unwrap();
+    assert!(selected.sender.try_send("ping".to_string()).is_ok());
+}
+
+#[test]
+fn least_inflight_avoids_busy_instance() {
+    let mut registry = BackendRegistry::with_balancing(LoadBalancing::LeastInflight);
+    let _a = register(&mut registry, 1, 10);
+    let _b = register(&mut registry, 1, 11);
+
+    let busy = registry.select_for_product(&id(1), &[]).unwrap();
+    for _ in 0..4 {
+        assert_ne!(pick(&registry, 1).unwrap(), busy.instance_id);
+    }
+    drop(busy);
+
+    let picks: Vec<String> = (0..2).map(|_| pick(&registry, 1).unwrap()).collect();
+    assert_ne!(picks[0], picks[1]);
+}
+
+#[test]
+fn closed_connections_are_skipped_and_pruned() {
+    let mut registry = BackendRegistry::new();
+    let a = register(&mut registry, 1, 10);
+    let _b = register(&mut registry, 1, 11);
+    drop(a);
+
+    for _ in 0..4 {
+        assert_eq!(pick(&registry, 1).unwrap(), id(11).to_hex());
+    }
+    registry.prune();
+    assert_eq!(registry.count(), 1);
+}
+
+#[test]
+fn excluded_instances_are_not_selected() {
+    let mut registry = BackendRegistry::new();
+    let _a = register(&mut registry, 1, 10);
+    let _b = register(&mut registry, 1, 11);
+
+    let exclude = [id(10).to_hex(), id(11).to_hex()];
+    let selected = registry.select_for_product(&id(1), &exclude[..1]).unwrap();
+    assert_eq!(selected.instance_id, id(11).to_hex());
+    assert!(registry.select_for_product(&id(1), &exclude).is_none());
+}
+
+#[test]
+fn unhealthy_instance_is_last_resort() {
+    let mut registry = BackendRegistry::new();
+    let _a = register(&mut registry, 1, 10);
+    let _b = register(&mut registry, 1, 11);
+    let flaky = id(10).to_hex();
+    for _ in 0..UNHEALTHY_AFTER_FAILURES {
+        registry.record_failure(&flaky);
+    }
+
+    for _ in 0..4 {
+        assert_eq!(pick(&registry, 1).unwrap(), id(11).to_hex());
+    }
+    let healthy = [id(11).to_hex()];
+    let fallback = registry.select_for_product(&id(1), &healthy).unwrap();
+    assert_eq!(fallback.instance_id, flaky);
+
+    registry.record_success(&flaky);
+    drop(fallback);
+    let picks: Vec<String> = (0..2).map(|_| pick(&registry, 1).unwrap()).collect();
+    assert!(picks.contains(&flaky));
+}
+
+#[test]
+fn draining_waits_for_inflight_requests() {
+    let mut registry = BackendRegistry::new();
+    let _a = register(&mut registry, 1, 10);
+    let _b = register(&mut registry, 1, 11);
+    let draining = id(10).to_hex();
+
+    let inflight = registry
+        .select_for_product(&id(1), &[id(11).to_hex()])
+        .unwrap();
+    assert_eq!(registry.begin_drain(&draining), Some(1));
+    assert_eq!(registry.count(), 2);
+    for _ in 0..4 {
+        assert_eq!(pick(&registry, 1).unwrap(), id(11).to_hex());
+    }
+
+    drop(inflight);
+    registry.prune();
+    assert_eq!(registry.count(), 1);
+    assert_eq!(registry.begin_drain(&draining), None);
+}
+
+#[tokio::test]
+async fn drained_instance_is_removed_when_its_last_request_ends() {
+    let mut registry = BackendRegistry::new();
+    let _a = register(&mut registry, 1, 10);
+    let _b = register(&mut registry, 1, 11);
+    let draining = id(10).to_hex();
+    let first = registry
+        .select_for_product(&id(1), &[id(11).to_hex()])
+        .unwrap();
+    let second = registry
+        .select_for_product(&id(1), &[id(11).to_hex()])
+        .unwrap();
+    assert_eq!(registry.begin_drain(&draining), Some(2));
+
+    let registry = Arc::new(RwLock::new(registry));
+    let watcher = tokio::spawn(BackendRegistry::remove_when_drained(
+        Arc::clone(&registry),
+        draining,
+    ));
+    drop(first);
+    tokio::task::yield_now().await;
+    assert_eq!(registry.read().await.count(), 2);
+
+    drop(second);
+    tokio::time::timeout(Duration::from_secs(5), watcher)
+        .await
+        .expect("instance should be removed")
+        .unwrap();
+    assert_eq!(registry.read().await.count(), 1);
+}
+
+#[test]
+fn drained_idle_instance_is_removed_at_once() {
+    let mut registry = BackendRegistry::new();
+    let _a = register(&mut registry, 1, 10);
+
+    assert_eq!(registry.begin_drain(&id(10).to_hex()), Some(0));
+    assert_eq!(registry.count(), 0);
+    assert!(pick(&registry, 1).is_none());
+}
+
+#[test]
+fn deregister_removes_instance() {
+    let mut registry = BackendRegistry::new();
+    let _a = register(&mut registry, 1, 10);
+    let _other = register(&mut registry, 2, 20);
+
+    assert!(registry.deregister(&id(10).to_hex()));
+    assert!(!registry.deregister(&id(10).to_hex()));
+    assert!(pick(&registry, 1).is_none());
+    assert_eq!(pick(&registry, 2).unwrap(), id(20).to_hex());
+}
+
+#[test]
+fn full_queue_is_skipped() {
+    let mut registry = BackendRegistry::with_balancing(LoadBalancing::RoundRobin);
+    let _a = register_with_capacity(&mut registry, 1, 10, 1);
+    let _b = register(&mut registry, 1, 11);
+
+    let full = registry
+        .select_for_product(&id(1), &[id(11).to_hex()])
+        .unwrap();
+    full.sender.try_send("queued".to_string()).unwrap();
+
+    for _ in 0..4 {
+        assert_eq!(pick(&registry, 1).unwrap(), id(11).to_hex());
+    }
+    assert!(!registry.is_saturated(&id(1), &[]));
+}
+
+#[test]
+fn saturated_when_every_queue_is_full() {
+    let mut registry = BackendRegistry::new();
+    let mut rx = register_with_capacity(&mut registry, 1, 10, 1);
+    assert!(!registry.is_saturated(&id(1), &[]));
+    assert!(!registry.is_saturated(&id(2), &[]));
+
+    let selected = registry.select_for_product(&id(1), &[]).unwrap();
+    selected.sender.try_send("queued".to_string()).unwrap();
+    assert!(pick(&registry, 1).is_none());
+    assert!(registry.is_saturated(&id(1), &[]));
+    assert!(!registry.is_saturated(&id(1), &[id(10).to_hex()]));
+
+    rx.try_recv().unwrap();
+    assert!(!registry.is_saturated(&id(1), &[]));
+    assert_eq!(pick(&registry, 1).unwrap(), id(10).to_hex());
+}
+
+#[test]
+fn queue_depths_report_each_instance() {
+    let mut registry = BackendRegistry::new();
+    let _a = register_with_capacity(&mut registry, 1, 10, 4);
+    let _b = register(&mut registry, 2, 20);
+
+    let selected = registry.select_for_product(&id(1), &[]).unwrap();
+    selected.sender.try_send("one".to_string()).unwrap();
+    selected.sender.try_send("two".to_string()).unwrap();
+
+    let depths = registry.queue_depths();
+    assert_eq!(depths.len(), 2);
+    assert_eq!(depths[0].instance_id, id(10).to_hex());
+    assert_eq!(depths[0].queue_depth, 2);
+    assert_eq!(depths[0].queue_capacity, 4);
+    assert_eq!(depths[0].inflight, 1);
+    assert_eq!(depths[0].health, "healthy");
+    assert_eq!(depths[1].queue_depth, 0);
+    assert_eq!(depths[1].queue_capacity, 8);
+}
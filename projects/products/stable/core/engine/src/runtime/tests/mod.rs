@@ -0,0 +1,3 @@
+mod backend_limits;
+mod backend_registry;
+mod route_table;
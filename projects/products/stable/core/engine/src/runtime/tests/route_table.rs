@@ -0,0 +1,124 @@
+// projects/products/stable/core/engine/src/runtime/tests/route_table.rs
+use common::Id128;
+use protocol::protocol_id::ProtocolId;
+use protocol::{PayloadFieldType, PayloadSchema};
+use tokio::sync::mpsc::{Receiver, channel};
+
+use crate::runtime::{BackendInfo, BackendRegistry, RegistrationError, RouteKind};
+
+fn id(byte: u8) -> ProtocolId {
+    ProtocolId::new(Id128::from_bytes_unchecked([byte; 16]))
+}
+
+fn info(product: u8, instance: u8, capabilities: &[&str], routes: &[&str]) -> BackendInfo {
+    BackendInfo {
+        product_id: id(product),
+        instance_id: id(instance),
+        capabilities: capabilities.iter().map(|c| c.to_string()).collect(),
+        routes: routes.iter().map(|r| r.to_string()).collect(),
+        schemas: Default::default(),
+    }
+}
+
+fn register(
+    registry: &mut BackendRegistry,
+    info: BackendInfo,
+) -> Result<Receiver<String>, RegistrationError> {
+    let (tx, rx) = channel(8);
+    registry.register_with_sender(info, tx)?;
+    Ok(rx)
+}
+
+#[test]
+fn exact_route_wins_over_capability() {
+    let mut registry = BackendRegistry::new();
+    let _git = register(&mut registry, info(1, 10, &[], &["git_autopilot.preview"])).unwrap();
+    let _ns = register(&mut registry, info(2, 20, &["git_autopilot"], &[])).unwrap();
+
+    let exact = registry.resolve_action("git_autopilot.preview").unwrap();
+    assert_eq!(exact.product_id, id(1));
+    assert_eq!(exact.kind, RouteKind::Route);
+
+    let namespaced = registry.resolve_action("git_autopilot.apply").unwrap();
+    assert_eq!(namespaced.product_id, id(2));
+    assert_eq!(namespaced.kind, RouteKind::Capability);
+
+    assert!(registry.resolve_action("git_autopilot").is_none());
+    assert!(registry.resolve_action("other.action").is_none());
+}
+
+#[test]
+fn conflicting_route_from_another_product_is_rejected() {
+    let mut registry = BackendRegistry::new();
+    let _first = register(&mut registry, info(1, 10, &["accounts"], &["x.run"])).unwrap();
+
+    let err = register(&mut registry, info(2, 20, &[], &["x.run"])).unwrap_err();
+    assert_eq!(
+        err,
+        RegistrationError::RouteConflict {
+            route: "x.run".to_string(),
+            product_id: id(1).to_hex(),
+        }
+    );
+    assert!(register(&mut registry, info(2, 21, &["accounts"], &[])).is_err());
+    assert_eq!(registry.count(), 1);
+
+    // Same product, another instance: joins the pool
+    let _second = register(&mut registry, info(1, 11, &["accounts"], &["x.run"])).unwrap();
+    assert_eq!(registry.resolve_action("x.run").unwrap().instances, 2);
+}
+
+#[test]
+fn routes_are_released_when_the_product_leaves() {
+    let mut registry = BackendRegistry::new();
+    let first = register(&mut registry, info(1, 10, &[], &["x.run"])).unwrap();
+    drop(first);
+
+    // The closed connection no longer owns the route
+    let _other = register(&mut registry, info(2, 20, &[], &["x.run"])).unwrap();
+    assert_eq!(registry.resolve_action("x.run").unwrap().product_id, id(2));
+
+    assert!(registry.deregister(&id(20).to_hex()));
+    assert!(registry.resolve_action("x.run").is_none());
+}
+
+#[test]
+fn reserved_namespaces_are_rejected() {
+    let mut registry = BackendRegistry::new();
+    for (capabilities, routes) in [
+        (&["engine"][..], &[][..]),
+        (&[][..], &["backend.hello"][..]),
+        (&[][..], &["project.start"][..]),
+    ] {
+        let err = register(&mut registry, info(1, 10, capabilities, routes)).unwrap_err();
+        assert!(matches!(err, RegistrationError::ReservedRoute(_)));
+    }
+    assert_eq!(registry.count(), 0);
+}
+
+#[test]
+fn schemas_are_published_with_their_route() {
+    let mut registry = BackendRegistry::new();
+    let schema = PayloadSchema::new().required("repo", PayloadFieldType::String);
+
+    let mut undeclared = info(1, 10, &[], &["x.run"]);
+    undeclared
+        .schemas
+        .insert("x.other".to_string(), schema.clone());
+    assert_eq!(
+        register(&mut registry, undeclared).unwrap_err(),
+        RegistrationError::UndeclaredSchema("x.other".to_string())
+    );
+
+    let mut declared = info(1, 10, &[], &["x.run"]);
+    declared.schemas.insert("x.run".to_string(), schema.clone());
+    let _rx = register(&mut registry, declared).unwrap();
+    assert_eq!(
+        registry.resolve_action("x.run").unwrap().schema,
+        Some(schema)
+    );
+
+    let entries = registry.route_entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].action, "x.run");
+}
@@ -1,5 +1,8 @@
 // projects/products/stable/core/engine/src/ws/backend_registration.rs
+use std::collections::BTreeMap;
+
 use protocol::protocol_id::ProtocolId;
+use protocol::{Handshake, PayloadSchema, SchemaVersionRange};
 use serde::Deserialize;
 
 /// Backend service registration payload
@@ -9,4 +12,17 @@ pub(crate) struct BackendRegistration {
     pub(crate) instance_id: ProtocolId,
     pub(crate) capabilities: Vec<String>,
     pub(crate) routes: Vec<String>,
+    /// Schema versions the backend speaks. Backends predating versioning omit it
+    /// and are assumed to speak the current version.
+    #[serde(default)]
+    pub(crate) schema_versions: Option<SchemaVersionRange>,
+    /// Payload schemas by declared route or capability
+    #[serde(default)]
+    pub(crate) schemas: BTreeMap<String, PayloadSchema>,
+}
+
+impl BackendRegistration {
+    pub(crate) fn handshake(&self) -> Handshake {
+        Handshake::new(self.schema_versions.unwrap_or_default())
+    }
 }
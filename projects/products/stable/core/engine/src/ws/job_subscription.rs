@@ -0,0 +1,42 @@
+// projects/products/stable/core/engine/src/ws/job_subscription.rs
+use protocol::protocol_id::ProtocolId;
+use serde::Deserialize;
+use tokio::sync::broadcast;
+
+use crate::jobs::{JobEvent, JobStore};
+
+/// Payload of `engine.jobs.subscribe`
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct JobSubscriptionRequest {
+    /// Last `seq` the client saw; events after it are replayed first.
+    /// Without it, only new events are sent.
+    #[serde(default)]
+    pub(crate) after_seq: Option<u64>,
+    /// Restrict the subscription to one job
+    #[serde(default)]
+    pub(crate) job_id: Option<ProtocolId>,
+}
+
+/// Live job events followed by one WebSocket connection
+#[derive(Debug)]
+pub(crate) struct JobSubscription {
+    pub(crate) receiver: broadcast::Receiver<JobEvent>,
+    pub(crate) job_id: Option<ProtocolId>,
+    /// Only jobs started by this subject; every job when unset
+    pub(crate) subject_id: Option<String>,
+    /// Last `seq` sent to the client
+    pub(crate) cursor: u64,
+}
+
+impl JobSubscription {
+    /// Whether `event` is for this subscriber and not sent yet
+    pub(crate) fn accepts(&self, event: &JobEvent, store: &JobStore) -> bool {
+        event.seq > self.cursor
+            && self.job_id.is_none_or(|id| id == event.job_id)
+            && self.subject_id.as_deref().is_none_or(|subject| {
+                store
+                    .get(&event.job_id)
+                    .is_some_and(|job| job.subject_id == subject)
+            })
+    }
+}
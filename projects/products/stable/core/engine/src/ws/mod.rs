@@ -1,6 +1,9 @@
 // projects/products/stable/core/engine/src/ws/mod.rs
 mod backend_registration;
+mod job_subscription;
 mod router;
+#[cfg(test)]
+mod tests;
 pub(crate) mod ws_event_args;
 pub(crate) mod ws_event_error_details;
 pub(crate) mod ws_events;
@@ -8,9 +11,10 @@ pub(crate) mod ws_events_errors;
 pub(crate) mod ws_handlers;
 
 pub(crate) use backend_registration::BackendRegistration;
+pub(crate) use job_subscription::{JobSubscription, JobSubscriptionRequest};
 pub(crate) use router::route_command;
 pub(crate) use ws_event_args::WsEventArgs;
 pub(crate) use ws_event_error_details::WsEventErrorDetails;
 pub(crate) use ws_events::{ws_event, ws_event_ok, ws_event_ok_payload};
-pub(crate) use ws_events_errors::ws_event_error;
+pub(crate) use ws_events_errors::{ws_event_error, ws_event_forward_error};
 pub(crate) use ws_handlers::ws_handle;
@@ -1,22 +1,23 @@
 // projects/products/stable/core/engine/src/ws/router.rs
 use common_json::{pjson, to_value};
-use protocol::{Command, Event, EventType, Payload, ProjectMetadata};
+use protocol::{
+    Command, CommandType, Event, EventType, Metadata, Payload, ProjectMetadata, SchemaVersionRange,
+};
 use security::{Permission, Token};
 use tracing::{info, warn};
 
+use crate::jobs::{JobRecord, JobStoreError};
 use crate::{
-    EngineState, require_permission, require_project_exists,
-    routes::http_forwarder::forward_to_backend,
-    ws::{ws_event_error, ws_event_ok, ws_event_ok_payload},
+    EngineState, RouteEntry, action_permission, is_ws_dispatchable, require_permission,
+    require_project_exists,
+    routes::http_forwarder::forward_with_origin,
+    ws::{ws_event_error, ws_event_forward_error, ws_event_ok, ws_event_ok_payload},
 };
 
 /// Command router "engine-level".
-pub(crate) async fn route_command(
-    cmd: Command,
-    state: &EngineState,
-    token: &Token,
-    _perms: &[Permission], // to be implemented as soon as possible
-) -> Event {
+pub(crate) async fn route_command(mut cmd: Command, state: &EngineState, token: &Token) -> Event {
+    // Backends identify the caller by this field; never trust the client's
+    cmd.metadata.client_id = Some(token.subject_id);
     let meta = cmd.metadata.clone();
 
     let action = match cmd.action.as_deref().map(str::trim) {
@@ -24,6 +25,11 @@ pub(crate) async fn route_command(
         _ => return ws_event_error(&meta, 400, 1000, "Command action is missing"),
     };
 
+    if let Err(e) = meta.check_schema_version(&SchemaVersionRange::current()) {
+        warn!(action, error = %e, "WS cmd rejected: unsupported schema version");
+        return ws_event_error(&meta, 426, e.code, e.message);
+    }
+
     match action {
         "engine.ping" => {
             info!("WS cmd: engine.ping (subject_id={})", token.subject_id);
@@ -36,7 +42,7 @@ pub(crate) async fn route_command(
                 token.subject_id
             );
 
-            if let Err(e) = require_permission(token, Permission::Read) {
+            if let Err(e) = require_permission(state, token, Permission::Read, "engine:projects") {
                 return ws_event_error(&meta, 403, 1003, e);
             }
 
@@ -51,10 +57,6 @@ pub(crate) async fn route_command(
         "project.start" | "project.stop" => {
             info!("WS cmd: {} (subject_id={})", action, token.subject_id);
 
-            if let Err(e) = require_permission(token, Permission::Admin) {
-                return ws_event_error(&meta, 403, 1003, e);
-            }
-
             let product_id = match cmd.metadata.product_id {
                 Some(id) => id,
                 None => {
@@ -62,11 +64,18 @@ pub(crate) async fn route_command(
                 }
             };
 
+            let resource = format!("product:{product_id}");
+            if let Err(e) = require_permission(state, token, Permission::Admin, &resource) {
+                return ws_event_error(&meta, 403, 1003, e);
+            }
+
             let payload = cmd.payload.clone().unwrap_or(Payload {
                 payload_type: None,
                 payload: None,
             });
-            match forward_to_backend(&product_id, action, payload, state).await {
+            match forward_with_origin(&product_id, action, payload, Some(&cmd.metadata), state)
+                .await
+            {
                 Ok(event) => {
                     if event.event_type == EventType::Error {
                         let msg = event
@@ -86,7 +95,7 @@ pub(crate) async fn route_command(
                     };
                     ws_event_ok_payload(&meta, name, "engine/product", value)
                 }
-                Err(e) => ws_event_error(&meta, 502, 1007, format!("Backend error: {e}")),
+                Err(e) => ws_event_forward_error(&meta, &e),
             }
         }
 
@@ -98,29 +107,148 @@ pub(crate) async fn route_command(
                 return ws_event_error(&meta, 404, 1001, e);
             }
 
-            let required_perm = if action.contains("read") || action.contains("get") {
-                Permission::Read
-            } else if action.contains("write") || action.contains("update") {
-                Permission::Write
-            } else if action.contains("delete") {
-                Permission::Delete
-            } else {
-                Permission::Execute
-            };
-
-            if let Err(e) = require_permission(token, required_perm) {
+            let resource = format!("project:{}", cmd.metadata.to_key());
+            if let Err(e) = require_permission(state, token, action_permission(action), &resource) {
                 return ws_event_error(&meta, 403, 1003, e);
             }
 
             ws_event_error(&meta, 501, 1005, "Project actions not implemented yet")
         }
 
-        other => {
+        // Account and auth actions go through the HTTP routes only
+        other if !is_ws_dispatchable(other) => {
             warn!(
-                "WS cmd: unsupported action '{}' (subject_id={})",
+                "WS cmd: '{}' is only available over HTTP (subject_id={})",
                 other, token.subject_id
             );
-            ws_event_error(&meta, 404, 1004, format!("Unsupported action: {}", other))
+            ws_event_error(
+                &meta,
+                403,
+                1003,
+                format!("Action is not available over WS: {}", other),
+            )
+        }
+
+        // Actions declared by backends at registration
+        other => {
+            let route = state
+                .backend_registry
+                .read()
+                .await
+                .resolve_action(other)
+                .cloned();
+            match route {
+                Some(route) => dispatch_to_route(&cmd, other, route, state, token).await,
+                None => {
+                    warn!(
+                        "WS cmd: unsupported action '{}' (subject_id={})",
+                        other, token.subject_id
+                    );
+                    ws_event_error(&meta, 404, 1004, format!("Unsupported action: {}", other))
+                }
+            }
+        }
+    }
+}
+
+/// Forwards `action` to the product whose backend declared it, after
+/// checking the caller's permission on `route:<action>` (see
+/// `action_permission`) and the payload schema published with the route. `StartJob` commands are tracked in the job store under
+/// `metadata.job_id` (the request id when unset).
+async fn dispatch_to_route(
+    cmd: &Command,
+    action: &str,
+    route: RouteEntry,
+    state: &EngineState,
+    token: &Token,
+) -> Event {
+    let mut meta = cmd.metadata.clone();
+    info!(
+        "WS cmd: {} -> product {} (subject_id={})",
+        action, route.product_id, token.subject_id
+    );
+
+    let resource = format!("route:{action}");
+    if let Err(e) = require_permission(state, token, action_permission(action), &resource) {
+        return ws_event_error(&meta, 403, 1003, e);
+    }
+
+    if let Some(schema) = &route.schema
+        && let Err(e) = schema.validate(cmd.payload.as_ref())
+    {
+        warn!(action, error = %e, "WS cmd rejected: payload does not match route schema");
+        let http_code = u16::try_from(e.code).unwrap_or(400);
+        return ws_event_error(&meta, http_code, e.code, e.message);
+    }
+
+    let payload = cmd.payload.clone().unwrap_or(Payload {
+        payload_type: None,
+        payload: None,
+    });
+    let job_id = if cmd.command_type == CommandType::StartJob {
+        let job_id = meta.job_id.unwrap_or(meta.request_id);
+        let job = JobRecord::new(
+            job_id,
+            route.product_id,
+            action,
+            &token.subject_id.to_string(),
+            Metadata::current_timestamp_ms(),
+        );
+        match state.jobs.write().await.create(job) {
+            Ok(_) => {}
+            Err(e @ JobStoreError::DuplicateJob(_)) => {
+                return ws_event_error(&meta, 409, 1008, e.to_string());
+            }
+            Err(e) => {
+                warn!(action, error = %e, "Failed to record job");
+                return ws_event_error(&meta, 500, 1009, "Failed to record job");
+            }
+        }
+        meta.job_id = Some(job_id);
+        Some(job_id)
+    } else {
+        None
+    };
+
+    let result = forward_with_origin(&route.product_id, action, payload, Some(&meta), state).await;
+    if let Some(job_id) = job_id {
+        let mut jobs = state.jobs.write().await;
+        let now_ms = Metadata::current_timestamp_ms();
+        let recorded = match &result {
+            Ok(event) => jobs.record_event(&job_id, event, now_ms),
+            Err(e) => jobs.record(
+                &job_id,
+                EventType::Failed,
+                Some(e.to_string()),
+                None,
+                None,
+                now_ms,
+            ),
+        };
+        if let Err(e) = recorded {
+            warn!(action, job_id = %job_id, error = %e, "Failed to record job event");
+        }
+    }
+
+    match result {
+        Ok(event) => {
+            if event.event_type == EventType::Error {
+                let msg = event
+                    .message
+                    .as_deref()
+                    .unwrap_or("Backend returned an error");
+                return ws_event_error(&meta, 502, 1007, msg.to_string());
+            }
+            let (payload_type, value) = match event.payload {
+                Some(Payload {
+                    payload_type,
+                    payload: Some(value),
+                }) => (payload_type, value),
+                _ => (None, pjson!({"ok": true})),
+            };
+            let payload_type = payload_type.unwrap_or_else(|| "backend/result".to_string());
+            ws_event_ok_payload(&meta, "CommandCompleted", &payload_type, value)
         }
+        Err(e) => ws_event_forward_error(&meta, &e),
     }
 }
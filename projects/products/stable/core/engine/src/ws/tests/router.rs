@@ -0,0 +1,147 @@
+// projects/products/stable/core/engine/src/ws/tests/router.rs
+use common::Id128;
+use protocol::protocol_id::ProtocolId;
+use protocol::{Command, CommandType, Event, Metadata};
+use security::{Role, Token, TokenService};
+use tokio::sync::mpsc::{Receiver, channel};
+
+use crate::auth::{
+    AuditLog, AuthSettings, DEFAULT_LOGIN_ACCOUNT_LIMIT, DEFAULT_LOGIN_IP_LIMIT, PolicyRuleConfig,
+    engine_policy,
+};
+use crate::jobs::JobStore;
+use crate::runtime::{BackendInfo, BackendLimits, LoadBalancing};
+use crate::ws::route_command;
+use crate::{EngineState, Registry};
+
+fn id(byte: u8) -> ProtocolId {
+    ProtocolId::new(Id128::from_bytes_unchecked([byte; 16]))
+}
+
+fn state() -> EngineState {
+    state_with_rules(&[])
+}
+
+fn state_with_rules(rules: &[PolicyRuleConfig]) -> EngineState {
+    EngineState::new(
+        Registry::default(),
+        TokenService::new_hs256("router_test_secret_router_test_secret").unwrap(),
+        LoadBalancing::default(),
+        BackendLimits::default(),
+        JobStore::new(),
+        AuthSettings {
+            login_ip_limit: DEFAULT_LOGIN_IP_LIMIT,
+            login_account_limit: DEFAULT_LOGIN_ACCOUNT_LIMIT,
+            trust_forwarded_for: false,
+        },
+        AuditLog::new(),
+    )
+    .with_policy(engine_policy(rules).unwrap())
+}
+
+fn token(role: Role) -> Token {
+    Token {
+        value: id(200),
+        subject_id: id(7),
+        role,
+        issued_at_ms: 0,
+        expires_at_ms: u64::MAX,
+        session_id: None,
+    }
+}
+
+/// Registers a backend of product 1 declaring `capabilities`
+async fn register(state: &EngineState, capabilities: &[&str]) -> Receiver<String> {
+    let (tx, rx) = channel(8);
+    let info = BackendInfo {
+        product_id: id(1),
+        instance_id: id(10),
+        capabilities: capabilities.iter().map(|c| c.to_string()).collect(),
+        routes: vec![],
+        schemas: Default::default(),
+    };
+    state
+        .backend_registry
+        .write()
+        .await
+        .register_with_sender(info, tx)
+        .unwrap();
+    rx
+}
+
+fn command(action: &str) -> Command {
+    Command {
+        metadata: Metadata::now(),
+        command_type: CommandType::Execute,
+        action: Some(action.to_string()),
+        payload: None,
+    }
+}
+
+fn http_code(event: &Event) -> Option<u64> {
+    event
+        .payload
+        .as_ref()?
+        .payload
+        .as_ref()?
+        .as_object()?
+        .get("http_code")?
+        .as_u64()
+}
+
+#[tokio::test]
+async fn account_actions_are_refused_over_ws() {
+    let state = state();
+    let mut backend = register(&state, &["auth", "accounts"]).await;
+
+    for role in [Role::User, Role::Admin] {
+        for action in ["accounts.update", "accounts.setup_admin", "auth.login"] {
+            let event = route_command(command(action), &state, &token(role)).await;
+            assert_eq!(http_code(&event), Some(403), "{action} as {role:?}");
+        }
+    }
+    assert!(backend.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn route_permission_follows_the_action_verb() {
+    let state = state();
+    let mut backend = register(&state, &["git_autopilot"]).await;
+
+    let event = route_command(
+        command("git_autopilot.delete_branch"),
+        &state,
+        &token(Role::User),
+    )
+    .await;
+
+    assert_eq!(http_code(&event), Some(403));
+    assert!(backend.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn configured_deny_rules_apply_to_routes() {
+    let rules: Vec<PolicyRuleConfig> = common_json::from_json_str(
+        r#"[{ "id": "no-git-for-users", "effect": "deny",
+              "subject": { "kind": "role", "value": "user" },
+              "actions": ["*"], "resources": ["route:git_autopilot.*"] }]"#,
+    )
+    .unwrap();
+    let state = state_with_rules(&rules);
+    let mut backend = register(&state, &["git_autopilot"]).await;
+
+    let event = route_command(command("git_autopilot.preview"), &state, &token(Role::User)).await;
+
+    assert_eq!(http_code(&event), Some(403));
+    assert!(backend.try_recv().is_err());
+}
+
+#[test]
+fn invalid_configured_rules_are_refused() {
+    let rules: Vec<PolicyRuleConfig> = common_json::from_json_str(
+        r#"[{ "id": "typo", "effect": "deny", "subject": { "kind": "role", "value": "usr" },
+              "actions": ["execute"] }]"#,
+    )
+    .unwrap();
+    assert!(engine_policy(&rules).is_err());
+}
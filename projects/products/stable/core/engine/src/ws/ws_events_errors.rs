@@ -1,9 +1,13 @@
 // projects/products/stable/core/engine/src/ws/ws_events_errors.rs
-use protocol::{Event, EventType, EventVariant, Metadata, ProtocolId};
+use protocol::{CommandResponse, Event, EventType, EventVariant, Metadata, Payload, ProtocolId};
 use std::str::FromStr;
 
+use crate::routes::forward_error::ForwardError;
 use crate::ws::{WsEventArgs, WsEventErrorDetails, ws_event};
 
+/// Payload type of a `CommandResponse` carried in an error event
+pub(crate) const COMMAND_RESPONSE_PAYLOAD_TYPE: &str = "protocol/command_response";
+
 // Update ws_event_error to use the new error details struct
 pub(crate) fn ws_event_error(
     meta: &Metadata,
@@ -33,3 +37,30 @@ pub(crate) fn ws_event_error(
 
     ws_event(args)
 }
+
+/// Error event for a failed backend forward. Overload and deadline failures
+/// carry an explicit `CommandResponse` so clients can tell them apart from
+/// backend errors and retry.
+pub(crate) fn ws_event_forward_error(meta: &Metadata, err: &ForwardError) -> Event {
+    let Some(error) = err.protocol_error() else {
+        return ws_event_error(meta, 502, 1007, format!("Backend error: {err}"));
+    };
+    let status = err.http_status();
+    let mut event = ws_event_error(meta, status.as_u16(), error.code, error.message.clone());
+
+    let name = if error.is_overloaded() {
+        "Overloaded"
+    } else {
+        "DeadlineExceeded"
+    };
+    let description = status.canonical_reason().unwrap_or("Error");
+    let response = CommandResponse::from_error(meta.clone(), description, error);
+    if let Ok(value) = common_json::to_value(&response) {
+        event.name = name.to_string();
+        event.payload = Some(Payload {
+            payload_type: Some(COMMAND_RESPONSE_PAYLOAD_TYPE.to_string()),
+            payload: Some(value),
+        });
+    }
+    event
+}
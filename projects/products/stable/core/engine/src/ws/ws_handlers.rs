@@ -1,11 +1,20 @@
 // projects/products/stable/core/engine/src/ws/ws_handlers.rs
 use crate::{
-    EngineState, WS_IDLE_TIMEOUT, WS_MAX_MESSAGE_BYTES, WS_PING_EVERY,
-    ws::{BackendRegistration, route_command, ws_event_error, ws_event_ok},
+    BackendInfo, EngineState, WS_IDLE_TIMEOUT, WS_MAX_MESSAGE_BYTES, WS_PING_EVERY,
+    jobs::JobEvent,
+    require_jobs_access,
+    runtime::BackendRegistry,
+    ws::{
+        BackendRegistration, JobSubscription, JobSubscriptionRequest, route_command,
+        ws_event_error, ws_event_ok, ws_event_ok_payload,
+    },
 };
-use common_json::{JsonSerializable, from_json_str, from_value};
+use common_json::{JsonSerializable, from_json_str, from_value, pjson, to_value};
 use futures_util::{SinkExt, StreamExt};
-use protocol::{Command, Event, Metadata};
+use protocol::protocol_id::ProtocolId;
+use protocol::{Command, Event, HANDSHAKE_ACK_PAYLOAD_TYPE, Handshake, Metadata};
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
 use tracing::{info, warn};
 use warp::ws::{Message, WebSocket};
 
@@ -16,10 +25,13 @@ const ERR_UNAUTHORIZED: &str = "Unauthorized";
 const ERR_MSG_TOO_LARGE: &str = "Message too large";
 const ERR_INVALID_JSON: &str = "Invalid Command JSON";
 const ERR_SERIALIZATION: &str = "Failed to serialize event";
+const ERR_NOT_REGISTERED: &str = "No backend registered on this connection";
+const ERR_SESSION_KILLED: &str = "Session revoked";
 
 // HTTP + app codes (stable contract with the front)
 const HTTP_UNAUTHORIZED: u16 = 401;
 const CODE_UNAUTHORIZED: i32 = 2000;
+const CODE_SESSION_KILLED: i32 = 2001;
 
 const HTTP_TOO_LARGE: u16 = 413;
 const CODE_TOO_LARGE: i32 = 2101;
@@ -27,6 +39,19 @@ const CODE_TOO_LARGE: i32 = 2101;
 const HTTP_BAD_JSON: u16 = 400;
 const CODE_BAD_JSON: i32 = 2100;
 
+const HTTP_FORBIDDEN: u16 = 403;
+const CODE_FORBIDDEN: i32 = 1003;
+
+const HTTP_CONFLICT: u16 = 409;
+const CODE_NOT_REGISTERED: i32 = 2300;
+const CODE_ROUTE_CONFLICT: i32 = 2301;
+
+const HTTP_UPGRADE_REQUIRED: u16 = 426;
+
+const JOBS_SUBSCRIBE_ACTION: &str = "engine.jobs.subscribe";
+const JOBS_UNSUBSCRIBE_ACTION: &str = "engine.jobs.unsubscribe";
+const JOB_EVENT_PAYLOAD_TYPE: &str = "engine/job_event";
+
 const HTTP_SERIALIZE: u16 = 500;
 const CODE_SERIALIZE: i32 = 2200;
 
@@ -50,9 +75,53 @@ fn meta_now() -> Metadata {
     Metadata::now()
 }
 
-pub(crate) async fn ws_handle(socket: WebSocket, state: EngineState, jwt: String) {
+/// Next live job event, or never when the connection is not subscribed
+async fn next_job_event(subscription: &mut Option<JobSubscription>) -> Result<JobEvent, RecvError> {
+    match subscription {
+        Some(subscription) => subscription.receiver.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Sends the events the subscriber has not seen and may see, and moves its
+/// cursor past all of them
+async fn send_job_events(
+    tx: &mut (impl SinkExt<Message> + Unpin),
+    state: &EngineState,
+    subscription: &mut JobSubscription,
+    events: Vec<JobEvent>,
+) -> bool {
+    let events: Vec<JobEvent> = {
+        let jobs = state.jobs.read().await;
+        events
+            .into_iter()
+            .filter(|event| subscription.accepts(event, &jobs))
+            .collect()
+    };
+    for event in events {
+        subscription.cursor = subscription.cursor.max(event.seq);
+        let mut meta = meta_now();
+        meta.job_id = Some(event.job_id);
+        let value = to_value(&event).unwrap_or_else(|_| pjson!({}));
+        let ev = ws_event_ok_payload(&meta, "JobEvent", JOB_EVENT_PAYLOAD_TYPE, value);
+        if !send_event(tx, &ev).await {
+            return false;
+        }
+    }
+    true
+}
+
+pub(crate) async fn ws_handle(
+    socket: WebSocket,
+    state: EngineState,
+    jwt: String,
+    ip: Option<String>,
+) {
     let (mut tx, mut rx) = socket.split();
-    let (out_tx, mut out_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    // Bounded: a backend that does not read fast enough is reported as
+    // overloaded instead of growing this queue
+    let (out_tx, mut out_rx) =
+        tokio::sync::mpsc::channel::<String>(state.backend_limits.queue_capacity);
 
     // --------
     // Auth
@@ -78,6 +147,13 @@ pub(crate) async fn ws_handle(socket: WebSocket, state: EngineState, jwt: String
         }
     };
 
+    // Killed sessions close their connections
+    let mut killed_sessions = {
+        let mut sessions = state.sessions.write().await;
+        sessions.touch(&token, ip.as_deref(), Metadata::current_timestamp_ms());
+        sessions.subscribe_kills()
+    };
+
     let perms = token.role.permissions();
     info!(
         subject_id = %token.subject_id,
@@ -90,6 +166,13 @@ pub(crate) async fn ws_handle(socket: WebSocket, state: EngineState, jwt: String
     let mut ping = tokio::time::interval(WS_PING_EVERY);
     let mut last_activity = tokio::time::Instant::now();
 
+    // Backend instances registered through this connection
+    let mut registered_instances: Vec<String> = Vec::new();
+    let mut registered_products: Vec<ProtocolId> = Vec::new();
+
+    // Job events followed by this connection
+    let mut job_subscription: Option<JobSubscription> = None;
+
     loop {
         tokio::select! {
             _ = ping.tick() => {
@@ -115,6 +198,49 @@ pub(crate) async fn ws_handle(socket: WebSocket, state: EngineState, jwt: String
                     }
             }
 
+            killed = killed_sessions.recv() => {
+                let close = match killed {
+                    Ok(session_id) => token.session_id == Some(session_id),
+                    // Missed kills may include ours: check the token again
+                    Err(RecvError::Lagged(_)) => state.token_service.verify(&jwt).is_err(),
+                    Err(RecvError::Closed) => false,
+                };
+                if close {
+                    info!(subject_id = %token.subject_id, "WS session killed, closing connection");
+                    let ev = ws_event_error(&meta_now(), HTTP_UNAUTHORIZED, CODE_SESSION_KILLED, ERR_SESSION_KILLED);
+                    let _ = send_event(&mut tx, &ev).await;
+                    let _ = tx.send(Message::close()).await;
+                    break;
+                }
+            }
+
+            received = next_job_event(&mut job_subscription) => {
+                let Some(subscription) = job_subscription.as_mut() else {
+                    continue;
+                };
+                let events = match received {
+                    Ok(event) => vec![event],
+                    // Too slow for the live buffer: catch up from the store
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(subject_id = %token.subject_id, skipped, "WS job subscriber lagged, replaying");
+                        let replay = state
+                            .jobs
+                            .read()
+                            .await
+                            .replay(subscription.cursor, subscription.job_id.as_ref());
+                        replay.events
+                    }
+                    Err(RecvError::Closed) => {
+                        job_subscription = None;
+                        continue;
+                    }
+                };
+                if !send_job_events(&mut tx, &state, subscription, events).await {
+                    info!(subject_id = %token.subject_id, "WS connection closed during job event send");
+                    break;
+                }
+            }
+
             msg = rx.next() => {
                 let msg = match msg {
                     Some(Ok(m)) => m,
@@ -172,11 +298,22 @@ pub(crate) async fn ws_handle(socket: WebSocket, state: EngineState, jwt: String
                     }
                 };
 
-                // Event -> pending HTTP forwarders
+                // Event -> pending HTTP forwarders, or progress of a job
+                // started on one of this connection's products
                 if let Ok(event) = from_json_str::<Event>(text) {
                     let request_id = event.metadata.request_id;
                     if let Some(tx) = state.pending_requests.write().await.remove(&request_id) {
                         let _ = tx.send(event);
+                    } else if let Some(job_id) = event.metadata.job_id {
+                        let mut jobs = state.jobs.write().await;
+                        let owned = jobs
+                            .get(&job_id)
+                            .is_some_and(|job| registered_products.contains(&job.product_id));
+                        if owned
+                            && let Err(e) = jobs.record_event(&job_id, &event, Metadata::current_timestamp_ms())
+                        {
+                            warn!(job_id = %job_id, error = %e, "Failed to record job event");
+                        }
                     }
                     continue;
                 }
@@ -220,26 +357,143 @@ pub(crate) async fn ws_handle(socket: WebSocket, state: EngineState, jwt: String
                         }
                     };
 
+                    let ack = match Handshake::current().negotiate(&registration.handshake()) {
+                        Ok(ack) => ack,
+                        Err(e) => {
+                            warn!(product_id = %registration.product_id, error = %e, "Backend registration rejected");
+                            let ev = ws_event_error(&meta, HTTP_UPGRADE_REQUIRED, e.code, e.message);
+                            let _ = send_event(&mut tx, &ev).await;
+                            continue;
+                        }
+                    };
+
+                    let instance_id = registration.instance_id.to_hex();
+                    let backend_info = BackendInfo {
+                        product_id: registration.product_id,
+                        instance_id: registration.instance_id,
+                        capabilities: registration.capabilities,
+                        routes: registration.routes,
+                        schemas: registration.schemas,
+                    };
                     let mut backends = state.backend_registry.write().await;
-                    backends.register_with_sender(
-                        registration.product_id,
-                        registration.instance_id,
-                        registration.capabilities,
-                        registration.routes,
-                        out_tx.clone(),
-                    );
+                    if let Err(e) = backends.register_with_sender(backend_info, out_tx.clone()) {
+                        warn!(instance_id = %instance_id, error = %e, "Backend registration rejected");
+                        let ev = ws_event_error(&meta, HTTP_CONFLICT, CODE_ROUTE_CONFLICT, e.to_string());
+                        let _ = send_event(&mut tx, &ev).await;
+                        continue;
+                    }
+                    if !registered_instances.contains(&instance_id) {
+                        registered_instances.push(instance_id);
+                    }
+                    if !registered_products.contains(&registration.product_id) {
+                        registered_products.push(registration.product_id);
+                    }
                     info!(
                         registered_backends = backends.count(),
+                        schema_version = ack.schema_version,
                         "Backend registration accepted"
                     );
+                    drop(backends);
+
+                    let ev = match to_value(&ack) {
+                        Ok(v) => ws_event_ok_payload(&meta, "BackendRegistered", HANDSHAKE_ACK_PAYLOAD_TYPE, v),
+                        Err(_) => ws_event_ok(&meta, "BackendRegistered"),
+                    };
+                    let _ = send_event(&mut tx, &ev).await;
+                    continue;
+                }
+
+                // Graceful deregistration: stop receiving new requests and let
+                // in-flight ones finish before the backend disconnects
+                if cmd.action.as_deref() == Some("backend.goodbye") {
+                    if registered_instances.is_empty() {
+                        let ev = ws_event_error(&meta, HTTP_CONFLICT, CODE_NOT_REGISTERED, ERR_NOT_REGISTERED);
+                        let _ = send_event(&mut tx, &ev).await;
+                        continue;
+                    }
+
+                    let inflight: usize = {
+                        let mut backends = state.backend_registry.write().await;
+                        registered_instances
+                            .iter()
+                            .filter_map(|instance_id| backends.begin_drain(instance_id))
+                            .sum()
+                    };
+                    // Drained instances leave the pool as soon as their last
+                    // request is answered, not when the connection closes
+                    for instance_id in &registered_instances {
+                        tokio::spawn(BackendRegistry::remove_when_drained(
+                            Arc::clone(&state.backend_registry),
+                            instance_id.clone(),
+                        ));
+                    }
+                    let ev = ws_event_ok_payload(
+                        &meta,
+                        "BackendDraining",
+                        "engine/backend_draining",
+                        pjson!({ "inflight": inflight }),
+                    );
+                    let _ = send_event(&mut tx, &ev).await;
+                    continue;
+                }
 
-                    let ev = ws_event_ok(&meta, "BackendRegistered");
+                // Job event subscription: replay what the client missed since
+                // its cursor, then stream new events on this connection
+                if cmd.action.as_deref() == Some(JOBS_SUBSCRIBE_ACTION) {
+                    let subject_id = match require_jobs_access(&state, &token) {
+                        Ok(subject_id) => subject_id,
+                        Err(e) => {
+                            let ev = ws_event_error(&meta, HTTP_FORBIDDEN, CODE_FORBIDDEN, e);
+                            let _ = send_event(&mut tx, &ev).await;
+                            continue;
+                        }
+                    };
+                    let request: JobSubscriptionRequest =
+                        match cmd.payload.as_ref().and_then(|p| p.payload.clone()) {
+                            Some(v) => match from_value(v) {
+                                Ok(r) => r,
+                                Err(e) => {
+                                    let ev = ws_event_error(&meta, HTTP_BAD_JSON, CODE_BAD_JSON, format!("Invalid payload: {e}"));
+                                    let _ = send_event(&mut tx, &ev).await;
+                                    continue;
+                                }
+                            },
+                            None => JobSubscriptionRequest::default(),
+                        };
+
+                    // Subscribing and replaying under one lock: no event falls
+                    // between the replay and the live stream
+                    let (subscription, replay) = {
+                        let jobs = state.jobs.read().await;
+                        let after_seq = request.after_seq.unwrap_or_else(|| jobs.last_seq());
+                        let mut replay = jobs.replay(after_seq, request.job_id.as_ref());
+                        let subscription = JobSubscription {
+                            receiver: jobs.subscribe(),
+                            job_id: request.job_id,
+                            subject_id,
+                            cursor: after_seq,
+                        };
+                        replay.events.retain(|event| subscription.accepts(event, &jobs));
+                        (subscription, replay)
+                    };
+                    let cursor = replay.last_seq;
+                    job_subscription = Some(JobSubscription { cursor, ..subscription });
+
+                    let value = to_value(&replay).unwrap_or_else(|_| pjson!({}));
+                    let ev = ws_event_ok_payload(&meta, "JobsSubscribed", "engine/jobs_subscribed", value);
+                    let _ = send_event(&mut tx, &ev).await;
+                    continue;
+                }
+
+                if cmd.action.as_deref() == Some(JOBS_UNSUBSCRIBE_ACTION) {
+                    job_subscription = None;
+                    let ev = ws_event_ok(&meta, "JobsUnsubscribed");
                     let _ = send_event(&mut tx, &ev).await;
                     continue;
                 }
 
                 // Route
-                let ev = route_command(cmd, &state, &token, perms).await;
+                let ev = route_command(cmd, &state, &token).await;
 
                 // Serialize event, fallback if it fails
                 let out = ev.to_json_string().unwrap_or_else(|e| {
@@ -256,5 +510,12 @@ pub(crate) async fn ws_handle(socket: WebSocket, state: EngineState, jwt: String
         }
     }
 
+    // Closing the receiver marks this connection's instances as closed; a
+    // newer connection of the same instance stays registered
+    drop(out_rx);
+    if !registered_instances.is_empty() {
+        state.backend_registry.write().await.prune();
+    }
+
     info!(subject_id = %token.subject_id, "WS connection closed");
 }
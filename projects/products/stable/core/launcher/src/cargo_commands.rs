@@ -1,5 +1,5 @@
 // projects/products/stable/core/launcher/src/cargo_commands.rs
-use std::{path::Path, process::Command};
+use std::{collections::BTreeSet, path::Path, process::Command};
 
 use anyhow::{Context, Result, bail};
 
@@ -30,3 +30,53 @@ pub(crate) fn cargo_build(root: &Path, build: &Build, dry_run: bool) -> Result<(
     }
     Ok(())
 }
+
+/// Builds only `bins`. Arguments selecting targets in `extra_args`
+/// (`--bins`, `--workspace`, `--all`) are dropped.
+pub(crate) fn cargo_build_bins(
+    root: &Path,
+    build: &Build,
+    bins: &BTreeSet<String>,
+) -> Result<(), anyhow::Error> {
+    let mut cmd = Command::new("cargo");
+    cmd.arg("build");
+
+    if build.profile == "release" {
+        cmd.arg("--release");
+    }
+
+    for a in &build.extra_args {
+        if !matches!(a.as_str(), "--bins" | "--workspace" | "--all") {
+            cmd.arg(a);
+        }
+    }
+    for bin in bins {
+        cmd.arg("--bin").arg(bin);
+    }
+
+    cmd.current_dir(root);
+
+    println!("🔨 rebuild: {:?}", cmd);
+    let status = cmd.status().context("failed to run cargo build")?;
+    if !status.success() {
+        bail!("cargo build failed with status={status}");
+    }
+    Ok(())
+}
+
+/// Output of `cargo metadata --format-version 1 --no-deps` run in `root`
+pub(crate) fn cargo_metadata(root: &Path) -> Result<String, anyhow::Error> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .current_dir(root)
+        .output()
+        .context("failed to run cargo metadata")?;
+    if !output.status.success() {
+        bail!(
+            "cargo metadata failed with status={}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    String::from_utf8(output.stdout).context("cargo metadata output is not UTF-8")
+}
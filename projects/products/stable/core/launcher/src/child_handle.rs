@@ -1,10 +1,30 @@
 // projects/products/stable/core/launcher/src/child_handle.rs
 use std::{
     process::Child,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, atomic::AtomicBool},
 };
 
+use crate::{limits::Cgroup, probe::ServiceProbes};
+
 #[derive(Clone)]
 pub(crate) struct ChildHandle {
     pub(crate) child: Arc<Mutex<Child>>,
+    /// Set when the launcher stops the child on purpose; its supervisor
+    /// then exits instead of applying the restart policy
+    pub(crate) stop_requested: Arc<AtomicBool>,
+    /// Readiness and liveness probes bound to this process
+    pub(crate) probes: Arc<ServiceProbes>,
+    /// cgroup the process was moved into, if any
+    pub(crate) cgroup: Option<Arc<Cgroup>>,
+}
+
+impl ChildHandle {
+    pub(crate) fn new(child: Child, probes: ServiceProbes, cgroup: Option<Cgroup>) -> Self {
+        Self {
+            child: Arc::new(Mutex::new(child)),
+            stop_requested: Arc::new(AtomicBool::new(false)),
+            probes: Arc::new(probes),
+            cgroup: cgroup.map(Arc::new),
+        }
+    }
 }
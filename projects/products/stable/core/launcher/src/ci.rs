@@ -1,5 +1,5 @@
 // projects/products/stable/core/launcher/src/ci.rs
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 
 #[derive(Parser, Debug)]
 #[command(name = "core-launcher")]
@@ -25,4 +25,48 @@ pub(crate) struct Cli {
     /// Don't build before launch (overrides config)
     #[arg(long)]
     pub(crate) no_build: bool,
+
+    /// Watch sources; rebuild and restart affected services on change
+    #[arg(long)]
+    pub(crate) dev: bool,
+
+    #[command(subcommand)]
+    pub(crate) command: Option<CliCommand>,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum CliCommand {
+    /// Query the logs captured by a running launcher
+    Logs(LogsArgs),
+}
+
+#[derive(Args, Debug)]
+pub(crate) struct LogsArgs {
+    /// Only this service
+    #[arg(long)]
+    pub(crate) service: Option<String>,
+    /// Minimum level (trace, debug, info, warn, error)
+    #[arg(long)]
+    pub(crate) level: Option<String>,
+    /// Start of the time range: ms since the epoch or an age (30s, 10m, 2h, 1d)
+    #[arg(long)]
+    pub(crate) since: Option<String>,
+    /// End of the time range, same format as --since
+    #[arg(long)]
+    pub(crate) until: Option<String>,
+    /// Regex matched against the message and fields
+    #[arg(long)]
+    pub(crate) grep: Option<String>,
+    /// Most recent records printed from history
+    #[arg(long)]
+    pub(crate) limit: Option<usize>,
+    /// Keep printing new records
+    #[arg(long, short)]
+    pub(crate) follow: bool,
+    /// Print raw JSON records
+    #[arg(long)]
+    pub(crate) json: bool,
+    /// Logs API address (default: logs.http_addr of the config)
+    #[arg(long)]
+    pub(crate) addr: Option<String>,
 }
@@ -1,7 +1,9 @@
 // projects/products/stable/core/launcher/src/config.rs
 use serde::Deserialize;
 
-use crate::{build::Build, launcher::Launcher, service::Service, workspace::Workspace};
+use crate::{
+    build::Build, dev::Dev, launcher::Launcher, logs::Logs, service::Service, workspace::Workspace,
+};
 
 #[derive(Debug, Deserialize)]
 pub(crate) struct Config {
@@ -12,4 +14,8 @@ pub(crate) struct Config {
     pub(crate) launcher: Launcher,
     #[serde(default)]
     pub(crate) service: Vec<Service>,
+    #[serde(default)]
+    pub(crate) dev: Dev,
+    #[serde(default)]
+    pub(crate) logs: Logs,
 }
@@ -0,0 +1,45 @@
+// projects/products/stable/core/launcher/src/dev.rs
+use serde::Deserialize;
+
+/// Dev mode (`--dev`): watch sources, rebuild and restart affected services
+#[derive(Debug, Deserialize)]
+pub(crate) struct Dev {
+    /// Directories watched, relative to the workspace root
+    #[serde(default = "default_watch")]
+    pub(crate) watch: Vec<String>,
+    /// Directory names never watched
+    #[serde(default = "default_ignore")]
+    pub(crate) ignore: Vec<String>,
+    /// Quiet time after the last change before a rebuild starts
+    #[serde(default = "default_debounce")]
+    pub(crate) debounce_ms: u64,
+    #[serde(default = "default_poll_interval")]
+    pub(crate) poll_interval_ms: u64,
+}
+
+impl Default for Dev {
+    fn default() -> Self {
+        Self {
+            watch: default_watch(),
+            ignore: default_ignore(),
+            debounce_ms: default_debounce(),
+            poll_interval_ms: default_poll_interval(),
+        }
+    }
+}
+
+pub(crate) fn default_watch() -> Vec<String> {
+    vec!["projects".to_string()]
+}
+pub(crate) fn default_ignore() -> Vec<String> {
+    ["target", ".git", "node_modules"]
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+pub(crate) fn default_debounce() -> u64 {
+    500
+}
+pub(crate) fn default_poll_interval() -> u64 {
+    500
+}
@@ -14,10 +14,13 @@ use clap::Parser;
 use crate::{
     cargo_commands::cargo_build,
     child_handle::ChildHandle,
-    ci::Cli,
+    ci::{Cli, CliCommand},
     config::Config,
+    log_capture::{LogHub, install, run_logs_command, spawn_log_server},
+    logging::log_message,
     normalizer::normalize_path,
     parsing::parse_csv,
+    reload::{DevContext, run_dev_loop},
     service::{topo_sort, validate_services},
     shutdown::install_shutdown_handler,
     supervisor::start_and_supervise,
@@ -42,6 +45,10 @@ pub(crate) fn main() -> Result<()> {
     let mut cfg: Config =
         toml::from_str(&cfg_text).with_context(|| "invalid TOML in configuration file")?;
 
+    if let Some(CliCommand::Logs(args)) = &cli.command {
+        return run_logs_command(args, &cfg.logs.http_addr);
+    }
+
     // Apply CLI overrides
     if cli.build {
         cfg.build.enabled = true;
@@ -92,6 +99,20 @@ pub(crate) fn main() -> Result<()> {
     // Determine target dir + binary paths
     let (_target_dir, profile_dir) = target_paths(&workspace.root, &cfg.build.profile);
 
+    // Capture service output before the first spawn
+    if cfg.logs.enabled && !cli.dry_run {
+        let hub = Arc::new(LogHub::new(
+            Some(workspace.root.join(&cfg.logs.dir)),
+            &cfg.logs,
+        ));
+        install(hub.clone());
+        if !cfg.logs.http_addr.is_empty()
+            && let Err(e) = spawn_log_server(&cfg.logs.http_addr, hub)
+        {
+            log_message(&format!("logs API disabled: {:#}", e), true, "launcher");
+        }
+    }
+
     // State
     let running: Arc<Mutex<HashMap<String, ChildHandle>>> = Arc::new(Mutex::new(HashMap::new()));
     let shutting_down = Arc::new(Mutex::new(false));
@@ -103,10 +124,10 @@ pub(crate) fn main() -> Result<()> {
     )?;
 
     // Start services in order, waiting for readiness if configured
-    for name in start_order {
+    for name in &start_order {
         let svc = services
             .iter()
-            .find(|s| s.name == name)
+            .find(|s| &s.name == name)
             .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "Service not found"))?
             .clone();
         let paths = Paths {
@@ -125,6 +146,22 @@ pub(crate) fn main() -> Result<()> {
 
     println!("✅ all services started");
 
+    if cli.dev && !cli.dry_run {
+        return run_dev_loop(DevContext {
+            dev: &cfg.dev,
+            build: &cfg.build,
+            services: &services,
+            start_order: &start_order,
+            paths: Paths {
+                workspace: workspace.clone(),
+                profile_dir,
+            },
+            running,
+            shutting_down,
+            startup_timeout: Duration::from_millis(cfg.launcher.startup_timeout_ms),
+        });
+    }
+
     // Keep main alive; supervisor threads do the rest
     loop {
         thread::park();
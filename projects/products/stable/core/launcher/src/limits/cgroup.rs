@@ -0,0 +1,226 @@
+// projects/products/stable/core/launcher/src/limits/cgroup.rs
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::OnceLock,
+};
+
+use anyhow::{Context, Result, anyhow};
+
+use crate::{logging::log_message, service::Service};
+
+use super::resource_limits::{MIB, ResourceLimits};
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+/// `cpu.max` period, in microseconds
+const CPU_PERIOD_US: u64 = 100_000;
+
+/// cgroup v2 of one service, reused across its restarts
+#[derive(Debug)]
+pub(crate) struct Cgroup {
+    dir: PathBuf,
+    /// `oom_kill` count when the current process was spawned
+    oom_kills_at_attach: u64,
+}
+
+impl Cgroup {
+    /// cgroup of `svc` when it asks for one; falls back to rlimits only
+    /// (with a warning) when no cgroup can be set up
+    pub(crate) fn for_service(svc: &Service) -> Option<Self> {
+        if !svc.limits.cgroup {
+            return None;
+        }
+        match Self::create(&svc.name, &svc.limits) {
+            Ok(cgroup) => Some(cgroup),
+            Err(e) => {
+                log_message(
+                    &format!("cgroup unavailable, using rlimits only: {:#}", e),
+                    true,
+                    &svc.name,
+                );
+                None
+            }
+        }
+    }
+
+    /// Creates (or reuses) `launcher-<service>` under the cgroup parent (see
+    /// [`CgroupParent`]) and writes the limits into it
+    pub(crate) fn create(service: &str, limits: &ResourceLimits) -> Result<Self> {
+        let parent = prepared_parent()?;
+
+        let dir = parent.join(format!("launcher-{service}"));
+        fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+
+        let memory = limits
+            .max_memory_mb
+            .map_or("max".to_string(), |mb| mb.saturating_mul(MIB).to_string());
+        write(&dir, "memory.max", &memory)?;
+        let quota = limits.cpu_percent.map_or("max".to_string(), |pct| {
+            (u64::from(pct) * CPU_PERIOD_US / 100)
+                .max(1_000)
+                .to_string()
+        });
+        write(&dir, "cpu.max", &format!("{quota} {CPU_PERIOD_US}"))?;
+
+        Ok(Self {
+            dir,
+            oom_kills_at_attach: 0,
+        })
+    }
+
+    /// Makes the process spawned by `cmd` join the cgroup before it execs,
+    /// so neither it nor anything it forks runs outside the limits
+    #[cfg(unix)]
+    pub(crate) fn attach_on_spawn(&mut self, cmd: &mut Command) -> Result<()> {
+        use std::{io::Write, os::unix::process::CommandExt};
+
+        let path = self.dir.join("cgroup.procs");
+        let procs = fs::OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .with_context(|| format!("failed to open {}", path.display()))?;
+        self.oom_kills_at_attach = self.oom_kills();
+
+        // SAFETY: the closure runs in the forked child before exec; it only
+        // calls write(2), which is async-signal-safe, on a file opened before
+        // the fork. Writing "0" moves the writing process itself.
+        unsafe {
+            cmd.pre_exec(move || (&procs).write_all(b"0"));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn attach_on_spawn(&mut self, _cmd: &mut Command) -> Result<()> {
+        anyhow::bail!("cgroups are not supported on this platform")
+    }
+
+    /// Removes the cgroup directory once the service stopped for good. Fails
+    /// while processes (e.g. leftover descendants) are still inside.
+    pub(crate) fn remove(&self) -> Result<()> {
+        match fs::remove_dir(&self.dir) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(e).with_context(|| format!("failed to remove {}", self.dir.display()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the kernel OOM-killed a process since the last spawn
+    pub(crate) fn oom_killed(&self) -> bool {
+        self.oom_kills() > self.oom_kills_at_attach
+    }
+
+    /// Current memory use of the cgroup (`memory.current`)
+    pub(crate) fn memory_current(&self) -> Option<u64> {
+        fs::read_to_string(self.dir.join("memory.current"))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    fn oom_kills(&self) -> u64 {
+        fs::read_to_string(self.dir.join("memory.events"))
+            .ok()
+            .and_then(|events| {
+                events.lines().find_map(|line| {
+                    line.strip_prefix("oom_kill ")
+                        .and_then(|n| n.trim().parse().ok())
+                })
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// Where the `launcher-<service>` cgroups are created
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct CgroupParent {
+    pub(crate) dir: PathBuf,
+    /// Leaf the launcher first moves itself into: cgroup v2 refuses to
+    /// enable controllers for the children of a cgroup holding processes
+    pub(crate) launcher_leaf: Option<PathBuf>,
+}
+
+impl CgroupParent {
+    /// `LAUNCHER_CGROUP_PARENT` when set (a delegated, process-free cgroup),
+    /// otherwise the launcher's own cgroup, emptied into `launcher-self`
+    pub(crate) fn select(env_parent: Option<&str>, own: &Path) -> Self {
+        match env_parent.map(str::trim).filter(|p| !p.is_empty()) {
+            Some(parent) => Self {
+                dir: PathBuf::from(parent),
+                launcher_leaf: None,
+            },
+            None => Self {
+                dir: own.to_path_buf(),
+                launcher_leaf: Some(own.join("launcher-self")),
+            },
+        }
+    }
+
+    /// Moves the launcher into its leaf, if any, then enables the controllers
+    fn prepare(&self) -> Result<()> {
+        if let Some(leaf) = &self.launcher_leaf {
+            fs::create_dir_all(leaf)
+                .with_context(|| format!("failed to create {}", leaf.display()))?;
+            write(leaf, "cgroup.procs", &std::process::id().to_string())?;
+        }
+        enable_controllers(&self.dir).with_context(|| {
+            format!(
+                "cannot enable controllers on {}; set LAUNCHER_CGROUP_PARENT to a delegated cgroup",
+                self.dir.display()
+            )
+        })
+    }
+}
+
+/// Parent shared by every service, prepared on first use: the launcher only
+/// moves itself once
+fn prepared_parent() -> Result<PathBuf> {
+    static PARENT: OnceLock<Result<PathBuf, String>> = OnceLock::new();
+    PARENT
+        .get_or_init(|| {
+            let root = Path::new(CGROUP_ROOT);
+            if !root.join("cgroup.controllers").exists() {
+                return Err(format!("cgroup v2 is not mounted at {CGROUP_ROOT}"));
+            }
+            let own = own_cgroup(root).map_err(|e| format!("{e:#}"))?;
+            let env_parent = std::env::var("LAUNCHER_CGROUP_PARENT").ok();
+            let parent = CgroupParent::select(env_parent.as_deref(), &own);
+            parent.prepare().map_err(|e| format!("{e:#}"))?;
+            Ok(parent.dir)
+        })
+        .clone()
+        .map_err(|e| anyhow!(e))
+}
+
+/// `/sys/fs/cgroup/<path>` from the `0::<path>` line of `/proc/self/cgroup`
+fn own_cgroup(root: &Path) -> Result<PathBuf> {
+    let text =
+        fs::read_to_string("/proc/self/cgroup").context("failed to read /proc/self/cgroup")?;
+    let path = text
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .context("no cgroup v2 entry in /proc/self/cgroup")?;
+    Ok(root.join(path.trim_start_matches('/')))
+}
+
+fn enable_controllers(parent: &Path) -> Result<()> {
+    let enabled = fs::read_to_string(parent.join("cgroup.subtree_control")).unwrap_or_default();
+    let missing: Vec<String> = ["memory", "cpu"]
+        .into_iter()
+        .filter(|c| !enabled.split_whitespace().any(|e| e == *c))
+        .map(|c| format!("+{c}"))
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    write(parent, "cgroup.subtree_control", &missing.join(" "))
+}
+
+fn write(dir: &Path, file: &str, value: &str) -> Result<()> {
+    let path = dir.join(file);
+    fs::write(&path, value)
+        .with_context(|| format!("failed to write `{value}` to {}", path.display()))
+}
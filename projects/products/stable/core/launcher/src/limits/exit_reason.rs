@@ -0,0 +1,22 @@
+// projects/products/stable/core/launcher/src/limits/exit_reason.rs
+use std::process::ExitStatus;
+
+use super::cgroup::Cgroup;
+
+/// Which limit ended the process, when the exit status or cgroup tells
+pub(crate) fn limit_exit_reason(status: &ExitStatus, cgroup: Option<&Cgroup>) -> Option<String> {
+    if cgroup.is_some_and(Cgroup::oom_killed) {
+        return Some("cgroup memory limit (OOM kill)".to_string());
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        if status.signal() == Some(nix::sys::signal::Signal::SIGXCPU as i32) {
+            return Some("CPU time limit (SIGXCPU)".to_string());
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = status;
+    None
+}
@@ -0,0 +1,16 @@
+// projects/products/stable/core/launcher/src/limits/mod.rs
+mod cgroup;
+mod exit_reason;
+mod proc_stats;
+mod resource_limits;
+mod resource_sampler;
+mod rlimits;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use cgroup::Cgroup;
+pub(crate) use exit_reason::limit_exit_reason;
+pub(crate) use resource_limits::ResourceLimits;
+pub(crate) use resource_sampler::ResourceSampler;
+pub(crate) use rlimits::apply_rlimits;
@@ -0,0 +1,61 @@
+// projects/products/stable/core/launcher/src/limits/proc_stats.rs
+use std::{fs, io, time::Duration};
+
+/// Memory and CPU use of one process, read from `/proc`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ProcStats {
+    pub(crate) rss_bytes: u64,
+    /// User + system CPU time of all threads
+    pub(crate) cpu_time: Duration,
+}
+
+impl ProcStats {
+    pub(crate) fn read(pid: u32) -> io::Result<Self> {
+        let stat = fs::read_to_string(format!("/proc/{pid}/stat"))?;
+        let status = fs::read_to_string(format!("/proc/{pid}/status"))?;
+        Self::parse(&stat, &status, clock_ticks_per_sec())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unexpected /proc format"))
+    }
+
+    /// Parses `/proc/<pid>/stat` (utime, stime) and `/proc/<pid>/status` (VmRSS)
+    pub(crate) fn parse(stat: &str, status: &str, ticks_per_sec: u64) -> Option<Self> {
+        // The command name may contain spaces and parentheses; fields after
+        // it start at `state` (field 3), so utime (14) and stime (15) are at 11 and 12
+        let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+        let utime: u64 = fields.get(11)?.parse().ok()?;
+        let stime: u64 = fields.get(12)?.parse().ok()?;
+        let ticks = utime + stime;
+        let ticks_per_sec = ticks_per_sec.max(1);
+        let cpu_time = Duration::from_secs(ticks / ticks_per_sec)
+            + Duration::from_nanos((ticks % ticks_per_sec) * 1_000_000_000 / ticks_per_sec);
+
+        let rss_kb: u64 = status
+            .lines()
+            .find_map(|line| line.strip_prefix("VmRSS:"))
+            .and_then(|v| v.split_whitespace().next())
+            .and_then(|kb| kb.parse().ok())
+            // Zombies and kernel threads have no VmRSS line
+            .unwrap_or(0);
+
+        Some(Self {
+            rss_bytes: rss_kb * 1024,
+            cpu_time,
+        })
+    }
+}
+
+#[cfg(unix)]
+fn clock_ticks_per_sec() -> u64 {
+    use nix::unistd::{SysconfVar, sysconf};
+
+    sysconf(SysconfVar::CLK_TCK)
+        .ok()
+        .flatten()
+        .and_then(|t| u64::try_from(t).ok())
+        .unwrap_or(100)
+}
+
+#[cfg(not(unix))]
+fn clock_ticks_per_sec() -> u64 {
+    100
+}
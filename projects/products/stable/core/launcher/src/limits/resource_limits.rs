@@ -0,0 +1,49 @@
+// projects/products/stable/core/launcher/src/limits/resource_limits.rs
+use serde::Deserialize;
+
+/// Resource limits and accounting of a service (`[service.limits]`)
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub(crate) struct ResourceLimits {
+    /// Memory: cgroup `memory.max` when `cgroup` is active, otherwise the
+    /// address space rlimit (`RLIMIT_AS`), so allocations beyond it fail
+    #[serde(default)]
+    pub(crate) max_memory_mb: Option<u64>,
+    /// CPU time (`RLIMIT_CPU`): SIGXCPU at the limit, SIGKILL 5s of CPU later
+    #[serde(default)]
+    pub(crate) max_cpu_seconds: Option<u64>,
+    /// Open file descriptors (`RLIMIT_NOFILE`)
+    #[serde(default)]
+    pub(crate) max_open_files: Option<u64>,
+    /// Resident memory checked at every sample; the process is killed above it
+    #[serde(default)]
+    pub(crate) max_rss_mb: Option<u64>,
+    /// Run the service in its own cgroup v2 when one can be created
+    #[serde(default)]
+    pub(crate) cgroup: bool,
+    /// cgroup CPU bandwidth (`cpu.max`); 100 = one full core
+    #[serde(default)]
+    pub(crate) cpu_percent: Option<u32>,
+    /// RSS/CPU sampling period; 0 disables sampling and `max_rss_mb`
+    #[serde(default = "default_sample_interval")]
+    pub(crate) sample_interval_ms: u64,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> Self {
+        Self {
+            max_memory_mb: None,
+            max_cpu_seconds: None,
+            max_open_files: None,
+            max_rss_mb: None,
+            cgroup: false,
+            cpu_percent: None,
+            sample_interval_ms: default_sample_interval(),
+        }
+    }
+}
+
+pub(crate) fn default_sample_interval() -> u64 {
+    30_000
+}
+
+pub(crate) const MIB: u64 = 1024 * 1024;
@@ -0,0 +1,97 @@
+// projects/products/stable/core/launcher/src/limits/resource_sampler.rs
+use std::time::{Duration, Instant};
+
+use super::{
+    proc_stats::ProcStats,
+    resource_limits::{MIB, ResourceLimits},
+};
+
+/// One periodic measurement of a service process
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ResourceSample {
+    pub(crate) stats: ProcStats,
+    /// CPU use since the previous sample, 100 = one full core
+    pub(crate) cpu_percent: Option<f64>,
+    /// `memory.current` of the service cgroup, when it has one
+    pub(crate) cgroup_memory: Option<u64>,
+}
+
+impl ResourceSample {
+    /// Line written to the service log stream
+    pub(crate) fn describe(&self) -> String {
+        let mut line = format!("📊 rss={:.1}MiB", self.stats.rss_bytes as f64 / MIB as f64);
+        if let Some(bytes) = self.cgroup_memory {
+            line.push_str(&format!(" cgroup={:.1}MiB", bytes as f64 / MIB as f64));
+        }
+        if let Some(pct) = self.cpu_percent {
+            line.push_str(&format!(" cpu={pct:.1}%"));
+        }
+        line.push_str(&format!(
+            " cpu_time={:.1}s",
+            self.stats.cpu_time.as_secs_f64()
+        ));
+        line
+    }
+
+    /// Reason to kill the process, if it is above `max_rss_mb`
+    pub(crate) fn exceeded(&self, limits: &ResourceLimits) -> Option<String> {
+        let max_mb = limits.max_rss_mb?;
+        (self.stats.rss_bytes > max_mb.saturating_mul(MIB)).then(|| {
+            format!(
+                "rss {:.1}MiB above max_rss_mb={max_mb}",
+                self.stats.rss_bytes as f64 / MIB as f64
+            )
+        })
+    }
+}
+
+/// Samples a process every `sample_interval_ms`
+#[derive(Debug)]
+pub(crate) struct ResourceSampler {
+    interval: Duration,
+    next_due: Instant,
+    previous: Option<(Instant, Duration)>,
+}
+
+impl ResourceSampler {
+    /// `None` when sampling is disabled
+    pub(crate) fn new(limits: &ResourceLimits, now: Instant) -> Option<Self> {
+        (limits.sample_interval_ms > 0).then(|| {
+            let interval = Duration::from_millis(limits.sample_interval_ms);
+            Self {
+                interval,
+                next_due: now + interval,
+                previous: None,
+            }
+        })
+    }
+
+    /// Reads the process stats when a sample is due
+    pub(crate) fn poll(&mut self, pid: u32, now: Instant) -> Option<ProcStats> {
+        if now < self.next_due {
+            return None;
+        }
+        self.next_due = now + self.interval;
+        ProcStats::read(pid).ok()
+    }
+
+    /// Turns stats read at `now` into a sample, with the CPU use since the
+    /// previous one
+    pub(crate) fn sample(
+        &mut self,
+        stats: ProcStats,
+        cgroup_memory: Option<u64>,
+        now: Instant,
+    ) -> ResourceSample {
+        let cpu_percent = self.previous.and_then(|(at, cpu)| {
+            let wall = now.checked_duration_since(at)?.as_secs_f64();
+            (wall > 0.0).then(|| stats.cpu_time.saturating_sub(cpu).as_secs_f64() / wall * 100.0)
+        });
+        self.previous = Some((now, stats.cpu_time));
+        ResourceSample {
+            stats,
+            cpu_percent,
+            cgroup_memory,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// projects/products/stable/core/launcher/src/limits/rlimits.rs
+use std::process::Command;
+
+#[cfg(not(unix))]
+use crate::logging::log_message;
+
+use super::resource_limits::{MIB, ResourceLimits};
+
+/// Extra CPU seconds between SIGXCPU (soft limit) and SIGKILL (hard limit)
+#[cfg(unix)]
+const CPU_KILL_GRACE_SECS: u64 = 5;
+
+/// Sets the rlimits of `limits` on the process spawned by `cmd` for service
+/// `name`. The memory rlimit is skipped when a cgroup enforces
+/// `max_memory_mb` instead.
+#[cfg(unix)]
+pub(crate) fn apply_rlimits(
+    cmd: &mut Command,
+    _name: &str,
+    limits: &ResourceLimits,
+    memory_in_cgroup: bool,
+) {
+    use std::os::unix::process::CommandExt;
+
+    use nix::sys::resource::{Resource, setrlimit};
+
+    let mut rlimits = Vec::new();
+    if let Some(mb) = limits.max_memory_mb.filter(|_| !memory_in_cgroup) {
+        let bytes = mb.saturating_mul(MIB);
+        rlimits.push((Resource::RLIMIT_AS, bytes, bytes));
+    }
+    if let Some(secs) = limits.max_cpu_seconds {
+        rlimits.push((
+            Resource::RLIMIT_CPU,
+            secs,
+            secs.saturating_add(CPU_KILL_GRACE_SECS),
+        ));
+    }
+    if let Some(files) = limits.max_open_files {
+        rlimits.push((Resource::RLIMIT_NOFILE, files, files));
+    }
+    if rlimits.is_empty() {
+        return;
+    }
+
+    // SAFETY: the closure runs in the forked child before exec; it only
+    // calls setrlimit(2), which is async-signal-safe, on a vector that was
+    // allocated before the fork.
+    unsafe {
+        cmd.pre_exec(move || {
+            for (resource, soft, hard) in &rlimits {
+                setrlimit(*resource, *soft, *hard)?;
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(unix))]
+pub(crate) fn apply_rlimits(
+    _cmd: &mut Command,
+    name: &str,
+    limits: &ResourceLimits,
+    memory_in_cgroup: bool,
+) {
+    if (limits.max_memory_mb.is_some() && !memory_in_cgroup)
+        || limits.max_cpu_seconds.is_some()
+        || limits.max_open_files.is_some()
+    {
+        log_message(
+            "rlimits are not supported on this platform; ignoring them",
+            true,
+            name,
+        );
+    }
+}
@@ -0,0 +1,30 @@
+// projects/products/stable/core/launcher/src/limits/tests/cgroup.rs
+use std::path::{Path, PathBuf};
+
+use crate::limits::cgroup::CgroupParent;
+
+const OWN: &str = "/sys/fs/cgroup/user.slice/session-1.scope";
+
+#[test]
+fn delegated_parent_is_used_as_is() {
+    let parent = CgroupParent::select(Some("/sys/fs/cgroup/launcher.slice"), Path::new(OWN));
+    assert_eq!(
+        parent,
+        CgroupParent {
+            dir: PathBuf::from("/sys/fs/cgroup/launcher.slice"),
+            launcher_leaf: None,
+        }
+    );
+}
+
+#[test]
+fn own_cgroup_is_emptied_into_a_leaf() {
+    for env in [None, Some(""), Some("  ")] {
+        let parent = CgroupParent::select(env, Path::new(OWN));
+        assert_eq!(parent.dir, PathBuf::from(OWN));
+        assert_eq!(
+            parent.launcher_leaf,
+            Some(Path::new(OWN).join("launcher-self"))
+        );
+    }
+}
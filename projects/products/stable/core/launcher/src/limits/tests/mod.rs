@@ -0,0 +1,5 @@
+mod cgroup;
+mod proc_stats;
+mod resource_sampler;
+#[cfg(unix)]
+mod rlimits;
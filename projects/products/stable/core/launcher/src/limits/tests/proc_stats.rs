@@ -0,0 +1,28 @@
+// projects/products/stable/core/launcher/src/limits/tests/proc_stats.rs
+use std::time::Duration;
+
+use crate::limits::proc_stats::ProcStats;
+
+const STATUS: &str =
+    "Name:\tengine\nState:\tS (sleeping)\nVmPeak:\t  204800 kB\nVmRSS:\t   51200 kB\nThreads:\t8\n";
+
+#[test]
+fn parses_cpu_time_and_rss() {
+    // utime = 250 ticks, stime = 50 ticks
+    let stat = "4242 (my engine (v2)) S 1 4242 4242 0 -1 4194560 1000 0 0 0 250 50 0 0 20 0 8 0 100 1000 12800";
+    let stats = ProcStats::parse(stat, STATUS, 100).expect("valid stat");
+    assert_eq!(stats.cpu_time, Duration::from_secs(3));
+    assert_eq!(stats.rss_bytes, 51_200 * 1024);
+}
+
+#[test]
+fn rejects_truncated_stat() {
+    assert_eq!(ProcStats::parse("4242 (engine) S 1 2", STATUS, 100), None);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn reads_the_current_process() {
+    let stats = ProcStats::read(std::process::id()).expect("readable /proc");
+    assert!(stats.rss_bytes > 0);
+}
@@ -0,0 +1,62 @@
+// projects/products/stable/core/launcher/src/limits/tests/resource_sampler.rs
+use std::time::{Duration, Instant};
+
+use crate::limits::{ResourceLimits, ResourceSampler, proc_stats::ProcStats};
+
+fn stats(rss_mb: u64, cpu_ms: u64) -> ProcStats {
+    ProcStats {
+        rss_bytes: rss_mb * 1024 * 1024,
+        cpu_time: Duration::from_millis(cpu_ms),
+    }
+}
+
+#[test]
+fn limits_default_to_sampling_only() {
+    let limits: ResourceLimits = toml::from_str("max_rss_mb = 256").expect("valid limits");
+    assert_eq!(limits.max_rss_mb, Some(256));
+    assert_eq!(limits.sample_interval_ms, 30_000);
+    assert!(!limits.cgroup);
+    assert_eq!(ResourceLimits::default().sample_interval_ms, 30_000);
+}
+
+#[test]
+fn cpu_use_is_measured_between_samples() {
+    let start = Instant::now();
+    let limits = ResourceLimits::default();
+    let mut sampler = ResourceSampler::new(&limits, start).expect("sampling enabled");
+
+    let first = sampler.sample(stats(10, 1_000), None, start);
+    assert_eq!(first.cpu_percent, None);
+
+    let second = sampler.sample(stats(10, 1_500), None, start + Duration::from_secs(1));
+    let pct = second.cpu_percent.expect("cpu percent");
+    assert!((pct - 50.0).abs() < 0.01, "{pct}");
+    assert!(second.describe().contains("cpu=50.0%"));
+}
+
+#[test]
+fn rss_above_the_limit_is_reported() {
+    let start = Instant::now();
+    let limits = ResourceLimits {
+        max_rss_mb: Some(100),
+        ..ResourceLimits::default()
+    };
+    let mut sampler = ResourceSampler::new(&limits, start).expect("sampling enabled");
+
+    assert_eq!(
+        sampler.sample(stats(100, 0), None, start).exceeded(&limits),
+        None
+    );
+    assert!(
+        sampler
+            .sample(stats(101, 0), None, start)
+            .exceeded(&limits)
+            .is_some()
+    );
+
+    let disabled = ResourceLimits {
+        sample_interval_ms: 0,
+        ..limits
+    };
+    assert!(ResourceSampler::new(&disabled, start).is_none());
+}
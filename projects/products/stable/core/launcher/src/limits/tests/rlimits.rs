@@ -0,0 +1,37 @@
+// projects/products/stable/core/launcher/src/limits/tests/rlimits.rs
+use std::process::Command;
+
+use crate::limits::{ResourceLimits, apply_rlimits};
+
+#[test]
+fn open_files_limit_applies_to_the_child() {
+    let limits = ResourceLimits {
+        max_open_files: Some(64),
+        ..ResourceLimits::default()
+    };
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", "ulimit -n"]);
+    apply_rlimits(&mut cmd, "svc", &limits, false);
+
+    let output = cmd.output().expect("sh runs");
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "64");
+}
+
+#[test]
+fn memory_rlimit_is_left_to_the_cgroup() {
+    let limits = ResourceLimits {
+        max_memory_mb: Some(64),
+        ..ResourceLimits::default()
+    };
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", "ulimit -v"]);
+    apply_rlimits(&mut cmd, "svc", &limits, true);
+    let output = cmd.output().expect("sh runs");
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "unlimited");
+
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", "ulimit -v"]);
+    apply_rlimits(&mut cmd, "svc", &limits, false);
+    let output = cmd.output().expect("sh runs");
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "65536");
+}
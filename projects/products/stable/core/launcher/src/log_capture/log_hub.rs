@@ -0,0 +1,157 @@
+// projects/products/stable/core/launcher/src/log_capture/log_hub.rs
+use std::{
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+    sync::{
+        Arc, Mutex, OnceLock,
+        mpsc::{self, Receiver, SyncSender, TrySendError},
+    },
+};
+
+use common_time::current_timestamp_ms;
+
+use crate::{logging::log_message, logs::Logs, supervisor::LogStream};
+
+use super::{
+    log_query::{DEFAULT_QUERY_LIMIT, LogQuery},
+    log_record::LogRecord,
+    rotating_file::RotationPolicy,
+    service_file::ServiceFile,
+};
+
+static HUB: OnceLock<Arc<LogHub>> = OnceLock::new();
+
+/// Records a follower may have pending; one lagging further is dropped
+pub(super) const FOLLOWER_BUFFER: usize = 1024;
+
+/// Makes `hub` receive the output of every service started afterwards
+pub(crate) fn install(hub: Arc<LogHub>) {
+    let _ = HUB.set(hub);
+}
+
+/// Records one output line of `service`; no-op until a hub is installed
+pub(crate) fn capture(service: &str, stream: LogStream, line: &str) {
+    if let Some(hub) = HUB.get() {
+        hub.record(LogRecord::parse(
+            service,
+            stream,
+            line,
+            current_timestamp_ms(),
+        ));
+    }
+}
+
+/// Per-service ring buffers, rotated JSONL files and live followers.
+///
+/// Files are written outside the hub lock, each under its own lock, so a slow
+/// disk only holds back the service being written.
+#[derive(Debug)]
+pub(crate) struct LogHub {
+    /// `None` keeps records in memory only
+    dir: Option<PathBuf>,
+    policy: RotationPolicy,
+    ring_capacity: usize,
+    state: Mutex<HubState>,
+    /// Opened on the first line of each service
+    files: Mutex<HashMap<String, Arc<Mutex<Option<ServiceFile>>>>>,
+}
+
+#[derive(Debug, Default)]
+struct HubState {
+    next_seq: u64,
+    rings: HashMap<String, VecDeque<LogRecord>>,
+    followers: Vec<SyncSender<LogRecord>>,
+}
+
+impl LogHub {
+    pub(crate) fn new(dir: Option<PathBuf>, settings: &Logs) -> Self {
+        Self {
+            dir,
+            policy: RotationPolicy {
+                max_bytes: settings.max_file_bytes.max(1),
+                max_age_ms: settings.max_file_age_ms.max(1),
+                retained: settings.retained_files,
+            },
+            ring_capacity: settings.ring_capacity.max(1),
+            state: Mutex::new(HubState::default()),
+            files: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn record(&self, mut record: LogRecord) {
+        {
+            let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+            state.next_seq += 1;
+            record.seq = state.next_seq;
+
+            state
+                .followers
+                .retain(|f| match f.try_send(record.clone()) {
+                    Ok(()) => true,
+                    Err(TrySendError::Full(_)) => {
+                        log_message(
+                            &format!(
+                                "dropping a log follower more than {FOLLOWER_BUFFER} records behind"
+                            ),
+                            true,
+                            "launcher",
+                        );
+                        false
+                    }
+                    Err(TrySendError::Disconnected(_)) => false,
+                });
+
+            let ring = state.rings.entry(record.service.clone()).or_default();
+            if ring.len() >= self.ring_capacity {
+                ring.pop_front();
+            }
+            ring.push_back(record.clone());
+        }
+
+        if let Some(dir) = &self.dir {
+            let line = common_json::to_string(&record).unwrap_or_default();
+            let slot = self
+                .files
+                .lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .entry(record.service.clone())
+                .or_default()
+                .clone();
+            slot.lock()
+                .unwrap_or_else(|p| p.into_inner())
+                .get_or_insert_with(|| {
+                    ServiceFile::open(dir, &record.service, self.policy, record.ts_ms)
+                })
+                .write_line(&record.service, &line, record.ts_ms);
+        }
+    }
+
+    /// Most recent matching records in capture order
+    pub(crate) fn query(&self, query: &LogQuery) -> Vec<LogRecord> {
+        let state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        Self::history(&state, query)
+    }
+
+    /// Matching history plus a receiver of every record captured afterwards.
+    /// The receiver is disconnected if it falls `FOLLOWER_BUFFER` records behind.
+    pub(crate) fn follow(&self, query: &LogQuery) -> (Vec<LogRecord>, Receiver<LogRecord>) {
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        let history = Self::history(&state, query);
+        let (tx, rx) = mpsc::sync_channel(FOLLOWER_BUFFER);
+        state.followers.push(tx);
+        (history, rx)
+    }
+
+    fn history(state: &HubState, query: &LogQuery) -> Vec<LogRecord> {
+        let mut records: Vec<LogRecord> = state
+            .rings
+            .iter()
+            .filter(|(service, _)| query.service.as_deref().is_none_or(|s| s == *service))
+            .flat_map(|(_, ring)| ring.iter().filter(|r| query.matches(r)).cloned())
+            .collect();
+        records.sort_by_key(|r| r.seq);
+        let limit = query.limit.unwrap_or(DEFAULT_QUERY_LIMIT);
+        let skip = records.len().saturating_sub(limit);
+        records.split_off(skip)
+    }
+}
@@ -0,0 +1,59 @@
+// projects/products/stable/core/launcher/src/log_capture/log_query.rs
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use super::{log_level::LogLevel, log_record::LogRecord};
+
+/// Records returned when a query sets no `limit`
+pub(crate) const DEFAULT_QUERY_LIMIT: usize = 200;
+
+/// Filters of `GET /logs`
+#[derive(Debug, Default)]
+pub(crate) struct LogQuery {
+    pub(crate) service: Option<String>,
+    /// Minimum level
+    pub(crate) level: Option<LogLevel>,
+    /// Inclusive bounds on `ts_ms`
+    pub(crate) since_ms: Option<u64>,
+    pub(crate) until_ms: Option<u64>,
+    /// Matched against the message and fields
+    pub(crate) pattern: Option<Regex>,
+    /// Most recent records returned from history
+    pub(crate) limit: Option<usize>,
+}
+
+impl LogQuery {
+    /// Parses `service`, `level`, `since`, `until` (ms since the epoch),
+    /// `grep` and `limit` parameters
+    pub(crate) fn from_params(params: &HashMap<String, String>) -> Result<Self, String> {
+        let number = |key: &str| -> Result<Option<u64>, String> {
+            params
+                .get(key)
+                .map(|v| v.parse().map_err(|_| format!("invalid `{key}`: {v}")))
+                .transpose()
+        };
+        Ok(Self {
+            service: params.get("service").filter(|s| !s.is_empty()).cloned(),
+            level: params.get("level").map(|l| l.parse()).transpose()?,
+            since_ms: number("since")?,
+            until_ms: number("until")?,
+            pattern: params
+                .get("grep")
+                .map(|p| Regex::new(p).map_err(|e| format!("invalid `grep`: {e}")))
+                .transpose()?,
+            limit: number("limit")?.map(|l| l as usize),
+        })
+    }
+
+    pub(crate) fn matches(&self, record: &LogRecord) -> bool {
+        self.service.as_deref().is_none_or(|s| s == record.service)
+            && self.level.is_none_or(|l| record.level >= l)
+            && self.since_ms.is_none_or(|t| record.ts_ms >= t)
+            && self.until_ms.is_none_or(|t| record.ts_ms <= t)
+            && self
+                .pattern
+                .as_ref()
+                .is_none_or(|p| p.is_match(&record.text()))
+    }
+}
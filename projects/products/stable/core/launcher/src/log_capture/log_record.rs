@@ -0,0 +1,103 @@
+// projects/products/stable/core/launcher/src/log_capture/log_record.rs
+use std::collections::BTreeMap;
+
+use common_json::{Json, JsonAccess};
+use serde::{Deserialize, Serialize};
+
+use crate::supervisor::LogStream;
+
+use super::log_level::LogLevel;
+
+/// One captured output line of a service
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct LogRecord {
+    /// Capture order across all services
+    pub(crate) seq: u64,
+    /// Capture time, ms since the epoch
+    pub(crate) ts_ms: u64,
+    pub(crate) service: String,
+    pub(crate) stream: String,
+    pub(crate) level: LogLevel,
+    pub(crate) message: String,
+    #[serde(default)]
+    pub(crate) target: Option<String>,
+    /// Structured fields of tracing-JSON lines, other than `message`
+    #[serde(default)]
+    pub(crate) fields: BTreeMap<String, String>,
+}
+
+impl LogRecord {
+    /// Builds a record from a raw line: tracing-JSON lines (`level`,
+    /// `fields.message`, `target`) are parsed, other lines keep their text
+    /// and get the first level word found near their start (info otherwise)
+    pub(crate) fn parse(service: &str, stream: LogStream, line: &str, ts_ms: u64) -> Self {
+        let mut record = Self {
+            seq: 0,
+            ts_ms,
+            service: service.to_string(),
+            stream: stream.as_str().to_string(),
+            level: LogLevel::Info,
+            message: line.to_string(),
+            target: None,
+            fields: BTreeMap::new(),
+        };
+        if line.trim_start().starts_with('{')
+            && let Ok(json) = common_json::parse(line)
+            && record.apply_tracing_json(&json)
+        {
+            return record;
+        }
+        if let Some(level) = line
+            .split_whitespace()
+            .take(4)
+            .find_map(|word| word.trim_matches(|c: char| !c.is_alphabetic()).parse().ok())
+        {
+            record.level = level;
+        }
+        record
+    }
+
+    fn apply_tracing_json(&mut self, json: &Json) -> bool {
+        let Some(level) = json
+            .get_field("level")
+            .ok()
+            .and_then(|l| l.as_str())
+            .and_then(|l| l.parse().ok())
+        else {
+            return false;
+        };
+        self.level = level;
+        self.target = json
+            .get_field("target")
+            .ok()
+            .and_then(|t| t.as_str())
+            .map(str::to_string);
+
+        let fields = json.get_field("fields").ok().and_then(|f| f.as_object());
+        if let Some(fields) = fields {
+            for (key, value) in fields {
+                let text = match value.as_str() {
+                    Some(s) => s.to_string(),
+                    None => common_json::to_string(value).unwrap_or_default(),
+                };
+                if key == "message" {
+                    self.message = text;
+                } else {
+                    self.fields.insert(key.clone(), text);
+                }
+            }
+        } else if let Some(message) = json.get_field("message").ok().and_then(|m| m.as_str()) {
+            self.message = message.to_string();
+        }
+        true
+    }
+
+    /// Text matched by query patterns: message then `key=value` fields
+    pub(crate) fn text(&self) -> String {
+        let mut text = self.message.clone();
+        for (key, value) in &self.fields {
+            text.push_str(&format!(" {key}={value}"));
+        }
+        text
+    }
+}
@@ -0,0 +1,117 @@
+// projects/products/stable/core/launcher/src/log_capture/log_server.rs
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, mpsc::RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use common_json::pjson;
+
+use crate::logging::log_message;
+
+use super::{log_hub::LogHub, log_query::LogQuery};
+
+/// Idle followers get an empty line this often, so closed clients are noticed
+const FOLLOW_KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// Serves `GET /logs` on `addr` from a background thread
+pub(crate) fn spawn_log_server(addr: &str, hub: Arc<LogHub>) -> Result<()> {
+    let listener = TcpListener::bind(addr).with_context(|| format!("failed to bind {addr}"))?;
+    log_message(
+        &format!("📜 logs API on http://{addr}/logs"),
+        false,
+        "launcher",
+    );
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let hub = hub.clone();
+            thread::spawn(move || {
+                let _ = handle_connection(stream, &hub);
+            });
+        }
+    });
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream, hub: &LogHub) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Headers are not needed; read them so the client is not reset
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    let Ok(url) = url::Url::parse(&format!("http://localhost{target}")) else {
+        return respond_error(&mut stream, 400, "Bad Request", "invalid request target");
+    };
+    if method != "GET" || url.path() != "/logs" {
+        return respond_error(&mut stream, 404, "Not Found", "only GET /logs is served");
+    }
+    let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+    let query = match LogQuery::from_params(&params) {
+        Ok(query) => query,
+        Err(e) => return respond_error(&mut stream, 400, "Bad Request", &e),
+    };
+    let follow = params
+        .get("follow")
+        .is_some_and(|f| f == "1" || f == "true");
+
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nConnection: close\r\n\r\n"
+    )?;
+    if !follow {
+        for record in hub.query(&query) {
+            writeln!(
+                stream,
+                "{}",
+                common_json::to_string(&record).unwrap_or_default()
+            )?;
+        }
+        return Ok(());
+    }
+
+    let (history, live) = hub.follow(&query);
+    for record in history {
+        writeln!(
+            stream,
+            "{}",
+            common_json::to_string(&record).unwrap_or_default()
+        )?;
+    }
+    loop {
+        match live.recv_timeout(FOLLOW_KEEPALIVE) {
+            Ok(record) if query.matches(&record) => writeln!(
+                stream,
+                "{}",
+                common_json::to_string(&record).unwrap_or_default()
+            )?,
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => writeln!(stream)?,
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        stream.flush()?;
+    }
+}
+
+fn respond_error(
+    stream: &mut TcpStream,
+    status: u16,
+    reason: &str,
+    message: &str,
+) -> std::io::Result<()> {
+    let body = common_json::to_string(&pjson!({ "error": message })).unwrap_or_default();
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
@@ -0,0 +1,115 @@
+// projects/products/stable/core/launcher/src/log_capture/logs_command.rs
+use std::io::{self, BufRead, BufReader, Write};
+
+use anyhow::{Context, Result, bail};
+use common_time::{current_timestamp_ms, timestamp_utils::format_timestamp};
+
+use crate::ci::LogsArgs;
+
+use super::log_record::LogRecord;
+
+/// `launcher logs`: queries the logs API of a running launcher and prints
+/// the records, following new ones with `--follow`
+pub(crate) fn run_logs_command(args: &LogsArgs, default_addr: &str) -> Result<()> {
+    let addr = args.addr.as_deref().unwrap_or(default_addr);
+    if addr.is_empty() {
+        bail!("the logs API is disabled: set logs.http_addr in the config or pass --addr");
+    }
+    let mut url = url::Url::parse(&format!("http://{addr}/logs"))
+        .with_context(|| format!("invalid logs API address `{addr}`"))?;
+    {
+        let now = current_timestamp_ms();
+        let mut query = url.query_pairs_mut();
+        if let Some(service) = &args.service {
+            query.append_pair("service", service);
+        }
+        if let Some(level) = &args.level {
+            query.append_pair("level", level);
+        }
+        if let Some(since) = &args.since {
+            query.append_pair("since", &parse_time_bound(since, now)?.to_string());
+        }
+        if let Some(until) = &args.until {
+            query.append_pair("until", &parse_time_bound(until, now)?.to_string());
+        }
+        if let Some(pattern) = &args.grep {
+            query.append_pair("grep", pattern);
+        }
+        if let Some(limit) = args.limit {
+            query.append_pair("limit", &limit.to_string());
+        }
+        if args.follow {
+            query.append_pair("follow", "1");
+        }
+    }
+
+    let agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .build()
+        .new_agent();
+    let mut resp = agent
+        .get(url.as_str())
+        .call()
+        .with_context(|| format!("cannot reach the launcher logs API at {addr}"))?;
+    if !resp.status().is_success() {
+        let body = resp.body_mut().read_to_string().unwrap_or_default();
+        bail!("logs API answered {}: {}", resp.status(), body.trim());
+    }
+
+    let reader = BufReader::new(resp.body_mut().as_reader());
+    let mut out = io::stdout().lock();
+    for line in reader.lines() {
+        let line = line.context("logs stream interrupted")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let text = match common_json::from_str::<LogRecord>(&line) {
+            Ok(record) if !args.json => format_record(&record),
+            _ => line,
+        };
+        // Stop quietly when the reader went away (`launcher logs | head`)
+        if writeln!(out, "{text}").and_then(|_| out.flush()).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// `2026-10-19 08:15:02.120 WARN  engine: message key=value`
+pub(crate) fn format_record(record: &LogRecord) -> String {
+    let mut out = format!(
+        "{}.{:03} {:<5} {}: {}",
+        format_timestamp(record.ts_ms / 1000),
+        record.ts_ms % 1000,
+        record.level.as_str().to_ascii_uppercase(),
+        record.service,
+        record.message
+    );
+    for (key, value) in &record.fields {
+        out.push_str(&format!(" {key}={value}"));
+    }
+    out
+}
+
+/// Milliseconds since the epoch, or an age relative to `now_ms`
+/// (`30s`, `10m`, `2h`, `1d`)
+pub(crate) fn parse_time_bound(value: &str, now_ms: u64) -> Result<u64> {
+    let value = value.trim();
+    if let Ok(ms) = value.parse::<u64>() {
+        return Ok(ms);
+    }
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .filter(|i| *i > 0)
+        .with_context(|| format!("invalid time `{value}`"))?;
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount.parse()?;
+    let unit_ms = match unit {
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        "d" => 86_400_000,
+        _ => bail!("invalid time unit `{unit}` (expected s, m, h or d)"),
+    };
+    Ok(now_ms.saturating_sub(amount.saturating_mul(unit_ms)))
+}
@@ -0,0 +1,16 @@
+// projects/products/stable/core/launcher/src/log_capture/mod.rs
+mod log_hub;
+mod log_level;
+mod log_query;
+mod log_record;
+mod log_server;
+mod logs_command;
+mod rotating_file;
+mod service_file;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use log_hub::{LogHub, capture, install};
+pub(crate) use log_server::spawn_log_server;
+pub(crate) use logs_command::run_logs_command;
@@ -0,0 +1,127 @@
+// projects/products/stable/core/launcher/src/log_capture/rotating_file.rs
+use std::{
+    fs::{self, File, Metadata, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+/// Rotation and retention of the log files of one service
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RotationPolicy {
+    pub(crate) max_bytes: u64,
+    pub(crate) max_age_ms: u64,
+    /// Rotated files kept; older ones are deleted
+    pub(crate) retained: usize,
+}
+
+/// `<dir>/<service>.log`, rotated to `<service>.<ts_ms>.log`
+#[derive(Debug)]
+pub(crate) struct RotatingFile {
+    dir: PathBuf,
+    service: String,
+    policy: RotationPolicy,
+    file: File,
+    size: u64,
+    /// Creation time of the current file, the origin of `max_age_ms`
+    created_ms: u64,
+}
+
+impl RotatingFile {
+    pub(crate) fn open(
+        dir: &Path,
+        service: &str,
+        policy: RotationPolicy,
+        now_ms: u64,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{service}.log"));
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let metadata = file.metadata()?;
+        let size = metadata.len();
+        // A file reopened after a restart keeps its age
+        let created_ms = if size > 0 {
+            created_ms(&metadata).map_or(now_ms, |created| created.min(now_ms))
+        } else {
+            now_ms
+        };
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            service: service.to_string(),
+            policy,
+            file,
+            size,
+            created_ms,
+        })
+    }
+
+    /// Appends one line, rotating first when the file is too big or too old
+    pub(crate) fn write_line(&mut self, line: &str, now_ms: u64) -> io::Result<()> {
+        let too_big = self.size > 0 && self.size + line.len() as u64 + 1 > self.policy.max_bytes;
+        let too_old = now_ms.saturating_sub(self.created_ms) >= self.policy.max_age_ms;
+        if too_big || (too_old && self.size > 0) {
+            self.rotate(now_ms)?;
+        }
+        writeln!(self.file, "{line}")?;
+        self.size += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    fn rotate(&mut self, now_ms: u64) -> io::Result<()> {
+        let current = self.dir.join(format!("{}.log", self.service));
+        // Two rotations in the same millisecond must not overwrite each other
+        let mut ts = now_ms;
+        let mut rotated = self.rotated_path(ts);
+        while rotated.exists() {
+            ts += 1;
+            rotated = self.rotated_path(ts);
+        }
+        fs::rename(&current, &rotated)?;
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&current)?;
+        self.size = 0;
+        self.created_ms = now_ms;
+        self.prune()
+    }
+
+    fn rotated_path(&self, ts_ms: u64) -> PathBuf {
+        self.dir.join(format!("{}.{ts_ms:013}.log", self.service))
+    }
+
+    /// Rotated files of this service, oldest first
+    pub(crate) fn rotated_files(&self) -> io::Result<Vec<PathBuf>> {
+        let prefix = format!("{}.", self.service);
+        let mut files: Vec<PathBuf> = fs::read_dir(&self.dir)?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .and_then(|n| n.strip_prefix(&prefix))
+                    .and_then(|n| n.strip_suffix(".log"))
+                    .is_some_and(|ts| !ts.is_empty() && ts.bytes().all(|b| b.is_ascii_digit()))
+            })
+            .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    fn prune(&self) -> io::Result<()> {
+        let files = self.rotated_files()?;
+        let excess = files.len().saturating_sub(self.policy.retained);
+        for old in &files[..excess] {
+            fs::remove_file(old)?;
+        }
+        Ok(())
+    }
+}
+
+/// Creation time of a file in ms since the epoch, or its last modification
+/// where the filesystem does not record creation
+fn created_ms(metadata: &Metadata) -> Option<u64> {
+    let time = metadata.created().or_else(|_| metadata.modified()).ok()?;
+    let since_epoch = time.duration_since(UNIX_EPOCH).ok()?;
+    u64::try_from(since_epoch.as_millis()).ok()
+}
@@ -0,0 +1,67 @@
+// projects/products/stable/core/launcher/src/log_capture/service_file.rs
+use std::path::Path;
+
+use crate::logging::log_message;
+
+use super::rotating_file::{RotatingFile, RotationPolicy};
+
+/// Log file of one service. Failures are reported once instead of on every
+/// captured line.
+#[derive(Debug)]
+pub(crate) enum ServiceFile {
+    Open {
+        file: RotatingFile,
+        /// The last write failed and was reported
+        failing: bool,
+    },
+    /// The file could not be opened; the service is kept in memory only
+    Unavailable,
+}
+
+impl ServiceFile {
+    pub(crate) fn open(dir: &Path, service: &str, policy: RotationPolicy, now_ms: u64) -> Self {
+        match RotatingFile::open(dir, service, policy, now_ms) {
+            Ok(file) => Self::Open {
+                file,
+                failing: false,
+            },
+            Err(e) => {
+                log_message(
+                    &format!(
+                        "cannot open log file in {}, keeping logs in memory only: {}",
+                        dir.display(),
+                        e
+                    ),
+                    true,
+                    service,
+                );
+                Self::Unavailable
+            }
+        }
+    }
+
+    pub(crate) fn write_line(&mut self, service: &str, line: &str, now_ms: u64) {
+        let Self::Open { file, failing } = self else {
+            return;
+        };
+        match file.write_line(line, now_ms) {
+            Ok(()) if *failing => {
+                *failing = false;
+                log_message("log file writable again", false, service);
+            }
+            Ok(()) => {}
+            Err(e) if !*failing => {
+                *failing = true;
+                log_message(
+                    &format!(
+                        "cannot write log file, dropping lines until it recovers: {}",
+                        e
+                    ),
+                    true,
+                    service,
+                );
+            }
+            Err(_) => {}
+        }
+    }
+}
@@ -0,0 +1,122 @@
+// projects/products/stable/core/launcher/src/log_capture/tests/log_hub.rs
+use std::{collections::HashMap, sync::mpsc::RecvTimeoutError, time::Duration};
+
+use crate::{
+    log_capture::{LogHub, log_hub::FOLLOWER_BUFFER, log_query::LogQuery, log_record::LogRecord},
+    logs::Logs,
+    supervisor::LogStream,
+};
+
+fn hub(ring_capacity: usize) -> LogHub {
+    LogHub::new(
+        None,
+        &Logs {
+            ring_capacity,
+            ..Logs::default()
+        },
+    )
+}
+
+fn record(hub: &LogHub, service: &str, line: &str, ts_ms: u64) {
+    hub.record(LogRecord::parse(service, LogStream::Stdout, line, ts_ms));
+}
+
+fn query(params: &[(&str, &str)]) -> LogQuery {
+    let params: HashMap<String, String> = params
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    LogQuery::from_params(&params).expect("valid query")
+}
+
+fn messages(records: &[LogRecord]) -> Vec<&str> {
+    records.iter().map(|r| r.message.as_str()).collect()
+}
+
+#[test]
+fn rings_keep_the_latest_records_per_service() {
+    let hub = hub(2);
+    record(&hub, "engine", "e1", 1);
+    record(&hub, "watcher", "w1", 2);
+    record(&hub, "engine", "e2", 3);
+    record(&hub, "engine", "e3", 4);
+
+    assert_eq!(messages(&hub.query(&query(&[]))), ["w1", "e2", "e3"]);
+    assert_eq!(
+        messages(&hub.query(&query(&[("service", "engine"), ("limit", "1")]))),
+        ["e3"]
+    );
+}
+
+#[test]
+fn queries_filter_by_level_time_and_pattern() {
+    let hub = hub(100);
+    record(&hub, "engine", "INFO started", 10);
+    record(&hub, "engine", "WARN slow backend", 20);
+    record(&hub, "engine", "ERROR backend lost", 30);
+
+    assert_eq!(
+        messages(&hub.query(&query(&[("level", "warn")]))),
+        ["WARN slow backend", "ERROR backend lost"]
+    );
+    assert_eq!(
+        messages(&hub.query(&query(&[("since", "15"), ("until", "25")]))),
+        ["WARN slow backend"]
+    );
+    assert_eq!(
+        messages(&hub.query(&query(&[("grep", "^(INFO|ERROR)")]))),
+        ["INFO started", "ERROR backend lost"]
+    );
+    assert!(LogQuery::from_params(&HashMap::from([("grep".into(), "(".into())])).is_err());
+}
+
+#[test]
+fn followers_get_history_then_new_records() {
+    let hub = hub(100);
+    record(&hub, "engine", "before", 1);
+
+    let (history, live) = hub.follow(&query(&[]));
+    assert_eq!(messages(&history), ["before"]);
+
+    record(&hub, "engine", "after", 2);
+    let next = live
+        .recv_timeout(Duration::from_secs(1))
+        .expect("live record");
+    assert_eq!(next.message, "after");
+}
+
+#[test]
+fn unwritable_log_dir_keeps_records_in_memory() {
+    // A regular file where the log directory should be
+    let blocker =
+        std::env::temp_dir().join(format!("launcher_logs_blocker_{}", std::process::id()));
+    std::fs::write(&blocker, "").expect("create blocker");
+    let hub = LogHub::new(Some(blocker.join("logs")), &Logs::default());
+
+    record(&hub, "engine", "first", 1);
+    record(&hub, "engine", "second", 2);
+
+    assert_eq!(messages(&hub.query(&query(&[]))), ["first", "second"]);
+    let _ = std::fs::remove_file(&blocker);
+}
+
+#[test]
+fn lagging_followers_are_dropped() {
+    let hub = hub(10);
+    let (_, live) = hub.follow(&query(&[]));
+    for i in 0..FOLLOWER_BUFFER + 5 {
+        record(&hub, "engine", "line", i as u64);
+    }
+
+    let mut received = 0;
+    loop {
+        match live.recv_timeout(Duration::from_secs(1)) {
+            Ok(_) => received += 1,
+            Err(RecvTimeoutError::Disconnected) => break,
+            Err(RecvTimeoutError::Timeout) => panic!("lagging follower was kept"),
+        }
+    }
+    assert_eq!(received, FOLLOWER_BUFFER);
+    // Capture goes on for everyone else
+    assert_eq!(hub.query(&query(&[("limit", "1")])).len(), 1);
+}
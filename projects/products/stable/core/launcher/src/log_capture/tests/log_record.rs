@@ -0,0 +1,44 @@
+// projects/products/stable/core/launcher/src/log_capture/tests/log_record.rs
+use crate::{
+    log_capture::{log_level::LogLevel, log_record::LogRecord},
+    supervisor::LogStream,
+};
+
+#[test]
+fn tracing_json_lines_are_structured() {
+    let line = r#"{"timestamp":"2026-10-19T08:15:02.120Z","level":"WARN","fields":{"message":"backend slow","product":"accounts","latency_ms":812},"target":"engine::ws"}"#;
+    let record = LogRecord::parse("engine", LogStream::Stdout, line, 42);
+
+    assert_eq!(record.level, LogLevel::Warn);
+    assert_eq!(record.message, "backend slow");
+    assert_eq!(record.target.as_deref(), Some("engine::ws"));
+    assert_eq!(
+        record.fields.get("product").map(String::as_str),
+        Some("accounts")
+    );
+    assert_eq!(
+        record.fields.get("latency_ms").map(String::as_str),
+        Some("812")
+    );
+    assert_eq!(record.ts_ms, 42);
+    assert_eq!(record.stream, "stdout");
+}
+
+#[test]
+fn plain_lines_keep_their_text() {
+    let record = LogRecord::parse(
+        "watcher",
+        LogStream::Stderr,
+        "2026-10-19T08:15:02Z ERROR watcher: disk full",
+        1,
+    );
+    assert_eq!(record.level, LogLevel::Error);
+    assert_eq!(
+        record.message,
+        "2026-10-19T08:15:02Z ERROR watcher: disk full"
+    );
+
+    let record = LogRecord::parse("watcher", LogStream::Stdout, "{not json", 1);
+    assert_eq!(record.level, LogLevel::Info);
+    assert_eq!(record.message, "{not json");
+}
@@ -0,0 +1,16 @@
+// projects/products/stable/core/launcher/src/log_capture/tests/logs_command.rs
+use crate::log_capture::logs_command::parse_time_bound;
+
+#[test]
+fn time_bounds_are_absolute_or_relative() {
+    let now = 10_000_000;
+    assert_eq!(
+        parse_time_bound("1760000000000", now).unwrap(),
+        1_760_000_000_000
+    );
+    assert_eq!(parse_time_bound("30s", now).unwrap(), now - 30_000);
+    assert_eq!(parse_time_bound("2h", now).unwrap(), now - 7_200_000);
+    assert_eq!(parse_time_bound("1d", now).unwrap(), 0);
+    assert!(parse_time_bound("5w", now).is_err());
+    assert!(parse_time_bound("m", now).is_err());
+}
@@ -0,0 +1,4 @@
+mod log_hub;
+mod log_record;
+mod logs_command;
+mod rotating_file;
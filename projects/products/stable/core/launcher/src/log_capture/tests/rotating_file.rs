@@ -0,0 +1,99 @@
+// projects/products/stable/core/launcher/src/log_capture/tests/rotating_file.rs
+use std::{
+    fs,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::log_capture::rotating_file::{RotatingFile, RotationPolicy};
+
+fn temp_dir(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!(
+        "launcher_logs_{}_{}_{}",
+        std::process::id(),
+        name,
+        n
+    ))
+}
+
+#[test]
+fn files_rotate_on_size_and_keep_the_newest() {
+    let dir = temp_dir("size");
+    let policy = RotationPolicy {
+        max_bytes: 10,
+        max_age_ms: u64::MAX,
+        retained: 2,
+    };
+    let mut file = RotatingFile::open(&dir, "engine", policy, 0).expect("open");
+    for (i, line) in ["aaaaaa", "bbbbbb", "cccccc", "dddddd"].iter().enumerate() {
+        file.write_line(line, i as u64).expect("write");
+    }
+
+    let rotated = file.rotated_files().expect("list");
+    assert_eq!(rotated.len(), 2);
+    assert_eq!(fs::read_to_string(&rotated[0]).expect("read"), "bbbbbb\n");
+    assert_eq!(fs::read_to_string(&rotated[1]).expect("read"), "cccccc\n");
+    assert_eq!(
+        fs::read_to_string(dir.join("engine.log")).expect("read"),
+        "dddddd\n"
+    );
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn files_rotate_on_age() {
+    let dir = temp_dir("age");
+    let policy = RotationPolicy {
+        max_bytes: u64::MAX,
+        max_age_ms: 1_000,
+        retained: 5,
+    };
+    let mut file = RotatingFile::open(&dir, "engine", policy, 0).expect("open");
+    file.write_line("old", 10).expect("write");
+    file.write_line("still old", 999).expect("write");
+    file.write_line("new", 1_000).expect("write");
+
+    let rotated = file.rotated_files().expect("list");
+    assert_eq!(rotated.len(), 1);
+    assert_eq!(
+        fs::read_to_string(&rotated[0]).expect("read"),
+        "old\nstill old\n"
+    );
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn reopened_files_keep_their_age() {
+    let dir = temp_dir("reopen");
+    let day_ms = 24 * 60 * 60 * 1000;
+    let policy = RotationPolicy {
+        max_bytes: u64::MAX,
+        max_age_ms: day_ms,
+        retained: 5,
+    };
+    let now = common_time::current_timestamp_ms();
+    let mut file = RotatingFile::open(&dir, "engine", policy, now).expect("open");
+    file.write_line("before restart", now).expect("write");
+    drop(file);
+
+    // Same day: the existing file is appended to
+    let mut file = RotatingFile::open(&dir, "engine", policy, now + 1_000).expect("reopen");
+    file.write_line("after restart", now + 1_000)
+        .expect("write");
+    assert!(file.rotated_files().expect("list").is_empty());
+    drop(file);
+
+    // Two days later: the file is too old, even though it was just reopened
+    let later = now + 2 * day_ms;
+    let mut file = RotatingFile::open(&dir, "engine", policy, later).expect("reopen");
+    file.write_line("next day", later).expect("write");
+    let rotated = file.rotated_files().expect("list");
+    assert_eq!(rotated.len(), 1);
+    assert_eq!(
+        fs::read_to_string(&rotated[0]).expect("read"),
+        "before restart\nafter restart\n"
+    );
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,63 @@
+// projects/products/stable/core/launcher/src/logs.rs
+use serde::Deserialize;
+
+/// Structured capture of service output (`[logs]`)
+#[derive(Debug, Deserialize, Clone)]
+pub(crate) struct Logs {
+    #[serde(default = "default_enabled")]
+    pub(crate) enabled: bool,
+    /// JSONL files, one per service, relative to the workspace root
+    #[serde(default = "default_dir")]
+    pub(crate) dir: String,
+    /// Rotate a service file above this size
+    #[serde(default = "default_max_file_bytes")]
+    pub(crate) max_file_bytes: u64,
+    /// Rotate a service file once it was created this long ago
+    #[serde(default = "default_max_file_age")]
+    pub(crate) max_file_age_ms: u64,
+    /// Rotated files kept per service
+    #[serde(default = "default_retained_files")]
+    pub(crate) retained_files: usize,
+    /// Records kept in memory per service for queries
+    #[serde(default = "default_ring_capacity")]
+    pub(crate) ring_capacity: usize,
+    /// Query API (unauthenticated); empty, the default, disables it
+    #[serde(default = "default_http_addr")]
+    pub(crate) http_addr: String,
+}
+
+impl Default for Logs {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            dir: default_dir(),
+            max_file_bytes: default_max_file_bytes(),
+            max_file_age_ms: default_max_file_age(),
+            retained_files: default_retained_files(),
+            ring_capacity: default_ring_capacity(),
+            http_addr: default_http_addr(),
+        }
+    }
+}
+
+pub(crate) fn default_enabled() -> bool {
+    true
+}
+pub(crate) fn default_dir() -> String {
+    "target/launcher-logs".to_string()
+}
+pub(crate) fn default_max_file_bytes() -> u64 {
+    10 * 1024 * 1024
+}
+pub(crate) fn default_max_file_age() -> u64 {
+    24 * 60 * 60 * 1000
+}
+pub(crate) fn default_retained_files() -> usize {
+    5
+}
+pub(crate) fn default_ring_capacity() -> usize {
+    2_000
+}
+pub(crate) fn default_http_addr() -> String {
+    String::new()
+}
@@ -5,12 +5,18 @@ mod child_handle;
 mod ci;
 mod config;
 mod defaults;
+mod dev;
 mod entry;
 mod launcher;
+mod limits;
+mod log_capture;
 mod logging;
+mod logs;
 mod normalizer;
 mod parsing;
+mod probe;
 mod process;
+mod reload;
 mod restart_policy;
 mod service;
 mod shutdown;
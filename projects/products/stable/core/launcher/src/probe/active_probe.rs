@@ -0,0 +1,171 @@
+// projects/products/stable/core/launcher/src/probe/active_probe.rs
+use std::{
+    io::{Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    path::Path,
+    process::{Command, Stdio},
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+
+use super::{log_line_watch::LogLineWatch, probe_config::Probe, probe_kind::ProbeKind};
+
+/// A probe bound to one spawned process
+#[derive(Debug)]
+pub(crate) struct ActiveProbe {
+    pub(crate) probe: Probe,
+    /// Set for log-line probes; fed by the process output pipes
+    pub(crate) log_watch: Option<Arc<LogLineWatch>>,
+}
+
+impl ActiveProbe {
+    /// Validates `probe` (patterns, URLs) and prepares it for a new process
+    pub(crate) fn new(probe: &Probe) -> Result<Self> {
+        let log_watch = match &probe.kind {
+            ProbeKind::LogLine { pattern } => {
+                let regex = Regex::new(pattern)
+                    .with_context(|| format!("invalid log-line pattern `{pattern}`"))?;
+                Some(Arc::new(LogLineWatch::new(regex)))
+            }
+            ProbeKind::Command { command } if command.is_empty() => {
+                bail!("command probe without a command")
+            }
+            ProbeKind::WebSocket { url } => {
+                ws_target(url)?;
+                None
+            }
+            _ => None,
+        };
+        Ok(Self {
+            probe: probe.clone(),
+            log_watch,
+        })
+    }
+
+    /// Runs one check. A log-line check passes once a line matched; with
+    /// `seen` (matches consumed by previous checks) only new matches count.
+    pub(crate) fn check(&self, cwd: &Path, seen: Option<&mut u64>) -> Result<()> {
+        let timeout = Duration::from_millis(self.probe.timeout_ms.max(1));
+        match &self.probe.kind {
+            ProbeKind::Http { url } => check_http(url, timeout),
+            ProbeKind::Tcp { addr } => check_tcp(addr, timeout).map(|_| ()),
+            ProbeKind::LogLine { .. } => {
+                let matches = self.log_watch.as_ref().map_or(0, |w| w.matches());
+                match seen {
+                    Some(seen) if matches > *seen => *seen = matches,
+                    None if matches > 0 => {}
+                    _ => bail!("no matching log line"),
+                }
+                Ok(())
+            }
+            ProbeKind::Command { command } => check_command(command, cwd, timeout),
+            ProbeKind::WebSocket { url } => check_websocket(url, timeout),
+        }
+    }
+}
+
+fn check_http(url: &str, timeout: Duration) -> Result<()> {
+    let config = ureq::Agent::config_builder()
+        .timeout_per_call(Some(timeout))
+        .http_status_as_error(false)
+        .build();
+    let agent = config.new_agent();
+    let resp = agent.get(url).call()?;
+    if !resp.status().is_success() {
+        bail!("status {}", resp.status());
+    }
+    Ok(())
+}
+
+fn check_tcp(addr: &str, timeout: Duration) -> Result<TcpStream> {
+    let mut last_err = None;
+    for sock in addr.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&sock, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    match last_err {
+        Some(e) => Err(e.into()),
+        None => bail!("`{addr}` did not resolve"),
+    }
+}
+
+fn check_command(command: &[String], cwd: &Path, timeout: Duration) -> Result<()> {
+    let mut child = Command::new(&command[0])
+        .args(&command[1..])
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("failed to run `{}`", command[0]))?;
+
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            if !status.success() {
+                bail!("exited with code {}", status.code().unwrap_or(-1));
+            }
+            return Ok(());
+        }
+        if start.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            bail!("timed out after {:?}", timeout);
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+/// `host:port` and request target of a `ws://` URL
+fn ws_target(url: &str) -> Result<(String, String)> {
+    let parsed = url::Url::parse(url).with_context(|| format!("invalid URL `{url}`"))?;
+    if parsed.scheme() != "ws" {
+        bail!("websocket probe only supports ws:// URLs, got `{url}`");
+    }
+    let host = parsed
+        .host_str()
+        .with_context(|| format!("no host in `{url}`"))?;
+    let port = parsed.port_or_known_default().unwrap_or(80);
+    let mut target = parsed.path().to_string();
+    if let Some(query) = parsed.query() {
+        target.push('?');
+        target.push_str(query);
+    }
+    Ok((format!("{host}:{port}"), target))
+}
+
+fn check_websocket(url: &str, timeout: Duration) -> Result<()> {
+    let (authority, target) = ws_target(url)?;
+    let mut stream = check_tcp(&authority, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    write!(
+        stream,
+        "GET {target} HTTP/1.1\r\nHost: {authority}\r\nUpgrade: websocket\r\n\
+         Connection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\
+         Sec-WebSocket-Version: 13\r\n\r\n"
+    )?;
+
+    // Only the status line matters
+    let mut buf = [0u8; 64];
+    let mut len = 0;
+    while len < buf.len() && !buf[..len].contains(&b'\n') {
+        let n = stream.read(&mut buf[len..])?;
+        if n == 0 {
+            break;
+        }
+        len += n;
+    }
+    let head = String::from_utf8_lossy(&buf[..len]);
+    let status_line = head.lines().next().unwrap_or_default();
+    if status_line.split_whitespace().nth(1) != Some("101") {
+        bail!("handshake refused: `{status_line}`");
+    }
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// projects/products/stable/core/launcher/src/probe/log_line_watch.rs
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use regex::Regex;
+
+/// Counts the output lines of one process matching a log-line probe
+#[derive(Debug)]
+pub(crate) struct LogLineWatch {
+    regex: Regex,
+    matches: AtomicU64,
+}
+
+impl LogLineWatch {
+    pub(crate) fn new(regex: Regex) -> Self {
+        Self {
+            regex,
+            matches: AtomicU64::new(0),
+        }
+    }
+
+    /// Called by the log pipes for every line printed by the process
+    pub(crate) fn observe(&self, line: &str) {
+        if self.regex.is_match(line) {
+            self.matches.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    pub(crate) fn matches(&self) -> u64 {
+        self.matches.load(Ordering::SeqCst)
+    }
+}
@@ -0,0 +1,15 @@
+// projects/products/stable/core/launcher/src/probe/mod.rs
+mod active_probe;
+mod log_line_watch;
+mod probe_config;
+mod probe_kind;
+mod probe_monitor;
+mod service_probes;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use log_line_watch::LogLineWatch;
+pub(crate) use probe_config::Probe;
+pub(crate) use probe_monitor::{ProbeEvent, ProbeMonitor};
+pub(crate) use service_probes::ServiceProbes;
@@ -0,0 +1,48 @@
+// projects/products/stable/core/launcher/src/probe/probe_config.rs
+use serde::Deserialize;
+
+use super::probe_kind::ProbeKind;
+
+/// Readiness or liveness probe of a service
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub(crate) struct Probe {
+    #[serde(flatten)]
+    pub(crate) kind: ProbeKind,
+    /// Time between two checks
+    #[serde(default = "default_period")]
+    pub(crate) period_ms: u64,
+    /// Upper bound of a single check
+    #[serde(default = "default_timeout")]
+    pub(crate) timeout_ms: u64,
+    /// Consecutive failed checks before the probe counts as failed
+    #[serde(default = "default_failure_threshold")]
+    pub(crate) failure_threshold: u32,
+    /// Wait before the first check once the service is running
+    #[serde(default)]
+    pub(crate) initial_delay_ms: u64,
+}
+
+impl Probe {
+    /// Probe equivalent to the legacy `ready_http` setting
+    pub(crate) fn http(url: &str) -> Self {
+        Self {
+            kind: ProbeKind::Http {
+                url: url.to_string(),
+            },
+            period_ms: 200,
+            timeout_ms: 800,
+            failure_threshold: default_failure_threshold(),
+            initial_delay_ms: 0,
+        }
+    }
+}
+
+pub(crate) fn default_period() -> u64 {
+    1_000
+}
+pub(crate) fn default_timeout() -> u64 {
+    1_000
+}
+pub(crate) fn default_failure_threshold() -> u32 {
+    3
+}
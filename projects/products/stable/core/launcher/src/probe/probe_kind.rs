@@ -0,0 +1,32 @@
+// projects/products/stable/core/launcher/src/probe/probe_kind.rs
+use serde::Deserialize;
+
+/// What a probe checks, selected by its `type` key
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub(crate) enum ProbeKind {
+    /// GET `url` answers with a 2xx status
+    Http { url: String },
+    /// A TCP connection to `addr` (`host:port`) is accepted
+    Tcp { addr: String },
+    /// The service printed a line matching `pattern` on stdout or stderr
+    /// (since the previous check, for liveness probes)
+    LogLine { pattern: String },
+    /// `command` (program then arguments, run in the service cwd) exits 0
+    Command { command: Vec<String> },
+    /// `url` (`ws://`) accepts a WebSocket upgrade (`101 Switching Protocols`)
+    #[serde(rename = "websocket")]
+    WebSocket { url: String },
+}
+
+impl ProbeKind {
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            Self::Http { url } => format!("http {url}"),
+            Self::Tcp { addr } => format!("tcp {addr}"),
+            Self::LogLine { pattern } => format!("log-line /{pattern}/"),
+            Self::Command { command } => format!("command `{}`", command.join(" ")),
+            Self::WebSocket { url } => format!("websocket {url}"),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// projects/products/stable/core/launcher/src/probe/probe_monitor.rs
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use super::active_probe::ActiveProbe;
+
+/// Outcome of a periodic probe worth acting on
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ProbeEvent {
+    /// `failure_threshold` consecutive checks failed; `reason` is the last error
+    Failed { reason: String },
+    /// A check passed again after `Failed`
+    Recovered,
+}
+
+/// Runs a probe every `period_ms` against a running process and tracks
+/// consecutive failures
+#[derive(Debug)]
+pub(crate) struct ProbeMonitor {
+    next_due: Instant,
+    failures: u32,
+    failed: bool,
+    /// Log-line matches consumed so far; `None` when any match passes
+    seen: Option<u64>,
+}
+
+impl ProbeMonitor {
+    /// Liveness monitors (`consume_log_lines`) need a new matching line
+    /// for every check; readiness ones accept any line seen so far
+    pub(crate) fn new(probe: &ActiveProbe, now: Instant, consume_log_lines: bool) -> Self {
+        Self {
+            next_due: now + Duration::from_millis(probe.probe.initial_delay_ms),
+            failures: 0,
+            failed: false,
+            seen: consume_log_lines.then_some(0),
+        }
+    }
+
+    /// Runs the check when due. Reports `Failed` once per outage, when the
+    /// failure threshold is reached, and `Recovered` when it ends.
+    pub(crate) fn poll(
+        &mut self,
+        probe: &ActiveProbe,
+        cwd: &Path,
+        now: Instant,
+    ) -> Option<ProbeEvent> {
+        if now < self.next_due {
+            return None;
+        }
+        self.next_due = now + Duration::from_millis(probe.probe.period_ms.max(1));
+
+        match probe.check(cwd, self.seen.as_mut()) {
+            Ok(()) => {
+                self.failures = 0;
+                if std::mem::take(&mut self.failed) {
+                    return Some(ProbeEvent::Recovered);
+                }
+                None
+            }
+            Err(e) => {
+                self.failures += 1;
+                if !self.failed && self.failures >= probe.probe.failure_threshold.max(1) {
+                    self.failed = true;
+                    return Some(ProbeEvent::Failed {
+                        reason: format!("{}: {:#}", probe.probe.kind.describe(), e),
+                    });
+                }
+                None
+            }
+        }
+    }
+}
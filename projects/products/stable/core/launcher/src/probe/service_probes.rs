@@ -0,0 +1,52 @@
+// projects/products/stable/core/launcher/src/probe/service_probes.rs
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+
+use crate::service::Service;
+
+use super::{active_probe::ActiveProbe, log_line_watch::LogLineWatch, probe_config::Probe};
+
+/// Probes of one spawned service process
+#[derive(Debug, Default)]
+pub(crate) struct ServiceProbes {
+    /// `readiness`, or `ready_http` when only that is set
+    pub(crate) readiness: Option<ActiveProbe>,
+    pub(crate) liveness: Option<ActiveProbe>,
+    /// Whether readiness is still checked once the service is up; not for
+    /// `ready_http`, which only ever gated startup
+    pub(crate) monitor_readiness: bool,
+}
+
+impl ServiceProbes {
+    pub(crate) fn for_service(svc: &Service) -> Result<Self> {
+        let readiness = match (&svc.readiness, &svc.ready_http) {
+            (Some(probe), _) => Some(probe.clone()),
+            (None, Some(url)) => Some(Probe::http(url)),
+            (None, None) => None,
+        };
+        Ok(Self {
+            monitor_readiness: svc.readiness.is_some(),
+            readiness: readiness
+                .as_ref()
+                .map(ActiveProbe::new)
+                .transpose()
+                .with_context(|| format!("service `{}`: invalid readiness probe", svc.name))?,
+            liveness: svc
+                .liveness
+                .as_ref()
+                .map(ActiveProbe::new)
+                .transpose()
+                .with_context(|| format!("service `{}`: invalid liveness probe", svc.name))?,
+        })
+    }
+
+    /// Watches the output pipes must feed
+    pub(crate) fn log_watches(&self) -> Vec<Arc<LogLineWatch>> {
+        [&self.readiness, &self.liveness]
+            .into_iter()
+            .flatten()
+            .filter_map(|p| p.log_watch.clone())
+            .collect()
+    }
+}
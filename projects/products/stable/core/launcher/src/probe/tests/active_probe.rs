@@ -0,0 +1,88 @@
+// projects/products/stable/core/launcher/src/probe/tests/active_probe.rs
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+    path::Path,
+    thread,
+};
+
+use crate::probe::{Probe, active_probe::ActiveProbe, probe_kind::ProbeKind};
+
+fn probe(kind: ProbeKind) -> ActiveProbe {
+    ActiveProbe::new(&Probe {
+        kind,
+        period_ms: 10,
+        timeout_ms: 500,
+        failure_threshold: 2,
+        initial_delay_ms: 0,
+    })
+    .expect("valid probe")
+}
+
+fn check(probe: &ActiveProbe) -> bool {
+    probe.check(Path::new("."), None).is_ok()
+}
+
+/// Answers the first connection with `response`, returns the address
+fn serve_once(response: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let addr = listener.local_addr().expect("addr").to_string();
+    thread::spawn(move || {
+        if let Ok((mut stream, _)) = listener.accept() {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    addr
+}
+
+#[test]
+fn tcp_probe_needs_a_listener() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let addr = listener.local_addr().expect("addr").to_string();
+    assert!(check(&probe(ProbeKind::Tcp { addr: addr.clone() })));
+
+    drop(listener);
+    assert!(!check(&probe(ProbeKind::Tcp { addr })));
+}
+
+#[test]
+fn command_probe_checks_the_exit_code() {
+    let command = |args: &[&str]| ProbeKind::Command {
+        command: args.iter().map(|a| a.to_string()).collect(),
+    };
+    assert!(check(&probe(command(&["true"]))));
+    assert!(!check(&probe(command(&["false"]))));
+    assert!(!check(&probe(command(&["sleep", "5"]))));
+}
+
+#[test]
+fn websocket_probe_expects_switching_protocols() {
+    let addr = serve_once("HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\n\r\n");
+    let url = format!("ws://{addr}/ws?token=x");
+    assert!(check(&probe(ProbeKind::WebSocket { url })));
+
+    let addr = serve_once("HTTP/1.1 400 Bad Request\r\n\r\n");
+    let url = format!("ws://{addr}/ws");
+    assert!(!check(&probe(ProbeKind::WebSocket { url })));
+}
+
+#[test]
+fn log_line_probe_counts_matching_lines() {
+    let probe = probe(ProbeKind::LogLine {
+        pattern: "listening on \\d+".into(),
+    });
+    let watch = probe.log_watch.clone().expect("log watch");
+    let mut seen = 0;
+
+    watch.observe("starting");
+    assert!(!check(&probe));
+
+    watch.observe("listening on 3030");
+    assert!(check(&probe));
+    assert!(probe.check(Path::new("."), Some(&mut seen)).is_ok());
+    // Readiness stays passing, liveness needs a new line
+    assert!(check(&probe));
+    assert!(probe.check(Path::new("."), Some(&mut seen)).is_err());
+}
@@ -0,0 +1,3 @@
+mod active_probe;
+mod probe_monitor;
+mod service_probes;
@@ -0,0 +1,46 @@
+// projects/products/stable/core/launcher/src/probe/tests/probe_monitor.rs
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::probe::{
+    Probe, ProbeEvent, ProbeMonitor, active_probe::ActiveProbe, probe_kind::ProbeKind,
+};
+
+#[test]
+fn failures_are_reported_once_at_the_threshold() {
+    let probe = ActiveProbe::new(&Probe {
+        kind: ProbeKind::LogLine {
+            pattern: "alive".into(),
+        },
+        period_ms: 100,
+        timeout_ms: 100,
+        failure_threshold: 2,
+        initial_delay_ms: 1_000,
+    })
+    .expect("valid probe");
+    let watch = probe.log_watch.clone().expect("log watch");
+    let cwd = Path::new(".");
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+    let mut monitor = ProbeMonitor::new(&probe, start, true);
+
+    // Initial delay, then one failure below the threshold
+    assert_eq!(monitor.poll(&probe, cwd, at(500)), None);
+    assert_eq!(monitor.poll(&probe, cwd, at(1_000)), None);
+    // Not due yet
+    assert_eq!(monitor.poll(&probe, cwd, at(1_050)), None);
+    assert!(matches!(
+        monitor.poll(&probe, cwd, at(1_100)),
+        Some(ProbeEvent::Failed { .. })
+    ));
+    assert_eq!(monitor.poll(&probe, cwd, at(1_200)), None);
+
+    watch.observe("still alive");
+    assert_eq!(
+        monitor.poll(&probe, cwd, at(1_300)),
+        Some(ProbeEvent::Recovered)
+    );
+    assert_eq!(monitor.poll(&probe, cwd, at(1_400)), None);
+}
@@ -0,0 +1,83 @@
+// projects/products/stable/core/launcher/src/probe/tests/service_probes.rs
+use crate::{
+    probe::{Probe, ServiceProbes, probe_kind::ProbeKind},
+    service::Service,
+};
+
+fn service(toml_text: &str) -> Service {
+    toml::from_str(toml_text).expect("valid service")
+}
+
+#[test]
+fn probes_parse_with_defaults() {
+    let svc = service(
+        r#"
+        name = "engine"
+        bin = "engine"
+
+        [readiness]
+        type = "tcp"
+        addr = "127.0.0.1:3030"
+
+        [liveness]
+        type = "websocket"
+        url = "ws://127.0.0.1:3030/ws"
+        period_ms = 5000
+        failure_threshold = 2
+        "#,
+    );
+    let probes = ServiceProbes::for_service(&svc).expect("valid probes");
+    assert!(probes.monitor_readiness);
+
+    let readiness = svc.readiness.expect("readiness");
+    assert_eq!(
+        readiness.kind,
+        ProbeKind::Tcp {
+            addr: "127.0.0.1:3030".into()
+        }
+    );
+    assert_eq!(readiness.period_ms, 1_000);
+    assert_eq!(readiness.failure_threshold, 3);
+
+    let liveness = svc.liveness.expect("liveness");
+    assert_eq!(liveness.period_ms, 5_000);
+    assert_eq!(liveness.failure_threshold, 2);
+}
+
+#[test]
+fn ready_http_is_a_readiness_probe() {
+    let svc = service(
+        r#"
+        name = "engine"
+        bin = "engine"
+        ready_http = "http://127.0.0.1:3030/health"
+        "#,
+    );
+    let probes = ServiceProbes::for_service(&svc).expect("valid probes");
+    let readiness = probes.readiness.expect("readiness");
+    assert_eq!(readiness.probe, Probe::http("http://127.0.0.1:3030/health"));
+    assert!(probes.liveness.is_none());
+    // Startup gate only, like before probes existed
+    assert!(!probes.monitor_readiness);
+}
+
+#[test]
+fn invalid_probes_are_rejected() {
+    let bad_pattern = service(
+        r#"
+        name = "engine"
+        bin = "engine"
+        liveness = { type = "log-line", pattern = "(" }
+        "#,
+    );
+    assert!(ServiceProbes::for_service(&bad_pattern).is_err());
+
+    let bad_url = service(
+        r#"
+        name = "engine"
+        bin = "engine"
+        readiness = { type = "websocket", url = "wss://example.org/ws" }
+        "#,
+    );
+    assert!(ServiceProbes::for_service(&bad_url).is_err());
+}
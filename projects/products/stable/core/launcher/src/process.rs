@@ -6,7 +6,11 @@ use std::{
 
 use anyhow::{Context, Result, anyhow};
 
-use crate::service::Service;
+use crate::{
+    limits::{Cgroup, apply_rlimits},
+    logging::log_message,
+    service::Service,
+};
 
 /// Resolves the binary path for a given service.
 ///
@@ -26,12 +30,22 @@ pub(crate) fn resolve_bin_path(workspace_root: &Path, profile_dir: &Path, bin: &
     p
 }
 
+/// Working directory of a service: its `cwd` relative to the workspace root.
+pub(crate) fn service_cwd(svc: &Service, workspace_root: &Path) -> PathBuf {
+    svc.cwd
+        .as_ref()
+        .map(|c| workspace_root.join(c))
+        .unwrap_or_else(|| workspace_root.to_path_buf())
+}
+
 /// Spawns a service process.
 ///
 /// # Arguments
 /// * `svc` - The service to spawn.
 /// * `workspace_root` - The root directory of the workspace.
 /// * `bin_path` - The path to the binary to execute.
+/// * `cgroup` - The cgroup the process joins before exec, if any; reset to
+///   `None` when it cannot be joined.
 /// * `dry_run` - If true, the service will not actually be started.
 ///
 /// # Returns
@@ -40,13 +54,10 @@ pub(crate) fn spawn_service(
     svc: &Service,
     workspace_root: &Path,
     bin_path: &Path,
+    cgroup: &mut Option<Cgroup>,
     dry_run: bool,
 ) -> Result<Child> {
-    let cwd = svc
-        .cwd
-        .as_ref()
-        .map(|c| workspace_root.join(c))
-        .unwrap_or_else(|| workspace_root.to_path_buf());
+    let cwd = service_cwd(svc, workspace_root);
 
     let mut cmd = Command::new(bin_path);
     cmd.args(&svc.args)
@@ -64,6 +75,17 @@ pub(crate) fn spawn_service(
         }
     }
     configure_watcher_env(svc, workspace_root, has_watcher_config, &mut cmd);
+    if let Some(group) = cgroup.as_mut()
+        && let Err(e) = group.attach_on_spawn(&mut cmd)
+    {
+        log_message(
+            &format!("cannot join cgroup, using rlimits only: {:#}", e),
+            true,
+            &svc.name,
+        );
+        *cgroup = None;
+    }
+    apply_rlimits(&mut cmd, &svc.name, &svc.limits, cgroup.is_some());
 
     println!("▶ spawn {}: {:?}", svc.name, cmd);
 
@@ -0,0 +1,146 @@
+// projects/products/stable/core/launcher/src/reload/dev_loop.rs
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use anyhow::Result;
+
+use crate::{
+    build::Build,
+    cargo_commands::cargo_build_bins,
+    child_handle::ChildHandle,
+    dev::Dev,
+    entry::Paths,
+    logging::log_message,
+    service::Service,
+    supervisor::{start_and_supervise, stop_service},
+};
+
+use super::{file_watcher::FileWatcher, reload_plan::ReloadPlan, workspace_graph::WorkspaceGraph};
+
+/// Everything the dev loop needs to rebuild and restart services
+pub(crate) struct DevContext<'a> {
+    pub(crate) dev: &'a Dev,
+    pub(crate) build: &'a Build,
+    pub(crate) services: &'a [Service],
+    pub(crate) start_order: &'a [String],
+    pub(crate) paths: Paths,
+    pub(crate) running: Arc<Mutex<HashMap<String, ChildHandle>>>,
+    pub(crate) shutting_down: Arc<Mutex<bool>>,
+    pub(crate) startup_timeout: Duration,
+}
+
+/// Watches the sources and, after each batch of changes, rebuilds the
+/// affected binaries and restarts their services and dependents.
+/// Services not affected keep running. Never returns on success.
+pub(crate) fn run_dev_loop(ctx: DevContext<'_>) -> Result<()> {
+    let root = ctx.paths.workspace.root.clone();
+    let mut graph = WorkspaceGraph::load(&root)?;
+    // `cargo metadata` reports canonical paths
+    let watch: Vec<PathBuf> = ctx
+        .dev
+        .watch
+        .iter()
+        .map(|w| fs::canonicalize(root.join(w)).unwrap_or_else(|_| root.join(w)))
+        .collect();
+    let roots = watched_dirs(&graph, &watch, ctx.services);
+    log_message(
+        &format!(
+            "👀 dev mode: watching {} crate dir(s) under {}",
+            roots.len(),
+            ctx.dev.watch.join(", ")
+        ),
+        false,
+        "launcher",
+    );
+    let mut watcher = FileWatcher::new(roots, ctx.dev.ignore.clone());
+    let poll_interval = Duration::from_millis(ctx.dev.poll_interval_ms.max(1));
+    let debounce = Duration::from_millis(ctx.dev.debounce_ms);
+
+    loop {
+        let changed = watcher.wait_for_changes(poll_interval, debounce);
+
+        // Crates or dependencies may have been added or removed
+        if changed.iter().any(|p| p.ends_with("Cargo.toml")) {
+            match WorkspaceGraph::load(&root) {
+                Ok(g) => {
+                    graph = g;
+                    watcher.set_roots(watched_dirs(&graph, &watch, ctx.services));
+                }
+                Err(e) => log_message(
+                    &format!("cargo metadata failed, keeping previous graph: {:#}", e),
+                    true,
+                    "launcher",
+                ),
+            }
+        }
+
+        let plan = ReloadPlan::new(
+            &graph.affected_bins(&changed),
+            ctx.services,
+            ctx.start_order,
+        );
+        if plan.is_empty() {
+            log_message(
+                &format!("{} file(s) changed; no service affected", changed.len()),
+                false,
+                "launcher",
+            );
+            continue;
+        }
+
+        // Keep the current processes when the new code does not build
+        if let Err(e) = cargo_build_bins(&root, ctx.build, &plan.bins) {
+            log_message(
+                &format!("rebuild failed, services left running: {:#}", e),
+                true,
+                "launcher",
+            );
+            continue;
+        }
+
+        if *ctx.shutting_down.lock().unwrap_or_else(|p| p.into_inner()) {
+            return Ok(());
+        }
+
+        log_message(
+            &format!("🔁 restarting: {}", plan.restart.join(", ")),
+            false,
+            "launcher",
+        );
+        for name in plan.restart.iter().rev() {
+            stop_service(name, &ctx.running);
+        }
+        for name in &plan.restart {
+            let Some(svc) = ctx.services.iter().find(|s| &s.name == name) else {
+                continue;
+            };
+            // Dependents of a service that fails to come back are still
+            // started; they surface the failure through their own logs
+            if let Err(e) = start_and_supervise(
+                svc.clone(),
+                ctx.paths.clone(),
+                ctx.running.clone(),
+                ctx.shutting_down.clone(),
+                ctx.startup_timeout,
+                false,
+            ) {
+                log_message(&format!("restart failed: {:#}", e), true, name);
+            }
+        }
+    }
+}
+
+/// Crate directories under the `watch` roots that the services' binaries
+/// are built from, so each poll only walks sources that can affect them
+fn watched_dirs(graph: &WorkspaceGraph, watch: &[PathBuf], services: &[Service]) -> Vec<PathBuf> {
+    graph
+        .source_dirs(services.iter().map(|s| s.bin.as_str()))
+        .into_iter()
+        .filter(|dir| watch.iter().any(|w| dir.starts_with(w)))
+        .collect()
+}
@@ -0,0 +1,121 @@
+// projects/products/stable/core/launcher/src/reload/file_watcher.rs
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// Extensions of the files whose changes trigger a rebuild
+const WATCHED_EXTENSIONS: &[&str] = &["rs", "toml"];
+
+/// Polling watcher: compares modification times of the source files under
+/// its roots between scans.
+pub(crate) struct FileWatcher {
+    roots: Vec<PathBuf>,
+    ignore: Vec<String>,
+    snapshot: HashMap<PathBuf, SystemTime>,
+}
+
+impl FileWatcher {
+    pub(crate) fn new(roots: Vec<PathBuf>, ignore: Vec<String>) -> Self {
+        let mut watcher = Self {
+            roots,
+            ignore,
+            snapshot: HashMap::new(),
+        };
+        watcher.snapshot = watcher.scan();
+        watcher
+    }
+
+    /// Watches `roots` from now on; files already there are not reported
+    pub(crate) fn set_roots(&mut self, roots: Vec<PathBuf>) {
+        self.roots = roots;
+        self.snapshot = self.scan();
+    }
+
+    /// Files added, modified or removed since the previous call
+    pub(crate) fn changes(&mut self) -> BTreeSet<PathBuf> {
+        let current = self.scan();
+        let mut changed: BTreeSet<PathBuf> = current
+            .iter()
+            .filter(|(path, mtime)| self.snapshot.get(*path) != Some(mtime))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.snapshot
+                .keys()
+                .filter(|path| !current.contains_key(*path))
+                .cloned(),
+        );
+        self.snapshot = current;
+        changed
+    }
+
+    /// Blocks until files change, then until no further change happens for
+    /// `debounce`; returns every file changed meanwhile
+    pub(crate) fn wait_for_changes(
+        &mut self,
+        poll_interval: Duration,
+        debounce: Duration,
+    ) -> BTreeSet<PathBuf> {
+        let mut changed = BTreeSet::new();
+        while changed.is_empty() {
+            thread::sleep(poll_interval);
+            changed = self.changes();
+        }
+        let mut quiet = Duration::ZERO;
+        while quiet < debounce {
+            let step = poll_interval.min(debounce - quiet);
+            thread::sleep(step);
+            let more = self.changes();
+            if more.is_empty() {
+                quiet += step;
+            } else {
+                changed.extend(more);
+                quiet = Duration::ZERO;
+            }
+        }
+        changed
+    }
+
+    fn scan(&self) -> HashMap<PathBuf, SystemTime> {
+        let mut files = HashMap::new();
+        for root in &self.roots {
+            self.scan_dir(root, &mut files);
+        }
+        files
+    }
+
+    fn scan_dir(&self, dir: &Path, files: &mut HashMap<PathBuf, SystemTime>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                let ignored = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|name| self.ignore.iter().any(|i| i == name));
+                if !ignored {
+                    self.scan_dir(&path, files);
+                }
+            } else if is_watched(&path)
+                && let Ok(mtime) = entry.metadata().and_then(|m| m.modified())
+            {
+                files.insert(path, mtime);
+            }
+        }
+    }
+}
+
+fn is_watched(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| WATCHED_EXTENSIONS.contains(&ext))
+}
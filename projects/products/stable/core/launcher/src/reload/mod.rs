@@ -0,0 +1,10 @@
+// projects/products/stable/core/launcher/src/reload/mod.rs
+mod dev_loop;
+mod file_watcher;
+mod reload_plan;
+mod workspace_graph;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use dev_loop::{DevContext, run_dev_loop};
@@ -0,0 +1,60 @@
+// projects/products/stable/core/launcher/src/reload/reload_plan.rs
+use std::collections::BTreeSet;
+
+use crate::service::Service;
+
+/// What dev mode rebuilds and restarts after a change
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ReloadPlan {
+    /// Binaries to rebuild
+    pub(crate) bins: BTreeSet<String>,
+    /// Services to restart, in start (`depends_on`) order
+    pub(crate) restart: Vec<String>,
+}
+
+impl ReloadPlan {
+    /// Services running one of `affected_bins` are restarted together with
+    /// every service depending on them, directly or not. Only the binaries
+    /// of the services themselves are rebuilt.
+    pub(crate) fn new(
+        affected_bins: &BTreeSet<String>,
+        services: &[Service],
+        start_order: &[String],
+    ) -> Self {
+        let mut restart: BTreeSet<&str> = services
+            .iter()
+            .filter(|s| affected_bins.contains(&s.bin))
+            .map(|s| s.name.as_str())
+            .collect();
+        let bins = services
+            .iter()
+            .filter(|s| restart.contains(s.name.as_str()))
+            .map(|s| s.bin.clone())
+            .collect();
+
+        loop {
+            let before = restart.len();
+            for s in services {
+                if s.depends_on.iter().any(|d| restart.contains(d.as_str())) {
+                    restart.insert(s.name.as_str());
+                }
+            }
+            if restart.len() == before {
+                break;
+            }
+        }
+
+        Self {
+            bins,
+            restart: start_order
+                .iter()
+                .filter(|name| restart.contains(name.as_str()))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.restart.is_empty()
+    }
+}
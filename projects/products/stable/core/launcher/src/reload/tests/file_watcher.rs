@@ -0,0 +1,90 @@
+// projects/products/stable/core/launcher/src/reload/tests/file_watcher.rs
+use std::{
+    collections::BTreeSet,
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, SystemTime},
+};
+
+use crate::reload::file_watcher::FileWatcher;
+
+fn temp_dir(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!(
+        "launcher_watch_{}_{}_{}",
+        std::process::id(),
+        name,
+        n
+    ));
+    fs::create_dir_all(dir.join("src")).expect("create dir");
+    dir
+}
+
+fn watcher(dir: &Path) -> FileWatcher {
+    FileWatcher::new(vec![dir.to_path_buf()], vec!["target".to_string()])
+}
+
+/// Moves the mtime forward so the change is seen even on coarse clocks
+fn touch(path: &Path, offset_secs: u64) {
+    let mtime = SystemTime::now() + Duration::from_secs(offset_secs);
+    File::options()
+        .write(true)
+        .open(path)
+        .and_then(|f| f.set_modified(mtime))
+        .expect("set mtime");
+}
+
+#[test]
+fn created_files_are_reported_once() {
+    let dir = temp_dir("create");
+    let mut watcher = watcher(&dir);
+    assert!(watcher.changes().is_empty());
+
+    let file = dir.join("src/lib.rs");
+    fs::write(&file, "fn main() {}").expect("write");
+
+    assert_eq!(watcher.changes(), BTreeSet::from([file]));
+    assert!(watcher.changes().is_empty());
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn modified_files_are_reported() {
+    let dir = temp_dir("modify");
+    let file = dir.join("Cargo.toml");
+    fs::write(&file, "[package]").expect("write");
+    let mut watcher = watcher(&dir);
+
+    touch(&file, 10);
+
+    assert_eq!(watcher.changes(), BTreeSet::from([file]));
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn deleted_files_are_reported() {
+    let dir = temp_dir("delete");
+    let file = dir.join("src/main.rs");
+    fs::write(&file, "fn main() {}").expect("write");
+    let mut watcher = watcher(&dir);
+
+    fs::remove_file(&file).expect("remove");
+
+    assert_eq!(watcher.changes(), BTreeSet::from([file]));
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn ignored_directories_and_other_files_are_skipped() {
+    let dir = temp_dir("ignore");
+    let mut watcher = watcher(&dir);
+
+    fs::create_dir_all(dir.join("target/debug/build")).expect("create dir");
+    fs::write(dir.join("target/debug/build/out.rs"), "").expect("write");
+    fs::write(dir.join("src/notes.md"), "").expect("write");
+
+    assert!(watcher.changes().is_empty());
+    let _ = fs::remove_dir_all(&dir);
+}
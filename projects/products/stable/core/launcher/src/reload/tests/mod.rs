@@ -0,0 +1,3 @@
+mod file_watcher;
+mod reload_plan;
+mod workspace_graph;
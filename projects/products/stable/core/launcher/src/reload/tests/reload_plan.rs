@@ -0,0 +1,65 @@
+// projects/products/stable/core/launcher/src/reload/tests/reload_plan.rs
+use std::collections::BTreeSet;
+
+use crate::{
+    reload::reload_plan::ReloadPlan,
+    service::{Service, topo_sort},
+};
+
+fn services() -> Vec<Service> {
+    #[derive(serde::Deserialize)]
+    struct Services {
+        service: Vec<Service>,
+    }
+    let parsed: Services = toml::from_str(
+        r#"
+        [[service]]
+        name = "engine"
+        bin = "engine"
+
+        [[service]]
+        name = "central_ui"
+        bin = "central_ui"
+        depends_on = ["engine"]
+
+        [[service]]
+        name = "gateway"
+        bin = "gateway"
+        depends_on = ["central_ui"]
+
+        [[service]]
+        name = "watcher"
+        bin = "watcher"
+        "#,
+    )
+    .expect("valid services");
+    parsed.service
+}
+
+fn bins(names: &[&str]) -> BTreeSet<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn dependents_are_restarted_in_start_order_without_rebuild() {
+    let services = services();
+    let order = topo_sort(&services).expect("no cycle");
+
+    let plan = ReloadPlan::new(&bins(&["engine"]), &services, &order);
+
+    assert_eq!(plan.bins, bins(&["engine"]));
+    assert_eq!(plan.restart, ["engine", "central_ui", "gateway"]);
+}
+
+#[test]
+fn unrelated_services_keep_running() {
+    let services = services();
+    let order = topo_sort(&services).expect("no cycle");
+
+    let plan = ReloadPlan::new(&bins(&["watcher"]), &services, &order);
+    assert_eq!(plan.restart, ["watcher"]);
+
+    let plan = ReloadPlan::new(&bins(&["some_tool"]), &services, &order);
+    assert!(plan.is_empty());
+    assert!(plan.bins.is_empty());
+}
@@ -0,0 +1,98 @@
+// projects/products/stable/core/launcher/src/reload/tests/workspace_graph.rs
+use std::path::PathBuf;
+
+use crate::reload::workspace_graph::WorkspaceGraph;
+
+const METADATA: &str = r#"{
+  "packages": [
+    {
+      "name": "common",
+      "version": "0.1.0",
+      "manifest_path": "/ws/libs/common/Cargo.toml",
+      "targets": [{ "name": "common", "kind": ["lib"], "src_path": "/ws/libs/common/src/lib.rs" }],
+      "dependencies": [{ "name": "serde", "req": "^1", "path": null }]
+    },
+    {
+      "name": "engine",
+      "version": "0.1.0",
+      "manifest_path": "/ws/core/engine/Cargo.toml",
+      "targets": [{ "name": "engine", "kind": ["bin"], "src_path": "/ws/core/engine/src/main.rs" }],
+      "dependencies": [{ "name": "common", "req": "*", "path": "/ws/libs/common" }]
+    },
+    {
+      "name": "engine-plugin",
+      "version": "0.1.0",
+      "manifest_path": "/ws/core/engine/plugin/Cargo.toml",
+      "targets": [{ "name": "plugin", "kind": ["bin"], "src_path": "/ws/core/engine/plugin/src/main.rs" }],
+      "dependencies": []
+    },
+    {
+      "name": "watcher",
+      "version": "0.1.0",
+      "manifest_path": "/ws/core/watcher/Cargo.toml",
+      "targets": [{ "name": "watcher", "kind": ["bin"], "src_path": "/ws/core/watcher/src/main.rs" }],
+      "dependencies": []
+    }
+  ],
+  "workspace_root": "/ws"
+}"#;
+
+fn graph() -> WorkspaceGraph {
+    WorkspaceGraph::from_metadata(METADATA).expect("valid metadata")
+}
+
+#[test]
+fn files_map_to_the_deepest_crate() {
+    let graph = graph();
+    assert_eq!(
+        graph.crate_for(&PathBuf::from("/ws/core/engine/src/main.rs")),
+        Some("engine")
+    );
+    assert_eq!(
+        graph.crate_for(&PathBuf::from("/ws/core/engine/plugin/src/main.rs")),
+        Some("engine-plugin")
+    );
+    assert_eq!(graph.crate_for(&PathBuf::from("/ws/README.md")), None);
+}
+
+#[test]
+fn library_changes_rebuild_dependent_binaries() {
+    let changed = [PathBuf::from("/ws/libs/common/src/lib.rs")];
+    let bins: Vec<String> = graph().affected_bins(&changed).into_iter().collect();
+    assert_eq!(bins, ["engine"]);
+}
+
+#[test]
+fn binary_changes_only_rebuild_that_binary() {
+    let changed = [PathBuf::from("/ws/core/watcher/src/main.rs")];
+    let bins: Vec<String> = graph().affected_bins(&changed).into_iter().collect();
+    assert_eq!(bins, ["watcher"]);
+}
+
+#[test]
+fn source_dirs_cover_the_dependency_closure_of_the_bins() {
+    let dirs = graph().source_dirs(["engine"]);
+    assert_eq!(
+        dirs,
+        [
+            PathBuf::from("/ws/core/engine"),
+            PathBuf::from("/ws/libs/common")
+        ]
+    );
+    assert_eq!(
+        graph().source_dirs(["watcher"]),
+        [PathBuf::from("/ws/core/watcher")]
+    );
+}
+
+#[test]
+fn nested_crate_dirs_are_walked_once() {
+    let dirs = graph().source_dirs(["engine", "plugin"]);
+    assert_eq!(
+        dirs,
+        [
+            PathBuf::from("/ws/core/engine"),
+            PathBuf::from("/ws/libs/common")
+        ]
+    );
+}
@@ -0,0 +1,186 @@
+// projects/products/stable/core/launcher/src/reload/workspace_graph.rs
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::cargo_commands::cargo_metadata;
+
+/// Subset of `cargo metadata --format-version 1 --no-deps` used by dev mode
+#[derive(Debug, Deserialize)]
+struct Metadata {
+    packages: Vec<Package>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Package {
+    name: String,
+    manifest_path: PathBuf,
+    #[serde(default)]
+    targets: Vec<Target>,
+    #[serde(default)]
+    dependencies: Vec<Dependency>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Target {
+    name: String,
+    #[serde(default)]
+    kind: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Dependency {
+    name: String,
+    /// Set for path dependencies, i.e. other workspace crates
+    #[serde(default)]
+    path: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+struct CrateNode {
+    name: String,
+    dir: PathBuf,
+    bins: Vec<String>,
+}
+
+/// Workspace crates, the binaries they produce and who depends on whom
+#[derive(Debug)]
+pub(crate) struct WorkspaceGraph {
+    crates: Vec<CrateNode>,
+    /// crate name -> workspace crates depending on it directly
+    dependents: HashMap<String, Vec<String>>,
+    /// crate name -> workspace crates it depends on directly
+    dependencies: HashMap<String, Vec<String>>,
+}
+
+impl WorkspaceGraph {
+    /// Runs `cargo metadata` in `root` and builds the graph from its output
+    pub(crate) fn load(root: &Path) -> Result<Self> {
+        let json = cargo_metadata(root)?;
+        Self::from_metadata(&json)
+    }
+
+    pub(crate) fn from_metadata(json: &str) -> Result<Self> {
+        let metadata: Metadata =
+            common_json::from_str(json).context("invalid cargo metadata output")?;
+
+        let names: BTreeSet<&str> = metadata.packages.iter().map(|p| p.name.as_str()).collect();
+        let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
+        let mut dependencies: HashMap<String, Vec<String>> = HashMap::new();
+        for package in &metadata.packages {
+            for dep in &package.dependencies {
+                if dep.path.is_some() && names.contains(dep.name.as_str()) {
+                    dependents
+                        .entry(dep.name.clone())
+                        .or_default()
+                        .push(package.name.clone());
+                    dependencies
+                        .entry(package.name.clone())
+                        .or_default()
+                        .push(dep.name.clone());
+                }
+            }
+        }
+
+        let crates = metadata
+            .packages
+            .into_iter()
+            .map(|package| CrateNode {
+                dir: package
+                    .manifest_path
+                    .parent()
+                    .map(Path::to_path_buf)
+                    .unwrap_or_default(),
+                bins: package
+                    .targets
+                    .into_iter()
+                    .filter(|t| t.kind.iter().any(|k| k == "bin"))
+                    .map(|t| t.name)
+                    .collect(),
+                name: package.name,
+            })
+            .collect();
+
+        Ok(Self {
+            crates,
+            dependents,
+            dependencies,
+        })
+    }
+
+    /// Crate owning `path`: the one with the deepest directory containing it
+    pub(crate) fn crate_for(&self, path: &Path) -> Option<&str> {
+        self.crates
+            .iter()
+            .filter(|c| path.starts_with(&c.dir))
+            .max_by_key(|c| c.dir.components().count())
+            .map(|c| c.name.as_str())
+    }
+
+    /// Binaries that must be rebuilt after `changed` files were modified:
+    /// those of the owning crates and of every crate depending on them
+    pub(crate) fn affected_bins<'a>(
+        &self,
+        changed: impl IntoIterator<Item = &'a PathBuf>,
+    ) -> BTreeSet<String> {
+        let mut affected: BTreeSet<&str> = BTreeSet::new();
+        let mut pending: Vec<&str> = changed
+            .into_iter()
+            .filter_map(|path| self.crate_for(path))
+            .collect();
+        while let Some(name) = pending.pop() {
+            if !affected.insert(name) {
+                continue;
+            }
+            if let Some(dependents) = self.dependents.get(name) {
+                pending.extend(dependents.iter().map(String::as_str));
+            }
+        }
+
+        self.crates
+            .iter()
+            .filter(|c| affected.contains(c.name.as_str()))
+            .flat_map(|c| c.bins.iter().cloned())
+            .collect()
+    }
+
+    /// Directories of the crates building `bins` and of every workspace
+    /// crate they depend on; directories nested in another one are dropped
+    pub(crate) fn source_dirs<'a>(&self, bins: impl IntoIterator<Item = &'a str>) -> Vec<PathBuf> {
+        let bins: BTreeSet<&str> = bins.into_iter().collect();
+        let mut closure: BTreeSet<&str> = BTreeSet::new();
+        let mut pending: Vec<&str> = self
+            .crates
+            .iter()
+            .filter(|c| c.bins.iter().any(|b| bins.contains(b.as_str())))
+            .map(|c| c.name.as_str())
+            .collect();
+        while let Some(name) = pending.pop() {
+            if !closure.insert(name) {
+                continue;
+            }
+            if let Some(dependencies) = self.dependencies.get(name) {
+                pending.extend(dependencies.iter().map(String::as_str));
+            }
+        }
+
+        let dirs: BTreeSet<&Path> = self
+            .crates
+            .iter()
+            .filter(|c| closure.contains(c.name.as_str()))
+            .map(|c| c.dir.as_path())
+            .collect();
+        dirs.iter()
+            .filter(|dir| {
+                !dirs
+                    .iter()
+                    .any(|other| other != *dir && dir.starts_with(other))
+            })
+            .map(|dir| dir.to_path_buf())
+            .collect()
+    }
+}
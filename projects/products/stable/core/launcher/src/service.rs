@@ -4,7 +4,11 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use anyhow::{Result, bail};
 use serde::Deserialize;
 
-use crate::restart_policy::{RestartPolicy, default_backoff, default_restart};
+use crate::{
+    limits::ResourceLimits,
+    probe::{Probe, ServiceProbes},
+    restart_policy::{RestartPolicy, default_backoff, default_restart},
+};
 
 #[derive(Debug, Deserialize, Clone)]
 pub(crate) struct Service {
@@ -28,8 +32,18 @@ pub(crate) struct Service {
     #[serde(default = "default_backoff")]
     pub(crate) restart_backoff_ms: u64,
 
+    /// Shorthand for an `http` readiness probe
     #[serde(default)]
     pub(crate) ready_http: Option<String>,
+    /// Gates startup of dependents; failures afterwards are only logged
+    #[serde(default)]
+    pub(crate) readiness: Option<Probe>,
+    /// Failures kill the service, which is then handled by `restart`
+    #[serde(default)]
+    pub(crate) liveness: Option<Probe>,
+
+    #[serde(default)]
+    pub(crate) limits: ResourceLimits,
 }
 
 pub(crate) fn validate_services(services: &[Service]) -> Result<()> {
@@ -41,6 +55,7 @@ pub(crate) fn validate_services(services: &[Service]) -> Result<()> {
         if !names.insert(s.name.clone()) {
             bail!("duplicate service name: {}", s.name);
         }
+        ServiceProbes::for_service(s)?;
     }
     // deps exist
     let set: HashSet<_> = services.iter().map(|s| s.name.as_str()).collect();
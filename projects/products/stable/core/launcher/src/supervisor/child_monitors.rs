@@ -0,0 +1,75 @@
+// projects/products/stable/core/launcher/src/supervisor/child_monitors.rs
+use std::{path::Path, sync::Arc, time::Instant};
+
+use crate::{
+    child_handle::ChildHandle, limits::ResourceSampler, logging::log_message, probe::ProbeEvent,
+    service::Service,
+};
+
+use super::{
+    locks::lock_recover_arc,
+    probe_worker::{ProbeRole, ProbeWorker},
+};
+
+/// Periodic checks of a running, ready child: probes and resource use
+pub(crate) struct ChildMonitors {
+    probes: Option<ProbeWorker>,
+    resources: Option<ResourceSampler>,
+}
+
+impl ChildMonitors {
+    pub(crate) fn new(svc: &Service, child: &ChildHandle, cwd: &Path, now: Instant) -> Self {
+        Self {
+            probes: ProbeWorker::spawn(
+                &svc.name,
+                Arc::clone(&child.probes),
+                cwd.to_path_buf(),
+                now,
+            ),
+            resources: ResourceSampler::new(&svc.limits, now),
+        }
+    }
+
+    /// Acts on probe results and samples resources when due; kills the
+    /// child when its liveness probe fails or it goes above `max_rss_mb`
+    pub(crate) fn poll(&mut self, svc: &Service, child: &ChildHandle) {
+        let now = Instant::now();
+        for (role, event) in self.probes.iter().flat_map(ProbeWorker::events) {
+            match (role, event) {
+                (ProbeRole::Readiness, ProbeEvent::Failed { reason }) => {
+                    log_message(&format!("not ready: {}", reason), true, &svc.name)
+                }
+                (ProbeRole::Readiness, ProbeEvent::Recovered) => {
+                    log_message("ready again", false, &svc.name)
+                }
+                (ProbeRole::Liveness, ProbeEvent::Failed { reason }) => {
+                    log_message(
+                        &format!("liveness failed, killing: {}", reason),
+                        true,
+                        &svc.name,
+                    );
+                    let _ = lock_recover_arc(&child.child, "child").kill();
+                    return;
+                }
+                (ProbeRole::Liveness, ProbeEvent::Recovered) => {}
+            }
+        }
+
+        if let Some(sampler) = self.resources.as_mut() {
+            let pid = lock_recover_arc(&child.child, "child").id();
+            if let Some(stats) = sampler.poll(pid, now) {
+                let cgroup_memory = child.cgroup.as_ref().and_then(|c| c.memory_current());
+                let sample = sampler.sample(stats, cgroup_memory, now);
+                log_message(&sample.describe(), false, &svc.name);
+                if let Some(reason) = sample.exceeded(&svc.limits) {
+                    log_message(
+                        &format!("resource limit exceeded, killing: {}", reason),
+                        true,
+                        &svc.name,
+                    );
+                    let _ = lock_recover_arc(&child.child, "child").kill();
+                }
+            }
+        }
+    }
+}
@@ -5,3 +5,12 @@ pub(crate) enum LogStream {
     Stdout,
     Stderr,
 }
+
+impl LogStream {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Stdout => "stdout",
+            Self::Stderr => "stderr",
+        }
+    }
+}
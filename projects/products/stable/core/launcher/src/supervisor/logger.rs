@@ -1,10 +1,13 @@
 // projects/products/stable/core/launcher/src/supervisor/logger.rs
 use std::{
     io::{BufRead, BufReader},
+    sync::Arc,
     thread,
 };
 
-use crate::{child_handle::ChildHandle, logging::log_message};
+use crate::{
+    child_handle::ChildHandle, log_capture::capture, logging::log_message, probe::LogLineWatch,
+};
 
 use super::{locks::lock_recover, log_stream::LogStream};
 
@@ -12,16 +15,23 @@ fn spawn_logger<R: std::io::Read + Send + 'static>(
     stream: Option<R>,
     name: String,
     log_stream: LogStream,
+    watches: Vec<Arc<LogLineWatch>>,
 ) {
     if let Some(stream) = stream {
         thread::spawn(move || {
             let reader = BufReader::new(stream);
             for line in reader.lines() {
                 match line {
-                    Ok(line) => match log_stream {
-                        LogStream::Stdout => log_message(&line, false, &name),
-                        LogStream::Stderr => log_message(&line, true, &name),
-                    },
+                    Ok(line) => {
+                        for watch in &watches {
+                            watch.observe(&line);
+                        }
+                        capture(&name, log_stream, &line);
+                        match log_stream {
+                            LogStream::Stdout => log_message(&line, false, &name),
+                            LogStream::Stderr => log_message(&line, true, &name),
+                        }
+                    }
                     Err(e) => {
                         log_message(&format!("log stream error: {}", e), true, &name);
                         break;
@@ -38,6 +48,7 @@ pub(crate) fn pipe_child_outputs(name: String, handle: ChildHandle) {
         (child.stdout.take(), child.stderr.take())
     };
 
-    spawn_logger(stdout, name.clone(), LogStream::Stdout);
-    spawn_logger(stderr, name, LogStream::Stderr);
+    let watches = handle.probes.log_watches();
+    spawn_logger(stdout, name.clone(), LogStream::Stdout, watches.clone());
+    spawn_logger(stderr, name, LogStream::Stderr, watches);
 }
@@ -1,10 +1,15 @@
 // projects/products/stable/core/launcher/src/supervisor/mod.rs
+mod child_monitors;
 mod locks;
 mod log_stream;
 mod logger;
+mod probe_worker;
 mod readiness;
 mod restart;
 mod start_and_supervise;
+mod stop_service;
 mod supervise_loop;
 
+pub(crate) use log_stream::LogStream;
 pub(crate) use start_and_supervise::start_and_supervise;
+pub(crate) use stop_service::stop_service;
@@ -0,0 +1,97 @@
+// projects/products/stable/core/launcher/src/supervisor/probe_worker.rs
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    logging::log_message,
+    probe::{ProbeEvent, ProbeMonitor, ServiceProbes},
+};
+
+const TICK: Duration = Duration::from_millis(100);
+
+/// Which probe an event comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ProbeRole {
+    Readiness,
+    Liveness,
+}
+
+/// Runs the periodic probes of one child on their own thread, so a slow
+/// check never delays exit detection in the supervise loop. The thread
+/// stops when the worker is dropped.
+pub(crate) struct ProbeWorker {
+    events: Receiver<(ProbeRole, ProbeEvent)>,
+    stop: Arc<AtomicBool>,
+}
+
+impl ProbeWorker {
+    /// `None` when the child has no probe to monitor once running
+    pub(crate) fn spawn(
+        name: &str,
+        probes: Arc<ServiceProbes>,
+        cwd: PathBuf,
+        now: Instant,
+    ) -> Option<Self> {
+        let mut monitors = Vec::new();
+        if let Some(probe) = probes
+            .readiness
+            .as_ref()
+            .filter(|_| probes.monitor_readiness)
+        {
+            monitors.push((ProbeRole::Readiness, ProbeMonitor::new(probe, now, false)));
+        }
+        if let Some(probe) = &probes.liveness {
+            monitors.push((ProbeRole::Liveness, ProbeMonitor::new(probe, now, true)));
+        }
+        if monitors.is_empty() {
+            return None;
+        }
+
+        let (tx, events) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::clone(&stop);
+        let spawned = thread::Builder::new()
+            .name(format!("probes-{name}"))
+            .spawn(move || {
+                while !stopped.load(Ordering::SeqCst) {
+                    for (role, monitor) in &mut monitors {
+                        let probe = match role {
+                            ProbeRole::Readiness => probes.readiness.as_ref(),
+                            ProbeRole::Liveness => probes.liveness.as_ref(),
+                        };
+                        if let Some(probe) = probe
+                            && let Some(event) = monitor.poll(probe, &cwd, Instant::now())
+                            && tx.send((*role, event)).is_err()
+                        {
+                            return;
+                        }
+                    }
+                    thread::sleep(TICK);
+                }
+            });
+        if let Err(e) = spawned {
+            log_message(&format!("cannot start probe thread: {}", e), true, name);
+            return None;
+        }
+        Some(Self { events, stop })
+    }
+
+    /// Events reported since the last call
+    pub(crate) fn events(&self) -> impl Iterator<Item = (ProbeRole, ProbeEvent)> + '_ {
+        self.events.try_iter()
+    }
+}
+
+impl Drop for ProbeWorker {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
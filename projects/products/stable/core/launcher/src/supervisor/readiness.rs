@@ -1,35 +1,57 @@
 // projects/products/stable/core/launcher/src/supervisor/readiness.rs
 use std::{
+    path::Path,
     thread,
     time::{Duration, Instant},
 };
 
 use anyhow::{Result, bail};
 
-use crate::{logging::log_message, service::Service};
+use crate::{child_handle::ChildHandle, logging::log_message, service::Service};
 
-pub(crate) fn wait_ready(svc: &Service, timeout: Duration) -> Result<()> {
-    let Some(url) = &svc.ready_http else {
+use super::locks::lock_recover_arc;
+
+/// Waits until the readiness probe of a freshly spawned service passes.
+/// Fails on timeout or when the process exits first.
+pub(crate) fn wait_ready(
+    svc: &Service,
+    handle: &ChildHandle,
+    cwd: &Path,
+    timeout: Duration,
+) -> Result<()> {
+    let Some(probe) = &handle.probes.readiness else {
         return Ok(());
     };
 
     let start = Instant::now();
+    thread::sleep(Duration::from_millis(probe.probe.initial_delay_ms).min(timeout));
+    let mut last_err = None;
     while start.elapsed() < timeout {
-        if is_http_healthy(url) {
-            log_message(&format!("✅ ready: {}", svc.name), false, &svc.name);
-            return Ok(());
+        match probe.check(cwd, None) {
+            Ok(()) => {
+                log_message(&format!("✅ ready: {}", svc.name), false, &svc.name);
+                return Ok(());
+            }
+            Err(e) => last_err = Some(e),
+        }
+        if let Ok(Some(status)) = lock_recover_arc(&handle.child, "child").try_wait() {
+            bail!(
+                "service `{}` exited before becoming ready (code={})",
+                svc.name,
+                status.code().unwrap_or(-1)
+            );
         }
-        thread::sleep(Duration::from_millis(200));
+        thread::sleep(Duration::from_millis(probe.probe.period_ms.max(1)));
     }
 
-    bail!("service `{}` not ready within {:?}", svc.name, timeout);
-}
-
-fn is_http_healthy(url: &str) -> bool {
-    let config = ureq::Agent::config_builder()
-        .timeout_per_call(Some(Duration::from_millis(800)))
-        .build();
-    let agent = config.new_agent();
-    let resp = agent.get(url).call();
-    matches!(resp, Ok(r) if r.status().is_success())
+    match last_err {
+        Some(e) => bail!(
+            "service `{}` not ready within {:?} ({}: {:#})",
+            svc.name,
+            timeout,
+            probe.probe.kind.describe(),
+            e
+        ),
+        None => bail!("service `{}` not ready within {:?}", svc.name, timeout),
+    }
 }
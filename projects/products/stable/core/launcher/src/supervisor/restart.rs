@@ -2,82 +2,116 @@
 use std::{
     collections::HashMap,
     path::Path,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, atomic::Ordering},
     thread,
     time::Duration,
 };
 
 use crate::{
-    child_handle::ChildHandle, logging::log_message, process::spawn_service, service::Service,
+    child_handle::ChildHandle,
+    limits::Cgroup,
+    logging::log_message,
+    probe::ServiceProbes,
+    process::{service_cwd, spawn_service},
+    service::Service,
 };
 
 use super::{locks::lock_recover, logger::pipe_child_outputs, readiness::wait_ready};
 
+/// What the supervisor does once a restart attempt is over
+pub(crate) enum RestartOutcome {
+    /// Supervise this process next
+    Supervise(ChildHandle),
+    /// The service was stopped on purpose while restarting; whoever stopped
+    /// it starts it again
+    Stopped,
+    /// The service stays down (shutdown, invalid probes)
+    Finished,
+}
+
+/// Restarts the exited `previous` process. Its map entry is only replaced
+/// while it is still unclaimed by `stop_service`, under the map lock, so a
+/// service stopped meanwhile is never started twice.
 pub(crate) fn handle_restart(
     svc: &Service,
+    previous: &ChildHandle,
     running: &Arc<Mutex<HashMap<String, ChildHandle>>>,
     shutting_down: &Arc<Mutex<bool>>,
     workspace_root: &Path,
     bin_path: &Path,
     startup_timeout: Duration,
-    restarts: &mut u32,
-) -> bool {
-    if svc.restart_max != 0 && *restarts >= svc.restart_max {
-        log_message(
-            &format!("[{}] restart limit reached ({})", svc.name, svc.restart_max),
-            true,
-            &svc.name,
-        );
-        lock_recover(running, "running map").remove(&svc.name);
-        return false;
-    }
+) -> RestartOutcome {
+    loop {
+        thread::sleep(Duration::from_millis(svc.restart_backoff_ms));
+
+        let is_shutting_down = {
+            let g = lock_recover(shutting_down, "shutting_down");
+            *g
+        };
+        if is_shutting_down {
+            return RestartOutcome::Finished;
+        }
+        if previous.stop_requested.load(Ordering::SeqCst) {
+            return RestartOutcome::Stopped;
+        }
 
-    thread::sleep(Duration::from_millis(svc.restart_backoff_ms));
+        let probes = match ServiceProbes::for_service(svc) {
+            Ok(p) => p,
+            Err(e) => {
+                log_message(&format!("[{}] {:#}", svc.name, e), true, &svc.name);
+                let mut map = lock_recover(running, "running map");
+                if previous.stop_requested.load(Ordering::SeqCst) {
+                    return RestartOutcome::Stopped;
+                }
+                map.remove(&svc.name);
+                return RestartOutcome::Finished;
+            }
+        };
 
-    let is_shutting_down = {
-        let g = lock_recover(shutting_down, "shutting_down");
-        *g
-    };
-    if is_shutting_down {
-        return false;
-    }
+        let mut cgroup = Cgroup::for_service(svc);
+        let child = match spawn_service(svc, workspace_root, bin_path, &mut cgroup, false) {
+            Ok(c) => c,
+            Err(e) => {
+                log_message(
+                    &format!("[{}] restart spawn failed: {:?}", svc.name, e),
+                    true,
+                    &svc.name,
+                );
+                continue; // Retry after the next backoff
+            }
+        };
 
-    let child = match spawn_service(svc, workspace_root, bin_path, false) {
-        Ok(c) => {
-            *restarts += 1; // Increment only after a successful spawn
-            c
+        let handle = ChildHandle::new(child, probes, cgroup);
+        let claimed = {
+            let mut map = lock_recover(running, "running map");
+            let claimed = previous.stop_requested.load(Ordering::SeqCst);
+            if !claimed {
+                map.insert(svc.name.clone(), handle.clone());
+            }
+            claimed
+        };
+        if claimed {
+            // Stopped while this process was starting: drop it
+            let mut child = lock_recover(&handle.child, "child");
+            let _ = child.kill();
+            let _ = child.wait();
+            return RestartOutcome::Stopped;
         }
-        Err(e) => {
+
+        pipe_child_outputs(svc.name.clone(), handle.clone());
+
+        let cwd = service_cwd(svc, workspace_root);
+        if let Err(e) = wait_ready(svc, &handle, &cwd, startup_timeout) {
             log_message(
-                &format!("[{}] restart spawn failed: {:?}", svc.name, e),
+                &format!("[{}] not ready after restart: {}", svc.name, e),
                 true,
                 &svc.name,
             );
-            return true; // Retry will occur in the next loop iteration
+            // Its exit is then handled by the supervisor like a crash
+            let mut child = lock_recover(&handle.child, "child");
+            let _ = child.kill();
         }
-    };
-
-    let handle = ChildHandle {
-        child: Arc::new(Mutex::new(child)),
-    };
-    lock_recover(running, "running map").insert(svc.name.clone(), handle.clone());
-
-    pipe_child_outputs(svc.name.clone(), handle.clone());
 
-    if let Err(e) = wait_ready(svc, startup_timeout) {
-        log_message(
-            &format!("[{}] not ready after restart: {}", svc.name, e),
-            true,
-            &svc.name,
-        );
-        {
-            let mut map = lock_recover(running, "running map");
-            map.remove(&svc.name); // Remove immediately after failure
-        }
-        let mut child = lock_recover(&handle.child, "child");
-        let _ = child.kill();
-        return true;
+        return RestartOutcome::Supervise(handle);
     }
-
-    true
 }
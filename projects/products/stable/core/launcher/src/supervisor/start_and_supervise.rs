@@ -11,8 +11,10 @@ use anyhow::Result;
 use crate::{
     child_handle::ChildHandle,
     entry::Paths,
+    limits::Cgroup,
     logging::log_message,
-    process::{resolve_bin_path, spawn_service},
+    probe::ServiceProbes,
+    process::{resolve_bin_path, service_cwd, spawn_service},
     service::Service,
 };
 
@@ -39,16 +41,20 @@ pub(crate) fn start_and_supervise(
     dry_run: bool,
 ) -> Result<()> {
     let bin_path = resolve_bin_path(&paths.workspace.root, &paths.profile_dir, &svc.bin);
+    let probes = ServiceProbes::for_service(&svc)?;
+    let mut cgroup = if dry_run {
+        None
+    } else {
+        Cgroup::for_service(&svc)
+    };
 
     // initial spawn
-    let child = spawn_service(&svc, &paths.workspace.root, &bin_path, dry_run)?;
+    let child = spawn_service(&svc, &paths.workspace.root, &bin_path, &mut cgroup, dry_run)?;
     if dry_run {
         return Ok(());
     }
 
-    let handle = ChildHandle {
-        child: Arc::new(Mutex::new(child)),
-    };
+    let handle = ChildHandle::new(child, probes, cgroup);
 
     // Insert into running map and drop lock immediately
     {
@@ -60,7 +66,8 @@ pub(crate) fn start_and_supervise(
     pipe_child_outputs(svc.name.clone(), handle.clone());
 
     // readiness
-    if let Err(e) = wait_ready(&svc, startup_timeout) {
+    let cwd = service_cwd(&svc, &paths.workspace.root);
+    if let Err(e) = wait_ready(&svc, &handle, &cwd, startup_timeout) {
         log_message(&format!("failed to become ready: {}", e), true, &svc.name);
         let mut running_map = lock_recover(&running, "running map");
         running_map.remove(&svc.name);
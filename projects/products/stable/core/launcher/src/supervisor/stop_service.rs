@@ -0,0 +1,42 @@
+// projects/products/stable/core/launcher/src/supervisor/stop_service.rs
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, atomic::Ordering},
+};
+
+use crate::{child_handle::ChildHandle, logging::log_message};
+
+use super::locks::{lock_recover, lock_recover_arc};
+
+/// Stops a running service without triggering its restart policy.
+///
+/// # Arguments
+/// * `name` - The name of the service to stop.
+/// * `running` - A shared map of running services and their handles.
+///
+/// # Returns
+/// `false` if the service was not running.
+pub(crate) fn stop_service(name: &str, running: &Arc<Mutex<HashMap<String, ChildHandle>>>) -> bool {
+    // Set under the map lock: the supervisor decides whether to restart
+    // an exited process under the same lock
+    let handle = {
+        let mut map = lock_recover(running, "running map");
+        let Some(handle) = map.remove(name) else {
+            return false;
+        };
+        handle.stop_requested.store(true, Ordering::SeqCst);
+        handle
+    };
+
+    let mut child = lock_recover_arc(&handle.child, "child");
+    let _ = child.kill();
+    match child.wait() {
+        Ok(status) => log_message(
+            &format!("stopped (code={})", status.code().unwrap_or(-1)),
+            false,
+            name,
+        ),
+        Err(e) => log_message(&format!("wait after stop failed: {}", e), true, name),
+    }
+    true
+}
@@ -1,20 +1,25 @@
 // projects/products/stable/core/launcher/src/supervisor/supervise_loop.rs
 use std::{
     collections::HashMap,
-    path::PathBuf,
-    sync::{Arc, Mutex},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, atomic::Ordering},
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use crate::{
-    child_handle::ChildHandle, logging::log_message, restart_policy::RestartPolicy,
+    child_handle::ChildHandle,
+    limits::{Cgroup, limit_exit_reason},
+    logging::log_message,
+    process::service_cwd,
+    restart_policy::RestartPolicy,
     service::Service,
 };
 
 use super::{
+    child_monitors::ChildMonitors,
     locks::{lock_recover, lock_recover_arc},
-    restart::handle_restart,
+    restart::{RestartOutcome, handle_restart},
 };
 
 // Final improvements: block-based `is_shutting_down` and strict ready fail policy
@@ -26,97 +31,143 @@ pub(crate) fn supervise_loop(
     shutting_down: Arc<Mutex<bool>>,
     startup_timeout: Duration,
 ) {
+    let mut cgroup = None;
+    let stopped_for_good = supervise(
+        &svc,
+        &workspace_root,
+        &bin_path,
+        &running,
+        &shutting_down,
+        startup_timeout,
+        &mut cgroup,
+    );
+    // A service stopped on purpose is started again by whoever stopped it
+    if stopped_for_good
+        && let Some(cgroup) = cgroup
+        && let Err(e) = cgroup.remove()
+    {
+        log_message(&format!("{:#}", e), true, &svc.name);
+    }
+}
+
+/// Supervises `svc` until it stops; returns `false` when it was stopped on
+/// purpose. `cgroup` is left with the cgroup of the last process.
+fn supervise(
+    svc: &Service,
+    workspace_root: &Path,
+    bin_path: &Path,
+    running: &Arc<Mutex<HashMap<String, ChildHandle>>>,
+    shutting_down: &Arc<Mutex<bool>>,
+    startup_timeout: Duration,
+    cgroup: &mut Option<Arc<Cgroup>>,
+) -> bool {
     let mut restarts: u32 = 0;
+    let cwd = service_cwd(svc, workspace_root);
+
+    let Some(mut child) = lock_recover(running, "running map").get(&svc.name).cloned() else {
+        return false;
+    };
 
     loop {
-        if *lock_recover(&shutting_down, "shutting_down") {
-            // Optionally kill the process if shutting down
-            if let Some(h) = {
-                let map = lock_recover(&running, "running map");
-                map.get(&svc.name).cloned()
-            } {
-                let mut c = lock_recover_arc(&h.child, "child");
+        cgroup.clone_from(&child.cgroup);
+        let mut monitors = ChildMonitors::new(svc, &child, &cwd, Instant::now());
+        loop {
+            if *lock_recover(shutting_down, "shutting_down") {
+                // Kill the process if shutting down
+                let mut c = lock_recover_arc(&child.child, "child");
                 let _ = c.kill();
+                let _ = c.wait();
+                return true;
             }
-            return;
-        }
 
-        let child = {
-            let map = lock_recover(&running, "running map");
-            map.get(&svc.name).cloned()
-        };
+            let mut child_guard = lock_recover_arc(&child.child, "child");
+            match child_guard.try_wait() {
+                Ok(Some(status)) => {
+                    drop(child_guard);
+                    let code: i32 = status.code().unwrap_or(-1);
+                    let success = status.success();
+                    let should_restart = match svc.restart {
+                        RestartPolicy::Never => false,
+                        RestartPolicy::Always => true,
+                        RestartPolicy::OnFailure => !success,
+                    };
+                    let limit_reached = svc.restart_max != 0 && restarts >= svc.restart_max;
 
-        if let Some(child) = child {
-            loop {
-                if *lock_recover(&shutting_down, "shutting_down") {
-                    // Kill the process if shutting down
-                    let mut c = lock_recover_arc(&child.child, "child");
-                    let _ = c.kill();
-                    return;
-                }
-
-                let mut child_guard = lock_recover_arc(&child.child, "child");
-                match child_guard.try_wait() {
-                    Ok(Some(status)) => {
-                        {
-                            let mut map = lock_recover(&running, "running map");
+                    // `stop_service` sets `stop_requested` under the map
+                    // lock, so a stop either happened before this point or
+                    // finds the exited handle still in the map and claims it
+                    {
+                        let mut map = lock_recover(running, "running map");
+                        if child.stop_requested.load(Ordering::SeqCst) {
+                            // Stopped on purpose (dev mode reload); whoever
+                            // stopped it owns the map entry from now on
+                            return false;
+                        }
+                        if !should_restart || limit_reached {
                             map.remove(&svc.name);
                         }
-                        let code: i32 = status.code().unwrap_or(-1);
-                        let success = status.success();
-                        log_message(&format!("exited (code={})", code), true, &svc.name);
-
-                        let should_restart = match svc.restart {
-                            RestartPolicy::Never => false,
-                            RestartPolicy::Always => true,
-                            RestartPolicy::OnFailure => !success,
-                        };
+                    }
 
-                        if !should_restart {
-                            return;
-                        }
-                        break;
+                    log_message(&format!("exited (code={})", code), true, &svc.name);
+                    if let Some(reason) = limit_exit_reason(&status, child.cgroup.as_deref()) {
+                        log_message(
+                            &format!("resource limit exceeded: {}", reason),
+                            true,
+                            &svc.name,
+                        );
                     }
-                    Ok(None) => {
-                        drop(child_guard);
-                        thread::sleep(Duration::from_millis(100));
+                    if !should_restart {
+                        return true;
                     }
-                    Err(e) => {
+                    if limit_reached {
                         log_message(
-                            &format!("[{}] try_wait() failed: {}", svc.name, e),
+                            &format!("[{}] restart limit reached ({})", svc.name, svc.restart_max),
                             true,
                             &svc.name,
                         );
-                        {
-                            let mut map = lock_recover(&running, "running map");
-                            map.remove(&svc.name);
-                        }
-                        return;
+                        return true;
                     }
+                    break;
+                }
+                Ok(None) => {
+                    drop(child_guard);
+                    // A failed liveness probe or an exceeded limit kills
+                    // the child; its exit is then handled like a crash
+                    monitors.poll(svc, &child);
+                    thread::sleep(Duration::from_millis(100));
+                }
+                Err(e) => {
+                    log_message(
+                        &format!("[{}] try_wait() failed: {}", svc.name, e),
+                        true,
+                        &svc.name,
+                    );
+                    let mut map = lock_recover(running, "running map");
+                    if child.stop_requested.load(Ordering::SeqCst) {
+                        return false;
+                    }
+                    map.remove(&svc.name);
+                    return true;
                 }
             }
         }
+        drop(monitors);
 
-        // Added a condition to block restart when RestartPolicy is Never
-        if matches!(svc.restart, RestartPolicy::Never) {
-            log_message(
-                "not running and restart policy is Never; stopping supervisor",
-                true,
-                &svc.name,
-            );
-            return;
-        }
-
-        if !handle_restart(
-            &svc,
-            &running,
-            &shutting_down,
-            workspace_root.as_path(),
-            bin_path.as_path(),
+        match handle_restart(
+            svc,
+            &child,
+            running,
+            shutting_down,
+            workspace_root,
+            bin_path,
             startup_timeout,
-            &mut restarts,
         ) {
-            return;
+            RestartOutcome::Supervise(next) => {
+                restarts += 1;
+                child = next;
+            }
+            RestartOutcome::Stopped => return false,
+            RestartOutcome::Finished => return true,
         }
     }
 }
@@ -0,0 +1,42 @@
+// projects/products/stable/core/watcher/src/checks/engine_check.rs
+use reqwest::Client;
+use serde::Deserialize;
+
+/// Body of the engine `GET /health` route
+#[derive(Debug, Deserialize)]
+struct EngineHealth {
+    ok: bool,
+    service: String,
+}
+
+/// Unlike a plain HTTP check, also fails when another service answers on the port
+pub(super) async fn engine_is_healthy(
+    client: &Client,
+    url: &str,
+    service: &str,
+) -> Result<(), String> {
+    let url = format!("{}/health", url.trim_end_matches('/'));
+    let resp = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("GET {} failed: {}", url, e))?;
+    if !resp.status().is_success() {
+        return Err(format!("GET {} returned {}", url, resp.status()));
+    }
+
+    let health: EngineHealth = resp
+        .json()
+        .await
+        .map_err(|e| format!("GET {} returned an invalid health body: {}", url, e))?;
+    if health.service != service {
+        return Err(format!(
+            "GET {} answered for service '{}', expected '{}'",
+            url, health.service, service
+        ));
+    }
+    if !health.ok {
+        return Err(format!("{} reports ok=false", service));
+    }
+    Ok(())
+}
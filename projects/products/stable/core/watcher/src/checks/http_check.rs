@@ -0,0 +1,10 @@
+// projects/products/stable/core/watcher/src/checks/http_check.rs
+use reqwest::Client;
+
+pub(super) async fn http_is_ok(client: &Client, url: &str) -> Result<(), String> {
+    match client.get(url).send().await {
+        Ok(resp) if resp.status().is_success() => Ok(()),
+        Ok(resp) => Err(format!("GET {} returned {}", url, resp.status())),
+        Err(e) => Err(format!("GET {} failed: {}", url, e)),
+    }
+}
@@ -0,0 +1,38 @@
+// projects/products/stable/core/watcher/src/checks/mod.rs
+mod engine_check;
+mod http_check;
+mod process_check;
+mod script_check;
+mod systemd_check;
+mod tcp_check;
+
+use reqwest::Client;
+
+use crate::config::PingConfig;
+
+/// Runs the configured health check; `Err` carries the failure reason
+pub(crate) async fn run_check(client: &Client, ping: &PingConfig) -> Result<(), String> {
+    match ping {
+        // "always alive" to never trigger a restart
+        PingConfig::Disabled => Ok(()),
+
+        PingConfig::Systemd { unit } => systemd_check::systemd_is_active(unit).await,
+
+        PingConfig::Process { process_name } => {
+            process_check::process_is_running(process_name).await
+        }
+
+        PingConfig::Http { url } => http_check::http_is_ok(client, url).await,
+
+        PingConfig::Tcp { addr } => tcp_check::tcp_accepts(addr).await,
+
+        PingConfig::Script {
+            command,
+            timeout_secs,
+        } => script_check::script_succeeds(command, *timeout_secs).await,
+
+        PingConfig::Engine { url, service } => {
+            engine_check::engine_is_healthy(client, url, service).await
+        }
+    }
+}
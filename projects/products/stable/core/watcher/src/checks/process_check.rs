@@ -0,0 +1,18 @@
+// projects/products/stable/core/watcher/src/checks/process_check.rs
+use tokio::process::Command;
+
+const PGREP: &str = "pgrep";
+
+pub(super) async fn process_is_running(process_name: &str) -> Result<(), String> {
+    // pgrep -x <process_name> => non-empty stdout if found
+    match Command::new(PGREP)
+        .arg("-x")
+        .arg(process_name)
+        .output()
+        .await
+    {
+        Ok(out) if out.status.success() && !out.stdout.is_empty() => Ok(()),
+        Ok(_) => Err(format!("no process named {}", process_name)),
+        Err(e) => Err(format!("pgrep failed for {}: {}", process_name, e)),
+    }
+}
@@ -0,0 +1,32 @@
+// projects/products/stable/core/watcher/src/checks/script_check.rs
+use std::{process::Stdio, time::Duration};
+
+use tokio::{process::Command, time::timeout};
+
+pub(super) async fn script_succeeds(command: &[String], timeout_secs: u64) -> Result<(), String> {
+    let Some((program, args)) = command.split_first() else {
+        return Err("empty script command".to_string());
+    };
+
+    let child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        // Dropped on timeout: the script must not outlive its check
+        .kill_on_drop(true)
+        .output();
+
+    match timeout(Duration::from_secs(timeout_secs), child).await {
+        Ok(Ok(out)) if out.status.success() => Ok(()),
+        Ok(Ok(out)) => {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            match stderr.lines().rev().find(|l| !l.trim().is_empty()) {
+                Some(line) => Err(format!("{} exited with {}: {}", program, out.status, line)),
+                None => Err(format!("{} exited with {}", program, out.status)),
+            }
+        }
+        Ok(Err(e)) => Err(format!("{} failed to start: {}", program, e)),
+        Err(_) => Err(format!("{} timed out after {}s", program, timeout_secs)),
+    }
+}
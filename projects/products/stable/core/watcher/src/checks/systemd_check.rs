@@ -0,0 +1,19 @@
+// projects/products/stable/core/watcher/src/checks/systemd_check.rs
+use tokio::process::Command;
+
+const SYSTEMCTL: &str = "systemctl";
+
+pub(super) async fn systemd_is_active(unit: &str) -> Result<(), String> {
+    // systemctl is-active --quiet <unit> => exit code 0 if active
+    match Command::new(SYSTEMCTL)
+        .arg("is-active")
+        .arg("--quiet")
+        .arg(unit)
+        .output()
+        .await
+    {
+        Ok(out) if out.status.success() => Ok(()),
+        Ok(_) => Err(format!("unit {} is not active", unit)),
+        Err(e) => Err(format!("systemctl is-active {} failed: {}", unit, e)),
+    }
+}
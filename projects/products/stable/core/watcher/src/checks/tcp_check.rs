@@ -0,0 +1,22 @@
+// projects/products/stable/core/watcher/src/checks/tcp_check.rs
+use std::time::Duration;
+
+use tokio::{net::TcpStream, time::timeout};
+
+const TCP_CONNECT_TIMEOUT_SECS: u64 = 2;
+
+pub(super) async fn tcp_accepts(addr: &str) -> Result<(), String> {
+    match timeout(
+        Duration::from_secs(TCP_CONNECT_TIMEOUT_SECS),
+        TcpStream::connect(addr),
+    )
+    .await
+    {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => Err(format!("connect {} failed: {}", addr, e)),
+        Err(_) => Err(format!(
+            "connect {} timed out after {}s",
+            addr, TCP_CONNECT_TIMEOUT_SECS
+        )),
+    }
+}
@@ -1,11 +1,12 @@
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
-use super::{PingConfig, RestartConfig};
+use super::{EscalationConfig, FlappingConfig, PingConfig, RestartConfig};
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub(crate) struct ComponentConfig {
-    /// Logical name (for logs, UI, etc.)
+    /// Logical name (for logs, UI, etc.), trimmed
+    #[serde(deserialize_with = "trimmed")]
     pub(crate) name: String,
 
     /// Interval between two checks (seconds)
@@ -19,8 +20,32 @@ pub(crate) struct ComponentConfig {
     /// How to restart the component
     #[serde(default)]
     pub(crate) restart: RestartConfig,
+
+    /// Components this one depends on (restarted with `restart_dependents`), trimmed
+    #[serde(default, deserialize_with = "trimmed_list")]
+    pub(crate) depends_on: Vec<String>,
+
+    /// What to do, in order, while the component keeps failing
+    #[serde(default)]
+    pub(crate) escalation: EscalationConfig,
+
+    /// Flapping detection (disabled if absent)
+    #[serde(default)]
+    pub(crate) flapping: Option<FlappingConfig>,
 }
 
 fn default_ping_interval() -> u64 {
     5
 }
+
+// Names are trimmed once here, so validation and lookups compare the same strings
+fn trimmed<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(String::deserialize(deserializer)?.trim().to_string())
+}
+
+fn trimmed_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    Ok(Vec::<String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|name| name.trim().to_string())
+        .collect())
+}
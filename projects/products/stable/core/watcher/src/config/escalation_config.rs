@@ -0,0 +1,37 @@
+use serde::Deserialize;
+
+use super::EscalationStep;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct EscalationConfig {
+    /// Steps applied in order while the component keeps failing
+    #[serde(default = "default_steps")]
+    pub(crate) steps: Vec<EscalationStep>,
+
+    /// Alert hook (program + args) run by the `alert` step and on recovery
+    #[serde(default)]
+    pub(crate) alert_command: Option<Vec<String>>,
+
+    /// Max duration of one alert hook run (seconds)
+    #[serde(default = "default_alert_timeout")]
+    pub(crate) alert_timeout_secs: u64,
+}
+
+impl Default for EscalationConfig {
+    fn default() -> Self {
+        Self {
+            steps: default_steps(),
+            alert_command: None,
+            alert_timeout_secs: default_alert_timeout(),
+        }
+    }
+}
+
+fn default_steps() -> Vec<EscalationStep> {
+    vec![EscalationStep::Restart]
+}
+
+fn default_alert_timeout() -> u64 {
+    30
+}
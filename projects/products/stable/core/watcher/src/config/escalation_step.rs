@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+/// One step of an escalation chain, applied while a component keeps failing
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EscalationStep {
+    /// Restart the component (repeated up to `restart.max_consecutive_restarts`)
+    Restart,
+    /// Restart the component, then every component depending on it
+    RestartDependents,
+    /// Flag the component as degraded in the status snapshot
+    MarkDegraded,
+    /// Run the `alert_command` hook
+    Alert,
+}
+
+impl EscalationStep {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            EscalationStep::Restart => "restart",
+            EscalationStep::RestartDependents => "restart_dependents",
+            EscalationStep::MarkDegraded => "mark_degraded",
+            EscalationStep::Alert => "alert",
+        }
+    }
+
+    pub(crate) fn is_restart(&self) -> bool {
+        matches!(
+            self,
+            EscalationStep::Restart | EscalationStep::RestartDependents
+        )
+    }
+}
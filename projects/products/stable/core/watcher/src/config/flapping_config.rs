@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct FlappingConfig {
+    /// Sliding window in which failures are counted (seconds)
+    #[serde(default = "default_window")]
+    pub(crate) window_secs: u64,
+
+    /// Number of healthy -> failing transitions within the window that marks the component as flapping
+    #[serde(default = "default_max_transitions")]
+    pub(crate) max_transitions: u32,
+}
+
+fn default_window() -> u64 {
+    300
+}
+
+fn default_max_transitions() -> u32 {
+    5
+}
@@ -1,14 +1,22 @@
 // projects/products/stable/core/watcher/src/config/mod.rs
 mod component_config;
+mod escalation_config;
+mod escalation_step;
+mod flapping_config;
 mod logging;
 mod ping_config;
 mod restart_config;
 mod restart_policy;
+mod status_config;
 mod watcher_config;
 
 pub(crate) use component_config::ComponentConfig;
+pub(crate) use escalation_config::EscalationConfig;
+pub(crate) use escalation_step::EscalationStep;
+pub(crate) use flapping_config::FlappingConfig;
 pub(crate) use logging::{LoggingConfig, initialize_logger};
 pub(crate) use ping_config::PingConfig;
 pub(crate) use restart_config::RestartConfig;
 pub(crate) use restart_policy::RestartPolicy;
+pub(crate) use status_config::StatusConfig;
 pub(crate) use watcher_config::WatcherConfig;
@@ -12,6 +12,23 @@ pub(crate) enum PingConfig {
     /// process: check via `pgrep -x <process_name>` (dev/fallback)
     Process { process_name: String },
 
+    /// TCP: the component accepts connections on `addr` (host:port)
+    Tcp { addr: String },
+
+    /// script: runs `command` (program + args), healthy if it exits with 0
+    Script {
+        command: Vec<String>,
+        #[serde(default = "default_script_timeout")]
+        timeout_secs: u64,
+    },
+
+    /// engine protocol: GET `<url>/health` must answer `{"ok": true, "service": <service>}`
+    Engine {
+        url: String,
+        #[serde(default = "default_engine_service")]
+        service: String,
+    },
+
     /// Disables ping (not recommended, but useful during bootstrap)
     Disabled,
 }
@@ -24,3 +41,11 @@ impl Default for PingConfig {
         }
     }
 }
+
+fn default_script_timeout() -> u64 {
+    10
+}
+
+fn default_engine_service() -> String {
+    "engine".to_string()
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct StatusConfig {
+    /// JSON snapshot of every component, rewritten on each state change
+    #[serde(default)]
+    pub(crate) file: Option<PathBuf>,
+
+    /// Serves the same snapshot on `GET /status` (host:port)
+    #[serde(default)]
+    pub(crate) http_addr: Option<String>,
+}
@@ -1,8 +1,15 @@
 use anyhow::bail;
 use serde::Deserialize;
-use std::{fs, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    net::SocketAddr,
+    path::Path,
+};
 
-use super::{ComponentConfig, LoggingConfig, PingConfig, RestartPolicy};
+use super::{
+    ComponentConfig, EscalationStep, LoggingConfig, PingConfig, RestartPolicy, StatusConfig,
+};
 
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -12,6 +19,9 @@ pub(crate) struct WatcherConfig {
 
     #[serde(default)]
     pub(crate) logging: LoggingConfig,
+
+    #[serde(default)]
+    pub(crate) status: StatusConfig,
 }
 
 impl WatcherConfig {
@@ -37,11 +47,15 @@ impl WatcherConfig {
 
     pub(crate) fn validate(&self) -> anyhow::Result<()> {
         // ---- components ----
+        let mut names = HashSet::new();
         for c in &self.components {
-            let name = c.name.trim();
+            let name = c.name.as_str();
             if name.is_empty() {
                 bail!("Component name cannot be empty");
             }
+            if !names.insert(name) {
+                bail!("Duplicate component name '{}'", c.name);
+            }
 
             if c.ping_interval == 0 {
                 bail!("Ping interval for component '{}' must be > 0", c.name);
@@ -82,9 +96,77 @@ impl WatcherConfig {
                         );
                     }
                 }
+                PingConfig::Tcp { addr } => {
+                    if addr.trim().is_empty() {
+                        bail!("ping.tcp.addr is empty for component '{}'", c.name);
+                    }
+                }
+                PingConfig::Script {
+                    command,
+                    timeout_secs,
+                } => {
+                    if command.first().is_none_or(|p| p.trim().is_empty()) {
+                        bail!("ping.script.command is empty for component '{}'", c.name);
+                    }
+                    if *timeout_secs == 0 {
+                        bail!(
+                            "ping.script.timeout_secs must be > 0 for component '{}'",
+                            c.name
+                        );
+                    }
+                }
+                PingConfig::Engine { url, service } => {
+                    let u = url.trim();
+                    if !(u.starts_with("http://") || u.starts_with("https://")) {
+                        bail!(
+                            "ping.engine.url must start with http:// or https:// for component '{}'",
+                            c.name
+                        );
+                    }
+                    if service.trim().is_empty() {
+                        bail!("ping.engine.service is empty for component '{}'", c.name);
+                    }
+                }
                 PingConfig::Disabled => {}
             }
 
+            // Escalation
+            if c.escalation.steps.is_empty() {
+                bail!("escalation.steps is empty for component '{}'", c.name);
+            }
+            if let Some(command) = &c.escalation.alert_command
+                && command.first().is_none_or(|p| p.trim().is_empty())
+            {
+                bail!(
+                    "escalation.alert_command is empty for component '{}'",
+                    c.name
+                );
+            }
+            if c.escalation.steps.contains(&EscalationStep::Alert)
+                && c.escalation.alert_command.is_none()
+            {
+                bail!(
+                    "escalation step 'alert' requires escalation.alert_command for component '{}'",
+                    c.name
+                );
+            }
+            if c.escalation.alert_timeout_secs == 0 {
+                bail!(
+                    "escalation.alert_timeout_secs must be > 0 for component '{}'",
+                    c.name
+                );
+            }
+
+            // Flapping
+            if let Some(flapping) = &c.flapping
+                && (flapping.window_secs == 0 || flapping.max_transitions == 0)
+            {
+                bail!(
+                    "flapping.window_secs and flapping.max_transitions must be > 0 for component '{}'",
+                    c.name
+                );
+            }
+
             // Policy consistency
             if matches!(c.restart.policy, RestartPolicy::Never)
                 && (c.restart.max_consecutive_restarts.unwrap_or(0) > 0)
@@ -94,6 +176,15 @@ impl WatcherConfig {
             }
         }
 
+        self.validate_dependencies()?;
+
+        // ---- status ----
+        if let Some(addr) = &self.status.http_addr
+            && addr.parse::<SocketAddr>().is_err()
+        {
+            bail!("status.http_addr '{}' is not a valid host:port", addr);
+        }
+
         // ---- logging ----
         if self.logging.log_file.as_os_str().is_empty() {
             bail!("Log file path cannot be empty");
@@ -101,4 +192,78 @@ impl WatcherConfig {
 
         Ok(())
     }
+
+    /// `depends_on` must name known components, without cycles
+    fn validate_dependencies(&self) -> anyhow::Result<()> {
+        let by_name: HashMap<&str, &ComponentConfig> = self
+            .components
+            .iter()
+            .map(|c| (c.name.as_str(), c))
+            .collect();
+
+        for c in &self.components {
+            for dep in &c.depends_on {
+                if dep == &c.name {
+                    bail!("Component '{}' cannot depend on itself", c.name);
+                }
+                if !by_name.contains_key(dep.as_str()) {
+                    bail!(
+                        "Component '{}' depends on unknown component '{}'",
+                        c.name,
+                        dep
+                    );
+                }
+            }
+        }
+
+        // Depth-first walk from every component; a dependency already on the path is a cycle
+        fn visit<'a>(
+            name: &'a str,
+            by_name: &HashMap<&'a str, &'a ComponentConfig>,
+            path: &mut Vec<&'a str>,
+            done: &mut HashSet<&'a str>,
+        ) -> anyhow::Result<()> {
+            if done.contains(name) {
+                return Ok(());
+            }
+            if path.contains(&name) {
+                bail!(
+                    "Dependency cycle between components: {} -> {}",
+                    path.join(" -> "),
+                    name
+                );
+            }
+            path.push(name);
+            for dep in &by_name[name].depends_on {
+                visit(dep, by_name, path, done)?;
+            }
+            path.pop();
+            done.insert(name);
+            Ok(())
+        }
+
+        let mut done = HashSet::new();
+        for c in &self.components {
+            visit(&c.name, &by_name, &mut Vec::new(), &mut done)?;
+        }
+        Ok(())
+    }
+
+    /// Components that depend on `name`, directly or transitively, closest first
+    pub(crate) fn dependents_of(&self, name: &str) -> Vec<ComponentConfig> {
+        let mut found: Vec<ComponentConfig> = Vec::new();
+        let mut frontier = vec![name.to_string()];
+        while let Some(current) = frontier.pop() {
+            for c in &self.components {
+                if c.depends_on.contains(&current)
+                    && c.name != name
+                    && !found.iter().any(|f| f.name == c.name)
+                {
+                    frontier.insert(0, c.name.clone());
+                    found.push(c.clone());
+                }
+            }
+        }
+        found
+    }
 }
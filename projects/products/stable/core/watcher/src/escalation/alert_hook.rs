@@ -0,0 +1,57 @@
+// projects/products/stable/core/watcher/src/escalation/alert_hook.rs
+use std::{process::Stdio, time::Duration};
+
+use log::{info, warn};
+use tokio::{process::Command, time::timeout};
+
+/// Runs the alert hook with `env` (`WATCHER_*` variables) and logs the outcome
+pub(crate) async fn run_alert_hook(
+    component: &str,
+    command: &[String],
+    timeout_secs: u64,
+    env: &[(&str, String)],
+) -> bool {
+    let Some((program, args)) = command.split_first() else {
+        return false;
+    };
+
+    let run = Command::new(program)
+        .args(args)
+        .envs(env.iter().map(|(k, v)| (*k, v.as_str())))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .output();
+
+    match timeout(Duration::from_secs(timeout_secs), run).await {
+        Ok(Ok(out)) if out.status.success() => {
+            info!("component={} event=alert_sent hook={}", component, program);
+            true
+        }
+        Ok(Ok(out)) => {
+            warn!(
+                "component={} event=alert_failed hook={} status={} stderr=\"{}\"",
+                component,
+                program,
+                out.status,
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
+            false
+        }
+        Ok(Err(e)) => {
+            warn!(
+                "component={} event=alert_failed hook={} error=\"{}\"",
+                component, program, e
+            );
+            false
+        }
+        Err(_) => {
+            warn!(
+                "component={} event=alert_failed hook={} error=\"timed out after {}s\"",
+                component, program, timeout_secs
+            );
+            false
+        }
+    }
+}
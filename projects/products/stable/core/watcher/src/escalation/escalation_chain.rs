@@ -0,0 +1,64 @@
+// projects/products/stable/core/watcher/src/escalation/escalation_chain.rs
+use crate::config::EscalationStep;
+
+/// Position of a failing component in its escalation steps.
+///
+/// `restart` is repeated up to `max_restarts` times before moving on, every
+/// other step is applied once. The chain is reset when the component is healthy again.
+#[derive(Debug)]
+pub(crate) struct EscalationChain {
+    steps: Vec<EscalationStep>,
+    max_restarts: Option<u32>,
+    index: usize,
+    restarts_in_step: u32,
+}
+
+impl EscalationChain {
+    pub(crate) fn new(steps: Vec<EscalationStep>, max_restarts: Option<u32>) -> Self {
+        Self {
+            steps,
+            max_restarts,
+            index: 0,
+            restarts_in_step: 0,
+        }
+    }
+
+    /// Step to apply for a new failed check, `None` once every step was applied.
+    /// Restart steps are passed over when `allow_restarts` is false.
+    pub(crate) fn next_step(&mut self, allow_restarts: bool) -> Option<EscalationStep> {
+        while let Some(&step) = self.steps.get(self.index) {
+            if step.is_restart() && !allow_restarts {
+                self.advance();
+                continue;
+            }
+            if step == EscalationStep::Restart {
+                if let Some(max) = self.max_restarts
+                    && self.restarts_in_step >= max
+                {
+                    self.advance();
+                    continue;
+                }
+                self.restarts_in_step += 1;
+                return Some(step);
+            }
+            self.advance();
+            return Some(step);
+        }
+        None
+    }
+
+    /// True if the chain restarts the component at some point
+    pub(crate) fn has_restarts(&self) -> bool {
+        self.steps.iter().any(EscalationStep::is_restart)
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.index = 0;
+        self.restarts_in_step = 0;
+    }
+
+    fn advance(&mut self) {
+        self.index += 1;
+        self.restarts_in_step = 0;
+    }
+}
@@ -0,0 +1,36 @@
+// projects/products/stable/core/watcher/src/escalation/flap_detector.rs
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::config::FlappingConfig;
+
+/// Counts healthy -> failing transitions in a sliding window
+#[derive(Debug)]
+pub(crate) struct FlapDetector {
+    window: Duration,
+    max_transitions: usize,
+    transitions: VecDeque<Instant>,
+}
+
+impl FlapDetector {
+    pub(crate) fn new(config: &FlappingConfig) -> Self {
+        Self {
+            window: Duration::from_secs(config.window_secs),
+            max_transitions: config.max_transitions as usize,
+            transitions: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn record_transition(&mut self, now: Instant) {
+        self.transitions.push_back(now);
+    }
+
+    pub(crate) fn is_flapping(&mut self, now: Instant) -> bool {
+        while let Some(&oldest) = self.transitions.front()
+            && now.duration_since(oldest) > self.window
+        {
+            self.transitions.pop_front();
+        }
+        self.transitions.len() >= self.max_transitions
+    }
+}
@@ -0,0 +1,8 @@
+// projects/products/stable/core/watcher/src/escalation/mod.rs
+mod alert_hook;
+mod escalation_chain;
+mod flap_detector;
+
+pub(crate) use alert_hook::run_alert_hook;
+pub(crate) use escalation_chain::EscalationChain;
+pub(crate) use flap_detector::FlapDetector;
@@ -1,5 +1,8 @@
 // projects/products/stable/core/watcher/src/main.rs
+mod checks;
 mod config;
+mod escalation;
+mod status;
 mod supervisor;
 
 use std::sync::Arc;
@@ -9,6 +12,7 @@ use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 
 use crate::config::{WatcherConfig, initialize_logger};
+use crate::status::StatusBoard;
 
 fn env_var(key: &str) -> Option<String> {
     std::env::var(key)
@@ -43,9 +47,23 @@ async fn main() -> anyhow::Result<()> {
     // 3) Cancellation: allows for a clean shutdown
     let shutdown = CancellationToken::new();
 
-    // 4) Launch all supervisions
+    // 4) Status snapshot (file and/or HTTP endpoint)
+    let board = StatusBoard::new(
+        config.components.iter().map(|c| c.name.as_str()),
+        config.status.file.clone(),
+    );
+    if let Some(addr) = &config.status.http_addr {
+        let addr = addr
+            .parse()
+            .with_context(|| format!("Invalid status.http_addr '{}'", addr))?;
+        status::spawn_status_server(addr, board.clone(), shutdown.clone())
+            .await
+            .with_context(|| format!("Failed to bind status endpoint on {}", addr))?;
+    }
+
+    // 5) Launch all supervisions
     let mut set = JoinSet::new();
-    let supervisor = supervisor::Supervisor::new();
+    let supervisor = supervisor::Supervisor::new(config.clone(), board);
 
     for component in config.components.clone() {
         let shutdown = shutdown.clone();
@@ -55,7 +73,7 @@ async fn main() -> anyhow::Result<()> {
         });
     }
 
-    // 5) Wait for Ctrl+C (or external shutdown)
+    // 6) Wait for Ctrl+C (or external shutdown)
     tokio::select! {
         _ = tokio::signal::ctrl_c() => {
             log::warn!("Watcher: Ctrl+C received, shutting down...");
@@ -70,7 +88,7 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
-    // 6) Trigger shutdown, then wait for tasks to finish (with timeout)
+    // 7) Trigger shutdown, then wait for tasks to finish (with timeout)
     shutdown.cancel();
 
     let grace = tokio::time::sleep(std::time::Duration::from_secs(5));
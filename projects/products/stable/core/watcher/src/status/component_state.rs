@@ -0,0 +1,24 @@
+// projects/products/stable/core/watcher/src/status/component_state.rs
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ComponentState {
+    /// No check completed yet
+    Starting,
+    Healthy,
+    Failing,
+    /// Failing, and the escalation reached `mark_degraded`
+    Degraded,
+}
+
+impl ComponentState {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ComponentState::Starting => "starting",
+            ComponentState::Healthy => "healthy",
+            ComponentState::Failing => "failing",
+            ComponentState::Degraded => "degraded",
+        }
+    }
+}
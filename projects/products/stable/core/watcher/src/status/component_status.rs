@@ -0,0 +1,71 @@
+// projects/products/stable/core/watcher/src/status/component_status.rs
+use std::collections::VecDeque;
+
+use serde::Serialize;
+
+use super::{ComponentState, RestartRecord};
+
+/// Restarts kept per component in the snapshot
+const RESTART_HISTORY_LEN: usize = 20;
+
+#[derive(Debug, Serialize, Clone)]
+pub(crate) struct ComponentStatus {
+    pub(crate) name: String,
+    pub(crate) state: ComponentState,
+    pub(crate) flapping: bool,
+    pub(crate) last_check_ms: Option<u64>,
+    pub(crate) last_ok_ms: Option<u64>,
+    pub(crate) last_error: Option<String>,
+    pub(crate) consecutive_failures: u32,
+    /// Last escalation step applied in the current failure
+    pub(crate) escalation_step: Option<String>,
+    pub(crate) restarts_total: u64,
+    pub(crate) alerts_total: u64,
+    /// Most recent last
+    pub(crate) restart_history: VecDeque<RestartRecord>,
+}
+
+impl ComponentStatus {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: ComponentState::Starting,
+            flapping: false,
+            last_check_ms: None,
+            last_ok_ms: None,
+            last_error: None,
+            consecutive_failures: 0,
+            escalation_step: None,
+            restarts_total: 0,
+            alerts_total: 0,
+            restart_history: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn record_ok(&mut self, now_ms: u64, flapping: bool) {
+        self.state = ComponentState::Healthy;
+        self.flapping = flapping;
+        self.last_check_ms = Some(now_ms);
+        self.last_ok_ms = Some(now_ms);
+        self.consecutive_failures = 0;
+        self.escalation_step = None;
+    }
+
+    pub(crate) fn record_failure(&mut self, now_ms: u64, reason: &str, flapping: bool) {
+        if self.state != ComponentState::Degraded {
+            self.state = ComponentState::Failing;
+        }
+        self.flapping = flapping;
+        self.last_check_ms = Some(now_ms);
+        self.last_error = Some(reason.to_string());
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+    }
+
+    pub(crate) fn record_restart(&mut self, record: RestartRecord) {
+        self.restarts_total += 1;
+        if self.restart_history.len() == RESTART_HISTORY_LEN {
+            self.restart_history.pop_front();
+        }
+        self.restart_history.push_back(record);
+    }
+}
@@ -0,0 +1,12 @@
+// projects/products/stable/core/watcher/src/status/mod.rs
+mod component_state;
+mod component_status;
+mod restart_record;
+mod status_board;
+mod status_server;
+
+pub(crate) use component_state::ComponentState;
+pub(crate) use component_status::ComponentStatus;
+pub(crate) use restart_record::RestartRecord;
+pub(crate) use status_board::StatusBoard;
+pub(crate) use status_server::spawn_status_server;
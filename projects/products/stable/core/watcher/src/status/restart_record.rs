@@ -0,0 +1,12 @@
+// projects/products/stable/core/watcher/src/status/restart_record.rs
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+pub(crate) struct RestartRecord {
+    pub(crate) at_ms: u64,
+    /// `escalation`, or `dependency:<name>` when restarted with a failing dependency
+    pub(crate) trigger: String,
+    pub(crate) ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) error: Option<String>,
+}
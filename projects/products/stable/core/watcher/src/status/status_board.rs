@@ -0,0 +1,101 @@
+// projects/products/stable/core/watcher/src/status/status_board.rs
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use common_time::current_timestamp_ms;
+use log::warn;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use super::ComponentStatus;
+
+#[derive(Serialize)]
+struct StatusSnapshot<'a> {
+    generated_at_ms: u64,
+    components: Vec<&'a ComponentStatus>,
+}
+
+/// State of every component, shared by the supervision tasks.
+///
+/// Every update sends a fresh snapshot to the file writer task (if a file is
+/// configured), so supervision never waits on the disk.
+#[derive(Clone)]
+pub(crate) struct StatusBoard {
+    components: Arc<Mutex<BTreeMap<String, ComponentStatus>>>,
+    snapshots: Option<Arc<watch::Sender<String>>>,
+}
+
+impl StatusBoard {
+    /// Must be called within a tokio runtime when `file` is set
+    pub(crate) fn new<'a>(names: impl IntoIterator<Item = &'a str>, file: Option<PathBuf>) -> Self {
+        let components: BTreeMap<String, ComponentStatus> = names
+            .into_iter()
+            .map(|name| (name.to_string(), ComponentStatus::new(name)))
+            .collect();
+        let snapshots = file.map(|file| {
+            let (tx, rx) = watch::channel(render(&components));
+            tokio::spawn(write_snapshots(file, rx));
+            Arc::new(tx)
+        });
+        Self {
+            components: Arc::new(Mutex::new(components)),
+            snapshots,
+        }
+    }
+
+    pub(crate) fn update(&self, name: &str, f: impl FnOnce(&mut ComponentStatus)) {
+        let mut components = self.lock();
+        let status = components
+            .entry(name.to_string())
+            .or_insert_with(|| ComponentStatus::new(name));
+        f(status);
+        // Rendered under the lock so snapshots reach the writer in order
+        if let Some(snapshots) = &self.snapshots {
+            snapshots.send_replace(render(&components));
+        }
+    }
+
+    /// Snapshot as pretty JSON
+    pub(crate) fn snapshot_json(&self) -> String {
+        render(&self.lock())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, ComponentStatus>> {
+        self.components
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Writes the latest snapshot to `file` whenever it changes. Snapshots sent
+/// while a write is in progress are coalesced into the next write.
+async fn write_snapshots(file: PathBuf, mut snapshots: watch::Receiver<String>) {
+    loop {
+        let json = snapshots.borrow_and_update().clone();
+        // Write then rename: readers never see a partial snapshot
+        let tmp = file.with_extension("json.tmp");
+        let written = match tokio::fs::write(&tmp, json).await {
+            Ok(()) => tokio::fs::rename(&tmp, &file).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = written {
+            warn!("[status] writing {} failed: {}", file.display(), e);
+        }
+        // Every board clone is gone
+        if snapshots.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+fn render(components: &BTreeMap<String, ComponentStatus>) -> String {
+    let snapshot = StatusSnapshot {
+        generated_at_ms: current_timestamp_ms(),
+        components: components.values().collect(),
+    };
+    common_json::to_string_pretty(&snapshot).unwrap_or_else(|e| {
+        warn!("[status] serializing snapshot failed: {}", e);
+        "{}".to_string()
+    })
+}
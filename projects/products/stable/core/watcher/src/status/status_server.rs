@@ -0,0 +1,96 @@
+// projects/products/stable/core/watcher/src/status/status_server.rs
+use std::net::SocketAddr;
+
+use log::{info, warn};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::sync::CancellationToken;
+
+use super::StatusBoard;
+
+/// Upper bound of a request head, anything larger is rejected
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Serves `GET /status` until `shutdown`; binding errors are returned to the caller
+pub(crate) async fn spawn_status_server(
+    addr: SocketAddr,
+    board: StatusBoard,
+    shutdown: CancellationToken,
+) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("[status] serving http://{}/status", listener.local_addr()?);
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, _)) => {
+                        let board = board.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = handle_connection(stream, &board).await {
+                                warn!("[status] request failed: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => warn!("[status] accept failed: {}", e),
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+async fn handle_connection(mut stream: TcpStream, board: &StatusBoard) -> std::io::Result<()> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        head.extend_from_slice(&buf[..n]);
+        if head.len() > MAX_REQUEST_BYTES {
+            return respond(&mut stream, "413 Payload Too Large", "text/plain", "").await;
+        }
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let mut request_line = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or_default();
+
+    match (method, path.split('?').next().unwrap_or_default()) {
+        ("GET", "/status") => {
+            let body = board.snapshot_json();
+            respond(&mut stream, "200 OK", "application/json", &body).await
+        }
+        ("GET", _) => respond(&mut stream, "404 Not Found", "text/plain", "not found\n").await,
+        _ => {
+            respond(
+                &mut stream,
+                "405 Method Not Allowed",
+                "text/plain",
+                "method not allowed\n",
+            )
+            .await
+        }
+    }
+}
+
+async fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> std::io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
@@ -1,25 +1,30 @@
 // projects/products/stable/core/watcher/src/supervisor.rs
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use common_time::current_timestamp_ms;
 use log::{error, info, warn};
 use reqwest::Client;
 use tokio::{process::Command, time::sleep};
 use tokio_util::sync::CancellationToken;
 
-use crate::config::{ComponentConfig, PingConfig, RestartPolicy};
+use crate::checks::run_check;
+use crate::config::{ComponentConfig, EscalationStep, PingConfig, RestartPolicy, WatcherConfig};
+use crate::escalation::{EscalationChain, FlapDetector, run_alert_hook};
+use crate::status::{ComponentState, RestartRecord, StatusBoard};
 
 const COOLDOWN_ON_RESTART_LOOP_SECS: u64 = 120; // 2 min: anti "restart storm"
 const SYSTEMCTL: &str = "systemctl";
-const PGREP: &str = "pgrep";
 
 #[derive(Clone)]
 pub(crate) struct Supervisor {
     client: Arc<Client>,
+    config: Arc<WatcherConfig>,
+    board: StatusBoard,
 }
 
 impl Supervisor {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(config: Arc<WatcherConfig>, board: StatusBoard) -> Self {
         let client = Client::builder()
             .timeout(Duration::from_secs(2))
             .build()
@@ -27,6 +32,8 @@ impl Supervisor {
 
         Self {
             client: Arc::new(client),
+            config,
+            board,
         }
     }
 
@@ -35,138 +42,205 @@ impl Supervisor {
         component: ComponentConfig,
         shutdown: CancellationToken,
     ) {
+        let name = component.name.as_str();
         let mut consecutive_failures: u32 = 0;
         let mut consecutive_restarts: u32 = 0;
 
+        let mut chain = EscalationChain::new(
+            component.escalation.steps.clone(),
+            component.restart.max_consecutive_restarts,
+        );
+        let mut flaps = component.flapping.as_ref().map(FlapDetector::new);
+        let mut was_healthy = false;
+        let mut was_flapping = false;
+        let mut alerted = false;
+
         let mut backoff_secs = component.restart.backoff_min_secs.max(1);
 
         loop {
-            let alive = self.ping_component(&component).await;
+            let result = run_check(&self.client, &component.ping).await;
+            let now_ms = current_timestamp_ms();
 
-            if alive {
-                if consecutive_failures > 0 || consecutive_restarts > 0 {
-                    info!(
-                        "component={} event=back_online failures={} restarts={}",
-                        component.name, consecutive_failures, consecutive_restarts
+            // Flapping: too many healthy -> failing transitions in the window
+            if let Some(flaps) = flaps.as_mut() {
+                if result.is_err() && was_healthy {
+                    flaps.record_transition(Instant::now());
+                }
+                let flapping = flaps.is_flapping(Instant::now());
+                if flapping != was_flapping {
+                    warn!(
+                        "component={} event={}",
+                        name,
+                        if flapping {
+                            "flapping_started"
+                        } else {
+                            "flapping_stopped"
+                        }
                     );
                 }
+                was_flapping = flapping;
+            }
+            was_healthy = result.is_ok();
+
+            let reason = match result {
+                Ok(()) => {
+                    if consecutive_failures > 0 || consecutive_restarts > 0 {
+                        info!(
+                            "component={} event=back_online failures={} restarts={}",
+                            name, consecutive_failures, consecutive_restarts
+                        );
+                    }
+                    self.board
+                        .update(name, |s| s.record_ok(now_ms, was_flapping));
+                    if alerted {
+                        self.alert(&component, "recovered", consecutive_failures, None)
+                            .await;
+                    }
 
-                consecutive_failures = 0;
-                consecutive_restarts = 0;
-                backoff_secs = component.restart.backoff_min_secs.max(1);
+                    consecutive_failures = 0;
+                    consecutive_restarts = 0;
+                    alerted = false;
+                    chain.reset();
+                    backoff_secs = component.restart.backoff_min_secs.max(1);
 
-                tokio::select! {
-                    _ = shutdown.cancelled() => {
-                        log::info!("component={} event=shutdown_requested", component.name);
+                    if !pause(&shutdown, name, component.ping_interval.max(1)).await {
                         break;
                     }
-                    _ = sleep(Duration::from_secs(component.ping_interval.max(1))) => {}
+                    continue;
                 }
-                continue;
-            }
+                Err(reason) => reason,
+            };
 
             consecutive_failures = consecutive_failures.saturating_add(1);
             warn!(
-                "component={} event=ping_failed failures={}",
-                component.name, consecutive_failures
+                "component={} event=ping_failed failures={} reason=\"{}\"",
+                name, consecutive_failures, reason
             );
-
-            // Decide whether to restart
-            let should_restart = match component.restart.policy {
-                RestartPolicy::Never => false,
-                RestartPolicy::Always => true,
-                RestartPolicy::OnFailure => true,
-            };
-
-            if !should_restart {
-                tokio::select! {
-                    _ = shutdown.cancelled() => {
-                        log::info!("component={} event=shutdown_requested", component.name);
+            self.board
+                .update(name, |s| s.record_failure(now_ms, &reason, was_flapping));
+
+            // Restarts are skipped when the policy forbids them, or while flapping
+            // (restarting would only feed the flap)
+            let allow_restarts = !was_flapping
+                && match component.restart.policy {
+                    RestartPolicy::Never => false,
+                    RestartPolicy::Always => true,
+                    RestartPolicy::OnFailure => true,
+                };
+
+            let Some(step) = chain.next_step(allow_restarts) else {
+                if allow_restarts && chain.has_restarts() {
+                    // Anti-loop: every step was tried, cooldown then start over
+                    error!(
+                        "component={} event=too_many_restarts restarts={} cooldown={}s",
+                        name, consecutive_restarts, COOLDOWN_ON_RESTART_LOOP_SECS
+                    );
+                    if !pause(&shutdown, name, COOLDOWN_ON_RESTART_LOOP_SECS).await {
                         break;
                     }
-                    _ = sleep(Duration::from_secs(component.ping_interval.max(1))) => {}
+                    // Partial reset for a clean retry
+                    chain.reset();
+                    consecutive_restarts = 0;
+                    backoff_secs = component.restart.backoff_min_secs.max(1);
+                } else if !pause(&shutdown, name, component.ping_interval.max(1)).await {
+                    // Nothing left to do but keep watching
+                    break;
                 }
                 continue;
-            }
+            };
 
-            // Anti-loop: if we exceed a threshold of consecutive restarts, cooldown
-            if let Some(max) = component.restart.max_consecutive_restarts
-                && consecutive_restarts >= max
-            {
-                error!(
-                    "component={} event=too_many_restarts restarts={} cooldown={}s",
-                    component.name, consecutive_restarts, COOLDOWN_ON_RESTART_LOOP_SECS
-                );
-                tokio::select! {
-                    _ = shutdown.cancelled() => {
-                        log::info!("component={} event=shutdown_requested", component.name);
-                        break;
+            self.board.update(name, |s| {
+                s.escalation_step = Some(step.as_str().to_string());
+            });
+
+            match step {
+                EscalationStep::Restart => {
+                    if self.restart_and_record(&component, "escalation").await {
+                        consecutive_restarts = consecutive_restarts.saturating_add(1);
+                        warn!(
+                            "component={} event=restarted restarts={}",
+                            name, consecutive_restarts
+                        );
                     }
-                    _ = sleep(Duration::from_secs(COOLDOWN_ON_RESTART_LOOP_SECS)) => {}
                 }
-                // Partial reset for a clean retry
-                consecutive_restarts = 0;
-                backoff_secs = component.restart.backoff_min_secs.max(1);
-                continue;
-            }
-
-            // Attempt the restart
-            match self.restart_component(&component).await {
-                Ok(()) => {
-                    consecutive_restarts = consecutive_restarts.saturating_add(1);
+                EscalationStep::RestartDependents => {
+                    if self.restart_and_record(&component, "escalation").await {
+                        consecutive_restarts = consecutive_restarts.saturating_add(1);
+                    }
+                    let trigger = format!("dependency:{}", name);
+                    for dependent in self.config.dependents_of(name) {
+                        if matches!(dependent.restart.policy, RestartPolicy::Never) {
+                            info!(
+                                "component={} event=dependent_restart_skipped dependency={} policy=never",
+                                dependent.name, name
+                            );
+                            continue;
+                        }
+                        self.restart_and_record(&dependent, &trigger).await;
+                    }
                     warn!(
-                        "component={} event=restarted restarts={}",
-                        component.name, consecutive_restarts
+                        "component={} event=restarted_with_dependents restarts={}",
+                        name, consecutive_restarts
                     );
                 }
-                Err(e) => {
+                EscalationStep::MarkDegraded => {
                     error!(
-                        "component={} event=restart_failed error=\"{}\"",
-                        component.name, e
+                        "component={} event=degraded failures={}",
+                        name, consecutive_failures
                     );
+                    self.board
+                        .update(name, |s| s.state = ComponentState::Degraded);
                 }
-            }
-
-            // Exponential backoff before the next ping
-            let max_backoff = component
-                .restart
-                .backoff_max_secs
-                .max(component.restart.backoff_min_secs)
-                .max(1);
-            tokio::select! {
-                _ = shutdown.cancelled() => {
-                    log::info!("component={} event=shutdown_requested", component.name);
-                    break;
+                EscalationStep::Alert => {
+                    self.alert(
+                        &component,
+                        "escalation",
+                        consecutive_failures,
+                        Some(&reason),
+                    )
+                    .await;
+                    alerted = true;
                 }
-                _ = sleep(Duration::from_secs(backoff_secs)) => {}
             }
-            backoff_secs = (backoff_secs.saturating_mul(2)).min(max_backoff);
-        }
-    }
 
-    async fn ping_component(&self, component: &ComponentConfig) -> bool {
-        match &component.ping {
-            PingConfig::Disabled => {
-                // "always alive" to never trigger a restart
-                true
+            let wait_secs = if step.is_restart() {
+                // Exponential backoff before the next ping
+                let current = backoff_secs;
+                let max_backoff = component
+                    .restart
+                    .backoff_max_secs
+                    .max(component.restart.backoff_min_secs)
+                    .max(1);
+                backoff_secs = (backoff_secs.saturating_mul(2)).min(max_backoff);
+                current
+            } else {
+                component.ping_interval.max(1)
+            };
+            if !pause(&shutdown, name, wait_secs).await {
+                break;
             }
-
-            PingConfig::Systemd { unit } => systemd_is_active(unit).await,
-
-            PingConfig::Process { process_name } => process_is_running(process_name).await,
-
-            PingConfig::Http { url } => self.http_is_ok(url).await,
         }
     }
 
-    async fn http_is_ok(&self, url: &str) -> bool {
-        match self.client.get(url).send().await {
-            Ok(resp) => resp.status().is_success(),
-            Err(e) => {
-                warn!("[http] GET {} failed: {}", url, e);
-                false
-            }
+    /// Restarts `component` and appends the attempt to its restart history
+    async fn restart_and_record(&self, component: &ComponentConfig, trigger: &str) -> bool {
+        let result = self.restart_component(component).await;
+        if let Err(e) = &result {
+            error!(
+                "component={} event=restart_failed trigger={} error=\"{}\"",
+                component.name, trigger, e
+            );
         }
+
+        let record = RestartRecord {
+            at_ms: current_timestamp_ms(),
+            trigger: trigger.to_string(),
+            ok: result.is_ok(),
+            error: result.as_ref().err().cloned(),
+        };
+        self.board
+            .update(&component.name, |s| s.record_restart(record));
+        result.is_ok()
     }
 
     async fn restart_component(&self, component: &ComponentConfig) -> Result<(), String> {
@@ -180,33 +254,58 @@ impl Supervisor {
 
         systemd_restart(&unit).await
     }
-}
 
-impl Default for Supervisor {
-    fn default() -> Self {
-        Self::new()
+    /// Runs the alert hook, if configured, with the component state in `WATCHER_*` variables
+    async fn alert(
+        &self,
+        component: &ComponentConfig,
+        event: &str,
+        failures: u32,
+        reason: Option<&str>,
+    ) {
+        let Some(command) = &component.escalation.alert_command else {
+            return;
+        };
+
+        let mut state = "";
+        let mut restarts = 0;
+        self.board.update(&component.name, |s| {
+            s.alerts_total += 1;
+            state = s.state.as_str();
+            restarts = s.restarts_total;
+        });
+
+        let env = [
+            ("WATCHER_COMPONENT", component.name.clone()),
+            ("WATCHER_EVENT", event.to_string()),
+            ("WATCHER_STATE", state.to_string()),
+            ("WATCHER_FAILURES", failures.to_string()),
+            ("WATCHER_RESTARTS", restarts.to_string()),
+            ("WATCHER_REASON", reason.unwrap_or_default().to_string()),
+        ];
+        run_alert_hook(
+            &component.name,
+            command,
+            component.escalation.alert_timeout_secs,
+            &env,
+        )
+        .await;
     }
 }
 
-/* ---------------- systemd helpers ---------------- */
-
-async fn systemd_is_active(unit: &str) -> bool {
-    // systemctl is-active --quiet <unit> => exit code 0 if active
-    match Command::new(SYSTEMCTL)
-        .arg("is-active")
-        .arg("--quiet")
-        .arg(unit)
-        .output()
-        .await
-    {
-        Ok(out) => out.status.success(),
-        Err(e) => {
-            warn!("[systemd] is-active failed for {}: {}", unit, e);
+/// Sleeps `secs`, returns false if shutdown was requested meanwhile
+async fn pause(shutdown: &CancellationToken, name: &str, secs: u64) -> bool {
+    tokio::select! {
+        _ = shutdown.cancelled() => {
+            log::info!("component={} event=shutdown_requested", name);
             false
         }
+        _ = sleep(Duration::from_secs(secs)) => true,
     }
 }
 
+/* ---------------- systemd helpers ---------------- */
+
 async fn systemd_restart(unit: &str) -> Result<(), String> {
     let out = Command::new(SYSTEMCTL)
         .arg("restart")
@@ -225,21 +324,3 @@ async fn systemd_restart(unit: &str) -> Result<(), String> {
         ))
     }
 }
-
-/* ---------------- process helpers ---------------- */
-
-async fn process_is_running(process_name: &str) -> bool {
-    // pgrep -x <process_name> => non-empty stdout if found
-    match Command::new(PGREP)
-        .arg("-x")
-        .arg(process_name)
-        .output()
-        .await
-    {
-        Ok(out) => out.status.success() && !out.stdout.is_empty(),
-        Err(e) => {
-            warn!("[process] pgrep failed for {}: {}", process_name, e);
-            false
-        }
-    }
-}
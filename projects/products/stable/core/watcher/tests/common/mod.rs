@@ -0,0 +1,7 @@
+// projects/products/stable/core/watcher/tests/common/mod.rs
+// Shared by several test binaries, each using only part of it
+#![allow(dead_code)]
+
+pub mod status_file;
+pub mod temp_dir;
+pub mod watcher_process;
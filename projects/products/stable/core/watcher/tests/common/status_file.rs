@@ -0,0 +1,64 @@
+// projects/products/stable/core/watcher/tests/common/status_file.rs
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Snapshot {
+    pub components: Vec<ComponentSnapshot>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ComponentSnapshot {
+    pub name: String,
+    pub state: String,
+    pub flapping: bool,
+    pub last_error: Option<String>,
+    pub restarts_total: u64,
+    pub alerts_total: u64,
+    pub restart_history: Vec<RestartSnapshot>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RestartSnapshot {
+    pub trigger: String,
+    pub ok: bool,
+}
+
+impl Snapshot {
+    pub fn component(&self, name: &str) -> &ComponentSnapshot {
+        self.components
+            .iter()
+            .find(|c| c.name == name)
+            .unwrap_or_else(|| panic!("component {name} missing from {self:?}"))
+    }
+}
+
+pub fn parse_snapshot(json: &str) -> Snapshot {
+    common_json::from_str(json).unwrap_or_else(|e| panic!("invalid snapshot {json}: {e:?}"))
+}
+
+/// Polls the status file until `done` holds, panics with the last snapshot on timeout
+pub fn wait_for_status(
+    path: &Path,
+    timeout: Duration,
+    done: impl Fn(&Snapshot) -> bool,
+) -> Snapshot {
+    let started_at = Instant::now();
+    let mut last = None;
+    loop {
+        if let Ok(json) = fs::read_to_string(path) {
+            let snapshot = parse_snapshot(&json);
+            if done(&snapshot) {
+                return snapshot;
+            }
+            last = Some(snapshot);
+        }
+        if started_at.elapsed() >= timeout {
+            panic!("status condition not reached within {timeout:?}, last snapshot: {last:?}");
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
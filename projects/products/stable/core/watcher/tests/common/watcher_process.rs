@@ -0,0 +1,44 @@
+// projects/products/stable/core/watcher/tests/common/watcher_process.rs
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+
+use super::temp_dir::TempDir;
+
+pub fn write_config(contents: &str) -> (TempDir, PathBuf) {
+    let dir = TempDir::new();
+    let path = write_config_in(&dir, contents);
+    (dir, path)
+}
+
+pub fn write_config_in(dir: &TempDir, contents: &str) -> PathBuf {
+    let path = dir.path().join("watcher.toml");
+    fs::write(&path, contents).expect("write config");
+    path
+}
+
+pub fn spawn_watcher(config_path: &Path) -> Child {
+    Command::new(env!("CARGO_BIN_EXE_watcher"))
+        .env("WATCHER_CONFIG", config_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn watcher")
+}
+
+/// Kills the watcher when the test ends, even on a failed assertion
+pub struct RunningWatcher(Child);
+
+impl RunningWatcher {
+    pub fn spawn(config_path: &Path) -> Self {
+        Self(spawn_watcher(config_path))
+    }
+}
+
+impl Drop for RunningWatcher {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
@@ -1,71 +1,10 @@
 // projects/products/stable/core/watcher/tests/config_tests.rs
-use std::fs;
-use std::hash::{Hash, Hasher};
-use std::process::{Command, Stdio};
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+mod common;
 
-static TEMP_DIR_COUNTER: AtomicU64 = AtomicU64::new(0);
+use std::process::Command;
+use std::time::{Duration, Instant};
 
-struct TempDir {
-    path: std::path::PathBuf,
-}
-
-impl TempDir {
-    fn new() -> Self {
-        let nanos = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_else(|_| Duration::from_secs(0))
-            .as_nanos();
-        let pid = std::process::id();
-        let counter = TEMP_DIR_COUNTER.fetch_add(1, Ordering::Relaxed);
-        let thread_hash = {
-            let mut hasher = std::collections::hash_map::DefaultHasher::new();
-            std::thread::current().id().hash(&mut hasher);
-            hasher.finish()
-        };
-
-        for collision_retry in 0..8 {
-            let path = std::env::temp_dir().join(format!(
-                "watcher_test_{nanos}_{pid}_{thread_hash}_{counter}_{collision_retry}"
-            ));
-            match fs::create_dir(&path) {
-                Ok(()) => return Self { path },
-                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
-                Err(e) => panic!("create temp dir failed: {e}"),
-            }
-        }
-
-        panic!("failed to create unique temp dir after retries");
-    }
-
-    fn path(&self) -> &std::path::Path {
-        &self.path
-    }
-}
-
-impl Drop for TempDir {
-    fn drop(&mut self) {
-        let _ = fs::remove_dir_all(&self.path);
-    }
-}
-
-fn write_config(contents: &str) -> (TempDir, std::path::PathBuf) {
-    let dir = TempDir::new();
-    let path = dir.path().join("watcher.toml");
-    fs::write(&path, contents).expect("write config");
-    (dir, path)
-}
-
-fn spawn_watcher(config_path: &std::path::Path) -> std::process::Child {
-    Command::new(env!("CARGO_BIN_EXE_watcher"))
-        .env("WATCHER_CONFIG", config_path)
-        .stdin(Stdio::null())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .expect("spawn watcher")
-}
+use common::watcher_process::{spawn_watcher, write_config};
 
 #[test]
 fn test_valid_config_starts() {
@@ -181,3 +120,108 @@ log_level = "not_a_level"
         "expected stderr to mention log_level or configuration failure, got: {stderr}"
     );
 }
+
+fn assert_config_rejected(config: &str, expected: &str) {
+    let (_dir, config_path) = write_config(config);
+    let output = Command::new(env!("CARGO_BIN_EXE_watcher"))
+        .env("WATCHER_CONFIG", config_path)
+        .output()
+        .expect("run watcher");
+
+    assert!(
+        !output.status.success(),
+        "expected watcher to reject the configuration"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(expected),
+        "expected stderr to mention {expected:?}, got: {stderr}"
+    );
+}
+
+#[test]
+fn test_unknown_dependency_fails() {
+    assert_config_rejected(
+        r#"
+[[components]]
+name = "api"
+ping = "disabled"
+depends_on = ["db"]
+"#,
+        "depends on unknown component 'db'",
+    );
+}
+
+#[test]
+fn test_dependency_cycle_fails() {
+    assert_config_rejected(
+        r#"
+[[components]]
+name = "api"
+ping = "disabled"
+depends_on = ["db"]
+
+[[components]]
+name = "db"
+ping = "disabled"
+depends_on = ["api"]
+"#,
+        "Dependency cycle",
+    );
+}
+
+#[test]
+fn test_alert_step_requires_alert_command() {
+    assert_config_rejected(
+        r#"
+[[components]]
+name = "api"
+ping = { tcp = { addr = "127.0.0.1:1" } }
+escalation = { steps = ["restart", "alert"] }
+"#,
+        "requires escalation.alert_command",
+    );
+}
+
+#[test]
+fn test_empty_script_command_fails() {
+    assert_config_rejected(
+        r#"
+[[components]]
+name = "api"
+ping = { script = { command = [] } }
+"#,
+        "ping.script.command is empty",
+    );
+}
+
+#[test]
+fn test_names_are_compared_trimmed() {
+    assert_config_rejected(
+        r#"
+[[components]]
+name = "api"
+ping = "disabled"
+
+[[components]]
+name = " api "
+ping = "disabled"
+"#,
+        "Duplicate component name 'api'",
+    );
+    // `db ` resolves to `db`, so the cycle is found instead of an unknown name
+    assert_config_rejected(
+        r#"
+[[components]]
+name = " api"
+ping = "disabled"
+depends_on = ["db "]
+
+[[components]]
+name = "db"
+ping = "disabled"
+depends_on = ["api"]
+"#,
+        "Dependency cycle",
+    );
+}
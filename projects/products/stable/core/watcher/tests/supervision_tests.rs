@@ -0,0 +1,250 @@
+// projects/products/stable/core/watcher/tests/supervision_tests.rs
+mod common;
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+use common::status_file::{parse_snapshot, wait_for_status};
+use common::temp_dir::TempDir;
+use common::watcher_process::{RunningWatcher, write_config_in};
+
+const WAIT: Duration = Duration::from_secs(10);
+
+fn toml_path(dir: &TempDir, file: &str) -> String {
+    dir.path().join(file).display().to_string()
+}
+
+/// `[logging]` and `[status]` sections writing into `dir`
+fn sections(dir: &TempDir) -> String {
+    format!(
+        r#"
+[logging]
+log_file = "{}"
+
+[status]
+file = "{}"
+"#,
+        toml_path(dir, "watcher.log"),
+        toml_path(dir, "status.json")
+    )
+}
+
+/// Minimal HTTP server answering every request with `body`
+fn serve_json(body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let addr = listener.local_addr().expect("addr").to_string();
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+        }
+    });
+    addr
+}
+
+#[test]
+fn test_tcp_check_reports_healthy() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let addr = listener.local_addr().expect("addr");
+
+    let dir = TempDir::new();
+    let config = format!(
+        r#"
+[[components]]
+name = "db"
+ping_interval = 1
+ping = {{ tcp = {{ addr = "{addr}" }} }}
+{}"#,
+        sections(&dir)
+    );
+    let _watcher = RunningWatcher::spawn(&write_config_in(&dir, &config));
+
+    wait_for_status(&dir.path().join("status.json"), WAIT, |s| {
+        s.component("db").state == "healthy"
+    });
+}
+
+#[test]
+fn test_engine_check_rejects_another_service() {
+    let engine = serve_json(r#"{"ok":true,"service":"engine"}"#);
+    let other = serve_json(r#"{"ok":true,"service":"central_ui"}"#);
+
+    let dir = TempDir::new();
+    let config = format!(
+        r#"
+[[components]]
+name = "engine"
+ping_interval = 1
+ping = {{ engine = {{ url = "http://{engine}" }} }}
+restart = {{ policy = "never" }}
+
+[[components]]
+name = "impostor"
+ping_interval = 1
+ping = {{ engine = {{ url = "http://{other}/" }} }}
+restart = {{ policy = "never" }}
+{}"#,
+        sections(&dir)
+    );
+    let _watcher = RunningWatcher::spawn(&write_config_in(&dir, &config));
+
+    let snapshot = wait_for_status(&dir.path().join("status.json"), WAIT, |s| {
+        s.component("engine").state == "healthy" && s.component("impostor").state == "failing"
+    });
+    let error = snapshot.component("impostor").last_error.as_deref();
+    assert!(
+        error.is_some_and(|e| e.contains("expected 'engine'")),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[test]
+fn test_failing_script_escalates_to_degraded_and_alert() {
+    let dir = TempDir::new();
+    let alert_file = toml_path(&dir, "alert.txt");
+    let config = format!(
+        r#"
+[[components]]
+name = "api"
+ping_interval = 1
+ping = {{ script = {{ command = ["sh", "-c", "echo not ready >&2; exit 3"] }} }}
+restart = {{ policy = "never" }}
+
+[components.escalation]
+steps = ["restart", "mark_degraded", "alert"]
+alert_command = ["sh", "-c", "echo \"$WATCHER_COMPONENT $WATCHER_EVENT $WATCHER_STATE\" >> {alert_file}"]
+{}"#,
+        sections(&dir)
+    );
+    let _watcher = RunningWatcher::spawn(&write_config_in(&dir, &config));
+
+    let snapshot = wait_for_status(&dir.path().join("status.json"), WAIT, |s| {
+        s.component("api").alerts_total == 1
+    });
+    let api = snapshot.component("api");
+    assert_eq!(api.state, "degraded");
+    // policy = "never": the restart step is skipped
+    assert_eq!(api.restarts_total, 0);
+    assert!(
+        api.last_error
+            .as_deref()
+            .is_some_and(|e| e.contains("not ready")),
+        "unexpected error: {:?}",
+        api.last_error
+    );
+
+    let alert = fs::read_to_string(&alert_file).expect("alert hook output");
+    assert_eq!(alert.trim(), "api escalation degraded");
+}
+
+#[test]
+fn test_restart_dependents_is_recorded_in_history() {
+    let dir = TempDir::new();
+    let config = format!(
+        r#"
+[[components]]
+name = "db"
+ping_interval = 1
+ping = {{ tcp = {{ addr = "127.0.0.1:1" }} }}
+restart = {{ policy = "always", systemd_unit = "watcher-test-missing.service", backoff_min_secs = 1, backoff_max_secs = 1 }}
+escalation = {{ steps = ["restart_dependents", "mark_degraded"] }}
+
+[[components]]
+name = "api"
+ping = "disabled"
+depends_on = ["db"]
+restart = {{ systemd_unit = "watcher-test-missing.service" }}
+{}"#,
+        sections(&dir)
+    );
+    let _watcher = RunningWatcher::spawn(&write_config_in(&dir, &config));
+
+    let snapshot = wait_for_status(&dir.path().join("status.json"), WAIT, |s| {
+        s.component("db").state == "degraded"
+    });
+    let db = snapshot.component("db");
+    assert_eq!(db.restart_history.len(), 1);
+    assert_eq!(db.restart_history[0].trigger, "escalation");
+    // The unit does not exist: the attempt is recorded as failed
+    assert!(!db.restart_history[0].ok);
+
+    let api = snapshot.component("api");
+    assert_eq!(api.restart_history.len(), 1);
+    assert_eq!(api.restart_history[0].trigger, "dependency:db");
+}
+
+#[test]
+fn test_flapping_component_is_flagged() {
+    let dir = TempDir::new();
+    let counter = toml_path(&dir, "counter");
+    let config = format!(
+        r#"
+[[components]]
+name = "api"
+ping_interval = 1
+# Alternates between success and failure on every check
+ping = {{ script = {{ command = ["sh", "-c", "n=$(cat {counter} 2>/dev/null || echo 0); echo $((n + 1)) > {counter}; [ $((n % 2)) -eq 0 ]"] }} }}
+restart = {{ policy = "never" }}
+flapping = {{ window_secs = 60, max_transitions = 2 }}
+{}"#,
+        sections(&dir)
+    );
+    let _watcher = RunningWatcher::spawn(&write_config_in(&dir, &config));
+
+    wait_for_status(&dir.path().join("status.json"), WAIT, |s| {
+        s.component("api").flapping
+    });
+}
+
+#[test]
+fn test_status_endpoint_serves_snapshot() {
+    let port = TcpListener::bind("127.0.0.1:0")
+        .and_then(|l| l.local_addr())
+        .expect("free port")
+        .port();
+
+    let dir = TempDir::new();
+    let config = format!(
+        r#"
+[[components]]
+name = "engine"
+ping = "disabled"
+
+[logging]
+log_file = "{}"
+
+[status]
+http_addr = "127.0.0.1:{port}"
+"#,
+        toml_path(&dir, "watcher.log")
+    );
+    let _watcher = RunningWatcher::spawn(&write_config_in(&dir, &config));
+
+    let started_at = std::time::Instant::now();
+    let response = loop {
+        if let Ok(mut stream) = TcpStream::connect(("127.0.0.1", port)) {
+            stream
+                .write_all(b"GET /status HTTP/1.1\r\nHost: localhost\r\n\r\n")
+                .expect("write request");
+            let mut response = String::new();
+            stream.read_to_string(&mut response).expect("read response");
+            break response;
+        }
+        assert!(started_at.elapsed() < WAIT, "status endpoint never came up");
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    assert!(response.starts_with("HTTP/1.1 200"), "got: {response}");
+    let body = response.split("\r\n\r\n").nth(1).expect("body");
+    let snapshot = parse_snapshot(body);
+    assert_eq!(snapshot.components.len(), 1);
+    assert_eq!(snapshot.component("engine").name, "engine");
+}